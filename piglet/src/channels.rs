@@ -0,0 +1,112 @@
+use crate::nimbus_hd_1_0::nimbus_core::NimbusCore;
+use anyhow::anyhow;
+use piglet_client::client::{Error, Error::InvalidArgument, RobotClient};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+// Settings for some of an instrument's channels, keyed by channel number starting from 1 like the
+// robot counts them. Calls want one value per channel, so this turns into those arrays with every
+// channel that isn't in the map switched off in tips_used and given the default for everything
+// else.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChannelMap<T>(BTreeMap<u16, T>);
+
+impl<T> ChannelMap<T> {
+    pub fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    pub fn insert(&mut self, channel: u16, value: T) -> Option<T> {
+        self.0.insert(channel, value)
+    }
+
+    pub fn with(mut self, channel: u16, value: T) -> Self {
+        self.0.insert(channel, value);
+        self
+    }
+
+    pub fn get(&self, channel: u16) -> Option<&T> {
+        self.0.get(&channel)
+    }
+
+    pub fn remove(&mut self, channel: u16) -> Option<T> {
+        self.0.remove(&channel)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u16, &T)> {
+        self.0.iter().map(|(channel, value)| (*channel, value))
+    }
+
+    pub fn channels(&self) -> impl Iterator<Item = u16> + '_ {
+        self.0.keys().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    // Fails if any channel isn't one of the instrument's
+    pub fn validate(&self, channel_count: u16) -> Result<(), Error> {
+        match self.channels().find(|c| *c == 0 || *c > channel_count) {
+            Some(channel) => Err(InvalidArgument(anyhow!(
+                "Channel {} is out of range, channels go from 1 to {}",
+                channel,
+                channel_count
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    // The tips_used argument for a call that only moves the channels in the map
+    pub fn tips_used(&self, channel_count: u16) -> Result<Vec<u16>, Error> {
+        self.column(channel_count, |_| 1)
+    }
+
+    // One value per channel picked out of each channel's settings, for use as a call's argument
+    pub fn column<U: Default>(
+        &self,
+        channel_count: u16,
+        f: impl Fn(&T) -> U,
+    ) -> Result<Vec<U>, Error> {
+        self.validate(channel_count)?;
+        Ok((1..=channel_count)
+            .map(|channel| self.get(channel).map(&f).unwrap_or_default())
+            .collect())
+    }
+}
+
+impl<T: Clone + Default> ChannelMap<T> {
+    pub fn to_vec(&self, channel_count: u16) -> Result<Vec<T>, Error> {
+        self.column(channel_count, T::clone)
+    }
+}
+
+impl<T> Default for ChannelMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(u16, T)> for ChannelMap<T> {
+    fn from_iter<I: IntoIterator<Item = (u16, T)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T, const N: usize> From<[(u16, T); N]> for ChannelMap<T> {
+    fn from(values: [(u16, T); N]) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+// How many channels the instrument has, which is how long every per-channel argument has to be
+pub async fn channel_count(robot: &Arc<RobotClient>) -> Result<u16, Error> {
+    Ok(NimbusCore::new(robot)
+        .get_channel_configuration_1()
+        .await?
+        .channels)
+}
@@ -0,0 +1,142 @@
+use crate::nimbus_hd_1_0::nimbus_core_ethernet::NimbusCoreEthernet;
+use piglet_client::client::RobotClient;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinSet;
+
+// The port instruments accept connections on unless someone changed it with set_tcp_port. Other
+// things listen on 2000 too, like Cisco phones, so only scan hosts that could be instruments.
+pub const DEFAULT_PORT: u16 = 2000;
+
+// Long enough for an instrument on the local network to connect and answer a few calls
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Instrument {
+    pub address: SocketAddr,
+    pub instrument_type: String,
+    // The serial number
+    pub instrument_id: String,
+    pub host_name: String,
+    // Where the instrument listens for UDP, see the note on scan
+    pub udp_port: u16,
+}
+
+// Opens a TCP session to every host at once, doing the HOI handshake and registration, and
+// returns the ones that answer like an instrument. Anything that doesn't within timeout is skipped.
+// Whatever else listens on the port sees a connection and a few bytes it won't understand, so keep
+// hosts to machines that could be instruments.
+//
+// Instruments also listen on a UDP port, but nobody has captured what they send or expect there,
+// so there's no broadcast or announcement to listen for instead.
+pub async fn scan<I: IntoIterator<Item = IpAddr>>(
+    hosts: I,
+    port: u16,
+    timeout: Duration,
+) -> Vec<Instrument> {
+    let mut probes = JoinSet::new();
+    for host in hosts {
+        probes.spawn(async move {
+            tokio::time::timeout(timeout, identify(SocketAddr::new(host, port))).await
+        });
+    }
+
+    let mut instruments = Vec::new();
+    while let Some(probe) = probes.join_next().await {
+        if let Ok(Ok(Ok(instrument))) = probe {
+            instruments.push(instrument);
+        }
+    }
+    instruments.sort_by_key(|i| i.address);
+    instruments
+}
+
+// Every other host in the /24 around this machine's address on the default route, to pass to scan
+// when the instruments are somewhere on the local network
+pub fn local_subnet() -> Result<Vec<IpAddr>, anyhow::Error> {
+    let local = local_address()?;
+    let [a, b, c, _] = local.octets();
+    Ok((1..=254)
+        .map(|d| Ipv4Addr::new(a, b, c, d))
+        .filter(|host| *host != local)
+        .map(IpAddr::V4)
+        .collect())
+}
+
+// Connecting only does the handshake and registers, which anything that isn't an instrument fails
+// quickly, then four calls to the Ethernet object describe it
+pub async fn identify(address: SocketAddr) -> Result<Instrument, anyhow::Error> {
+    let robot = Arc::new(RobotClient::connect(address).await?);
+    let ethernet = NimbusCoreEthernet::new(&robot);
+    let instrument = async {
+        Ok::<_, anyhow::Error>(Instrument {
+            address,
+            instrument_type: ethernet.get_instrument_type().await?,
+            instrument_id: ethernet.get_instrument_id().await?,
+            host_name: ethernet.get_host_name().await?,
+            udp_port: ethernet.get_udp_port().await?,
+        })
+    }
+    .await;
+    drop(ethernet);
+    if let Ok(robot) = Arc::try_unwrap(robot) {
+        robot.close().await?;
+    }
+    instrument
+}
+
+fn local_address() -> Result<Ipv4Addr, anyhow::Error> {
+    // Connecting a UDP socket sends nothing but makes the OS pick the interface it would route
+    // through. The address is from a documentation range so it's never anything real.
+    let socket = UdpSocket::bind("0.0.0.0:0")?;
+    socket.connect("192.0.2.1:9")?;
+    match socket.local_addr()?.ip() {
+        IpAddr::V4(ip) if !ip.is_unspecified() => Ok(ip),
+        other => anyhow::bail!("No IPv4 address to scan around, got {}", other),
+    }
+}
+
+// A simulator on the loopback interface that answers identify like an instrument would
+#[cfg(feature = "testing")]
+pub async fn responder(
+    instrument_type: &str,
+    instrument_id: &str,
+    host_name: &str,
+    udp_port: u16,
+) -> Result<piglet_client::testing::Simulator, anyhow::Error> {
+    use bytes::BytesMut;
+    use piglet_client::{
+        object_address::ObjectAddress,
+        testing::{SimulatedObject, Simulator},
+        values::PigletSerialize,
+    };
+
+    let ethernet = ObjectAddress {
+        module_id: 1,
+        node_id: 1,
+        object_id: 259,
+    };
+    let mut builder = Simulator::builder().root(
+        ethernet.object_id,
+        SimulatedObject {
+            name: "Ethernet".to_string(),
+            version: "1.0".to_string(),
+            ..Default::default()
+        },
+    );
+    for (call_type_id, value) in [(4, host_name), (18, instrument_id), (19, instrument_type)] {
+        let value = value.to_string();
+        builder = builder.on(&ethernet, 1, call_type_id, move |_| {
+            let mut values = BytesMut::new();
+            value.serialize(&mut values);
+            Ok((1, values.freeze()))
+        });
+    }
+    builder = builder.on(&ethernet, 1, 26, move |_| {
+        let mut values = BytesMut::new();
+        udp_port.serialize(&mut values);
+        Ok((1, values.freeze()))
+    });
+    builder.start().await
+}
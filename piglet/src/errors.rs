@@ -0,0 +1,139 @@
+use piglet_client::client::{Error, RobotError};
+use piglet_client::object_tree::ObjectTree;
+use piglet_client::values::ErrorCode;
+
+// What went wrong when the robot rejects a call, for recovery code to match on instead of raw
+// codes
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ErrorKind {
+    NoTip,
+    TipPresent,
+    TipNotDiscarded,
+    WrongTip,
+    LiquidNotFound,
+    InsufficientLiquid,
+    LiquidNotAspirated,
+    Clot,
+    PressureOutOfRange,
+    Obstacle,
+    DoorOpen,
+    Unknown(ErrorCode),
+}
+
+impl ErrorKind {
+    pub fn description(&self) -> &'static str {
+        match self {
+            ErrorKind::NoTip => "No tip was picked up, or there wasn't one where expected",
+            ErrorKind::TipPresent => "There's already a tip on the channel",
+            ErrorKind::TipNotDiscarded => "The tip couldn't be discarded",
+            ErrorKind::WrongTip => "The tip on the channel isn't the expected type",
+            ErrorKind::LiquidNotFound => "No liquid surface was found",
+            ErrorKind::InsufficientLiquid => "There isn't enough liquid to aspirate",
+            ErrorKind::LiquidNotAspirated => "Liquid wasn't aspirated correctly",
+            ErrorKind::Clot => "A clot was detected while aspirating",
+            ErrorKind::PressureOutOfRange => "Pressure went outside the expected curve",
+            ErrorKind::Obstacle => "Movement was blocked by an obstacle",
+            ErrorKind::DoorOpen => "The door is open",
+            ErrorKind::Unknown(_) => "This code isn't in the catalog",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            ErrorKind::Unknown(code) => write!(f, "Unknown error code {}", code.0),
+            kind => write!(f, "{}", kind.description()),
+        }
+    }
+}
+
+// Codes mean different things on different objects, so each is scoped to the name of the objects
+// that report it, like Channel for NimbusCore.Channel[3]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatalogEntry {
+    pub object: String,
+    pub code: ErrorCode,
+    pub kind: ErrorKind,
+}
+
+// No codes have been documented or recorded from a robot yet, so there's no built-in table. A
+// catalog starts empty and is filled with the codes seen from the firmware in use.
+#[derive(Clone, Debug, Default)]
+pub struct Catalog {
+    entries: Vec<CatalogEntry>,
+}
+
+impl Catalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Objects are named without their index, like Channel for every channel
+    pub fn with(mut self, object: &str, code: ErrorCode, kind: ErrorKind) -> Self {
+        self.entries.push(CatalogEntry {
+            object: object.to_string(),
+            code,
+            kind,
+        });
+        self
+    }
+
+    pub fn entries(&self) -> &[CatalogEntry] {
+        &self.entries
+    }
+
+    // Looks up a code reported by an object with the given name, like Channel
+    pub fn lookup(&self, object: &str, code: ErrorCode) -> ErrorKind {
+        self.entries
+            .iter()
+            .find(|e| e.object.eq_ignore_ascii_case(object) && e.code == code)
+            .map(|e| e.kind)
+            .unwrap_or(ErrorKind::Unknown(code))
+    }
+
+    // The tree names the object that reported the error, see RobotClient::object_tree. Errors from
+    // objects it doesn't have are Unknown.
+    pub fn kind(&self, tree: &ObjectTree, error: &RobotError) -> ErrorKind {
+        match tree.find(error.source()) {
+            Some(node) => self.lookup(&node.object.name, error.code()),
+            None => ErrorKind::Unknown(error.code()),
+        }
+    }
+
+    // Every error the robot reported for a call along with what it means, empty if the call
+    // failed some other way like a timeout
+    pub fn kinds<'a>(
+        &self,
+        tree: &ObjectTree,
+        error: &'a Error,
+    ) -> Vec<(&'a RobotError, ErrorKind)> {
+        match error {
+            Error::CallError { errors, .. } => {
+                errors.iter().map(|e| (e, self.kind(tree, e))).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    // Whether any object reported this kind of error, like a channel without a tip
+    pub fn has(&self, tree: &ObjectTree, error: &Error, wanted: ErrorKind) -> bool {
+        self.kinds(tree, error)
+            .iter()
+            .any(|(_, kind)| *kind == wanted)
+    }
+}
+
+// The number of the channel that reported an error, like 3 for NimbusCore.Channel[3] or 1 for the
+// only channel
+pub fn channel(tree: &ObjectTree, error: &RobotError) -> Option<u16> {
+    let node = tree.find(error.source())?;
+    if node.object.name != "Channel" {
+        return None;
+    }
+    let last = node.path.rsplit('.').next()?;
+    match last.split_once('[') {
+        Some((_, n)) => n.strip_suffix(']')?.parse().ok(),
+        None => Some(1),
+    }
+}
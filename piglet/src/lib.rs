@@ -1,5 +1,13 @@
+pub mod channels;
+pub mod discovery;
+pub mod errors;
+pub mod liquid_handling;
+
+#[cfg(feature = "testing")]
+pub use piglet_client::testing;
 pub use piglet_client::{
-    client::Error, client::RobotClient, dynamic_object::DynamicObject,
-    object_address::ObjectAddress, values,
+    client::Error, client::ReconnectPolicy, client::RobotClient, client::Timeout, compatibility,
+    derive, dynamic_object::DynamicObject, events, object_address::ObjectAddress, object_tree,
+    units, values,
 };
 pub use piglet_generated::nimbus_hd_1_0;
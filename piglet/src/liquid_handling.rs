@@ -0,0 +1,390 @@
+use crate::channels::{ChannelMap, channel_count};
+use crate::nimbus_hd_1_0::nimbus_core_pipette::{
+    Aspirate1Request, DispenseRequest, NimbusCorePipette,
+};
+use anyhow::anyhow;
+use piglet_client::client::{Error, Error::InvalidArgument, RobotClient};
+use piglet_client::units::{Microliters, MicrolitersPerSecond, Millimeters, MmPerSecond};
+use std::sync::Arc;
+
+// Where one well is in deck coordinates. For a tip rack, top is where the channel starts pressing
+// onto the tip and bottom is where it stops.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Well {
+    pub x: Millimeters,
+    pub y: Millimeters,
+    pub top: Millimeters,
+    pub bottom: Millimeters,
+}
+
+// A plate or rack of wells on a regular grid. Columns go left to right along x and rows go from
+// the back towards you along -y, the same way channel 1 is at the back.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Labware {
+    // The centre of A1
+    pub x: Millimeters,
+    pub y: Millimeters,
+    pub top: Millimeters,
+    pub bottom: Millimeters,
+    pub rows: u16,
+    pub columns: u16,
+    // Distance between the centres of neighbouring wells
+    pub pitch: Millimeters,
+}
+
+impl Labware {
+    // An SBS 96 well plate or rack
+    pub fn grid_96(x: Millimeters, y: Millimeters, top: Millimeters, bottom: Millimeters) -> Self {
+        Self {
+            x,
+            y,
+            top,
+            bottom,
+            rows: 8,
+            columns: 12,
+            pitch: Millimeters(9.0),
+        }
+    }
+
+    // Rows and columns count from 1, so well_at(1, 1) is A1
+    pub fn well_at(&self, row: u16, column: u16) -> Result<Well, Error> {
+        if row == 0 || row > self.rows || column == 0 || column > self.columns {
+            return Err(InvalidArgument(anyhow!(
+                "Row {} column {} is outside a {}x{} labware",
+                row,
+                column,
+                self.rows,
+                self.columns
+            )));
+        }
+        Ok(Well {
+            x: self.x + self.pitch * (column - 1) as f64,
+            y: self.y - self.pitch * (row - 1) as f64,
+            top: self.top,
+            bottom: self.bottom,
+        })
+    }
+
+    // A well by its name, like "A1" or "H12"
+    pub fn well(&self, name: &str) -> Result<Well, Error> {
+        let invalid = || InvalidArgument(anyhow!("{:?} isn't a well name", name));
+        let mut chars = name.chars();
+        let row = chars
+            .next()
+            .filter(|c| c.is_ascii_uppercase())
+            .ok_or_else(invalid)?;
+        let column = chars.as_str().parse::<u16>().map_err(|_| invalid())?;
+        self.well_at(row as u16 - 'A' as u16 + 1, column)
+    }
+
+    // The wells of a column with channel 1 on row A, channel 2 on row B and so on
+    pub fn column(&self, column: u16) -> Result<ChannelMap<Well>, Error> {
+        (1..=self.rows)
+            .map(|row| Ok((row, self.well_at(row, column)?)))
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TipRack {
+    pub labware: Labware,
+    // As the instrument numbers them, see get_tip_and_needle_types
+    pub tip_type: u16,
+}
+
+// How to move a liquid. Raw integers are in whatever units the firmware uses for that argument.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LiquidClass {
+    pub aspirate_type: i16,
+    pub dispense_type: i16,
+    pub aspirate_speed: MicrolitersPerSecond,
+    pub dispense_speed: MicrolitersPerSecond,
+    pub cutoff_speed: MicrolitersPerSecond,
+    pub liquid_exit_speed: MmPerSecond,
+    pub transport_air_volume: Microliters,
+    pub blowout_volume: Microliters,
+    pub stop_back_volume: Microliters,
+    pub settling_time: u32,
+    // How far above the bottom of the well the tip goes to aspirate and dispense
+    pub aspirate_height: Millimeters,
+    pub dispense_height: Millimeters,
+    pub submerge_depth: Millimeters,
+    // 0 turns liquid level detection off
+    pub lld_mode: i16,
+    pub capacitive_lld_sensitivity: i16,
+    pub pressure_lld_sensitivity: i16,
+}
+
+impl LiquidClass {
+    // A gentle starting point for aqueous liquids, not something tuned for any particular tip
+    pub fn water() -> Self {
+        Self {
+            aspirate_type: 0,
+            dispense_type: 0,
+            aspirate_speed: MicrolitersPerSecond(100.0),
+            dispense_speed: MicrolitersPerSecond(100.0),
+            cutoff_speed: MicrolitersPerSecond(25.0),
+            liquid_exit_speed: MmPerSecond(10.0),
+            transport_air_volume: Microliters(5.0),
+            blowout_volume: Microliters(10.0),
+            stop_back_volume: Microliters(0.0),
+            settling_time: 5,
+            aspirate_height: Millimeters(1.0),
+            dispense_height: Millimeters(2.0),
+            submerge_depth: Millimeters(2.0),
+            lld_mode: 0,
+            capacitive_lld_sensitivity: 1,
+            pressure_lld_sensitivity: 1,
+        }
+    }
+}
+
+pub enum Waste {
+    // The instrument's own waste chute
+    Default,
+    Wells(ChannelMap<Well>),
+}
+
+// Pipetting in terms of labware rather than coordinate arrays. Keeps track of which channels have
+// tips so transfers only use those.
+pub struct LiquidHandler {
+    pipette: NimbusCorePipette,
+    channels: u16,
+    tips: ChannelMap<u16>,
+    pub traverse_height: Millimeters,
+}
+
+impl LiquidHandler {
+    pub async fn new(
+        robot: &Arc<RobotClient>,
+        traverse_height: Millimeters,
+    ) -> Result<LiquidHandler, Error> {
+        Ok(LiquidHandler {
+            pipette: NimbusCorePipette::new(robot),
+            channels: channel_count(robot).await?,
+            tips: ChannelMap::new(),
+            traverse_height,
+        })
+    }
+
+    pub fn channel_count(&self) -> u16 {
+        self.channels
+    }
+
+    // The tip type on each channel that has one
+    pub fn tips(&self) -> &ChannelMap<u16> {
+        &self.tips
+    }
+
+    // One tip per channel, taken from the columns in turn going down each one, until every channel
+    // has one or the columns run out
+    pub async fn pick_up_tips(&mut self, rack: &TipRack, columns: &[u16]) -> Result<(), Error> {
+        let mut wells = Vec::new();
+        for column in columns {
+            wells.extend(rack.labware.column(*column)?.iter().map(|(_, well)| *well));
+        }
+        let tips: ChannelMap<Well> = (1..=self.channels).zip(wells).collect();
+        self.pick_up_tips_at(rack, &tips).await
+    }
+
+    pub async fn pick_up_tips_at(
+        &mut self,
+        rack: &TipRack,
+        tips: &ChannelMap<Well>,
+    ) -> Result<(), Error> {
+        if let Some(channel) = tips.channels().find(|c| self.tips.get(*c).is_some()) {
+            return Err(InvalidArgument(anyhow!(
+                "Channel {} already has a tip",
+                channel
+            )));
+        }
+        let n = self.channels;
+        self.pipette
+            .pickup_tips(
+                tips.tips_used(n)?,
+                tips.column(n, |w| w.x)?,
+                tips.column(n, |w| w.y)?,
+                self.traverse_height,
+                tips.column(n, |w| w.top)?,
+                tips.column(n, |w| w.bottom)?,
+                tips.column(n, |_| rack.tip_type)?,
+            )
+            .await?;
+        for channel in tips.channels() {
+            self.tips.insert(channel, rack.tip_type);
+        }
+        Ok(())
+    }
+
+    // Drops every tip the channels are holding
+    pub async fn drop_tips(&mut self, waste: &Waste) -> Result<(), Error> {
+        let n = self.channels;
+        let wells = match waste {
+            Waste::Default => self
+                .tips
+                .iter()
+                .map(|(c, _)| (c, Well::default()))
+                .collect(),
+            Waste::Wells(wells) => {
+                if let Some(channel) = self.tips.channels().find(|c| wells.get(*c).is_none()) {
+                    return Err(InvalidArgument(anyhow!(
+                        "Channel {} has a tip but nowhere to drop it",
+                        channel
+                    )));
+                }
+                self.tips
+                    .iter()
+                    .map(|(c, _)| (c, *wells.get(c).unwrap()))
+                    .collect::<ChannelMap<Well>>()
+            }
+        };
+        self.pipette
+            .drop_tips(
+                wells.tips_used(n)?,
+                wells.column(n, |w| w.x)?,
+                wells.column(n, |w| w.y)?,
+                self.traverse_height,
+                wells.column(n, |w| w.top)?,
+                wells.column(n, |w| w.bottom)?,
+                wells.column(n, |_| self.traverse_height)?,
+                matches!(waste, Waste::Default),
+            )
+            .await?;
+        self.tips = ChannelMap::new();
+        Ok(())
+    }
+
+    // Moves volume from one well to another with the first channel that has a tip
+    pub async fn transfer(
+        &mut self,
+        source: Well,
+        destination: Well,
+        volume: Microliters,
+        class: &LiquidClass,
+    ) -> Result<(), Error> {
+        let channel = self
+            .tips
+            .channels()
+            .next()
+            .ok_or_else(|| InvalidArgument(anyhow!("No channel has a tip")))?;
+        self.transfer_channels(
+            &ChannelMap::from([(channel, source)]),
+            &ChannelMap::from([(channel, destination)]),
+            volume,
+            class,
+        )
+        .await
+    }
+
+    // Moves volume from each channel's source to its destination, all channels at once
+    pub async fn transfer_channels(
+        &mut self,
+        sources: &ChannelMap<Well>,
+        destinations: &ChannelMap<Well>,
+        volume: Microliters,
+        class: &LiquidClass,
+    ) -> Result<(), Error> {
+        if !sources.channels().eq(destinations.channels()) {
+            return Err(InvalidArgument(anyhow!(
+                "Sources are for channels {:?} but destinations are for {:?}",
+                sources.channels().collect::<Vec<_>>(),
+                destinations.channels().collect::<Vec<_>>()
+            )));
+        }
+        if let Some(channel) = sources.channels().find(|c| self.tips.get(*c).is_none()) {
+            return Err(InvalidArgument(anyhow!("Channel {} has no tip", channel)));
+        }
+        self.pipette
+            .aspirate_1_with(&self.aspirate_request(sources, volume, class)?)
+            .await?;
+        self.pipette
+            .dispense_with(&self.dispense_request(destinations, volume, class)?)
+            .await
+    }
+
+    fn aspirate_request(
+        &self,
+        wells: &ChannelMap<Well>,
+        volume: Microliters,
+        class: &LiquidClass,
+    ) -> Result<Aspirate1Request, Error> {
+        let n = self.channels;
+        Ok(Aspirate1Request::default()
+            .aspirate_type(wells.column(n, |_| class.aspirate_type)?)
+            .tips_used(wells.tips_used(n)?)
+            .x_position(wells.column(n, |w| w.x)?)
+            .y_position(wells.column(n, |w| w.y)?)
+            .traverse_height(self.traverse_height)
+            .liquid_seek_height(wells.column(n, |w| w.top)?)
+            .liquid_surface_height(wells.column(n, |w| w.bottom + class.aspirate_height)?)
+            .submerge_depth(wells.column(n, |_| class.submerge_depth)?)
+            .follow_depth(wells.column(n, |_| Millimeters(0.0))?)
+            .z_min_position(wells.column(n, |w| w.bottom)?)
+            .clot_check_height(wells.column(n, |_| Millimeters(0.0))?)
+            .z_final(self.traverse_height)
+            .liquid_exit_speed(wells.column(n, |_| class.liquid_exit_speed)?)
+            .blowout_volume(wells.column(n, |_| class.blowout_volume)?)
+            .prewet_volume(wells.column(n, |_| Microliters(0.0))?)
+            .aspirate_volume(wells.column(n, |_| volume)?)
+            .transport_air_volume(wells.column(n, |_| class.transport_air_volume)?)
+            .aspirate_speed(wells.column(n, |_| class.aspirate_speed)?)
+            .settling_time(wells.column(n, |_| class.settling_time)?)
+            .mix_volume(wells.column(n, |_| Microliters(0.0))?)
+            .mix_cycles(wells.column(n, |_| 0)?)
+            .mix_position(wells.column(n, |_| Millimeters(0.0))?)
+            .mix_follow_distance(wells.column(n, |_| Millimeters(0.0))?)
+            .mix_speed(wells.column(n, |_| class.aspirate_speed)?)
+            .tube_section_height(wells.column(n, |_| Millimeters(0.0))?)
+            .tube_section_ratio(wells.column(n, |_| 0)?)
+            .lld_mode(wells.column(n, |_| class.lld_mode)?)
+            .capacitive_lld_sensitivity(wells.column(n, |_| class.capacitive_lld_sensitivity)?)
+            .pressure_lld_sensitivity(wells.column(n, |_| class.pressure_lld_sensitivity)?)
+            .lld_height_difference(wells.column(n, |_| 0)?)
+            .tadm_enabled(false)
+            .limit_curve_index(wells.column(n, |_| 0)?)
+            .recording_mode(0))
+    }
+
+    fn dispense_request(
+        &self,
+        wells: &ChannelMap<Well>,
+        volume: Microliters,
+        class: &LiquidClass,
+    ) -> Result<DispenseRequest, Error> {
+        let n = self.channels;
+        Ok(DispenseRequest::default()
+            .dispense_type(wells.column(n, |_| class.dispense_type)?)
+            .tips_used(wells.tips_used(n)?)
+            .x_position(wells.column(n, |w| w.x)?)
+            .y_position(wells.column(n, |w| w.y)?)
+            .traverse_height(self.traverse_height)
+            .liquid_seek_height(wells.column(n, |w| w.top)?)
+            .dispense_height(wells.column(n, |w| w.bottom + class.dispense_height)?)
+            .submerge_depth(wells.column(n, |_| class.submerge_depth)?)
+            .follow_depth(wells.column(n, |_| Millimeters(0.0))?)
+            .z_min_position(wells.column(n, |w| w.bottom)?)
+            .z_final(self.traverse_height)
+            .liquid_exit_speed(wells.column(n, |_| class.liquid_exit_speed)?)
+            .transport_air_volume(wells.column(n, |_| class.transport_air_volume)?)
+            .dispense_volume(wells.column(n, |_| volume)?)
+            .stop_back_volume(wells.column(n, |_| class.stop_back_volume)?)
+            .blowout_volume(wells.column(n, |_| class.blowout_volume)?)
+            .dispense_speed(wells.column(n, |_| class.dispense_speed)?)
+            .cutoff_speed(wells.column(n, |_| class.cutoff_speed)?)
+            .settling_time(wells.column(n, |_| class.settling_time)?)
+            .mix_volume(wells.column(n, |_| Microliters(0.0))?)
+            .mix_cycles(wells.column(n, |_| 0)?)
+            .mix_position(wells.column(n, |_| Millimeters(0.0))?)
+            .mix_follow_distance(wells.column(n, |_| Millimeters(0.0))?)
+            .mix_speed(wells.column(n, |_| class.dispense_speed)?)
+            .touch_off_distance(Millimeters(0.0))
+            .dispense_offset(wells.column(n, |_| 0)?)
+            .tube_section_height(wells.column(n, |_| Millimeters(0.0))?)
+            .tube_section_ratio(wells.column(n, |_| 0)?)
+            .lld_mode(wells.column(n, |_| class.lld_mode)?)
+            .capacitive_lld_sensitivity(wells.column(n, |_| class.capacitive_lld_sensitivity)?)
+            .tadm_enabled(false)
+            .limit_curve_index(wells.column(n, |_| 0)?)
+            .recording_mode(0))
+    }
+}
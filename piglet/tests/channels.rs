@@ -0,0 +1,52 @@
+// Per-channel settings turning into the arrays calls take
+use piglet::Error;
+use piglet::channels::ChannelMap;
+use piglet::units::Millimeters;
+
+#[test]
+fn channels_map_to_their_place_in_the_arrays() {
+    let heights = ChannelMap::from([(3, Millimeters(12.5)), (1, Millimeters(4.0))]);
+    assert_eq!(
+        heights.to_vec(4).unwrap(),
+        [
+            Millimeters(4.0),
+            Millimeters(0.0),
+            Millimeters(12.5),
+            Millimeters(0.0)
+        ]
+    );
+    assert_eq!(heights.tips_used(4).unwrap(), [1, 0, 1, 0]);
+    assert_eq!(
+        heights.column(4, |h| h.0 > 5.0).unwrap(),
+        [false, false, true, false]
+    );
+}
+
+#[test]
+fn channels_the_instrument_does_not_have_are_rejected() {
+    for channel in [0, 9] {
+        let map = ChannelMap::new().with(channel, 1u32);
+        let result = map.tips_used(8);
+        let Err(Error::InvalidArgument(e)) = result else {
+            panic!("{result:?}");
+        };
+        assert_eq!(
+            e.to_string(),
+            format!("Channel {channel} is out of range, channels go from 1 to 8")
+        );
+    }
+    assert!(ChannelMap::new().with(8, 1u32).validate(8).is_ok());
+}
+
+#[test]
+fn iteration_is_in_channel_order() {
+    let mut map: ChannelMap<&str> = [(5, "e"), (2, "b"), (7, "g")].into_iter().collect();
+    map.insert(1, "a");
+    assert_eq!(map.channels().collect::<Vec<_>>(), [1, 2, 5, 7]);
+    assert_eq!(
+        map.iter().collect::<Vec<_>>(),
+        [(1, &"a"), (2, &"b"), (5, &"e"), (7, &"g")]
+    );
+    assert_eq!(map.remove(5), Some("e"));
+    assert_eq!(map.len(), 3);
+}
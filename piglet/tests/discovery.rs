@@ -0,0 +1,49 @@
+// Scanning the loopback interface, where a responder stands in for an instrument
+use piglet::discovery::{self, Instrument};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+#[tokio::test]
+async fn scan_finds_the_responder() {
+    let responder = discovery::responder("NIMBUS", "1234", "nimbus-1", 34569)
+        .await
+        .unwrap();
+    let port = responder.address().port();
+
+    let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    // Nothing answers on the other address, which only costs the timeout
+    let hosts = [localhost, IpAddr::V4(Ipv4Addr::new(127, 0, 0, 2))];
+    let instruments = discovery::scan(hosts, port, Duration::from_secs(2)).await;
+    assert_eq!(
+        instruments,
+        [Instrument {
+            address: SocketAddr::new(localhost, port),
+            instrument_type: "NIMBUS".to_string(),
+            instrument_id: "1234".to_string(),
+            host_name: "nimbus-1".to_string(),
+            udp_port: 34569,
+        }]
+    );
+
+    responder.close().await.unwrap();
+}
+
+// There may be no network to find, but if there is the subnet leaves out this machine
+#[test]
+fn local_subnet_is_the_rest_of_the_24() {
+    let Ok(hosts) = discovery::local_subnet() else {
+        return;
+    };
+    assert_eq!(hosts.len(), 253);
+    let prefixes: Vec<[u8; 3]> = hosts
+        .iter()
+        .map(|host| match host {
+            IpAddr::V4(ip) => {
+                let [a, b, c, _] = ip.octets();
+                [a, b, c]
+            }
+            IpAddr::V6(_) => panic!("{host}"),
+        })
+        .collect();
+    assert!(prefixes.iter().all(|p| *p == prefixes[0]));
+}
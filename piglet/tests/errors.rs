@@ -0,0 +1,131 @@
+// Naming the codes objects report, using the names and paths from the simulator's tree
+use bytes::Bytes;
+use piglet::errors::{self, Catalog, ErrorKind};
+use piglet::object_tree::ObjectTree;
+use piglet::testing::{SimulatedObject, Simulator};
+use piglet::values::ErrorCode;
+use piglet::{Error, ObjectAddress, RobotClient};
+
+fn address(object_id: u16) -> ObjectAddress {
+    ObjectAddress {
+        module_id: 1,
+        node_id: 1,
+        object_id,
+    }
+}
+
+fn named(name: &str) -> SimulatedObject {
+    SimulatedObject {
+        name: name.to_string(),
+        ..Default::default()
+    }
+}
+
+const NO_TIP: ErrorCode = ErrorCode(0x4b);
+
+// Three channels under NimbusCore, so they're Channel[1] to Channel[3], and a head with a single
+// Channel. Every object fails call 1:1 with NO_TIP.
+async fn robot() -> (Simulator, RobotClient) {
+    let simulator = Simulator::builder()
+        .root(
+            48896,
+            SimulatedObject {
+                subobjects: vec![address(257), address(258), address(259), address(260)],
+                ..named("NimbusCore")
+            },
+        )
+        .object(&address(257), named("Channel"))
+        .object(&address(258), named("Channel"))
+        .object(&address(259), named("Channel"))
+        .object(
+            &address(260),
+            SimulatedObject {
+                subobjects: vec![address(261)],
+                ..named("Head")
+            },
+        )
+        .object(&address(261), named("Channel"))
+        .on(&address(259), 1, 1, |_| Err(NO_TIP))
+        .on(&address(260), 1, 1, |_| Err(NO_TIP))
+        .on(&address(261), 1, 1, |_| Err(NO_TIP))
+        .start()
+        .await
+        .unwrap();
+    let robot = RobotClient::connect(simulator.address()).await.unwrap();
+    (simulator, robot)
+}
+
+async fn fail(robot: &RobotClient, object_id: u16) -> Error {
+    robot
+        .act(&address(object_id), 1, 3, 1, Bytes::new())
+        .await
+        .unwrap_err()
+}
+
+#[tokio::test]
+async fn codes_are_named_for_the_objects_reporting_them() {
+    let (simulator, robot) = robot().await;
+    let catalog = Catalog::new().with("channel", NO_TIP, ErrorKind::NoTip);
+    let tree = robot.object_tree().await;
+
+    let error = fail(&robot, 259).await;
+    assert!(catalog.has(tree, &error, ErrorKind::NoTip));
+    let kinds = catalog.kinds(tree, &error);
+    assert_eq!(kinds.len(), 1);
+    assert_eq!(kinds[0].1, ErrorKind::NoTip);
+    assert_eq!(errors::channel(tree, kinds[0].0), Some(3));
+
+    // The only channel on the head has no index
+    let error = fail(&robot, 261).await;
+    let kinds = catalog.kinds(tree, &error);
+    assert_eq!(kinds[0].1, ErrorKind::NoTip);
+    assert_eq!(errors::channel(tree, kinds[0].0), Some(1));
+
+    // The same code from something that isn't a channel isn't in the catalog
+    let error = fail(&robot, 260).await;
+    let kinds = catalog.kinds(tree, &error);
+    assert_eq!(kinds[0].1, ErrorKind::Unknown(NO_TIP));
+    assert_eq!(errors::channel(tree, kinds[0].0), None);
+    assert!(!catalog.has(tree, &error, ErrorKind::NoTip));
+
+    robot.close().await.unwrap();
+    simulator.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn objects_missing_from_the_tree_are_unknown() {
+    let (simulator, robot) = robot().await;
+    let catalog = Catalog::new().with("Channel", NO_TIP, ErrorKind::NoTip);
+    let error = fail(&robot, 259).await;
+
+    // A tree from another robot doesn't have the channel
+    let tree = ObjectTree { roots: Vec::new() };
+    let kinds = catalog.kinds(&tree, &error);
+    assert_eq!(kinds[0].1, ErrorKind::Unknown(NO_TIP));
+    assert_eq!(errors::channel(&tree, kinds[0].0), None);
+
+    robot.close().await.unwrap();
+    simulator.close().await.unwrap();
+}
+
+#[test]
+fn codes_only_mean_something_on_their_own_objects() {
+    let catalog = Catalog::new()
+        .with("Channel", ErrorCode(1), ErrorKind::Clot)
+        .with("Pipette", ErrorCode(1), ErrorKind::NoTip);
+    assert_eq!(catalog.lookup("Channel", ErrorCode(1)), ErrorKind::Clot);
+    assert_eq!(catalog.lookup("pipette", ErrorCode(1)), ErrorKind::NoTip);
+    assert_eq!(
+        catalog.lookup("Channel", ErrorCode(2)),
+        ErrorKind::Unknown(ErrorCode(2))
+    );
+    assert_eq!(
+        Catalog::new().lookup("Channel", ErrorCode(1)),
+        ErrorKind::Unknown(ErrorCode(1))
+    );
+    assert_eq!(ErrorKind::DoorOpen.to_string(), "The door is open");
+    assert_eq!(
+        ErrorKind::Unknown(ErrorCode(7)).to_string(),
+        "Unknown error code 7"
+    );
+}
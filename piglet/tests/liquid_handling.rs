@@ -0,0 +1,353 @@
+// Wells, and the per-channel coordinates the liquid handler sends for them
+use bytes::{Bytes, BytesMut};
+use piglet::channels::ChannelMap;
+use piglet::liquid_handling::{Labware, LiquidClass, LiquidHandler, TipRack, Waste, Well};
+use piglet::testing::{SimulatedObject, Simulator};
+use piglet::units::{Microliters, Millimeters};
+use piglet::values::{PigletDeserialize, PigletSerialize};
+use piglet::{Error, ObjectAddress, RobotClient};
+use std::sync::Arc;
+
+fn plate() -> Labware {
+    Labware::grid_96(
+        Millimeters(200.0),
+        Millimeters(-100.0),
+        Millimeters(120.0),
+        Millimeters(105.0),
+    )
+}
+
+#[test]
+fn wells_are_found_by_name() {
+    let plate = plate();
+    assert_eq!(
+        plate.well("A1").unwrap(),
+        Well {
+            x: Millimeters(200.0),
+            y: Millimeters(-100.0),
+            top: Millimeters(120.0),
+            bottom: Millimeters(105.0),
+        }
+    );
+    // Columns step along x and rows step towards the front along -y
+    let h12 = plate.well("H12").unwrap();
+    assert_eq!((h12.x, h12.y), (Millimeters(299.0), Millimeters(-163.0)));
+    assert_eq!(plate.well("C5").unwrap(), plate.well_at(3, 5).unwrap());
+
+    for name in ["", "A", "a1", "1A", "A1x", "I1", "A0", "A13"] {
+        let result = plate.well(name);
+        assert!(
+            matches!(result, Err(Error::InvalidArgument(_))),
+            "{name:?}: {result:?}"
+        );
+    }
+}
+
+#[test]
+fn columns_put_channel_1_on_row_a() {
+    let column = plate().column(2).unwrap();
+    assert_eq!(
+        column.channels().collect::<Vec<_>>(),
+        (1..=8).collect::<Vec<_>>()
+    );
+    let ys: Vec<Millimeters> = column.iter().map(|(_, w)| w.y).collect();
+    assert_eq!(ys[0], Millimeters(-100.0));
+    assert_eq!(ys[7], Millimeters(-163.0));
+    assert!(column.iter().all(|(_, w)| w.x == Millimeters(209.0)));
+    assert!(plate().column(13).is_err());
+}
+
+const NIMBUS_CORE: ObjectAddress = ObjectAddress {
+    module_id: 1,
+    node_id: 1,
+    object_id: 48896,
+};
+
+const PIPETTE: ObjectAddress = ObjectAddress {
+    module_id: 1,
+    node_id: 1,
+    object_id: 257,
+};
+
+// A four channel instrument whose pipette accepts pickup_tips, drop_tips, aspirate_1 and dispense
+async fn simulator() -> Simulator {
+    let mut builder = Simulator::builder()
+        .root(
+            NIMBUS_CORE.object_id,
+            SimulatedObject {
+                name: "NimbusCore".to_string(),
+                ..Default::default()
+            },
+        )
+        .root(
+            PIPETTE.object_id,
+            SimulatedObject {
+                name: "Pipette".to_string(),
+                ..Default::default()
+            },
+        )
+        // get_channel_configuration_1
+        .on(&NIMBUS_CORE, 1, 15, |_| {
+            let mut values = BytesMut::new();
+            4u16.serialize(&mut values);
+            vec![0i16; 4].serialize(&mut values);
+            Ok((2, values.freeze()))
+        });
+    for call_type_id in [PICKUP_TIPS, DROP_TIPS, ASPIRATE, DISPENSE] {
+        builder = builder.on(&PIPETTE, 1, call_type_id, |_| Ok((0, Bytes::new())));
+    }
+    builder.start().await.unwrap()
+}
+
+const PICKUP_TIPS: u16 = 4;
+const DROP_TIPS: u16 = 5;
+const ASPIRATE: u16 = 6;
+const DISPENSE: u16 = 7;
+
+// What the pipette was last sent for a call
+fn parameters(simulator: &Simulator, call_type_id: u16) -> Bytes {
+    simulator
+        .calls()
+        .into_iter()
+        .filter(|c| c.destination == PIPETTE && c.interface_id == 1)
+        .filter(|c| c.call_type_id == call_type_id)
+        .last()
+        .unwrap()
+        .parameters
+}
+
+#[tokio::test]
+async fn tips_are_picked_up_down_each_column_in_turn() {
+    let simulator = simulator().await;
+    let robot = Arc::new(RobotClient::connect(simulator.address()).await.unwrap());
+    let mut handler = LiquidHandler::new(&robot, Millimeters(186.0))
+        .await
+        .unwrap();
+    assert_eq!(handler.channel_count(), 4);
+
+    // Three rows, so the fourth channel's tip comes from the top of the next column
+    let rack = TipRack {
+        labware: Labware { rows: 3, ..plate() },
+        tip_type: 4,
+    };
+    handler.pick_up_tips(&rack, &[5, 6]).await.unwrap();
+    assert_eq!(
+        handler.tips().iter().collect::<Vec<_>>(),
+        [(1, &4), (2, &4), (3, &4), (4, &4)]
+    );
+
+    let mut parameters = parameters(&simulator, PICKUP_TIPS);
+    assert_eq!(
+        Vec::<u16>::deserialize(&mut parameters).unwrap(),
+        [1, 1, 1, 1]
+    );
+    // In 0.01 mm
+    assert_eq!(
+        Vec::<i32>::deserialize(&mut parameters).unwrap(),
+        [23600, 23600, 23600, 24500]
+    );
+    assert_eq!(
+        Vec::<i32>::deserialize(&mut parameters).unwrap(),
+        [-10000, -10900, -11800, -10000]
+    );
+    assert_eq!(i32::deserialize(&mut parameters).unwrap(), 18600);
+    assert_eq!(
+        Vec::<i32>::deserialize(&mut parameters).unwrap(),
+        [12000; 4]
+    );
+    assert_eq!(
+        Vec::<i32>::deserialize(&mut parameters).unwrap(),
+        [10500; 4]
+    );
+    assert_eq!(Vec::<u16>::deserialize(&mut parameters).unwrap(), [4; 4]);
+
+    // Every channel has a tip now
+    let again = handler.pick_up_tips(&rack, &[7]).await;
+    assert!(matches!(again, Err(Error::InvalidArgument(_))), "{again:?}");
+
+    simulator.close().await.unwrap();
+}
+
+fn i32s(parameters: &mut Bytes) -> Vec<i32> {
+    Vec::<i32>::deserialize(parameters).unwrap()
+}
+
+fn u32s(parameters: &mut Bytes) -> Vec<u32> {
+    Vec::<u32>::deserialize(parameters).unwrap()
+}
+
+#[tokio::test]
+async fn transfers_send_wire_units_for_the_channel_with_a_tip() {
+    let simulator = simulator().await;
+    let robot = Arc::new(RobotClient::connect(simulator.address()).await.unwrap());
+    let mut handler = LiquidHandler::new(&robot, Millimeters(186.0))
+        .await
+        .unwrap();
+    let rack = TipRack {
+        labware: plate(),
+        tip_type: 4,
+    };
+    let no_tip = handler
+        .transfer(
+            plate().well("A1").unwrap(),
+            plate().well("B2").unwrap(),
+            Microliters(50.5),
+            &LiquidClass::water(),
+        )
+        .await;
+    assert!(
+        matches!(no_tip, Err(Error::InvalidArgument(_))),
+        "{no_tip:?}"
+    );
+
+    handler
+        .pick_up_tips_at(&rack, &ChannelMap::from([(2, plate().well("A1").unwrap())]))
+        .await
+        .unwrap();
+    handler
+        .transfer(
+            plate().well("A1").unwrap(),
+            plate().well("B2").unwrap(),
+            Microliters(50.5),
+            &LiquidClass::water(),
+        )
+        .await
+        .unwrap();
+
+    // Only channel 2 has a tip, so only its place in each array is filled in, in 0.01 mm, 0.1 µL,
+    // 0.01 mm/s and 0.1 µL/s
+    let mut aspirate = parameters(&simulator, ASPIRATE);
+    assert_eq!(Vec::<i16>::deserialize(&mut aspirate).unwrap(), [0; 4]);
+    assert_eq!(
+        Vec::<u16>::deserialize(&mut aspirate).unwrap(),
+        [0, 1, 0, 0]
+    );
+    assert_eq!(i32s(&mut aspirate), [0, 20000, 0, 0]);
+    assert_eq!(i32s(&mut aspirate), [0, -10000, 0, 0]);
+    assert_eq!(i32::deserialize(&mut aspirate).unwrap(), 18600);
+    // Liquid seek at the top, the surface 1 mm above the bottom and 2 mm submerged
+    assert_eq!(i32s(&mut aspirate), [0, 12000, 0, 0]);
+    assert_eq!(i32s(&mut aspirate), [0, 10600, 0, 0]);
+    assert_eq!(i32s(&mut aspirate), [0, 200, 0, 0]);
+    assert_eq!(i32s(&mut aspirate), [0; 4]);
+    assert_eq!(i32s(&mut aspirate), [0, 10500, 0, 0]);
+    assert_eq!(i32s(&mut aspirate), [0; 4]);
+    assert_eq!(i32::deserialize(&mut aspirate).unwrap(), 18600);
+    // Liquid exit speed, blowout, prewet, the volume itself, transport air and speed
+    assert_eq!(u32s(&mut aspirate), [0, 1000, 0, 0]);
+    assert_eq!(u32s(&mut aspirate), [0, 100, 0, 0]);
+    assert_eq!(u32s(&mut aspirate), [0; 4]);
+    assert_eq!(u32s(&mut aspirate), [0, 505, 0, 0]);
+    assert_eq!(u32s(&mut aspirate), [0, 50, 0, 0]);
+    assert_eq!(u32s(&mut aspirate), [0, 1000, 0, 0]);
+    assert_eq!(u32s(&mut aspirate), [0, 5, 0, 0]);
+
+    let mut dispense = parameters(&simulator, DISPENSE);
+    assert_eq!(Vec::<i16>::deserialize(&mut dispense).unwrap(), [0; 4]);
+    assert_eq!(
+        Vec::<u16>::deserialize(&mut dispense).unwrap(),
+        [0, 1, 0, 0]
+    );
+    assert_eq!(i32s(&mut dispense), [0, 20900, 0, 0]);
+    assert_eq!(i32s(&mut dispense), [0, -10900, 0, 0]);
+    assert_eq!(i32::deserialize(&mut dispense).unwrap(), 18600);
+    // Dispensing 2 mm above the bottom
+    assert_eq!(i32s(&mut dispense), [0, 12000, 0, 0]);
+    assert_eq!(i32s(&mut dispense), [0, 10700, 0, 0]);
+    assert_eq!(i32s(&mut dispense), [0, 200, 0, 0]);
+    assert_eq!(i32s(&mut dispense), [0; 4]);
+    assert_eq!(i32s(&mut dispense), [0, 10500, 0, 0]);
+    assert_eq!(i32::deserialize(&mut dispense).unwrap(), 18600);
+    // Liquid exit speed, transport air, the volume, stop back, blowout, speed and cutoff speed
+    assert_eq!(u32s(&mut dispense), [0, 1000, 0, 0]);
+    assert_eq!(u32s(&mut dispense), [0, 50, 0, 0]);
+    assert_eq!(u32s(&mut dispense), [0, 505, 0, 0]);
+    assert_eq!(u32s(&mut dispense), [0; 4]);
+    assert_eq!(u32s(&mut dispense), [0, 100, 0, 0]);
+    assert_eq!(u32s(&mut dispense), [0, 1000, 0, 0]);
+    assert_eq!(u32s(&mut dispense), [0, 250, 0, 0]);
+
+    // Channels have to line up, and every one needs a tip
+    let a1 = ChannelMap::from([(2, plate().well("A1").unwrap())]);
+    let mismatched = handler
+        .transfer_channels(
+            &a1,
+            &ChannelMap::from([(3, plate().well("A2").unwrap())]),
+            Microliters(10.0),
+            &LiquidClass::water(),
+        )
+        .await;
+    assert!(
+        matches!(mismatched, Err(Error::InvalidArgument(_))),
+        "{mismatched:?}"
+    );
+    let without_tip = ChannelMap::from([(1, plate().well("A1").unwrap())]);
+    let result = handler
+        .transfer_channels(
+            &without_tip,
+            &without_tip,
+            Microliters(10.0),
+            &LiquidClass::water(),
+        )
+        .await;
+    let Err(Error::InvalidArgument(e)) = result else {
+        panic!("{result:?}");
+    };
+    assert_eq!(e.to_string(), "Channel 1 has no tip");
+
+    simulator.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn tips_are_dropped_from_every_channel_holding_one() {
+    let simulator = simulator().await;
+    let robot = Arc::new(RobotClient::connect(simulator.address()).await.unwrap());
+    let mut handler = LiquidHandler::new(&robot, Millimeters(186.0))
+        .await
+        .unwrap();
+    let rack = TipRack {
+        labware: plate(),
+        tip_type: 4,
+    };
+    handler.pick_up_tips(&rack, &[1]).await.unwrap();
+
+    // Somewhere to drop is needed for every channel with a tip
+    let waste = Waste::Wells(ChannelMap::from([(1, plate().well("A12").unwrap())]));
+    let result = handler.drop_tips(&waste).await;
+    assert!(
+        matches!(result, Err(Error::InvalidArgument(_))),
+        "{result:?}"
+    );
+    assert_eq!(handler.tips().len(), 4);
+
+    let waste = Waste::Wells(plate().column(12).unwrap());
+    handler.drop_tips(&waste).await.unwrap();
+    assert_eq!(handler.tips().len(), 0);
+
+    let mut dropped = parameters(&simulator, DROP_TIPS);
+    assert_eq!(Vec::<u16>::deserialize(&mut dropped).unwrap(), [1, 1, 1, 1]);
+    assert_eq!(i32s(&mut dropped), [29900; 4]);
+    assert_eq!(i32s(&mut dropped), [-10000, -10900, -11800, -12700]);
+    assert_eq!(i32::deserialize(&mut dropped).unwrap(), 18600);
+    assert_eq!(i32s(&mut dropped), [12000; 4]);
+    assert_eq!(i32s(&mut dropped), [10500; 4]);
+    assert_eq!(i32s(&mut dropped), [18600; 4]);
+    assert!(!bool::deserialize(&mut dropped).unwrap());
+
+    // The instrument's own waste doesn't need coordinates
+    handler.pick_up_tips(&rack, &[2]).await.unwrap();
+    handler.drop_tips(&Waste::Default).await.unwrap();
+    let mut dropped = parameters(&simulator, DROP_TIPS);
+    assert_eq!(Vec::<u16>::deserialize(&mut dropped).unwrap(), [1, 1, 1, 1]);
+    for _ in 0..2 {
+        assert_eq!(i32s(&mut dropped), [0; 4]);
+    }
+    assert_eq!(i32::deserialize(&mut dropped).unwrap(), 18600);
+    for _ in 0..2 {
+        assert_eq!(i32s(&mut dropped), [0; 4]);
+    }
+    assert_eq!(i32s(&mut dropped), [18600; 4]);
+    assert!(bool::deserialize(&mut dropped).unwrap());
+
+    simulator.close().await.unwrap();
+}
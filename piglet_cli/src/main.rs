@@ -0,0 +1,228 @@
+use anyhow::anyhow;
+use piglet_client::{
+    client::RobotClient,
+    dynamic_object::{DynamicObject, Method, method_names},
+    object_address::ObjectAddress,
+    values::{Value, type_name},
+};
+use serde_json::{Map, Value as Json};
+use std::env;
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() -> Result<(), anyhow::Error> {
+    let args: Vec<String> = env::args().collect();
+    let usage = || {
+        anyhow!(
+            "Usage: {0} <ip> tree\n       {0} <ip> describe <path>\n       {0} <ip> call <path> <method> [arguments...]",
+            args[0]
+        )
+    };
+    if args.len() < 3 {
+        return Err(usage());
+    }
+
+    let robot = Arc::new(
+        RobotClient::connect(&args[1])
+            .await
+            .map_err(|e| anyhow!("Error connecting to robot: {}", e))?,
+    );
+    let result = match (args[2].as_str(), &args[3..]) {
+        ("tree", []) => {
+            tree(&robot).await;
+            Ok(())
+        }
+        ("describe", [path]) => describe(&robot, path).await,
+        ("call", [path, method, arguments @ ..]) => call(&robot, path, method, arguments).await,
+        _ => Err(usage()),
+    };
+    if let Ok(robot) = Arc::try_unwrap(robot) {
+        robot.close().await?;
+    }
+    result
+}
+
+async fn tree(robot: &RobotClient) {
+    for node in robot.object_tree().await.iter() {
+        println!(
+            "{}{} {} (version {})",
+            "  ".repeat(node.path.matches('.').count()),
+            node.object.name,
+            node.object.address,
+            node.object.version
+        );
+    }
+}
+
+async fn describe(robot: &Arc<RobotClient>, path: &str) -> Result<(), anyhow::Error> {
+    let dynamic = DynamicObject::new(&resolve(robot, path).await?, robot);
+    let object = dynamic.get_object().await?;
+    println!(
+        "{} {} (version {})",
+        object.name, object.address, object.version
+    );
+
+    let methods = dynamic.methods().await?;
+    // Numbered where they share a name, the way DynamicObject::call expects
+    let names = method_names(methods.iter().map(|m| m.name.as_str()));
+    for (name, method) in names.iter().zip(methods) {
+        println!("  {}", signature(name, method)?);
+    }
+    Ok(())
+}
+
+fn signature(name: &str, method: &Method) -> Result<String, anyhow::Error> {
+    let (arguments, returns): (Vec<_>, Vec<_>) = method
+        .parameters()?
+        .into_iter()
+        .map(|p| {
+            (
+                format!("{}: {}", p.label, type_name(p.type_id)),
+                p.is_argument,
+            )
+        })
+        .partition(|(_, is_argument)| *is_argument);
+    let list = |parameters: Vec<(String, bool)>| {
+        parameters
+            .into_iter()
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    Ok(if returns.is_empty() {
+        format!("{}({})", name, list(arguments))
+    } else {
+        format!("{}({}) -> ({})", name, list(arguments), list(returns))
+    })
+}
+
+async fn call(
+    robot: &Arc<RobotClient>,
+    path: &str,
+    method_name: &str,
+    arguments: &[String],
+) -> Result<(), anyhow::Error> {
+    let dynamic = DynamicObject::new(&resolve(robot, path).await?, robot);
+    let method = dynamic.find_method(method_name).await?;
+    let parameters = method.parameters()?;
+    let (expected, returns): (Vec<_>, Vec<_>) = parameters.iter().partition(|p| p.is_argument);
+    if arguments.len() != expected.len() {
+        anyhow::bail!(
+            "{} takes {} arguments, not {}\n  {}",
+            method.name,
+            expected.len(),
+            arguments.len(),
+            signature(method_name, method)?
+        );
+    }
+
+    let mut values = Vec::new();
+    for (argument, parameter) in arguments.iter().zip(&expected) {
+        // Anything that isn't valid JSON is taken as a string, so names don't need quoting
+        let json = serde_json::from_str(argument).unwrap_or_else(|_| Json::from(argument.clone()));
+        let value = from_json(&json, parameter.type_id).ok_or_else(|| {
+            anyhow!(
+                "{} should be {}, not {}",
+                parameter.label,
+                type_name(parameter.type_id),
+                argument
+            )
+        })?;
+        values.push(value);
+    }
+
+    let results = dynamic.call_method(method, values).await?;
+    let mut reply = Map::new();
+    for (parameter, value) in returns.iter().zip(results) {
+        reply.insert(parameter.label.clone(), to_json(value));
+    }
+    println!("{}", serde_json::to_string_pretty(&reply)?);
+    Ok(())
+}
+
+// Finds an object either by its address, like 1-1-257, or by the names leading to it from a root
+// object, like NimbusCore.Pipette
+async fn resolve(robot: &RobotClient, path: &str) -> Result<ObjectAddress, anyhow::Error> {
+    if let [module_id, node_id, object_id] = path.split('-').collect::<Vec<_>>()[..]
+        && let (Ok(module_id), Ok(node_id), Ok(object_id)) =
+            (module_id.parse(), node_id.parse(), object_id.parse())
+    {
+        return Ok(ObjectAddress {
+            module_id,
+            node_id,
+            object_id,
+        });
+    }
+    Ok(robot.resolve(path).await?)
+}
+
+fn from_json(json: &Json, type_id: u8) -> Option<Value> {
+    Some(match type_id {
+        1 => Value::I8(json.as_i64()?.try_into().ok()?),
+        2 => Value::I16(json.as_i64()?.try_into().ok()?),
+        3 => Value::I32(json.as_i64()?.try_into().ok()?),
+        4 => Value::U8(json.as_i64()?.try_into().ok()?),
+        5 => Value::U16(json.as_i64()?.try_into().ok()?),
+        6 => Value::U32(json.as_i64()?.try_into().ok()?),
+        15 => Value::String(json.as_str()?.to_string()),
+        22 => Value::U8Array(integers(json)?),
+        23 => Value::Bool(json.as_bool()?),
+        25 => Value::I16Array(integers(json)?),
+        26 => Value::U16Array(integers(json)?),
+        27 => Value::I32Array(integers(json)?),
+        28 => Value::U32Array(integers(json)?),
+        29 => Value::BoolArray(
+            json.as_array()?
+                .iter()
+                .map(Json::as_bool)
+                .collect::<Option<_>>()?,
+        ),
+        32 => Value::Enum(json.as_i64()?.try_into().ok()?),
+        34 => Value::StringArray(
+            json.as_array()?
+                .iter()
+                .map(|s| Some(s.as_str()?.to_string()))
+                .collect::<Option<_>>()?,
+        ),
+        35 => Value::EnumArray(integers(json)?),
+        40 => Value::F32(json.as_f64()? as f32),
+        // Structs would need their field types from get_structs
+        _ => return None,
+    })
+}
+
+fn integers<T: TryFrom<i64>>(json: &Json) -> Option<Vec<T>> {
+    json.as_array()?
+        .iter()
+        .map(|v| v.as_i64()?.try_into().ok())
+        .collect()
+}
+
+fn to_json(value: Value) -> Json {
+    match value {
+        Value::I8(v) => v.into(),
+        Value::I16(v) => v.into(),
+        Value::I32(v) => v.into(),
+        Value::U8(v) => v.into(),
+        Value::U16(v) => v.into(),
+        Value::U32(v) => v.into(),
+        Value::F32(v) => v.into(),
+        Value::Bool(v) => v.into(),
+        Value::String(v) => v.into(),
+        Value::U8Array(v) => v.into(),
+        Value::I16Array(v) => v.into(),
+        Value::U16Array(v) => v.into(),
+        Value::I32Array(v) => v.into(),
+        Value::U32Array(v) => v.into(),
+        Value::BoolArray(v) => v.into(),
+        Value::StringArray(v) => v.into(),
+        Value::Enum(v) => v.into(),
+        Value::EnumArray(v) => v.into(),
+        Value::Struct(fields) => fields.into_iter().map(to_json).collect(),
+        Value::StructArray(items) => items
+            .into_iter()
+            .map(|fields| fields.into_iter().map(to_json).collect::<Json>())
+            .collect(),
+        Value::ErrorCode(v) => v.0.into(),
+    }
+}
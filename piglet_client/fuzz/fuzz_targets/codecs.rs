@@ -0,0 +1,30 @@
+#![no_main]
+
+use bytes::Bytes;
+use libfuzzer_sys::fuzz_target;
+use piglet_client::values::{ErrorCode, NetworkResult, PigletDeserialize};
+
+fn decode<T: PigletDeserialize>(data: &[u8]) {
+    let _ = T::deserialize(&mut Bytes::copy_from_slice(data));
+}
+
+fuzz_target!(|data: &[u8]| {
+    decode::<i8>(data);
+    decode::<i16>(data);
+    decode::<i32>(data);
+    decode::<u8>(data);
+    decode::<u16>(data);
+    decode::<u32>(data);
+    decode::<f32>(data);
+    decode::<bool>(data);
+    decode::<String>(data);
+    decode::<Vec<u8>>(data);
+    decode::<Vec<i16>>(data);
+    decode::<Vec<u16>>(data);
+    decode::<Vec<i32>>(data);
+    decode::<Vec<u32>>(data);
+    decode::<Vec<bool>>(data);
+    decode::<Vec<String>>(data);
+    decode::<ErrorCode>(data);
+    decode::<NetworkResult>(data);
+});
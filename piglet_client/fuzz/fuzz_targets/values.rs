@@ -0,0 +1,11 @@
+#![no_main]
+
+use bytes::Bytes;
+use libfuzzer_sys::fuzz_target;
+use piglet_client::values::Value;
+
+// A reply's values one after another, the way DynamicObject reads them
+fuzz_target!(|data: &[u8]| {
+    let mut stream = Bytes::copy_from_slice(data);
+    while let Ok(_) = Value::deserialize(&mut stream) {}
+});
@@ -1,65 +1,211 @@
-use crate::client::Error::{CallError, ConnectionError};
-use crate::connection::{Connection, ConnectionDetails, connect};
+use crate::client::Error::{
+    AddressNotFound, CallError, ConnectionError, InvalidArgument, Mismatch, ProtocolError,
+    TimeoutError, TransportError,
+};
+use crate::connection::{Connection, ConnectionDetails, Endpoint, replay};
+use crate::dynamic_object::Method;
+use crate::events::{self, Event, Events, PigletEvent, Subscribers, Subscription};
 use crate::object_address::ObjectAddress;
+use crate::object_tree::ObjectTree;
 use crate::values::{ErrorCode, PigletDeserialize};
 use anyhow::{anyhow, bail};
-use bytes::{Buf, BufMut, Bytes, BytesMut};
+use bytes::{Buf, BufMut, Bytes, BytesMut, TryGetError};
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::{
     net::ToSocketAddrs,
-    sync::{mpsc, oneshot},
+    sync::{OnceCell, mpsc, oneshot},
+    task::JoinHandle,
 };
 
+// Generous enough for most motions; long ones like initialization should pass their own timeout
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
+// How long a late reply can still show up after its call gave up waiting. Until then the request
+// ID isn't reused, unless every other one is taken.
+const ABANDONED_GRACE: Duration = Duration::from_secs(600);
+
+// How long a single call waits for its reply
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Timeout {
+    // The client's, see RobotClient::with_default_timeout
+    #[default]
+    Default,
+    After(Duration),
+    Never,
+}
+
 pub struct RobotClient {
-    channels: Arc<Mutex<HashMap<ObjectAddress, Channel>>>,
-    client_address: ObjectAddress,
-    connection: Connection,
+    inner: Arc<Inner>,
     pub globals: Vec<ObjectAddress>,
     pub objects: Vec<ObjectAddress>,
+    // Walked the first time something asks for it
+    tree: OnceCell<ObjectTree>,
+    // Each object's methods, kept whenever something like DynamicObject or verify reads them so
+    // errors can name methods without asking the robot
+    methods: Mutex<HashMap<ObjectAddress, Arc<Vec<Method>>>>,
     stop_tx: oneshot::Sender<()>,
-    task: tokio::task::JoinHandle<()>,
+    task: JoinHandle<Result<(), anyhow::Error>>,
+    timeout: Option<Duration>,
+}
+
+// How hard to try getting a connection back after it drops. Every attempt gets a new client ID
+// and registers again; calls that were waiting when the connection dropped fail rather than
+// being resent.
+#[derive(Clone, Debug)]
+pub struct ReconnectPolicy {
+    // None keeps trying forever
+    pub attempts: Option<u32>,
+    pub delay: Duration,
+    // The delay doubles after every failed attempt up to this
+    pub max_delay: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            attempts: None,
+            delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+// Everything the client shares with the task watching over its connection
+struct Inner {
+    channels: Mutex<HashMap<ObjectAddress, Channel>>,
+    // None when the client can't reconnect, like when it's replaying a recording
+    endpoint: Option<Endpoint>,
+    reconnect: Mutex<Option<ReconnectPolicy>>,
+    // None while the client is disconnected
+    session: Mutex<Option<Session>>,
+    // Why the client stopped reconnecting, for the errors calls get afterwards
+    gave_up: Mutex<Option<String>>,
+    subscribers: Subscribers,
+}
+
+struct Session {
+    client_address: ObjectAddress,
+    connection: Connection,
 }
 
 impl RobotClient {
     pub async fn connect<T: ToSocketAddrs>(address: T) -> Result<RobotClient, anyhow::Error> {
-        let ConnectionDetails {
-            connection,
-            client_id,
-        } = connect(&address, 48).await?;
-        let receiver = connection.register_protocol(6);
+        let endpoint = Endpoint::resolve(&address, 48, None).await?;
+        Self::start(endpoint.connect().await?, Some(endpoint)).await
+    }
+
+    // Like connect, but writes every frame exchanged with the robot to the file at path
+    pub async fn connect_recording<T: ToSocketAddrs, P: AsRef<Path>>(
+        address: T,
+        path: P,
+    ) -> Result<RobotClient, anyhow::Error> {
+        let endpoint = Endpoint::resolve(&address, 48, Some(path.as_ref())).await?;
+        Self::start(endpoint.connect().await?, Some(endpoint)).await
+    }
+
+    // Plays back a file written by connect_recording in place of a robot
+    pub async fn replay<P: AsRef<Path>>(path: P) -> Result<RobotClient, anyhow::Error> {
+        Self::start(replay(path.as_ref(), 48).await?, None).await
+    }
+
+    async fn start(
+        details: ConnectionDetails,
+        endpoint: Option<Endpoint>,
+    ) -> Result<RobotClient, anyhow::Error> {
+        let inner = Arc::new(Inner {
+            channels: Mutex::new(HashMap::new()),
+            endpoint,
+            gave_up: Mutex::new(None),
+            reconnect: Mutex::new(None),
+            session: Mutex::new(None),
+            subscribers: Subscribers::default(),
+        });
+        let reader = open_session(&inner, details);
+        let (globals, objects) = match register(&inner).await {
+            Ok(roots) => roots,
+            Err(e) => {
+                // A replay that diverged says why, which beats the lost connection it caused
+                let closed = inner.close_session().await;
+                let _ = reader.await;
+                closed?;
+                return Err(e);
+            }
+        };
         let (stop_tx, stop_rx) = oneshot::channel();
-        let channels = Arc::new(Mutex::new(HashMap::new()));
-        let channels_clone = channels.clone();
-        let task =
-            tokio::spawn(
-                async move { read_loop(channels_clone, receiver, stop_rx).await.unwrap() },
-            );
-        let mut robot = RobotClient {
-            channels,
-            client_address: ObjectAddress {
-                module_id: 2,
-                node_id: client_id,
-                object_id: 65535,
-            },
-            connection,
-            globals: Vec::new(),
-            objects: Vec::new(),
+        let task = tokio::spawn(supervise(
+            inner.clone(),
+            reader,
+            stop_rx,
+            (globals.clone(), objects.clone()),
+        ));
+        Ok(RobotClient {
+            inner,
+            globals,
+            objects,
+            tree: OnceCell::new(),
+            methods: Mutex::new(HashMap::new()),
             stop_tx,
             task,
-        };
-        let (globals, objects) = register(&mut robot).await?;
-        robot.globals.extend(globals);
-        robot.objects.extend(objects);
-        Ok(robot)
+            timeout: Some(DEFAULT_TIMEOUT),
+        })
+    }
+
+    // Everything on the robot, read the first time this is called and kept for later ones
+    pub async fn object_tree(&self) -> &ObjectTree {
+        self.tree.get_or_init(|| ObjectTree::walk(self)).await
+    }
+
+    // The address of an object by the names leading to it from a root, like NimbusCore.DoorLock,
+    // see ObjectTree::resolve
+    pub async fn resolve(&self, path: &str) -> Result<ObjectAddress, Error> {
+        Ok(self
+            .object_tree()
+            .await
+            .resolve(path)?
+            .object
+            .address
+            .clone())
+    }
+
+    // How long calls wait for a reply unless they say otherwise. None waits forever.
+    pub fn with_default_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    // Reconnects according to policy whenever the connection drops
+    pub fn with_reconnect(self, policy: ReconnectPolicy) -> Self {
+        *self.inner.reconnect.lock().unwrap() = Some(policy);
+        self
+    }
+
+    // False once the connection has dropped, until a reconnect succeeds
+    pub fn is_connected(&self) -> bool {
+        self.inner.session.lock().unwrap().is_some()
     }
 
     pub async fn close(self) -> Result<(), anyhow::Error> {
-        self.stop_tx
-            .send(())
-            .or_else(|_| Err(anyhow::anyhow!("Failed to send stop signal")))?;
-        self.task.await?;
-        self.connection.close().await
+        // This only fails if the connection already dropped and isn't coming back
+        let _ = self.stop_tx.send(());
+        self.task.await?
+    }
+
+    // Everything the object at source sends without being asked, for as long as the client is open
+    pub fn subscribe_events(&self, source: &ObjectAddress) -> Events {
+        events::add(&self.inner.subscribers, source, None)
+    }
+
+    // Only the events of type T from source, decoded. Other kinds of event the object sends are
+    // left out.
+    pub fn subscribe<T: PigletEvent>(&self, source: &ObjectAddress) -> Subscription<T> {
+        Subscription::new(events::add(
+            &self.inner.subscribers,
+            source,
+            Some((T::INTERFACE_ID, T::CALL_TYPE_ID)),
+        ))
     }
 
     pub async fn act(
@@ -70,6 +216,120 @@ impl RobotClient {
         call_type_id: u16,
         parameters: Bytes,
     ) -> Result<(u8, Bytes), Error> {
+        self.act_with_timeout(
+            destination,
+            interface_id,
+            call_type,
+            call_type_id,
+            parameters,
+            Timeout::Default,
+        )
+        .await
+    }
+
+    // Like act, but waits as long as timeout says
+    pub async fn act_with_timeout(
+        &self,
+        destination: &ObjectAddress,
+        interface_id: u8,
+        call_type: u8,
+        call_type_id: u16,
+        parameters: Bytes,
+        timeout: Timeout,
+    ) -> Result<(u8, Bytes), Error> {
+        match self
+            .call(
+                destination,
+                interface_id,
+                call_type,
+                call_type_id,
+                parameters,
+                timeout,
+            )
+            .await
+        {
+            Err(CallError {
+                call: _,
+                context,
+                mut errors,
+                source,
+            }) => {
+                // Errors are only named from what's already known, so a failing call doesn't set
+                // off more calls
+                let tree = self.tree.get();
+                for error in &mut errors {
+                    error.path = tree
+                        .and_then(|t| t.find(&error.source))
+                        .map(|n| n.path.clone());
+                    // The robot doesn't say which interface failed, but when it's the method that
+                    // was called, it's the interface it was called on
+                    if error.source == source && error.method_id == call_type_id {
+                        error.interface_id = Some(interface_id);
+                    }
+                    error.method =
+                        self.method_name(&error.source, error.interface_id, error.method_id);
+                }
+                let call = match (
+                    tree.and_then(|t| t.find(&source)),
+                    self.method_name(&source, Some(interface_id), call_type_id),
+                ) {
+                    (Some(node), Some(method)) => Some(format!("{}.{}", node.path, method)),
+                    _ => None,
+                };
+                Err(CallError {
+                    call,
+                    context,
+                    errors,
+                    source,
+                })
+            }
+            result => result,
+        }
+    }
+
+    // An object's methods, if something has read them since connecting
+    pub fn methods(&self, address: &ObjectAddress) -> Option<Arc<Vec<Method>>> {
+        self.methods.lock().unwrap().get(address).cloned()
+    }
+
+    pub(crate) fn remember_methods(&self, address: &ObjectAddress, methods: &[Method]) {
+        self.methods
+            .lock()
+            .unwrap()
+            .insert(address.clone(), Arc::new(methods.to_vec()));
+    }
+
+    // Without the interface, only a method id no other interface also uses can be named
+    fn method_name(
+        &self,
+        address: &ObjectAddress,
+        interface_id: Option<u8>,
+        method_id: u16,
+    ) -> Option<String> {
+        let methods = self.methods(address)?;
+        let mut found = methods.iter().filter(|m| {
+            m.method_id == method_id && interface_id.is_none_or(|i| m.interface_id == i)
+        });
+        match (found.next(), found.next()) {
+            (Some(method), None) => Some(method.name.clone()),
+            _ => None,
+        }
+    }
+
+    async fn call(
+        &self,
+        destination: &ObjectAddress,
+        interface_id: u8,
+        call_type: u8,
+        call_type_id: u16,
+        parameters: Bytes,
+        timeout: Timeout,
+    ) -> Result<(u8, Bytes), Error> {
+        let timeout = match timeout {
+            Timeout::Default => self.timeout,
+            Timeout::After(timeout) => Some(timeout),
+            Timeout::Never => None,
+        };
         let mut request = BytesMut::new();
         request.put_u8(interface_id);
         request.put_u8(call_type);
@@ -79,15 +339,15 @@ impl RobotClient {
         request.put(parameters);
 
         let response = self
-            .request(&destination, 2, call_type, true, request.freeze())
-            .await
-            .map_err(|e| ConnectionError(e))?;
+            .inner
+            .request(destination, 2, call_type, true, request.freeze(), timeout)
+            .await?;
         let mut bytes = response.bytes;
-        let _interface_id = bytes.get_u8();
-        let _call_type = bytes.get_u8();
-        let _call_type_id = bytes.get_u16_le();
-        let _unknown = bytes.get_u8();
-        let count = bytes.get_u8();
+        let _interface_id = bytes.try_get_u8()?;
+        let _call_type = bytes.try_get_u8()?;
+        let _call_type_id = bytes.try_get_u16_le()?;
+        let _unknown = bytes.try_get_u8()?;
+        let count = bytes.try_get_u8()?;
 
         if call_type == 0 && response.code == 1 {
             Ok((count, bytes))
@@ -102,18 +362,21 @@ impl RobotClient {
                     .into_iter()
                     .map(|m| parse_robot_error(m))
                     .collect::<anyhow::Result<Vec<_>>>()
-                    .map_err(|e| ConnectionError(e))?
+                    .map_err(ProtocolError)?
             } else {
-                vec![parse_robot_error(&message).map_err(|e| ConnectionError(e))?]
+                vec![parse_robot_error(&message).map_err(ProtocolError)?]
             };
             Err(CallError {
+                call: None,
                 context: None,
                 errors,
                 source: destination.clone(),
             })
         }
     }
+}
 
+impl Inner {
     async fn request(
         &self,
         destination: &ObjectAddress,
@@ -121,14 +384,23 @@ impl RobotClient {
         call_type: u8,
         require_response: bool,
         bytes: Bytes,
-    ) -> Result<Response, anyhow::Error> {
+        timeout: Option<Duration>,
+    ) -> Result<Response, Error> {
         let (tx, rx) = oneshot::channel();
         let id = {
             let mut channels = self.channels.lock().unwrap();
+            let session = self.session.lock().unwrap();
+            let session = session.as_ref().ok_or_else(|| {
+                TransportError(match &*self.gave_up.lock().unwrap() {
+                    Some(reason) => anyhow!("Not connected to the robot: {}", reason),
+                    None => anyhow!("Not connected to the robot"),
+                })
+            })?;
             let channel = match channels.get_mut(&destination) {
                 Some(channel) => channel,
                 _ => {
                     let channel = Channel {
+                        abandoned: HashMap::new(),
                         active: HashMap::new(),
                         id_generator: RequestIdGenerator::default(),
                     };
@@ -137,9 +409,9 @@ impl RobotClient {
                 }
             };
 
-            let id = channel.id_generator.allocate()?;
+            let id = channel.allocate().map_err(ConnectionError)?;
             let mut framed = BytesMut::new();
-            framed.put(self.client_address.to_bytes());
+            framed.put(session.client_address.to_bytes());
             framed.put(destination.to_bytes());
             framed.put_u8(id);
             framed.put_u8(0);
@@ -152,24 +424,167 @@ impl RobotClient {
             framed.put(bytes);
             let copy = framed.freeze();
             channel.active.insert(id, tx);
-            self.connection.write(/* protocol= */ 6, copy)?;
+            if let Err(e) = session.connection.write(/* protocol= */ 6, copy) {
+                channel.active.remove(&id);
+                channel.id_generator.release(id);
+                return Err(TransportError(e));
+            }
             id
         };
+        let _pending = PendingRequest {
+            channels: &self.channels,
+            destination,
+            id,
+        };
 
-        let result = rx
-            .await
-            .or_else(|_| Err(anyhow::anyhow!("Unable to receive response")));
+        let response = match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, rx)
+                .await
+                .map_err(|_| TimeoutError {
+                    context: None,
+                    source: destination.clone(),
+                    timeout,
+                })?,
+            None => rx.await,
+        };
+        response.map_err(|_| TransportError(anyhow!("Connection to the robot was lost")))?
+    }
+    // Closing the connection also ends the session's read loop
+    async fn close_session(&self) -> Result<(), anyhow::Error> {
+        let session = self.session.lock().unwrap().take();
+        match session {
+            Some(session) => session.connection.close().await,
+            None => Ok(()),
+        }
+    }
+}
 
-        {
-            let mut channels = self.channels.lock().unwrap();
-            let channel = channels.get_mut(&destination).unwrap();
-            channel.id_generator.release(id);
+fn open_session(inner: &Arc<Inner>, details: ConnectionDetails) -> JoinHandle<()> {
+    let ConnectionDetails {
+        connection,
+        client_id,
+    } = details;
+    let receiver = connection.register_protocol(6);
+    *inner.session.lock().unwrap() = Some(Session {
+        client_address: ObjectAddress {
+            module_id: 2,
+            node_id: client_id,
+            object_id: 65535,
+        },
+        connection,
+    });
+    let inner = inner.clone();
+    tokio::spawn(async move {
+        if let Err(e) = read_loop(&inner, receiver).await {
+            eprintln!("piglet: {e}");
+        }
+    })
+}
+
+// Waits for the session's read loop to end, which means the connection dropped, then fails
+// everything in flight and reconnects if there's a policy for it. roots are the globals and objects
+// the robot registered when the client connected.
+async fn supervise(
+    inner: Arc<Inner>,
+    mut reader: JoinHandle<()>,
+    mut stop_rx: oneshot::Receiver<()>,
+    roots: Roots,
+) -> Result<(), anyhow::Error> {
+    loop {
+        tokio::select! {
+            _ = &mut reader => {}
+            _ = &mut stop_rx => {
+                let result = inner.close_session().await;
+                let _ = reader.await;
+                return result;
+            }
         }
 
-        result
+        let closed = inner.close_session().await;
+        // Dropping the senders wakes every waiting request with an error
+        inner.channels.lock().unwrap().clear();
+
+        let policy = inner.reconnect.lock().unwrap().clone();
+        let (Some(policy), Some(endpoint)) = (policy, &inner.endpoint) else {
+            eprintln!("piglet: connection to the robot was lost");
+            return closed;
+        };
+        reader = match reconnect(&inner, endpoint, &policy, &mut stop_rx, &roots).await {
+            Ok(Some(reader)) => reader,
+            Ok(None) => return Ok(()),
+            Err(e) => {
+                eprintln!("piglet: {e}");
+                *inner.gave_up.lock().unwrap() = Some(e.to_string());
+                return Err(e);
+            }
+        };
     }
 }
 
+type Roots = (Vec<ObjectAddress>, Vec<ObjectAddress>);
+
+// Returns the new session's read loop, or None if we gave up or were told to stop. Fails if the
+// robot came back with different objects, since the client's addresses may no longer mean the
+// same thing.
+async fn reconnect(
+    inner: &Arc<Inner>,
+    endpoint: &Endpoint,
+    policy: &ReconnectPolicy,
+    stop_rx: &mut oneshot::Receiver<()>,
+    roots: &Roots,
+) -> Result<Option<JoinHandle<()>>, anyhow::Error> {
+    let mut delay = policy.delay;
+    let mut attempt = 0;
+    loop {
+        if policy.attempts.is_some_and(|attempts| attempt >= attempts) {
+            eprintln!("piglet: giving up on reconnecting after {attempt} attempts");
+            *inner.gave_up.lock().unwrap() =
+                Some(format!("gave up on reconnecting after {attempt} attempts"));
+            return Ok(None);
+        }
+        attempt += 1;
+
+        tokio::select! {
+            _ = tokio::time::sleep(delay) => {}
+            _ = &mut *stop_rx => return Ok(None),
+        }
+        delay = (delay * 2).min(policy.max_delay);
+
+        let connected = tokio::select! {
+            result = endpoint.connect() => result,
+            _ = &mut *stop_rx => return Ok(None),
+        };
+        let details = match connected {
+            Ok(details) => details,
+            Err(e) => {
+                eprintln!("piglet: reconnect attempt {attempt} failed: {e}");
+                continue;
+            }
+        };
+        let reader = open_session(inner, details);
+        match register(inner).await {
+            Ok(registered) if registered == *roots => return Ok(Some(reader)),
+            Ok(_) => {
+                let _ = inner.close_session().await;
+                let _ = reader.await;
+                bail!(
+                    "The robot's objects changed while it was disconnected, so it won't be reconnected to"
+                );
+            }
+            Err(e) => {
+                eprintln!("piglet: registration after reconnecting failed: {e}");
+                let _ = inner.close_session().await;
+                let _ = reader.await;
+            }
+        }
+    }
+}
+
+// For the generated Request structs, whose fields are None until they're set
+pub fn required<T>(value: Option<T>, request: &str, field: &str) -> Result<T, Error> {
+    value.ok_or_else(|| InvalidArgument(anyhow!("{} has no {} set", request, field)))
+}
+
 pub fn with_context<V, C: FnOnce() -> String>(
     result: Result<V, Error>,
     context_generator: C,
@@ -178,10 +593,12 @@ pub fn with_context<V, C: FnOnce() -> String>(
         let ctx = context_generator();
         match err {
             CallError {
+                call,
                 context,
                 errors,
                 source,
             } => CallError {
+                call,
                 context: Some(match context {
                     Some(original) => format!("{}\n\ncaused by: {}", ctx, original),
                     None => ctx,
@@ -190,6 +607,29 @@ pub fn with_context<V, C: FnOnce() -> String>(
                 source,
             },
             ConnectionError(e) => ConnectionError(e.context(ctx)),
+            InvalidArgument(e) => InvalidArgument(e.context(ctx)),
+            Mismatch(e) => Mismatch(e.context(ctx)),
+            TransportError(e) => TransportError(e.context(ctx)),
+            ProtocolError(e) => ProtocolError(e.context(ctx)),
+            AddressNotFound { address, context } => AddressNotFound {
+                address,
+                context: Some(match context {
+                    Some(original) => format!("{}\n\ncaused by: {}", ctx, original),
+                    None => ctx,
+                }),
+            },
+            TimeoutError {
+                context,
+                source,
+                timeout,
+            } => TimeoutError {
+                context: Some(match context {
+                    Some(original) => format!("{}\n\ncaused by: {}", ctx, original),
+                    None => ctx,
+                }),
+                source,
+                timeout,
+            },
         }
     })
 }
@@ -198,34 +638,110 @@ pub fn with_context<V, C: FnOnce() -> String>(
 pub struct RobotError {
     code: ErrorCode,
     source: ObjectAddress,
+    // Only known for the method that was called, see act_with_timeout
+    interface_id: Option<u8>,
+    method_id: u16,
+    // Filled in by the client when it can name them
+    path: Option<String>,
+    method: Option<String>,
+}
+
+impl RobotError {
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    // The object that reported the error, which can be under the one that was called
+    pub fn source(&self) -> &ObjectAddress {
+        &self.source
+    }
+
+    pub fn interface_id(&self) -> Option<u8> {
+        self.interface_id
+    }
+
+    pub fn method_id(&self) -> u16 {
+        self.method_id
+    }
+
+    // Like NimbusCore.Channel[3]
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    pub fn method(&self) -> Option<&str> {
+        self.method.as_deref()
+    }
 }
 
 impl std::fmt::Display for RobotError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(
-            f,
-            "Call to {} failed with code {:?}",
-            self.source, self.code
-        )
+        let object = match &self.path {
+            Some(path) => path.clone(),
+            None => self.source.to_string(),
+        };
+        match (&self.method, self.interface_id) {
+            (Some(method), _) => write!(f, "{}.{}", object, method)?,
+            (None, Some(interface_id)) => {
+                write!(f, "{} method {}:{}", object, interface_id, self.method_id)?
+            }
+            (None, None) => write!(f, "{} method {}", object, self.method_id)?,
+        }
+        write!(f, " failed with code {:?}", self.code)
     }
 }
 
+impl std::error::Error for RobotError {}
+
 #[derive(Debug)]
 pub enum Error {
     CallError {
+        // The method that was called, like NimbusCore.Pipette.Aspirate, when it could be named
+        call: Option<String>,
         context: Option<String>,
         errors: Vec<RobotError>,
         source: ObjectAddress,
     },
+    // Anything else
     ConnectionError(anyhow::Error),
+    // Arguments that can't be sent, caught before anything goes to the robot
+    InvalidArgument(anyhow::Error),
+    // The robot isn't what the caller expected, like an object with different methods
+    Mismatch(anyhow::Error),
+    TimeoutError {
+        context: Option<String>,
+        source: ObjectAddress,
+        timeout: Duration,
+    },
+    // Not connected, or the connection dropped before the reply came back
+    TransportError(anyhow::Error),
+    // The robot sent something that couldn't be read, like a reply with the wrong types in it
+    ProtocolError(anyhow::Error),
+    // The robot has nothing at the address a request was sent to
+    AddressNotFound {
+        address: ObjectAddress,
+        context: Option<String>,
+    },
 }
 
 impl std::error::Error for Error {
+    // A call that fails in several objects reports the first of them
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        None
+        match self {
+            ConnectionError(e) | InvalidArgument(e) | Mismatch(e) | TransportError(e)
+            | ProtocolError(e) => Some(e.as_ref()),
+            CallError { errors, .. } => errors
+                .first()
+                .map(|e| e as &(dyn std::error::Error + 'static)),
+            TimeoutError { .. } | AddressNotFound { .. } => None,
+        }
     }
-    fn cause(&self) -> Option<&dyn std::error::Error> {
-        None
+}
+
+// Running out of bytes partway through a reply
+impl From<TryGetError> for Error {
+    fn from(e: TryGetError) -> Self {
+        ProtocolError(e.into())
     }
 }
 
@@ -233,14 +749,20 @@ impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match self {
             CallError {
+                call,
                 context,
                 errors,
                 source,
             } => {
-                if errors.len() == 1 {
-                    write!(f, "{}", errors.get(0).unwrap())?;
+                if errors.len() == 1 && errors[0].source == *source {
+                    write!(f, "{}", errors[0])?;
                 } else {
-                    write!(f, "Call to {} failed with multiple errors:", source)?;
+                    // Usually calls to one object that fail in the objects under it, like a
+                    // pipette's channels
+                    match call {
+                        Some(call) => write!(f, "{} failed:", call)?,
+                        None => write!(f, "Call to {} failed:", source)?,
+                    }
                     for error in errors {
                         write!(f, "\n - {}", error)?;
                     }
@@ -250,16 +772,86 @@ impl std::fmt::Display for Error {
                 }
                 Ok(())
             }
-            ConnectionError(e) => write!(f, "{}", e.to_string()),
+            ConnectionError(e) | InvalidArgument(e) | Mismatch(e) | TransportError(e)
+            | ProtocolError(e) => write!(f, "{}", e),
+            TimeoutError {
+                context,
+                source,
+                timeout,
+            } => {
+                write!(f, "Call to {} timed out after {:?}", source, timeout)?;
+                if let Some(c) = context {
+                    write!(f, "\n\ncontext: {}", c)?;
+                }
+                Ok(())
+            }
+            AddressNotFound { address, context } => {
+                write!(f, "The robot has no object at {}", address)?;
+                if let Some(c) = context {
+                    write!(f, "\n\ncontext: {}", c)?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
 struct Channel {
-    active: HashMap<u8, oneshot::Sender<Response>>,
+    // IDs of requests nobody is waiting for anymore and when they were given up on, held until
+    // their reply shows up or ABANDONED_GRACE passes
+    abandoned: HashMap<u8, Instant>,
+    // Routing failures are sent instead of a response
+    active: HashMap<u8, oneshot::Sender<Result<Response, Error>>>,
     id_generator: RequestIdGenerator,
 }
 
+impl Channel {
+    fn allocate(&mut self) -> Result<u8, anyhow::Error> {
+        let id_generator = &mut self.id_generator;
+        self.abandoned.retain(|id, since| {
+            let waiting = since.elapsed() < ABANDONED_GRACE;
+            if !waiting {
+                id_generator.release(*id);
+            }
+            waiting
+        });
+        if let Ok(id) = self.id_generator.allocate() {
+            return Ok(id);
+        }
+        // Every ID is taken, so the reply least likely to still come loses its place
+        let oldest = self
+            .abandoned
+            .iter()
+            .min_by_key(|(_, since)| **since)
+            .map(|(id, _)| *id)
+            .ok_or_else(|| anyhow!("Ran out of available message IDs"))?;
+        self.abandoned.remove(&oldest);
+        Ok(oldest)
+    }
+}
+
+// Cleans up after a request however it ends, including when the caller stops waiting. An ID
+// whose reply never arrived isn't reused until the reply does, so a late reply can't be mistaken
+// for the answer to a newer request.
+struct PendingRequest<'a> {
+    channels: &'a Mutex<HashMap<ObjectAddress, Channel>>,
+    destination: &'a ObjectAddress,
+    id: u8,
+}
+
+impl Drop for PendingRequest<'_> {
+    fn drop(&mut self) {
+        let mut channels = self.channels.lock().unwrap();
+        if let Some(channel) = channels.get_mut(self.destination) {
+            if channel.active.remove(&self.id).is_some() {
+                channel.abandoned.insert(self.id, Instant::now());
+            } else {
+                channel.id_generator.release(self.id);
+            }
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 struct RequestIdGenerator {
     i: u8,
@@ -300,89 +892,183 @@ struct Response {
 }
 
 #[derive(Debug)]
-struct RegistrationResponse {
+pub(crate) struct RegistrationResponse {
     roots: Vec<ObjectAddress>,
 }
 
+// Ends when the connection drops
 async fn read_loop(
-    channels: Arc<Mutex<HashMap<ObjectAddress, Channel>>>,
+    inner: &Inner,
     mut receiver: mpsc::Receiver<Bytes>,
-    mut stop_rx: oneshot::Receiver<()>,
 ) -> Result<(), anyhow::Error> {
-    let mut running = true;
-    while running {
-        tokio::select! {
-            response = receiver.recv() => {
-                if let Some(mut bytes) = response {
-                    let expected_length = bytes.remaining();
-                    let source = ObjectAddress::from_bytes(&mut bytes)?;
-                    let _destination = ObjectAddress::from_bytes(&mut bytes)?;
-                    let id = bytes.get_u8();
-                    let _unknown = bytes.get_u8();
-                    let protocol = bytes.get_u8();
-                    let code = bytes.get_u8();
-                    let length = bytes.get_u16_le();
-                    if expected_length != length.into() {
-                        anyhow::bail!("Bad length");
-                    }
+    while let Some(bytes) = receiver.recv().await {
+        // One bad frame shouldn't end the session, so it's dropped and whoever was waiting for it
+        // times out
+        let Frame {
+            source,
+            id,
+            protocol,
+            code,
+            routing_error,
+            bytes,
+        } = match read_frame(bytes) {
+            Ok(frame) => frame,
+            Err(e) => {
+                eprintln!("piglet: dropping unreadable frame: {e}");
+                continue;
+            }
+        };
 
-                    let mut options_length = bytes.get_u16_le();
-                    while options_length > 0 {
-                        let option = bytes.get_u8();
-                        let length = bytes.get_u8() as u16;
-                        if option == 1 {
-                            if length != 8 {
-                                anyhow::bail!("Expected length of 8");
-                            }
-                            let address = ObjectAddress::from_bytes(&mut bytes);
-                            let result = bytes.get_u16_le();
-                            if result == 516 {
-                                anyhow::bail!("Unable to find address {:?}", address);
-                            } else {
-                                anyhow::bail!("Unknown communication error with {:?}", address);
-                            }
-                        } else {
-                            bytes.advance(length.into());
-                        }
-                        options_length -= 1 + 1 + length;
+        let pending = {
+            let mut c = inner.channels.lock().unwrap();
+            match c.get_mut(&source) {
+                Some(channel) => {
+                    if channel.abandoned.remove(&id).is_some() {
+                        // A late reply to a request that timed out or was cancelled
+                        channel.id_generator.release(id);
+                        continue;
                     }
-
-                    let _ = bytes.get_u8(); // unknown
-                    let _ = bytes.get_u8(); // unknown
-
-                    let mut c = channels.lock().unwrap();
-                    let channel = match c.get_mut(&source) {
-                        Some(ch) => ch,
-                        None => {
-                            eprintln!("piglet: no channel for source {source}");
-                            continue;
-                        }
-                    };
-                    let tx = match channel.active.remove(&id) {
-                        Some(tx) => tx,
-                        None => {
-                            eprintln!("piglet: no pending request for id {id} from {source}");
-                            continue;
+                    channel.active.remove(&id)
+                }
+                None => None,
+            }
+        };
+        let tx = match pending {
+            Some(tx) => tx,
+            None => {
+                // Nobody asked for this, so it's an event
+                match read_event(source.clone(), protocol, code, bytes) {
+                    Some(event) => {
+                        if !events::dispatch(&inner.subscribers, event) {
+                            eprintln!(
+                                "piglet: no pending request or subscriber for id {id} from {source}"
+                            );
                         }
-                    };
-                    if tx.send(Response { protocol, code, bytes }).is_err() {
-                        eprintln!("piglet: receiver dropped for id {id} from {source}");
                     }
-                } else {
-                    running = false;
+                    None => eprintln!("piglet: unreadable message with id {id} from {source}"),
                 }
+                continue;
             }
-            _ = &mut stop_rx => {
-                running = false;
-            }
+        };
+        let response = match routing_error {
+            Some(e) => Err(e),
+            None => Ok(Response {
+                protocol,
+                code,
+                bytes,
+            }),
+        };
+        if tx.send(response).is_err() {
+            eprintln!("piglet: receiver dropped for id {id} from {source}");
         }
     }
     Ok(())
 }
 
+pub(crate) struct Frame {
+    source: ObjectAddress,
+    id: u8,
+    protocol: u8,
+    code: u8,
+    // The router answers in place of an object it couldn't deliver a request to
+    routing_error: Option<Error>,
+    bytes: Bytes,
+}
+
+pub(crate) fn read_frame(mut bytes: Bytes) -> Result<Frame, anyhow::Error> {
+    let expected_length = bytes.remaining();
+    let source = ObjectAddress::from_bytes(&mut bytes)?;
+    let _destination = ObjectAddress::from_bytes(&mut bytes)?;
+    let id = bytes.try_get_u8()?;
+    let _unknown = bytes.try_get_u8()?;
+    let protocol = bytes.try_get_u8()?;
+    let code = bytes.try_get_u8()?;
+    let length = bytes.try_get_u16_le()?;
+    if expected_length != length.into() {
+        bail!(
+            "Bad length {}, the frame is {} bytes",
+            length,
+            expected_length
+        );
+    }
+
+    let mut routing_error = None;
+    let mut options_length = bytes.try_get_u16_le()?;
+    while options_length > 0 {
+        let option = bytes.try_get_u8()?;
+        let length = bytes.try_get_u8()?;
+        if option == 1 {
+            if length != 8 {
+                bail!("Expected length of 8");
+            }
+            let address = ObjectAddress::from_bytes(&mut bytes)?;
+            let result = bytes.try_get_u16_le()?;
+            routing_error = Some(if result == 516 {
+                AddressNotFound {
+                    address,
+                    context: None,
+                }
+            } else {
+                TransportError(anyhow!(
+                    "Unknown communication error {} with {}",
+                    result,
+                    address
+                ))
+            });
+        } else {
+            if bytes.remaining() < length.into() {
+                bail!("Option {} is longer than the frame", option);
+            }
+            bytes.advance(length.into());
+        }
+        options_length = options_length
+            .checked_sub(2 + length as u16)
+            .ok_or_else(|| anyhow!("Options are longer than their length says"))?;
+    }
+
+    let _ = bytes.try_get_u8()?; // unknown
+    let _ = bytes.try_get_u8()?; // unknown
+    Ok(Frame {
+        source,
+        id,
+        protocol,
+        code,
+        routing_error,
+        bytes,
+    })
+}
+
+fn read_event(source: ObjectAddress, protocol: u8, action: u8, mut bytes: Bytes) -> Option<Event> {
+    if protocol != 2 || bytes.remaining() < 6 {
+        return None;
+    }
+    let interface_id = bytes.get_u8();
+    let call_type = bytes.get_u8();
+    let call_type_id = bytes.get_u16_le();
+    let _unknown = bytes.get_u8();
+    let count = bytes.get_u8();
+    Some(Event {
+        source,
+        action,
+        interface_id,
+        call_type,
+        call_type_id,
+        count,
+        parameters: bytes,
+    })
+}
+
 async fn register(
-    robot: &mut RobotClient,
+    inner: &Inner,
 ) -> Result<(Vec<ObjectAddress>, Vec<ObjectAddress>), anyhow::Error> {
+    let client_address = inner
+        .session
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|session| session.client_address.clone())
+        .ok_or_else(|| anyhow!("Not connected to the robot"))?;
+
     // This is supposed to be 0-0-65534 but it seems to work just as well
     let registration = ObjectAddress {
         module_id: 1,
@@ -395,7 +1081,7 @@ async fn register(
     register.put_u16_le(0); // response code
     register.put_u8(0); // unknown
     register.put_u8(0); // unknown
-    register.put(robot.client_address.to_bytes());
+    register.put(client_address.to_bytes());
     register.put(
         (ObjectAddress {
             module_id: 0,
@@ -405,8 +1091,15 @@ async fn register(
         .to_bytes(),
     );
     register.put_u16_le(0); // command length
-    let register_response = robot
-        .request(&registration, 3, 3, false, register.freeze())
+    let register_response = inner
+        .request(
+            &registration,
+            3,
+            3,
+            false,
+            register.freeze(),
+            Some(DEFAULT_TIMEOUT),
+        )
         .await?;
     if register_response.protocol != 3 {
         anyhow::bail!("Expected protocol 3, not {}", register_response.protocol);
@@ -439,8 +1132,15 @@ async fn register(
     find_objects.put_u8(2); // length of data
     find_objects.put_u8(2); // protocol
     find_objects.put_u8(1); // request id
-    let find_objects_response = robot
-        .request(&registration, 3, 3, true, find_objects.freeze())
+    let find_objects_response = inner
+        .request(
+            &registration,
+            3,
+            3,
+            true,
+            find_objects.freeze(),
+            Some(DEFAULT_TIMEOUT),
+        )
         .await?;
     if find_objects_response.protocol != 3 {
         anyhow::bail!(
@@ -476,8 +1176,15 @@ async fn register(
     find_globals.put_u8(2); // length of data
     find_globals.put_u8(2); // protocol
     find_globals.put_u8(2); // request id
-    let find_globals_response = robot
-        .request(&registration, 3, 3, true, find_globals.freeze())
+    let find_globals_response = inner
+        .request(
+            &registration,
+            3,
+            3,
+            true,
+            find_globals.freeze(),
+            Some(DEFAULT_TIMEOUT),
+        )
         .await?;
     if find_globals_response.protocol != 3 {
         anyhow::bail!(
@@ -490,30 +1197,30 @@ async fn register(
     Ok((find_globals_roots, find_objects_roots))
 }
 
-fn read_registration(mut bytes: Bytes) -> Result<RegistrationResponse, anyhow::Error> {
-    let _call_type_code = bytes.get_u16_le();
-    let response_code = bytes.get_u16_le();
+pub(crate) fn read_registration(mut bytes: Bytes) -> Result<RegistrationResponse, anyhow::Error> {
+    let _call_type_code = bytes.try_get_u16_le()?;
+    let response_code = bytes.try_get_u16_le()?;
 
     if response_code > 0 {
         anyhow::bail!("Request failed with code {}", response_code);
     }
 
-    let _unknown1 = bytes.get_u8();
-    let _unknown2 = bytes.get_u8();
+    let _unknown1 = bytes.try_get_u8()?;
+    let _unknown2 = bytes.try_get_u8()?;
 
     // these seem pretty useless?
     let _destination = ObjectAddress::from_bytes(&mut bytes)?;
     let _source = ObjectAddress::from_bytes(&mut bytes)?;
 
     let mut roots = Vec::<ObjectAddress>::new();
-    let option_count = bytes.get_u16_le();
+    let option_count = bytes.try_get_u16_le()?;
     for _ in 0..option_count {
-        let option = bytes.get_u8();
-        let length = bytes.get_u8();
+        let option = bytes.try_get_u8()?;
+        let length = bytes.try_get_u8()?;
 
         if option == 6 {
             if length > 0 {
-                let pad = bytes.get_u16_le();
+                let pad = bytes.try_get_u16_le()?;
                 if (pad & 0x8000) != 0 {
                     anyhow::bail!("some padding issue?");
                 }
@@ -521,7 +1228,7 @@ fn read_registration(mut bytes: Bytes) -> Result<RegistrationResponse, anyhow::E
                     roots.push(ObjectAddress {
                         module_id: 1,
                         node_id: 1,
-                        object_id: bytes.get_u16_le(),
+                        object_id: bytes.try_get_u16_le()?,
                     });
                 }
             }
@@ -543,8 +1250,8 @@ fn parse_robot_error(message: &str) -> anyhow::Result<RobotError> {
         .ok_or_else(|| anyhow!("Unable to parse error {}", message))?;
     let source_parts: Vec<u16> = raw_source
         .split('.')
-        .map(|s| u16::from_str_radix(&s[2..], 16))
-        .collect::<Result<Vec<u16>, _>>()?;
+        .map(hex)
+        .collect::<anyhow::Result<Vec<u16>>>()?;
     if source_parts.len() != 3 {
         bail!(
             "Unable to parse the robot error source. Original: {}",
@@ -559,17 +1266,30 @@ fn parse_robot_error(message: &str) -> anyhow::Result<RobotError> {
     };
     let detail_parts: Vec<u16> = rest
         .split(',')
-        .map(|s| u16::from_str_radix(&s[2..], 16))
-        .collect::<Result<Vec<u16>, _>>()?;
+        .map(hex)
+        .collect::<anyhow::Result<Vec<u16>>>()?;
     if detail_parts.len() != 3 {
         bail!(
             "Unable to parse the robot error details. Original: {}",
             message
         );
     }
-    let [_unknown, _call_type_id, code] = detail_parts.try_into().unwrap();
+    // What the first detail means hasn't been worked out, so it isn't taken to be the interface
+    let [_unknown, method_id, code] = detail_parts.try_into().unwrap();
     Ok(RobotError {
         source,
         code: ErrorCode(code),
+        interface_id: None,
+        method_id,
+        path: None,
+        method: None,
     })
 }
+
+// Like 0x1a
+fn hex(text: &str) -> anyhow::Result<u16> {
+    let digits = text
+        .strip_prefix("0x")
+        .ok_or_else(|| anyhow!("Expected a hex number, not {}", text))?;
+    Ok(u16::from_str_radix(digits, 16)?)
+}
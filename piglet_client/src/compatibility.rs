@@ -0,0 +1,97 @@
+use crate::client::{Error, Error::Mismatch, RobotClient};
+use crate::dynamic_object::{DynamicObject, Method};
+use crate::object_address::ObjectAddress;
+use anyhow::anyhow;
+use std::sync::Arc;
+
+// An object generated bindings were made from, as it was when they were generated
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpectedObject {
+    pub address: ObjectAddress,
+    // These are None when the bindings came from a reconstructed dump, whose names and method
+    // descriptions weren't read off a robot, so only what was is checked
+    pub name: Option<&'static str>,
+    pub version: Option<&'static str>,
+    // Also None for objects that wouldn't describe their methods when the bindings were generated
+    pub fingerprint: Option<u64>,
+}
+
+// A hash of everything that decides how a method is called: its name, ids and parameter types,
+// all as get_method reports them. Labels aren't included.
+// This is FNV-1a so it comes out the same in the generator and at runtime regardless of Rust
+// version.
+pub fn fingerprint(methods: &[Method]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut write = |bytes: &[u8]| {
+        for b in bytes {
+            hash ^= *b as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    };
+    for method in methods {
+        write(&[method.interface_id, method.call_type]);
+        write(&method.method_id.to_le_bytes());
+        write(&(method.name.len() as u32).to_le_bytes());
+        write(method.name.as_bytes());
+        write(&(method.parameter_types.len() as u32).to_le_bytes());
+        write(&method.parameter_types);
+    }
+    hash
+}
+
+// Checks that every object is still where the bindings expect it, with the same name, version and
+// methods, and lists everything that isn't if not
+pub async fn verify(robot: &Arc<RobotClient>, expected: &[ExpectedObject]) -> Result<(), Error> {
+    let mut problems = Vec::new();
+    for object in expected {
+        let expected_name = object.name.unwrap_or("an object");
+        let dynamic = DynamicObject::new(&object.address, robot);
+        let found = match dynamic.get_object().await {
+            Ok(found) => found,
+            Err(e) => {
+                problems.push(format!(
+                    "{} should be {} but couldn't be read: {}",
+                    object.address, expected_name, e
+                ));
+                continue;
+            }
+        };
+
+        if object.name.is_some_and(|name| name != found.name)
+            || object
+                .version
+                .is_some_and(|version| version != found.version)
+        {
+            problems.push(format!(
+                "{} should be {} version {} but is {} version {:?}",
+                object.address,
+                expected_name,
+                object
+                    .version
+                    .map_or("unknown".to_string(), |v| format!("{:?}", v)),
+                found.name,
+                found.version
+            ));
+            continue;
+        }
+
+        if let Some(expected_fingerprint) = object.fingerprint {
+            let actual = fingerprint(dynamic.methods().await?);
+            if actual != expected_fingerprint {
+                problems.push(format!(
+                    "{} {} has different methods than it was generated from (fingerprint {:016x}, expected {:016x})",
+                    object.address, found.name, actual, expected_fingerprint
+                ));
+            }
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(Mismatch(anyhow!(
+            "The robot doesn't match these bindings, they may need regenerating for its firmware:\n  {}",
+            problems.join("\n  ")
+        )))
+    }
+}
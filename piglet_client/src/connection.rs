@@ -1,17 +1,27 @@
+use crate::recording::{Direction, Recorder, load, play};
 use bytes::{Buf, BufMut, Bytes, BytesMut};
+use socket2::{SockRef, TcpKeepalive};
 use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::{
-    io::AsyncWriteExt,
-    net::tcp::{OwnedReadHalf, OwnedWriteHalf},
-    net::{TcpStream, ToSocketAddrs},
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::{TcpStream, ToSocketAddrs, lookup_host},
     sync::{mpsc, oneshot},
+    task::JoinHandle,
     time::Instant,
 };
 
+// How long connecting and initializing can take before giving up on the robot
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
 pub struct Connection {
     last_tx_time: Arc<Mutex<Instant>>,
+    // What's playing the instrument's side when replaying, which fails if the client strays from
+    // the recording
+    player: Option<JoinHandle<Result<(), anyhow::Error>>>,
     protocols: Arc<Mutex<HashMap<u8, mpsc::Sender<Bytes>>>>,
     stop_tx: oneshot::Sender<()>,
     task: tokio::task::JoinHandle<()>,
@@ -21,11 +31,22 @@ pub struct Connection {
 
 impl Connection {
     pub async fn close(self) -> Result<(), anyhow::Error> {
-        self.stop_tx
-            .send(())
-            .or_else(|_| Err(anyhow::anyhow!("Failed to send stop signal")))?;
-        self.task.await?;
-        Ok(())
+        let Connection {
+            player,
+            stop_tx,
+            task,
+            write_tx,
+            ..
+        } = self;
+        // This only fails if the read loop already stopped because the robot went away
+        let _ = stop_tx.send(());
+        task.await?;
+        // The player only sees the end of the recording once nothing can write to it anymore
+        drop(write_tx);
+        match player {
+            Some(player) => player.await?,
+            None => Ok(()),
+        }
     }
 
     pub fn register_protocol(&self, id: u8) -> mpsc::Receiver<Bytes> {
@@ -45,7 +66,7 @@ impl Connection {
     }
 }
 
-fn frame_message(protocol: u8, version: u8, bytes: Bytes) -> Bytes {
+pub(crate) fn frame_message(protocol: u8, version: u8, bytes: Bytes) -> Bytes {
     let mut framed = BytesMut::new();
     framed.put_u16_le(1 + 1 + 1 + 1 + bytes.remaining() as u16);
     framed.put_u8(protocol);
@@ -56,8 +77,15 @@ fn frame_message(protocol: u8, version: u8, bytes: Bytes) -> Bytes {
     framed.freeze()
 }
 
-async fn write_loop(mut rx: mpsc::Receiver<Bytes>, mut writer: OwnedWriteHalf) {
+async fn write_loop<W: AsyncWrite + Unpin>(
+    mut rx: mpsc::Receiver<Bytes>,
+    mut writer: W,
+    recorder: Option<Recorder>,
+) {
     while let Some(frame) = rx.recv().await {
+        if let Some(recorder) = &recorder {
+            recorder.record(Direction::Sent, &frame);
+        }
         if let Err(e) = writer.write_all(&frame).await {
             eprintln!("piglet: write error: {e}");
             break;
@@ -70,21 +98,25 @@ pub struct ConnectionDetails {
     pub client_id: u16,
 }
 
-async fn read_loop(
+pub(crate) async fn read_loop<R: AsyncRead + Unpin>(
     protocols: Arc<Mutex<HashMap<u8, mpsc::Sender<Bytes>>>>,
-    reader: &OwnedReadHalf,
+    mut reader: R,
+    recorder: Option<Recorder>,
     mut stop_rx: oneshot::Receiver<()>,
 ) -> Result<(), anyhow::Error> {
     let mut buffer = vec![0u8; 1024];
     let mut buffer_offset = 0;
     let mut awaiting_length = true;
     let mut packet_length = 0;
-    let mut running = true;
-    while running {
-        let read = match reader.try_read(&mut buffer[buffer_offset..]) {
-            Ok(count) => count,
-            _ => 0,
+    loop {
+        let read = tokio::select! {
+            result = reader.read(&mut buffer[buffer_offset..]) => result?,
+            _ = &mut stop_rx => break,
         };
+        if read == 0 {
+            // The other end hung up
+            break;
+        }
         buffer_offset += read;
 
         loop {
@@ -101,11 +133,17 @@ async fn read_loop(
             }
 
             if buffer_offset >= packet_length {
+                if let Some(recorder) = &recorder {
+                    recorder.record(Direction::Received, &buffer[..packet_length]);
+                }
                 let tx = {
                     let p = protocols.lock().unwrap();
                     p.get(&buffer[2]).cloned()
                 };
-                if let Some(tx) = tx {
+                if packet_length < 6 {
+                    // Too short to have a header, but the length still says where the next starts
+                    eprintln!("piglet: dropping a {packet_length} byte frame");
+                } else if let Some(tx) = tx {
                     // Remove the framing
                     let data = Bytes::from(buffer[6..packet_length].to_vec());
                     tx.send(data).await?;
@@ -122,13 +160,6 @@ async fn read_loop(
                 break;
             }
         }
-
-        tokio::select! {
-            _ = &mut stop_rx => {
-                running = false;
-            }
-            _ = tokio::time::sleep(Duration::from_millis(if read > 0 { 0 } else { 50 })) => {}
-        }
     }
     Ok(())
 }
@@ -162,21 +193,29 @@ async fn initialize(raw: &Connection) -> Result<(u16, mpsc::Receiver<Bytes>), an
     message.put_u16_le(300);
     raw.write(/* protocol= */ 7, message.freeze())?;
 
-    let mut response = initialize_rx.recv().await.unwrap();
-    let _version = response.get_u8();
-    let message_id = response.get_u8();
+    let response = initialize_rx
+        .recv()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Connection closed during initialization"))?;
+    Ok((read_client_id(response)?, initialize_rx))
+}
+
+// The reply to initialize, which says what the robot calls us
+pub(crate) fn read_client_id(mut response: Bytes) -> Result<u16, anyhow::Error> {
+    let _version = response.try_get_u8()?;
+    let message_id = response.try_get_u8()?;
     if message_id != 0 {
         anyhow::bail!("Expected message ID 0, not {}", message_id);
     }
 
-    let count = response.get_u8();
-    let _unknown = response.get_u8();
-    let mut client_id = 0;
+    let count = response.try_get_u8()?;
+    let _unknown = response.try_get_u8()?;
+    let mut client_id = None;
     for _ in 0..count {
-        let parameter = response.get_u8();
-        let meta = response.get_u8();
-        let code = response.get_u16_le();
-        let value = response.get_u16_le();
+        let parameter = response.try_get_u8()?;
+        let meta = response.try_get_u8()?;
+        let code = response.try_get_u16_le()?;
+        let value = response.try_get_u16_le()?;
         if meta != 17 {
             anyhow::bail!("Expected meta 17, not {}", meta);
         }
@@ -185,11 +224,13 @@ async fn initialize(raw: &Connection) -> Result<(u16, mpsc::Receiver<Bytes>), an
         }
 
         if parameter == 1 {
-            client_id = value;
+            client_id = Some(value);
         }
     }
 
-    Ok((client_id, initialize_rx))
+    // Everything we send is addressed from the client ID, so carrying on without one would only
+    // fail later and more confusingly
+    client_id.ok_or_else(|| anyhow::anyhow!("The robot didn't assign a client ID"))
 }
 
 async fn keep_alive(
@@ -243,24 +284,103 @@ async fn keep_alive(
     }
 }
 
-pub async fn connect<A: ToSocketAddrs>(
-    addr: A,
+// Everything needed to connect to the same robot again
+#[derive(Clone)]
+pub struct Endpoint {
+    addresses: Vec<SocketAddr>,
+    recorder: Option<Recorder>,
+    version: u8,
+}
+
+impl Endpoint {
+    // Frames exchanged over every connection made to the endpoint are written to recording
+    pub async fn resolve<A: ToSocketAddrs>(
+        addr: A,
+        version: u8,
+        recording: Option<&Path>,
+    ) -> Result<Endpoint, anyhow::Error> {
+        let addresses: Vec<SocketAddr> = lookup_host(addr).await?.collect();
+        if addresses.is_empty() {
+            anyhow::bail!("Address did not resolve to anything");
+        }
+        Ok(Endpoint {
+            addresses,
+            recorder: recording.map(Recorder::create).transpose()?,
+            version,
+        })
+    }
+
+    pub async fn connect(&self) -> Result<ConnectionDetails, anyhow::Error> {
+        tokio::time::timeout(CONNECT_TIMEOUT, self.connect_now())
+            .await
+            .map_err(|_| {
+                anyhow::anyhow!("Timed out connecting to the robot after {CONNECT_TIMEOUT:?}")
+            })?
+    }
+
+    async fn connect_now(&self) -> Result<ConnectionDetails, anyhow::Error> {
+        let stream = TcpStream::connect(&self.addresses[..]).await?;
+        // Without keepalives a link that dies quietly, say when a switch reboots, looks idle forever
+        SockRef::from(&stream).set_tcp_keepalive(
+            &TcpKeepalive::new()
+                .with_time(Duration::from_secs(10))
+                .with_interval(Duration::from_secs(5)),
+        )?;
+        let (reader, writer) = stream.into_split();
+        establish(reader, writer, self.version, self.recorder.clone()).await
+    }
+}
+
+pub async fn replay(path: &Path, version: u8) -> Result<ConnectionDetails, anyhow::Error> {
+    let frames = load(path)?;
+    let (client, instrument) = tokio::io::duplex(64 * 1024);
+    let player = tokio::spawn(play(frames, instrument));
+    let (reader, writer) = tokio::io::split(client);
+    let mut details =
+        tokio::time::timeout(CONNECT_TIMEOUT, establish(reader, writer, version, None))
+            .await
+            .map_err(|_| {
+                anyhow::anyhow!("Timed out starting the replay after {CONNECT_TIMEOUT:?}")
+            })??;
+    details.connection.player = Some(player);
+    Ok(details)
+}
+
+async fn establish<R, W>(
+    reader: R,
+    writer: W,
     version: u8,
-) -> Result<ConnectionDetails, anyhow::Error> {
-    let stream = TcpStream::connect(addr).await?;
+    recorder: Option<Recorder>,
+) -> Result<ConnectionDetails, anyhow::Error>
+where
+    R: AsyncRead + Send + Unpin + 'static,
+    W: AsyncWrite + Send + Unpin + 'static,
+{
     let (stop_tx, stop_rx) = oneshot::channel();
-    let (reader, writer) = stream.into_split();
     let (write_tx, write_rx) = mpsc::channel(100);
-    tokio::spawn(write_loop(write_rx, writer));
-    let protocols = Arc::new(Mutex::new(HashMap::new()));
+    let protocols: Arc<Mutex<HashMap<u8, mpsc::Sender<Bytes>>>> =
+        Arc::new(Mutex::new(HashMap::new()));
+
+    // Once either direction fails, dropping every protocol's sender tells its receiver that the
+    // connection is gone
+    let protocols_clone = protocols.clone();
+    let write_recorder = recorder.clone();
+    tokio::spawn(async move {
+        write_loop(write_rx, writer, write_recorder).await;
+        protocols_clone.lock().unwrap().clear();
+    });
     let protocols_clone = protocols.clone();
     let task = tokio::spawn(async move {
-        read_loop(protocols_clone, &reader, stop_rx).await.unwrap();
+        if let Err(e) = read_loop(protocols_clone.clone(), reader, recorder, stop_rx).await {
+            eprintln!("piglet: read error: {e}");
+        }
+        protocols_clone.lock().unwrap().clear();
     });
 
     let last_tx_time = Arc::new(Mutex::new(Instant::now()));
     let connection = Connection {
         last_tx_time: last_tx_time.clone(),
+        player: None,
         protocols,
         stop_tx,
         task,
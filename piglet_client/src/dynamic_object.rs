@@ -1,9 +1,13 @@
-use crate::client::{Error, Error::ConnectionError, RobotClient};
+use crate::client::{
+    Error, Error::InvalidArgument, Error::ProtocolError, RobotClient, with_context,
+};
 use crate::object_address::ObjectAddress;
-use crate::values::{PigletDeserialize, PigletSerialize};
+use crate::values::{PigletDeserialize, PigletSerialize, Value, has_definition, type_name};
 use anyhow::anyhow;
 use bytes::{Buf, Bytes, BytesMut};
+use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::sync::OnceCell;
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Enum {
@@ -28,6 +32,14 @@ pub struct Method {
     pub parameter_types: Vec<u8>,
 }
 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Parameter {
+    pub label: String,
+    pub is_argument: bool,
+    // What it's sent as, which is what Value::type_id returns
+    pub type_id: u8,
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Object {
     pub address: ObjectAddress,
@@ -46,6 +58,7 @@ pub struct Struct {
 
 pub struct DynamicObject {
     address: ObjectAddress,
+    methods: OnceCell<Vec<Method>>,
     robot: Arc<RobotClient>,
 }
 
@@ -53,46 +66,17 @@ impl DynamicObject {
     pub fn new(address: &ObjectAddress, robot: &Arc<RobotClient>) -> Self {
         Self {
             address: address.clone(),
+            methods: OnceCell::new(),
             robot: robot.clone(),
         }
     }
 
     pub async fn get_object(&self) -> Result<Object, Error> {
-        let (count, mut stream) = self.robot.act(&self.address, 0, 0, 1, Bytes::new()).await?;
-        if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
-        }
-        let name = String::deserialize(&mut stream)?;
-        let version = String::deserialize(&mut stream)?;
-        let method_count = u32::deserialize(&mut stream)?;
-        let subobject_count = u16::deserialize(&mut stream)?;
-        Ok(Object {
-            address: self.address.clone(),
-            name,
-            version,
-            method_count,
-            subobject_count,
-        })
+        object_info(&self.robot, &self.address).await
     }
 
     pub async fn get_subobject_address(&self, index: u16) -> Result<ObjectAddress, Error> {
-        let mut args = BytesMut::new();
-        index.serialize(&mut args);
-        let (count, mut stream) = self
-            .robot
-            .act(&self.address, 0, 0, 3, args.freeze())
-            .await?;
-        if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
-        }
-        let module_id = u16::deserialize(&mut stream)?;
-        let node_id = u16::deserialize(&mut stream)?;
-        let object_id = u16::deserialize(&mut stream)?;
-        Ok(ObjectAddress {
-            module_id,
-            node_id,
-            object_id,
-        })
+        subobject_address(&self.robot, &self.address, index).await
     }
 
     pub async fn get_enums(&self, interface_id: u8) -> Result<Vec<Enum>, Error> {
@@ -103,7 +87,7 @@ impl DynamicObject {
             .act(&self.address, 0, 0, 5, args.freeze())
             .await?;
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let names = Vec::<String>::deserialize(&mut stream)?;
         let numbers = Vec::<u32>::deserialize(&mut stream)?;
@@ -112,33 +96,29 @@ impl DynamicObject {
 
         let mut enums = Vec::new();
         let mut offset = 0;
-        for i in 0..names.len() {
-            let count = numbers[i] as usize;
+        for (name, count) in names.into_iter().zip(numbers) {
+            let range = offset..(offset + count as usize);
+            let (Some(labels), Some(values)) = (
+                descriptions.get(range.clone()),
+                enum_values.get(range.clone()),
+            ) else {
+                return Err(ProtocolError(anyhow!(
+                    "Enum {} has more values than were sent",
+                    name
+                )));
+            };
             enums.push(Enum {
-                name: names[i].clone(),
-                labels: descriptions[offset..(offset + count)].to_vec(),
-                values: enum_values[offset..(offset + count)].to_vec(),
+                name,
+                labels: labels.to_vec(),
+                values: values.to_vec(),
             });
-            offset += count;
+            offset = range.end;
         }
         Ok(enums)
     }
 
     pub async fn get_interfaces(&self) -> Result<Vec<Interface>, Error> {
-        let (count, mut stream) = self.robot.act(&self.address, 0, 0, 4, Bytes::new()).await?;
-        if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
-        }
-        let ids = Vec::<u8>::deserialize(&mut stream)?;
-        let descriptions = Vec::<String>::deserialize(&mut stream)?;
-        let mut interfaces = Vec::new();
-        for i in 0..ids.len() {
-            interfaces.push(Interface {
-                id: ids[i],
-                label: descriptions[i].clone(),
-            });
-        }
-        Ok(interfaces)
+        interfaces(&self.robot, &self.address).await
     }
 
     pub async fn get_method(&self, index: u32) -> Result<Method, Error> {
@@ -148,32 +128,7 @@ impl DynamicObject {
             .robot
             .act(&self.address, 0, 0, 2, args.freeze())
             .await?;
-        if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
-        }
-        let interface_id = u8::deserialize(&mut stream)?;
-        let call_type = u8::deserialize(&mut stream)?;
-        let method_id = u16::deserialize(&mut stream)?;
-        let name = String::deserialize(&mut stream)?;
-        let parameter_types = String::deserialize(&mut stream)?.as_bytes().to_vec();
-        let parameter_labels = if stream.remaining() > 0 {
-            let s = String::deserialize(&mut stream)?;
-            if s.len() > 0 {
-                s.split(",").map(|s| s.to_string()).collect()
-            } else {
-                Vec::new()
-            }
-        } else {
-            vec![]
-        };
-        Ok(Method {
-            call_type,
-            interface_id,
-            method_id,
-            name,
-            parameter_labels,
-            parameter_types,
-        })
+        decode_method(count, &mut stream)
     }
 
     pub async fn get_structs(&self, interface_id: u8) -> Result<Vec<Struct>, Error> {
@@ -184,7 +139,7 @@ impl DynamicObject {
             .act(&self.address, 0, 0, 6, args.freeze())
             .await?;
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let names = Vec::<String>::deserialize(&mut stream)?;
         let numbers = Vec::<u32>::deserialize(&mut stream)?;
@@ -194,26 +149,389 @@ impl DynamicObject {
         let mut structs = Vec::new();
         let mut labels_offset = 0;
         let mut types_offset = 0;
-        for i in 0..names.len() {
-            let labels_count = numbers[i] as usize;
+        let too_short =
+            |name: &str| ProtocolError(anyhow!("Struct {} has more elements than were sent", name));
+        for (name, labels_count) in names.into_iter().zip(numbers) {
+            let labels_count = labels_count as usize;
             let mut types_count = 0;
             for _i in 0..labels_count {
-                let jump = match element_types[types_offset + types_count] {
-                    30 => 3,
-                    32 => 3,
-                    _ => 1,
+                let jump = match element_types.get(types_offset + types_count) {
+                    Some(&type_id) if has_definition(type_id) => 3,
+                    Some(_) => 1,
+                    None => return Err(too_short(&name)),
                 };
                 types_count += jump;
             }
+            let (Some(labels), Some(types)) = (
+                element_labels.get(labels_offset..(labels_offset + labels_count)),
+                element_types.get(types_offset..(types_offset + types_count)),
+            ) else {
+                return Err(too_short(&name));
+            };
             structs.push(Struct {
-                name: names[i].clone(),
-                element_labels: element_labels[labels_offset..(labels_offset + labels_count)]
-                    .to_vec(),
-                element_types: element_types[types_offset..(types_offset + types_count)].to_vec(),
+                name,
+                element_labels: labels.to_vec(),
+                element_types: types.to_vec(),
             });
             labels_offset += labels_count;
             types_offset += types_count;
         }
         Ok(structs)
     }
+
+    // Every method the object has, fetched the first time they're needed
+    pub async fn methods(&self) -> Result<&[Method], Error> {
+        let methods = self
+            .methods
+            .get_or_try_init(|| async {
+                let object = self.get_object().await?;
+                let mut methods = Vec::new();
+                for i in 0..object.method_count {
+                    methods.push(self.get_method(i).await?);
+                }
+                self.robot.remember_methods(&self.address, &methods);
+                Ok::<_, Error>(methods)
+            })
+            .await?;
+        Ok(methods)
+    }
+
+    // Looks a method up by name. Where several interfaces have a method with the same name, add
+    // _1, _2 and so on to pick one, the same way the generated code names them.
+    pub async fn find_method(&self, method_name: &str) -> Result<&Method, Error> {
+        let methods = self.methods().await?;
+        let names = method_names(methods.iter().map(|m| m.name.as_str()));
+        if let Some(i) = names.iter().position(|name| name == method_name) {
+            return Ok(&methods[i]);
+        }
+        let count = methods.iter().filter(|m| m.name == method_name).count();
+        if count > 1 {
+            return Err(InvalidArgument(anyhow!(
+                "There's more than one {}, call {}_1 to {}_{} instead",
+                method_name,
+                method_name,
+                method_name,
+                count
+            )));
+        }
+        Err(InvalidArgument(anyhow!("No method called {}", method_name)))
+    }
+
+    pub async fn call(
+        &self,
+        method_name: &str,
+        arguments: Vec<Value>,
+    ) -> Result<Vec<Value>, Error> {
+        let method = self.find_method(method_name).await?;
+        self.call_method(method, arguments).await
+    }
+
+    // Checks the arguments against the method's parameter types, then decodes whatever it returns
+    pub async fn call_method(
+        &self,
+        method: &Method,
+        arguments: Vec<Value>,
+    ) -> Result<Vec<Value>, Error> {
+        let (expected, returns): (Vec<_>, Vec<_>) = method
+            .parameters()?
+            .into_iter()
+            .partition(|p| p.is_argument);
+        if arguments.len() != expected.len() {
+            return Err(InvalidArgument(anyhow!(
+                "{} takes {} arguments, not {}",
+                method.name,
+                expected.len(),
+                arguments.len()
+            )));
+        }
+        let mut args = BytesMut::new();
+        for (argument, parameter) in arguments.iter().zip(expected) {
+            if argument.type_id() != parameter.type_id {
+                return Err(InvalidArgument(anyhow!(
+                    "Argument {} of {} should be {} but is {:?}",
+                    parameter.label,
+                    method.name,
+                    type_name(parameter.type_id),
+                    argument
+                )));
+            }
+            argument.serialize(&mut args);
+        }
+
+        let (count, mut stream) = with_context(
+            self.robot
+                .act(
+                    &self.address,
+                    method.interface_id,
+                    method.call_type,
+                    method.method_id,
+                    args.freeze(),
+                )
+                .await,
+            || format!("in call to {}({:?})", method.name, arguments),
+        )?;
+        if count as usize != returns.len() {
+            return Err(ProtocolError(anyhow!(
+                "Expected {} values, not {}",
+                returns.len(),
+                count
+            )));
+        }
+        let mut values = Vec::new();
+        for parameter in returns {
+            let value = Value::deserialize(&mut stream)?;
+            if value.type_id() != parameter.type_id {
+                return Err(ProtocolError(anyhow!(
+                    "Expected {} to be {} but got {:?}",
+                    parameter.label,
+                    type_name(parameter.type_id),
+                    value
+                )));
+            }
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+// These take the client itself rather than an Arc so it can look around before it's shared
+pub(crate) async fn object_info(
+    robot: &RobotClient,
+    address: &ObjectAddress,
+) -> Result<Object, Error> {
+    let (count, mut stream) = robot.act(address, 0, 0, 1, Bytes::new()).await?;
+    if count != 4 {
+        return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
+    }
+    let name = String::deserialize(&mut stream)?;
+    let version = String::deserialize(&mut stream)?;
+    let method_count = u32::deserialize(&mut stream)?;
+    let subobject_count = u16::deserialize(&mut stream)?;
+    Ok(Object {
+        address: address.clone(),
+        name,
+        version,
+        method_count,
+        subobject_count,
+    })
+}
+
+pub(crate) async fn subobject_address(
+    robot: &RobotClient,
+    address: &ObjectAddress,
+    index: u16,
+) -> Result<ObjectAddress, Error> {
+    let mut args = BytesMut::new();
+    index.serialize(&mut args);
+    let (count, mut stream) = robot.act(address, 0, 0, 3, args.freeze()).await?;
+    if count != 3 {
+        return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
+    }
+    let module_id = u16::deserialize(&mut stream)?;
+    let node_id = u16::deserialize(&mut stream)?;
+    let object_id = u16::deserialize(&mut stream)?;
+    Ok(ObjectAddress {
+        module_id,
+        node_id,
+        object_id,
+    })
+}
+
+pub(crate) async fn interfaces(
+    robot: &RobotClient,
+    address: &ObjectAddress,
+) -> Result<Vec<Interface>, Error> {
+    let (count, mut stream) = robot.act(address, 0, 0, 4, Bytes::new()).await?;
+    if count != 2 {
+        return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
+    }
+    let ids = Vec::<u8>::deserialize(&mut stream)?;
+    let descriptions = Vec::<String>::deserialize(&mut stream)?;
+    if ids.len() != descriptions.len() {
+        return Err(ProtocolError(anyhow!(
+            "Got {} interface ids but {} descriptions",
+            ids.len(),
+            descriptions.len()
+        )));
+    }
+    Ok(ids
+        .into_iter()
+        .zip(descriptions)
+        .map(|(id, label)| Interface { id, label })
+        .collect())
+}
+
+// The reply to method_info, which is also how RobotClient names the methods in errors
+pub(crate) fn decode_method(count: u8, stream: &mut Bytes) -> Result<Method, Error> {
+    if count != 6 {
+        return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
+    }
+    let interface_id = u8::deserialize(stream)?;
+    let call_type = u8::deserialize(stream)?;
+    let method_id = u16::deserialize(stream)?;
+    let name = String::deserialize(stream)?;
+    let parameter_types = String::deserialize(stream)?.as_bytes().to_vec();
+    let parameter_labels = if stream.remaining() > 0 {
+        let s = String::deserialize(stream)?;
+        if !s.is_empty() {
+            s.split(",").map(|s| s.to_string()).collect()
+        } else {
+            Vec::new()
+        }
+    } else {
+        vec![]
+    };
+    Ok(Method {
+        call_type,
+        interface_id,
+        method_id,
+        name,
+        parameter_labels,
+        parameter_types,
+    })
+}
+
+// The paths of a set of siblings under prefix: their names, with [1], [2] and so on added where
+// several share a name, like Channel[2]
+pub fn sibling_paths<'a>(prefix: &str, names: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let names: Vec<&str> = names.into_iter().collect();
+    let mut seen = HashMap::<&str, usize>::new();
+    names
+        .iter()
+        .map(|name| {
+            let n = seen.entry(name).or_default();
+            *n += 1;
+            if names.iter().filter(|other| *other == name).count() > 1 {
+                format!("{}{}[{}]", prefix, name, n)
+            } else {
+                format!("{}{}", prefix, name)
+            }
+        })
+        .collect()
+}
+
+// The names methods get, in the same order: their own, with _1, _2 and so on added where several
+// share one. The generated bindings, find_method and the codegen's diff all number them this way.
+pub fn method_names<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let names: Vec<&str> = names.into_iter().collect();
+    let mut seen = HashMap::<&str, usize>::new();
+    names
+        .iter()
+        .map(|name| {
+            let n = seen.entry(name).or_default();
+            *n += 1;
+            if names.iter().filter(|other| *other == name).count() > 1 {
+                format!("{}_{}", name, n)
+            } else {
+                name.to_string()
+            }
+        })
+        .collect()
+}
+
+// Where a parameter goes in a call
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Position {
+    Argument,
+    // One of several values in the reply
+    ReturnElement,
+    // The only value in the reply
+    ReturnValue,
+}
+
+// The codes get_method reports that we've seen on a robot, with the type id each is sent as. The
+// numbering has a pattern, but codes that haven't been seen aren't guessed at. 64 and 85 return
+// arrays, like GetTrackLedStates giving every LED's state.
+const PARAMETER_TYPES: &[(u8, Position, u8)] = &[
+    (1, Position::Argument, 1),
+    (2, Position::Argument, 4),
+    (3, Position::Argument, 2),
+    (4, Position::Argument, 5),
+    (5, Position::Argument, 3),
+    (6, Position::Argument, 6),
+    (7, Position::Argument, 15),
+    (8, Position::Argument, 22),
+    (18, Position::ReturnElement, 4),
+    (19, Position::ReturnElement, 2),
+    (20, Position::ReturnElement, 5),
+    (21, Position::ReturnElement, 3),
+    (22, Position::ReturnElement, 6),
+    (23, Position::ReturnElement, 15),
+    (24, Position::ReturnElement, 22),
+    (25, Position::ReturnValue, 1),
+    (26, Position::ReturnValue, 4),
+    (27, Position::ReturnValue, 2),
+    (28, Position::ReturnValue, 5),
+    (29, Position::ReturnValue, 3),
+    (30, Position::ReturnValue, 6),
+    (31, Position::ReturnValue, 15),
+    (32, Position::ReturnValue, 22),
+    (33, Position::Argument, 23),
+    (35, Position::ReturnElement, 23),
+    (36, Position::ReturnValue, 23),
+    (41, Position::Argument, 25),
+    (43, Position::ReturnElement, 25),
+    (44, Position::ReturnValue, 25),
+    (45, Position::Argument, 26),
+    (47, Position::ReturnElement, 26),
+    (48, Position::ReturnValue, 26),
+    (49, Position::Argument, 27),
+    (51, Position::ReturnElement, 27),
+    (52, Position::ReturnValue, 27),
+    (53, Position::Argument, 28),
+    (55, Position::ReturnElement, 28),
+    (56, Position::ReturnValue, 28),
+    (60, Position::ReturnValue, 30),
+    (61, Position::Argument, 31),
+    (64, Position::ReturnValue, 31),
+    (66, Position::Argument, 29),
+    (68, Position::ReturnElement, 29),
+    (69, Position::ReturnValue, 29),
+    (76, Position::ReturnElement, 34),
+    (78, Position::Argument, 32),
+    (81, Position::ReturnValue, 32),
+    (82, Position::Argument, 35),
+    (85, Position::ReturnValue, 35),
+    (102, Position::Argument, 40),
+    (104, Position::ReturnElement, 40),
+    (105, Position::ReturnValue, 40),
+];
+
+// Reads a parameter type code from get_method as where the parameter goes and the type id it's
+// sent as, or None if it isn't one we've seen
+pub fn parameter_type(code: u8) -> Option<(Position, u8)> {
+    PARAMETER_TYPES
+        .iter()
+        .find(|(c, _, _)| *c == code)
+        .map(|&(_, position, type_id)| (position, type_id))
+}
+
+impl Method {
+    // The method's arguments and return values with the type ids they have on the wire, worked
+    // out from the parameter types get_method reports
+    pub fn parameters(&self) -> Result<Vec<Parameter>, Error> {
+        let mut parameters = Vec::new();
+        let mut i = 0;
+        while i < self.parameter_types.len() {
+            let code = self.parameter_types[i];
+            let Some((position, type_id)) = parameter_type(code) else {
+                return Err(ProtocolError(anyhow!(
+                    "{} has a parameter with unverified type code {}",
+                    self.name,
+                    code
+                )));
+            };
+            parameters.push(Parameter {
+                label: self
+                    .parameter_labels
+                    .get(parameters.len())
+                    .cloned()
+                    .unwrap_or_default(),
+                is_argument: position == Position::Argument,
+                type_id,
+            });
+            i += if has_definition(type_id) { 3 } else { 1 };
+        }
+        Ok(parameters)
+    }
 }
@@ -0,0 +1,134 @@
+use crate::client::{Error, Error::ProtocolError};
+use crate::object_address::ObjectAddress;
+use crate::values::PigletDeserialize;
+use anyhow::anyhow;
+use bytes::Bytes;
+use futures_core::Stream;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
+
+// A message the robot sent without being asked, like a sensor change or a button press
+#[derive(Clone, Debug)]
+pub struct Event {
+    pub source: ObjectAddress,
+    pub action: u8,
+    pub interface_id: u8,
+    pub call_type: u8,
+    pub call_type_id: u16,
+    pub count: u8,
+    pub parameters: Bytes,
+}
+
+// A kind of event, implemented by the generated Event structs. Objects can send several kinds, like
+// the deck's track sensor and gantry state events, which the robot tells apart by these ids.
+pub trait PigletEvent: PigletDeserialize {
+    const INTERFACE_ID: u8;
+    const CALL_TYPE_ID: u16;
+}
+
+pub(crate) struct Subscriber {
+    // The interface and call type ids of the only events wanted, or None for all of them
+    kind: Option<(u8, u16)>,
+    tx: mpsc::UnboundedSender<Event>,
+}
+
+pub(crate) type Subscribers = Arc<Mutex<HashMap<ObjectAddress, Vec<Subscriber>>>>;
+
+// Returns whether anyone was listening for the event
+pub(crate) fn dispatch(subscribers: &Subscribers, event: Event) -> bool {
+    let mut subscribers = subscribers.lock().unwrap();
+    let Some(listening) = subscribers.get_mut(&event.source) else {
+        return false;
+    };
+    let kind = (event.interface_id, event.call_type_id);
+    let mut delivered = false;
+    listening.retain(|subscriber| {
+        if subscriber.kind.is_some_and(|wanted| wanted != kind) {
+            return !subscriber.tx.is_closed();
+        }
+        let sent = subscriber.tx.send(event.clone()).is_ok();
+        delivered |= sent;
+        sent
+    });
+    if listening.is_empty() {
+        subscribers.remove(&event.source);
+    }
+    delivered
+}
+
+pub(crate) fn add(
+    subscribers: &Subscribers,
+    source: &ObjectAddress,
+    kind: Option<(u8, u16)>,
+) -> Events {
+    let (tx, receiver) = mpsc::unbounded_channel();
+    subscribers
+        .lock()
+        .unwrap()
+        .entry(source.clone())
+        .or_default()
+        .push(Subscriber { kind, tx });
+    Events { receiver }
+}
+
+// Events sent by one object, undecoded. Ends when the connection closes.
+pub struct Events {
+    receiver: mpsc::UnboundedReceiver<Event>,
+}
+
+impl Events {
+    pub async fn recv(&mut self) -> Option<Event> {
+        self.receiver.recv().await
+    }
+}
+
+impl Stream for Events {
+    type Item = Event;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Event>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+// One kind of event from one object, decoded as T
+pub struct Subscription<T> {
+    events: Events,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T: PigletEvent> Subscription<T> {
+    pub(crate) fn new(events: Events) -> Self {
+        Self {
+            events,
+            _type: PhantomData,
+        }
+    }
+
+    pub async fn recv(&mut self) -> Option<Result<T, Error>> {
+        self.events.recv().await.map(decode)
+    }
+}
+
+impl<T: PigletEvent> Stream for Subscription<T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.events)
+            .poll_next(cx)
+            .map(|event| event.map(decode))
+    }
+}
+
+fn decode<T: PigletEvent>(mut event: Event) -> Result<T, Error> {
+    if event.count == 0 {
+        return Err(ProtocolError(anyhow!(
+            "Event from {} carried no values",
+            event.source
+        )));
+    }
+    T::deserialize(&mut event.parameters)
+}
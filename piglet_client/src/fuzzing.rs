@@ -0,0 +1,44 @@
+// Entry points for the targets in piglet_client/fuzz, which can only reach public functions. Each
+// decodes whatever it's given and throws the result away, since all that matters is not panicking.
+use crate::client::{read_frame, read_registration};
+use crate::connection::{read_client_id, read_loop};
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, oneshot};
+
+pub fn frame(data: &[u8]) {
+    let _ = read_frame(Bytes::copy_from_slice(data));
+}
+
+pub fn registration(data: &[u8]) {
+    let _ = read_registration(Bytes::copy_from_slice(data));
+}
+
+pub fn initialization(data: &[u8]) {
+    let _ = read_client_id(Bytes::copy_from_slice(data));
+}
+
+// Splits data into frames the way a connection reads them off the socket
+pub fn stream(data: &[u8]) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let protocols = Arc::new(Mutex::new(HashMap::new()));
+        let mut drains = Vec::new();
+        for protocol in [6, 7] {
+            let (tx, mut rx) = mpsc::channel::<Bytes>(100);
+            protocols.lock().unwrap().insert(protocol, tx);
+            drains.push(tokio::spawn(
+                async move { while rx.recv().await.is_some() {} },
+            ));
+        }
+        let (_stop_tx, stop_rx) = oneshot::channel();
+        let _ = read_loop(protocols.clone(), data, None, stop_rx).await;
+        protocols.lock().unwrap().clear();
+        for drain in drains {
+            let _ = drain.await;
+        }
+    });
+}
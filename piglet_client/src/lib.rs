@@ -1,5 +1,23 @@
 pub mod client;
+pub mod compatibility;
 mod connection;
 pub mod dynamic_object;
+pub mod events;
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing;
 pub mod object_address;
+pub mod object_tree;
+mod recording;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod units;
 pub mod values;
+
+// What code from #[derive(PigletCodec)] refers to, so crates using it don't need their own
+// dependencies on these
+#[doc(hidden)]
+pub mod derive {
+    pub use crate::client::Error;
+    pub use anyhow::anyhow;
+    pub use bytes::{Buf, BufMut, Bytes, BytesMut};
+}
@@ -0,0 +1,128 @@
+use crate::client::{Error, Error::InvalidArgument, RobotClient};
+use crate::dynamic_object::{
+    Interface, Object, interfaces, object_info, sibling_paths, subobject_address,
+};
+use crate::object_address::ObjectAddress;
+use anyhow::anyhow;
+
+// Every object on the robot, read the first time the client is asked for it
+#[derive(Clone, Debug, Default)]
+pub struct ObjectTree {
+    pub roots: Vec<ObjectNode>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ObjectNode {
+    pub object: Object,
+    // The names leading to the object from a root, like NimbusCore.Pipette. Siblings that share a
+    // name are numbered from 1, like NimbusCore.Channel[2].
+    pub path: String,
+    // None when the object wouldn't describe its interfaces
+    pub interfaces: Option<Vec<Interface>>,
+    pub children: Vec<ObjectNode>,
+}
+
+impl ObjectTree {
+    // Objects that don't answer are left out along with everything under them rather than failing
+    // the whole walk, and reported on stderr
+    pub(crate) async fn walk(robot: &RobotClient) -> Self {
+        let roots: Vec<ObjectAddress> = robot
+            .globals
+            .iter()
+            .chain(&robot.objects)
+            .cloned()
+            .collect();
+        Self {
+            roots: walk_siblings(robot, "", &roots).await,
+        }
+    }
+
+    // Every object, each followed by everything under it
+    pub fn iter(&self) -> impl Iterator<Item = &ObjectNode> {
+        let mut stack: Vec<&ObjectNode> = self.roots.iter().rev().collect();
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    // Names are matched ignoring case
+    pub fn get(&self, path: &str) -> Option<&ObjectNode> {
+        self.iter().find(|node| node.path == path).or_else(|| {
+            self.iter()
+                .find(|node| node.path.eq_ignore_ascii_case(path))
+        })
+    }
+
+    pub fn find(&self, address: &ObjectAddress) -> Option<&ObjectNode> {
+        self.iter().find(|node| &node.object.address == address)
+    }
+
+    // Like get, but explains what went wrong
+    pub fn resolve(&self, path: &str) -> Result<&ObjectNode, Error> {
+        if let Some(node) = self.get(path) {
+            return Ok(node);
+        }
+
+        let numbered = format!("{}[", path.to_ascii_lowercase());
+        let count = self
+            .iter()
+            .filter(|node| {
+                node.path
+                    .to_ascii_lowercase()
+                    .strip_prefix(&numbered)
+                    .is_some_and(|rest| rest.ends_with(']') && !rest.contains('.'))
+            })
+            .count();
+        if count > 0 {
+            Err(InvalidArgument(anyhow!(
+                "There are {} objects at {}, pick one with {}[1] to {}[{}]",
+                count,
+                path,
+                path,
+                path,
+                count
+            )))
+        } else {
+            Err(InvalidArgument(anyhow!("There's no object at {}", path)))
+        }
+    }
+}
+
+async fn walk_siblings(
+    robot: &RobotClient,
+    prefix: &str,
+    addresses: &[ObjectAddress],
+) -> Vec<ObjectNode> {
+    let mut objects = Vec::new();
+    for address in addresses {
+        match object_info(robot, address).await {
+            Ok(object) => objects.push(object),
+            Err(e) => eprintln!("piglet: leaving {address} out of the object tree: {e}"),
+        }
+    }
+
+    let paths = sibling_paths(prefix, objects.iter().map(|o| o.name.as_str()));
+    let mut nodes = Vec::new();
+    for (path, object) in paths.into_iter().zip(objects) {
+        let interfaces = interfaces(robot, &object.address).await.ok();
+        let mut subobjects = Vec::new();
+        for i in 0..object.subobject_count {
+            match subobject_address(robot, &object.address, i).await {
+                Ok(subobject) => subobjects.push(subobject),
+                Err(e) => {
+                    eprintln!("piglet: leaving subobject {i} of {path} out of the object tree: {e}")
+                }
+            }
+        }
+        let children = Box::pin(walk_siblings(robot, &format!("{}.", path), &subobjects)).await;
+        nodes.push(ObjectNode {
+            object,
+            path,
+            interfaces,
+            children,
+        });
+    }
+    nodes
+}
@@ -0,0 +1,164 @@
+use crate::object_address::ObjectAddress;
+use bytes::Bytes;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt, DuplexStream},
+    time::Instant,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Direction {
+    Received,
+    Sent,
+}
+
+// Writes one line per frame: elapsed microseconds, direction, some decoded header fields for
+// humans and finally the raw frame in hex, which is the only part replay relies on.
+#[derive(Clone)]
+pub(crate) struct Recorder {
+    file: Arc<Mutex<BufWriter<File>>>,
+    start: Instant,
+}
+
+impl Recorder {
+    pub(crate) fn create(path: &Path) -> Result<Self, anyhow::Error> {
+        let mut file = BufWriter::new(File::create(path)?);
+        writeln!(file, "# piglet recording v1")?;
+        file.flush()?;
+        Ok(Self {
+            file: Arc::new(Mutex::new(file)),
+            start: Instant::now(),
+        })
+    }
+
+    pub(crate) fn record(&self, direction: Direction, frame: &[u8]) {
+        let line = format!(
+            "{:012} {} {} {}",
+            self.start.elapsed().as_micros(),
+            match direction {
+                Direction::Received => "rx",
+                Direction::Sent => "tx",
+            },
+            describe(frame),
+            frame
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>()
+        );
+        let mut file = self.file.lock().unwrap();
+        if let Err(e) = writeln!(file, "{line}").and_then(|_| file.flush()) {
+            eprintln!("piglet: failed to record frame: {e}");
+        }
+    }
+}
+
+fn describe(frame: &[u8]) -> String {
+    if frame.len() < 3 {
+        return "protocol=?".to_string();
+    }
+    let protocol = frame[2];
+    if protocol == 6 && frame.len() > 6 + 6 + 6 {
+        let mut header = Bytes::copy_from_slice(&frame[6..18]);
+        if let (Ok(source), Ok(destination)) = (
+            ObjectAddress::from_bytes(&mut header),
+            ObjectAddress::from_bytes(&mut header),
+        ) {
+            return format!(
+                "protocol={} src={} dst={} id={}",
+                protocol, source, destination, frame[18]
+            );
+        }
+    }
+    format!("protocol={}", protocol)
+}
+
+pub(crate) fn load(path: &Path) -> Result<Vec<(Direction, Bytes)>, anyhow::Error> {
+    let mut frames = Vec::new();
+    for (i, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+        let line = line?;
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() < 3 {
+            anyhow::bail!("Malformed recording line {}", i + 1);
+        }
+        let direction = match parts[1] {
+            "rx" => Direction::Received,
+            "tx" => Direction::Sent,
+            other => anyhow::bail!("Unknown direction {} on line {}", other, i + 1),
+        };
+        let hex = parts[parts.len() - 1];
+        if !hex.len().is_multiple_of(2) {
+            anyhow::bail!("Odd number of hex digits on line {}", i + 1);
+        }
+        let frame = (0..hex.len())
+            .step_by(2)
+            .map(|j| {
+                let digits = hex
+                    .get(j..j + 2)
+                    .ok_or_else(|| anyhow::anyhow!("Bad hex on line {}", i + 1))?;
+                Ok(u8::from_str_radix(digits, 16)?)
+            })
+            .collect::<Result<Vec<u8>, anyhow::Error>>()?;
+        frames.push((direction, Bytes::from(frame)));
+    }
+    Ok(frames)
+}
+
+// Keep-alives depend on wall clock timing, so replay neither expects nor checks them. They're
+// connection messages (protocol 7) whose first fragment has type 3, which sits after the framing
+// (length, protocol, version and two zeros) and the message's version, ID, count and padding.
+fn is_keep_alive(frame: &[u8]) -> bool {
+    frame.len() > 10 && frame[2] == 7 && frame[10] == 3
+}
+
+// Plays the instrument's side of a recording: every time the client sends the frame we expect,
+// answer with whatever the instrument sent before the client's next frame. Fails, hanging up on
+// the client, as soon as it sends something else.
+pub(crate) async fn play(
+    frames: Vec<(Direction, Bytes)>,
+    mut stream: DuplexStream,
+) -> Result<(), anyhow::Error> {
+    let mut position = 0;
+    loop {
+        while position < frames.len() {
+            match &frames[position] {
+                (Direction::Received, frame) => {
+                    if stream.write_all(frame).await.is_err() {
+                        return Ok(());
+                    }
+                }
+                (Direction::Sent, frame) if is_keep_alive(frame) => {}
+                _ => break,
+            }
+            position += 1;
+        }
+
+        // The client hanging up is how a replay normally ends
+        let length = match stream.read_u16_le().await {
+            Ok(length) => length,
+            Err(_) => return Ok(()),
+        };
+        let mut frame = vec![0u8; 2 + length as usize];
+        frame[..2].copy_from_slice(&length.to_le_bytes());
+        if stream.read_exact(&mut frame[2..]).await.is_err() {
+            return Ok(());
+        }
+        if is_keep_alive(&frame) {
+            continue;
+        }
+
+        match frames.get(position) {
+            Some((Direction::Sent, expected)) if expected[..] == frame[..] => position += 1,
+            Some(_) => anyhow::bail!(
+                "Replay diverged from the recording at frame {}",
+                position + 1
+            ),
+            None => anyhow::bail!("Replay ran past the end of the recording"),
+        }
+    }
+}
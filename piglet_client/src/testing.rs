@@ -0,0 +1,727 @@
+use crate::client::{Error, Error::ConnectionError};
+use crate::connection::frame_message;
+use crate::dynamic_object::{Enum, Interface, Method, Struct};
+use crate::events::Event;
+use crate::object_address::ObjectAddress;
+use crate::values::{ErrorCode, PigletCodec, PigletDeserialize, PigletSerialize};
+use anyhow::anyhow;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream, tcp::OwnedReadHalf},
+    sync::{Notify, mpsc, oneshot},
+    task::JoinSet,
+};
+
+// Reported for calls that have no handler and aren't introspection calls the simulator can answer
+const NOT_HANDLED: ErrorCode = ErrorCode(0xffff);
+
+// The client never sends a request with message ID 0, so an event sent with it can't be taken for
+// the reply to one
+const EVENT_ID: u8 = 0;
+
+type Handler = Box<dyn Fn(Bytes) -> Result<(u8, Bytes), ErrorCode> + Send + Sync>;
+
+#[derive(Clone, Debug, Default)]
+pub struct SimulatedObject {
+    pub name: String,
+    pub version: String,
+    // None makes interface introspection fail, like objects that don't support it
+    pub interfaces: Option<Vec<Interface>>,
+    pub methods: Vec<Method>,
+    pub enums: HashMap<u8, Vec<Enum>>,
+    pub structs: HashMap<u8, Vec<Struct>>,
+    pub subobjects: Vec<ObjectAddress>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SimulatedCall {
+    // The client that made the call, which changes when it reconnects
+    pub source: ObjectAddress,
+    pub destination: ObjectAddress,
+    pub interface_id: u8,
+    pub call_type: u8,
+    pub call_type_id: u16,
+    pub parameters: Bytes,
+}
+
+#[derive(Default)]
+pub struct SimulatorBuilder {
+    delays: HashMap<(ObjectAddress, u8, u16), Duration>,
+    globals: Vec<ObjectAddress>,
+    handlers: HashMap<(ObjectAddress, u8, u16), Handler>,
+    objects: HashMap<ObjectAddress, SimulatedObject>,
+    roots: Vec<ObjectAddress>,
+}
+
+impl SimulatorBuilder {
+    pub fn global(mut self, object_id: u16, object: SimulatedObject) -> Self {
+        let address = root_address(object_id);
+        self.globals.push(address.clone());
+        self.objects.insert(address, object);
+        self
+    }
+
+    pub fn root(mut self, object_id: u16, object: SimulatedObject) -> Self {
+        let address = root_address(object_id);
+        self.roots.push(address.clone());
+        self.objects.insert(address, object);
+        self
+    }
+
+    pub fn object(mut self, address: &ObjectAddress, object: SimulatedObject) -> Self {
+        self.objects.insert(address.clone(), object);
+        self
+    }
+
+    pub fn on<F>(
+        mut self,
+        address: &ObjectAddress,
+        interface_id: u8,
+        call_type_id: u16,
+        f: F,
+    ) -> Self
+    where
+        F: Fn(Bytes) -> Result<(u8, Bytes), ErrorCode> + Send + Sync + 'static,
+    {
+        self.handlers
+            .insert((address.clone(), interface_id, call_type_id), Box::new(f));
+        self
+    }
+
+    // Holds back replies to a call for delay, like a slow motion would. Other calls are still
+    // answered in the meantime.
+    pub fn delay(
+        mut self,
+        address: &ObjectAddress,
+        interface_id: u8,
+        call_type_id: u16,
+        delay: Duration,
+    ) -> Self {
+        self.delays
+            .insert((address.clone(), interface_id, call_type_id), delay);
+        self
+    }
+
+    pub async fn start(self) -> Result<Simulator, anyhow::Error> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let state = Arc::new(State {
+            calls: calls.clone(),
+            delays: self.delays,
+            globals: self.globals,
+            handlers: self.handlers,
+            disconnect: Notify::new(),
+            next_client_id: Mutex::new(1),
+            objects: self.objects,
+            roots: self.roots,
+            sessions: Mutex::new(HashMap::new()),
+        });
+        let (stop_tx, stop_rx) = oneshot::channel();
+        let task = tokio::spawn(accept_loop(listener, state.clone(), stop_rx));
+        Ok(Simulator {
+            address,
+            calls,
+            state,
+            stop_tx,
+            task,
+        })
+    }
+}
+
+pub struct Simulator {
+    address: SocketAddr,
+    calls: Arc<Mutex<Vec<SimulatedCall>>>,
+    state: Arc<State>,
+    stop_tx: oneshot::Sender<()>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl Simulator {
+    pub fn builder() -> SimulatorBuilder {
+        SimulatorBuilder::default()
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    pub fn calls(&self) -> Vec<SimulatedCall> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    // Drops every open connection, like a network outage would. New connections still work.
+    pub fn disconnect(&self) {
+        self.state.disconnect.notify_waiters();
+    }
+
+    // Sends an unsolicited message to every connected client, exactly as described by event
+    pub fn emit(&self, event: &Event) {
+        let mut payload = BytesMut::new();
+        payload.put_u8(event.interface_id);
+        payload.put_u8(event.call_type);
+        payload.put_u16_le(event.call_type_id);
+        payload.put_u8(0);
+        payload.put_u8(event.count);
+        payload.put(event.parameters.clone());
+        let payload = payload.freeze();
+        for (client_id, tx) in self.state.sessions.lock().unwrap().iter() {
+            let frame = robot_frame(
+                &event.source,
+                &client_address(*client_id),
+                EVENT_ID,
+                2,
+                event.action,
+                Bytes::new(),
+                payload.clone(),
+            );
+            let _ = tx.send(frame_message(6, 48, frame));
+        }
+    }
+
+    pub async fn close(self) -> Result<(), anyhow::Error> {
+        self.stop_tx
+            .send(())
+            .map_err(|_| anyhow::anyhow!("Failed to send stop signal"))?;
+        self.task.await?;
+        Ok(())
+    }
+}
+
+// A call made on one of the generated fakes, with each argument as it prints with {:?}
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordedCall {
+    pub method: &'static str,
+    pub arguments: Vec<(&'static str, String)>,
+}
+
+impl RecordedCall {
+    pub fn argument(&self, name: &str) -> Option<&str> {
+        self.arguments
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+// A queued reply, with the name of its type for when a call expects another
+type Reply = (&'static str, Box<dyn Any + Send>);
+
+// What the generated fakes, like NimbusCorePipetteFake, keep their calls and queued replies in.
+// Clones share both.
+#[derive(Clone, Default)]
+pub struct Recorder {
+    calls: Arc<Mutex<Vec<RecordedCall>>>,
+    replies: Arc<Mutex<HashMap<&'static str, VecDeque<Reply>>>>,
+}
+
+impl Recorder {
+    // Queues a reply for a method by its Rust name, like get_position. Replies to the same method
+    // are used in the order they were queued.
+    pub fn reply<T: Send + 'static>(&self, method: &'static str, reply: Result<T, Error>) {
+        self.replies
+            .lock()
+            .unwrap()
+            .entry(method)
+            .or_default()
+            .push_back((std::any::type_name::<T>(), Box::new(reply)));
+    }
+
+    pub fn calls(&self) -> Vec<RecordedCall> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    // Records a call and takes the next reply queued for it. Methods that return nothing succeed
+    // without one.
+    pub fn record<T: 'static>(
+        &self,
+        method: &'static str,
+        arguments: Vec<(&'static str, String)>,
+    ) -> Result<T, Error> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(RecordedCall { method, arguments });
+        let queued = self
+            .replies
+            .lock()
+            .unwrap()
+            .get_mut(method)
+            .and_then(VecDeque::pop_front);
+        let Some((type_name, reply)) = queued else {
+            let unit: Box<dyn Any + Send> = Box::new(Ok::<(), Error>(()));
+            return unit
+                .downcast::<Result<T, Error>>()
+                .map(|r| *r)
+                .map_err(|_| {
+                    ConnectionError(anyhow!("No reply was queued for a call to {}", method))
+                })?;
+        };
+        reply
+            .downcast::<Result<T, Error>>()
+            .map(|r| *r)
+            .map_err(|_| {
+                ConnectionError(anyhow!(
+                    "The reply queued for {} is a {}, not a {}",
+                    method,
+                    type_name,
+                    std::any::type_name::<T>()
+                ))
+            })?
+    }
+}
+
+struct State {
+    calls: Arc<Mutex<Vec<SimulatedCall>>>,
+    delays: HashMap<(ObjectAddress, u8, u16), Duration>,
+    disconnect: Notify,
+    globals: Vec<ObjectAddress>,
+    handlers: HashMap<(ObjectAddress, u8, u16), Handler>,
+    next_client_id: Mutex<u16>,
+    objects: HashMap<ObjectAddress, SimulatedObject>,
+    roots: Vec<ObjectAddress>,
+    // Outgoing frames for each connected client, keyed by client ID
+    sessions: Mutex<HashMap<u16, mpsc::UnboundedSender<Bytes>>>,
+}
+
+fn client_address(client_id: u16) -> ObjectAddress {
+    ObjectAddress {
+        module_id: 2,
+        node_id: client_id,
+        object_id: 65535,
+    }
+}
+
+fn root_address(object_id: u16) -> ObjectAddress {
+    // Registration only reports object IDs, so roots always live on 1-1
+    ObjectAddress {
+        module_id: 1,
+        node_id: 1,
+        object_id,
+    }
+}
+
+async fn accept_loop(listener: TcpListener, state: Arc<State>, mut stop_rx: oneshot::Receiver<()>) {
+    // Dropping the set when we stop aborts every open session
+    let mut sessions = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                match accepted {
+                    Ok((stream, _)) => {
+                        let state = state.clone();
+                        sessions.spawn(async move {
+                            if let Err(e) = serve(stream, state).await {
+                                eprintln!("piglet: simulator session failed: {e}");
+                            }
+                        });
+                    }
+                    Err(e) => eprintln!("piglet: simulator accept failed: {e}"),
+                }
+            }
+            _ = &mut stop_rx => break,
+        }
+    }
+}
+
+async fn serve(stream: TcpStream, state: Arc<State>) -> Result<(), anyhow::Error> {
+    let client_id = {
+        let mut next = state.next_client_id.lock().unwrap();
+        let id = *next;
+        *next = next.wrapping_add(1);
+        id
+    };
+
+    // Replies and events share one writer so their frames never interleave
+    let (mut reader, mut writer) = stream.into_split();
+    let (tx, mut rx) = mpsc::unbounded_channel::<Bytes>();
+    let writer_task = tokio::spawn(async move {
+        while let Some(frame) = rx.recv().await {
+            if writer.write_all(&frame).await.is_err() {
+                break;
+            }
+        }
+    });
+    state.sessions.lock().unwrap().insert(client_id, tx.clone());
+    let result = tokio::select! {
+        result = read_requests(&mut reader, &state, client_id, &tx) => result,
+        _ = state.disconnect.notified() => Ok(()),
+    };
+    state.sessions.lock().unwrap().remove(&client_id);
+    drop(tx);
+    let _ = writer_task.await;
+    result
+}
+
+async fn read_requests(
+    stream: &mut OwnedReadHalf,
+    state: &State,
+    client_id: u16,
+    tx: &mpsc::UnboundedSender<Bytes>,
+) -> Result<(), anyhow::Error> {
+    loop {
+        let length = match stream.read_u16_le().await {
+            Ok(length) => length as usize,
+            Err(_) => return Ok(()), // client went away
+        };
+        let mut frame = vec![0u8; length];
+        stream.read_exact(&mut frame).await?;
+        let mut frame = Bytes::from(frame);
+        if frame.remaining() < 4 {
+            anyhow::bail!("Frame too short: {} bytes", frame.remaining());
+        }
+        let protocol = frame.get_u8();
+        let version = frame.get_u8();
+        frame.advance(2);
+
+        let (reply, delay) = match protocol {
+            6 => robot_reply(state, frame)?,
+            7 => (connection_reply(client_id, frame)?, None),
+            _ => {
+                eprintln!("piglet: simulator ignoring protocol {protocol}");
+                continue;
+            }
+        };
+        let reply = frame_message(protocol, version, reply);
+        match delay {
+            Some(delay) => {
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    tokio::time::sleep(delay).await;
+                    let _ = tx.send(reply);
+                });
+            }
+            None => tx.send(reply)?,
+        }
+    }
+}
+
+fn connection_reply(client_id: u16, mut message: Bytes) -> Result<Bytes, anyhow::Error> {
+    if message.remaining() < 4 {
+        anyhow::bail!("Connection message too short");
+    }
+    let mut reply = BytesMut::new();
+    reply.put_u8(message.get_u8()); // version
+    reply.put_u8(message.get_u8()); // message ID
+    let count = message.get_u8();
+    reply.put_u8(count);
+    reply.put_u8(message.get_u8());
+    for _ in 0..count {
+        if message.remaining() < 6 {
+            anyhow::bail!("Connection parameter too short");
+        }
+        let parameter = message.get_u8();
+        let _meta = message.get_u8();
+        let _code = message.get_u16_le();
+        let value = message.get_u16_le();
+        reply.put_u8(parameter);
+        reply.put_u8(17);
+        reply.put_u16_le(0);
+        reply.put_u16_le(if parameter == 1 { client_id } else { value });
+    }
+    Ok(reply.freeze())
+}
+
+// The reply and how long to hold it back
+fn robot_reply(
+    state: &State,
+    mut frame: Bytes,
+) -> Result<(Bytes, Option<Duration>), anyhow::Error> {
+    let source = ObjectAddress::from_bytes(&mut frame)?;
+    let destination = ObjectAddress::from_bytes(&mut frame)?;
+    if frame.remaining() < 8 {
+        anyhow::bail!("Robot frame too short");
+    }
+    let id = frame.get_u8();
+    let _unknown = frame.get_u8();
+    let protocol = frame.get_u8();
+    let _action = frame.get_u8();
+    let _length = frame.get_u16_le();
+    let options_length = frame.get_u16_le() as usize;
+    if frame.remaining() < options_length + 2 {
+        anyhow::bail!("Robot frame too short for its options");
+    }
+    frame.advance(options_length + 2);
+
+    if protocol == 3 {
+        let payload = registration_reply(state, &source, &destination, frame)?;
+        return Ok((
+            robot_frame(&destination, &source, id, 3, 4, Bytes::new(), payload),
+            None,
+        ));
+    }
+
+    if !state.objects.contains_key(&destination) {
+        let mut option = BytesMut::new();
+        option.put_u8(1);
+        option.put_u8(8);
+        option.put(destination.to_bytes());
+        option.put_u16_le(516);
+        return Ok((
+            robot_frame(
+                &destination,
+                &source,
+                id,
+                protocol,
+                0,
+                option.freeze(),
+                Bytes::new(),
+            ),
+            None,
+        ));
+    }
+
+    if frame.remaining() < 6 {
+        anyhow::bail!("Call too short");
+    }
+    let interface_id = frame.get_u8();
+    let call_type = frame.get_u8();
+    let call_type_id = frame.get_u16_le();
+    let _unknown = frame.get_u8();
+    let _count = frame.get_u8();
+    state.calls.lock().unwrap().push(SimulatedCall {
+        source: source.clone(),
+        destination: destination.clone(),
+        interface_id,
+        call_type,
+        call_type_id,
+        parameters: frame.clone(),
+    });
+
+    let key = (destination.clone(), interface_id, call_type_id);
+    let result = match state.handlers.get(&key) {
+        Some(handler) => handler(frame),
+        None if interface_id == 0 => introspect(&state.objects[&destination], call_type_id, frame),
+        None => Err(NOT_HANDLED),
+    };
+
+    let mut payload = BytesMut::new();
+    payload.put_u8(interface_id);
+    payload.put_u8(call_type);
+    payload.put_u16_le(call_type_id);
+    payload.put_u8(0);
+    let action = match result {
+        Ok((count, values)) => {
+            payload.put_u8(count);
+            payload.put(values);
+            call_type + 1
+        }
+        Err(code) => {
+            payload.put_u8(2);
+            code.serialize(&mut payload);
+            format!(
+                "0x{:04x}.0x{:04x}.0x{:04x}:0x{:04x},0x{:04x},0x{:04x}",
+                destination.module_id,
+                destination.node_id,
+                destination.object_id,
+                interface_id,
+                call_type_id,
+                code.0
+            )
+            .serialize(&mut payload);
+            call_type + 2
+        }
+    };
+    Ok((
+        robot_frame(
+            &destination,
+            &source,
+            id,
+            protocol,
+            action,
+            Bytes::new(),
+            payload.freeze(),
+        ),
+        state.delays.get(&key).copied(),
+    ))
+}
+
+fn robot_frame(
+    source: &ObjectAddress,
+    destination: &ObjectAddress,
+    id: u8,
+    protocol: u8,
+    action: u8,
+    options: Bytes,
+    payload: Bytes,
+) -> Bytes {
+    let mut framed = BytesMut::new();
+    framed.put(source.to_bytes());
+    framed.put(destination.to_bytes());
+    framed.put_u8(id);
+    framed.put_u8(0);
+    framed.put_u8(protocol);
+    framed.put_u8(action);
+    framed
+        .put_u16_le((6 + 6 + 1 + 1 + 1 + 1 + 2 + 2 + options.len() + 1 + 1 + payload.len()) as u16);
+    framed.put_u16_le(options.len() as u16);
+    framed.put(options);
+    framed.put_u8(0);
+    framed.put_u8(0);
+    framed.put(payload);
+    framed.freeze()
+}
+
+fn registration_reply(
+    state: &State,
+    source: &ObjectAddress,
+    destination: &ObjectAddress,
+    mut request: Bytes,
+) -> Result<Bytes, anyhow::Error> {
+    if request.remaining() < 20 {
+        anyhow::bail!("Registration request too short");
+    }
+    let call_type = request.get_u16_le();
+    request.advance(2 + 1 + 1 + 6 + 6);
+    let command_length = request.get_u16_le() as usize;
+    if request.remaining() < command_length {
+        anyhow::bail!("Registration command too short");
+    }
+    let command = request.copy_to_bytes(command_length);
+
+    let mut reply = BytesMut::new();
+    reply.put_u16_le(call_type);
+    reply.put_u16_le(0); // response code
+    reply.put_u8(0);
+    reply.put_u8(0);
+    reply.put(source.to_bytes());
+    reply.put(destination.to_bytes());
+    if call_type == 12 && command.len() == 4 {
+        let roots = match command[3] {
+            1 => &state.roots,
+            2 => &state.globals,
+            other => anyhow::bail!("Unknown registration request {}", other),
+        };
+        reply.put_u16_le(1); // option count
+        reply.put_u8(6);
+        reply.put_u8((2 + 2 * roots.len()) as u8);
+        reply.put_u16_le(0); // padding
+        for root in roots {
+            reply.put_u16_le(root.object_id);
+        }
+    } else {
+        reply.put_u16_le(0);
+    }
+    Ok(reply.freeze())
+}
+
+fn introspect(
+    object: &SimulatedObject,
+    call_type_id: u16,
+    mut parameters: Bytes,
+) -> Result<(u8, Bytes), ErrorCode> {
+    let mut values = BytesMut::new();
+    let count = match call_type_id {
+        1 => {
+            object.name.serialize(&mut values);
+            object.version.serialize(&mut values);
+            (object.methods.len() as u32).serialize(&mut values);
+            (object.subobjects.len() as u16).serialize(&mut values);
+            4
+        }
+        2 => {
+            let index = u32::deserialize(&mut parameters).map_err(|_| NOT_HANDLED)?;
+            let method = object.methods.get(index as usize).ok_or(NOT_HANDLED)?;
+            method.interface_id.serialize(&mut values);
+            method.call_type.serialize(&mut values);
+            method.method_id.serialize(&mut values);
+            method.name.serialize(&mut values);
+            // Parameter types are raw type codes smuggled through a string
+            values.put_u8(String::TYPE_ID);
+            values.put_u8(0);
+            values.put_u16_le((method.parameter_types.len() + 1) as u16);
+            values.put_slice(&method.parameter_types);
+            values.put_u8(0);
+            method.parameter_labels.join(",").serialize(&mut values);
+            6
+        }
+        3 => {
+            let index = u16::deserialize(&mut parameters).map_err(|_| NOT_HANDLED)?;
+            let address = object.subobjects.get(index as usize).ok_or(NOT_HANDLED)?;
+            address.module_id.serialize(&mut values);
+            address.node_id.serialize(&mut values);
+            address.object_id.serialize(&mut values);
+            3
+        }
+        4 => {
+            let interfaces = object.interfaces.as_ref().ok_or(NOT_HANDLED)?;
+            interfaces
+                .iter()
+                .map(|i| i.id)
+                .collect::<Vec<u8>>()
+                .serialize(&mut values);
+            interfaces
+                .iter()
+                .map(|i| i.label.clone())
+                .collect::<Vec<String>>()
+                .serialize(&mut values);
+            2
+        }
+        5 => {
+            let interface_id = u8::deserialize(&mut parameters).map_err(|_| NOT_HANDLED)?;
+            let enums = object
+                .enums
+                .get(&interface_id)
+                .map(|e| e.as_slice())
+                .unwrap_or_default();
+            enums
+                .iter()
+                .map(|e| e.name.clone())
+                .collect::<Vec<String>>()
+                .serialize(&mut values);
+            enums
+                .iter()
+                .map(|e| e.values.len() as u32)
+                .collect::<Vec<u32>>()
+                .serialize(&mut values);
+            enums
+                .iter()
+                .flat_map(|e| e.values.iter().copied())
+                .collect::<Vec<i32>>()
+                .serialize(&mut values);
+            enums
+                .iter()
+                .flat_map(|e| e.labels.iter().cloned())
+                .collect::<Vec<String>>()
+                .serialize(&mut values);
+            4
+        }
+        6 => {
+            let interface_id = u8::deserialize(&mut parameters).map_err(|_| NOT_HANDLED)?;
+            let structs = object
+                .structs
+                .get(&interface_id)
+                .map(|s| s.as_slice())
+                .unwrap_or_default();
+            structs
+                .iter()
+                .map(|s| s.name.clone())
+                .collect::<Vec<String>>()
+                .serialize(&mut values);
+            structs
+                .iter()
+                .map(|s| s.element_labels.len() as u32)
+                .collect::<Vec<u32>>()
+                .serialize(&mut values);
+            structs
+                .iter()
+                .flat_map(|s| s.element_types.iter().copied())
+                .collect::<Vec<u8>>()
+                .serialize(&mut values);
+            structs
+                .iter()
+                .flat_map(|s| s.element_labels.iter().cloned())
+                .collect::<Vec<String>>()
+                .serialize(&mut values);
+            4
+        }
+        _ => return Err(NOT_HANDLED),
+    };
+    Ok((count, values.freeze()))
+}
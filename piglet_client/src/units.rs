@@ -0,0 +1,81 @@
+use crate::client::{Error, Error::InvalidArgument};
+use anyhow::anyhow;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+
+// The robot takes lengths in 0.01 mm and volumes in 0.1 µL, and speeds in the same units per
+// second. These wrap the human-sized value and only turn into wire integers at the last moment.
+macro_rules! unit {
+    ($name:ident, $symbol:literal, $per_unit:literal) => {
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $name(pub f64);
+
+        impl $name {
+            // How many wire units make up one of this unit
+            pub const PER_UNIT: f64 = $per_unit;
+
+            pub fn from_wire<W: Into<i64>>(wire: W) -> Self {
+                Self(wire.into() as f64 / Self::PER_UNIT)
+            }
+
+            // Rounds to the nearest wire unit, failing rather than wrapping if that doesn't fit in W
+            pub fn to_wire<W: TryFrom<i64>>(self) -> Result<W, Error> {
+                let wire = (self.0 * Self::PER_UNIT).round();
+                if wire.is_finite() && wire >= i64::MIN as f64 && wire <= i64::MAX as f64 {
+                    if let Ok(wire) = W::try_from(wire as i64) {
+                        return Ok(wire);
+                    }
+                }
+                Err(InvalidArgument(anyhow!(
+                    "{} is out of range for a {}",
+                    self,
+                    std::any::type_name::<W>()
+                )))
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+                write!(f, "{} {}", self.0, $symbol)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self(self.0 + other.0)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self(self.0 - other.0)
+            }
+        }
+
+        impl Mul<f64> for $name {
+            type Output = Self;
+
+            fn mul(self, factor: f64) -> Self {
+                Self(self.0 * factor)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+    };
+}
+
+unit!(Millimeters, "mm", 100.0);
+unit!(Microliters, "µL", 10.0);
+unit!(MmPerSecond, "mm/s", 100.0);
+unit!(MicrolitersPerSecond, "µL/s", 10.0);
@@ -1,11 +1,26 @@
-use crate::client::{Error, Error::ConnectionError};
+use crate::client::{Error, Error::ProtocolError};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct NetworkResult {}
+pub use piglet_derive::PigletCodec;
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+// A struct from the firmware's own network layer, like a calibration date, which get_structs
+// doesn't describe. It's kept as the bytes it came in so it can be sent back unchanged.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NetworkResult {
+    pub bytes: Bytes,
+}
+
+impl NetworkResult {
+    // Its fields, decoded by the type id each one carries
+    pub fn fields(&self) -> Result<Vec<Value>, Error> {
+        fields(self.bytes.clone())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ErrorCode(pub u16);
 
 pub trait PigletCodec: Sized {
@@ -20,6 +35,67 @@ pub trait PigletDeserialize: PigletCodec {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error>;
 }
 
+// Structs and enums, which arrays hold without the header each value usually starts with. Vec and
+// slices of these are codecs too. #[derive(PigletCodec)] implements this.
+pub trait PigletElement: Sized {
+    const ARRAY_TYPE_ID: u8;
+
+    fn serialize_item(&self, stream: &mut BytesMut);
+    fn deserialize_item(stream: &mut Bytes) -> Result<Self, Error>;
+}
+
+// Writes the fields of a struct as one item of an array, which has only a length in front
+pub fn serialize_struct_item(stream: &mut BytesMut, fields: impl FnOnce(&mut BytesMut)) {
+    let mut buffer = BytesMut::new();
+    fields(&mut buffer);
+    stream.put_u16_le(buffer.len() as u16);
+    stream.put(buffer);
+}
+
+// The bytes of one struct in an array, to read its fields from
+pub fn deserialize_struct_item(stream: &mut Bytes) -> Result<Bytes, Error> {
+    let length = stream.try_get_u16_le()? as usize;
+    take(stream, length)
+}
+
+impl<T: PigletElement> PigletCodec for &[T] {
+    const TYPE_ID: u8 = T::ARRAY_TYPE_ID;
+}
+
+impl<T: PigletElement> PigletCodec for Vec<T> {
+    const TYPE_ID: u8 = T::ARRAY_TYPE_ID;
+}
+
+impl<T: PigletElement> PigletSerialize for &[T] {
+    fn serialize(&self, stream: &mut BytesMut) {
+        let mut buffer = BytesMut::new();
+        for item in self.iter() {
+            item.serialize_item(&mut buffer);
+        }
+        stream.put_u8(Self::TYPE_ID);
+        stream.put_u8(0);
+        stream.put_u16_le(buffer.len() as u16);
+        stream.put(buffer);
+    }
+}
+
+impl<T: PigletElement> PigletSerialize for Vec<T> {
+    fn serialize(&self, stream: &mut BytesMut) {
+        self.as_slice().serialize(stream)
+    }
+}
+
+impl<T: PigletElement> PigletDeserialize for Vec<T> {
+    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
+        let mut arr = Vec::new();
+        while bytes.has_remaining() {
+            arr.push(T::deserialize_item(&mut bytes)?);
+        }
+        Ok(arr)
+    }
+}
+
 impl PigletCodec for i8 {
     const TYPE_ID: u8 = 1;
 }
@@ -35,18 +111,8 @@ impl PigletSerialize for i8 {
 
 impl PigletDeserialize for i8 {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(bytes.get_i8())
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
+        Ok(bytes.try_get_i8()?)
     }
 }
 
@@ -65,18 +131,8 @@ impl PigletSerialize for i16 {
 
 impl PigletDeserialize for i16 {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(bytes.get_i16_le())
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
+        Ok(bytes.try_get_i16_le()?)
     }
 }
 
@@ -95,18 +151,8 @@ impl PigletSerialize for i32 {
 
 impl PigletDeserialize for i32 {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(bytes.get_i32_le())
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
+        Ok(bytes.try_get_i32_le()?)
     }
 }
 
@@ -125,18 +171,8 @@ impl PigletSerialize for u8 {
 
 impl PigletDeserialize for u8 {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(bytes.get_u8())
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
+        Ok(bytes.try_get_u8()?)
     }
 }
 
@@ -155,18 +191,8 @@ impl PigletSerialize for u16 {
 
 impl PigletDeserialize for u16 {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(bytes.get_u16_le())
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
+        Ok(bytes.try_get_u16_le()?)
     }
 }
 
@@ -185,18 +211,8 @@ impl PigletSerialize for u32 {
 
 impl PigletDeserialize for u32 {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(bytes.get_u32_le())
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
+        Ok(bytes.try_get_u32_le()?)
     }
 }
 
@@ -227,21 +243,11 @@ impl PigletSerialize for String {
 
 impl PigletDeserialize for String {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
+        let (flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
 
         let padding = if flags & 1 == 1 { 2 } else { 1 };
-        bytes.truncate(bytes.len() - (if bytes.len() > 0 { padding } else { 0 }));
-        String::from_utf8(bytes.to_vec()).map_err(|e| ConnectionError(e.into()))
+        bytes.truncate(bytes.len().saturating_sub(padding));
+        String::from_utf8(bytes.to_vec()).map_err(|e| ProtocolError(e.into()))
     }
 }
 
@@ -260,18 +266,8 @@ impl PigletSerialize for bool {
 
 impl PigletDeserialize for bool {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(bytes.get_u8() == 1)
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
+        Ok(bytes.try_get_u8()? == 1)
     }
 }
 
@@ -285,21 +281,11 @@ impl PigletCodec for Vec<u8> {
 
 impl PigletDeserialize for Vec<u8> {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let bytes = stream.copy_to_bytes(length);
+        let (flags, bytes) = read_header(stream, Self::TYPE_ID)?;
 
         let mut arr = bytes.to_vec();
         let padding = if flags & 1 == 1 { 1 } else { 0 };
-        arr.truncate(arr.len() - (if arr.len() > 0 { padding } else { 0 }));
+        arr.truncate(arr.len().saturating_sub(padding));
         Ok(arr)
     }
 }
@@ -330,21 +316,11 @@ impl PigletCodec for Vec<i16> {
 
 impl PigletDeserialize for Vec<i16> {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
 
         let mut arr = Vec::with_capacity(bytes.len() / 2);
         for _ in 0..(bytes.len() / 2) {
-            arr.push(bytes.get_i16_le());
+            arr.push(bytes.try_get_i16_le()?);
         }
         Ok(arr)
     }
@@ -378,21 +354,11 @@ impl PigletCodec for Vec<u16> {
 
 impl PigletDeserialize for Vec<u16> {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
 
         let mut arr = Vec::with_capacity(bytes.len() / 2);
         for _ in 0..(bytes.len() / 2) {
-            arr.push(bytes.get_u16_le());
+            arr.push(bytes.try_get_u16_le()?);
         }
         Ok(arr)
     }
@@ -426,21 +392,11 @@ impl PigletCodec for Vec<i32> {
 
 impl PigletDeserialize for Vec<i32> {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
 
         let mut arr = Vec::with_capacity(bytes.len() / 4);
         for _ in 0..(bytes.len() / 4) {
-            arr.push(bytes.get_i32_le());
+            arr.push(bytes.try_get_i32_le()?);
         }
         Ok(arr)
     }
@@ -474,21 +430,11 @@ impl PigletCodec for Vec<u32> {
 
 impl PigletDeserialize for Vec<u32> {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
 
         let mut arr = Vec::with_capacity(bytes.len() / 4);
         for _ in 0..(bytes.len() / 4) {
-            arr.push(bytes.get_u32_le());
+            arr.push(bytes.try_get_u32_le()?);
         }
         Ok(arr)
     }
@@ -522,24 +468,14 @@ impl PigletCodec for Vec<bool> {
 
 impl PigletDeserialize for Vec<bool> {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
+        let (flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
 
         let mut arr = Vec::with_capacity(bytes.len());
         for _ in 0..bytes.len() {
-            arr.push(bytes.get_u8() == 1);
+            arr.push(bytes.try_get_u8()? == 1);
         }
         let padding = if flags & 1 == 1 { 1 } else { 0 };
-        arr.truncate(arr.len() - (if arr.len() > 0 { padding } else { 0 }));
+        arr.truncate(arr.len().saturating_sub(padding));
         Ok(arr)
     }
 }
@@ -567,26 +503,32 @@ impl PigletCodec for NetworkResult {
 }
 
 impl PigletSerialize for NetworkResult {
-    fn serialize(&self, _stream: &mut BytesMut) {
-        todo!("literally no idea");
+    fn serialize(&self, stream: &mut BytesMut) {
+        stream.put_u8(Self::TYPE_ID);
+        stream.put_u8(0);
+        stream.put_u16_le(self.bytes.len() as u16);
+        stream.put_slice(&self.bytes);
     }
 }
 
 impl PigletDeserialize for NetworkResult {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let bytes = stream.copy_to_bytes(length);
-        println!("got a network result {:?}", bytes);
-        Ok(NetworkResult {})
+        let (_flags, bytes) = read_header(stream, Self::TYPE_ID)?;
+        Ok(NetworkResult { bytes })
+    }
+}
+
+impl PigletElement for NetworkResult {
+    const ARRAY_TYPE_ID: u8 = STRUCT_ARRAY_TYPE_ID;
+
+    fn serialize_item(&self, stream: &mut BytesMut) {
+        serialize_struct_item(stream, |buffer| buffer.put_slice(&self.bytes));
+    }
+
+    fn deserialize_item(stream: &mut Bytes) -> Result<Self, Error> {
+        Ok(NetworkResult {
+            bytes: deserialize_struct_item(stream)?,
+        })
     }
 }
 
@@ -605,18 +547,8 @@ impl PigletSerialize for ErrorCode {
 
 impl PigletDeserialize for ErrorCode {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(Self(bytes.get_u16_le()))
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
+        Ok(Self(bytes.try_get_u16_le()?))
     }
 }
 
@@ -634,28 +566,17 @@ impl PigletCodec for Vec<String> {
 
 impl PigletDeserialize for Vec<String> {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let bytes = stream.copy_to_bytes(length);
+        let (flags, bytes) = read_header(stream, Self::TYPE_ID)?;
 
         let mut raw = bytes.to_vec();
         let padding = if flags & 1 == 1 { 2 } else { 1 };
-        raw.truncate(bytes.len() - (if bytes.len() > 0 { padding } else { 0 }));
-        Ok(if raw.len() > 0 {
-            raw.split(|v| *v == b'\0')
-                .map(|v| String::from_utf8(v.to_vec()).unwrap())
-                .collect()
-        } else {
-            Vec::new()
-        })
+        raw.truncate(raw.len().saturating_sub(padding));
+        if raw.is_empty() {
+            return Ok(Vec::new());
+        }
+        raw.split(|v| *v == b'\0')
+            .map(|v| String::from_utf8(v.to_vec()).map_err(|e| ProtocolError(e.into())))
+            .collect()
     }
 }
 
@@ -712,17 +633,277 @@ impl PigletSerialize for f32 {
 
 impl PigletDeserialize for f32 {
     fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize; // Read length
-        let mut bytes = stream.copy_to_bytes(length); // Use length
-        Ok(bytes.get_f32_le())
+        let (_flags, mut bytes) = read_header(stream, Self::TYPE_ID)?;
+        Ok(bytes.try_get_f32_le()?)
+    }
+}
+
+// Any value the robot sends or takes, for calling methods that weren't generated ahead of time.
+// Each one knows its own type id so decoding doesn't need to know what to expect.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    F32(f32),
+    Bool(bool),
+    String(String),
+    U8Array(Vec<u8>),
+    I16Array(Vec<i16>),
+    U16Array(Vec<u16>),
+    I32Array(Vec<i32>),
+    U32Array(Vec<u32>),
+    BoolArray(Vec<bool>),
+    StringArray(Vec<String>),
+    // Enums go over the wire as their numeric value
+    Enum(i32),
+    EnumArray(Vec<i32>),
+    // A struct's fields in the order get_structs lists them
+    Struct(Vec<Value>),
+    StructArray(Vec<Vec<Value>>),
+    ErrorCode(ErrorCode),
+}
+
+const STRUCT_TYPE_ID: u8 = 30;
+const STRUCT_ARRAY_TYPE_ID: u8 = 31;
+const ENUM_TYPE_ID: u8 = 32;
+const ENUM_ARRAY_TYPE_ID: u8 = 35;
+
+impl Value {
+    pub fn type_id(&self) -> u8 {
+        match self {
+            Value::I8(_) => i8::TYPE_ID,
+            Value::I16(_) => i16::TYPE_ID,
+            Value::I32(_) => i32::TYPE_ID,
+            Value::U8(_) => u8::TYPE_ID,
+            Value::U16(_) => u16::TYPE_ID,
+            Value::U32(_) => u32::TYPE_ID,
+            Value::F32(_) => f32::TYPE_ID,
+            Value::Bool(_) => bool::TYPE_ID,
+            Value::String(_) => String::TYPE_ID,
+            Value::U8Array(_) => Vec::<u8>::TYPE_ID,
+            Value::I16Array(_) => Vec::<i16>::TYPE_ID,
+            Value::U16Array(_) => Vec::<u16>::TYPE_ID,
+            Value::I32Array(_) => Vec::<i32>::TYPE_ID,
+            Value::U32Array(_) => Vec::<u32>::TYPE_ID,
+            Value::BoolArray(_) => Vec::<bool>::TYPE_ID,
+            Value::StringArray(_) => Vec::<String>::TYPE_ID,
+            Value::Enum(_) => ENUM_TYPE_ID,
+            Value::EnumArray(_) => ENUM_ARRAY_TYPE_ID,
+            Value::Struct(_) => STRUCT_TYPE_ID,
+            Value::StructArray(_) => STRUCT_ARRAY_TYPE_ID,
+            Value::ErrorCode(_) => ErrorCode::TYPE_ID,
+        }
+    }
+
+    pub fn serialize(&self, stream: &mut BytesMut) {
+        match self {
+            Value::I8(v) => v.serialize(stream),
+            Value::I16(v) => v.serialize(stream),
+            Value::I32(v) => v.serialize(stream),
+            Value::U8(v) => v.serialize(stream),
+            Value::U16(v) => v.serialize(stream),
+            Value::U32(v) => v.serialize(stream),
+            Value::F32(v) => v.serialize(stream),
+            Value::Bool(v) => v.serialize(stream),
+            Value::String(v) => v.serialize(stream),
+            Value::U8Array(v) => v.serialize(stream),
+            Value::I16Array(v) => v.serialize(stream),
+            Value::U16Array(v) => v.serialize(stream),
+            Value::I32Array(v) => v.serialize(stream),
+            Value::U32Array(v) => v.serialize(stream),
+            Value::BoolArray(v) => v.serialize(stream),
+            Value::StringArray(v) => v.serialize(stream),
+            Value::Enum(v) => {
+                stream.put_u8(ENUM_TYPE_ID);
+                stream.put_u8(0);
+                stream.put_u16_le(4);
+                stream.put_i32_le(*v);
+            }
+            Value::EnumArray(v) => {
+                stream.put_u8(ENUM_ARRAY_TYPE_ID);
+                stream.put_u8(0);
+                stream.put_u16_le(4 * v.len() as u16);
+                for item in v {
+                    stream.put_i32_le(*item);
+                }
+            }
+            Value::Struct(fields) => {
+                let mut buffer = BytesMut::new();
+                for field in fields {
+                    field.serialize(&mut buffer);
+                }
+                stream.put_u8(STRUCT_TYPE_ID);
+                stream.put_u8(0);
+                stream.put_u16_le(buffer.len() as u16);
+                stream.put(buffer);
+            }
+            Value::StructArray(items) => {
+                let mut outer = BytesMut::new();
+                for fields in items {
+                    let mut buffer = BytesMut::new();
+                    for field in fields {
+                        field.serialize(&mut buffer);
+                    }
+                    outer.put_u16_le(buffer.len() as u16);
+                    outer.put(buffer);
+                }
+                stream.put_u8(STRUCT_ARRAY_TYPE_ID);
+                stream.put_u8(0);
+                stream.put_u16_le(outer.len() as u16);
+                stream.put(outer);
+            }
+            Value::ErrorCode(v) => v.serialize(stream),
+        }
+    }
+
+    pub fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
+        let Some(&type_id) = stream.first() else {
+            return Err(ProtocolError(anyhow!("Expected a value but got nothing")));
+        };
+        Ok(match type_id {
+            1 => Value::I8(i8::deserialize(stream)?),
+            2 => Value::I16(i16::deserialize(stream)?),
+            3 => Value::I32(i32::deserialize(stream)?),
+            4 => Value::U8(u8::deserialize(stream)?),
+            5 => Value::U16(u16::deserialize(stream)?),
+            6 => Value::U32(u32::deserialize(stream)?),
+            15 => Value::String(String::deserialize(stream)?),
+            22 => Value::U8Array(Vec::<u8>::deserialize(stream)?),
+            23 => Value::Bool(bool::deserialize(stream)?),
+            25 => Value::I16Array(Vec::<i16>::deserialize(stream)?),
+            26 => Value::U16Array(Vec::<u16>::deserialize(stream)?),
+            27 => Value::I32Array(Vec::<i32>::deserialize(stream)?),
+            28 => Value::U32Array(Vec::<u32>::deserialize(stream)?),
+            29 => Value::BoolArray(Vec::<bool>::deserialize(stream)?),
+            33 => Value::ErrorCode(ErrorCode::deserialize(stream)?),
+            34 => Value::StringArray(Vec::<String>::deserialize(stream)?),
+            40 => Value::F32(f32::deserialize(stream)?),
+            ENUM_TYPE_ID => Value::Enum(read_header(stream, type_id)?.1.try_get_i32_le()?),
+            ENUM_ARRAY_TYPE_ID => {
+                let (_flags, mut bytes) = read_header(stream, type_id)?;
+                let mut arr = Vec::with_capacity(bytes.len() / 4);
+                for _ in 0..(bytes.len() / 4) {
+                    arr.push(bytes.try_get_i32_le()?);
+                }
+                Value::EnumArray(arr)
+            }
+            STRUCT_TYPE_ID => Value::Struct(fields(read_header(stream, type_id)?.1)?),
+            STRUCT_ARRAY_TYPE_ID => {
+                let (_flags, mut outer) = read_header(stream, type_id)?;
+                let mut items = Vec::new();
+                while outer.has_remaining() {
+                    let length = outer.try_get_u16_le()? as usize;
+                    items.push(fields(take(&mut outer, length)?)?);
+                }
+                Value::StructArray(items)
+            }
+            _ => return Err(ProtocolError(anyhow!("Unknown type {}", type_id))),
+        })
     }
 }
+
+// What a type id holds, for describing methods to people
+pub fn type_name(type_id: u8) -> &'static str {
+    match type_id {
+        1 => "i8",
+        2 => "i16",
+        3 => "i32",
+        4 => "u8",
+        5 => "u16",
+        6 => "u32",
+        15 => "string",
+        22 => "u8[]",
+        23 => "bool",
+        25 => "i16[]",
+        26 => "u16[]",
+        27 => "i32[]",
+        28 => "u32[]",
+        29 => "bool[]",
+        STRUCT_TYPE_ID => "struct",
+        STRUCT_ARRAY_TYPE_ID => "struct[]",
+        ENUM_TYPE_ID => "enum",
+        33 => "error code",
+        34 => "string[]",
+        ENUM_ARRAY_TYPE_ID => "enum[]",
+        40 => "f32",
+        _ => "unknown",
+    }
+}
+
+// Structs and enums are followed by where they're defined and which one they are, wherever their
+// type id appears in a method's parameter types or a struct's element types
+pub fn has_definition(type_id: u8) -> bool {
+    matches!(
+        type_id,
+        STRUCT_TYPE_ID | STRUCT_ARRAY_TYPE_ID | ENUM_TYPE_ID | ENUM_ARRAY_TYPE_ID
+    )
+}
+
+// Reads the type id, flags and length every value starts with, failing if the type id isn't the
+// expected one, and returns the flags and the bytes the length covers
+pub fn read_header(stream: &mut Bytes, expected: u8) -> Result<(u8, Bytes), Error> {
+    let type_id = stream.try_get_u8()?;
+    if type_id != expected {
+        return Err(ProtocolError(anyhow!(
+            "Expected {} but got {}",
+            expected,
+            type_id
+        )));
+    }
+    let flags = stream.try_get_u8()?;
+    let length = stream.try_get_u16_le()? as usize;
+    Ok((flags, take(stream, length)?))
+}
+
+// Like copy_to_bytes, but an error rather than a panic when the stream is too short
+pub fn take(stream: &mut Bytes, length: usize) -> Result<Bytes, Error> {
+    if stream.remaining() < length {
+        return Err(ProtocolError(anyhow!(
+            "Expected {} more bytes but only {} are left",
+            length,
+            stream.remaining()
+        )));
+    }
+    Ok(stream.split_to(length))
+}
+
+fn fields(mut bytes: Bytes) -> Result<Vec<Value>, Error> {
+    let mut fields = Vec::new();
+    while bytes.has_remaining() {
+        fields.push(Value::deserialize(&mut bytes)?);
+    }
+    Ok(fields)
+}
+
+macro_rules! value_from {
+    ($type:ty, $variant:ident) => {
+        impl From<$type> for Value {
+            fn from(v: $type) -> Self {
+                Value::$variant(v.into())
+            }
+        }
+    };
+}
+
+value_from!(i8, I8);
+value_from!(i16, I16);
+value_from!(i32, I32);
+value_from!(u8, U8);
+value_from!(u16, U16);
+value_from!(u32, U32);
+value_from!(f32, F32);
+value_from!(bool, Bool);
+value_from!(String, String);
+value_from!(&str, String);
+value_from!(Vec<u8>, U8Array);
+value_from!(Vec<i16>, I16Array);
+value_from!(Vec<u16>, U16Array);
+value_from!(Vec<i32>, I32Array);
+value_from!(Vec<u32>, U32Array);
+value_from!(Vec<bool>, BoolArray);
+value_from!(Vec<String>, StringArray);
+value_from!(ErrorCode, ErrorCode);
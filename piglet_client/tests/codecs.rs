@@ -0,0 +1,233 @@
+// Round trips through every codec, and checks that short or garbled input is an error rather
+// than a panic. Values come from a seeded generator so a failure shows up the same way every run.
+use bytes::{BufMut, Bytes, BytesMut};
+use piglet_client::values::{ErrorCode, NetworkResult, PigletDeserialize, PigletSerialize, Value};
+use std::fmt::Debug;
+
+const RUNS: usize = 500;
+
+// xorshift64*, plenty for picking test values
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545f4914f6cdd1d)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn bool(&mut self) -> bool {
+        self.next() & 1 == 1
+    }
+
+    fn f32(&mut self) -> f32 {
+        loop {
+            let f = f32::from_bits(self.next() as u32);
+            if f.is_finite() {
+                return f;
+            }
+        }
+    }
+
+    // Strings can't hold a null since that's what ends them on the wire
+    fn string(&mut self) -> String {
+        let chars = ['a', 'Z', '0', ' ', '.', 'µ', 'é', '水', '🐷'];
+        (0..self.below(12))
+            .map(|_| chars[self.below(chars.len())])
+            .collect()
+    }
+
+    fn strings(&mut self) -> Vec<String> {
+        let strings: Vec<String> = (0..self.below(6)).map(|_| self.string()).collect();
+        // A single empty string is written the same way as no strings at all
+        if strings == [""] { Vec::new() } else { strings }
+    }
+
+    fn vec<T>(&mut self, mut item: impl FnMut(&mut Self) -> T) -> Vec<T> {
+        (0..self.below(20)).map(|_| item(self)).collect()
+    }
+
+    fn bytes(&mut self) -> Vec<u8> {
+        self.vec(|r| r.next() as u8)
+    }
+
+    fn value(&mut self, depth: usize) -> Value {
+        let variants = if depth > 1 { 19 } else { 21 };
+        match self.below(variants) {
+            0 => Value::I8(self.next() as i8),
+            1 => Value::I16(self.next() as i16),
+            2 => Value::I32(self.next() as i32),
+            3 => Value::U8(self.next() as u8),
+            4 => Value::U16(self.next() as u16),
+            5 => Value::U32(self.next() as u32),
+            6 => Value::F32(self.f32()),
+            7 => Value::Bool(self.bool()),
+            8 => Value::String(self.string()),
+            9 => Value::U8Array(self.bytes()),
+            10 => Value::I16Array(self.vec(|r| r.next() as i16)),
+            11 => Value::U16Array(self.vec(|r| r.next() as u16)),
+            12 => Value::I32Array(self.vec(|r| r.next() as i32)),
+            13 => Value::U32Array(self.vec(|r| r.next() as u32)),
+            14 => Value::BoolArray(self.vec(Rng::bool)),
+            15 => Value::StringArray(self.strings()),
+            16 => Value::Enum(self.next() as i32),
+            17 => Value::EnumArray(self.vec(|r| r.next() as i32)),
+            18 => Value::ErrorCode(ErrorCode(self.next() as u16)),
+            19 => Value::Struct(self.fields(depth)),
+            _ => Value::StructArray((0..self.below(4)).map(|_| self.fields(depth)).collect()),
+        }
+    }
+
+    fn fields(&mut self, depth: usize) -> Vec<Value> {
+        (0..self.below(5)).map(|_| self.value(depth + 1)).collect()
+    }
+}
+
+// Decodes what value encodes to, then checks every shorter prefix of it fails cleanly
+fn check<T: PartialEq + Debug>(
+    value: T,
+    serialize: impl Fn(&T, &mut BytesMut),
+    deserialize: impl Fn(&mut Bytes) -> Result<T, piglet_client::client::Error>,
+) {
+    let mut buffer = BytesMut::new();
+    serialize(&value, &mut buffer);
+    let encoded = buffer.freeze();
+
+    let mut stream = encoded.clone();
+    let decoded = deserialize(&mut stream).unwrap();
+    assert_eq!(decoded, value);
+    assert!(stream.is_empty(), "{:?} left {:?} unread", value, stream);
+
+    for length in 0..encoded.len() {
+        assert!(
+            deserialize(&mut encoded.slice(..length)).is_err(),
+            "{:?} cut to {} bytes still decoded",
+            value,
+            length
+        );
+    }
+}
+
+fn check_codec<T: PigletSerialize + PigletDeserialize + PartialEq + Debug>(value: T) {
+    check(value, T::serialize, T::deserialize);
+}
+
+#[test]
+fn scalars_round_trip() {
+    let mut rng = Rng(1);
+    for _ in 0..RUNS {
+        check_codec(rng.next() as i8);
+        check_codec(rng.next() as i16);
+        check_codec(rng.next() as i32);
+        check_codec(rng.next() as u8);
+        check_codec(rng.next() as u16);
+        check_codec(rng.next() as u32);
+        check_codec(rng.f32());
+        check_codec(rng.bool());
+        check_codec(ErrorCode(rng.next() as u16));
+    }
+}
+
+#[test]
+fn strings_round_trip() {
+    let mut rng = Rng(2);
+    for _ in 0..RUNS {
+        check_codec(rng.string());
+        check_codec(rng.strings());
+    }
+}
+
+#[test]
+fn string_arguments_decode_as_strings() {
+    let mut rng = Rng(6);
+    for _ in 0..RUNS {
+        let strings = rng.strings();
+        let borrowed: Vec<&str> = strings.iter().map(String::as_str).collect();
+        check(
+            strings.clone(),
+            |_, buffer| borrowed.as_slice().serialize(buffer),
+            Vec::<String>::deserialize,
+        );
+        check(
+            strings.clone(),
+            |v, buffer| v.as_slice().serialize(buffer),
+            Vec::<String>::deserialize,
+        );
+    }
+}
+
+#[test]
+fn arrays_round_trip() {
+    let mut rng = Rng(3);
+    for _ in 0..RUNS {
+        check_codec(rng.bytes());
+        check_codec(rng.vec(|r| r.next() as i16));
+        check_codec(rng.vec(|r| r.next() as u16));
+        check_codec(rng.vec(|r| r.next() as i32));
+        check_codec(rng.vec(|r| r.next() as u32));
+        check_codec(rng.vec(Rng::bool));
+    }
+}
+
+#[test]
+fn values_round_trip() {
+    let mut rng = Rng(4);
+    for _ in 0..RUNS * 4 {
+        check(rng.value(0), Value::serialize, Value::deserialize);
+    }
+}
+
+#[test]
+fn network_results_keep_their_fields() {
+    let mut rng = Rng(7);
+    for _ in 0..RUNS {
+        let fields = rng.fields(1);
+        let mut bytes = BytesMut::new();
+        for field in &fields {
+            field.serialize(&mut bytes);
+        }
+        let result = NetworkResult {
+            bytes: bytes.freeze(),
+        };
+        assert_eq!(result.fields().unwrap(), fields);
+        check_codec(result);
+    }
+}
+
+#[test]
+fn garbage_is_an_error_not_a_panic() {
+    let mut rng = Rng(5);
+    for _ in 0..RUNS * 4 {
+        let data = rng.bytes();
+        let mut stream = Bytes::from(data.clone());
+        while Value::deserialize(&mut stream).is_ok() {}
+
+        let _ = String::deserialize(&mut Bytes::from(data.clone()));
+        let _ = Vec::<String>::deserialize(&mut Bytes::from(data.clone()));
+        let _ = Vec::<bool>::deserialize(&mut Bytes::from(data.clone()));
+        piglet_client::fuzzing::frame(&data);
+        piglet_client::fuzzing::registration(&data);
+        piglet_client::fuzzing::initialization(&data);
+        piglet_client::fuzzing::stream(&data);
+    }
+}
+
+#[test]
+fn garbled_values_are_errors() {
+    // A string too short for the padding its flags ask for
+    let mut stream = Bytes::from_static(&[15, 1, 1, 0, b'a']);
+    assert_eq!(String::deserialize(&mut stream).unwrap(), "");
+
+    let mut invalid_utf8 = BytesMut::new();
+    invalid_utf8.put_slice(&[34, 0, 4, 0, 0xff, 0xfe, 0, 0]);
+    assert!(Vec::<String>::deserialize(&mut invalid_utf8.freeze()).is_err());
+
+    // A struct array whose item says it's longer than what's left
+    let mut stream = Bytes::from_static(&[31, 0, 2, 0, 9, 0]);
+    assert!(Value::deserialize(&mut stream).is_err());
+}
@@ -0,0 +1,134 @@
+// Checking a robot against the objects bindings were generated from
+use piglet_client::client::{Error, RobotClient};
+use piglet_client::compatibility::{ExpectedObject, fingerprint, verify};
+use piglet_client::dynamic_object::Method;
+use piglet_client::object_address::ObjectAddress;
+use piglet_client::testing::{SimulatedObject, Simulator};
+use std::sync::Arc;
+
+const DOOR_LOCK: ObjectAddress = ObjectAddress {
+    module_id: 1,
+    node_id: 1,
+    object_id: 268,
+};
+
+fn method(call_type: u8, method_id: u16, name: &str, parameter_types: Vec<u8>) -> Method {
+    Method {
+        call_type,
+        interface_id: 1,
+        method_id,
+        name: name.to_string(),
+        parameter_labels: vec![],
+        parameter_types,
+    }
+}
+
+fn door_lock_methods() -> Vec<Method> {
+    vec![
+        method(3, 1, "LockDoor", vec![]),
+        method(0, 3, "IsDoorLocked", vec![23]),
+    ]
+}
+
+#[test]
+fn fingerprints_do_not_change_between_builds() {
+    // Generated bindings have these values baked in, so the hash can't change without
+    // regenerating every one of them
+    assert_eq!(fingerprint(&door_lock_methods()), 0x4da076e4a833a4bb);
+    assert_eq!(fingerprint(&[]), 0xcbf29ce484222325);
+
+    // Labels don't decide how a method is called, but everything else does
+    let mut labelled = door_lock_methods();
+    labelled[0].parameter_labels = vec!["unused".to_string()];
+    assert_eq!(fingerprint(&labelled), 0x4da076e4a833a4bb);
+    let mut renumbered = door_lock_methods();
+    renumbered[1].method_id = 4;
+    assert_ne!(fingerprint(&renumbered), 0x4da076e4a833a4bb);
+}
+
+async fn door_lock(methods: Vec<Method>) -> (Simulator, Arc<RobotClient>) {
+    let simulator = Simulator::builder()
+        .root(
+            DOOR_LOCK.object_id,
+            SimulatedObject {
+                name: "DoorLock".to_string(),
+                version: "1.0".to_string(),
+                methods,
+                ..Default::default()
+            },
+        )
+        .start()
+        .await
+        .unwrap();
+    let robot = Arc::new(RobotClient::connect(simulator.address()).await.unwrap());
+    (simulator, robot)
+}
+
+const EXPECTED: &[ExpectedObject] = &[ExpectedObject {
+    address: DOOR_LOCK,
+    name: Some("DoorLock"),
+    version: Some("1.0"),
+    fingerprint: Some(0x4da076e4a833a4bb),
+}];
+
+#[tokio::test]
+async fn matching_objects_verify() {
+    let (simulator, robot) = door_lock(door_lock_methods()).await;
+    verify(&robot, EXPECTED).await.unwrap();
+    simulator.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn changed_methods_name_the_object() {
+    let mut methods = door_lock_methods();
+    methods[1].parameter_types = vec![18];
+    let (simulator, robot) = door_lock(methods).await;
+
+    let result = verify(&robot, EXPECTED).await;
+    let Err(Error::Mismatch(e)) = result else {
+        panic!("{result:?}");
+    };
+    let message = e.to_string();
+    assert!(
+        message.contains("1-1-268 DoorLock has different methods than it was generated from"),
+        "{message}"
+    );
+    simulator.close().await.unwrap();
+}
+
+// Bindings from a reconstructed dump only know where objects are, so names and methods that differ
+// from the reconstruction's guesses aren't a mismatch, but a missing object still is
+#[tokio::test]
+async fn reconstructed_objects_only_check_the_address() {
+    let mut methods = door_lock_methods();
+    methods[1].parameter_types = vec![18];
+    let (simulator, robot) = door_lock(methods).await;
+
+    let unchecked = ExpectedObject {
+        address: DOOR_LOCK,
+        name: None,
+        version: None,
+        fingerprint: None,
+    };
+    verify(&robot, std::slice::from_ref(&unchecked))
+        .await
+        .unwrap();
+
+    let missing = ExpectedObject {
+        address: ObjectAddress {
+            object_id: 269,
+            ..DOOR_LOCK
+        },
+        ..unchecked
+    };
+    let result = verify(&robot, &[missing]).await;
+    let Err(Error::Mismatch(e)) = result else {
+        panic!("{result:?}");
+    };
+    assert!(
+        e.to_string()
+            .contains("1-1-269 should be an object but couldn't be read"),
+        "{e}"
+    );
+    simulator.close().await.unwrap();
+}
@@ -0,0 +1,134 @@
+// Structs and enums from #[derive(PigletCodec)] against the encoding Value uses for any struct
+use bytes::{Bytes, BytesMut};
+use piglet_client::values::{
+    NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, Value,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, PigletCodec)]
+enum Rail {
+    Left = 1,
+    Right = 2,
+    Unknown = -1,
+}
+
+#[derive(Clone, Debug, PartialEq, PigletCodec)]
+struct Led {
+    index: u16,
+    on: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, PigletCodec)]
+struct Everything {
+    name: String,
+    volume: f32,
+    positions: Vec<i32>,
+    rail: Rail,
+    rails: Vec<Rail>,
+    led: Led,
+    leds: Vec<Led>,
+    date: NetworkResult,
+}
+
+#[derive(Clone, Debug, PartialEq, PigletCodec)]
+struct Nothing {}
+
+fn encode<T: PigletSerialize>(value: &T) -> Bytes {
+    let mut buffer = BytesMut::new();
+    value.serialize(&mut buffer);
+    buffer.freeze()
+}
+
+fn encode_value(value: &Value) -> Bytes {
+    let mut buffer = BytesMut::new();
+    value.serialize(&mut buffer);
+    buffer.freeze()
+}
+
+fn everything() -> Everything {
+    Everything {
+        name: "Channel".to_string(),
+        volume: 12.5,
+        positions: vec![-3, 0, 70_000],
+        rail: Rail::Right,
+        rails: vec![Rail::Left, Rail::Unknown],
+        led: Led { index: 3, on: true },
+        leds: vec![
+            Led {
+                index: 1,
+                on: false,
+            },
+            Led { index: 2, on: true },
+        ],
+        date: NetworkResult {
+            bytes: encode(&2025u16),
+        },
+    }
+}
+
+#[test]
+fn structs_round_trip() {
+    for value in [
+        everything(),
+        Everything {
+            rails: vec![],
+            leds: vec![],
+            ..everything()
+        },
+    ] {
+        let mut stream = encode(&value);
+        assert_eq!(Everything::deserialize(&mut stream).unwrap(), value);
+        assert!(stream.is_empty());
+    }
+    assert_eq!(
+        Nothing::deserialize(&mut encode(&Nothing {})).unwrap(),
+        Nothing {}
+    );
+}
+
+#[test]
+fn arrays_round_trip() {
+    let items = vec![everything(), everything()];
+    let mut stream = encode(&items);
+    assert_eq!(Vec::<Everything>::TYPE_ID, 31);
+    assert_eq!(Vec::<Everything>::deserialize(&mut stream).unwrap(), items);
+
+    let rails = [Rail::Unknown, Rail::Left];
+    let mut stream = encode(&rails.as_slice());
+    assert_eq!(Vec::<Rail>::TYPE_ID, 35);
+    assert_eq!(Vec::<Rail>::deserialize(&mut stream).unwrap(), rails);
+}
+
+#[test]
+fn encoded_like_any_other_struct() {
+    let led = |index: u16, on: bool| vec![Value::U16(index), Value::Bool(on)];
+    let expected = Value::Struct(vec![
+        Value::String("Channel".to_string()),
+        Value::F32(12.5),
+        Value::I32Array(vec![-3, 0, 70_000]),
+        Value::Enum(2),
+        Value::EnumArray(vec![1, -1]),
+        Value::Struct(led(3, true)),
+        Value::StructArray(vec![led(1, false), led(2, true)]),
+        Value::Struct(vec![Value::U16(2025)]),
+    ]);
+    assert_eq!(encode(&everything()), encode_value(&expected));
+    assert_eq!(
+        Value::deserialize(&mut encode(&everything())).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn unknown_enum_values_are_errors() {
+    assert_eq!(Rail::try_from(-1).unwrap(), Rail::Unknown);
+    assert!(Rail::try_from(3).is_err());
+    assert!(Rail::deserialize(&mut encode_value(&Value::Enum(3))).is_err());
+}
+
+#[test]
+fn short_input_is_an_error() {
+    let encoded = encode(&everything());
+    for length in 0..encoded.len() {
+        assert!(Everything::deserialize(&mut encoded.slice(..length)).is_err());
+    }
+}
@@ -0,0 +1,92 @@
+// Calling methods by name on an object the simulator describes
+use bytes::BytesMut;
+use piglet_client::client::{Error, RobotClient};
+use piglet_client::dynamic_object::{DynamicObject, Method};
+use piglet_client::object_address::ObjectAddress;
+use piglet_client::testing::{SimulatedObject, Simulator};
+use piglet_client::values::{PigletDeserialize, PigletSerialize, Value};
+use std::sync::Arc;
+
+const CALCULATOR: ObjectAddress = ObjectAddress {
+    module_id: 1,
+    node_id: 1,
+    object_id: 300,
+};
+
+async fn calculator() -> (Simulator, DynamicObject) {
+    let simulator = Simulator::builder()
+        .root(
+            CALCULATOR.object_id,
+            SimulatedObject {
+                name: "Calculator".to_string(),
+                methods: vec![Method {
+                    call_type: 3,
+                    interface_id: 1,
+                    method_id: 7,
+                    name: "Double".to_string(),
+                    parameter_labels: vec!["value".to_string(), "doubled".to_string()],
+                    // A u32 argument and a u32 return value
+                    parameter_types: vec![6, 30],
+                }],
+                ..Default::default()
+            },
+        )
+        .on(&CALCULATOR, 1, 7, |mut parameters| {
+            let doubled = u32::deserialize(&mut parameters).unwrap() * 2;
+            let mut values = BytesMut::new();
+            doubled.serialize(&mut values);
+            Ok((1, values.freeze()))
+        })
+        .start()
+        .await
+        .unwrap();
+    let robot = Arc::new(RobotClient::connect(simulator.address()).await.unwrap());
+    let calculator = DynamicObject::new(&CALCULATOR, &robot);
+    (simulator, calculator)
+}
+
+#[tokio::test]
+async fn calls_go_by_name() {
+    let (simulator, calculator) = calculator().await;
+    assert_eq!(
+        calculator
+            .call("Double", vec![Value::U32(21)])
+            .await
+            .unwrap(),
+        [Value::U32(42)]
+    );
+    simulator.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn bad_arguments_are_caught_before_sending() {
+    let (simulator, calculator) = calculator().await;
+
+    let result = calculator.call("Double", vec![]).await;
+    let Err(Error::InvalidArgument(e)) = result else {
+        panic!("{result:?}");
+    };
+    assert_eq!(e.to_string(), "Double takes 1 arguments, not 0");
+
+    let result = calculator
+        .call("Double", vec![Value::String("21".to_string())])
+        .await;
+    let Err(Error::InvalidArgument(e)) = result else {
+        panic!("{result:?}");
+    };
+    assert!(
+        e.to_string()
+            .starts_with("Argument value of Double should be"),
+        "{e}"
+    );
+
+    let result = calculator.call("Halve", vec![Value::U32(21)]).await;
+    let Err(Error::InvalidArgument(e)) = result else {
+        panic!("{result:?}");
+    };
+    assert_eq!(e.to_string(), "No method called Halve");
+
+    // Only the introspection calls went out
+    assert!(simulator.calls().iter().all(|c| c.interface_id == 0));
+    simulator.close().await.unwrap();
+}
@@ -0,0 +1,103 @@
+// Looking objects up by their path in the tree the simulator describes
+use piglet_client::client::{Error, RobotClient};
+use piglet_client::object_address::ObjectAddress;
+use piglet_client::testing::{SimulatedObject, Simulator};
+
+fn address(object_id: u16) -> ObjectAddress {
+    ObjectAddress {
+        module_id: 1,
+        node_id: 1,
+        object_id,
+    }
+}
+
+fn named(name: &str) -> SimulatedObject {
+    SimulatedObject {
+        name: name.to_string(),
+        ..Default::default()
+    }
+}
+
+async fn robot() -> (Simulator, RobotClient) {
+    let simulator = Simulator::builder()
+        .root(
+            48896,
+            SimulatedObject {
+                subobjects: vec![address(257), address(258), address(259)],
+                ..named("NimbusCore")
+            },
+        )
+        .object(&address(257), named("Pipette"))
+        .object(&address(258), named("Channel"))
+        .object(&address(259), named("Channel"))
+        .start()
+        .await
+        .unwrap();
+    let robot = RobotClient::connect(simulator.address()).await.unwrap();
+    (simulator, robot)
+}
+
+#[tokio::test]
+async fn paths_lead_to_addresses() {
+    let (simulator, robot) = robot().await;
+    assert_eq!(robot.resolve("NimbusCore").await.unwrap(), address(48896));
+    assert_eq!(
+        robot.resolve("NimbusCore.Pipette").await.unwrap(),
+        address(257)
+    );
+    assert_eq!(
+        robot.resolve("NimbusCore.Channel[1]").await.unwrap(),
+        address(258)
+    );
+    assert_eq!(
+        robot.resolve("NimbusCore.Channel[2]").await.unwrap(),
+        address(259)
+    );
+
+    let tree = robot.object_tree().await;
+    assert_eq!(
+        tree.find(&address(259)).unwrap().path,
+        "NimbusCore.Channel[2]"
+    );
+    robot.close().await.unwrap();
+    simulator.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn case_does_not_matter() {
+    let (simulator, robot) = robot().await;
+    assert_eq!(
+        robot.resolve("nimbuscore.pipette").await.unwrap(),
+        address(257)
+    );
+    assert_eq!(
+        robot.resolve("NIMBUSCORE.CHANNEL[2]").await.unwrap(),
+        address(259)
+    );
+    robot.close().await.unwrap();
+    simulator.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn unclear_and_missing_paths_say_why() {
+    let (simulator, robot) = robot().await;
+
+    let result = robot.resolve("NimbusCore.channel").await;
+    let Err(Error::InvalidArgument(e)) = result else {
+        panic!("{result:?}");
+    };
+    assert_eq!(
+        e.to_string(),
+        "There are 2 objects at NimbusCore.channel, pick one with NimbusCore.channel[1] to NimbusCore.channel[2]"
+    );
+
+    for path in ["NimbusCore.Channel[3]", "NimbusCore.Gripper", "Pipette"] {
+        let result = robot.resolve(path).await;
+        let Err(Error::InvalidArgument(e)) = result else {
+            panic!("{path}: {result:?}");
+        };
+        assert_eq!(e.to_string(), format!("There's no object at {path}"));
+    }
+    robot.close().await.unwrap();
+    simulator.close().await.unwrap();
+}
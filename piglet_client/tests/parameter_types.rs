@@ -0,0 +1,111 @@
+// The parameter type codes get_method reports, against the type ids values go over the wire as
+use piglet_client::dynamic_object::{Method, Position, parameter_type};
+use piglet_client::values::{has_definition, type_name};
+
+// Codes seen on a Nimbus, with what they turned out to be
+const SEEN: &[(u8, Position, u8)] = &[
+    (1, Position::Argument, 1),
+    (2, Position::Argument, 4),
+    (3, Position::Argument, 2),
+    (4, Position::Argument, 5),
+    (5, Position::Argument, 3),
+    (6, Position::Argument, 6),
+    (7, Position::Argument, 15),
+    (8, Position::Argument, 22),
+    (18, Position::ReturnElement, 4),
+    (21, Position::ReturnElement, 3),
+    (23, Position::ReturnElement, 15),
+    (25, Position::ReturnValue, 1),
+    (32, Position::ReturnValue, 22),
+    (33, Position::Argument, 23),
+    (35, Position::ReturnElement, 23),
+    (36, Position::ReturnValue, 23),
+    (41, Position::Argument, 25),
+    (44, Position::ReturnValue, 25),
+    (53, Position::Argument, 28),
+    (55, Position::ReturnElement, 28),
+    (60, Position::ReturnValue, 30),
+    (61, Position::Argument, 31),
+    (64, Position::ReturnValue, 31),
+    (66, Position::Argument, 29),
+    (69, Position::ReturnValue, 29),
+    (76, Position::ReturnElement, 34),
+    (78, Position::Argument, 32),
+    (81, Position::ReturnValue, 32),
+    (82, Position::Argument, 35),
+    (85, Position::ReturnValue, 35),
+    (102, Position::Argument, 40),
+    (104, Position::ReturnElement, 40),
+    (105, Position::ReturnValue, 40),
+];
+
+#[test]
+fn codes_seen_on_a_nimbus() {
+    for &(code, position, type_id) in SEEN {
+        assert_eq!(
+            parameter_type(code),
+            Some((position, type_id)),
+            "code {}",
+            code
+        );
+    }
+}
+
+// Every code maps to a type the values module can read
+#[test]
+fn codes_map_to_known_types() {
+    for code in 0..=u8::MAX {
+        if let Some((_, type_id)) = parameter_type(code) {
+            assert_ne!(type_name(type_id), "unknown", "code {}", code);
+        }
+    }
+}
+
+#[test]
+fn structs_and_enums_skip_their_definitions() {
+    let method = Method {
+        call_type: 0,
+        interface_id: 1,
+        method_id: 1,
+        name: "Everything".to_string(),
+        parameter_labels: ["a", "b", "c", "d", "e"].map(String::from).to_vec(),
+        parameter_types: vec![61, 2, 1, 78, 1, 1, 3, 82, 2, 2, 85, 1, 2],
+    };
+    let parameters = method.parameters().unwrap();
+    let summary: Vec<(&str, bool, u8)> = parameters
+        .iter()
+        .map(|p| (p.label.as_str(), p.is_argument, p.type_id))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("a", true, 31),
+            ("b", true, 32),
+            ("c", true, 2),
+            ("d", true, 35),
+            ("e", false, 35),
+        ]
+    );
+    assert!(has_definition(31) && has_definition(35));
+    assert!(!has_definition(2));
+}
+
+// Codes nobody has seen a robot use, even where the numbering suggests what they'd be
+#[test]
+fn unverified_codes_are_errors() {
+    for code in [0, 9, 16, 17, 34, 37, 57, 65, 118, 255] {
+        assert_eq!(parameter_type(code), None, "code {}", code);
+        let method = Method {
+            call_type: 0,
+            interface_id: 1,
+            method_id: 1,
+            name: "Unknown".to_string(),
+            parameter_labels: vec!["a".to_string()],
+            parameter_types: vec![code],
+        };
+        assert_eq!(
+            method.parameters().unwrap_err().to_string(),
+            format!("Unknown has a parameter with unverified type code {}", code)
+        );
+    }
+}
@@ -0,0 +1,97 @@
+// Sessions recorded against the simulator, then played back without it
+use bytes::{Bytes, BytesMut};
+use piglet_client::client::{Error, RobotClient};
+use piglet_client::object_address::ObjectAddress;
+use piglet_client::testing::{SimulatedObject, Simulator};
+use piglet_client::values::{PigletDeserialize, PigletSerialize};
+use std::path::PathBuf;
+
+const COUNTER: ObjectAddress = ObjectAddress {
+    module_id: 1,
+    node_id: 1,
+    object_id: 300,
+};
+
+// Doubles its argument
+async fn double(robot: &RobotClient, value: u32) -> Result<u32, Error> {
+    let mut arguments = BytesMut::new();
+    value.serialize(&mut arguments);
+    let (_, mut values) = robot.act(&COUNTER, 1, 3, 1, arguments.freeze()).await?;
+    u32::deserialize(&mut values)
+}
+
+async fn record(name: &str) -> PathBuf {
+    let simulator = Simulator::builder()
+        .root(
+            COUNTER.object_id,
+            SimulatedObject {
+                name: "Counter".to_string(),
+                ..Default::default()
+            },
+        )
+        .on(&COUNTER, 1, 1, |mut parameters| {
+            let mut values = BytesMut::new();
+            (u32::deserialize(&mut parameters).unwrap() * 2).serialize(&mut values);
+            Ok((1, values.freeze()))
+        })
+        .start()
+        .await
+        .unwrap();
+    let path = std::env::temp_dir().join(format!("piglet-{}-{}.txt", name, std::process::id()));
+    let robot = RobotClient::connect_recording(simulator.address(), &path)
+        .await
+        .unwrap();
+    assert_eq!(double(&robot, 1).await.unwrap(), 2);
+    assert_eq!(double(&robot, 21).await.unwrap(), 42);
+    robot.close().await.unwrap();
+    simulator.close().await.unwrap();
+    path
+}
+
+#[tokio::test]
+async fn replays_what_was_recorded() {
+    let path = record("replay").await;
+    let robot = RobotClient::replay(&path).await.unwrap();
+    assert_eq!(double(&robot, 1).await.unwrap(), 2);
+    assert_eq!(double(&robot, 21).await.unwrap(), 42);
+    robot.close().await.unwrap();
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn straying_from_the_recording_is_an_error() {
+    let path = record("diverge").await;
+    let robot = RobotClient::replay(&path).await.unwrap();
+    assert_eq!(double(&robot, 1).await.unwrap(), 2);
+    assert!(matches!(
+        double(&robot, 20).await,
+        Err(Error::TransportError(_))
+    ));
+    let error = robot.close().await.unwrap_err().to_string();
+    assert!(
+        error.starts_with("Replay diverged from the recording at frame"),
+        "{error}"
+    );
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn keep_alives_are_not_replayed() {
+    let path = record("keep-alive").await;
+    // A keep-alive whose message ID wrapped around to 0, which the client could send at any time
+    let keep_alive = Bytes::from_static(&[14, 0, 7, 48, 0, 0, 0, 0, 1, 0, 3, 16, 0, 0, 0, 0]);
+    let recording = std::fs::read_to_string(&path).unwrap();
+    let mut lines: Vec<String> = recording.lines().map(str::to_string).collect();
+    let hex: String = keep_alive.iter().map(|b| format!("{:02x}", b)).collect();
+    lines.insert(
+        lines.len() - 2,
+        format!("000000000000 tx protocol=7 {}", hex),
+    );
+    std::fs::write(&path, lines.join("\n")).unwrap();
+
+    let robot = RobotClient::replay(&path).await.unwrap();
+    assert_eq!(double(&robot, 1).await.unwrap(), 2);
+    assert_eq!(double(&robot, 21).await.unwrap(), 42);
+    robot.close().await.unwrap();
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,269 @@
+// A RobotClient talking to the simulator over a real socket
+use bytes::{Bytes, BytesMut};
+use piglet_client::client::{Error, ReconnectPolicy, RobotClient, Timeout};
+use piglet_client::dynamic_object::{DynamicObject, Method};
+use piglet_client::object_address::ObjectAddress;
+use piglet_client::testing::{SimulatedCall, SimulatedObject, Simulator};
+use piglet_client::values::{ErrorCode, PigletDeserialize, PigletSerialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+const DOOR_LOCK: ObjectAddress = ObjectAddress {
+    module_id: 1,
+    node_id: 1,
+    object_id: 268,
+};
+
+#[tokio::test]
+async fn calls_reach_the_simulator() {
+    let simulator = Simulator::builder()
+        .root(
+            DOOR_LOCK.object_id,
+            SimulatedObject {
+                name: "DoorLock".to_string(),
+                ..Default::default()
+            },
+        )
+        .on(&DOOR_LOCK, 1, 7, |mut parameters| {
+            let doubled = u32::deserialize(&mut parameters).unwrap() * 2;
+            let mut values = BytesMut::new();
+            doubled.serialize(&mut values);
+            Ok((1, values.freeze()))
+        })
+        .start()
+        .await
+        .unwrap();
+    let robot = RobotClient::connect(simulator.address()).await.unwrap();
+
+    let mut arguments = BytesMut::new();
+    21u32.serialize(&mut arguments);
+    let (count, mut values) = robot
+        .act(&DOOR_LOCK, 1, 3, 7, arguments.clone().freeze())
+        .await
+        .unwrap();
+    assert_eq!(count, 1);
+    assert_eq!(u32::deserialize(&mut values).unwrap(), 42);
+
+    // Leave out the introspection calls the client makes on its own
+    let calls: Vec<SimulatedCall> = simulator
+        .calls()
+        .into_iter()
+        .filter(|c| c.interface_id != 0)
+        .collect();
+    assert_eq!(
+        calls,
+        [SimulatedCall {
+            source: calls[0].source.clone(),
+            destination: DOOR_LOCK,
+            interface_id: 1,
+            call_type: 3,
+            call_type_id: 7,
+            parameters: Bytes::from(arguments),
+        }]
+    );
+
+    robot.close().await.unwrap();
+    simulator.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn the_object_tree_is_walked_once_when_first_needed() {
+    let simulator = Simulator::builder()
+        .root(
+            DOOR_LOCK.object_id,
+            SimulatedObject {
+                name: "DoorLock".to_string(),
+                ..Default::default()
+            },
+        )
+        .start()
+        .await
+        .unwrap();
+    let robot = RobotClient::connect(simulator.address()).await.unwrap();
+    assert!(simulator.calls().is_empty());
+
+    assert_eq!(robot.resolve("DoorLock").await.unwrap(), DOOR_LOCK);
+    let walked = simulator.calls().len();
+    assert!(walked > 0);
+    assert_eq!(robot.object_tree().await.roots.len(), 1);
+    assert_eq!(simulator.calls().len(), walked);
+
+    robot.close().await.unwrap();
+    simulator.close().await.unwrap();
+}
+
+// Each call answers with its own call type id
+async fn which(robot: &RobotClient, call_type_id: u16, timeout: Timeout) -> Result<u16, Error> {
+    let (_, mut values) = robot
+        .act_with_timeout(&DOOR_LOCK, 1, 3, call_type_id, Bytes::new(), timeout)
+        .await?;
+    u16::deserialize(&mut values)
+}
+
+#[tokio::test]
+async fn late_replies_are_not_mistaken_for_newer_ones() {
+    let mut builder = Simulator::builder()
+        .root(
+            DOOR_LOCK.object_id,
+            SimulatedObject {
+                name: "DoorLock".to_string(),
+                ..Default::default()
+            },
+        )
+        .delay(&DOOR_LOCK, 1, 1, Duration::from_millis(200));
+    for call_type_id in [1, 2] {
+        builder = builder.on(&DOOR_LOCK, 1, call_type_id, move |_| {
+            let mut values = BytesMut::new();
+            call_type_id.serialize(&mut values);
+            Ok((1, values.freeze()))
+        });
+    }
+    let simulator = builder.start().await.unwrap();
+    let robot = RobotClient::connect(simulator.address()).await.unwrap();
+
+    let slow = which(&robot, 1, Timeout::After(Duration::from_millis(50))).await;
+    assert!(matches!(slow, Err(Error::TimeoutError { .. })), "{slow:?}");
+    // Sent while the slow call's reply is still on its way
+    assert_eq!(which(&robot, 2, Timeout::Default).await.unwrap(), 2);
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(which(&robot, 2, Timeout::Default).await.unwrap(), 2);
+    // And with nothing to cut it short, the slow call gets its answer
+    assert_eq!(which(&robot, 1, Timeout::Never).await.unwrap(), 1);
+
+    robot.close().await.unwrap();
+    simulator.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn unanswered_calls_do_not_use_up_request_ids() {
+    let simulator = Simulator::builder()
+        .root(
+            DOOR_LOCK.object_id,
+            SimulatedObject {
+                name: "DoorLock".to_string(),
+                ..Default::default()
+            },
+        )
+        .on(&DOOR_LOCK, 1, 1, |_| Ok((0, Bytes::new())))
+        .delay(&DOOR_LOCK, 1, 1, Duration::from_secs(60))
+        .start()
+        .await
+        .unwrap();
+    let robot = RobotClient::connect(simulator.address()).await.unwrap();
+
+    // More than there are IDs, so the oldest ones have to be taken back
+    for _ in 0..300 {
+        let result = which(&robot, 1, Timeout::After(Duration::from_millis(1))).await;
+        assert!(
+            matches!(result, Err(Error::TimeoutError { .. })),
+            "{result:?}"
+        );
+    }
+
+    robot.close().await.unwrap();
+    simulator.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn reconnects_after_the_connection_drops() {
+    let simulator = Simulator::builder()
+        .root(
+            DOOR_LOCK.object_id,
+            SimulatedObject {
+                name: "DoorLock".to_string(),
+                ..Default::default()
+            },
+        )
+        .on(&DOOR_LOCK, 1, 1, |_| {
+            let mut values = BytesMut::new();
+            1u16.serialize(&mut values);
+            Ok((1, values.freeze()))
+        })
+        .start()
+        .await
+        .unwrap();
+    let robot = RobotClient::connect(simulator.address())
+        .await
+        .unwrap()
+        .with_reconnect(ReconnectPolicy {
+            attempts: Some(5),
+            delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(100),
+        });
+    assert_eq!(which(&robot, 1, Timeout::Default).await.unwrap(), 1);
+
+    simulator.disconnect();
+    while robot.is_connected() {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    while !robot.is_connected() {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(which(&robot, 1, Timeout::Default).await.unwrap(), 1);
+
+    // The robot gives every connection its own client ID, and the client sends from the new one
+    let sources: Vec<ObjectAddress> = simulator
+        .calls()
+        .into_iter()
+        .filter(|c| c.interface_id == 1)
+        .map(|c| c.source)
+        .collect();
+    assert_eq!(sources.len(), 2);
+    assert_ne!(sources[0], sources[1]);
+
+    robot.close().await.unwrap();
+    simulator.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn errors_are_named_without_asking_the_robot() {
+    let simulator = Simulator::builder()
+        .root(
+            DOOR_LOCK.object_id,
+            SimulatedObject {
+                name: "DoorLock".to_string(),
+                methods: vec![Method {
+                    call_type: 3,
+                    interface_id: 1,
+                    method_id: 1,
+                    name: "LockDoor".to_string(),
+                    parameter_labels: vec![],
+                    parameter_types: vec![],
+                }],
+                ..Default::default()
+            },
+        )
+        .on(&DOOR_LOCK, 1, 1, |_| Err(ErrorCode(0x32)))
+        .start()
+        .await
+        .unwrap();
+    let robot = Arc::new(RobotClient::connect(simulator.address()).await.unwrap());
+
+    // Nothing has read the tree or the methods yet, so the error only has numbers, and failing
+    // doesn't go and fetch them
+    let error = which(&robot, 1, Timeout::Default).await.unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "1-1-268 method 1:1 failed with code ErrorCode(50)"
+    );
+    assert!(simulator.calls().iter().all(|c| c.interface_id != 0));
+
+    DynamicObject::new(&DOOR_LOCK, &robot)
+        .methods()
+        .await
+        .unwrap();
+    robot.object_tree().await;
+    assert_eq!(robot.methods(&DOOR_LOCK).unwrap().len(), 1);
+    let error = which(&robot, 1, Timeout::Default).await.unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "DoorLock.LockDoor failed with code ErrorCode(50)"
+    );
+    let Error::CallError { errors, .. } = error else {
+        panic!("{error:?}");
+    };
+    assert_eq!(errors[0].interface_id(), Some(1));
+    assert_eq!(errors[0].method(), Some("LockDoor"));
+
+    simulator.close().await.unwrap();
+}
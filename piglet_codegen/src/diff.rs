@@ -0,0 +1,248 @@
+use crate::dump::{Dump, Enum, Method, Object, Struct};
+use piglet_client::dynamic_object::method_names;
+use std::collections::BTreeMap;
+
+pub struct Change {
+    // Whether code generated from the old dump could stop working against the new robot
+    pub breaking: bool,
+    pub description: String,
+}
+
+pub fn diff(old: &Dump, new: &Dump) -> Vec<Change> {
+    let mut changes = Vec::new();
+    // Matching by path means an object that moved to a new address is still paired up
+    let old_objects = old.paths();
+    let new_objects = new.paths();
+
+    for (path, old_object) in &old_objects {
+        let Some(new_object) = new_objects.get(path) else {
+            changes.push(breaking(format!(
+                "{} was removed from {}",
+                path, old_object.address
+            )));
+            continue;
+        };
+
+        if old_object.address != new_object.address {
+            changes.push(breaking(format!(
+                "{} moved from {} to {}",
+                path, old_object.address, new_object.address
+            )));
+        }
+        if old_object.version != new_object.version {
+            changes.push(additive(format!(
+                "{} version changed from {} to {}",
+                path, old_object.version, new_object.version
+            )));
+        }
+        diff_methods(path, old_object, new_object, &mut changes);
+        diff_enums(path, old_object, new_object, &mut changes);
+        diff_structs(path, old_object, new_object, &mut changes);
+    }
+
+    for (path, new_object) in &new_objects {
+        if !old_objects.contains_key(path) {
+            changes.push(additive(format!(
+                "{} was added at {}",
+                path, new_object.address
+            )));
+        }
+    }
+
+    changes
+}
+
+fn diff_methods(path: &str, old: &Object, new: &Object, changes: &mut Vec<Change>) {
+    let mut unmatched = methods(new);
+    let mut pairs = Vec::new();
+    let mut removed = Vec::new();
+    // Overloads are numbered by where they come in the list, so a method with the same name and id
+    // is the same method even if one was added before it and its number changed. Anything else is
+    // matched by the name its binding gets.
+    let same = |a: &Method, b: &Method| {
+        (&a.name, a.interface_id, a.method_id) == (&b.name, b.interface_id, b.method_id)
+    };
+    let mut by_name = Vec::new();
+    for (name, old_method) in methods(old) {
+        match unmatched.iter().position(|(_, m)| same(m, old_method)) {
+            Some(i) => pairs.push((name, old_method, unmatched.remove(i))),
+            None => by_name.push((name, old_method)),
+        }
+    }
+    for (name, old_method) in by_name {
+        match unmatched.iter().position(|(n, _)| *n == name) {
+            Some(i) => pairs.push((name, old_method, unmatched.remove(i))),
+            None => removed.push(name),
+        }
+    }
+    pairs.sort_by(|a, b| a.0.cmp(&b.0));
+    removed.sort();
+
+    for name in removed {
+        changes.push(breaking(format!("{}.{} was removed", path, name)));
+    }
+    for (name, old_method, (new_name, new_method)) in pairs {
+        if name != new_name {
+            changes.push(breaking(format!(
+                "{}.{} is now called {}",
+                path, name, new_name
+            )));
+        }
+        let id = |m: &Method| (m.interface_id, m.call_type, m.method_id);
+        if id(old_method) != id(new_method) {
+            changes.push(breaking(format!(
+                "{}.{} is now interface {} method {} (call type {}), was interface {} method {} (call type {})",
+                path,
+                name,
+                new_method.interface_id,
+                new_method.method_id,
+                new_method.call_type,
+                old_method.interface_id,
+                old_method.method_id,
+                old_method.call_type
+            )));
+        }
+        if old_method.parameter_labels != new_method.parameter_labels {
+            changes.push(breaking(format!(
+                "{}.{} parameters changed from ({}) to ({})",
+                path,
+                name,
+                old_method.parameter_labels.join(", "),
+                new_method.parameter_labels.join(", ")
+            )));
+        }
+        if old_method.parameter_types != new_method.parameter_types {
+            changes.push(breaking(format!(
+                "{}.{} parameter_types changed from {:?} to {:?}",
+                path, name, old_method.parameter_types, new_method.parameter_types
+            )));
+        }
+    }
+
+    unmatched.sort_by(|a, b| a.0.cmp(&b.0));
+    for (name, _) in unmatched {
+        changes.push(additive(format!("{}.{} was added", path, name)));
+    }
+}
+
+fn diff_enums(path: &str, old: &Object, new: &Object, changes: &mut Vec<Change>) {
+    let old_enums = enums(old);
+    let new_enums = enums(new);
+    for (name, old_enum) in &old_enums {
+        let Some(new_enum) = new_enums.get(name) else {
+            changes.push(breaking(format!("{} enum {} was removed", path, name)));
+            continue;
+        };
+
+        let old_values = enum_values(old_enum);
+        let new_values = enum_values(new_enum);
+        for (label, old_value) in &old_values {
+            match new_values.get(label) {
+                None => changes.push(breaking(format!(
+                    "{} enum {} lost {} = {}",
+                    path, name, label, old_value
+                ))),
+                Some(new_value) if new_value != old_value => changes.push(breaking(format!(
+                    "{} enum {} changed {} from {} to {}",
+                    path, name, label, old_value, new_value
+                ))),
+                Some(_) => {}
+            }
+        }
+        for (label, new_value) in &new_values {
+            if !old_values.contains_key(label) {
+                changes.push(additive(format!(
+                    "{} enum {} gained {} = {}",
+                    path, name, label, new_value
+                )));
+            }
+        }
+    }
+
+    for name in new_enums.keys() {
+        if !old_enums.contains_key(name) {
+            changes.push(additive(format!("{} enum {} was added", path, name)));
+        }
+    }
+}
+
+fn diff_structs(path: &str, old: &Object, new: &Object, changes: &mut Vec<Change>) {
+    let old_structs = structs(old);
+    let new_structs = structs(new);
+    for (name, old_struct) in &old_structs {
+        match new_structs.get(name) {
+            None => changes.push(breaking(format!("{} struct {} was removed", path, name))),
+            Some(new_struct)
+                if old_struct.element_labels != new_struct.element_labels
+                    || old_struct.element_types != new_struct.element_types =>
+            {
+                changes.push(breaking(format!(
+                    "{} struct {} changed from ({}) {:?} to ({}) {:?}",
+                    path,
+                    name,
+                    old_struct.element_labels.join(", "),
+                    old_struct.element_types,
+                    new_struct.element_labels.join(", "),
+                    new_struct.element_types
+                )))
+            }
+            Some(_) => {}
+        }
+    }
+
+    for name in new_structs.keys() {
+        if !old_structs.contains_key(name) {
+            changes.push(additive(format!("{} struct {} was added", path, name)));
+        }
+    }
+}
+
+fn breaking(description: String) -> Change {
+    Change {
+        breaking: true,
+        description,
+    }
+}
+
+fn additive(description: String) -> Change {
+    Change {
+        breaking: false,
+        description,
+    }
+}
+
+// Methods with the name their binding gets, Name_1, Name_2 and so on where several share a name
+fn methods(object: &Object) -> Vec<(String, &Method)> {
+    method_names(object.methods.iter().map(|m| m.name.as_str()))
+        .into_iter()
+        .zip(&object.methods)
+        .collect()
+}
+
+fn enums(object: &Object) -> BTreeMap<&str, &Enum> {
+    object
+        .interfaces
+        .iter()
+        .flatten()
+        .flat_map(|i| &i.enums)
+        .map(|e| (e.name.as_str(), e))
+        .collect()
+}
+
+fn enum_values(e: &Enum) -> BTreeMap<&str, i32> {
+    e.labels
+        .iter()
+        .map(String::as_str)
+        .zip(e.values.iter().copied())
+        .collect()
+}
+
+fn structs(object: &Object) -> BTreeMap<&str, &Struct> {
+    object
+        .interfaces
+        .iter()
+        .flatten()
+        .flat_map(|i| &i.structs)
+        .map(|s| (s.name.as_str(), s))
+        .collect()
+}
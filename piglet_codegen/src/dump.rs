@@ -0,0 +1,238 @@
+use piglet_client::{
+    client::RobotClient,
+    compatibility,
+    dynamic_object::{self, DynamicObject, sibling_paths},
+    object_address::ObjectAddress,
+    object_tree::ObjectNode,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+// Everything the generator reads off a robot, so bindings can be regenerated from a file without
+// an instrument on hand
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Dump {
+    // Set by hand on dumps pieced together from existing bindings instead of read off a robot. The
+    // names, interface labels and layout of the objects in those are guesses.
+    #[serde(default)]
+    pub reconstructed: bool,
+    pub globals: Vec<Object>,
+    pub objects: Vec<Object>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Object {
+    #[serde(deserialize_with = "parse_address", serialize_with = "format_address")]
+    pub address: ObjectAddress,
+    pub name: String,
+    pub version: String,
+    // None when the object wouldn't describe its interfaces, in which case it has no methods either
+    pub interfaces: Option<Vec<Interface>>,
+    pub methods: Vec<Method>,
+    pub subobjects: Vec<Object>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Interface {
+    pub id: u8,
+    pub label: String,
+    pub enums: Vec<Enum>,
+    pub structs: Vec<Struct>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Method {
+    pub interface_id: u8,
+    pub call_type: u8,
+    pub method_id: u16,
+    pub name: String,
+    pub parameter_labels: Vec<String>,
+    pub parameter_types: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Enum {
+    pub name: String,
+    pub labels: Vec<String>,
+    pub values: Vec<i32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Struct {
+    pub name: String,
+    pub element_labels: Vec<String>,
+    pub element_types: Vec<u8>,
+}
+
+impl Dump {
+    pub async fn from_robot(robot: &Arc<RobotClient>) -> Result<Self, anyhow::Error> {
+        let mut globals = Vec::new();
+        let mut objects = Vec::new();
+        for node in &robot.object_tree().await.roots {
+            let object = Object::from_robot(node, robot).await?;
+            if robot.globals.contains(&node.object.address) {
+                globals.push(object);
+            } else {
+                objects.push(object);
+            }
+        }
+        Ok(Self {
+            reconstructed: false,
+            globals,
+            objects,
+        })
+    }
+}
+
+impl Dump {
+    // Every object keyed by the names leading to it, the way RobotClient::resolve finds them
+    pub fn paths(&self) -> BTreeMap<String, &Object> {
+        let mut found = BTreeMap::new();
+        add_paths("", self.globals.iter().chain(&self.objects), &mut found);
+        found
+    }
+}
+
+fn add_paths<'a>(
+    prefix: &str,
+    siblings: impl Iterator<Item = &'a Object> + Clone,
+    found: &mut BTreeMap<String, &'a Object>,
+) {
+    let names = siblings.clone().map(|o| o.name.as_str());
+    for (path, object) in sibling_paths(prefix, names).into_iter().zip(siblings) {
+        add_paths(&format!("{}.", path), object.subobjects.iter(), found);
+        found.insert(path, object);
+    }
+}
+
+impl Object {
+    async fn from_robot(
+        node: &ObjectNode,
+        robot: &Arc<RobotClient>,
+    ) -> Result<Self, anyhow::Error> {
+        let dynamic = DynamicObject::new(&node.object.address, robot);
+        println!("{:?}", node.object);
+
+        let mut interfaces = None;
+        let mut methods = Vec::new();
+        if let Some(found) = &node.interfaces {
+            let mut described = Vec::new();
+            for interface in found {
+                described.push(Interface {
+                    id: interface.id,
+                    label: interface.label.clone(),
+                    enums: dynamic
+                        .get_enums(interface.id)
+                        .await?
+                        .into_iter()
+                        .map(Enum::from)
+                        .collect(),
+                    structs: dynamic
+                        .get_structs(interface.id)
+                        .await?
+                        .into_iter()
+                        .map(Struct::from)
+                        .collect(),
+                });
+            }
+            interfaces = Some(described);
+
+            for i in 0..node.object.method_count {
+                let method = dynamic.get_method(i).await?;
+                println!("{:?}", method);
+                methods.push(Method::from(method));
+            }
+        }
+
+        let mut subobjects = Vec::new();
+        for child in &node.children {
+            subobjects.push(Box::pin(Object::from_robot(child, robot)).await?);
+        }
+
+        Ok(Self {
+            address: node.object.address.clone(),
+            name: node.object.name.clone(),
+            version: node.object.version.clone(),
+            interfaces,
+            methods,
+            subobjects,
+        })
+    }
+}
+
+impl Object {
+    // See piglet_client::compatibility::fingerprint, None if the object didn't describe its methods
+    pub fn fingerprint(&self) -> Option<u64> {
+        self.interfaces.as_ref()?;
+        Some(compatibility::fingerprint(
+            &self.methods.iter().map(Into::into).collect::<Vec<_>>(),
+        ))
+    }
+}
+
+impl From<dynamic_object::Method> for Method {
+    fn from(method: dynamic_object::Method) -> Self {
+        Self {
+            interface_id: method.interface_id,
+            call_type: method.call_type,
+            method_id: method.method_id,
+            name: method.name,
+            parameter_labels: method.parameter_labels,
+            parameter_types: method.parameter_types,
+        }
+    }
+}
+
+impl From<&Method> for dynamic_object::Method {
+    fn from(method: &Method) -> Self {
+        Self {
+            call_type: method.call_type,
+            interface_id: method.interface_id,
+            method_id: method.method_id,
+            name: method.name.clone(),
+            parameter_labels: method.parameter_labels.clone(),
+            parameter_types: method.parameter_types.clone(),
+        }
+    }
+}
+
+impl From<dynamic_object::Enum> for Enum {
+    fn from(e: dynamic_object::Enum) -> Self {
+        Self {
+            name: e.name,
+            labels: e.labels,
+            values: e.values,
+        }
+    }
+}
+
+impl From<dynamic_object::Struct> for Struct {
+    fn from(s: dynamic_object::Struct) -> Self {
+        Self {
+            name: s.name,
+            element_labels: s.element_labels,
+            element_types: s.element_types,
+        }
+    }
+}
+
+// Addresses are written the way they're printed, like 1-1-257
+fn format_address<S: Serializer>(address: &ObjectAddress, s: S) -> Result<S::Ok, S::Error> {
+    s.collect_str(address)
+}
+
+fn parse_address<'de, D: Deserializer<'de>>(d: D) -> Result<ObjectAddress, D::Error> {
+    let text = String::deserialize(d)?;
+    match text.split('-').collect::<Vec<_>>()[..] {
+        [module_id, node_id, object_id] => Ok(ObjectAddress {
+            module_id: module_id.parse().map_err(de::Error::custom)?,
+            node_id: node_id.parse().map_err(de::Error::custom)?,
+            object_id: object_id.parse().map_err(de::Error::custom)?,
+        }),
+        _ => Err(de::Error::custom(format!(
+            "Expected an address like 1-1-257, not {}",
+            text
+        ))),
+    }
+}
@@ -1,15 +1,19 @@
+mod diff;
+mod dump;
 mod parameters;
+mod units;
 
+use crate::dump::{Dump, Method, Object};
 use crate::parameters::ParameterUse::{Argument, ReturnElement, ReturnValue};
 use anyhow::anyhow;
 use convert_case::{Case, Casing};
 use piglet_client::{
     client::{Error::ConnectionError, RobotClient},
-    dynamic_object::{DynamicObject, Method, Object},
+    dynamic_object::{Position, method_names, parameter_type},
     object_address::ObjectAddress,
-    values::PigletCodec,
+    values::{ErrorCode, PigletCodec, has_definition},
 };
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::env;
 use std::sync::Arc;
 use tokio::io::AsyncWriteExt;
@@ -21,98 +25,180 @@ struct GeneratedModule {
     enum_defs: Vec<String>,
     method_defs: Vec<String>,
     struct_defs: Vec<String>,
-    unknown: bool,
+    // The methods again, declared on the object's Api trait and implemented by it and its Fake
+    trait_defs: Vec<String>,
+    trait_impls: Vec<String>,
+    fake_defs: Vec<String>,
+    unit_imports: BTreeSet<&'static str>,
+    // The name the robot gives the object, rather than the prefixed one above
+    object_name: String,
     version: String,
-}
-
-struct MethodGroup {
-    methods: Vec<Method>,
+    // None when the object didn't describe its methods
+    fingerprint: Option<u64>,
 }
 
 #[tokio::main]
 async fn main() -> Result<(), anyhow::Error> {
     let args: Vec<String> = env::args().collect();
-    if args.len() != 3 {
-        anyhow::bail!("Usage: {} <ip> <name>", args[0]);
+    match args[1..].iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["dump", ip, path] => {
+            let dump = dump_robot(ip).await?;
+            fs::write(path, serde_json::to_string_pretty(&dump)? + "\n").await?;
+        }
+        ["generate", path, name] => generate(&read_dump(path).await?, name).await?,
+        ["diff", old, new] => print_diff(&read_dump(old).await?, &read_dump(new).await?),
+        [ip, name] => generate(&dump_robot(ip).await?, name).await?,
+        _ => anyhow::bail!(
+            "Usage: {0} dump <ip> <out.json>\n       {0} generate <dump.json> <name>\n       {0} diff <old.json> <new.json>\n       {0} <ip> <name>",
+            args[0]
+        ),
     }
+    Ok(())
+}
 
-    println!("Connecting to {}...", args[1]);
+async fn dump_robot(ip: &str) -> Result<Dump, anyhow::Error> {
+    println!("Connecting to {}...", ip);
     let robot = Arc::new(
-        RobotClient::connect(&args[1])
+        RobotClient::connect(ip)
             .await
             .map_err(|e| anyhow!("Error connecting to robot: {}", e))?,
     );
+    let dump = Dump::from_robot(&robot).await?;
+    Arc::try_unwrap(robot)
+        .map_err(|_| anyhow!("Error closing robot"))?
+        .close()
+        .await?;
+    Ok(dump)
+}
 
+// An ExpectedObject for every object in the tree, each with its own version and methods since
+// objects that share a module don't always match. A reconstructed dump's names and methods weren't
+// read off a robot, so for those only the address is checked.
+fn expected_objects<'a>(
+    objects: impl Iterator<Item = &'a Object>,
+    reconstructed: bool,
+) -> Vec<String> {
+    let mut expected = Vec::new();
+    for object in objects {
+        let (name, version, fingerprint) = if reconstructed {
+            ("None".to_string(), "None".to_string(), None)
+        } else {
+            (
+                format!("Some({:?})", object.name),
+                format!("Some({:?})", object.version),
+                object.fingerprint(),
+            )
+        };
+        expected.push(format!(
+            r#"
+  ExpectedObject {{
+    address: {:?},
+    name: {},
+    version: {},
+    fingerprint: {},
+  }},"#,
+            object.address,
+            name,
+            version,
+            match fingerprint {
+                Some(fingerprint) => format!("Some(0x{:016x})", fingerprint),
+                None => "None".to_string(),
+            }
+        ));
+        expected.extend(expected_objects(object.subobjects.iter(), reconstructed));
+    }
+    expected
+}
+
+async fn read_dump(path: &str) -> Result<Dump, anyhow::Error> {
+    serde_json::from_str(&fs::read_to_string(path).await?)
+        .map_err(|e| anyhow!("Error reading {}: {}", path, e))
+}
+
+fn print_diff(old: &Dump, new: &Dump) {
+    let (breaking, additive): (Vec<_>, Vec<_>) =
+        diff::diff(old, new).into_iter().partition(|c| c.breaking);
+    if !breaking.is_empty() {
+        println!("Breaking changes:");
+        for change in &breaking {
+            println!("  - {}", change.description);
+        }
+    }
+    if !additive.is_empty() {
+        println!("Additive changes:");
+        for change in &additive {
+            println!("  + {}", change.description);
+        }
+    }
+    println!("{} breaking, {} additive", breaking.len(), additive.len());
+}
+
+async fn generate(dump: &Dump, name: &str) -> Result<(), anyhow::Error> {
     let mut global_enums = HashMap::new();
     let mut global_imports = Vec::new();
     let mut global_structs = HashMap::new();
-    for root in &robot.globals {
-        let dynamic = DynamicObject::new(&root, &robot);
-        let mut object = dynamic.get_object().await?;
-        object.name = object.name.to_case(Case::Pascal);
-        let module = object.name.from_case(Case::Pascal).to_case(Case::Snake);
-
-        let interfaces = dynamic.get_interfaces().await?;
-        for interface in interfaces {
-            let mut enumerations = dynamic.get_enums(interface.id).await?;
-            for e in &mut enumerations {
-                e.name = format_type_name(&e.name);
-                global_enums.insert(global_enums.len() as u8, e.name.clone());
-                global_imports.push(format!("{}::{}", module, e.name));
+    for root in &dump.globals {
+        let module = root
+            .name
+            .to_case(Case::Pascal)
+            .from_case(Case::Pascal)
+            .to_case(Case::Snake);
+
+        for interface in root.interfaces.iter().flatten() {
+            for e in &interface.enums {
+                let name = format_type_name(&e.name);
+                global_enums.insert(global_enums.len() as u8, name.clone());
+                global_imports.push(format!("{}::{}", module, name));
             }
 
-            let mut structs = dynamic.get_structs(interface.id).await?;
-            for s in &mut structs {
-                s.name = format_type_name(&s.name);
-                global_structs.insert(global_structs.len() as u8, s.name.clone());
-                global_imports.push(format!("{}::{}", module, s.name));
+            for s in &interface.structs {
+                let name = format_type_name(&s.name);
+                global_structs.insert(global_structs.len() as u8, name.clone());
+                global_imports.push(format!("{}::{}", module, name));
             }
         }
     }
 
-    let mut modules = HashMap::new();
-    for address in &robot.globals {
-        build_modules(
-            &address,
-            &robot,
-            &"".to_string(),
-            &global_enums,
-            &global_structs,
-            &mut modules,
-        )
-        .await?;
+    let mut modules = BTreeMap::new();
+    for object in dump.globals.iter().chain(&dump.objects) {
+        build_modules(object, "", &global_enums, &global_structs, &mut modules)?;
     }
-    for address in &robot.objects {
-        build_modules(
-            &address,
-            &robot,
-            &"".to_string(),
-            &global_enums,
-            &global_structs,
-            &mut modules,
-        )
-        .await?;
-    }
-    Arc::try_unwrap(robot)
-        .map_err(|_| anyhow!("Error closing robot"))?
-        .close()
-        .await?;
 
-    let root = format!("piglet_generated/src/{}", args[2]);
+    let root = format!("piglet_generated/src/{}", name);
     fs::create_dir_all(&root).await?;
 
+    let paths: HashMap<ObjectAddress, String> = dump
+        .paths()
+        .into_iter()
+        .map(|(path, object)| (object.address.clone(), path))
+        .collect();
     let mut module_files = Vec::new();
     for module in modules.values() {
         let filename = module.name.from_case(Case::Pascal).to_case(Case::Snake);
         module_files.push(filename.clone());
 
         let mut file = File::create(format!("{}/{}.rs", root, filename)).await?;
-        dump_module(&module, &global_imports, &mut file, &filename, &args[2])
-            .await
-            .map_err(|e| ConnectionError(e))?;
+        dump_module(
+            module,
+            &global_imports,
+            &paths,
+            &mut file,
+            &filename,
+            &name.to_string(),
+            dump.reconstructed,
+        )
+        .await
+        .map_err(ConnectionError)?;
     }
 
-    let mut file = File::create(format!("piglet_generated/src/{}.rs", args[2])).await?;
+    let mut file = File::create(format!("piglet_generated/src/{}.rs", name)).await?;
+    if dump.reconstructed {
+        file.write_all(
+            "// Generated from a reconstructed dump rather than one read off a robot, so the object names\n// and paths in here haven't been checked against an instrument\n"
+                .as_bytes(),
+        )
+        .await?;
+    }
     file.write_all("#![allow(unused)]\n\n".as_bytes()).await?;
     file.write_all(
         module_files
@@ -123,6 +209,31 @@ async fn main() -> Result<(), anyhow::Error> {
             .as_bytes(),
     )
     .await?;
+    file.write_all(
+        format!(
+            r#"
+use piglet_client::{{
+  client::{{Error, RobotClient}},
+  compatibility::{{self, ExpectedObject}},
+  object_address::ObjectAddress,
+}};
+use std::sync::Arc;
+
+// Every object these bindings were generated from
+pub const OBJECTS: &[ExpectedObject] = &[{}
+];
+
+// Checks that the robot's firmware still has these objects at the same addresses with the same
+// methods, so a mismatch is caught up front instead of calling the wrong method id
+pub async fn verify(robot: &Arc<RobotClient>) -> Result<(), Error> {{
+  compatibility::verify(robot, OBJECTS).await
+}}
+"#,
+            expected_objects(dump.globals.iter().chain(&dump.objects), dump.reconstructed).join("")
+        )
+        .as_bytes(),
+    )
+    .await?;
 
     Ok(())
 }
@@ -139,82 +250,178 @@ struct StructId {
     interface_id: u8,
 }
 
+// Every struct and enum a module can refer to, by where they're defined
+struct Definitions<'a> {
+    global_enums: &'a HashMap<u8, String>,
+    global_structs: &'a HashMap<u8, String>,
+    enums: &'a HashMap<EnumId, String>,
+    structs: &'a HashMap<StructId, String>,
+}
+
+impl Definitions<'_> {
+    // The name of the struct or enum a type refers to, from the two codes after it: where it's
+    // defined (1 for the global objects, 2 for the interface and 3 for the firmware's own network
+    // types) and which one it is, counting from 1
+    fn name(&self, type_id: u8, interface_id: u8, codes: &[u8]) -> Result<String, anyhow::Error> {
+        let &[source_id, id, ..] = codes else {
+            anyhow::bail!("Type {} is missing where it's defined", type_id);
+        };
+        let index = id.wrapping_sub(1);
+        let is_struct = matches!(type_id, 30 | 31);
+        let found = match (source_id, is_struct) {
+            (1, true) => self.global_structs.get(&index),
+            (2, true) => self.structs.get(&StructId {
+                interface_id,
+                struct_id: index,
+            }),
+            (3, true) => return Ok("NetworkResult".to_string()),
+            (1, false) => self.global_enums.get(&index),
+            (2, false) => self.enums.get(&EnumId {
+                interface_id,
+                enum_id: index,
+            }),
+            _ => anyhow::bail!("Unknown source {}", source_id),
+        };
+        found.cloned().ok_or_else(|| {
+            anyhow!(
+                "No type {} numbered {} in source {}",
+                type_id,
+                id,
+                source_id
+            )
+        })
+    }
+}
+
+// The Rust type for a type id that isn't a struct or enum, and whether it's an array of it
+fn primitive_type(type_id: u8) -> Option<(&'static str, bool)> {
+    Some(match type_id {
+        i8::TYPE_ID => ("i8", false),
+        i16::TYPE_ID => ("i16", false),
+        i32::TYPE_ID => ("i32", false),
+        u8::TYPE_ID => ("u8", false),
+        u16::TYPE_ID => ("u16", false),
+        u32::TYPE_ID => ("u32", false),
+        f32::TYPE_ID => ("f32", false),
+        bool::TYPE_ID => ("bool", false),
+        String::TYPE_ID => ("String", false),
+        ErrorCode::TYPE_ID => ("piglet_client::values::ErrorCode", false),
+        Vec::<i16>::TYPE_ID => ("i16", true),
+        Vec::<i32>::TYPE_ID => ("i32", true),
+        Vec::<u8>::TYPE_ID => ("u8", true),
+        Vec::<u16>::TYPE_ID => ("u16", true),
+        Vec::<u32>::TYPE_ID => ("u32", true),
+        Vec::<bool>::TYPE_ID => ("bool", true),
+        Vec::<String>::TYPE_ID => ("String", true),
+        _ => return None,
+    })
+}
+
 #[derive(Clone, Debug)]
 struct Parameter {
     name: String,
     is_as_ref: bool,
     rust_type: String,
-    wrap_in_mvec: bool,
+    // Set for values that are a piglet_client::units type instead of a raw integer
+    unit: Option<&'static str>,
+    // A single struct, which unlike everything else passed by value isn't Copy
+    is_struct: bool,
 }
 
-async fn build_modules(
-    address: &ObjectAddress,
-    robot: &Arc<RobotClient>,
-    prefix: &String,
+// Methods with at least this many arguments also get a Request struct, since a long row of
+// positional integers is easy to get out of order
+const REQUEST_ARGUMENTS: usize = 9;
+
+// Goes on every struct and enum written out, for crates with a serde feature like piglet_generated
+const SERDE_DERIVE: &str =
+    r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#;
+
+impl Parameter {
+    // The type callers see, which is the unit in place of the integer if there is one
+    fn public_type(&self) -> String {
+        match self.unit {
+            Some(unit) if self.is_as_ref => format!("impl AsRef<[{}]>", unit),
+            Some(unit) if self.rust_type.starts_with("Vec::<") => format!("Vec::<{}>", unit),
+            Some(unit) => unit.to_string(),
+            None => self.rust_type.clone(),
+        }
+    }
+
+    // The owned type a Request struct holds this argument as
+    fn owned_type(&self) -> String {
+        let rust_type = self.public_type();
+        if let Some(inner) = rust_type
+            .strip_prefix("impl AsRef<[")
+            .and_then(|t| t.strip_suffix("]>"))
+        {
+            format!("Vec<{}>", inner)
+        } else if rust_type == "&str" {
+            "String".to_string()
+        } else {
+            rust_type
+        }
+    }
+}
+
+fn build_modules(
+    object: &Object,
+    prefix: &str,
     global_enums: &HashMap<u8, String>,
     global_structs: &HashMap<u8, String>,
-    modules: &mut HashMap<String, GeneratedModule>,
+    modules: &mut BTreeMap<String, GeneratedModule>,
 ) -> Result<(), anyhow::Error> {
-    let dynamic = DynamicObject::new(&address, &robot);
-    let mut object = dynamic.get_object().await?;
-    println!("{:?}", object);
-    object.name = object.name.to_case(Case::Pascal);
-
-    let prefixed = format!("{}{}", prefix, object.name);
+    let prefixed = format!("{}{}", prefix, object.name.to_case(Case::Pascal));
     if let Some(existing) = modules.get_mut(&prefixed) {
-        existing.addresses.push(address.clone());
+        existing.addresses.push(object.address.clone());
     } else {
         modules.insert(
             prefixed.clone(),
-            generate_module(&object, &prefixed, &dynamic, global_enums, global_structs).await?,
+            generate_module(object, &prefixed, global_enums, global_structs)?,
         );
     }
 
-    for i in 0..(object.subobject_count) {
-        let subobject = dynamic.get_subobject_address(i).await?;
-        Box::pin(build_modules(
-            &subobject,
-            &robot,
-            &prefixed,
-            &global_enums,
-            &global_structs,
-            modules,
-        ))
-        .await?;
+    for subobject in &object.subobjects {
+        build_modules(subobject, &prefixed, global_enums, global_structs, modules)?;
     }
 
     Ok(())
 }
 
-async fn generate_module(
+fn generate_module(
     object: &Object,
     name: &String,
-    dynamic: &DynamicObject,
     global_enums: &HashMap<u8, String>,
     global_structs: &HashMap<u8, String>,
 ) -> Result<GeneratedModule, anyhow::Error> {
     let address = &object.address;
-    let interfaces_result = dynamic.get_interfaces().await;
-    if let Err(_) = interfaces_result {
+    let Some(interfaces) = &object.interfaces else {
         return Ok(GeneratedModule {
             name: name.clone(),
             addresses: vec![address.clone()],
             enum_defs: vec![],
             method_defs: vec![],
             struct_defs: vec![],
-            unknown: true,
+            trait_defs: vec![],
+            trait_impls: vec![],
+            fake_defs: vec![],
+            unit_imports: BTreeSet::new(),
+            object_name: object.name.clone(),
             version: object.version.clone(),
+            fingerprint: None,
         });
-    }
+    };
 
-    let interfaces = interfaces_result?;
     let mut enums = HashMap::new();
     let mut enum_defs = Vec::new();
     let mut method_defs = Vec::new();
+    let mut trait_defs = Vec::new();
+    let mut trait_impls = Vec::new();
+    let mut fake_defs = Vec::new();
     let mut structs = HashMap::new();
     let mut struct_defs = Vec::new();
-    for interface in &interfaces {
-        let mut enumerations = dynamic.get_enums(interface.id).await?;
+    let mut unit_imports = BTreeSet::new();
+    for interface in interfaces {
+        let mut enumerations = interface.enums.clone();
         for i in 0..enumerations.len() {
             let e = &mut enumerations[i];
             e.name = format_type_name(&e.name);
@@ -228,10 +435,11 @@ async fn generate_module(
 
             let mut def = vec![format!(
                 r#"
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PigletCodec)]
+{}
 pub enum {} {{
 "#,
-                e.name
+                SERDE_DERIVE, e.name
             )];
             for v in 0..e.labels.len() {
                 def.push(format!(
@@ -240,108 +448,14 @@ pub enum {} {{
                     e.values[v]
                 ));
             }
-
             def.push("}\n".to_string());
 
-            def.push(format!(
-                r#"
-impl TryFrom<i32> for {} {{
-  type Error = piglet_client::client::Error;
-
-  fn try_from(v: i32) -> Result<Self, Self::Error> {{
-    match v {{
-"#,
-                e.name
-            ));
-            for v in 0..e.labels.len() {
-                def.push(format!(
-                    "      {} => Ok({}::{}),",
-                    e.values[v],
-                    e.name,
-                    e.labels[v].from_case(Case::Constant).to_case(Case::Pascal)
-                ));
-            }
-            def.push(format!(
-                r#"
-      _ => Err(ConnectionError(anyhow!("Unknown {} value {{}}", v))),
-    }}
-  }}
-}}
-
-impl PigletCodec for {} {{
-  const TYPE_ID: u8 = 32;
-}}
-
-impl PigletDeserialize for {} {{
-  fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {{
-    let type_id = stream.get_u8();
-    if Self::TYPE_ID != type_id {{
-        return Err(ConnectionError(anyhow!("Expected {{}} but got {{}}", Self::TYPE_ID, type_id)));
-    }}
-    let _flags = stream.get_u8();
-    let length = stream.get_u16_le() as usize;
-    let mut bytes = stream.copy_to_bytes(length);
-    stream.get_i32_le().try_into()
-  }}
-}}
-
-impl PigletSerialize for {} {{
-  fn serialize(&self, stream: &mut BytesMut) {{
-    stream.put_u8(Self::TYPE_ID);
-    stream.put_u8(0);
-    stream.put_u16_le(4);
-    stream.put_i32_le(*self as i32);
-  }}
-
-}}
-
-impl PigletCodec for MSlice<'_, {}> {{
-  const TYPE_ID: u8 = 35;
-}}
-
-impl PigletCodec for MVec<{}> {{
-  const TYPE_ID: u8 = 35;
-}}
-
-impl PigletDeserialize for MVec<{}> {{
-  fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {{
-    let type_id = stream.get_u8();
-    if Self::TYPE_ID != type_id {{
-        return Err(ConnectionError(anyhow!("Expected {{}} but got {{}}", Self::TYPE_ID, type_id)));
-    }}
-    let _flags = stream.get_u8();
-    let length = stream.get_u16_le() as usize;
-    let mut arr = Vec::new();
-    for i in 0..(length / 4) {{
-      arr.push(stream.get_i32_le().try_into()?);
-    }}
-    Ok(MVec(arr))
-  }}
-}}
-
-impl PigletSerialize for MSlice<'_, {}> {{
-  fn serialize(&self, bytes: &mut BytesMut) {{
-    bytes.put_u8(Self::TYPE_ID);
-    bytes.put_u8(0);
-    bytes.put_u16_le(4 * self.0.len() as u16);
-    for v in self.0.as_ref() {{
-      bytes.put_i32_le(*v as i32);
-    }}
-  }}
-}}
-
-"#,
-                e.name, e.name, e.name, e.name, e.name, e.name, e.name, e.name
-            ));
-
             enum_defs.push(def.join("\n"));
         }
     }
 
-    for interface in &interfaces {
-        let structures = dynamic.get_structs(interface.id).await?;
-        for i in 0..structures.len() {
-            let s = &structures[i];
+    for interface in interfaces {
+        for (i, s) in interface.structs.iter().enumerate() {
             structs.insert(
                 StructId {
                     interface_id: interface.id,
@@ -349,278 +463,75 @@ impl PigletSerialize for MSlice<'_, {}> {{
                 },
                 s.name.clone(),
             );
-
+        }
+    }
+    let definitions = Definitions {
+        global_enums,
+        global_structs,
+        enums: &enums,
+        structs: &structs,
+    };
+
+    for interface in interfaces {
+        for (i, s) in interface.structs.iter().enumerate() {
             let mut def = vec![format!(
                 r#"
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PigletCodec)]
+{}
 pub struct {} {{
 "#,
-                s.name
+                SERDE_DERIVE, s.name
             )];
-            let mut deserializes = vec![];
-            let mut serializes = vec![];
             let mut type_offset = 0;
             for m in 0..s.element_labels.len() {
-                let rust_type = match s.element_types[type_offset] {
-                    i8::TYPE_ID => "i8",
-                    i16::TYPE_ID => "i16",
-                    i32::TYPE_ID => "i32",
-                    u8::TYPE_ID => "u8",
-                    u16::TYPE_ID => "u16",
-                    u32::TYPE_ID => "u32",
-                    String::TYPE_ID => "String",
-                    Vec::<u8>::TYPE_ID => "Vec::<u8>",
-                    bool::TYPE_ID => "bool",
-                    Vec::<i16>::TYPE_ID => "Vec::<i16>",
-                    Vec::<u16>::TYPE_ID => "Vec::<u16>",
-                    Vec::<i32>::TYPE_ID => "Vec::<i32>",
-                    Vec::<u32>::TYPE_ID => "Vec::<u32>",
-                    Vec::<bool>::TYPE_ID => "Vec::<bool>",
-                    30 => {
-                        let source_id = s.element_types[type_offset + 1];
-                        let id = s.element_types[type_offset + 2];
-                        let e = match source_id {
-                            1 => &global_structs[&(id - 1)],
-                            2 => {
-                                &structs[&StructId {
-                                    interface_id: interface.id,
-                                    struct_id: id - 1,
-                                }]
-                            }
-                            3 => "NetworkResult",
-                            _ => anyhow::bail!("Unknown source {}", source_id),
-                        };
-                        type_offset += 2;
-                        e
-                    }
-                    31 => {
-                        let source_id = s.element_types[type_offset + 1];
-                        let id = s.element_types[type_offset + 2];
-                        let e = match source_id {
-                            1 => &global_structs[&(id - 1)],
-                            2 => {
-                                &structs[&StructId {
-                                    interface_id: interface.id,
-                                    struct_id: id - 1,
-                                }]
-                            }
-                            3 => "NetworkResult",
-                            _ => anyhow::bail!("Unknown source {}", source_id),
-                        };
-                        type_offset += 2;
-                        &format!("Vec::<{}>", e)
-                    }
-                    32 => {
-                        let source_id = s.element_types[type_offset + 1];
-                        let id = s.element_types[type_offset + 2];
-                        let e = match source_id {
-                            1 => &global_enums[&(id - 1)],
-                            2 => {
-                                &enums[&EnumId {
-                                    interface_id: interface.id,
-                                    enum_id: id - 1,
-                                }]
-                            }
-                            _ => anyhow::bail!("Unknown source {}", source_id),
-                        };
-                        type_offset += 2;
-                        e
+                let type_id = s.element_types[type_offset];
+                let rust_type = if has_definition(type_id) {
+                    let e = definitions.name(
+                        type_id,
+                        interface.id,
+                        &s.element_types[type_offset + 1..],
+                    )?;
+                    type_offset += 2;
+                    match type_id {
+                        31 | 35 => format!("Vec::<{}>", e),
+                        _ => e,
                     }
-                    33 => "piglet_client::values::ErrorCode",
-                    35 => {
-                        let source_id = s.element_types[type_offset + 1];
-                        let id = s.element_types[type_offset + 2];
-                        let e = match source_id {
-                            1 => &global_enums[&(id - 1)],
-                            2 => {
-                                &enums[&EnumId {
-                                    interface_id: interface.id,
-                                    enum_id: id - 1,
-                                }]
-                            }
-                            _ => anyhow::bail!("Unknown source {}", source_id),
-                        };
-                        type_offset += 2;
-                        &format!("Vec::<{}>", e)
+                } else {
+                    match primitive_type(type_id) {
+                        Some((e, true)) => format!("Vec::<{}>", e),
+                        Some((e, false)) => e.to_string(),
+                        None => anyhow::bail!("Unknown value type {}", type_id),
                     }
-                    Vec::<String>::TYPE_ID => "Vec::<String>",
-                    _ => anyhow::bail!("Unknown value type {}", s.element_types[type_offset]),
                 };
                 let name = format_member_name(&s.element_labels[m]);
                 def.push(format!("  pub {}: {},", name, rust_type));
-
-                let (deserialize, serialize) = match s.element_types[type_offset] {
-                    31 | 35 => (
-                        format!(
-                            "{}: MVec::<{}>::deserialize(&mut bytes)?.0",
-                            name, rust_type
-                        ),
-                        format!("MSlice(s.{}).serialize(&mut buffer)", name),
-                    ),
-                    _ => (
-                        format!("{}: {}::deserialize(&mut bytes)?", name, rust_type),
-                        format!("s.{}.serialize(&mut buffer)", name),
-                    ),
-                };
-                deserializes.push(deserialize);
-                serializes.push(serialize);
                 type_offset += 1;
             }
-            def.push(format!(
-                r#"
-}}
-
-impl PigletCodec for {} {{
-  const TYPE_ID: u8 = 30;
-}}
-
-impl PigletSerialize for {} {{
-  fn serialize(&self, stream: &mut BytesMut) {{
-    stream.put_u8(Self::TYPE_ID);
-    stream.put_u8(0);
-    let mut buffer = BytesMut::new();
-    let s = self;
-"#,
-                s.name, s.name,
-            ));
-
-            for serialize in &serializes {
-                def.push(format!("    {};", serialize));
-            }
-
-            def.push(format!(
-                r#"
-    stream.put_u16_le(buffer.len() as u16);
-    stream.put(buffer);
-  }}
-}}
-
-impl PigletDeserialize for {} {{
-  fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {{
-    let type_id = stream.get_u8();
-    if Self::TYPE_ID != type_id {{
-        return Err(ConnectionError(anyhow!("Expected {{}} but got {{}}", Self::TYPE_ID, type_id)));
-    }}
-    let _flags = stream.get_u8();
-    let length = stream.get_u16_le() as usize;
-    let mut bytes = stream.copy_to_bytes(length);
-    Ok(Self {{
-"#,
-                s.name,
-            ));
-
-            for deserialize in &deserializes {
-                def.push(format!("      {},", deserialize));
-            }
-
-            def.push(format!(
-                r#"
-    }})
-  }}
-}}
-
-impl PigletCodec for MSlice<'_, {}> {{
-  const TYPE_ID: u8 = 31;
-}}
-
-impl PigletCodec for MVec<{}> {{
-  const TYPE_ID: u8 = 31;
-}}
-
-impl PigletSerialize for MSlice<'_, {}> {{
-  fn serialize(&self, stream: &mut BytesMut) {{
-    stream.put_u8(Self::TYPE_ID);
-    stream.put_u8(0);
-
-    let mut outer = BytesMut::new();
-    for s in self.0.as_ref() {{
-        let mut buffer = BytesMut::new();
-"#,
-                s.name, s.name, s.name
-            ));
-
-            for serialize in &serializes {
-                def.push(format!("    {};", serialize));
-            }
-
-            def.push(format!(
-                r#"
-        outer.put_u16_le(buffer.len() as u16);
-        outer.put(buffer);
-    }}
-
-    stream.put_u16_le(outer.len() as u16);
-    stream.put(outer);
-  }}
-}}
-
-impl PigletSerialize for MVec<{}> {{
-  fn serialize(&self, stream: &mut BytesMut) {{
-    MSlice(&self.0).serialize(stream)
-  }}
+            def.push("}\n".to_string());
+            // The robot doesn't describe its events apart from these structs, so they're taken to
+            // be sent with the struct's number in its interface. Events sent under other ids still
+            // reach subscribe_events.
+            if s.name.starts_with("Event") {
+                def.push(format!(
+                    r#"impl piglet_client::events::PigletEvent for {} {{
+  const INTERFACE_ID: u8 = {};
+  const CALL_TYPE_ID: u16 = {};
 }}
-
-impl PigletDeserialize for MVec<{}> {{
-  fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {{
-    let type_id = stream.get_u8();
-    if Self::TYPE_ID != type_id {{
-        return Err(ConnectionError(anyhow!("Expected {{}} but got {{}}", Self::TYPE_ID, type_id)));
-    }}
-    let _flags = stream.get_u8();
-    let mut length = stream.get_u16_le() as usize;
-    let mut outer = stream.copy_to_bytes(length);
-    let mut arr = Vec::new();
-    while length > 0 {{
-        let mut length = outer.get_u16_le() as usize;
-        let mut bytes = outer.copy_to_bytes(length);
-        arr.push({} {{
 "#,
-                s.name, s.name, s.name
-            ));
-
-            for deserialize in &deserializes {
-                def.push(format!("          {},", deserialize));
+                    s.name, interface.id, i
+                ));
             }
-
-            def.push(format!(
-                r#"
-        }});
-    }}
-    Ok(MVec(arr))
-  }}
-}}
-"#
-            ));
             struct_defs.push(def.join("\n"));
         }
     }
 
-    let mut methods = HashMap::<String, MethodGroup>::new();
-    for i in 0..(object.method_count) {
-        let method = dynamic.get_method(i).await?;
-        println!("{:?}", method);
-        if let Some(existing) = methods.get_mut(&method.name) {
-            existing.methods.push(method);
-        } else {
-            methods.insert(
-                method.name.clone(),
-                MethodGroup {
-                    methods: vec![method],
-                },
-            );
-        }
-    }
-
+    let names = method_names(object.methods.iter().map(|m| m.name.as_str()));
     let mut flatten = Vec::new();
-    for group in methods.into_values() {
-        if group.methods.len() == 1 {
-            flatten.push(group.methods.into_iter().next().unwrap());
-        } else {
-            for (i, mut method) in group.methods.into_iter().enumerate() {
-                method.name = format!("{}_{}", method.name, i + 1);
-                flatten.push(method);
-            }
-        }
+    for (name, method) in names.into_iter().zip(&object.methods) {
+        flatten.push(Method {
+            name,
+            ..method.clone()
+        });
     }
 
     flatten.sort_by(|a, b| {
@@ -636,7 +547,7 @@ impl PigletDeserialize for MVec<{}> {{
         return a_key.cmp(&b_key);
     });
 
-    for method in flatten {
+    'methods: for method in flatten {
         let mut arguments = Vec::new();
         let mut return_elements = Vec::new();
         let mut return_values = Vec::new();
@@ -644,296 +555,63 @@ impl PigletDeserialize for MVec<{}> {{
         for label in &method.parameter_labels {
             let name = format_parameter_name(&label);
             let raw_type = method.parameter_types[parameter_i];
-            let parsed_type = match raw_type {
-                1 => Argument {
-                    rust_type: "i8".to_string(),
-                },
-                2 => Argument {
-                    rust_type: "u8".to_string(),
-                },
-                3 => Argument {
-                    rust_type: "i16".to_string(),
-                },
-                4 => Argument {
-                    rust_type: "u16".to_string(),
-                },
-                5 => Argument {
-                    rust_type: "i32".to_string(),
-                },
-                6 => Argument {
-                    rust_type: "u32".to_string(),
-                },
-                7 => Argument {
-                    rust_type: "&str".to_string(),
-                },
-                8 => Argument {
-                    rust_type: "impl AsRef<[u8]>".to_string(),
-                },
-                18 => ReturnElement {
-                    rust_type: "u8".to_string(),
-                },
-                19 => ReturnElement {
-                    rust_type: "i16".to_string(),
-                },
-                20 => ReturnElement {
-                    rust_type: "u16".to_string(),
-                },
-                21 => ReturnElement {
-                    rust_type: "i32".to_string(),
-                },
-                22 => ReturnElement {
-                    rust_type: "u32".to_string(),
-                },
-                23 => ReturnElement {
-                    rust_type: "String".to_string(),
-                },
-                24 => ReturnElement {
-                    rust_type: "Vec::<u8>".to_string(),
-                },
-                25 => ReturnValue {
-                    rust_type: "i8".to_string(),
-                },
-                26 => ReturnValue {
-                    rust_type: "u8".to_string(),
-                },
-                27 => ReturnValue {
-                    rust_type: "i16".to_string(),
-                },
-                28 => ReturnValue {
-                    rust_type: "u16".to_string(),
-                },
-                29 => ReturnValue {
-                    rust_type: "i32".to_string(),
-                },
-                30 => ReturnValue {
-                    rust_type: "u32".to_string(),
-                },
-                31 => ReturnValue {
-                    rust_type: "String".to_string(),
-                },
-                32 => ReturnValue {
-                    rust_type: "Vec::<u8>".to_string(),
-                },
-                33 => Argument {
-                    rust_type: "bool".to_string(),
-                },
-                35 => ReturnElement {
-                    rust_type: "bool".to_string(),
-                },
-                36 => ReturnValue {
-                    rust_type: "bool".to_string(),
-                },
-                41 => Argument {
-                    rust_type: "impl AsRef<[i16]>".to_string(),
-                },
-                43 => ReturnElement {
-                    rust_type: "Vec::<i16>".to_string(),
-                },
-                44 => ReturnValue {
-                    rust_type: "Vec::<i16>".to_string(),
-                },
-                45 => Argument {
-                    rust_type: "impl AsRef<[u16]>".to_string(),
-                },
-                47 => ReturnElement {
-                    rust_type: "Vec::<u16>".to_string(),
-                },
-                48 => ReturnValue {
-                    rust_type: "Vec::<u16>".to_string(),
-                },
-                49 => Argument {
-                    rust_type: "impl AsRef<[i32]>".to_string(),
-                },
-                51 => ReturnElement {
-                    rust_type: "Vec::<i32>".to_string(),
-                },
-                52 => ReturnValue {
-                    rust_type: "Vec::<i32>".to_string(),
-                },
-                53 => Argument {
-                    rust_type: "impl AsRef<[u32]>".to_string(),
-                },
-                55 => ReturnElement {
-                    rust_type: "Vec::<u32>".to_string(),
-                },
-                56 => ReturnValue {
-                    rust_type: "Vec::<u32>".to_string(),
-                },
-                60 => {
-                    let source_id = method.parameter_types[parameter_i + 1];
-                    let id = method.parameter_types[parameter_i + 2];
-                    let e = match source_id {
-                        1 => &global_structs[&(id - 1)],
-                        2 => {
-                            &structs[&StructId {
-                                interface_id: method.interface_id,
-                                struct_id: id - 1,
-                            }]
-                        }
-                        _ => anyhow::bail!("Unknown source {}", source_id),
-                    };
-                    parameter_i += 2;
-                    ReturnValue {
-                        rust_type: e.clone(),
-                    }
-                }
-                61 => {
-                    let source_id = method.parameter_types[parameter_i + 1];
-                    let id = method.parameter_types[parameter_i + 2];
-                    let e = match source_id {
-                        1 => &global_structs[&(id - 1)],
-                        2 => {
-                            &structs[&StructId {
-                                interface_id: method.interface_id,
-                                struct_id: id - 1,
-                            }]
-                        }
-                        _ => anyhow::bail!("Unknown source {}", source_id),
-                    };
-                    parameter_i += 2;
-                    Argument {
-                        rust_type: format!("impl AsRef<[{}]>", e),
-                    }
-                }
-                64 => {
-                    let source_id = method.parameter_types[parameter_i + 1];
-                    let id = method.parameter_types[parameter_i + 2];
-                    let e = match source_id {
-                        1 => &global_structs[&(id - 1)],
-                        2 => {
-                            &structs[&StructId {
-                                interface_id: method.interface_id,
-                                struct_id: id - 1,
-                            }]
-                        }
-                        _ => anyhow::bail!("Unknown source {}", source_id),
-                    };
-                    parameter_i += 2;
-                    ReturnValue {
-                        rust_type: format!("{}", e),
-                    }
-                }
-                66 => Argument {
-                    rust_type: "impl AsRef<[bool]>".to_string(),
-                },
-                68 => ReturnElement {
-                    rust_type: "Vec::<bool>".to_string(),
-                },
-                69 => ReturnValue {
-                    rust_type: "Vec::<bool>".to_string(),
-                },
-                76 => ReturnElement {
-                    rust_type: "Vec::<String>".to_string(),
-                },
-                78 => {
-                    let source_id = method.parameter_types[parameter_i + 1];
-                    let id = method.parameter_types[parameter_i + 2];
-                    let e = match source_id {
-                        1 => &global_enums[&(id - 1)],
-                        2 => {
-                            &enums[&EnumId {
-                                interface_id: method.interface_id,
-                                enum_id: id - 1,
-                            }]
-                        }
-                        _ => anyhow::bail!("Unknown source {}", source_id),
-                    };
-                    parameter_i += 2;
-                    Argument {
-                        rust_type: e.clone(),
-                    }
-                }
-                81 => {
-                    let source_id = method.parameter_types[parameter_i + 1];
-                    let id = method.parameter_types[parameter_i + 2];
-                    let e = match source_id {
-                        1 => &global_enums[&(id - 1)],
-                        2 => {
-                            &enums[&EnumId {
-                                interface_id: method.interface_id,
-                                enum_id: id - 1,
-                            }]
-                        }
-                        _ => anyhow::bail!("Unknown source {}", source_id),
-                    };
-                    parameter_i += 2;
-                    ReturnValue {
-                        rust_type: e.clone(),
-                    }
-                }
-                82 => {
-                    let source_id = method.parameter_types[parameter_i + 1];
-                    let id = method.parameter_types[parameter_i + 2];
-                    let e = match source_id {
-                        1 => &global_enums[&(id - 1)],
-                        2 => {
-                            &enums[&EnumId {
-                                interface_id: method.interface_id,
-                                enum_id: id - 1,
-                            }]
-                        }
-                        _ => anyhow::bail!("Unknown source {}", source_id),
-                    };
-                    parameter_i += 2;
-                    Argument {
-                        rust_type: format!("impl AsRef<[{}]>", e),
-                    }
-                }
-                85 => {
-                    let source_id = method.parameter_types[parameter_i + 1];
-                    let id = method.parameter_types[parameter_i + 2];
-                    let e = match source_id {
-                        1 => &global_enums[&(id - 1)],
-                        2 => {
-                            &enums[&EnumId {
-                                interface_id: method.interface_id,
-                                enum_id: id - 1,
-                            }]
-                        }
-                        _ => anyhow::bail!("Unknown source {}", source_id),
-                    };
-                    parameter_i += 2;
-                    ReturnValue {
-                        rust_type: format!("{}", e),
-                    }
-                }
-                102 => Argument {
-                    rust_type: "f32".to_string(),
-                },
-                104 => ReturnElement {
-                    rust_type: "f32".to_string(),
-                },
-                105 => ReturnValue {
-                    rust_type: "f32".to_string(),
-                },
-                _ => anyhow::bail!("Unknown type {}", raw_type),
+            // Rather than guess at how a type we haven't seen on a robot is sent, the method is
+            // left out. It can still be called with raw bytes through RobotClient::act.
+            let Some((position, type_id)) = parameter_type(raw_type) else {
+                eprintln!(
+                    "Warning: skipping {}.{}, it has a parameter with unverified type code {}",
+                    object.name, method.name, raw_type
+                );
+                continue 'methods;
             };
-            let is_as_ref = match raw_type {
-                8 | 41 | 45 | 49 | 53 | 61 | 66 | 82 => true,
-                _ => false,
+            let (element, is_array) = if has_definition(type_id) {
+                let e = definitions.name(
+                    type_id,
+                    method.interface_id,
+                    &method.parameter_types[parameter_i + 1..],
+                )?;
+                parameter_i += 2;
+                (e, matches!(type_id, 31 | 35))
+            } else {
+                let (e, is_array) = primitive_type(type_id)
+                    .ok_or_else(|| anyhow!("Unknown type {} in {}", raw_type, method.name))?;
+                (e.to_string(), is_array)
+            };
+            let is_as_ref = position == Position::Argument && is_array;
+            let rust_type = match position {
+                Position::Argument if is_array => format!("impl AsRef<[{}]>", element),
+                Position::Argument if element == "String" => "&str".to_string(),
+                _ if is_array => format!("Vec::<{}>", element),
+                _ => element,
             };
-            let wrap_in_mvec = match raw_type {
-                61 | 64 | 82 | 85 => true,
-                _ => false,
+            let parsed_type = match position {
+                Position::Argument => Argument { rust_type },
+                Position::ReturnElement => ReturnElement { rust_type },
+                Position::ReturnValue => ReturnValue { rust_type },
             };
+            let unit =
+                |rust_type: &str| units::unit_for(&object.name, &method.name, &name, rust_type);
             match parsed_type {
                 Argument { rust_type } => arguments.push(Parameter {
+                    unit: unit(&rust_type),
                     name,
                     is_as_ref,
                     rust_type,
-                    wrap_in_mvec,
+                    is_struct: type_id == 30,
                 }),
                 ReturnElement { rust_type } => return_elements.push(Parameter {
+                    unit: unit(&rust_type),
                     name,
                     is_as_ref,
                     rust_type,
-                    wrap_in_mvec,
+                    is_struct: false,
                 }),
                 ReturnValue { rust_type } => return_values.push(Parameter {
+                    unit: unit(&rust_type),
                     name,
                     is_as_ref,
                     rust_type,
-                    wrap_in_mvec,
+                    is_struct: false,
                 }),
             }
 
@@ -964,40 +642,158 @@ impl PigletDeserialize for MVec<{}> {{
             "",
             method.name.from_case(Case::Pascal).to_case(Case::Snake)
         )];
+        // The trait's futures are Send, so anything they hold on to has to be too
+        let mut trait_arguments = Vec::new();
         for argument in &arguments {
-            contents.push(format!("    {}: {},", argument.name, argument.rust_type));
+            let rust_type = argument.public_type();
+            contents.push(format!("    {}: {},", argument.name, rust_type));
+            let send = if rust_type.starts_with("impl ") {
+                " + Send"
+            } else {
+                ""
+            };
+            trait_arguments.push(format!("{}: {}{}", argument.name, rust_type, send));
         }
 
+        // Overloads are numbered like Aspirate_1, which the types they get leave the _ out of
+        let type_name = method.name.replace('_', "");
         if return_elements.len() > 0 {
             let mut struct_def = vec![format!(
                 r#"
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+{}
 pub struct {}Reply {{
 "#,
-                method.name
+                SERDE_DERIVE, type_name
             )];
             for e in &return_elements {
-                struct_def.push(format!("  pub {}: {},", e.name, e.rust_type));
+                struct_def.push(format!("  pub {}: {},", e.name, e.public_type()));
             }
             struct_def.push("}".to_string());
             struct_defs.push(struct_def.join("\n"));
         }
 
         let return_type = if return_elements.len() > 0 {
-            format!("{}Reply", method.name)
+            format!("{}Reply", type_name)
         } else if return_values.len() == 1 {
             let value = &return_values[0];
-            let wrapped = if value.wrap_in_mvec {
-                format!("Vec::<{}>", value.rust_type)
-            } else {
-                value.rust_type.clone()
-            };
-            format!("/* {}= */ {}", value.name, wrapped)
+            format!("/* {}= */ {}", value.name, value.public_type())
         } else {
             "()".to_string()
         };
 
+        let fn_name = method.name.from_case(Case::Pascal).to_case(Case::Snake);
+        let signature = format!(
+            "fn {}(&self, {}) -> impl Future<Output = Result<{}, Error>> + Send",
+            fn_name,
+            trait_arguments.join(", "),
+            return_type
+        );
+        trait_defs.push(format!("  {};", signature));
+        trait_impls.push(format!(
+            "  {} {{ {}::{}(self, {}) }}",
+            signature,
+            name,
+            fn_name,
+            arguments
+                .iter()
+                .map(|a| a.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        let recorded = arguments
+            .iter()
+            .map(|a| {
+                let usage = if a.is_as_ref {
+                    format!("{}.as_ref()", a.name)
+                } else {
+                    a.name.clone()
+                };
+                format!("({:?}, format!(\"{{:?}}\", {}))", a.name, usage)
+            })
+            .collect::<Vec<_>>();
+        fake_defs.push(format!(
+            r#"
+  {} {{
+    let reply = self.recorder.record({:?}, vec![{}]);
+    async move {{ reply }}
+  }}"#,
+            signature,
+            fn_name,
+            recorded.join(", ")
+        ));
+
+        let mut request_method = None;
+        if arguments.len() >= REQUEST_ARGUMENTS {
+            let request = format!("{}Request", type_name);
+            let mut struct_def = vec![format!(
+                r#"
+#[derive(Clone, Debug, Default)]
+{}
+pub struct {} {{
+"#,
+                SERDE_DERIVE, request
+            )];
+            let mut setters = vec![format!("impl {} {{", request)];
+            let mut forwarded = Vec::new();
+            for argument in &arguments {
+                // Every field starts out unset, and the call fails rather than sending a zero for
+                // one that's still unset
+                let owned = argument.owned_type();
+                struct_def.push(format!("  pub {}: Option<{}>,", argument.name, owned));
+                let (value_type, value) = if let Some(inner) = owned.strip_prefix("Vec<") {
+                    (format!("impl Into<Vec<{}>", inner), ".into()")
+                } else if owned == "String" {
+                    ("impl Into<String>".to_string(), ".into()")
+                } else {
+                    (owned.clone(), "")
+                };
+                setters.push(format!(
+                    "  pub fn {0}(mut self, {0}: {1}) -> Self {{ self.{0} = Some({0}{2}); self }}",
+                    argument.name, value_type, value
+                ));
+                let value = if owned.starts_with("Vec<") {
+                    ".as_ref()"
+                } else if owned == "String" {
+                    ".as_deref()"
+                } else if argument.is_struct {
+                    ".clone()"
+                } else {
+                    ""
+                };
+                forwarded.push(format!(
+                    "piglet_client::client::required(request.{0}{1}, {2:?}, {0:?})?",
+                    argument.name, value, request
+                ));
+            }
+            struct_def.push("}".to_string());
+            setters.push("}".to_string());
+            struct_defs.push(struct_def.join("\n"));
+            struct_defs.push(setters.join("\n"));
+            request_method = Some(format!(
+                r#"
+  pub async fn {0}_with(&self, request: &{1}) -> Result<{2}, Error> {{
+    self.{0}({3}).await
+  }}"#,
+                fn_name,
+                request,
+                return_type,
+                forwarded.join(", ")
+            ));
+            // Checked before anything is awaited, so the future doesn't need Self to be Sync
+            trait_defs.push(format!(
+                r#"
+  fn {0}_with(&self, request: &{1}) -> impl Future<Output = Result<{2}, Error>> + Send {{
+    let call = (|| Ok::<_, Error>(self.{0}({3})))();
+    async move {{ call?.await }}
+  }}"#,
+                fn_name,
+                request,
+                return_type,
+                forwarded.join(", ")
+            ));
+        }
+
         contents.push(format!("  ) -> Result<{}, Error> {{", return_type));
         contents.push("    let mut args = BytesMut::new();".to_string());
         for argument in &arguments {
@@ -1006,17 +802,27 @@ pub struct {}Reply {{
             } else {
                 argument.name.clone()
             };
-            let source = if argument.wrap_in_mvec {
-                format!("MSlice({})", usage)
-            } else {
-                usage
+            let source = match argument.unit {
+                Some(unit) => {
+                    unit_imports.insert(unit);
+                    let wire = units::wire_type(&argument.rust_type).unwrap();
+                    if argument.is_as_ref {
+                        format!(
+                            "{}.iter().map(|v| v.to_wire::<{}>()).collect::<Result<Vec<{}>, Error>>()?",
+                            usage, wire, wire
+                        )
+                    } else {
+                        format!("{}.to_wire::<{}>()?", usage, wire)
+                    }
+                }
+                None => usage,
             };
             contents.push(format!("    {}.serialize(&mut args);", source));
         }
         contents.push(format!(
             r#" let (count, mut stream) =
         with_context(
-            self.robot.act(&self.address, {}, {}, {}, args.freeze()).await,
+            self.robot.act_with_timeout(&self.address, {}, {}, {}, args.freeze(), self.timeout).await,
             || {{
 "#,
             method.interface_id, method.call_type, method.method_id,
@@ -1065,30 +871,38 @@ pub struct {}Reply {{
         let expected_length = return_elements.len() + return_values.len();
         contents.push(format!("    if count != {} {{", expected_length));
         contents.push(format!(
-            "      return Err(ConnectionError(anyhow!(\"Expected {} values, not {{}}\", count)));",
+            "      return Err(ProtocolError(anyhow!(\"Expected {} values, not {{}}\", count)));",
             expected_length
         ));
         contents.push("    }".to_string());
 
         for e in return_elements.iter().chain(&return_values) {
-            let wrapped = if e.wrap_in_mvec {
-                format!("MVec::<{}>", e.rust_type)
-            } else {
-                e.rust_type.clone()
+            let wire = format!("{}::deserialize(&mut stream)?", e.rust_type);
+            let value = match e.unit {
+                Some(unit) if e.rust_type.starts_with("Vec::<") => {
+                    unit_imports.insert(unit);
+                    format!(
+                        "{}.into_iter().map({}::from_wire).collect::<Vec<_>>()",
+                        wire, unit
+                    )
+                }
+                Some(unit) => {
+                    unit_imports.insert(unit);
+                    format!("{}::from_wire({})", unit, wire)
+                }
+                None => wire,
             };
-            let suffix = if e.wrap_in_mvec { ".0" } else { "" };
             contents.push(format!(
-                "    let {} = {}::deserialize(&mut stream)?{};",
+                "    let {} = {};",
                 e.name.from_case(Case::Camel).to_case(Case::Snake),
-                wrapped,
-                suffix
+                value
             ));
         }
 
         let ret = if return_elements.len() > 0 {
             format!(
                 "{}Reply {{ {} }}",
-                method.name,
+                type_name,
                 return_elements
                     .into_iter()
                     .map(|e| e.name.from_case(Case::Camel).to_case(Case::Snake))
@@ -1106,6 +920,7 @@ pub struct {}Reply {{
         contents.push("  }".to_string());
 
         method_defs.push(contents.join("\n"));
+        method_defs.extend(request_method);
     }
 
     Ok(GeneratedModule {
@@ -1114,17 +929,24 @@ pub struct {}Reply {{
         enum_defs,
         method_defs,
         struct_defs,
-        unknown: false,
+        trait_defs,
+        trait_impls,
+        fake_defs,
+        unit_imports,
+        object_name: object.name.clone(),
         version: object.version.clone(),
+        fingerprint: object.fingerprint(),
     })
 }
 
 async fn dump_module(
     module: &GeneratedModule,
     global_imports: &Vec<String>,
+    paths: &HashMap<ObjectAddress, String>,
     file: &mut File,
     filename: &String,
     parent: &String,
+    reconstructed: bool,
 ) -> Result<(), anyhow::Error> {
     let name = &module.name;
     let addresses = &module.addresses;
@@ -1136,16 +958,31 @@ async fn dump_module(
         }
     }
 
+    if !module.unit_imports.is_empty() {
+        file.write_all(
+            format!(
+                "use piglet_client::units::{{{}}};\n",
+                module
+                    .unit_imports
+                    .iter()
+                    .copied()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+            .as_bytes(),
+        )
+        .await?;
+    }
+
     file.write_all(
         format!(
             r#"
 use anyhow::anyhow;
 use bytes::{{Buf, BufMut, Bytes, BytesMut}};
-use crate::traits::{{MSlice, MVec}};
 use piglet_client::{{
-  client::{{Error, Error::ConnectionError, RobotClient, with_context}},
+  client::{{Error, Error::ProtocolError, RobotClient, Timeout, with_context}},
   object_address::ObjectAddress,
-  values::{{PigletCodec, PigletDeserialize, PigletSerialize, NetworkResult}},
+  values::{{PigletCodec, PigletDeserialize, PigletSerialize, NetworkResult, read_header, take}},
 }};
 use std::sync::Arc;
 
@@ -1153,18 +990,50 @@ use std::sync::Arc;
 pub struct {} {{
   address: ObjectAddress,
   robot: Arc<RobotClient>,
+  timeout: Timeout,
 }}
 
 impl {} {{
-
-  // version {}
+  // {}
+  pub const NAME: &'static str = {:?};
+  pub const VERSION: &'static str = {:?};
 "#,
-            name, name, module.version
+            name,
+            name,
+            if reconstructed {
+                "What the reconstructed dump calls this object and its version, not checked against a robot"
+            } else {
+                "What the robot called this object and its version when these bindings were generated"
+            },
+            module.object_name,
+            module.version
         )
         .as_bytes(),
     )
     .await?;
 
+    // A reconstructed dump's methods weren't read off a robot, so a fingerprint of them wouldn't
+    // match one
+    if let Some(fingerprint) = module.fingerprint.filter(|_| !reconstructed) {
+        file.write_all(
+            format!(
+                r#"
+  // Identifies the methods generated below, see piglet_client::compatibility::fingerprint
+  pub const FINGERPRINT: u64 = 0x{:016x};
+"#,
+                fingerprint
+            )
+            .as_bytes(),
+        )
+        .await?;
+    }
+
+    // A reconstructed dump's names and layout are guesses, so a real robot may not have its paths
+    let unverified_path = if reconstructed {
+        "\n  // The path is from a reconstructed dump and hasn't been checked against a robot, so new is\n  // the default"
+    } else {
+        ""
+    };
     if addresses.len() == 1 {
         let address = &addresses[0];
         file.write_all(
@@ -1174,10 +1043,20 @@ impl {} {{
     Self {{
       address: {:?},
       robot: robot.clone(),
+      timeout: Timeout::Default,
     }}
   }}
+
+  // Like new, but looks the object up by name in case the robot numbers its objects differently{}
+  pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {{
+    Ok(Self {{
+      address: robot.resolve({:?}).await?,
+      robot: robot.clone(),
+      timeout: Timeout::Default,
+    }})
+  }}
 "#,
-                address
+                address, unverified_path, paths[address]
             )
             .as_bytes(),
         )
@@ -1188,15 +1067,33 @@ impl {} {{
             file.write_all(
                 format!(
                     r#"
-  pub fn new_{}(robot: &Arc<RobotClient>) -> Self {{
+  pub fn new_{0}(robot: &Arc<RobotClient>) -> Self {{
     Self {{
-      address: {:?},
+      address: {1:?},
       robot: robot.clone(),
+      timeout: Timeout::Default,
     }}
   }}
+
+{3}  pub async fn resolve_{0}(robot: &Arc<RobotClient>) -> Result<Self, Error> {{
+    Ok(Self {{
+      address: robot.resolve({2:?}).await?,
+      robot: robot.clone(),
+      timeout: Timeout::Default,
+    }})
+  }}
 "#,
                     i + 1,
-                    address
+                    address,
+                    paths[address],
+                    if reconstructed {
+                        format!(
+                            "  // The path is from a reconstructed dump and hasn't been checked against a robot, so\n  // new_{} is the default\n",
+                            i + 1
+                        )
+                    } else {
+                        String::new()
+                    }
                 )
                 .as_bytes(),
             )
@@ -1204,18 +1101,58 @@ impl {} {{
         }
     }
 
+    file.write_all(
+        r#"
+  // Calls made through the returned object wait as long as timeout says
+  pub fn with_timeout(&self, timeout: Timeout) -> Self {
+    Self {
+      timeout,
+      ..self.clone()
+    }
+  }
+"#
+        .as_bytes(),
+    )
+    .await?;
+
     file.write_all(
         format!(
             r#"
-{}
+{0}
 }}
 
-{}
+// The methods above as a trait, so code using them can be tested against {1}Fake instead
+#[allow(clippy::too_many_arguments)]
+pub trait {1}Api {{
+{2}
+}}
 
-{}
+impl {1}Api for {1} {{
+{3}
+}}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct {1}Fake {{
+  pub recorder: piglet_client::testing::Recorder,
+}}
+
+#[cfg(feature = "testing")]
+impl {1}Api for {1}Fake {{
+{4}
+}}
+
+{5}
+
+{6}
 
 "#,
             module.method_defs.join("\n\n"),
+            name,
+            module.trait_defs.join("\n"),
+            module.trait_impls.join("\n\n"),
+            module.fake_defs.join("\n"),
             module.enum_defs.join("\n\n"),
             module.struct_defs.join("\n\n")
         )
@@ -1223,7 +1160,7 @@ impl {} {{
     )
     .await?;
 
-    if module.unknown {
+    if module.fingerprint.is_none() {
         file.write_all("// module was not present on the dumping machine".as_bytes())
             .await?;
     }
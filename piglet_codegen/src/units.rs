@@ -0,0 +1,40 @@
+// Picks a piglet_client::units type for an integer argument or return value based on its name.
+// Offsets, accelerations and the like stay raw. Bare names like "position" get a unit too, except
+// on the drives, whose low-level moves don't say what they're measured in, and for the gripper's
+// wrist, which turns rather than moves.
+pub fn unit_for(object: &str, method: &str, name: &str, rust_type: &str) -> Option<&'static str> {
+    wire_type(rust_type)?;
+    if !name.contains('_') && (object.ends_with("Drive") || method.starts_with("WMove")) {
+        return None;
+    }
+    let name = name.strip_suffix('s').unwrap_or(name);
+    if name.ends_with("volume") {
+        Some("Microliters")
+    } else if name.ends_with("speed") {
+        // Liquid handling speeds are flow rates rather than how fast something moves
+        let flow = ["aspirate_", "dispense_", "mix_", "cutoff_"];
+        if flow.iter().any(|prefix| name.starts_with(prefix)) {
+            Some("MicrolitersPerSecond")
+        } else {
+            Some("MmPerSecond")
+        }
+    } else if ["position", "height", "distance", "depth", "width"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+        || name.contains("_position_")
+        || ["x_final", "y_final", "z_final"].contains(&name)
+    {
+        Some("Millimeters")
+    } else {
+        None
+    }
+}
+
+// The integer type a unit is sent as, for the argument and return types units apply to
+pub fn wire_type(rust_type: &str) -> Option<&'static str> {
+    match rust_type {
+        "i32" | "impl AsRef<[i32]>" | "Vec::<i32>" => Some("i32"),
+        "u32" | "impl AsRef<[u32]>" | "Vec::<u32>" => Some("u32"),
+        _ => None,
+    }
+}
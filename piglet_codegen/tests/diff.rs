@@ -0,0 +1,166 @@
+// Comparing small dumps the way piglet_codegen diff does between firmware versions
+use serde_json::{Value, json};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn method(name: &str, interface_id: u8, method_id: u16, labels: &[&str]) -> Value {
+    json!({
+        "interface_id": interface_id,
+        "call_type": 3,
+        "method_id": method_id,
+        "name": name,
+        "parameter_labels": labels,
+        "parameter_types": vec![3; labels.len()],
+    })
+}
+
+fn object(address: &str, name: &str, methods: Vec<Value>, enum_values: &[i32]) -> Value {
+    json!({
+        "address": address,
+        "name": name,
+        "version": "1.0",
+        "interfaces": [{
+            "id": 1,
+            "label": "IPipette",
+            "enums": [{
+                "name": "TipType",
+                "labels": ["Short", "Long"],
+                "values": enum_values,
+            }],
+            "structs": [],
+        }],
+        "methods": methods,
+        "subobjects": [],
+    })
+}
+
+// The changes diff reports going from old to new, with - for breaking ones and + for the rest
+fn diff(name: &str, old: Vec<Value>, new: Vec<Value>) -> Vec<String> {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("diff")
+        .join(name);
+    fs::create_dir_all(&dir).unwrap();
+    for (file, objects) in [("old.json", old), ("new.json", new)] {
+        let dump = json!({ "globals": [], "objects": objects });
+        fs::write(dir.join(file), dump.to_string()).unwrap();
+    }
+    let output = Command::new(env!("CARGO_BIN_EXE_piglet_codegen"))
+        .current_dir(&dir)
+        .args(["diff", "old.json", "new.json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .filter_map(|line| line.strip_prefix("  "))
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn objects_added_removed_and_moved() {
+    let changes = diff(
+        "objects",
+        vec![
+            object("1-1-257", "Pipette", vec![], &[1, 2]),
+            object("1-1-260", "Gripper", vec![], &[1, 2]),
+        ],
+        vec![
+            object("1-1-258", "Pipette", vec![], &[1, 2]),
+            object("1-1-268", "DoorLock", vec![], &[1, 2]),
+        ],
+    );
+    assert_eq!(
+        changes,
+        [
+            "- Gripper was removed from 1-1-260",
+            "- Pipette moved from 1-1-257 to 1-1-258",
+            "+ DoorLock was added at 1-1-268",
+        ]
+    );
+}
+
+#[test]
+fn enum_values_that_change() {
+    let changes = diff(
+        "enums",
+        vec![object("1-1-257", "Pipette", vec![], &[1, 2])],
+        vec![object("1-1-257", "Pipette", vec![], &[1, 3])],
+    );
+    assert_eq!(changes, ["- Pipette enum TipType changed Long from 2 to 3"]);
+}
+
+#[test]
+fn methods_added_removed_and_changed() {
+    let changes = diff(
+        "methods",
+        vec![object(
+            "1-1-257",
+            "Pipette",
+            vec![
+                method("Aspirate", 1, 1, &["volume"]),
+                method("Dispense", 1, 2, &["volume"]),
+                method("Park", 1, 3, &[]),
+            ],
+            &[1, 2],
+        )],
+        vec![object(
+            "1-1-257",
+            "Pipette",
+            vec![
+                method("Aspirate", 1, 1, &["volume", "speed"]),
+                method("Dispense", 1, 4, &["volume"]),
+                method("Home", 1, 5, &[]),
+            ],
+            &[1, 2],
+        )],
+    );
+    assert_eq!(
+        changes,
+        [
+            "- Pipette.Park was removed",
+            "- Pipette.Aspirate parameters changed from (volume) to (volume, speed)",
+            "- Pipette.Aspirate parameter_types changed from [3] to [3, 3]",
+            "- Pipette.Dispense is now interface 1 method 4 (call type 3), was interface 1 method 2 (call type 3)",
+            "+ Pipette.Home was added",
+        ]
+    );
+}
+
+#[test]
+fn overloads_are_matched_by_id_before_their_number() {
+    // A new overload ahead of the old one takes its number, which changes the old one's binding
+    // but isn't the old one having its parameters changed
+    let changes = diff(
+        "overloads",
+        vec![object(
+            "1-1-257",
+            "Pipette",
+            vec![
+                method("Aspirate", 1, 1, &["volume"]),
+                method("Aspirate", 2, 1, &["volume", "speed"]),
+            ],
+            &[1, 2],
+        )],
+        vec![object(
+            "1-1-257",
+            "Pipette",
+            vec![
+                method("Aspirate", 1, 7, &["volume", "height"]),
+                method("Aspirate", 1, 1, &["volume"]),
+                method("Aspirate", 2, 1, &["volume", "speed"]),
+            ],
+            &[1, 2],
+        )],
+    );
+    assert_eq!(
+        changes,
+        [
+            "- Pipette.Aspirate_1 is now called Aspirate_2",
+            "- Pipette.Aspirate_2 is now called Aspirate_3",
+            "+ Pipette.Aspirate_1 was added",
+        ]
+    );
+}
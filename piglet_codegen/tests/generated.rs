@@ -0,0 +1,62 @@
+// The checked-in bindings have to be exactly what the generator makes from the checked-in
+// reconstruction, so the generator can't change without them. This keeps them in step with the
+// generator, it doesn't say anything about whether they match a robot.
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn files(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn bindings_match_the_generator() {
+    let repo = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("generated");
+    let _ = fs::remove_dir_all(&out);
+    fs::create_dir_all(&out).unwrap();
+
+    let dump = repo.join("piglet_generated/dumps/nimbus_hd_1_0.reconstructed.json");
+    let status = Command::new(env!("CARGO_BIN_EXE_piglet_codegen"))
+        .current_dir(&out)
+        .args(["generate", dump.to_str().unwrap(), "nimbus_hd_1_0"])
+        .status()
+        .unwrap();
+    assert!(status.success());
+    // The same as cargo fmt does to the checked-in copy, which also reaches every module
+    let generated = out.join("piglet_generated/src");
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2024"])
+        .arg(generated.join("nimbus_hd_1_0.rs"))
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let checked_in = repo.join("piglet_generated/src");
+    let mut stale = Vec::new();
+    for dir in ["", "nimbus_hd_1_0"] {
+        let names = files(&generated.join(dir));
+        let expected = files(&checked_in.join(dir))
+            .into_iter()
+            .filter(|name| !dir.is_empty() || name.starts_with("nimbus_hd_1_0"))
+            .collect::<Vec<_>>();
+        assert_eq!(names, expected);
+        for name in names.iter().filter(|name| name.ends_with(".rs")) {
+            let path = Path::new(dir).join(name);
+            if fs::read_to_string(generated.join(&path)).unwrap()
+                != fs::read_to_string(checked_in.join(&path)).unwrap()
+            {
+                stale.push(path.display().to_string());
+            }
+        }
+    }
+    assert!(
+        stale.is_empty(),
+        "{stale:?} don't match piglet_generated/dumps/nimbus_hd_1_0.reconstructed.json, see the README on regenerating them"
+    );
+}
@@ -0,0 +1,63 @@
+// Methods using a type code that hasn't been seen on a robot are left out of the bindings instead
+// of stopping the generator
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const DUMP: &str = r#"{
+  "globals": [],
+  "objects": [
+    {
+      "address": "1-1-48896",
+      "name": "Gauge",
+      "version": "1.0",
+      "interfaces": [
+        { "id": 0, "label": "I0", "enums": [], "structs": [] },
+        { "id": 1, "label": "I1", "enums": [], "structs": [] }
+      ],
+      "methods": [
+        {
+          "interface_id": 1,
+          "call_type": 3,
+          "method_id": 1,
+          "name": "Read",
+          "parameter_labels": ["reading"],
+          "parameter_types": [30]
+        },
+        {
+          "interface_id": 1,
+          "call_type": 3,
+          "method_id": 2,
+          "name": "ReadWide",
+          "parameter_labels": ["reading"],
+          "parameter_types": [37]
+        }
+      ],
+      "subobjects": []
+    }
+  ]
+}"#;
+
+#[test]
+fn methods_with_unverified_types_are_skipped() {
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("unverified_types");
+    let _ = fs::remove_dir_all(&out);
+    fs::create_dir_all(&out).unwrap();
+    fs::write(out.join("dump.json"), DUMP).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_piglet_codegen"))
+        .current_dir(&out)
+        .args(["generate", "dump.json", "gauges"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let warnings = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        warnings.contains("skipping Gauge.ReadWide, it has a parameter with unverified type code 37"),
+        "{warnings}"
+    );
+
+    let bindings = fs::read_to_string(out.join("piglet_generated/src/gauges/gauge.rs")).unwrap();
+    assert!(bindings.contains("fn read("));
+    assert!(!bindings.contains("read_wide"));
+}
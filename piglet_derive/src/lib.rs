@@ -0,0 +1,197 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Path, parse_macro_input, spanned::Spanned};
+
+// Encodes a struct with named fields the way the robot sends structs, each field in order with its
+// own codec, or an enum without fields as its i32 value. Either can also go in arrays, as a Vec or
+// a slice.
+//
+// The generated code refers to piglet_client, or to another crate that re-exports it given
+// #[piglet(crate = "piglet")].
+#[proc_macro_derive(PigletCodec, attributes(piglet))]
+pub fn derive_piglet_codec(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2, syn::Error> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "PigletCodec can't be derived for generic types",
+        ));
+    }
+    let krate = crate_path(input)?;
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => {
+                let names: Vec<_> = fields.named.iter().map(|f| &f.ident).collect();
+                let types: Vec<_> = fields.named.iter().map(|f| &f.ty).collect();
+                Ok(expand_struct(&krate, input, &names, &types))
+            }
+            _ => Err(syn::Error::new(
+                input.ident.span(),
+                "PigletCodec structs need named fields, since the robot describes them by name",
+            )),
+        },
+        Data::Enum(data) => {
+            let mut variants = Vec::new();
+            for variant in &data.variants {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(syn::Error::new(
+                        variant.span(),
+                        "PigletCodec enums can't have fields, they're sent as an i32",
+                    ));
+                }
+                variants.push(&variant.ident);
+            }
+            Ok(expand_enum(&krate, input, &variants))
+        }
+        Data::Union(_) => Err(syn::Error::new(
+            input.ident.span(),
+            "PigletCodec can't be derived for unions",
+        )),
+    }
+}
+
+// piglet_client unless #[piglet(crate = "...")] says otherwise
+fn crate_path(input: &DeriveInput) -> Result<Path, syn::Error> {
+    let mut krate = syn::parse_quote!(::piglet_client);
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("piglet")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                krate = meta.value()?.parse::<syn::LitStr>()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("expected `crate`"))
+            }
+        })?;
+    }
+    Ok(krate)
+}
+
+fn expand_struct(
+    krate: &Path,
+    input: &DeriveInput,
+    names: &[&Option<syn::Ident>],
+    types: &[&syn::Type],
+) -> TokenStream2 {
+    let name = &input.ident;
+    let values = quote!(#krate::values);
+    let support = quote!(#krate::derive);
+    let serialize_fields = quote! {
+        #( #values::PigletSerialize::serialize(&self.#names, buffer); )*
+    };
+    let deserialize_fields = quote! {
+        Self {
+            #( #names: <#types as #values::PigletDeserialize>::deserialize(&mut bytes)?, )*
+        }
+    };
+    quote! {
+        impl #values::PigletCodec for #name {
+            const TYPE_ID: u8 = 30;
+        }
+
+        // A struct without fields leaves the buffers unused
+        #[allow(unused_mut, unused_variables)]
+        impl #values::PigletSerialize for #name {
+            fn serialize(&self, stream: &mut #support::BytesMut) {
+                use #support::BufMut;
+                let mut fields = #support::BytesMut::new();
+                let buffer = &mut fields;
+                #serialize_fields
+                stream.put_u8(<Self as #values::PigletCodec>::TYPE_ID);
+                stream.put_u8(0);
+                stream.put_u16_le(fields.len() as u16);
+                stream.put(fields);
+            }
+        }
+
+        #[allow(unused_mut)]
+        impl #values::PigletDeserialize for #name {
+            fn deserialize(stream: &mut #support::Bytes) -> Result<Self, #support::Error> {
+                let (_flags, mut bytes) =
+                    #values::read_header(stream, <Self as #values::PigletCodec>::TYPE_ID)?;
+                Ok(#deserialize_fields)
+            }
+        }
+
+        #[allow(unused_mut, unused_variables)]
+        impl #values::PigletElement for #name {
+            const ARRAY_TYPE_ID: u8 = 31;
+
+            fn serialize_item(&self, stream: &mut #support::BytesMut) {
+                #values::serialize_struct_item(stream, |buffer| { #serialize_fields });
+            }
+
+            fn deserialize_item(stream: &mut #support::Bytes) -> Result<Self, #support::Error> {
+                let mut bytes = #values::deserialize_struct_item(stream)?;
+                Ok(#deserialize_fields)
+            }
+        }
+    }
+}
+
+fn expand_enum(krate: &Path, input: &DeriveInput, variants: &[&syn::Ident]) -> TokenStream2 {
+    let name = &input.ident;
+    let values = quote!(#krate::values);
+    let support = quote!(#krate::derive);
+    let unknown = format!("Unknown {} value {{}}", name);
+    let value = quote! {
+        match self {
+            #( Self::#variants => Self::#variants as i32, )*
+        }
+    };
+    quote! {
+        impl TryFrom<i32> for #name {
+            type Error = #support::Error;
+
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                match v {
+                    #( v if v == Self::#variants as i32 => Ok(Self::#variants), )*
+                    _ => Err(#support::Error::ProtocolError(#support::anyhow!(#unknown, v))),
+                }
+            }
+        }
+
+        impl #values::PigletCodec for #name {
+            const TYPE_ID: u8 = 32;
+        }
+
+        impl #values::PigletSerialize for #name {
+            fn serialize(&self, stream: &mut #support::BytesMut) {
+                use #support::BufMut;
+                stream.put_u8(<Self as #values::PigletCodec>::TYPE_ID);
+                stream.put_u8(0);
+                stream.put_u16_le(4);
+                stream.put_i32_le(#value);
+            }
+        }
+
+        impl #values::PigletDeserialize for #name {
+            fn deserialize(stream: &mut #support::Bytes) -> Result<Self, #support::Error> {
+                use #support::Buf;
+                let (_flags, mut bytes) =
+                    #values::read_header(stream, <Self as #values::PigletCodec>::TYPE_ID)?;
+                bytes.try_get_i32_le()?.try_into()
+            }
+        }
+
+        impl #values::PigletElement for #name {
+            const ARRAY_TYPE_ID: u8 = 35;
+
+            fn serialize_item(&self, stream: &mut #support::BytesMut) {
+                use #support::BufMut;
+                stream.put_i32_le(#value);
+            }
+
+            fn deserialize_item(stream: &mut #support::Bytes) -> Result<Self, #support::Error> {
+                use #support::Buf;
+                stream.try_get_i32_le()?.try_into()
+            }
+        }
+    }
+}
@@ -1,2 +1 @@
 pub mod nimbus_hd_1_0;
-mod traits;
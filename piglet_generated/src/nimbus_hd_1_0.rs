@@ -1,3 +1,5 @@
+// Generated from a reconstructed dump rather than one read off a robot, so the object names
+// and paths in here haven't been checked against an instrument
 #![allow(unused)]
 
 pub mod nimbus_core;
@@ -52,3 +54,690 @@ pub mod nimbus_core_right_door_lock_unit_safety_object;
 pub mod nimbus_core_service;
 pub mod nimbus_core_x_drive;
 pub mod nimbus_core_xy_coord;
+
+use piglet_client::{
+    client::{Error, RobotClient},
+    compatibility::{self, ExpectedObject},
+    object_address::ObjectAddress,
+};
+use std::sync::Arc;
+
+// Every object these bindings were generated from
+pub const OBJECTS: &[ExpectedObject] = &[
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 48897,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 48896,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 96,
+            object_id: 48896,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 96,
+            object_id: 49408,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 96,
+            object_id: 259,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 263,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 258,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 272,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 262,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 273,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 274,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 275,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 276,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 277,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 278,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 279,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 384,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 49152,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 49408,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 49409,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 49410,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 49411,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 49412,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 49413,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 49414,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 49415,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 32,
+            object_id: 48896,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 32,
+            object_id: 256,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 32,
+            object_id: 272,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 32,
+            object_id: 288,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 32,
+            object_id: 304,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 32,
+            object_id: 257,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 32,
+            object_id: 273,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 32,
+            object_id: 289,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 32,
+            object_id: 305,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 32,
+            object_id: 49408,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 32,
+            object_id: 320,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 268,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 259,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 269,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 264,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 271,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 265,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 266,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 96,
+            node_id: 1,
+            object_id: 48896,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 96,
+            node_id: 1,
+            object_id: 49408,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 96,
+            node_id: 1,
+            object_id: 8192,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 96,
+            node_id: 1,
+            object_id: 8704,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 96,
+            node_id: 1,
+            object_id: 4608,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 96,
+            node_id: 1,
+            object_id: 4352,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 96,
+            node_id: 1,
+            object_id: 48880,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 96,
+            node_id: 1,
+            object_id: 12288,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 96,
+            node_id: 1,
+            object_id: 8448,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 768,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 128,
+            object_id: 48896,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 128,
+            object_id: 49408,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 128,
+            object_id: 256,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 128,
+            object_id: 40960,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 257,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 270,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 129,
+            object_id: 48896,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 129,
+            object_id: 49408,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 129,
+            object_id: 256,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 129,
+            object_id: 40960,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 48880,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 260,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+    ExpectedObject {
+        address: ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 261,
+        },
+        name: None,
+        version: None,
+        fingerprint: None,
+    },
+];
+
+// Checks that the robot's firmware still has these objects at the same addresses with the same
+// methods, so a mismatch is caught up front instead of calling the wrong method id
+pub async fn verify(robot: &Arc<RobotClient>) -> Result<(), Error> {
+    compatibility::verify(robot, OBJECTS).await
+}
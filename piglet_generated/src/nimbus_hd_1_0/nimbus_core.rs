@@ -1,14 +1,14 @@
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
+use piglet_client::units::{Millimeters, MmPerSecond};
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +16,13 @@ use std::sync::Arc;
 pub struct NimbusCore {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCore {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "NimbusCore";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,26 +32,73 @@ impl NimbusCore {
                 object_id: 48896,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn initialize(
         &self,
 
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
-        z_final: impl AsRef<[i32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
+        z_final: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
-        z_final.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 1, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position.as_ref()),
@@ -65,7 +115,7 @@ impl NimbusCore {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -73,20 +123,47 @@ impl NimbusCore {
     pub async fn initialize_smart(
         &self,
 
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
-        z_final: impl AsRef<[i32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
+        z_final: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
-        z_final.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position.as_ref()),
@@ -103,7 +180,7 @@ impl NimbusCore {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -111,12 +188,14 @@ impl NimbusCore {
     pub async fn park(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.Park()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -124,12 +203,14 @@ impl NimbusCore {
     pub async fn park_button_park(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.ParkButtonPark()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -137,18 +218,20 @@ impl NimbusCore {
     pub async fn shift_and_scan_rack(
         &self,
 
-        traverse_height: i32,
-        min_x_position: i32,
-        max_x_position: i32,
+        traverse_height: Millimeters,
+        min_x_position: Millimeters,
+        max_x_position: Millimeters,
         cam_position: i16,
     ) -> Result<ShiftAndScanRackReply, Error> {
         let mut args = BytesMut::new();
-        traverse_height.serialize(&mut args);
-        min_x_position.serialize(&mut args);
-        max_x_position.serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        min_x_position.to_wire::<i32>()?.serialize(&mut args);
+        max_x_position.to_wire::<i32>()?.serialize(&mut args);
         cam_position.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  traverse_height: {:?}", traverse_height),
@@ -164,9 +247,12 @@ impl NimbusCore {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
-        let x_positions = Vec::<i32>::deserialize(&mut stream)?;
+        let x_positions = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         let barcodes = String::deserialize(&mut stream)?;
         Ok(ShiftAndScanRackReply {
             x_positions,
@@ -177,18 +263,20 @@ impl NimbusCore {
     pub async fn shift_and_scan_row(
         &self,
 
-        traverse_height: i32,
-        x_position: i32,
+        traverse_height: Millimeters,
+        x_position: Millimeters,
         cams_used: impl AsRef<[u16]>,
         cam_positions: impl AsRef<[i16]>,
     ) -> Result<ShiftAndScanRowReply, Error> {
         let mut args = BytesMut::new();
-        traverse_height.serialize(&mut args);
-        x_position.serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
         cams_used.as_ref().serialize(&mut args);
         cam_positions.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  traverse_height: {:?}", traverse_height),
@@ -204,7 +292,7 @@ impl NimbusCore {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let barcodes = String::deserialize(&mut stream)?;
         Ok(ShiftAndScanRowReply { barcodes })
@@ -213,12 +301,14 @@ impl NimbusCore {
     pub async fn method_begin(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.MethodBegin()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -226,12 +316,14 @@ impl NimbusCore {
     pub async fn method_end(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 8, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.MethodEnd()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -240,7 +332,9 @@ impl NimbusCore {
         let mut args = BytesMut::new();
         instrument_error.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 9, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  instrument_error: {:?}", instrument_error)];
                 format!(
@@ -251,7 +345,7 @@ impl NimbusCore {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -259,12 +353,14 @@ impl NimbusCore {
     pub async fn get_instrument_error_mode(&self) -> Result</* instrument_error= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 10, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.GetInstrumentErrorMode()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let instrument_error = bool::deserialize(&mut stream)?;
         Ok(instrument_error)
@@ -274,7 +370,9 @@ impl NimbusCore {
         let mut args = BytesMut::new();
         operator_attention.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 11, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  operator_attention: {:?}", operator_attention)];
                 format!(
@@ -285,7 +383,7 @@ impl NimbusCore {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -295,12 +393,14 @@ impl NimbusCore {
     ) -> Result</* operator_attention= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 12, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.GetOperatorAttentionMode()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let operator_attention = bool::deserialize(&mut stream)?;
         Ok(operator_attention)
@@ -309,12 +409,14 @@ impl NimbusCore {
     pub async fn get_xml_compatibility(&self) -> Result</* level= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 13, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 13, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.GetXMLCompatibility()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let level = String::deserialize(&mut stream)?;
         Ok(level)
@@ -323,12 +425,14 @@ impl NimbusCore {
     pub async fn is_initialized(&self) -> Result</* initialized= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 14, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 14, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.IsInitialized()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let initialized = bool::deserialize(&mut stream)?;
         Ok(initialized)
@@ -336,19 +440,21 @@ impl NimbusCore {
 
     pub async fn get_channel_configuration_1(
         &self,
-    ) -> Result<GetChannelConfiguration_1Reply, Error> {
+    ) -> Result<GetChannelConfiguration1Reply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 15, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 15, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.GetChannelConfiguration_1()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let channels = u16::deserialize(&mut stream)?;
         let channel_types = Vec::<i16>::deserialize(&mut stream)?;
-        Ok(GetChannelConfiguration_1Reply {
+        Ok(GetChannelConfiguration1Reply {
             channels,
             channel_types,
         })
@@ -357,12 +463,14 @@ impl NimbusCore {
     pub async fn preinitialize_smart(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 16, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 16, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.PreinitializeSmart()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -370,12 +478,14 @@ impl NimbusCore {
     pub async fn is_plate_gripped(&self) -> Result</* plate_gripped= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 17, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 17, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.IsPlateGripped()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let plate_gripped = bool::deserialize(&mut stream)?;
         Ok(plate_gripped)
@@ -384,12 +494,14 @@ impl NimbusCore {
     pub async fn is_gripper_present(&self) -> Result</* present= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 18, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 18, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.IsGripperPresent()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let present = bool::deserialize(&mut stream)?;
         Ok(present)
@@ -398,12 +510,14 @@ impl NimbusCore {
     pub async fn is_shift_and_scan_present(&self) -> Result</* present= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 19, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 19, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.IsShiftAndScanPresent()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let present = bool::deserialize(&mut stream)?;
         Ok(present)
@@ -413,7 +527,9 @@ impl NimbusCore {
         let mut args = BytesMut::new();
         device_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 20, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 20, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  device_id: {:?}", device_id)];
                 format!(
@@ -424,7 +540,7 @@ impl NimbusCore {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let present = bool::deserialize(&mut stream)?;
         Ok(present)
@@ -434,7 +550,9 @@ impl NimbusCore {
         let mut args = BytesMut::new();
         scale.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 21, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 21, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  scale: {:?}", scale)];
                 format!(
@@ -445,7 +563,7 @@ impl NimbusCore {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -453,12 +571,14 @@ impl NimbusCore {
     pub async fn get_x_speed_scale(&self) -> Result</* scale= */ u16, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 22, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 22, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.GetXSpeedScale()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let scale = u16::deserialize(&mut stream)?;
         Ok(scale)
@@ -468,7 +588,9 @@ impl NimbusCore {
         let mut args = BytesMut::new();
         handle.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 23, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 23, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  handle: {:?}", handle)];
                 format!(
@@ -479,7 +601,7 @@ impl NimbusCore {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let data = Vec::<u8>::deserialize(&mut stream)?;
         Ok(data)
@@ -490,7 +612,9 @@ impl NimbusCore {
         handle.serialize(&mut args);
         data.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 24, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 24, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  handle: {:?}", handle),
@@ -504,7 +628,7 @@ impl NimbusCore {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -512,12 +636,14 @@ impl NimbusCore {
     pub async fn get_shift_and_scan_sensors(&self) -> Result<GetShiftAndScanSensorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 25, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 25, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.GetShiftAndScanSensors()".to_string(),
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let sensor_1 = bool::deserialize(&mut stream)?;
         let sensor_2 = bool::deserialize(&mut stream)?;
@@ -532,12 +658,14 @@ impl NimbusCore {
     pub async fn reset(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 26, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 26, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.Reset()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -545,20 +673,22 @@ impl NimbusCore {
     pub async fn shift_and_scan_rack_x_speed(
         &self,
 
-        traverse_height: i32,
-        min_x_position: i32,
-        max_x_position: i32,
-        x_speed: u32,
+        traverse_height: Millimeters,
+        min_x_position: Millimeters,
+        max_x_position: Millimeters,
+        x_speed: MmPerSecond,
         cam_position: i16,
     ) -> Result<ShiftAndScanRackXSpeedReply, Error> {
         let mut args = BytesMut::new();
-        traverse_height.serialize(&mut args);
-        min_x_position.serialize(&mut args);
-        max_x_position.serialize(&mut args);
-        x_speed.serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        min_x_position.to_wire::<i32>()?.serialize(&mut args);
+        max_x_position.to_wire::<i32>()?.serialize(&mut args);
+        x_speed.to_wire::<u32>()?.serialize(&mut args);
         cam_position.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 27, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 27, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  traverse_height: {:?}", traverse_height),
@@ -575,9 +705,12 @@ impl NimbusCore {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
-        let x_positions = Vec::<i32>::deserialize(&mut stream)?;
+        let x_positions = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         let barcodes = String::deserialize(&mut stream)?;
         Ok(ShiftAndScanRackXSpeedReply {
             x_positions,
@@ -588,22 +721,54 @@ impl NimbusCore {
     pub async fn initialize_roll(
         &self,
 
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
-        z_final: impl AsRef<[i32]>,
-        roll_distance: impl AsRef<[i32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
+        z_final: impl AsRef<[Millimeters]>,
+        roll_distance: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
-        z_final.as_ref().serialize(&mut args);
-        roll_distance.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        roll_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 28, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 28, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position.as_ref()),
@@ -621,7 +786,7 @@ impl NimbusCore {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -629,22 +794,54 @@ impl NimbusCore {
     pub async fn initialize_smart_roll(
         &self,
 
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
-        z_final: impl AsRef<[i32]>,
-        roll_distance: impl AsRef<[i32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
+        z_final: impl AsRef<[Millimeters]>,
+        roll_distance: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
-        z_final.as_ref().serialize(&mut args);
-        roll_distance.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        roll_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 29, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 29, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position.as_ref()),
@@ -662,7 +859,7 @@ impl NimbusCore {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -672,14 +869,16 @@ impl NimbusCore {
     ) -> Result</* configuration= */ Vec<ChannelConfiguration>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 30, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 30, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.GetChannelConfiguration_2()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let configuration = MVec::<ChannelConfiguration>::deserialize(&mut stream)?.0;
+        let configuration = Vec::<ChannelConfiguration>::deserialize(&mut stream)?;
         Ok(configuration)
     }
 
@@ -691,7 +890,9 @@ impl NimbusCore {
         let mut args = BytesMut::new();
         device_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 31, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 31, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  device_id: {:?}", device_id)];
                 format!(
@@ -702,7 +903,7 @@ impl NimbusCore {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let present = bool::deserialize(&mut stream)?;
         Ok(present)
@@ -711,12 +912,14 @@ impl NimbusCore {
     pub async fn get_x_velocity_limits(&self) -> Result<GetXVelocityLimitsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 32, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 32, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.GetXVelocityLimits()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let lower_limit = u32::deserialize(&mut stream)?;
         let upper_limit = u32::deserialize(&mut stream)?;
@@ -730,7 +933,9 @@ impl NimbusCore {
         let mut args = BytesMut::new();
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 33, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 33, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  velocity: {:?}", velocity)];
                 format!(
@@ -741,7 +946,7 @@ impl NimbusCore {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -749,12 +954,14 @@ impl NimbusCore {
     pub async fn get_x_maximum_velocity(&self) -> Result</* velocity= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 34, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 34, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.GetXMaximumVelocity()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let velocity = u32::deserialize(&mut stream)?;
         Ok(velocity)
@@ -763,12 +970,14 @@ impl NimbusCore {
     pub async fn reset_x_maximum_velocity(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 35, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 35, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.ResetXMaximumVelocity()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -776,12 +985,14 @@ impl NimbusCore {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -799,7 +1010,9 @@ impl NimbusCore {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -810,7 +1023,7 @@ impl NimbusCore {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -832,7 +1045,9 @@ impl NimbusCore {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -843,7 +1058,7 @@ impl NimbusCore {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -858,12 +1073,14 @@ impl NimbusCore {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCore.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -877,7 +1094,9 @@ impl NimbusCore {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -888,7 +1107,7 @@ impl NimbusCore {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -906,7 +1125,9 @@ impl NimbusCore {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -917,7 +1138,7 @@ impl NimbusCore {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -932,7 +1153,916 @@ impl NimbusCore {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+// The methods above as a trait, so code using them can be tested against NimbusCoreFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreApi {
+    fn initialize(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn initialize_smart(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn park(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn park_button_park(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn shift_and_scan_rack(
+        &self,
+        traverse_height: Millimeters,
+        min_x_position: Millimeters,
+        max_x_position: Millimeters,
+        cam_position: i16,
+    ) -> impl Future<Output = Result<ShiftAndScanRackReply, Error>> + Send;
+    fn shift_and_scan_row(
+        &self,
+        traverse_height: Millimeters,
+        x_position: Millimeters,
+        cams_used: impl AsRef<[u16]> + Send,
+        cam_positions: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<ShiftAndScanRowReply, Error>> + Send;
+    fn method_begin(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn method_end(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_instrument_error_mode(
+        &self,
+        instrument_error: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_instrument_error_mode(
+        &self,
+    ) -> impl Future<Output = Result</* instrument_error= */ bool, Error>> + Send;
+    fn set_operator_attention_mode(
+        &self,
+        operator_attention: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_operator_attention_mode(
+        &self,
+    ) -> impl Future<Output = Result</* operator_attention= */ bool, Error>> + Send;
+    fn get_xml_compatibility(
+        &self,
+    ) -> impl Future<Output = Result</* level= */ String, Error>> + Send;
+    fn is_initialized(&self)
+    -> impl Future<Output = Result</* initialized= */ bool, Error>> + Send;
+    fn get_channel_configuration_1(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelConfiguration1Reply, Error>> + Send;
+    fn preinitialize_smart(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn is_plate_gripped(
+        &self,
+    ) -> impl Future<Output = Result</* plate_gripped= */ bool, Error>> + Send;
+    fn is_gripper_present(&self)
+    -> impl Future<Output = Result</* present= */ bool, Error>> + Send;
+    fn is_shift_and_scan_present(
+        &self,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send;
+    fn is_device_present_1(
+        &self,
+        device_id: i16,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send;
+    fn set_x_speed_scale(&self, scale: u16) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_x_speed_scale(&self) -> impl Future<Output = Result</* scale= */ u16, Error>> + Send;
+    fn get_data_store(
+        &self,
+        handle: i16,
+    ) -> impl Future<Output = Result</* data= */ Vec<u8>, Error>> + Send;
+    fn set_data_store(
+        &self,
+        handle: i16,
+        data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_shift_and_scan_sensors(
+        &self,
+    ) -> impl Future<Output = Result<GetShiftAndScanSensorsReply, Error>> + Send;
+    fn reset(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn shift_and_scan_rack_x_speed(
+        &self,
+        traverse_height: Millimeters,
+        min_x_position: Millimeters,
+        max_x_position: Millimeters,
+        x_speed: MmPerSecond,
+        cam_position: i16,
+    ) -> impl Future<Output = Result<ShiftAndScanRackXSpeedReply, Error>> + Send;
+    fn initialize_roll(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn initialize_smart_roll(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_channel_configuration_2(
+        &self,
+    ) -> impl Future<Output = Result</* configuration= */ Vec<ChannelConfiguration>, Error>> + Send;
+    fn is_device_present_2(
+        &self,
+        device_id: DeviceId,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send;
+    fn get_x_velocity_limits(
+        &self,
+    ) -> impl Future<Output = Result<GetXVelocityLimitsReply, Error>> + Send;
+    fn set_x_maximum_velocity(
+        &self,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_x_maximum_velocity(
+        &self,
+    ) -> impl Future<Output = Result</* velocity= */ u32, Error>> + Send;
+    fn reset_x_maximum_velocity(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreApi for NimbusCore {
+    fn initialize(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::initialize(
+            self,
+            x_position,
+            y_position,
+            z_start_position,
+            z_stop_position,
+            z_final,
+        )
+    }
+
+    fn initialize_smart(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::initialize_smart(
+            self,
+            x_position,
+            y_position,
+            z_start_position,
+            z_stop_position,
+            z_final,
+        )
+    }
+
+    fn park(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::park(self)
+    }
+
+    fn park_button_park(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::park_button_park(self)
+    }
+
+    fn shift_and_scan_rack(
+        &self,
+        traverse_height: Millimeters,
+        min_x_position: Millimeters,
+        max_x_position: Millimeters,
+        cam_position: i16,
+    ) -> impl Future<Output = Result<ShiftAndScanRackReply, Error>> + Send {
+        NimbusCore::shift_and_scan_rack(
+            self,
+            traverse_height,
+            min_x_position,
+            max_x_position,
+            cam_position,
+        )
+    }
+
+    fn shift_and_scan_row(
+        &self,
+        traverse_height: Millimeters,
+        x_position: Millimeters,
+        cams_used: impl AsRef<[u16]> + Send,
+        cam_positions: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<ShiftAndScanRowReply, Error>> + Send {
+        NimbusCore::shift_and_scan_row(self, traverse_height, x_position, cams_used, cam_positions)
+    }
+
+    fn method_begin(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::method_begin(self)
+    }
+
+    fn method_end(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::method_end(self)
+    }
+
+    fn set_instrument_error_mode(
+        &self,
+        instrument_error: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::set_instrument_error_mode(self, instrument_error)
+    }
+
+    fn get_instrument_error_mode(
+        &self,
+    ) -> impl Future<Output = Result</* instrument_error= */ bool, Error>> + Send {
+        NimbusCore::get_instrument_error_mode(self)
+    }
+
+    fn set_operator_attention_mode(
+        &self,
+        operator_attention: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::set_operator_attention_mode(self, operator_attention)
+    }
+
+    fn get_operator_attention_mode(
+        &self,
+    ) -> impl Future<Output = Result</* operator_attention= */ bool, Error>> + Send {
+        NimbusCore::get_operator_attention_mode(self)
+    }
+
+    fn get_xml_compatibility(
+        &self,
+    ) -> impl Future<Output = Result</* level= */ String, Error>> + Send {
+        NimbusCore::get_xml_compatibility(self)
+    }
+
+    fn is_initialized(
+        &self,
+    ) -> impl Future<Output = Result</* initialized= */ bool, Error>> + Send {
+        NimbusCore::is_initialized(self)
+    }
+
+    fn get_channel_configuration_1(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelConfiguration1Reply, Error>> + Send {
+        NimbusCore::get_channel_configuration_1(self)
+    }
+
+    fn preinitialize_smart(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::preinitialize_smart(self)
+    }
+
+    fn is_plate_gripped(
+        &self,
+    ) -> impl Future<Output = Result</* plate_gripped= */ bool, Error>> + Send {
+        NimbusCore::is_plate_gripped(self)
+    }
+
+    fn is_gripper_present(
+        &self,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        NimbusCore::is_gripper_present(self)
+    }
+
+    fn is_shift_and_scan_present(
+        &self,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        NimbusCore::is_shift_and_scan_present(self)
+    }
+
+    fn is_device_present_1(
+        &self,
+        device_id: i16,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        NimbusCore::is_device_present_1(self, device_id)
+    }
+
+    fn set_x_speed_scale(&self, scale: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::set_x_speed_scale(self, scale)
+    }
+
+    fn get_x_speed_scale(&self) -> impl Future<Output = Result</* scale= */ u16, Error>> + Send {
+        NimbusCore::get_x_speed_scale(self)
+    }
+
+    fn get_data_store(
+        &self,
+        handle: i16,
+    ) -> impl Future<Output = Result</* data= */ Vec<u8>, Error>> + Send {
+        NimbusCore::get_data_store(self, handle)
+    }
+
+    fn set_data_store(
+        &self,
+        handle: i16,
+        data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::set_data_store(self, handle, data)
+    }
+
+    fn get_shift_and_scan_sensors(
+        &self,
+    ) -> impl Future<Output = Result<GetShiftAndScanSensorsReply, Error>> + Send {
+        NimbusCore::get_shift_and_scan_sensors(self)
+    }
+
+    fn reset(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::reset(self)
+    }
+
+    fn shift_and_scan_rack_x_speed(
+        &self,
+        traverse_height: Millimeters,
+        min_x_position: Millimeters,
+        max_x_position: Millimeters,
+        x_speed: MmPerSecond,
+        cam_position: i16,
+    ) -> impl Future<Output = Result<ShiftAndScanRackXSpeedReply, Error>> + Send {
+        NimbusCore::shift_and_scan_rack_x_speed(
+            self,
+            traverse_height,
+            min_x_position,
+            max_x_position,
+            x_speed,
+            cam_position,
+        )
+    }
+
+    fn initialize_roll(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::initialize_roll(
+            self,
+            x_position,
+            y_position,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            roll_distance,
+        )
+    }
+
+    fn initialize_smart_roll(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::initialize_smart_roll(
+            self,
+            x_position,
+            y_position,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            roll_distance,
+        )
+    }
+
+    fn get_channel_configuration_2(
+        &self,
+    ) -> impl Future<Output = Result</* configuration= */ Vec<ChannelConfiguration>, Error>> + Send
+    {
+        NimbusCore::get_channel_configuration_2(self)
+    }
+
+    fn is_device_present_2(
+        &self,
+        device_id: DeviceId,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        NimbusCore::is_device_present_2(self, device_id)
+    }
+
+    fn get_x_velocity_limits(
+        &self,
+    ) -> impl Future<Output = Result<GetXVelocityLimitsReply, Error>> + Send {
+        NimbusCore::get_x_velocity_limits(self)
+    }
+
+    fn set_x_maximum_velocity(
+        &self,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::set_x_maximum_velocity(self, velocity)
+    }
+
+    fn get_x_maximum_velocity(
+        &self,
+    ) -> impl Future<Output = Result</* velocity= */ u32, Error>> + Send {
+        NimbusCore::get_x_maximum_velocity(self)
+    }
+
+    fn reset_x_maximum_velocity(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCore::reset_x_maximum_velocity(self)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCore::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCore::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCore::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCore::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCore::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCore::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreApi for NimbusCoreFake {
+    fn initialize(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "initialize",
+            vec![
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("z_final", format!("{:?}", z_final.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn initialize_smart(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "initialize_smart",
+            vec![
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("z_final", format!("{:?}", z_final.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn park(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("park", vec![]);
+        async move { reply }
+    }
+
+    fn park_button_park(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("park_button_park", vec![]);
+        async move { reply }
+    }
+
+    fn shift_and_scan_rack(
+        &self,
+        traverse_height: Millimeters,
+        min_x_position: Millimeters,
+        max_x_position: Millimeters,
+        cam_position: i16,
+    ) -> impl Future<Output = Result<ShiftAndScanRackReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "shift_and_scan_rack",
+            vec![
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("min_x_position", format!("{:?}", min_x_position)),
+                ("max_x_position", format!("{:?}", max_x_position)),
+                ("cam_position", format!("{:?}", cam_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn shift_and_scan_row(
+        &self,
+        traverse_height: Millimeters,
+        x_position: Millimeters,
+        cams_used: impl AsRef<[u16]> + Send,
+        cam_positions: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<ShiftAndScanRowReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "shift_and_scan_row",
+            vec![
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("x_position", format!("{:?}", x_position)),
+                ("cams_used", format!("{:?}", cams_used.as_ref())),
+                ("cam_positions", format!("{:?}", cam_positions.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn method_begin(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("method_begin", vec![]);
+        async move { reply }
+    }
+
+    fn method_end(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("method_end", vec![]);
+        async move { reply }
+    }
+
+    fn set_instrument_error_mode(
+        &self,
+        instrument_error: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_instrument_error_mode",
+            vec![("instrument_error", format!("{:?}", instrument_error))],
+        );
+        async move { reply }
+    }
+
+    fn get_instrument_error_mode(
+        &self,
+    ) -> impl Future<Output = Result</* instrument_error= */ bool, Error>> + Send {
+        let reply = self.recorder.record("get_instrument_error_mode", vec![]);
+        async move { reply }
+    }
+
+    fn set_operator_attention_mode(
+        &self,
+        operator_attention: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_operator_attention_mode",
+            vec![("operator_attention", format!("{:?}", operator_attention))],
+        );
+        async move { reply }
+    }
+
+    fn get_operator_attention_mode(
+        &self,
+    ) -> impl Future<Output = Result</* operator_attention= */ bool, Error>> + Send {
+        let reply = self.recorder.record("get_operator_attention_mode", vec![]);
+        async move { reply }
+    }
+
+    fn get_xml_compatibility(
+        &self,
+    ) -> impl Future<Output = Result</* level= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_xml_compatibility", vec![]);
+        async move { reply }
+    }
+
+    fn is_initialized(
+        &self,
+    ) -> impl Future<Output = Result</* initialized= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_initialized", vec![]);
+        async move { reply }
+    }
+
+    fn get_channel_configuration_1(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelConfiguration1Reply, Error>> + Send {
+        let reply = self.recorder.record("get_channel_configuration_1", vec![]);
+        async move { reply }
+    }
+
+    fn preinitialize_smart(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("preinitialize_smart", vec![]);
+        async move { reply }
+    }
+
+    fn is_plate_gripped(
+        &self,
+    ) -> impl Future<Output = Result</* plate_gripped= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_plate_gripped", vec![]);
+        async move { reply }
+    }
+
+    fn is_gripper_present(
+        &self,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_gripper_present", vec![]);
+        async move { reply }
+    }
+
+    fn is_shift_and_scan_present(
+        &self,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_shift_and_scan_present", vec![]);
+        async move { reply }
+    }
+
+    fn is_device_present_1(
+        &self,
+        device_id: i16,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        let reply = self.recorder.record(
+            "is_device_present_1",
+            vec![("device_id", format!("{:?}", device_id))],
+        );
+        async move { reply }
+    }
+
+    fn set_x_speed_scale(&self, scale: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("set_x_speed_scale", vec![("scale", format!("{:?}", scale))]);
+        async move { reply }
+    }
+
+    fn get_x_speed_scale(&self) -> impl Future<Output = Result</* scale= */ u16, Error>> + Send {
+        let reply = self.recorder.record("get_x_speed_scale", vec![]);
+        async move { reply }
+    }
+
+    fn get_data_store(
+        &self,
+        handle: i16,
+    ) -> impl Future<Output = Result</* data= */ Vec<u8>, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("get_data_store", vec![("handle", format!("{:?}", handle))]);
+        async move { reply }
+    }
+
+    fn set_data_store(
+        &self,
+        handle: i16,
+        data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_data_store",
+            vec![
+                ("handle", format!("{:?}", handle)),
+                ("data", format!("{:?}", data.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_shift_and_scan_sensors(
+        &self,
+    ) -> impl Future<Output = Result<GetShiftAndScanSensorsReply, Error>> + Send {
+        let reply = self.recorder.record("get_shift_and_scan_sensors", vec![]);
+        async move { reply }
+    }
+
+    fn reset(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("reset", vec![]);
+        async move { reply }
+    }
+
+    fn shift_and_scan_rack_x_speed(
+        &self,
+        traverse_height: Millimeters,
+        min_x_position: Millimeters,
+        max_x_position: Millimeters,
+        x_speed: MmPerSecond,
+        cam_position: i16,
+    ) -> impl Future<Output = Result<ShiftAndScanRackXSpeedReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "shift_and_scan_rack_x_speed",
+            vec![
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("min_x_position", format!("{:?}", min_x_position)),
+                ("max_x_position", format!("{:?}", max_x_position)),
+                ("x_speed", format!("{:?}", x_speed)),
+                ("cam_position", format!("{:?}", cam_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn initialize_roll(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "initialize_roll",
+            vec![
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("z_final", format!("{:?}", z_final.as_ref())),
+                ("roll_distance", format!("{:?}", roll_distance.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn initialize_smart_roll(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "initialize_smart_roll",
+            vec![
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("z_final", format!("{:?}", z_final.as_ref())),
+                ("roll_distance", format!("{:?}", roll_distance.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_channel_configuration_2(
+        &self,
+    ) -> impl Future<Output = Result</* configuration= */ Vec<ChannelConfiguration>, Error>> + Send
+    {
+        let reply = self.recorder.record("get_channel_configuration_2", vec![]);
+        async move { reply }
+    }
+
+    fn is_device_present_2(
+        &self,
+        device_id: DeviceId,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        let reply = self.recorder.record(
+            "is_device_present_2",
+            vec![("device_id", format!("{:?}", device_id))],
+        );
+        async move { reply }
+    }
+
+    fn get_x_velocity_limits(
+        &self,
+    ) -> impl Future<Output = Result<GetXVelocityLimitsReply, Error>> + Send {
+        let reply = self.recorder.record("get_x_velocity_limits", vec![]);
+        async move { reply }
+    }
+
+    fn set_x_maximum_velocity(
+        &self,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_x_maximum_velocity",
+            vec![("velocity", format!("{:?}", velocity))],
+        );
+        async move { reply }
+    }
+
+    fn get_x_maximum_velocity(
+        &self,
+    ) -> impl Future<Output = Result</* velocity= */ u32, Error>> + Send {
+        let reply = self.recorder.record("get_x_maximum_velocity", vec![]);
+        async move { reply }
+    }
+
+    fn reset_x_maximum_velocity(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("reset_x_maximum_velocity", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeviceId {
     DeviceIdInvalid = 0,
     DeviceIdChannel1 = 1,
@@ -952,146 +2082,50 @@ pub enum DeviceId {
     DeviceIdChannel8 = 15,
 }
 
-impl TryFrom<i32> for DeviceId {
-    type Error = piglet_client::client::Error;
-
-    fn try_from(v: i32) -> Result<Self, Self::Error> {
-        match v {
-            0 => Ok(DeviceId::DeviceIdInvalid),
-            1 => Ok(DeviceId::DeviceIdChannel1),
-            2 => Ok(DeviceId::DeviceIdChannel2),
-            3 => Ok(DeviceId::DeviceIdChannel3),
-            4 => Ok(DeviceId::DeviceIdChannel4),
-            5 => Ok(DeviceId::DeviceIdGripper),
-            6 => Ok(DeviceId::DeviceIdBarcodeReader),
-            7 => Ok(DeviceId::DeviceIdShiftAndScan),
-            8 => Ok(DeviceId::DeviceIdBdPuncture),
-            9 => Ok(DeviceId::DeviceIdSiemensCarousel),
-            10 => Ok(DeviceId::DeviceIdDoorLock),
-            11 => Ok(DeviceId::DeviceIdIoBoard),
-            12 => Ok(DeviceId::DeviceIdChannel5),
-            13 => Ok(DeviceId::DeviceIdChannel6),
-            14 => Ok(DeviceId::DeviceIdChannel7),
-            15 => Ok(DeviceId::DeviceIdChannel8),
-
-            _ => Err(ConnectionError(anyhow!("Unknown DeviceId value {}", v))),
-        }
-    }
-}
-
-impl PigletCodec for DeviceId {
-    const TYPE_ID: u8 = 32;
-}
-
-impl PigletDeserialize for DeviceId {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        stream.get_i32_le().try_into()
-    }
-}
-
-impl PigletSerialize for DeviceId {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        stream.put_u16_le(4);
-        stream.put_i32_le(*self as i32);
-    }
-}
-
-impl PigletCodec for MSlice<'_, DeviceId> {
-    const TYPE_ID: u8 = 35;
-}
-
-impl PigletCodec for MVec<DeviceId> {
-    const TYPE_ID: u8 = 35;
-}
-
-impl PigletDeserialize for MVec<DeviceId> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut arr = Vec::new();
-        for i in 0..(length / 4) {
-            arr.push(stream.get_i32_le().try_into()?);
-        }
-        Ok(MVec(arr))
-    }
-}
-
-impl PigletSerialize for MSlice<'_, DeviceId> {
-    fn serialize(&self, bytes: &mut BytesMut) {
-        bytes.put_u8(Self::TYPE_ID);
-        bytes.put_u8(0);
-        bytes.put_u16_le(4 * self.0.len() as u16);
-        for v in self.0.as_ref() {
-            bytes.put_i32_le(*v as i32);
-        }
-    }
-}
-
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShiftAndScanRackReply {
-    pub x_positions: Vec<i32>,
+    pub x_positions: Vec<Millimeters>,
     pub barcodes: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShiftAndScanRowReply {
     pub barcodes: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
-pub struct GetChannelConfiguration_1Reply {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetChannelConfiguration1Reply {
     pub channels: u16,
     pub channel_types: Vec<i16>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetShiftAndScanSensorsReply {
     pub sensor_1: bool,
     pub sensor_2: bool,
     pub sensor_3: bool,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShiftAndScanRackXSpeedReply {
-    pub x_positions: Vec<i32>,
+    pub x_positions: Vec<Millimeters>,
     pub barcodes: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetXVelocityLimitsReply {
     pub lower_limit: u32,
     pub upper_limit: u32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -1099,8 +2133,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -1110,23 +2144,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -1134,8 +2168,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
@@ -1,14 +1,14 @@
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
+use piglet_client::units::Millimeters;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +16,13 @@ use std::sync::Arc;
 pub struct NimbusCoreBarcodeScanner0 {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreBarcodeScanner0 {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "BarcodeScanner0";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,18 +32,40 @@ impl NimbusCoreBarcodeScanner0 {
                 object_id: 48896,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.BarcodeScanner0").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn is_scanner_1_present(&self) -> Result</* present= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0.IsScanner1Present()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let present = bool::deserialize(&mut stream)?;
         Ok(present)
@@ -49,12 +74,14 @@ impl NimbusCoreBarcodeScanner0 {
     pub async fn is_scanner_2_present(&self) -> Result</* present= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0.IsScanner2Present()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let present = bool::deserialize(&mut stream)?;
         Ok(present)
@@ -63,28 +90,32 @@ impl NimbusCoreBarcodeScanner0 {
     pub async fn get_run_time(&self) -> Result</* ms_run_time= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 3, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0.GetRunTime()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let ms_run_time = u32::deserialize(&mut stream)?;
         Ok(ms_run_time)
     }
 
-    pub async fn buddy_test_method(&self) -> Result</* position= */ i32, Error> {
+    pub async fn buddy_test_method(&self) -> Result</* position= */ Millimeters, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0.BuddyTestMethod()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let position = i32::deserialize(&mut stream)?;
+        let position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
         Ok(position)
     }
 
@@ -92,7 +123,9 @@ impl NimbusCoreBarcodeScanner0 {
         let mut args = BytesMut::new();
         position_seed.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position_seed: {:?}", position_seed)];
                 format!(
@@ -103,7 +136,7 @@ impl NimbusCoreBarcodeScanner0 {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -111,12 +144,14 @@ impl NimbusCoreBarcodeScanner0 {
     pub async fn get_hardware_version(&self) -> Result</* value= */ u8, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 6, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0.GetHardwareVersion()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = u8::deserialize(&mut stream)?;
         Ok(value)
@@ -125,12 +160,14 @@ impl NimbusCoreBarcodeScanner0 {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -148,7 +185,9 @@ impl NimbusCoreBarcodeScanner0 {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -159,7 +198,7 @@ impl NimbusCoreBarcodeScanner0 {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -181,7 +220,9 @@ impl NimbusCoreBarcodeScanner0 {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -192,7 +233,7 @@ impl NimbusCoreBarcodeScanner0 {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -207,12 +248,14 @@ impl NimbusCoreBarcodeScanner0 {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -226,7 +269,9 @@ impl NimbusCoreBarcodeScanner0 {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -237,7 +282,7 @@ impl NimbusCoreBarcodeScanner0 {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -255,7 +300,9 @@ impl NimbusCoreBarcodeScanner0 {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -266,7 +313,7 @@ impl NimbusCoreBarcodeScanner0 {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -281,8 +328,228 @@ impl NimbusCoreBarcodeScanner0 {
     }
 }
 
-#[allow(non_camel_case_types)]
+// The methods above as a trait, so code using them can be tested against NimbusCoreBarcodeScanner0Fake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreBarcodeScanner0Api {
+    fn is_scanner_1_present(
+        &self,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send;
+    fn is_scanner_2_present(
+        &self,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send;
+    fn get_run_time(&self) -> impl Future<Output = Result</* ms_run_time= */ u32, Error>> + Send;
+    fn buddy_test_method(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ Millimeters, Error>> + Send;
+    fn set_buddy_test_seed_value(
+        &self,
+        position_seed: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_hardware_version(&self) -> impl Future<Output = Result</* value= */ u8, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreBarcodeScanner0Api for NimbusCoreBarcodeScanner0 {
+    fn is_scanner_1_present(
+        &self,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        NimbusCoreBarcodeScanner0::is_scanner_1_present(self)
+    }
+
+    fn is_scanner_2_present(
+        &self,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        NimbusCoreBarcodeScanner0::is_scanner_2_present(self)
+    }
+
+    fn get_run_time(&self) -> impl Future<Output = Result</* ms_run_time= */ u32, Error>> + Send {
+        NimbusCoreBarcodeScanner0::get_run_time(self)
+    }
+
+    fn buddy_test_method(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ Millimeters, Error>> + Send {
+        NimbusCoreBarcodeScanner0::buddy_test_method(self)
+    }
+
+    fn set_buddy_test_seed_value(
+        &self,
+        position_seed: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreBarcodeScanner0::set_buddy_test_seed_value(self, position_seed)
+    }
+
+    fn get_hardware_version(&self) -> impl Future<Output = Result</* value= */ u8, Error>> + Send {
+        NimbusCoreBarcodeScanner0::get_hardware_version(self)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreBarcodeScanner0Fake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreBarcodeScanner0Api for NimbusCoreBarcodeScanner0Fake {
+    fn is_scanner_1_present(
+        &self,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_scanner_1_present", vec![]);
+        async move { reply }
+    }
+
+    fn is_scanner_2_present(
+        &self,
+    ) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_scanner_2_present", vec![]);
+        async move { reply }
+    }
+
+    fn get_run_time(&self) -> impl Future<Output = Result</* ms_run_time= */ u32, Error>> + Send {
+        let reply = self.recorder.record("get_run_time", vec![]);
+        async move { reply }
+    }
+
+    fn buddy_test_method(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ Millimeters, Error>> + Send {
+        let reply = self.recorder.record("buddy_test_method", vec![]);
+        async move { reply }
+    }
+
+    fn set_buddy_test_seed_value(
+        &self,
+        position_seed: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_buddy_test_seed_value",
+            vec![("position_seed", format!("{:?}", position_seed))],
+        );
+        async move { reply }
+    }
+
+    fn get_hardware_version(&self) -> impl Future<Output = Result</* value= */ u8, Error>> + Send {
+        let reply = self.recorder.record("get_hardware_version", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -290,8 +557,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -301,23 +568,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -325,8 +592,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
@@ -2,13 +2,12 @@ use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +15,13 @@ use std::sync::Arc;
 pub struct NimbusCoreBarcodeScanner0BarcodeModuleCpu {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
-    // version
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "BarcodeModuleCpu";
+    pub const VERSION: &'static str = "";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,18 +31,42 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
                 object_id: 49408,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot
+                .resolve("NimbusCore.BarcodeScanner0.BarcodeModuleCpu")
+                .await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn download_info(&self) -> Result<DownloadInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0BarcodeModuleCpu.DownloadInfo()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let buffer_size = i32::deserialize(&mut stream)?;
         let file_name_template = String::deserialize(&mut stream)?;
@@ -53,7 +79,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
     pub async fn download_initiate(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 "in call to NimbusCoreBarcodeScanner0BarcodeModuleCpu.DownloadInitiate()"
                     .to_string()
@@ -61,7 +89,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -70,7 +98,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         let mut args = BytesMut::new();
         download_data.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  download_data: {:?}", download_data.as_ref())];
                 format!(
@@ -81,7 +111,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -90,7 +120,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         let mut args = BytesMut::new();
         success.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  success: {:?}", success)];
                 format!(
@@ -101,7 +133,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -109,12 +141,14 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
     pub async fn version(&self) -> Result<VersionReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 5, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0BarcodeModuleCpu.Version()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let firmware_version = String::deserialize(&mut stream)?;
         Ok(VersionReply { firmware_version })
@@ -123,12 +157,14 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
     pub async fn is_in_boot(&self) -> Result</* in_boot= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 6, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0BarcodeModuleCpu.IsInBoot()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let in_boot = bool::deserialize(&mut stream)?;
         Ok(in_boot)
@@ -138,7 +174,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         let mut args = BytesMut::new();
         address.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  address: {:?}", address)];
                 format!(
@@ -149,7 +187,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = u8::deserialize(&mut stream)?;
         Ok(value)
@@ -159,7 +197,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         let mut args = BytesMut::new();
         address.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 8, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  address: {:?}", address)];
                 format!(
@@ -170,7 +210,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = u32::deserialize(&mut stream)?;
         Ok(value)
@@ -181,7 +221,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         address.serialize(&mut args);
         value.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 9, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  address: {:?}", address),
@@ -195,7 +237,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -205,7 +247,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         address.serialize(&mut args);
         value.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 10, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  address: {:?}", address),
@@ -219,7 +263,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -227,12 +271,14 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
     pub async fn reg_table_entries(&self) -> Result</* entries= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 11, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0BarcodeModuleCpu.RegTableEntries()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let entries = u32::deserialize(&mut stream)?;
         Ok(entries)
@@ -242,7 +288,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         let mut args = BytesMut::new();
         entry.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 12, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  entry: {:?}", entry)];
                 format!(
@@ -253,7 +301,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -271,7 +319,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         let mut args = BytesMut::new();
         delay_ms.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 2, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 2, 3, 1, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  delay_ms: {:?}", delay_ms)];
                 format!(
@@ -282,7 +332,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -290,7 +340,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
     pub async fn boot_loader_version(&self) -> Result</* boot_loader_version= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 2, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 2, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 "in call to NimbusCoreBarcodeScanner0BarcodeModuleCpu.BootLoaderVersion()"
                     .to_string()
@@ -298,7 +350,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let boot_loader_version = String::deserialize(&mut stream)?;
         Ok(boot_loader_version)
@@ -307,12 +359,14 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
     pub async fn get_up_time(&self) -> Result</* value= */ SUpTime, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 2, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 2, 0, 3, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0BarcodeModuleCpu.GetUpTime()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = SUpTime::deserialize(&mut stream)?;
         Ok(value)
@@ -321,12 +375,14 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
     pub async fn get_test_address(&self) -> Result</* value= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 2, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 2, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0BarcodeModuleCpu.GetTestAddress()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = u32::deserialize(&mut stream)?;
         Ok(value)
@@ -337,7 +393,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
     ) -> Result</* algorithm= */ CompressionAlgorithm, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 2, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 2, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 "in call to NimbusCoreBarcodeScanner0BarcodeModuleCpu.GetCompressionAlgorithm()"
                     .to_string()
@@ -345,7 +403,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let algorithm = CompressionAlgorithm::deserialize(&mut stream)?;
         Ok(algorithm)
@@ -359,7 +417,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         let mut args = BytesMut::new();
         download_data.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 2, 3, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 2, 3, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  download_data: {:?}", download_data.as_ref())];
                 format!(
@@ -370,7 +430,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -378,7 +438,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
     pub async fn get_download_timeouts(&self) -> Result<GetDownloadTimeoutsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 2, 0, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 2, 0, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 "in call to NimbusCoreBarcodeScanner0BarcodeModuleCpu.GetDownloadTimeouts()"
                     .to_string()
@@ -386,7 +448,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let download_write_timeout = u32::deserialize(&mut stream)?;
         let download_complete_timeout = u32::deserialize(&mut stream)?;
@@ -399,12 +461,14 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0BarcodeModuleCpu.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -422,7 +486,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -433,7 +499,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -455,7 +521,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -466,7 +534,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -481,7 +549,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 "in call to NimbusCoreBarcodeScanner0BarcodeModuleCpu.InterfaceDescriptors()"
                     .to_string()
@@ -489,7 +559,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -503,7 +573,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -514,7 +586,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -532,7 +604,9 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -543,7 +617,7 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -558,235 +632,480 @@ impl NimbusCoreBarcodeScanner0BarcodeModuleCpu {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub enum CompressionAlgorithm {
-    Nocompression = 0,
-    Hexcompression = 1,
-    Fastlz = 2,
+// The methods above as a trait, so code using them can be tested against NimbusCoreBarcodeScanner0BarcodeModuleCpuFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreBarcodeScanner0BarcodeModuleCpuApi {
+    fn download_info(&self) -> impl Future<Output = Result<DownloadInfoReply, Error>> + Send;
+    fn download_initiate(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn download_write(
+        &self,
+        download_data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn download_complete(&self, success: bool) -> impl Future<Output = Result<(), Error>> + Send;
+    fn version(&self) -> impl Future<Output = Result<VersionReply, Error>> + Send;
+    fn is_in_boot(&self) -> impl Future<Output = Result</* in_boot= */ bool, Error>> + Send;
+    fn read_uint_8(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u8, Error>> + Send;
+    fn read_uint_32(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u32, Error>> + Send;
+    fn write_uint_8(
+        &self,
+        address: u32,
+        value: u8,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn write_uint_32(
+        &self,
+        address: u32,
+        value: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn reg_table_entries(&self) -> impl Future<Output = Result</* entries= */ u32, Error>> + Send;
+    fn reg_table_entry(
+        &self,
+        entry: u32,
+    ) -> impl Future<Output = Result<RegTableEntryReply, Error>> + Send;
+    fn reset(&self, delay_ms: u32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn boot_loader_version(
+        &self,
+    ) -> impl Future<Output = Result</* boot_loader_version= */ String, Error>> + Send;
+    fn get_up_time(&self) -> impl Future<Output = Result</* value= */ SUpTime, Error>> + Send;
+    fn get_test_address(&self) -> impl Future<Output = Result</* value= */ u32, Error>> + Send;
+    fn get_compression_algorithm(
+        &self,
+    ) -> impl Future<Output = Result</* algorithm= */ CompressionAlgorithm, Error>> + Send;
+    fn download_write_compressed_data(
+        &self,
+        download_data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_download_timeouts(
+        &self,
+    ) -> impl Future<Output = Result<GetDownloadTimeoutsReply, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
 }
 
-impl TryFrom<i32> for CompressionAlgorithm {
-    type Error = piglet_client::client::Error;
+impl NimbusCoreBarcodeScanner0BarcodeModuleCpuApi for NimbusCoreBarcodeScanner0BarcodeModuleCpu {
+    fn download_info(&self) -> impl Future<Output = Result<DownloadInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::download_info(self)
+    }
 
-    fn try_from(v: i32) -> Result<Self, Self::Error> {
-        match v {
-            0 => Ok(CompressionAlgorithm::Nocompression),
-            1 => Ok(CompressionAlgorithm::Hexcompression),
-            2 => Ok(CompressionAlgorithm::Fastlz),
+    fn download_initiate(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::download_initiate(self)
+    }
 
-            _ => Err(ConnectionError(anyhow!(
-                "Unknown CompressionAlgorithm value {}",
-                v
-            ))),
-        }
+    fn download_write(
+        &self,
+        download_data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::download_write(self, download_data)
     }
-}
 
-impl PigletCodec for CompressionAlgorithm {
-    const TYPE_ID: u8 = 32;
-}
+    fn download_complete(&self, success: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::download_complete(self, success)
+    }
 
-impl PigletDeserialize for CompressionAlgorithm {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        stream.get_i32_le().try_into()
+    fn version(&self) -> impl Future<Output = Result<VersionReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::version(self)
     }
-}
 
-impl PigletSerialize for CompressionAlgorithm {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        stream.put_u16_le(4);
-        stream.put_i32_le(*self as i32);
+    fn is_in_boot(&self) -> impl Future<Output = Result</* in_boot= */ bool, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::is_in_boot(self)
     }
-}
 
-impl PigletCodec for MSlice<'_, CompressionAlgorithm> {
-    const TYPE_ID: u8 = 35;
-}
+    fn read_uint_8(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u8, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::read_uint_8(self, address)
+    }
 
-impl PigletCodec for MVec<CompressionAlgorithm> {
-    const TYPE_ID: u8 = 35;
-}
+    fn read_uint_32(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u32, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::read_uint_32(self, address)
+    }
 
-impl PigletDeserialize for MVec<CompressionAlgorithm> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut arr = Vec::new();
-        for i in 0..(length / 4) {
-            arr.push(stream.get_i32_le().try_into()?);
-        }
-        Ok(MVec(arr))
+    fn write_uint_8(
+        &self,
+        address: u32,
+        value: u8,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::write_uint_8(self, address, value)
     }
-}
 
-impl PigletSerialize for MSlice<'_, CompressionAlgorithm> {
-    fn serialize(&self, bytes: &mut BytesMut) {
-        bytes.put_u8(Self::TYPE_ID);
-        bytes.put_u8(0);
-        bytes.put_u16_le(4 * self.0.len() as u16);
-        for v in self.0.as_ref() {
-            bytes.put_i32_le(*v as i32);
-        }
+    fn write_uint_32(
+        &self,
+        address: u32,
+        value: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::write_uint_32(self, address, value)
     }
-}
 
-#[derive(Clone, Debug)]
-pub struct SUpTime {
-    pub days: u8,
-    pub hours: u8,
-    pub minutes: u8,
-    pub seconds: u8,
-    pub milliseconds: u16,
-}
+    fn reg_table_entries(&self) -> impl Future<Output = Result</* entries= */ u32, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::reg_table_entries(self)
+    }
 
-impl PigletCodec for SUpTime {
-    const TYPE_ID: u8 = 30;
-}
+    fn reg_table_entry(
+        &self,
+        entry: u32,
+    ) -> impl Future<Output = Result<RegTableEntryReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::reg_table_entry(self, entry)
+    }
+
+    fn reset(&self, delay_ms: u32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::reset(self, delay_ms)
+    }
 
-impl PigletSerialize for SUpTime {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        let mut buffer = BytesMut::new();
-        let s = self;
+    fn boot_loader_version(
+        &self,
+    ) -> impl Future<Output = Result</* boot_loader_version= */ String, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::boot_loader_version(self)
+    }
 
-        s.days.serialize(&mut buffer);
-        s.hours.serialize(&mut buffer);
-        s.minutes.serialize(&mut buffer);
-        s.seconds.serialize(&mut buffer);
-        s.milliseconds.serialize(&mut buffer);
+    fn get_up_time(&self) -> impl Future<Output = Result</* value= */ SUpTime, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::get_up_time(self)
+    }
 
-        stream.put_u16_le(buffer.len() as u16);
-        stream.put(buffer);
+    fn get_test_address(&self) -> impl Future<Output = Result</* value= */ u32, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::get_test_address(self)
     }
-}
 
-impl PigletDeserialize for SUpTime {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(Self {
-            days: u8::deserialize(&mut bytes)?,
-            hours: u8::deserialize(&mut bytes)?,
-            minutes: u8::deserialize(&mut bytes)?,
-            seconds: u8::deserialize(&mut bytes)?,
-            milliseconds: u16::deserialize(&mut bytes)?,
-        })
+    fn get_compression_algorithm(
+        &self,
+    ) -> impl Future<Output = Result</* algorithm= */ CompressionAlgorithm, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::get_compression_algorithm(self)
+    }
+
+    fn download_write_compressed_data(
+        &self,
+        download_data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::download_write_compressed_data(
+            self,
+            download_data,
+        )
+    }
+
+    fn get_download_timeouts(
+        &self,
+    ) -> impl Future<Output = Result<GetDownloadTimeoutsReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::get_download_timeouts(self)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::method_info(self, method)
     }
-}
 
-impl PigletCodec for MSlice<'_, SUpTime> {
-    const TYPE_ID: u8 = 31;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0BarcodeModuleCpu::struct_info(self, interface_id)
+    }
 }
 
-impl PigletCodec for MVec<SUpTime> {
-    const TYPE_ID: u8 = 31;
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreBarcodeScanner0BarcodeModuleCpuFake {
+    pub recorder: piglet_client::testing::Recorder,
 }
 
-impl PigletSerialize for MSlice<'_, SUpTime> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
+#[cfg(feature = "testing")]
+impl NimbusCoreBarcodeScanner0BarcodeModuleCpuApi
+    for NimbusCoreBarcodeScanner0BarcodeModuleCpuFake
+{
+    fn download_info(&self) -> impl Future<Output = Result<DownloadInfoReply, Error>> + Send {
+        let reply = self.recorder.record("download_info", vec![]);
+        async move { reply }
+    }
 
-        let mut outer = BytesMut::new();
-        for s in self.0.as_ref() {
-            let mut buffer = BytesMut::new();
+    fn download_initiate(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("download_initiate", vec![]);
+        async move { reply }
+    }
 
-            s.days.serialize(&mut buffer);
-            s.hours.serialize(&mut buffer);
-            s.minutes.serialize(&mut buffer);
-            s.seconds.serialize(&mut buffer);
-            s.milliseconds.serialize(&mut buffer);
+    fn download_write(
+        &self,
+        download_data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "download_write",
+            vec![("download_data", format!("{:?}", download_data.as_ref()))],
+        );
+        async move { reply }
+    }
 
-            outer.put_u16_le(buffer.len() as u16);
-            outer.put(buffer);
-        }
+    fn download_complete(&self, success: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "download_complete",
+            vec![("success", format!("{:?}", success))],
+        );
+        async move { reply }
+    }
 
-        stream.put_u16_le(outer.len() as u16);
-        stream.put(outer);
+    fn version(&self) -> impl Future<Output = Result<VersionReply, Error>> + Send {
+        let reply = self.recorder.record("version", vec![]);
+        async move { reply }
     }
-}
 
-impl PigletSerialize for MVec<SUpTime> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        MSlice(&self.0).serialize(stream)
+    fn is_in_boot(&self) -> impl Future<Output = Result</* in_boot= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_in_boot", vec![]);
+        async move { reply }
     }
-}
 
-impl PigletDeserialize for MVec<SUpTime> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let mut length = stream.get_u16_le() as usize;
-        let mut outer = stream.copy_to_bytes(length);
-        let mut arr = Vec::new();
-        while length > 0 {
-            let mut length = outer.get_u16_le() as usize;
-            let mut bytes = outer.copy_to_bytes(length);
-            arr.push(SUpTime {
-                days: u8::deserialize(&mut bytes)?,
-                hours: u8::deserialize(&mut bytes)?,
-                minutes: u8::deserialize(&mut bytes)?,
-                seconds: u8::deserialize(&mut bytes)?,
-                milliseconds: u16::deserialize(&mut bytes)?,
-            });
-        }
-        Ok(MVec(arr))
+    fn read_uint_8(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u8, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("read_uint_8", vec![("address", format!("{:?}", address))]);
+        async move { reply }
+    }
+
+    fn read_uint_32(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u32, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("read_uint_32", vec![("address", format!("{:?}", address))]);
+        async move { reply }
+    }
+
+    fn write_uint_8(
+        &self,
+        address: u32,
+        value: u8,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "write_uint_8",
+            vec![
+                ("address", format!("{:?}", address)),
+                ("value", format!("{:?}", value)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn write_uint_32(
+        &self,
+        address: u32,
+        value: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "write_uint_32",
+            vec![
+                ("address", format!("{:?}", address)),
+                ("value", format!("{:?}", value)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn reg_table_entries(&self) -> impl Future<Output = Result</* entries= */ u32, Error>> + Send {
+        let reply = self.recorder.record("reg_table_entries", vec![]);
+        async move { reply }
+    }
+
+    fn reg_table_entry(
+        &self,
+        entry: u32,
+    ) -> impl Future<Output = Result<RegTableEntryReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("reg_table_entry", vec![("entry", format!("{:?}", entry))]);
+        async move { reply }
+    }
+
+    fn reset(&self, delay_ms: u32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("reset", vec![("delay_ms", format!("{:?}", delay_ms))]);
+        async move { reply }
+    }
+
+    fn boot_loader_version(
+        &self,
+    ) -> impl Future<Output = Result</* boot_loader_version= */ String, Error>> + Send {
+        let reply = self.recorder.record("boot_loader_version", vec![]);
+        async move { reply }
+    }
+
+    fn get_up_time(&self) -> impl Future<Output = Result</* value= */ SUpTime, Error>> + Send {
+        let reply = self.recorder.record("get_up_time", vec![]);
+        async move { reply }
     }
+
+    fn get_test_address(&self) -> impl Future<Output = Result</* value= */ u32, Error>> + Send {
+        let reply = self.recorder.record("get_test_address", vec![]);
+        async move { reply }
+    }
+
+    fn get_compression_algorithm(
+        &self,
+    ) -> impl Future<Output = Result</* algorithm= */ CompressionAlgorithm, Error>> + Send {
+        let reply = self.recorder.record("get_compression_algorithm", vec![]);
+        async move { reply }
+    }
+
+    fn download_write_compressed_data(
+        &self,
+        download_data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "download_write_compressed_data",
+            vec![("download_data", format!("{:?}", download_data.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn get_download_timeouts(
+        &self,
+    ) -> impl Future<Output = Result<GetDownloadTimeoutsReply, Error>> + Send {
+        let reply = self.recorder.record("get_download_timeouts", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompressionAlgorithm {
+    Nocompression = 0,
+    Hexcompression = 1,
+    Fastlz = 2,
+}
+
+#[derive(Clone, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SUpTime {
+    pub days: u8,
+    pub hours: u8,
+    pub minutes: u8,
+    pub seconds: u8,
+    pub milliseconds: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DownloadInfoReply {
     pub buffer_size: i32,
     pub file_name_template: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VersionReply {
     pub firmware_version: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegTableEntryReply {
     pub module_id: u16,
     pub node_id: u16,
@@ -794,15 +1113,15 @@ pub struct RegTableEntryReply {
     pub link_handle: u32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetDownloadTimeoutsReply {
     pub download_write_timeout: u32,
     pub download_complete_timeout: u32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -810,8 +1129,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -821,23 +1140,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -845,8 +1164,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
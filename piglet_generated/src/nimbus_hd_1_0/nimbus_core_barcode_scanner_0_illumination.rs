@@ -2,13 +2,12 @@ use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +15,13 @@ use std::sync::Arc;
 pub struct NimbusCoreBarcodeScanner0Illumination {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreBarcodeScanner0Illumination {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Illumination";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,18 +31,42 @@ impl NimbusCoreBarcodeScanner0Illumination {
                 object_id: 259,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot
+                .resolve("NimbusCore.BarcodeScanner0.Illumination")
+                .await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn get_enable(&self) -> Result</* enabled= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0Illumination.GetEnable()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let enabled = bool::deserialize(&mut stream)?;
         Ok(enabled)
@@ -50,7 +76,9 @@ impl NimbusCoreBarcodeScanner0Illumination {
         let mut args = BytesMut::new();
         enabled.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  enabled: {:?}", enabled)];
                 format!(
@@ -61,7 +89,7 @@ impl NimbusCoreBarcodeScanner0Illumination {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -70,7 +98,9 @@ impl NimbusCoreBarcodeScanner0Illumination {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -81,7 +111,7 @@ impl NimbusCoreBarcodeScanner0Illumination {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let intensity_percent = u16::deserialize(&mut stream)?;
         Ok(intensity_percent)
@@ -92,7 +122,9 @@ impl NimbusCoreBarcodeScanner0Illumination {
         channel.serialize(&mut args);
         intensity_percent.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -106,7 +138,7 @@ impl NimbusCoreBarcodeScanner0Illumination {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -114,12 +146,14 @@ impl NimbusCoreBarcodeScanner0Illumination {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBarcodeScanner0Illumination.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -137,7 +171,9 @@ impl NimbusCoreBarcodeScanner0Illumination {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -148,7 +184,7 @@ impl NimbusCoreBarcodeScanner0Illumination {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -170,7 +206,9 @@ impl NimbusCoreBarcodeScanner0Illumination {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -181,7 +219,7 @@ impl NimbusCoreBarcodeScanner0Illumination {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -196,7 +234,9 @@ impl NimbusCoreBarcodeScanner0Illumination {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 "in call to NimbusCoreBarcodeScanner0Illumination.InterfaceDescriptors()"
                     .to_string()
@@ -204,7 +244,7 @@ impl NimbusCoreBarcodeScanner0Illumination {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -218,7 +258,9 @@ impl NimbusCoreBarcodeScanner0Illumination {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -229,7 +271,7 @@ impl NimbusCoreBarcodeScanner0Illumination {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -247,7 +289,9 @@ impl NimbusCoreBarcodeScanner0Illumination {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -258,7 +302,7 @@ impl NimbusCoreBarcodeScanner0Illumination {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -273,8 +317,209 @@ impl NimbusCoreBarcodeScanner0Illumination {
     }
 }
 
-#[allow(non_camel_case_types)]
+// The methods above as a trait, so code using them can be tested against NimbusCoreBarcodeScanner0IlluminationFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreBarcodeScanner0IlluminationApi {
+    fn get_enable(&self) -> impl Future<Output = Result</* enabled= */ bool, Error>> + Send;
+    fn set_enable(&self, enabled: bool) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_intensity(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result</* intensity_percent= */ u16, Error>> + Send;
+    fn set_intensity(
+        &self,
+        channel: u16,
+        intensity_percent: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreBarcodeScanner0IlluminationApi for NimbusCoreBarcodeScanner0Illumination {
+    fn get_enable(&self) -> impl Future<Output = Result</* enabled= */ bool, Error>> + Send {
+        NimbusCoreBarcodeScanner0Illumination::get_enable(self)
+    }
+
+    fn set_enable(&self, enabled: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreBarcodeScanner0Illumination::set_enable(self, enabled)
+    }
+
+    fn get_intensity(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result</* intensity_percent= */ u16, Error>> + Send {
+        NimbusCoreBarcodeScanner0Illumination::get_intensity(self, channel)
+    }
+
+    fn set_intensity(
+        &self,
+        channel: u16,
+        intensity_percent: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreBarcodeScanner0Illumination::set_intensity(self, channel, intensity_percent)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0Illumination::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0Illumination::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0Illumination::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0Illumination::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0Illumination::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreBarcodeScanner0Illumination::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreBarcodeScanner0IlluminationFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreBarcodeScanner0IlluminationApi for NimbusCoreBarcodeScanner0IlluminationFake {
+    fn get_enable(&self) -> impl Future<Output = Result</* enabled= */ bool, Error>> + Send {
+        let reply = self.recorder.record("get_enable", vec![]);
+        async move { reply }
+    }
+
+    fn set_enable(&self, enabled: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("set_enable", vec![("enabled", format!("{:?}", enabled))]);
+        async move { reply }
+    }
+
+    fn get_intensity(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result</* intensity_percent= */ u16, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("get_intensity", vec![("channel", format!("{:?}", channel))]);
+        async move { reply }
+    }
+
+    fn set_intensity(
+        &self,
+        channel: u16,
+        intensity_percent: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_intensity",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("intensity_percent", format!("{:?}", intensity_percent)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -282,8 +527,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -293,23 +538,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -317,8 +562,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
@@ -2,13 +2,12 @@ use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +15,13 @@ use std::sync::Arc;
 pub struct NimbusCoreBoanduzCan {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreBoanduzCan {
-    // version 0.3
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "BoanduzCan";
+    pub const VERSION: &'static str = "0.3";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,6 +31,26 @@ impl NimbusCoreBoanduzCan {
                 object_id: 263,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.BoanduzCan").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
@@ -44,7 +66,9 @@ impl NimbusCoreBoanduzCan {
         command_timeout.serialize(&mut args);
         request.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 1, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  address: {:?}", address),
@@ -59,7 +83,7 @@ impl NimbusCoreBoanduzCan {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let response = String::deserialize(&mut stream)?;
         Ok(response)
@@ -70,7 +94,9 @@ impl NimbusCoreBoanduzCan {
         address.serialize(&mut args);
         request.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  address: {:?}", address),
@@ -84,7 +110,7 @@ impl NimbusCoreBoanduzCan {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -92,12 +118,14 @@ impl NimbusCoreBoanduzCan {
     pub async fn receive_no_wait(&self) -> Result<ReceiveNoWaitReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBoanduzCan.ReceiveNoWait()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let address = i32::deserialize(&mut stream)?;
         let response = String::deserialize(&mut stream)?;
@@ -108,7 +136,9 @@ impl NimbusCoreBoanduzCan {
         let mut args = BytesMut::new();
         command_timeout.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  command_timeout: {:?}", command_timeout)];
                 format!(
@@ -119,7 +149,7 @@ impl NimbusCoreBoanduzCan {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let address = i32::deserialize(&mut stream)?;
         let response = String::deserialize(&mut stream)?;
@@ -129,12 +159,14 @@ impl NimbusCoreBoanduzCan {
     pub async fn last_command(&self) -> Result</* request= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 5, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBoanduzCan.LastCommand()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let request = String::deserialize(&mut stream)?;
         Ok(request)
@@ -143,12 +175,14 @@ impl NimbusCoreBoanduzCan {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBoanduzCan.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -166,7 +200,9 @@ impl NimbusCoreBoanduzCan {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -177,7 +213,7 @@ impl NimbusCoreBoanduzCan {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -199,7 +235,9 @@ impl NimbusCoreBoanduzCan {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -210,7 +248,7 @@ impl NimbusCoreBoanduzCan {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -225,12 +263,14 @@ impl NimbusCoreBoanduzCan {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreBoanduzCan.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -244,7 +284,9 @@ impl NimbusCoreBoanduzCan {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -255,7 +297,7 @@ impl NimbusCoreBoanduzCan {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -273,7 +315,9 @@ impl NimbusCoreBoanduzCan {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -284,7 +328,7 @@ impl NimbusCoreBoanduzCan {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -299,22 +343,242 @@ impl NimbusCoreBoanduzCan {
     }
 }
 
-#[allow(non_camel_case_types)]
+// The methods above as a trait, so code using them can be tested against NimbusCoreBoanduzCanFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreBoanduzCanApi {
+    fn command(
+        &self,
+        address: i32,
+        command_timeout: i32,
+        request: &str,
+    ) -> impl Future<Output = Result</* response= */ String, Error>> + Send;
+    fn send(&self, address: i32, request: &str) -> impl Future<Output = Result<(), Error>> + Send;
+    fn receive_no_wait(&self) -> impl Future<Output = Result<ReceiveNoWaitReply, Error>> + Send;
+    fn receive(
+        &self,
+        command_timeout: i32,
+    ) -> impl Future<Output = Result<ReceiveReply, Error>> + Send;
+    fn last_command(&self) -> impl Future<Output = Result</* request= */ String, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreBoanduzCanApi for NimbusCoreBoanduzCan {
+    fn command(
+        &self,
+        address: i32,
+        command_timeout: i32,
+        request: &str,
+    ) -> impl Future<Output = Result</* response= */ String, Error>> + Send {
+        NimbusCoreBoanduzCan::command(self, address, command_timeout, request)
+    }
+
+    fn send(&self, address: i32, request: &str) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreBoanduzCan::send(self, address, request)
+    }
+
+    fn receive_no_wait(&self) -> impl Future<Output = Result<ReceiveNoWaitReply, Error>> + Send {
+        NimbusCoreBoanduzCan::receive_no_wait(self)
+    }
+
+    fn receive(
+        &self,
+        command_timeout: i32,
+    ) -> impl Future<Output = Result<ReceiveReply, Error>> + Send {
+        NimbusCoreBoanduzCan::receive(self, command_timeout)
+    }
+
+    fn last_command(&self) -> impl Future<Output = Result</* request= */ String, Error>> + Send {
+        NimbusCoreBoanduzCan::last_command(self)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreBoanduzCan::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreBoanduzCan::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreBoanduzCan::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreBoanduzCan::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreBoanduzCan::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreBoanduzCan::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreBoanduzCanFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreBoanduzCanApi for NimbusCoreBoanduzCanFake {
+    fn command(
+        &self,
+        address: i32,
+        command_timeout: i32,
+        request: &str,
+    ) -> impl Future<Output = Result</* response= */ String, Error>> + Send {
+        let reply = self.recorder.record(
+            "command",
+            vec![
+                ("address", format!("{:?}", address)),
+                ("command_timeout", format!("{:?}", command_timeout)),
+                ("request", format!("{:?}", request)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn send(&self, address: i32, request: &str) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "send",
+            vec![
+                ("address", format!("{:?}", address)),
+                ("request", format!("{:?}", request)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn receive_no_wait(&self) -> impl Future<Output = Result<ReceiveNoWaitReply, Error>> + Send {
+        let reply = self.recorder.record("receive_no_wait", vec![]);
+        async move { reply }
+    }
+
+    fn receive(
+        &self,
+        command_timeout: i32,
+    ) -> impl Future<Output = Result<ReceiveReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "receive",
+            vec![("command_timeout", format!("{:?}", command_timeout))],
+        );
+        async move { reply }
+    }
+
+    fn last_command(&self) -> impl Future<Output = Result</* request= */ String, Error>> + Send {
+        let reply = self.recorder.record("last_command", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReceiveNoWaitReply {
     pub address: i32,
     pub response: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReceiveReply {
     pub address: i32,
     pub response: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -322,8 +586,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -333,23 +597,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -357,8 +621,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
@@ -1,14 +1,14 @@
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
+use piglet_client::units::{Microliters, Millimeters};
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +16,13 @@ use std::sync::Arc;
 pub struct NimbusCoreCalibration {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreCalibration {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Calibration";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,18 +32,40 @@ impl NimbusCoreCalibration {
                 object_id: 258,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Calibration").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn calibration_start(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.CalibrationStart()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -48,12 +73,14 @@ impl NimbusCoreCalibration {
     pub async fn calibration_save(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.CalibrationSave()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -61,12 +88,14 @@ impl NimbusCoreCalibration {
     pub async fn calibration_cancel(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.CalibrationCancel()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -75,21 +104,23 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        z_distance: i32,
-        z_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        z_distance.serialize(&mut args);
-        z_cal_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        z_distance.to_wire::<i32>()?.serialize(&mut args);
+        z_cal_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -107,7 +138,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -116,21 +147,23 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        x_distance: i32,
-        x_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        x_cal_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        x_distance.serialize(&mut args);
-        x_cal_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
+        x_cal_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -148,7 +181,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -157,21 +190,23 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        y_distance: i32,
-        y_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        y_distance: Millimeters,
+        y_cal_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        y_distance.serialize(&mut args);
-        y_cal_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        y_distance.to_wire::<i32>()?.serialize(&mut args);
+        y_cal_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -189,7 +224,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -198,19 +233,21 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        x_distance: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
     ) -> Result<CalibrateXResolutionStartReply, Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        x_distance.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -227,7 +264,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let x_measured = i32::deserialize(&mut stream)?;
         Ok(CalibrateXResolutionStartReply { x_measured })
@@ -237,19 +274,21 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        x_distance: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
     ) -> Result<CalibrateXResolutionFinishReply, Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        x_distance.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 8, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -266,7 +305,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let x_measured = i32::deserialize(&mut stream)?;
         let x_resolution = i32::deserialize(&mut stream)?;
@@ -280,25 +319,27 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        x_distance: i32,
-        y_distance: i32,
-        x_cal_position: i32,
-        y_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        y_distance: Millimeters,
+        x_cal_position: Millimeters,
+        y_cal_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        x_distance.serialize(&mut args);
-        y_distance.serialize(&mut args);
-        x_cal_position.serialize(&mut args);
-        y_cal_position.serialize(&mut args);
-        let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 9, args.freeze()).await,
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
+        y_distance.to_wire::<i32>()?.serialize(&mut args);
+        x_cal_position.to_wire::<i32>()?.serialize(&mut args);
+        y_cal_position.to_wire::<i32>()?.serialize(&mut args);
+        let (count, mut stream) = with_context(
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 9, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -318,7 +359,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -327,21 +368,23 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        z_distance: i32,
-        z_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        z_distance.serialize(&mut args);
-        z_cal_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        z_distance.to_wire::<i32>()?.serialize(&mut args);
+        z_cal_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 10, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -359,7 +402,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -368,21 +411,23 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        x_distance: i32,
-        x_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        x_cal_position: Millimeters,
     ) -> Result<CalibrationCheckXReply, Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        x_distance.serialize(&mut args);
-        x_cal_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
+        x_cal_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 11, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -400,7 +445,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let x_offset = i32::deserialize(&mut stream)?;
         Ok(CalibrationCheckXReply { x_offset })
@@ -410,21 +455,23 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        y_distance: i32,
-        y_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        y_distance: Millimeters,
+        y_cal_position: Millimeters,
     ) -> Result<CalibrationCheckYReply, Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        y_distance.serialize(&mut args);
-        y_cal_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        y_distance.to_wire::<i32>()?.serialize(&mut args);
+        y_cal_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 12, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -442,7 +489,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let y_offset = i32::deserialize(&mut stream)?;
         Ok(CalibrationCheckYReply { y_offset })
@@ -452,21 +499,23 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        z_distance: i32,
-        z_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
     ) -> Result<CalibrationCheckZReply, Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        z_distance.serialize(&mut args);
-        z_cal_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        z_distance.to_wire::<i32>()?.serialize(&mut args);
+        z_cal_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 13, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 13, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -484,7 +533,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let z_offset = i32::deserialize(&mut stream)?;
         Ok(CalibrationCheckZReply { z_offset })
@@ -494,25 +543,27 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_start_position: i32,
-        z_stop_position: i32,
-        z_final: i32,
-        tip_volume: u32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
         tip_collet_check: i16,
     ) -> Result</* steps= */ i32, Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
-        z_final.serialize(&mut args);
-        tip_volume.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        tip_volume.to_wire::<u32>()?.serialize(&mut args);
         tip_collet_check.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 14, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 14, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -532,7 +583,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let steps = i32::deserialize(&mut stream)?;
         Ok(steps)
@@ -542,19 +593,21 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_start_position: i32,
-        z_stop_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 15, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 15, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -571,7 +624,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -580,25 +633,27 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_start_position: i32,
-        z_stop_position: i32,
-        z_final: i32,
-        tip_volume: u32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
         tip_collet_check: i16,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
-        z_final.serialize(&mut args);
-        tip_volume.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        tip_volume.to_wire::<u32>()?.serialize(&mut args);
         tip_collet_check.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 16, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 16, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -618,7 +673,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -627,15 +682,17 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 17, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 17, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -650,7 +707,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -658,12 +715,14 @@ impl NimbusCoreCalibration {
     pub async fn grip_calibration_start(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 18, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 18, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.GripCalibrationStart()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -671,12 +730,14 @@ impl NimbusCoreCalibration {
     pub async fn grip_calibration_cancel(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 19, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 19, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.GripCalibrationCancel()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -684,12 +745,14 @@ impl NimbusCoreCalibration {
     pub async fn grip_calibration_save(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 20, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 20, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.GripCalibrationSave()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -698,23 +761,25 @@ impl NimbusCoreCalibration {
         &self,
 
         lld_channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        tool_height: i32,
-        z_distance: i32,
-        z_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
     ) -> Result<GripPreCalibrateZReply, Error> {
         let mut args = BytesMut::new();
         lld_channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        tool_height.serialize(&mut args);
-        z_distance.serialize(&mut args);
-        z_cal_position.serialize(&mut args);
-        let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 21, args.freeze()).await,
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        tool_height.to_wire::<i32>()?.serialize(&mut args);
+        z_distance.to_wire::<i32>()?.serialize(&mut args);
+        z_cal_position.to_wire::<i32>()?.serialize(&mut args);
+        let (count, mut stream) = with_context(
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 21, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  lld_channel: {:?}", lld_channel),
@@ -733,7 +798,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let z_offset = i32::deserialize(&mut stream)?;
         Ok(GripPreCalibrateZReply { z_offset })
@@ -743,27 +808,29 @@ impl NimbusCoreCalibration {
         &self,
 
         lld_channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        tool_height: i32,
-        x_distance: i32,
-        y_distance: i32,
-        x_cal_position: i32,
-        y_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        x_distance: Millimeters,
+        y_distance: Millimeters,
+        x_cal_position: Millimeters,
+        y_cal_position: Millimeters,
     ) -> Result<GripCalibrateXYReply, Error> {
         let mut args = BytesMut::new();
         lld_channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        tool_height.serialize(&mut args);
-        x_distance.serialize(&mut args);
-        y_distance.serialize(&mut args);
-        x_cal_position.serialize(&mut args);
-        y_cal_position.serialize(&mut args);
-        let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 22, args.freeze()).await,
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        tool_height.to_wire::<i32>()?.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
+        y_distance.to_wire::<i32>()?.serialize(&mut args);
+        x_cal_position.to_wire::<i32>()?.serialize(&mut args);
+        y_cal_position.to_wire::<i32>()?.serialize(&mut args);
+        let (count, mut stream) = with_context(
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 22, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  lld_channel: {:?}", lld_channel),
@@ -784,31 +851,89 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let x_offset = i32::deserialize(&mut stream)?;
         let y_offset = i32::deserialize(&mut stream)?;
         Ok(GripCalibrateXYReply { x_offset, y_offset })
     }
 
+    pub async fn grip_calibrate_xy_with(
+        &self,
+        request: &GripCalibrateXYRequest,
+    ) -> Result<GripCalibrateXYReply, Error> {
+        self.grip_calibrate_xy(
+            piglet_client::client::required(
+                request.lld_channel,
+                "GripCalibrateXYRequest",
+                "lld_channel",
+            )?,
+            piglet_client::client::required(
+                request.x_position,
+                "GripCalibrateXYRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_position,
+                "GripCalibrateXYRequest",
+                "y_position",
+            )?,
+            piglet_client::client::required(
+                request.z_position,
+                "GripCalibrateXYRequest",
+                "z_position",
+            )?,
+            piglet_client::client::required(
+                request.tool_height,
+                "GripCalibrateXYRequest",
+                "tool_height",
+            )?,
+            piglet_client::client::required(
+                request.x_distance,
+                "GripCalibrateXYRequest",
+                "x_distance",
+            )?,
+            piglet_client::client::required(
+                request.y_distance,
+                "GripCalibrateXYRequest",
+                "y_distance",
+            )?,
+            piglet_client::client::required(
+                request.x_cal_position,
+                "GripCalibrateXYRequest",
+                "x_cal_position",
+            )?,
+            piglet_client::client::required(
+                request.y_cal_position,
+                "GripCalibrateXYRequest",
+                "y_cal_position",
+            )?,
+        )
+        .await
+    }
+
     pub async fn grip_calibration_tool_setup(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 23, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 23, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.GripCalibrationToolSetup()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn grip_calibration_tool_pickup(&self, tool_width: i32) -> Result<(), Error> {
+    pub async fn grip_calibration_tool_pickup(&self, tool_width: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        tool_width.serialize(&mut args);
+        tool_width.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 24, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 24, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tool_width: {:?}", tool_width)];
                 format!(
@@ -819,7 +944,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -827,20 +952,22 @@ impl NimbusCoreCalibration {
     pub async fn grip_calibration_auto_tool_pickup(
         &self,
 
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
         grip_open: i32,
-        tool_width: i32,
+        tool_width: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
         grip_open.serialize(&mut args);
-        tool_width.serialize(&mut args);
+        tool_width.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 25, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 25, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -857,7 +984,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -865,12 +992,14 @@ impl NimbusCoreCalibration {
     pub async fn grip_calibration_tool_drop(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 26, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 26, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.GripCalibrationToolDrop()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -879,21 +1008,23 @@ impl NimbusCoreCalibration {
         &self,
 
         lld_channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        tool_height: i32,
-        y_distance: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        y_distance: Millimeters,
     ) -> Result<GripCalibrateWristReply, Error> {
         let mut args = BytesMut::new();
         lld_channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        tool_height.serialize(&mut args);
-        y_distance.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        tool_height.to_wire::<i32>()?.serialize(&mut args);
+        y_distance.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 27, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 27, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  lld_channel: {:?}", lld_channel),
@@ -911,7 +1042,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let wrist_offset = i32::deserialize(&mut stream)?;
         Ok(GripCalibrateWristReply { wrist_offset })
@@ -921,23 +1052,25 @@ impl NimbusCoreCalibration {
         &self,
 
         lld_channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        tool_height: i32,
-        z_distance: i32,
-        z_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
     ) -> Result<GripCalibrateZReply, Error> {
         let mut args = BytesMut::new();
         lld_channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        tool_height.serialize(&mut args);
-        z_distance.serialize(&mut args);
-        z_cal_position.serialize(&mut args);
-        let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 28, args.freeze()).await,
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        tool_height.to_wire::<i32>()?.serialize(&mut args);
+        z_distance.to_wire::<i32>()?.serialize(&mut args);
+        z_cal_position.to_wire::<i32>()?.serialize(&mut args);
+        let (count, mut stream) = with_context(
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 28, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  lld_channel: {:?}", lld_channel),
@@ -956,17 +1089,19 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let z_offset = i32::deserialize(&mut stream)?;
         Ok(GripCalibrateZReply { z_offset })
     }
 
-    pub async fn grip_calibration_grip(&self, tool_width: i32) -> Result<(), Error> {
+    pub async fn grip_calibration_grip(&self, tool_width: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        tool_width.serialize(&mut args);
+        tool_width.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 29, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 29, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tool_width: {:?}", tool_width)];
                 format!(
@@ -977,7 +1112,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -986,23 +1121,25 @@ impl NimbusCoreCalibration {
         &self,
 
         lld_channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        tool_height: i32,
-        x_distance: i32,
-        x_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        x_distance: Millimeters,
+        x_cal_position: Millimeters,
     ) -> Result<GripCalibrationCheckXReply, Error> {
         let mut args = BytesMut::new();
         lld_channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        tool_height.serialize(&mut args);
-        x_distance.serialize(&mut args);
-        x_cal_position.serialize(&mut args);
-        let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 30, args.freeze()).await,
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        tool_height.to_wire::<i32>()?.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
+        x_cal_position.to_wire::<i32>()?.serialize(&mut args);
+        let (count, mut stream) = with_context(
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 30, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  lld_channel: {:?}", lld_channel),
@@ -1021,7 +1158,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let x_offset = i32::deserialize(&mut stream)?;
         Ok(GripCalibrationCheckXReply { x_offset })
@@ -1031,23 +1168,25 @@ impl NimbusCoreCalibration {
         &self,
 
         lld_channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        tool_height: i32,
-        y_distance: i32,
-        y_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        y_distance: Millimeters,
+        y_cal_position: Millimeters,
     ) -> Result<GripCalibrationCheckYReply, Error> {
         let mut args = BytesMut::new();
         lld_channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        tool_height.serialize(&mut args);
-        y_distance.serialize(&mut args);
-        y_cal_position.serialize(&mut args);
-        let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 31, args.freeze()).await,
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        tool_height.to_wire::<i32>()?.serialize(&mut args);
+        y_distance.to_wire::<i32>()?.serialize(&mut args);
+        y_cal_position.to_wire::<i32>()?.serialize(&mut args);
+        let (count, mut stream) = with_context(
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 31, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  lld_channel: {:?}", lld_channel),
@@ -1066,7 +1205,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let y_offset = i32::deserialize(&mut stream)?;
         Ok(GripCalibrationCheckYReply { y_offset })
@@ -1076,23 +1215,25 @@ impl NimbusCoreCalibration {
         &self,
 
         lld_channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        tool_height: i32,
-        z_distance: i32,
-        z_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
     ) -> Result<GripCalibrationCheckZReply, Error> {
         let mut args = BytesMut::new();
         lld_channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        tool_height.serialize(&mut args);
-        z_distance.serialize(&mut args);
-        z_cal_position.serialize(&mut args);
-        let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 32, args.freeze()).await,
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        tool_height.to_wire::<i32>()?.serialize(&mut args);
+        z_distance.to_wire::<i32>()?.serialize(&mut args);
+        z_cal_position.to_wire::<i32>()?.serialize(&mut args);
+        let (count, mut stream) = with_context(
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 32, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  lld_channel: {:?}", lld_channel),
@@ -1111,7 +1252,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let z_offset = i32::deserialize(&mut stream)?;
         Ok(GripCalibrationCheckZReply { z_offset })
@@ -1120,12 +1261,14 @@ impl NimbusCoreCalibration {
     pub async fn get_pressure(&self) -> Result</* pressures= */ Vec<i16>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 33, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 33, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.GetPressure()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let pressures = Vec::<i16>::deserialize(&mut stream)?;
         Ok(pressures)
@@ -1134,12 +1277,14 @@ impl NimbusCoreCalibration {
     pub async fn get_potentiometer_settings(&self) -> Result<GetPotentiometerSettingsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 34, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 34, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.GetPotentiometerSettings()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let gain = Vec::<i16>::deserialize(&mut stream)?;
         let offset = Vec::<i16>::deserialize(&mut stream)?;
@@ -1156,7 +1301,9 @@ impl NimbusCoreCalibration {
         channel.serialize(&mut args);
         mode.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 35, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 35, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -1170,7 +1317,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1180,7 +1327,9 @@ impl NimbusCoreCalibration {
         channel.serialize(&mut args);
         gain.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 36, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 36, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -1194,7 +1343,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1204,7 +1353,9 @@ impl NimbusCoreCalibration {
         channel.serialize(&mut args);
         offset.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 37, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 37, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -1218,7 +1369,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1228,7 +1379,9 @@ impl NimbusCoreCalibration {
         channel.serialize(&mut args);
         shift.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 38, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 38, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -1242,7 +1395,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1251,7 +1404,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 39, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 39, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -1262,7 +1417,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1271,7 +1426,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 40, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 40, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -1282,7 +1439,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1291,7 +1448,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 41, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 41, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -1302,7 +1461,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1310,12 +1469,14 @@ impl NimbusCoreCalibration {
     pub async fn calibration_initialize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 42, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 42, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.CalibrationInitialize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1324,29 +1485,56 @@ impl NimbusCoreCalibration {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        traverse_height: i32,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
-        z_final: impl AsRef<[i32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
+        z_final: impl AsRef<[Millimeters]>,
         tip_type: impl AsRef<[u16]>,
         time: impl AsRef<[u32]>,
         test_type: impl AsRef<[bool]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
-        z_final.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         tip_type.as_ref().serialize(&mut args);
         time.as_ref().serialize(&mut args);
         test_type.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 43, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 43, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1368,16 +1556,70 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn leak_check_with(&self, request: &LeakCheckRequest) -> Result<(), Error> {
+        self.leak_check(
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "LeakCheckRequest",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.x_position.as_ref(),
+                "LeakCheckRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_position.as_ref(),
+                "LeakCheckRequest",
+                "y_position",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "LeakCheckRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.z_start_position.as_ref(),
+                "LeakCheckRequest",
+                "z_start_position",
+            )?,
+            piglet_client::client::required(
+                request.z_stop_position.as_ref(),
+                "LeakCheckRequest",
+                "z_stop_position",
+            )?,
+            piglet_client::client::required(
+                request.z_final.as_ref(),
+                "LeakCheckRequest",
+                "z_final",
+            )?,
+            piglet_client::client::required(
+                request.tip_type.as_ref(),
+                "LeakCheckRequest",
+                "tip_type",
+            )?,
+            piglet_client::client::required(request.time.as_ref(), "LeakCheckRequest", "time")?,
+            piglet_client::client::required(
+                request.test_type.as_ref(),
+                "LeakCheckRequest",
+                "test_type",
+            )?,
+        )
+        .await
+    }
+
     pub async fn calibrate_lld(&self, channel: u16) -> Result<(), Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 44, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 44, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -1388,7 +1630,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1396,12 +1638,14 @@ impl NimbusCoreCalibration {
     pub async fn calibration_store(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 45, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 45, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.CalibrationStore()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1414,7 +1658,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 46, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 46, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1425,7 +1671,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1438,7 +1684,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 47, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 47, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1449,7 +1697,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1457,12 +1705,14 @@ impl NimbusCoreCalibration {
     pub async fn calibration_reset(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 48, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 48, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.CalibrationReset()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1470,12 +1720,14 @@ impl NimbusCoreCalibration {
     pub async fn calibration_values(&self) -> Result<CalibrationValuesReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 49, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 49, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.CalibrationValues()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let x_offset = i32::deserialize(&mut stream)?;
         let x_resolution = i32::deserialize(&mut stream)?;
@@ -1493,7 +1745,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         x_offset.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 50, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 50, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  x_offset: {:?}", x_offset)];
                 format!(
@@ -1504,7 +1758,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1513,7 +1767,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         x_resolution.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 51, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 51, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  x_resolution: {:?}", x_resolution)];
                 format!(
@@ -1524,7 +1780,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1537,7 +1793,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         y_home_offset.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 52, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 52, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  y_home_offset: {:?}", y_home_offset.as_ref())];
                 format!(
@@ -1548,7 +1806,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1561,7 +1819,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         z_home_offset.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 53, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 53, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  z_home_offset: {:?}", z_home_offset.as_ref())];
                 format!(
@@ -1572,7 +1832,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1581,19 +1841,21 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        x_distance: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
     ) -> Result<CalibrationSeekXReply, Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        x_distance.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 54, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 54, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -1610,7 +1872,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let x_offset = i32::deserialize(&mut stream)?;
         Ok(CalibrationSeekXReply { x_offset })
@@ -1620,19 +1882,21 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        y_distance: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        y_distance: Millimeters,
     ) -> Result<CalibrationSeekYReply, Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        y_distance.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        y_distance.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 55, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 55, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -1649,7 +1913,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let y_offset = i32::deserialize(&mut stream)?;
         Ok(CalibrationSeekYReply { y_offset })
@@ -1659,7 +1923,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         enable.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 56, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 56, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  enable: {:?}", enable)];
                 format!(
@@ -1670,7 +1936,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1679,7 +1945,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 57, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 57, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1690,7 +1958,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1700,12 +1968,14 @@ impl NimbusCoreCalibration {
     ) -> Result<GripCalibrateGripTravelExtentReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 58, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 58, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.GripCalibrateGripTravelExtent()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let lower_limit = i32::deserialize(&mut stream)?;
         let upper_limit = i32::deserialize(&mut stream)?;
@@ -1719,25 +1989,27 @@ impl NimbusCoreCalibration {
         &self,
 
         channel: u16,
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
-        x_distance: i32,
-        y_distance: i32,
-        x_cal_position: i32,
-        y_cal_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        y_distance: Millimeters,
+        x_cal_position: Millimeters,
+        y_cal_position: Millimeters,
     ) -> Result<CalibrationCheckXYReply, Error> {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
-        x_distance.serialize(&mut args);
-        y_distance.serialize(&mut args);
-        x_cal_position.serialize(&mut args);
-        y_cal_position.serialize(&mut args);
-        let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 59, args.freeze()).await,
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
+        y_distance.to_wire::<i32>()?.serialize(&mut args);
+        x_cal_position.to_wire::<i32>()?.serialize(&mut args);
+        y_cal_position.to_wire::<i32>()?.serialize(&mut args);
+        let (count, mut stream) = with_context(
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 59, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -1757,7 +2029,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let x_offset = i32::deserialize(&mut stream)?;
         let y_offset = i32::deserialize(&mut stream)?;
@@ -1767,12 +2039,14 @@ impl NimbusCoreCalibration {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -1790,7 +2064,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -1801,7 +2077,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -1823,7 +2099,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -1834,7 +2112,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -1849,12 +2127,14 @@ impl NimbusCoreCalibration {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCalibration.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -1868,7 +2148,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -1879,7 +2161,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -1897,7 +2179,9 @@ impl NimbusCoreCalibration {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -1908,7 +2192,7 @@ impl NimbusCoreCalibration {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -1923,89 +2207,2406 @@ impl NimbusCoreCalibration {
     }
 }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct CalibrateXResolutionStartReply {
-    pub x_measured: i32,
+// The methods above as a trait, so code using them can be tested against NimbusCoreCalibrationFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreCalibrationApi {
+    fn calibration_start(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibration_save(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibration_cancel(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pre_calibrate_z(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pre_calibrate_x(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        x_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pre_calibrate_y(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        y_distance: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_x_resolution_start(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<CalibrateXResolutionStartReply, Error>> + Send;
+    fn calibrate_x_resolution_finish(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<CalibrateXResolutionFinishReply, Error>> + Send;
+    fn calibrate_channel_xy(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        y_distance: Millimeters,
+        x_cal_position: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_channel_z(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibration_check_x(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        x_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationCheckXReply, Error>> + Send;
+    fn calibration_check_y(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        y_distance: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationCheckYReply, Error>> + Send;
+    fn calibration_check_z(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationCheckZReply, Error>> + Send;
+    fn calibration_check_dispenser(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
+        tip_collet_check: i16,
+    ) -> impl Future<Output = Result</* steps= */ i32, Error>> + Send;
+    fn calibrate_squeeze(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_tip_height(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
+        tip_collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_touchoff(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn grip_calibration_start(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn grip_calibration_cancel(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn grip_calibration_save(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn grip_pre_calibrate_z(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripPreCalibrateZReply, Error>> + Send;
+    fn grip_calibrate_xy(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        x_distance: Millimeters,
+        y_distance: Millimeters,
+        x_cal_position: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrateXYReply, Error>> + Send;
+
+    fn grip_calibrate_xy_with(
+        &self,
+        request: &GripCalibrateXYRequest,
+    ) -> impl Future<Output = Result<GripCalibrateXYReply, Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.grip_calibrate_xy(
+                piglet_client::client::required(
+                    request.lld_channel,
+                    "GripCalibrateXYRequest",
+                    "lld_channel",
+                )?,
+                piglet_client::client::required(
+                    request.x_position,
+                    "GripCalibrateXYRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_position,
+                    "GripCalibrateXYRequest",
+                    "y_position",
+                )?,
+                piglet_client::client::required(
+                    request.z_position,
+                    "GripCalibrateXYRequest",
+                    "z_position",
+                )?,
+                piglet_client::client::required(
+                    request.tool_height,
+                    "GripCalibrateXYRequest",
+                    "tool_height",
+                )?,
+                piglet_client::client::required(
+                    request.x_distance,
+                    "GripCalibrateXYRequest",
+                    "x_distance",
+                )?,
+                piglet_client::client::required(
+                    request.y_distance,
+                    "GripCalibrateXYRequest",
+                    "y_distance",
+                )?,
+                piglet_client::client::required(
+                    request.x_cal_position,
+                    "GripCalibrateXYRequest",
+                    "x_cal_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_cal_position,
+                    "GripCalibrateXYRequest",
+                    "y_cal_position",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn grip_calibration_tool_setup(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn grip_calibration_tool_pickup(
+        &self,
+        tool_width: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn grip_calibration_auto_tool_pickup(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_open: i32,
+        tool_width: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn grip_calibration_tool_drop(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn grip_calibrate_wrist(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        y_distance: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrateWristReply, Error>> + Send;
+    fn grip_calibrate_z(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrateZReply, Error>> + Send;
+    fn grip_calibration_grip(
+        &self,
+        tool_width: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn grip_calibration_check_x(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        x_distance: Millimeters,
+        x_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrationCheckXReply, Error>> + Send;
+    fn grip_calibration_check_y(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        y_distance: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrationCheckYReply, Error>> + Send;
+    fn grip_calibration_check_z(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrationCheckZReply, Error>> + Send;
+    fn get_pressure(&self)
+    -> impl Future<Output = Result</* pressures= */ Vec<i16>, Error>> + Send;
+    fn get_potentiometer_settings(
+        &self,
+    ) -> impl Future<Output = Result<GetPotentiometerSettingsReply, Error>> + Send;
+    fn set_pressure_measurement_mode(
+        &self,
+        channel: u16,
+        mode: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_pressure_sensor_gain(
+        &self,
+        channel: u16,
+        gain: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_pressure_sensor_offset(
+        &self,
+        channel: u16,
+        offset: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_pressure_sensor_shift(
+        &self,
+        channel: u16,
+        shift: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn store_pressure_sensor_gain(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn store_pressure_sensor_offset(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_tadm_offset(&self, channel: u16)
+    -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibration_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn leak_check(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        tip_type: impl AsRef<[u16]> + Send,
+        time: impl AsRef<[u32]> + Send,
+        test_type: impl AsRef<[bool]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn leak_check_with(
+        &self,
+        request: &LeakCheckRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.leak_check(
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "LeakCheckRequest",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.x_position.as_ref(),
+                    "LeakCheckRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_position.as_ref(),
+                    "LeakCheckRequest",
+                    "y_position",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "LeakCheckRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_start_position.as_ref(),
+                    "LeakCheckRequest",
+                    "z_start_position",
+                )?,
+                piglet_client::client::required(
+                    request.z_stop_position.as_ref(),
+                    "LeakCheckRequest",
+                    "z_stop_position",
+                )?,
+                piglet_client::client::required(
+                    request.z_final.as_ref(),
+                    "LeakCheckRequest",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.tip_type.as_ref(),
+                    "LeakCheckRequest",
+                    "tip_type",
+                )?,
+                piglet_client::client::required(request.time.as_ref(), "LeakCheckRequest", "time")?,
+                piglet_client::client::required(
+                    request.test_type.as_ref(),
+                    "LeakCheckRequest",
+                    "test_type",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn calibrate_lld(&self, channel: u16) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibration_store(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibration_squeeze_check_torque(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_squeeze_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibration_reset(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<CalibrationValuesReply, Error>> + Send;
+    fn calibration_set_x_home_offset(
+        &self,
+        x_offset: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibration_set_x_resolution(
+        &self,
+        x_resolution: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibration_set_y_home_offsets(
+        &self,
+        y_home_offset: impl AsRef<[i32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibration_set_z_home_offsets(
+        &self,
+        z_home_offset: impl AsRef<[i32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibration_seek_x(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationSeekXReply, Error>> + Send;
+    fn calibration_seek_y(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        y_distance: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationSeekYReply, Error>> + Send;
+    fn calibration_barcode_laser(
+        &self,
+        enable: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn grip_calibrate_grip_travel_extent(
+        &self,
+    ) -> impl Future<Output = Result<GripCalibrateGripTravelExtentReply, Error>> + Send;
+    fn calibration_check_xy(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        y_distance: Millimeters,
+        x_cal_position: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationCheckXYReply, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
 }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct CalibrateXResolutionFinishReply {
-    pub x_measured: i32,
-    pub x_resolution: i32,
-}
+impl NimbusCoreCalibrationApi for NimbusCoreCalibration {
+    fn calibration_start(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibration_start(self)
+    }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct CalibrationCheckXReply {
-    pub x_offset: i32,
-}
+    fn calibration_save(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibration_save(self)
+    }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct CalibrationCheckYReply {
-    pub y_offset: i32,
-}
+    fn calibration_cancel(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibration_cancel(self)
+    }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct CalibrationCheckZReply {
-    pub z_offset: i32,
-}
+    fn pre_calibrate_z(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::pre_calibrate_z(
+            self,
+            channel,
+            x_position,
+            y_position,
+            z_position,
+            z_distance,
+            z_cal_position,
+        )
+    }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GripPreCalibrateZReply {
-    pub z_offset: i32,
-}
+    fn pre_calibrate_x(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        x_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::pre_calibrate_x(
+            self,
+            channel,
+            x_position,
+            y_position,
+            z_position,
+            x_distance,
+            x_cal_position,
+        )
+    }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GripCalibrateXYReply {
-    pub x_offset: i32,
-    pub y_offset: i32,
-}
+    fn pre_calibrate_y(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        y_distance: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::pre_calibrate_y(
+            self,
+            channel,
+            x_position,
+            y_position,
+            z_position,
+            y_distance,
+            y_cal_position,
+        )
+    }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GripCalibrateWristReply {
-    pub wrist_offset: i32,
-}
+    fn calibrate_x_resolution_start(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<CalibrateXResolutionStartReply, Error>> + Send {
+        NimbusCoreCalibration::calibrate_x_resolution_start(
+            self, channel, x_position, y_position, z_position, x_distance,
+        )
+    }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GripCalibrateZReply {
-    pub z_offset: i32,
-}
+    fn calibrate_x_resolution_finish(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<CalibrateXResolutionFinishReply, Error>> + Send {
+        NimbusCoreCalibration::calibrate_x_resolution_finish(
+            self, channel, x_position, y_position, z_position, x_distance,
+        )
+    }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GripCalibrationCheckXReply {
-    pub x_offset: i32,
-}
+    fn calibrate_channel_xy(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        y_distance: Millimeters,
+        x_cal_position: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibrate_channel_xy(
+            self,
+            channel,
+            x_position,
+            y_position,
+            z_position,
+            x_distance,
+            y_distance,
+            x_cal_position,
+            y_cal_position,
+        )
+    }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GripCalibrationCheckYReply {
-    pub y_offset: i32,
-}
+    fn calibrate_channel_z(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibrate_channel_z(
+            self,
+            channel,
+            x_position,
+            y_position,
+            z_position,
+            z_distance,
+            z_cal_position,
+        )
+    }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GripCalibrationCheckZReply {
-    pub z_offset: i32,
-}
+    fn calibration_check_x(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        x_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationCheckXReply, Error>> + Send {
+        NimbusCoreCalibration::calibration_check_x(
+            self,
+            channel,
+            x_position,
+            y_position,
+            z_position,
+            x_distance,
+            x_cal_position,
+        )
+    }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GetPotentiometerSettingsReply {
-    pub gain: Vec<i16>,
-    pub offset: Vec<i16>,
+    fn calibration_check_y(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        y_distance: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationCheckYReply, Error>> + Send {
+        NimbusCoreCalibration::calibration_check_y(
+            self,
+            channel,
+            x_position,
+            y_position,
+            z_position,
+            y_distance,
+            y_cal_position,
+        )
+    }
+
+    fn calibration_check_z(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationCheckZReply, Error>> + Send {
+        NimbusCoreCalibration::calibration_check_z(
+            self,
+            channel,
+            x_position,
+            y_position,
+            z_position,
+            z_distance,
+            z_cal_position,
+        )
+    }
+
+    fn calibration_check_dispenser(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
+        tip_collet_check: i16,
+    ) -> impl Future<Output = Result</* steps= */ i32, Error>> + Send {
+        NimbusCoreCalibration::calibration_check_dispenser(
+            self,
+            channel,
+            x_position,
+            y_position,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            tip_volume,
+            tip_collet_check,
+        )
+    }
+
+    fn calibrate_squeeze(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibrate_squeeze(
+            self,
+            channel,
+            x_position,
+            y_position,
+            z_start_position,
+            z_stop_position,
+        )
+    }
+
+    fn calibrate_tip_height(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
+        tip_collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibrate_tip_height(
+            self,
+            channel,
+            x_position,
+            y_position,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            tip_volume,
+            tip_collet_check,
+        )
+    }
+
+    fn calibrate_touchoff(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibrate_touchoff(self, channel, x_position, y_position)
+    }
+
+    fn grip_calibration_start(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::grip_calibration_start(self)
+    }
+
+    fn grip_calibration_cancel(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::grip_calibration_cancel(self)
+    }
+
+    fn grip_calibration_save(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::grip_calibration_save(self)
+    }
+
+    fn grip_pre_calibrate_z(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripPreCalibrateZReply, Error>> + Send {
+        NimbusCoreCalibration::grip_pre_calibrate_z(
+            self,
+            lld_channel,
+            x_position,
+            y_position,
+            z_position,
+            tool_height,
+            z_distance,
+            z_cal_position,
+        )
+    }
+
+    fn grip_calibrate_xy(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        x_distance: Millimeters,
+        y_distance: Millimeters,
+        x_cal_position: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrateXYReply, Error>> + Send {
+        NimbusCoreCalibration::grip_calibrate_xy(
+            self,
+            lld_channel,
+            x_position,
+            y_position,
+            z_position,
+            tool_height,
+            x_distance,
+            y_distance,
+            x_cal_position,
+            y_cal_position,
+        )
+    }
+
+    fn grip_calibration_tool_setup(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::grip_calibration_tool_setup(self)
+    }
+
+    fn grip_calibration_tool_pickup(
+        &self,
+        tool_width: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::grip_calibration_tool_pickup(self, tool_width)
+    }
+
+    fn grip_calibration_auto_tool_pickup(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_open: i32,
+        tool_width: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::grip_calibration_auto_tool_pickup(
+            self, x_position, y_position, z_position, grip_open, tool_width,
+        )
+    }
+
+    fn grip_calibration_tool_drop(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::grip_calibration_tool_drop(self)
+    }
+
+    fn grip_calibrate_wrist(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        y_distance: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrateWristReply, Error>> + Send {
+        NimbusCoreCalibration::grip_calibrate_wrist(
+            self,
+            lld_channel,
+            x_position,
+            y_position,
+            z_position,
+            tool_height,
+            y_distance,
+        )
+    }
+
+    fn grip_calibrate_z(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrateZReply, Error>> + Send {
+        NimbusCoreCalibration::grip_calibrate_z(
+            self,
+            lld_channel,
+            x_position,
+            y_position,
+            z_position,
+            tool_height,
+            z_distance,
+            z_cal_position,
+        )
+    }
+
+    fn grip_calibration_grip(
+        &self,
+        tool_width: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::grip_calibration_grip(self, tool_width)
+    }
+
+    fn grip_calibration_check_x(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        x_distance: Millimeters,
+        x_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrationCheckXReply, Error>> + Send {
+        NimbusCoreCalibration::grip_calibration_check_x(
+            self,
+            lld_channel,
+            x_position,
+            y_position,
+            z_position,
+            tool_height,
+            x_distance,
+            x_cal_position,
+        )
+    }
+
+    fn grip_calibration_check_y(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        y_distance: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrationCheckYReply, Error>> + Send {
+        NimbusCoreCalibration::grip_calibration_check_y(
+            self,
+            lld_channel,
+            x_position,
+            y_position,
+            z_position,
+            tool_height,
+            y_distance,
+            y_cal_position,
+        )
+    }
+
+    fn grip_calibration_check_z(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrationCheckZReply, Error>> + Send {
+        NimbusCoreCalibration::grip_calibration_check_z(
+            self,
+            lld_channel,
+            x_position,
+            y_position,
+            z_position,
+            tool_height,
+            z_distance,
+            z_cal_position,
+        )
+    }
+
+    fn get_pressure(
+        &self,
+    ) -> impl Future<Output = Result</* pressures= */ Vec<i16>, Error>> + Send {
+        NimbusCoreCalibration::get_pressure(self)
+    }
+
+    fn get_potentiometer_settings(
+        &self,
+    ) -> impl Future<Output = Result<GetPotentiometerSettingsReply, Error>> + Send {
+        NimbusCoreCalibration::get_potentiometer_settings(self)
+    }
+
+    fn set_pressure_measurement_mode(
+        &self,
+        channel: u16,
+        mode: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::set_pressure_measurement_mode(self, channel, mode)
+    }
+
+    fn set_pressure_sensor_gain(
+        &self,
+        channel: u16,
+        gain: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::set_pressure_sensor_gain(self, channel, gain)
+    }
+
+    fn set_pressure_sensor_offset(
+        &self,
+        channel: u16,
+        offset: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::set_pressure_sensor_offset(self, channel, offset)
+    }
+
+    fn set_pressure_sensor_shift(
+        &self,
+        channel: u16,
+        shift: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::set_pressure_sensor_shift(self, channel, shift)
+    }
+
+    fn store_pressure_sensor_gain(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::store_pressure_sensor_gain(self, channel)
+    }
+
+    fn store_pressure_sensor_offset(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::store_pressure_sensor_offset(self, channel)
+    }
+
+    fn calibrate_tadm_offset(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibrate_tadm_offset(self, channel)
+    }
+
+    fn calibration_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibration_initialize(self)
+    }
+
+    fn leak_check(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        tip_type: impl AsRef<[u16]> + Send,
+        time: impl AsRef<[u32]> + Send,
+        test_type: impl AsRef<[bool]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::leak_check(
+            self,
+            tips_used,
+            x_position,
+            y_position,
+            traverse_height,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            tip_type,
+            time,
+            test_type,
+        )
+    }
+
+    fn calibrate_lld(&self, channel: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibrate_lld(self, channel)
+    }
+
+    fn calibration_store(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibration_store(self)
+    }
+
+    fn calibration_squeeze_check_torque(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibration_squeeze_check_torque(self, tips_used)
+    }
+
+    fn calibrate_squeeze_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibrate_squeeze_position(self, tips_used)
+    }
+
+    fn calibration_reset(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibration_reset(self)
+    }
+
+    fn calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<CalibrationValuesReply, Error>> + Send {
+        NimbusCoreCalibration::calibration_values(self)
+    }
+
+    fn calibration_set_x_home_offset(
+        &self,
+        x_offset: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibration_set_x_home_offset(self, x_offset)
+    }
+
+    fn calibration_set_x_resolution(
+        &self,
+        x_resolution: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibration_set_x_resolution(self, x_resolution)
+    }
+
+    fn calibration_set_y_home_offsets(
+        &self,
+        y_home_offset: impl AsRef<[i32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibration_set_y_home_offsets(self, y_home_offset)
+    }
+
+    fn calibration_set_z_home_offsets(
+        &self,
+        z_home_offset: impl AsRef<[i32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibration_set_z_home_offsets(self, z_home_offset)
+    }
+
+    fn calibration_seek_x(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationSeekXReply, Error>> + Send {
+        NimbusCoreCalibration::calibration_seek_x(
+            self, channel, x_position, y_position, z_position, x_distance,
+        )
+    }
+
+    fn calibration_seek_y(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        y_distance: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationSeekYReply, Error>> + Send {
+        NimbusCoreCalibration::calibration_seek_y(
+            self, channel, x_position, y_position, z_position, y_distance,
+        )
+    }
+
+    fn calibration_barcode_laser(
+        &self,
+        enable: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::calibration_barcode_laser(self, enable)
+    }
+
+    fn z_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCalibration::z_servo_off(self, tips_used)
+    }
+
+    fn grip_calibrate_grip_travel_extent(
+        &self,
+    ) -> impl Future<Output = Result<GripCalibrateGripTravelExtentReply, Error>> + Send {
+        NimbusCoreCalibration::grip_calibrate_grip_travel_extent(self)
+    }
+
+    fn calibration_check_xy(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        y_distance: Millimeters,
+        x_cal_position: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationCheckXYReply, Error>> + Send {
+        NimbusCoreCalibration::calibration_check_xy(
+            self,
+            channel,
+            x_position,
+            y_position,
+            z_position,
+            x_distance,
+            y_distance,
+            x_cal_position,
+            y_cal_position,
+        )
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreCalibration::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreCalibration::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreCalibration::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreCalibration::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreCalibration::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreCalibration::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreCalibrationFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreCalibrationApi for NimbusCoreCalibrationFake {
+    fn calibration_start(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("calibration_start", vec![]);
+        async move { reply }
+    }
+
+    fn calibration_save(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("calibration_save", vec![]);
+        async move { reply }
+    }
+
+    fn calibration_cancel(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("calibration_cancel", vec![]);
+        async move { reply }
+    }
+
+    fn pre_calibrate_z(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pre_calibrate_z",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("z_distance", format!("{:?}", z_distance)),
+                ("z_cal_position", format!("{:?}", z_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn pre_calibrate_x(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        x_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pre_calibrate_x",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("x_distance", format!("{:?}", x_distance)),
+                ("x_cal_position", format!("{:?}", x_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn pre_calibrate_y(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        y_distance: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pre_calibrate_y",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("y_distance", format!("{:?}", y_distance)),
+                ("y_cal_position", format!("{:?}", y_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_x_resolution_start(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<CalibrateXResolutionStartReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_x_resolution_start",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("x_distance", format!("{:?}", x_distance)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_x_resolution_finish(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<CalibrateXResolutionFinishReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_x_resolution_finish",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("x_distance", format!("{:?}", x_distance)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_channel_xy(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        y_distance: Millimeters,
+        x_cal_position: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_channel_xy",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("x_distance", format!("{:?}", x_distance)),
+                ("y_distance", format!("{:?}", y_distance)),
+                ("x_cal_position", format!("{:?}", x_cal_position)),
+                ("y_cal_position", format!("{:?}", y_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_channel_z(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_channel_z",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("z_distance", format!("{:?}", z_distance)),
+                ("z_cal_position", format!("{:?}", z_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibration_check_x(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        x_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationCheckXReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_check_x",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("x_distance", format!("{:?}", x_distance)),
+                ("x_cal_position", format!("{:?}", x_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibration_check_y(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        y_distance: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationCheckYReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_check_y",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("y_distance", format!("{:?}", y_distance)),
+                ("y_cal_position", format!("{:?}", y_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibration_check_z(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationCheckZReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_check_z",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("z_distance", format!("{:?}", z_distance)),
+                ("z_cal_position", format!("{:?}", z_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibration_check_dispenser(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
+        tip_collet_check: i16,
+    ) -> impl Future<Output = Result</* steps= */ i32, Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_check_dispenser",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("z_final", format!("{:?}", z_final)),
+                ("tip_volume", format!("{:?}", tip_volume)),
+                ("tip_collet_check", format!("{:?}", tip_collet_check)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_squeeze(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_squeeze",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_tip_height(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
+        tip_collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_tip_height",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("z_final", format!("{:?}", z_final)),
+                ("tip_volume", format!("{:?}", tip_volume)),
+                ("tip_collet_check", format!("{:?}", tip_collet_check)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_touchoff(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_touchoff",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn grip_calibration_start(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("grip_calibration_start", vec![]);
+        async move { reply }
+    }
+
+    fn grip_calibration_cancel(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("grip_calibration_cancel", vec![]);
+        async move { reply }
+    }
+
+    fn grip_calibration_save(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("grip_calibration_save", vec![]);
+        async move { reply }
+    }
+
+    fn grip_pre_calibrate_z(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripPreCalibrateZReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "grip_pre_calibrate_z",
+            vec![
+                ("lld_channel", format!("{:?}", lld_channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("tool_height", format!("{:?}", tool_height)),
+                ("z_distance", format!("{:?}", z_distance)),
+                ("z_cal_position", format!("{:?}", z_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn grip_calibrate_xy(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        x_distance: Millimeters,
+        y_distance: Millimeters,
+        x_cal_position: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrateXYReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "grip_calibrate_xy",
+            vec![
+                ("lld_channel", format!("{:?}", lld_channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("tool_height", format!("{:?}", tool_height)),
+                ("x_distance", format!("{:?}", x_distance)),
+                ("y_distance", format!("{:?}", y_distance)),
+                ("x_cal_position", format!("{:?}", x_cal_position)),
+                ("y_cal_position", format!("{:?}", y_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn grip_calibration_tool_setup(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("grip_calibration_tool_setup", vec![]);
+        async move { reply }
+    }
+
+    fn grip_calibration_tool_pickup(
+        &self,
+        tool_width: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "grip_calibration_tool_pickup",
+            vec![("tool_width", format!("{:?}", tool_width))],
+        );
+        async move { reply }
+    }
+
+    fn grip_calibration_auto_tool_pickup(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_open: i32,
+        tool_width: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "grip_calibration_auto_tool_pickup",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("grip_open", format!("{:?}", grip_open)),
+                ("tool_width", format!("{:?}", tool_width)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn grip_calibration_tool_drop(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("grip_calibration_tool_drop", vec![]);
+        async move { reply }
+    }
+
+    fn grip_calibrate_wrist(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        y_distance: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrateWristReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "grip_calibrate_wrist",
+            vec![
+                ("lld_channel", format!("{:?}", lld_channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("tool_height", format!("{:?}", tool_height)),
+                ("y_distance", format!("{:?}", y_distance)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn grip_calibrate_z(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrateZReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "grip_calibrate_z",
+            vec![
+                ("lld_channel", format!("{:?}", lld_channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("tool_height", format!("{:?}", tool_height)),
+                ("z_distance", format!("{:?}", z_distance)),
+                ("z_cal_position", format!("{:?}", z_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn grip_calibration_grip(
+        &self,
+        tool_width: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "grip_calibration_grip",
+            vec![("tool_width", format!("{:?}", tool_width))],
+        );
+        async move { reply }
+    }
+
+    fn grip_calibration_check_x(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        x_distance: Millimeters,
+        x_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrationCheckXReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "grip_calibration_check_x",
+            vec![
+                ("lld_channel", format!("{:?}", lld_channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("tool_height", format!("{:?}", tool_height)),
+                ("x_distance", format!("{:?}", x_distance)),
+                ("x_cal_position", format!("{:?}", x_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn grip_calibration_check_y(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        y_distance: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrationCheckYReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "grip_calibration_check_y",
+            vec![
+                ("lld_channel", format!("{:?}", lld_channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("tool_height", format!("{:?}", tool_height)),
+                ("y_distance", format!("{:?}", y_distance)),
+                ("y_cal_position", format!("{:?}", y_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn grip_calibration_check_z(
+        &self,
+        lld_channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        tool_height: Millimeters,
+        z_distance: Millimeters,
+        z_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<GripCalibrationCheckZReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "grip_calibration_check_z",
+            vec![
+                ("lld_channel", format!("{:?}", lld_channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("tool_height", format!("{:?}", tool_height)),
+                ("z_distance", format!("{:?}", z_distance)),
+                ("z_cal_position", format!("{:?}", z_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_pressure(
+        &self,
+    ) -> impl Future<Output = Result</* pressures= */ Vec<i16>, Error>> + Send {
+        let reply = self.recorder.record("get_pressure", vec![]);
+        async move { reply }
+    }
+
+    fn get_potentiometer_settings(
+        &self,
+    ) -> impl Future<Output = Result<GetPotentiometerSettingsReply, Error>> + Send {
+        let reply = self.recorder.record("get_potentiometer_settings", vec![]);
+        async move { reply }
+    }
+
+    fn set_pressure_measurement_mode(
+        &self,
+        channel: u16,
+        mode: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_pressure_measurement_mode",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("mode", format!("{:?}", mode)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_pressure_sensor_gain(
+        &self,
+        channel: u16,
+        gain: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_pressure_sensor_gain",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("gain", format!("{:?}", gain)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_pressure_sensor_offset(
+        &self,
+        channel: u16,
+        offset: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_pressure_sensor_offset",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("offset", format!("{:?}", offset)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_pressure_sensor_shift(
+        &self,
+        channel: u16,
+        shift: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_pressure_sensor_shift",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("shift", format!("{:?}", shift)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn store_pressure_sensor_gain(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "store_pressure_sensor_gain",
+            vec![("channel", format!("{:?}", channel))],
+        );
+        async move { reply }
+    }
+
+    fn store_pressure_sensor_offset(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "store_pressure_sensor_offset",
+            vec![("channel", format!("{:?}", channel))],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_tadm_offset(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_tadm_offset",
+            vec![("channel", format!("{:?}", channel))],
+        );
+        async move { reply }
+    }
+
+    fn calibration_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("calibration_initialize", vec![]);
+        async move { reply }
+    }
+
+    fn leak_check(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        tip_type: impl AsRef<[u16]> + Send,
+        time: impl AsRef<[u32]> + Send,
+        test_type: impl AsRef<[bool]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "leak_check",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("z_final", format!("{:?}", z_final.as_ref())),
+                ("tip_type", format!("{:?}", tip_type.as_ref())),
+                ("time", format!("{:?}", time.as_ref())),
+                ("test_type", format!("{:?}", test_type.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_lld(&self, channel: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("calibrate_lld", vec![("channel", format!("{:?}", channel))]);
+        async move { reply }
+    }
+
+    fn calibration_store(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("calibration_store", vec![]);
+        async move { reply }
+    }
+
+    fn calibration_squeeze_check_torque(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_squeeze_check_torque",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_squeeze_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_squeeze_position",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn calibration_reset(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("calibration_reset", vec![]);
+        async move { reply }
+    }
+
+    fn calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<CalibrationValuesReply, Error>> + Send {
+        let reply = self.recorder.record("calibration_values", vec![]);
+        async move { reply }
+    }
+
+    fn calibration_set_x_home_offset(
+        &self,
+        x_offset: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_set_x_home_offset",
+            vec![("x_offset", format!("{:?}", x_offset))],
+        );
+        async move { reply }
+    }
+
+    fn calibration_set_x_resolution(
+        &self,
+        x_resolution: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_set_x_resolution",
+            vec![("x_resolution", format!("{:?}", x_resolution))],
+        );
+        async move { reply }
+    }
+
+    fn calibration_set_y_home_offsets(
+        &self,
+        y_home_offset: impl AsRef<[i32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_set_y_home_offsets",
+            vec![("y_home_offset", format!("{:?}", y_home_offset.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn calibration_set_z_home_offsets(
+        &self,
+        z_home_offset: impl AsRef<[i32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_set_z_home_offsets",
+            vec![("z_home_offset", format!("{:?}", z_home_offset.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn calibration_seek_x(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationSeekXReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_seek_x",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("x_distance", format!("{:?}", x_distance)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibration_seek_y(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        y_distance: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationSeekYReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_seek_y",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("y_distance", format!("{:?}", y_distance)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibration_barcode_laser(
+        &self,
+        enable: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_barcode_laser",
+            vec![("enable", format!("{:?}", enable))],
+        );
+        async move { reply }
+    }
+
+    fn z_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_servo_off",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn grip_calibrate_grip_travel_extent(
+        &self,
+    ) -> impl Future<Output = Result<GripCalibrateGripTravelExtentReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("grip_calibrate_grip_travel_extent", vec![]);
+        async move { reply }
+    }
+
+    fn calibration_check_xy(
+        &self,
+        channel: u16,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        x_distance: Millimeters,
+        y_distance: Millimeters,
+        x_cal_position: Millimeters,
+        y_cal_position: Millimeters,
+    ) -> impl Future<Output = Result<CalibrationCheckXYReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_check_xy",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("x_distance", format!("{:?}", x_distance)),
+                ("y_distance", format!("{:?}", y_distance)),
+                ("x_cal_position", format!("{:?}", x_cal_position)),
+                ("y_cal_position", format!("{:?}", y_cal_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalibrateXResolutionStartReply {
+    pub x_measured: i32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalibrateXResolutionFinishReply {
+    pub x_measured: i32,
+    pub x_resolution: i32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalibrationCheckXReply {
+    pub x_offset: i32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalibrationCheckYReply {
+    pub y_offset: i32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalibrationCheckZReply {
+    pub z_offset: i32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GripPreCalibrateZReply {
+    pub z_offset: i32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GripCalibrateXYReply {
+    pub x_offset: i32,
+    pub y_offset: i32,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GripCalibrateXYRequest {
+    pub lld_channel: Option<u16>,
+    pub x_position: Option<Millimeters>,
+    pub y_position: Option<Millimeters>,
+    pub z_position: Option<Millimeters>,
+    pub tool_height: Option<Millimeters>,
+    pub x_distance: Option<Millimeters>,
+    pub y_distance: Option<Millimeters>,
+    pub x_cal_position: Option<Millimeters>,
+    pub y_cal_position: Option<Millimeters>,
+}
+
+impl GripCalibrateXYRequest {
+    pub fn lld_channel(mut self, lld_channel: u16) -> Self {
+        self.lld_channel = Some(lld_channel);
+        self
+    }
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn y_position(mut self, y_position: Millimeters) -> Self {
+        self.y_position = Some(y_position);
+        self
+    }
+    pub fn z_position(mut self, z_position: Millimeters) -> Self {
+        self.z_position = Some(z_position);
+        self
+    }
+    pub fn tool_height(mut self, tool_height: Millimeters) -> Self {
+        self.tool_height = Some(tool_height);
+        self
+    }
+    pub fn x_distance(mut self, x_distance: Millimeters) -> Self {
+        self.x_distance = Some(x_distance);
+        self
+    }
+    pub fn y_distance(mut self, y_distance: Millimeters) -> Self {
+        self.y_distance = Some(y_distance);
+        self
+    }
+    pub fn x_cal_position(mut self, x_cal_position: Millimeters) -> Self {
+        self.x_cal_position = Some(x_cal_position);
+        self
+    }
+    pub fn y_cal_position(mut self, y_cal_position: Millimeters) -> Self {
+        self.y_cal_position = Some(y_cal_position);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GripCalibrateWristReply {
+    pub wrist_offset: i32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GripCalibrateZReply {
+    pub z_offset: i32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GripCalibrationCheckXReply {
+    pub x_offset: i32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GripCalibrationCheckYReply {
+    pub y_offset: i32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GripCalibrationCheckZReply {
+    pub z_offset: i32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetPotentiometerSettingsReply {
+    pub gain: Vec<i16>,
+    pub offset: Vec<i16>,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeakCheckRequest {
+    pub tips_used: Option<Vec<u16>>,
+    pub x_position: Option<Vec<Millimeters>>,
+    pub y_position: Option<Vec<Millimeters>>,
+    pub traverse_height: Option<Millimeters>,
+    pub z_start_position: Option<Vec<Millimeters>>,
+    pub z_stop_position: Option<Vec<Millimeters>>,
+    pub z_final: Option<Vec<Millimeters>>,
+    pub tip_type: Option<Vec<u16>>,
+    pub time: Option<Vec<u32>>,
+    pub test_type: Option<Vec<bool>>,
+}
+
+impl LeakCheckRequest {
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn x_position(mut self, x_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.x_position = Some(x_position.into());
+        self
+    }
+    pub fn y_position(mut self, y_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_position = Some(y_position.into());
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn z_start_position(mut self, z_start_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.z_start_position = Some(z_start_position.into());
+        self
+    }
+    pub fn z_stop_position(mut self, z_stop_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.z_stop_position = Some(z_stop_position.into());
+        self
+    }
+    pub fn z_final(mut self, z_final: impl Into<Vec<Millimeters>>) -> Self {
+        self.z_final = Some(z_final.into());
+        self
+    }
+    pub fn tip_type(mut self, tip_type: impl Into<Vec<u16>>) -> Self {
+        self.tip_type = Some(tip_type.into());
+        self
+    }
+    pub fn time(mut self, time: impl Into<Vec<u32>>) -> Self {
+        self.time = Some(time.into());
+        self
+    }
+    pub fn test_type(mut self, test_type: impl Into<Vec<bool>>) -> Self {
+        self.test_type = Some(test_type.into());
+        self
+    }
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CalibrationValuesReply {
     pub x_offset: i32,
     pub x_resolution: i32,
@@ -2013,34 +4614,34 @@ pub struct CalibrationValuesReply {
     pub z_offset: Vec<i32>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CalibrationSeekXReply {
     pub x_offset: i32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CalibrationSeekYReply {
     pub y_offset: i32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GripCalibrateGripTravelExtentReply {
     pub lower_limit: i32,
     pub upper_limit: i32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CalibrationCheckXYReply {
     pub x_offset: i32,
     pub y_offset: i32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -2048,8 +4649,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -2059,23 +4660,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -2083,8 +4684,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
@@ -1,14 +1,14 @@
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
+use piglet_client::units::{Microliters, MicrolitersPerSecond, Millimeters, MmPerSecond};
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +16,13 @@ use std::sync::Arc;
 pub struct NimbusCoreChannel {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreChannel {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Channel";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new_1(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,9 +32,20 @@ impl NimbusCoreChannel {
                 object_id: 272,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_1 is the default
+    pub async fn resolve_1(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Channel[1]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_2(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -40,9 +54,20 @@ impl NimbusCoreChannel {
                 object_id: 273,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_2 is the default
+    pub async fn resolve_2(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Channel[2]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_3(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -51,9 +76,20 @@ impl NimbusCoreChannel {
                 object_id: 274,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_3 is the default
+    pub async fn resolve_3(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Channel[3]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_4(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -62,9 +98,20 @@ impl NimbusCoreChannel {
                 object_id: 275,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_4 is the default
+    pub async fn resolve_4(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Channel[4]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_5(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -73,9 +120,20 @@ impl NimbusCoreChannel {
                 object_id: 276,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_5 is the default
+    pub async fn resolve_5(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Channel[5]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_6(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -84,9 +142,20 @@ impl NimbusCoreChannel {
                 object_id: 277,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_6 is the default
+    pub async fn resolve_6(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Channel[6]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_7(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -95,9 +164,20 @@ impl NimbusCoreChannel {
                 object_id: 278,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_7 is the default
+    pub async fn resolve_7(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Channel[7]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_8(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -106,6 +186,25 @@ impl NimbusCoreChannel {
                 object_id: 279,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_8 is the default
+    pub async fn resolve_8(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Channel[8]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
@@ -119,7 +218,9 @@ impl NimbusCoreChannel {
         command_timeout.serialize(&mut args);
         request.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 1, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  command_timeout: {:?}", command_timeout),
@@ -133,7 +234,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let response = String::deserialize(&mut stream)?;
         Ok(response)
@@ -143,7 +244,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         request.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  request: {:?}", request)];
                 format!(
@@ -154,7 +257,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -162,12 +265,14 @@ impl NimbusCoreChannel {
     pub async fn receive_no_wait(&self) -> Result</* response= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.ReceiveNoWait()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let response = String::deserialize(&mut stream)?;
         Ok(response)
@@ -177,7 +282,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         command_timeout.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  command_timeout: {:?}", command_timeout)];
                 format!(
@@ -188,7 +295,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let response = String::deserialize(&mut stream)?;
         Ok(response)
@@ -197,12 +304,14 @@ impl NimbusCoreChannel {
     pub async fn last_command(&self) -> Result</* request= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 5, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.LastCommand()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let request = String::deserialize(&mut stream)?;
         Ok(request)
@@ -211,20 +320,22 @@ impl NimbusCoreChannel {
     pub async fn pickup_tip(
         &self,
 
-        z_start_position: i32,
-        z_stop_position: i32,
-        z_final: i32,
-        volume: u32,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
         collet_check: i16,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
-        z_final.serialize(&mut args);
-        volume.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        volume.to_wire::<u32>()?.serialize(&mut args);
         collet_check.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  z_start_position: {:?}", z_start_position),
@@ -241,7 +352,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -249,22 +360,24 @@ impl NimbusCoreChannel {
     pub async fn pickup_needle(
         &self,
 
-        z_start_position: i32,
-        z_stop_position: i32,
-        z_final: i32,
-        volume: u32,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
         length: u16,
         collet_check: i16,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
-        z_final.serialize(&mut args);
-        volume.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        volume.to_wire::<u32>()?.serialize(&mut args);
         length.serialize(&mut args);
         collet_check.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  z_start_position: {:?}", z_start_position),
@@ -282,7 +395,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -290,16 +403,18 @@ impl NimbusCoreChannel {
     pub async fn drop_tip(
         &self,
 
-        z_start_position: i32,
-        z_stop_position: i32,
-        z_final: i32,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
-        z_final.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 8, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  z_start_position: {:?}", z_start_position),
@@ -314,7 +429,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -322,14 +437,16 @@ impl NimbusCoreChannel {
     pub async fn drop_tip_no_z_final(
         &self,
 
-        z_start_position: i32,
-        z_stop_position: i32,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 9, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  z_start_position: {:?}", z_start_position),
@@ -343,7 +460,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -352,25 +469,25 @@ impl NimbusCoreChannel {
         &self,
 
         aspirate_mode: i16,
-        liquid_seek_height: i32,
-        submerge_depth: i32,
-        follow_depth: i32,
-        z_min_position: i32,
-        clot_check_height: i32,
-        z_final: i32,
-        liquid_exit_speed: u32,
-        blowout_volume: u32,
-        prewet_volume: u32,
-        aspirate_volume: u32,
-        transport_air_volume: u32,
-        aspirate_speed: u32,
+        liquid_seek_height: Millimeters,
+        submerge_depth: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        clot_check_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        blowout_volume: Microliters,
+        prewet_volume: Microliters,
+        aspirate_volume: Microliters,
+        transport_air_volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
         settling_time: u32,
-        mix_volume: u32,
+        mix_volume: Microliters,
         mix_cycles: u32,
-        mix_position: i32,
-        mix_follow_distance: i32,
-        mix_speed: u32,
-        tube_section_height: i32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
         tube_section_ratio: i32,
         capacitive_lld_steepness: u32,
         capacitive_lld_offset: u32,
@@ -381,25 +498,25 @@ impl NimbusCoreChannel {
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         aspirate_mode.serialize(&mut args);
-        liquid_seek_height.serialize(&mut args);
-        submerge_depth.serialize(&mut args);
-        follow_depth.serialize(&mut args);
-        z_min_position.serialize(&mut args);
-        clot_check_height.serialize(&mut args);
-        z_final.serialize(&mut args);
-        liquid_exit_speed.serialize(&mut args);
-        blowout_volume.serialize(&mut args);
-        prewet_volume.serialize(&mut args);
-        aspirate_volume.serialize(&mut args);
-        transport_air_volume.serialize(&mut args);
-        aspirate_speed.serialize(&mut args);
+        liquid_seek_height.to_wire::<i32>()?.serialize(&mut args);
+        submerge_depth.to_wire::<i32>()?.serialize(&mut args);
+        follow_depth.to_wire::<i32>()?.serialize(&mut args);
+        z_min_position.to_wire::<i32>()?.serialize(&mut args);
+        clot_check_height.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        liquid_exit_speed.to_wire::<u32>()?.serialize(&mut args);
+        blowout_volume.to_wire::<u32>()?.serialize(&mut args);
+        prewet_volume.to_wire::<u32>()?.serialize(&mut args);
+        aspirate_volume.to_wire::<u32>()?.serialize(&mut args);
+        transport_air_volume.to_wire::<u32>()?.serialize(&mut args);
+        aspirate_speed.to_wire::<u32>()?.serialize(&mut args);
         settling_time.serialize(&mut args);
-        mix_volume.serialize(&mut args);
+        mix_volume.to_wire::<u32>()?.serialize(&mut args);
         mix_cycles.serialize(&mut args);
-        mix_position.serialize(&mut args);
-        mix_follow_distance.serialize(&mut args);
-        mix_speed.serialize(&mut args);
-        tube_section_height.serialize(&mut args);
+        mix_position.to_wire::<i32>()?.serialize(&mut args);
+        mix_follow_distance.to_wire::<i32>()?.serialize(&mut args);
+        mix_speed.to_wire::<u32>()?.serialize(&mut args);
+        tube_section_height.to_wire::<i32>()?.serialize(&mut args);
         tube_section_ratio.serialize(&mut args);
         capacitive_lld_steepness.serialize(&mut args);
         capacitive_lld_offset.serialize(&mut args);
@@ -408,7 +525,9 @@ impl NimbusCoreChannel {
         recording_mode.serialize(&mut args);
         measurement_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 10, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  aspirate_mode: {:?}", aspirate_mode),
@@ -447,40 +566,184 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn aspirate_capacitive_lld_with(
+        &self,
+        request: &AspirateCapacitiveLldRequest,
+    ) -> Result<(), Error> {
+        self.aspirate_capacitive_lld(
+            piglet_client::client::required(
+                request.aspirate_mode,
+                "AspirateCapacitiveLldRequest",
+                "aspirate_mode",
+            )?,
+            piglet_client::client::required(
+                request.liquid_seek_height,
+                "AspirateCapacitiveLldRequest",
+                "liquid_seek_height",
+            )?,
+            piglet_client::client::required(
+                request.submerge_depth,
+                "AspirateCapacitiveLldRequest",
+                "submerge_depth",
+            )?,
+            piglet_client::client::required(
+                request.follow_depth,
+                "AspirateCapacitiveLldRequest",
+                "follow_depth",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position,
+                "AspirateCapacitiveLldRequest",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(
+                request.clot_check_height,
+                "AspirateCapacitiveLldRequest",
+                "clot_check_height",
+            )?,
+            piglet_client::client::required(
+                request.z_final,
+                "AspirateCapacitiveLldRequest",
+                "z_final",
+            )?,
+            piglet_client::client::required(
+                request.liquid_exit_speed,
+                "AspirateCapacitiveLldRequest",
+                "liquid_exit_speed",
+            )?,
+            piglet_client::client::required(
+                request.blowout_volume,
+                "AspirateCapacitiveLldRequest",
+                "blowout_volume",
+            )?,
+            piglet_client::client::required(
+                request.prewet_volume,
+                "AspirateCapacitiveLldRequest",
+                "prewet_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_volume,
+                "AspirateCapacitiveLldRequest",
+                "aspirate_volume",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume,
+                "AspirateCapacitiveLldRequest",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_speed,
+                "AspirateCapacitiveLldRequest",
+                "aspirate_speed",
+            )?,
+            piglet_client::client::required(
+                request.settling_time,
+                "AspirateCapacitiveLldRequest",
+                "settling_time",
+            )?,
+            piglet_client::client::required(
+                request.mix_volume,
+                "AspirateCapacitiveLldRequest",
+                "mix_volume",
+            )?,
+            piglet_client::client::required(
+                request.mix_cycles,
+                "AspirateCapacitiveLldRequest",
+                "mix_cycles",
+            )?,
+            piglet_client::client::required(
+                request.mix_position,
+                "AspirateCapacitiveLldRequest",
+                "mix_position",
+            )?,
+            piglet_client::client::required(
+                request.mix_follow_distance,
+                "AspirateCapacitiveLldRequest",
+                "mix_follow_distance",
+            )?,
+            piglet_client::client::required(
+                request.mix_speed,
+                "AspirateCapacitiveLldRequest",
+                "mix_speed",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_height,
+                "AspirateCapacitiveLldRequest",
+                "tube_section_height",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_ratio,
+                "AspirateCapacitiveLldRequest",
+                "tube_section_ratio",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_steepness,
+                "AspirateCapacitiveLldRequest",
+                "capacitive_lld_steepness",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_offset,
+                "AspirateCapacitiveLldRequest",
+                "capacitive_lld_offset",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "AspirateCapacitiveLldRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index,
+                "AspirateCapacitiveLldRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "AspirateCapacitiveLldRequest",
+                "recording_mode",
+            )?,
+            piglet_client::client::required(
+                request.measurement_id.as_deref(),
+                "AspirateCapacitiveLldRequest",
+                "measurement_id",
+            )?,
+        )
+        .await
+    }
+
     pub async fn aspirate_pressure_lld(
         &self,
 
         aspirate_mode: i16,
-        liquid_seek_height: i32,
-        submerge_depth: i32,
-        follow_depth: i32,
-        z_min_position: i32,
-        z_final: i32,
-        liquid_exit_speed: u32,
-        blowout_volume: u32,
-        prewet_volume: u32,
-        aspirate_volume: u32,
-        transport_air_volume: u32,
-        aspirate_speed: u32,
+        liquid_seek_height: Millimeters,
+        submerge_depth: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        blowout_volume: Microliters,
+        prewet_volume: Microliters,
+        aspirate_volume: Microliters,
+        transport_air_volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
         settling_time: u32,
-        mix_volume: u32,
+        mix_volume: Microliters,
         mix_cycles: u32,
-        mix_position: i32,
-        mix_follow_distance: i32,
-        mix_speed: u32,
-        tube_section_height: i32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
         tube_section_ratio: i32,
         lld_mode: i16,
         capacitive_lld_steepness: u32,
         capacitive_lld_offset: u32,
         pressure_lld_steepness: u32,
         pressure_lld_offset: u32,
-        seek_speed: u32,
+        seek_speed: MmPerSecond,
         lld_height_difference: i32,
         tadm_enabled: bool,
         limit_curve_index: u32,
@@ -490,31 +753,31 @@ impl NimbusCoreChannel {
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         aspirate_mode.serialize(&mut args);
-        liquid_seek_height.serialize(&mut args);
-        submerge_depth.serialize(&mut args);
-        follow_depth.serialize(&mut args);
-        z_min_position.serialize(&mut args);
-        z_final.serialize(&mut args);
-        liquid_exit_speed.serialize(&mut args);
-        blowout_volume.serialize(&mut args);
-        prewet_volume.serialize(&mut args);
-        aspirate_volume.serialize(&mut args);
-        transport_air_volume.serialize(&mut args);
-        aspirate_speed.serialize(&mut args);
+        liquid_seek_height.to_wire::<i32>()?.serialize(&mut args);
+        submerge_depth.to_wire::<i32>()?.serialize(&mut args);
+        follow_depth.to_wire::<i32>()?.serialize(&mut args);
+        z_min_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        liquid_exit_speed.to_wire::<u32>()?.serialize(&mut args);
+        blowout_volume.to_wire::<u32>()?.serialize(&mut args);
+        prewet_volume.to_wire::<u32>()?.serialize(&mut args);
+        aspirate_volume.to_wire::<u32>()?.serialize(&mut args);
+        transport_air_volume.to_wire::<u32>()?.serialize(&mut args);
+        aspirate_speed.to_wire::<u32>()?.serialize(&mut args);
         settling_time.serialize(&mut args);
-        mix_volume.serialize(&mut args);
+        mix_volume.to_wire::<u32>()?.serialize(&mut args);
         mix_cycles.serialize(&mut args);
-        mix_position.serialize(&mut args);
-        mix_follow_distance.serialize(&mut args);
-        mix_speed.serialize(&mut args);
-        tube_section_height.serialize(&mut args);
+        mix_position.to_wire::<i32>()?.serialize(&mut args);
+        mix_follow_distance.to_wire::<i32>()?.serialize(&mut args);
+        mix_speed.to_wire::<u32>()?.serialize(&mut args);
+        tube_section_height.to_wire::<i32>()?.serialize(&mut args);
         tube_section_ratio.serialize(&mut args);
         lld_mode.serialize(&mut args);
         capacitive_lld_steepness.serialize(&mut args);
         capacitive_lld_offset.serialize(&mut args);
         pressure_lld_steepness.serialize(&mut args);
         pressure_lld_offset.serialize(&mut args);
-        seek_speed.serialize(&mut args);
+        seek_speed.to_wire::<u32>()?.serialize(&mut args);
         lld_height_difference.serialize(&mut args);
         tadm_enabled.serialize(&mut args);
         limit_curve_index.serialize(&mut args);
@@ -522,7 +785,9 @@ impl NimbusCoreChannel {
         measurement_id.serialize(&mut args);
         tip_is_filtered.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 11, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  aspirate_mode: {:?}", aspirate_mode),
@@ -566,33 +831,202 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn aspirate_pressure_lld_with(
+        &self,
+        request: &AspiratePressureLldRequest,
+    ) -> Result<(), Error> {
+        self.aspirate_pressure_lld(
+            piglet_client::client::required(
+                request.aspirate_mode,
+                "AspiratePressureLldRequest",
+                "aspirate_mode",
+            )?,
+            piglet_client::client::required(
+                request.liquid_seek_height,
+                "AspiratePressureLldRequest",
+                "liquid_seek_height",
+            )?,
+            piglet_client::client::required(
+                request.submerge_depth,
+                "AspiratePressureLldRequest",
+                "submerge_depth",
+            )?,
+            piglet_client::client::required(
+                request.follow_depth,
+                "AspiratePressureLldRequest",
+                "follow_depth",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position,
+                "AspiratePressureLldRequest",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(
+                request.z_final,
+                "AspiratePressureLldRequest",
+                "z_final",
+            )?,
+            piglet_client::client::required(
+                request.liquid_exit_speed,
+                "AspiratePressureLldRequest",
+                "liquid_exit_speed",
+            )?,
+            piglet_client::client::required(
+                request.blowout_volume,
+                "AspiratePressureLldRequest",
+                "blowout_volume",
+            )?,
+            piglet_client::client::required(
+                request.prewet_volume,
+                "AspiratePressureLldRequest",
+                "prewet_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_volume,
+                "AspiratePressureLldRequest",
+                "aspirate_volume",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume,
+                "AspiratePressureLldRequest",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_speed,
+                "AspiratePressureLldRequest",
+                "aspirate_speed",
+            )?,
+            piglet_client::client::required(
+                request.settling_time,
+                "AspiratePressureLldRequest",
+                "settling_time",
+            )?,
+            piglet_client::client::required(
+                request.mix_volume,
+                "AspiratePressureLldRequest",
+                "mix_volume",
+            )?,
+            piglet_client::client::required(
+                request.mix_cycles,
+                "AspiratePressureLldRequest",
+                "mix_cycles",
+            )?,
+            piglet_client::client::required(
+                request.mix_position,
+                "AspiratePressureLldRequest",
+                "mix_position",
+            )?,
+            piglet_client::client::required(
+                request.mix_follow_distance,
+                "AspiratePressureLldRequest",
+                "mix_follow_distance",
+            )?,
+            piglet_client::client::required(
+                request.mix_speed,
+                "AspiratePressureLldRequest",
+                "mix_speed",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_height,
+                "AspiratePressureLldRequest",
+                "tube_section_height",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_ratio,
+                "AspiratePressureLldRequest",
+                "tube_section_ratio",
+            )?,
+            piglet_client::client::required(
+                request.lld_mode,
+                "AspiratePressureLldRequest",
+                "lld_mode",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_steepness,
+                "AspiratePressureLldRequest",
+                "capacitive_lld_steepness",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_offset,
+                "AspiratePressureLldRequest",
+                "capacitive_lld_offset",
+            )?,
+            piglet_client::client::required(
+                request.pressure_lld_steepness,
+                "AspiratePressureLldRequest",
+                "pressure_lld_steepness",
+            )?,
+            piglet_client::client::required(
+                request.pressure_lld_offset,
+                "AspiratePressureLldRequest",
+                "pressure_lld_offset",
+            )?,
+            piglet_client::client::required(
+                request.seek_speed,
+                "AspiratePressureLldRequest",
+                "seek_speed",
+            )?,
+            piglet_client::client::required(
+                request.lld_height_difference,
+                "AspiratePressureLldRequest",
+                "lld_height_difference",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "AspiratePressureLldRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index,
+                "AspiratePressureLldRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "AspiratePressureLldRequest",
+                "recording_mode",
+            )?,
+            piglet_client::client::required(
+                request.measurement_id.as_deref(),
+                "AspiratePressureLldRequest",
+                "measurement_id",
+            )?,
+            piglet_client::client::required(
+                request.tip_is_filtered,
+                "AspiratePressureLldRequest",
+                "tip_is_filtered",
+            )?,
+        )
+        .await
+    }
+
     pub async fn aspirate(
         &self,
 
         aspirate_mode: i16,
-        aspirate_height: i32,
-        follow_depth: i32,
-        z_min_position: i32,
-        air_height: i32,
-        z_final: i32,
-        liquid_exit_speed: u32,
-        blowout_volume: u32,
-        prewet_volume: u32,
-        aspirate_volume: u32,
-        transport_air_volume: u32,
-        aspirate_speed: u32,
+        aspirate_height: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        air_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        blowout_volume: Microliters,
+        prewet_volume: Microliters,
+        aspirate_volume: Microliters,
+        transport_air_volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
         settling_time: u32,
-        mix_volume: u32,
+        mix_volume: Microliters,
         mix_cycles: u32,
-        mix_position: i32,
-        mix_follow_distance: i32,
-        mix_speed: u32,
-        tube_section_height: i32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
         tube_section_ratio: i32,
         bottom_search: bool,
         aspirate_offset: i32,
@@ -603,24 +1037,24 @@ impl NimbusCoreChannel {
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         aspirate_mode.serialize(&mut args);
-        aspirate_height.serialize(&mut args);
-        follow_depth.serialize(&mut args);
-        z_min_position.serialize(&mut args);
-        air_height.serialize(&mut args);
-        z_final.serialize(&mut args);
-        liquid_exit_speed.serialize(&mut args);
-        blowout_volume.serialize(&mut args);
-        prewet_volume.serialize(&mut args);
-        aspirate_volume.serialize(&mut args);
-        transport_air_volume.serialize(&mut args);
-        aspirate_speed.serialize(&mut args);
+        aspirate_height.to_wire::<i32>()?.serialize(&mut args);
+        follow_depth.to_wire::<i32>()?.serialize(&mut args);
+        z_min_position.to_wire::<i32>()?.serialize(&mut args);
+        air_height.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        liquid_exit_speed.to_wire::<u32>()?.serialize(&mut args);
+        blowout_volume.to_wire::<u32>()?.serialize(&mut args);
+        prewet_volume.to_wire::<u32>()?.serialize(&mut args);
+        aspirate_volume.to_wire::<u32>()?.serialize(&mut args);
+        transport_air_volume.to_wire::<u32>()?.serialize(&mut args);
+        aspirate_speed.to_wire::<u32>()?.serialize(&mut args);
         settling_time.serialize(&mut args);
-        mix_volume.serialize(&mut args);
+        mix_volume.to_wire::<u32>()?.serialize(&mut args);
         mix_cycles.serialize(&mut args);
-        mix_position.serialize(&mut args);
-        mix_follow_distance.serialize(&mut args);
-        mix_speed.serialize(&mut args);
-        tube_section_height.serialize(&mut args);
+        mix_position.to_wire::<i32>()?.serialize(&mut args);
+        mix_follow_distance.to_wire::<i32>()?.serialize(&mut args);
+        mix_speed.to_wire::<u32>()?.serialize(&mut args);
+        tube_section_height.to_wire::<i32>()?.serialize(&mut args);
         tube_section_ratio.serialize(&mut args);
         bottom_search.serialize(&mut args);
         aspirate_offset.serialize(&mut args);
@@ -629,7 +1063,9 @@ impl NimbusCoreChannel {
         recording_mode.serialize(&mut args);
         measurement_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 12, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  aspirate_mode: {:?}", aspirate_mode),
@@ -667,32 +1103,148 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn aspirate_with(&self, request: &AspirateRequest) -> Result<(), Error> {
+        self.aspirate(
+            piglet_client::client::required(
+                request.aspirate_mode,
+                "AspirateRequest",
+                "aspirate_mode",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_height,
+                "AspirateRequest",
+                "aspirate_height",
+            )?,
+            piglet_client::client::required(
+                request.follow_depth,
+                "AspirateRequest",
+                "follow_depth",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position,
+                "AspirateRequest",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(request.air_height, "AspirateRequest", "air_height")?,
+            piglet_client::client::required(request.z_final, "AspirateRequest", "z_final")?,
+            piglet_client::client::required(
+                request.liquid_exit_speed,
+                "AspirateRequest",
+                "liquid_exit_speed",
+            )?,
+            piglet_client::client::required(
+                request.blowout_volume,
+                "AspirateRequest",
+                "blowout_volume",
+            )?,
+            piglet_client::client::required(
+                request.prewet_volume,
+                "AspirateRequest",
+                "prewet_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_volume,
+                "AspirateRequest",
+                "aspirate_volume",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume,
+                "AspirateRequest",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_speed,
+                "AspirateRequest",
+                "aspirate_speed",
+            )?,
+            piglet_client::client::required(
+                request.settling_time,
+                "AspirateRequest",
+                "settling_time",
+            )?,
+            piglet_client::client::required(request.mix_volume, "AspirateRequest", "mix_volume")?,
+            piglet_client::client::required(request.mix_cycles, "AspirateRequest", "mix_cycles")?,
+            piglet_client::client::required(
+                request.mix_position,
+                "AspirateRequest",
+                "mix_position",
+            )?,
+            piglet_client::client::required(
+                request.mix_follow_distance,
+                "AspirateRequest",
+                "mix_follow_distance",
+            )?,
+            piglet_client::client::required(request.mix_speed, "AspirateRequest", "mix_speed")?,
+            piglet_client::client::required(
+                request.tube_section_height,
+                "AspirateRequest",
+                "tube_section_height",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_ratio,
+                "AspirateRequest",
+                "tube_section_ratio",
+            )?,
+            piglet_client::client::required(
+                request.bottom_search,
+                "AspirateRequest",
+                "bottom_search",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_offset,
+                "AspirateRequest",
+                "aspirate_offset",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "AspirateRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index,
+                "AspirateRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "AspirateRequest",
+                "recording_mode",
+            )?,
+            piglet_client::client::required(
+                request.measurement_id.as_deref(),
+                "AspirateRequest",
+                "measurement_id",
+            )?,
+        )
+        .await
+    }
+
     pub async fn dispense_lld(
         &self,
 
-        liquid_seek_height: i32,
-        submerge_depth: i32,
-        follow_depth: i32,
-        z_min_position: i32,
-        z_final: i32,
-        liquid_exit_speed: u32,
-        dispense_volume: u32,
-        stop_back_volume: u32,
-        transport_air_volume: u32,
-        dispense_speed: u32,
-        cutoff_speed: u32,
+        liquid_seek_height: Millimeters,
+        submerge_depth: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        dispense_volume: Microliters,
+        stop_back_volume: Microliters,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
         settling_time: u32,
-        mix_volume: u32,
+        mix_volume: Microliters,
         mix_cycles: u32,
-        mix_position: i32,
-        mix_follow_distance: i32,
-        mix_speed: u32,
-        tube_section_height: i32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
         tube_section_ratio: i32,
         capacitive_lld_steepness: u32,
         capacitive_lld_offset: u32,
@@ -702,24 +1254,24 @@ impl NimbusCoreChannel {
         measurement_id: &str,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        liquid_seek_height.serialize(&mut args);
-        submerge_depth.serialize(&mut args);
-        follow_depth.serialize(&mut args);
-        z_min_position.serialize(&mut args);
-        z_final.serialize(&mut args);
-        liquid_exit_speed.serialize(&mut args);
-        dispense_volume.serialize(&mut args);
-        stop_back_volume.serialize(&mut args);
-        transport_air_volume.serialize(&mut args);
-        dispense_speed.serialize(&mut args);
-        cutoff_speed.serialize(&mut args);
+        liquid_seek_height.to_wire::<i32>()?.serialize(&mut args);
+        submerge_depth.to_wire::<i32>()?.serialize(&mut args);
+        follow_depth.to_wire::<i32>()?.serialize(&mut args);
+        z_min_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        liquid_exit_speed.to_wire::<u32>()?.serialize(&mut args);
+        dispense_volume.to_wire::<u32>()?.serialize(&mut args);
+        stop_back_volume.to_wire::<u32>()?.serialize(&mut args);
+        transport_air_volume.to_wire::<u32>()?.serialize(&mut args);
+        dispense_speed.to_wire::<u32>()?.serialize(&mut args);
+        cutoff_speed.to_wire::<u32>()?.serialize(&mut args);
         settling_time.serialize(&mut args);
-        mix_volume.serialize(&mut args);
+        mix_volume.to_wire::<u32>()?.serialize(&mut args);
         mix_cycles.serialize(&mut args);
-        mix_position.serialize(&mut args);
-        mix_follow_distance.serialize(&mut args);
-        mix_speed.serialize(&mut args);
-        tube_section_height.serialize(&mut args);
+        mix_position.to_wire::<i32>()?.serialize(&mut args);
+        mix_follow_distance.to_wire::<i32>()?.serialize(&mut args);
+        mix_speed.to_wire::<u32>()?.serialize(&mut args);
+        tube_section_height.to_wire::<i32>()?.serialize(&mut args);
         tube_section_ratio.serialize(&mut args);
         capacitive_lld_steepness.serialize(&mut args);
         capacitive_lld_offset.serialize(&mut args);
@@ -728,7 +1280,9 @@ impl NimbusCoreChannel {
         recording_mode.serialize(&mut args);
         measurement_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 13, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 13, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  liquid_seek_height: {:?}", liquid_seek_height),
@@ -765,32 +1319,155 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn dispense_lld_with(&self, request: &DispenseLldRequest) -> Result<(), Error> {
+        self.dispense_lld(
+            piglet_client::client::required(
+                request.liquid_seek_height,
+                "DispenseLldRequest",
+                "liquid_seek_height",
+            )?,
+            piglet_client::client::required(
+                request.submerge_depth,
+                "DispenseLldRequest",
+                "submerge_depth",
+            )?,
+            piglet_client::client::required(
+                request.follow_depth,
+                "DispenseLldRequest",
+                "follow_depth",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position,
+                "DispenseLldRequest",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(request.z_final, "DispenseLldRequest", "z_final")?,
+            piglet_client::client::required(
+                request.liquid_exit_speed,
+                "DispenseLldRequest",
+                "liquid_exit_speed",
+            )?,
+            piglet_client::client::required(
+                request.dispense_volume,
+                "DispenseLldRequest",
+                "dispense_volume",
+            )?,
+            piglet_client::client::required(
+                request.stop_back_volume,
+                "DispenseLldRequest",
+                "stop_back_volume",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume,
+                "DispenseLldRequest",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_speed,
+                "DispenseLldRequest",
+                "dispense_speed",
+            )?,
+            piglet_client::client::required(
+                request.cutoff_speed,
+                "DispenseLldRequest",
+                "cutoff_speed",
+            )?,
+            piglet_client::client::required(
+                request.settling_time,
+                "DispenseLldRequest",
+                "settling_time",
+            )?,
+            piglet_client::client::required(
+                request.mix_volume,
+                "DispenseLldRequest",
+                "mix_volume",
+            )?,
+            piglet_client::client::required(
+                request.mix_cycles,
+                "DispenseLldRequest",
+                "mix_cycles",
+            )?,
+            piglet_client::client::required(
+                request.mix_position,
+                "DispenseLldRequest",
+                "mix_position",
+            )?,
+            piglet_client::client::required(
+                request.mix_follow_distance,
+                "DispenseLldRequest",
+                "mix_follow_distance",
+            )?,
+            piglet_client::client::required(request.mix_speed, "DispenseLldRequest", "mix_speed")?,
+            piglet_client::client::required(
+                request.tube_section_height,
+                "DispenseLldRequest",
+                "tube_section_height",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_ratio,
+                "DispenseLldRequest",
+                "tube_section_ratio",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_steepness,
+                "DispenseLldRequest",
+                "capacitive_lld_steepness",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_offset,
+                "DispenseLldRequest",
+                "capacitive_lld_offset",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "DispenseLldRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index,
+                "DispenseLldRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "DispenseLldRequest",
+                "recording_mode",
+            )?,
+            piglet_client::client::required(
+                request.measurement_id.as_deref(),
+                "DispenseLldRequest",
+                "measurement_id",
+            )?,
+        )
+        .await
+    }
+
     pub async fn dispense(
         &self,
 
-        dispense_height: i32,
-        follow_depth: i32,
-        z_min_position: i32,
-        air_height: i32,
-        z_final: i32,
-        liquid_exit_speed: u32,
-        dispense_volume: u32,
-        stop_back_volume: u32,
-        transport_air_volume: u32,
-        dispense_speed: u32,
-        cutoff_speed: u32,
+        dispense_height: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        air_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        dispense_volume: Microliters,
+        stop_back_volume: Microliters,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
         settling_time: u32,
-        mix_volume: u32,
+        mix_volume: Microliters,
         mix_cycles: u32,
-        mix_position: i32,
-        mix_follow_distance: i32,
-        mix_speed: u32,
-        tube_section_height: i32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
         tube_section_ratio: i32,
         bottom_search: bool,
         dispense_offset: i32,
@@ -800,24 +1477,24 @@ impl NimbusCoreChannel {
         measurement_id: &str,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        dispense_height.serialize(&mut args);
-        follow_depth.serialize(&mut args);
-        z_min_position.serialize(&mut args);
-        air_height.serialize(&mut args);
-        z_final.serialize(&mut args);
-        liquid_exit_speed.serialize(&mut args);
-        dispense_volume.serialize(&mut args);
-        stop_back_volume.serialize(&mut args);
-        transport_air_volume.serialize(&mut args);
-        dispense_speed.serialize(&mut args);
-        cutoff_speed.serialize(&mut args);
+        dispense_height.to_wire::<i32>()?.serialize(&mut args);
+        follow_depth.to_wire::<i32>()?.serialize(&mut args);
+        z_min_position.to_wire::<i32>()?.serialize(&mut args);
+        air_height.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        liquid_exit_speed.to_wire::<u32>()?.serialize(&mut args);
+        dispense_volume.to_wire::<u32>()?.serialize(&mut args);
+        stop_back_volume.to_wire::<u32>()?.serialize(&mut args);
+        transport_air_volume.to_wire::<u32>()?.serialize(&mut args);
+        dispense_speed.to_wire::<u32>()?.serialize(&mut args);
+        cutoff_speed.to_wire::<u32>()?.serialize(&mut args);
         settling_time.serialize(&mut args);
-        mix_volume.serialize(&mut args);
+        mix_volume.to_wire::<u32>()?.serialize(&mut args);
         mix_cycles.serialize(&mut args);
-        mix_position.serialize(&mut args);
-        mix_follow_distance.serialize(&mut args);
-        mix_speed.serialize(&mut args);
-        tube_section_height.serialize(&mut args);
+        mix_position.to_wire::<i32>()?.serialize(&mut args);
+        mix_follow_distance.to_wire::<i32>()?.serialize(&mut args);
+        mix_speed.to_wire::<u32>()?.serialize(&mut args);
+        tube_section_height.to_wire::<i32>()?.serialize(&mut args);
         tube_section_ratio.serialize(&mut args);
         bottom_search.serialize(&mut args);
         dispense_offset.serialize(&mut args);
@@ -826,7 +1503,9 @@ impl NimbusCoreChannel {
         recording_mode.serialize(&mut args);
         measurement_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 14, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 14, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  dispense_height: {:?}", dispense_height),
@@ -863,25 +1542,136 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn dispense_with(&self, request: &DispenseRequest) -> Result<(), Error> {
+        self.dispense(
+            piglet_client::client::required(
+                request.dispense_height,
+                "DispenseRequest",
+                "dispense_height",
+            )?,
+            piglet_client::client::required(
+                request.follow_depth,
+                "DispenseRequest",
+                "follow_depth",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position,
+                "DispenseRequest",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(request.air_height, "DispenseRequest", "air_height")?,
+            piglet_client::client::required(request.z_final, "DispenseRequest", "z_final")?,
+            piglet_client::client::required(
+                request.liquid_exit_speed,
+                "DispenseRequest",
+                "liquid_exit_speed",
+            )?,
+            piglet_client::client::required(
+                request.dispense_volume,
+                "DispenseRequest",
+                "dispense_volume",
+            )?,
+            piglet_client::client::required(
+                request.stop_back_volume,
+                "DispenseRequest",
+                "stop_back_volume",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume,
+                "DispenseRequest",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_speed,
+                "DispenseRequest",
+                "dispense_speed",
+            )?,
+            piglet_client::client::required(
+                request.cutoff_speed,
+                "DispenseRequest",
+                "cutoff_speed",
+            )?,
+            piglet_client::client::required(
+                request.settling_time,
+                "DispenseRequest",
+                "settling_time",
+            )?,
+            piglet_client::client::required(request.mix_volume, "DispenseRequest", "mix_volume")?,
+            piglet_client::client::required(request.mix_cycles, "DispenseRequest", "mix_cycles")?,
+            piglet_client::client::required(
+                request.mix_position,
+                "DispenseRequest",
+                "mix_position",
+            )?,
+            piglet_client::client::required(
+                request.mix_follow_distance,
+                "DispenseRequest",
+                "mix_follow_distance",
+            )?,
+            piglet_client::client::required(request.mix_speed, "DispenseRequest", "mix_speed")?,
+            piglet_client::client::required(
+                request.tube_section_height,
+                "DispenseRequest",
+                "tube_section_height",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_ratio,
+                "DispenseRequest",
+                "tube_section_ratio",
+            )?,
+            piglet_client::client::required(
+                request.bottom_search,
+                "DispenseRequest",
+                "bottom_search",
+            )?,
+            piglet_client::client::required(
+                request.dispense_offset,
+                "DispenseRequest",
+                "dispense_offset",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "DispenseRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index,
+                "DispenseRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "DispenseRequest",
+                "recording_mode",
+            )?,
+            piglet_client::client::required(
+                request.measurement_id.as_deref(),
+                "DispenseRequest",
+                "measurement_id",
+            )?,
+        )
+        .await
+    }
+
     pub async fn empty_tip(
         &self,
 
-        dispense_height: i32,
-        follow_depth: i32,
-        z_min_position: i32,
-        air_height: i32,
-        z_final: i32,
-        liquid_exit_speed: u32,
-        transport_air_volume: u32,
-        dispense_speed: u32,
-        cutoff_speed: u32,
+        dispense_height: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        air_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
         settling_time: u32,
-        tube_section_height: i32,
+        tube_section_height: Millimeters,
         tube_section_ratio: i32,
         tadm_enabled: bool,
         limit_curve_index: u32,
@@ -889,24 +1679,26 @@ impl NimbusCoreChannel {
         measurement_id: &str,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        dispense_height.serialize(&mut args);
-        follow_depth.serialize(&mut args);
-        z_min_position.serialize(&mut args);
-        air_height.serialize(&mut args);
-        z_final.serialize(&mut args);
-        liquid_exit_speed.serialize(&mut args);
-        transport_air_volume.serialize(&mut args);
-        dispense_speed.serialize(&mut args);
-        cutoff_speed.serialize(&mut args);
+        dispense_height.to_wire::<i32>()?.serialize(&mut args);
+        follow_depth.to_wire::<i32>()?.serialize(&mut args);
+        z_min_position.to_wire::<i32>()?.serialize(&mut args);
+        air_height.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        liquid_exit_speed.to_wire::<u32>()?.serialize(&mut args);
+        transport_air_volume.to_wire::<u32>()?.serialize(&mut args);
+        dispense_speed.to_wire::<u32>()?.serialize(&mut args);
+        cutoff_speed.to_wire::<u32>()?.serialize(&mut args);
         settling_time.serialize(&mut args);
-        tube_section_height.serialize(&mut args);
+        tube_section_height.to_wire::<i32>()?.serialize(&mut args);
         tube_section_ratio.serialize(&mut args);
         tadm_enabled.serialize(&mut args);
         limit_curve_index.serialize(&mut args);
         recording_mode.serialize(&mut args);
         measurement_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 15, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 15, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  dispense_height: {:?}", dispense_height),
@@ -934,20 +1726,100 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn empty_tip_with(&self, request: &EmptyTipRequest) -> Result<(), Error> {
+        self.empty_tip(
+            piglet_client::client::required(
+                request.dispense_height,
+                "EmptyTipRequest",
+                "dispense_height",
+            )?,
+            piglet_client::client::required(
+                request.follow_depth,
+                "EmptyTipRequest",
+                "follow_depth",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position,
+                "EmptyTipRequest",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(request.air_height, "EmptyTipRequest", "air_height")?,
+            piglet_client::client::required(request.z_final, "EmptyTipRequest", "z_final")?,
+            piglet_client::client::required(
+                request.liquid_exit_speed,
+                "EmptyTipRequest",
+                "liquid_exit_speed",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume,
+                "EmptyTipRequest",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_speed,
+                "EmptyTipRequest",
+                "dispense_speed",
+            )?,
+            piglet_client::client::required(
+                request.cutoff_speed,
+                "EmptyTipRequest",
+                "cutoff_speed",
+            )?,
+            piglet_client::client::required(
+                request.settling_time,
+                "EmptyTipRequest",
+                "settling_time",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_height,
+                "EmptyTipRequest",
+                "tube_section_height",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_ratio,
+                "EmptyTipRequest",
+                "tube_section_ratio",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "EmptyTipRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index,
+                "EmptyTipRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "EmptyTipRequest",
+                "recording_mode",
+            )?,
+            piglet_client::client::required(
+                request.measurement_id.as_deref(),
+                "EmptyTipRequest",
+                "measurement_id",
+            )?,
+        )
+        .await
+    }
+
     pub async fn y_pre_initialize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 16, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 16, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.YPreInitialize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -955,21 +1827,25 @@ impl NimbusCoreChannel {
     pub async fn y_initialize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 17, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 17, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.YInitialize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn y_move_relative(&self, distance: i32) -> Result<(), Error> {
+    pub async fn y_move_relative(&self, distance: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        distance.serialize(&mut args);
+        distance.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 18, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 18, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  distance: {:?}", distance)];
                 format!(
@@ -980,7 +1856,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -988,16 +1864,18 @@ impl NimbusCoreChannel {
     pub async fn y_move_relative_speed(
         &self,
 
-        distance: i32,
+        distance: Millimeters,
         acceleration: u32,
         velocity: u32,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        distance.serialize(&mut args);
+        distance.to_wire::<i32>()?.serialize(&mut args);
         acceleration.serialize(&mut args);
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 19, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 19, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  distance: {:?}", distance),
@@ -1012,16 +1890,18 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn y_move_absolute(&self, position: i32) -> Result<(), Error> {
+    pub async fn y_move_absolute(&self, position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 20, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 20, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -1032,7 +1912,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1040,16 +1920,18 @@ impl NimbusCoreChannel {
     pub async fn y_move_absolute_speed(
         &self,
 
-        position: i32,
+        position: Millimeters,
         acceleration: u32,
         velocity: u32,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         acceleration.serialize(&mut args);
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 21, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 21, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  position: {:?}", position),
@@ -1064,17 +1946,19 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn y_seek_lld(&self, position: i32, velocity: u32) -> Result<(), Error> {
+    pub async fn y_seek_lld(&self, position: Millimeters, velocity: u32) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 22, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 22, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  position: {:?}", position),
@@ -1088,7 +1972,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1096,12 +1980,14 @@ impl NimbusCoreChannel {
     pub async fn y_move_negative_stall(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 23, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 23, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.YMoveNegativeStall()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1109,16 +1995,18 @@ impl NimbusCoreChannel {
     pub async fn y_move_absolute_gripper(
         &self,
 
-        position: i32,
+        position: Millimeters,
         acceleration: u32,
         velocity: u32,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         acceleration.serialize(&mut args);
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 24, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 24, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  position: {:?}", position),
@@ -1133,7 +2021,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1141,12 +2029,14 @@ impl NimbusCoreChannel {
     pub async fn z_initialize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 25, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 25, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.ZInitialize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1154,21 +2044,25 @@ impl NimbusCoreChannel {
     pub async fn z_move_safe(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 26, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 26, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.ZMoveSafe()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn z_move_relative(&self, distance: i32) -> Result<(), Error> {
+    pub async fn z_move_relative(&self, distance: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        distance.serialize(&mut args);
+        distance.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 27, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 27, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  distance: {:?}", distance)];
                 format!(
@@ -1179,7 +2073,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1187,16 +2081,18 @@ impl NimbusCoreChannel {
     pub async fn z_move_relative_speed(
         &self,
 
-        distance: i32,
+        distance: Millimeters,
         acceleration: u32,
         velocity: u32,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        distance.serialize(&mut args);
+        distance.to_wire::<i32>()?.serialize(&mut args);
         acceleration.serialize(&mut args);
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 28, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 28, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  distance: {:?}", distance),
@@ -1211,16 +2107,18 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn z_move_absolute(&self, position: i32) -> Result<(), Error> {
+    pub async fn z_move_absolute(&self, position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 29, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 29, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -1231,7 +2129,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1239,16 +2137,18 @@ impl NimbusCoreChannel {
     pub async fn z_move_absolute_speed(
         &self,
 
-        position: i32,
+        position: Millimeters,
         acceleration: u32,
         velocity: u32,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         acceleration.serialize(&mut args);
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 30, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 30, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  position: {:?}", position),
@@ -1263,16 +2163,18 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn z_move_traverse(&self, position: i32) -> Result<(), Error> {
+    pub async fn z_move_traverse(&self, position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 31, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 31, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -1283,7 +2185,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1291,16 +2193,18 @@ impl NimbusCoreChannel {
     pub async fn z_seek_lld(
         &self,
 
-        position: i32,
-        min_position: i32,
+        position: Millimeters,
+        min_position: Millimeters,
         velocity: u32,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
-        min_position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
+        min_position.to_wire::<i32>()?.serialize(&mut args);
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 32, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 32, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  position: {:?}", position),
@@ -1315,7 +2219,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1323,16 +2227,18 @@ impl NimbusCoreChannel {
     pub async fn z_move_absolute_gripper(
         &self,
 
-        position: i32,
+        position: Millimeters,
         acceleration: u32,
         velocity: u32,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         acceleration.serialize(&mut args);
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 33, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 33, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  position: {:?}", position),
@@ -1347,7 +2253,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1355,12 +2261,14 @@ impl NimbusCoreChannel {
     pub async fn dispense_initialize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 34, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 34, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.DispenseInitialize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1368,21 +2276,25 @@ impl NimbusCoreChannel {
     pub async fn dispense_empty(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 35, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 35, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.DispenseEmpty()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn dispense_move_relative(&self, distance: i32) -> Result<(), Error> {
+    pub async fn dispense_move_relative(&self, distance: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        distance.serialize(&mut args);
+        distance.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 36, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 36, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  distance: {:?}", distance)];
                 format!(
@@ -1393,7 +2305,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1401,12 +2313,14 @@ impl NimbusCoreChannel {
     pub async fn squeeze_initialize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 37, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 37, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.SqueezeInitialize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1414,12 +2328,14 @@ impl NimbusCoreChannel {
     pub async fn squeeze_on(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 38, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 38, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.SqueezeOn()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1427,12 +2343,14 @@ impl NimbusCoreChannel {
     pub async fn squeeze_off(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 39, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 39, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.SqueezeOff()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1440,12 +2358,14 @@ impl NimbusCoreChannel {
     pub async fn lld_on(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 40, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 40, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.LldOn()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1453,12 +2373,14 @@ impl NimbusCoreChannel {
     pub async fn lld_off(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 41, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 41, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.LldOff()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1467,7 +2389,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 42, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 42, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -1478,7 +2402,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1486,12 +2410,14 @@ impl NimbusCoreChannel {
     pub async fn synchronize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 43, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 43, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.Synchronize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1499,12 +2425,14 @@ impl NimbusCoreChannel {
     pub async fn is_initialized(&self) -> Result</* initialized= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 44, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 44, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.IsInitialized()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let initialized = bool::deserialize(&mut stream)?;
         Ok(initialized)
@@ -1513,12 +2441,14 @@ impl NimbusCoreChannel {
     pub async fn is_tip_present(&self) -> Result</* present= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 45, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 45, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.IsTipPresent()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let present = bool::deserialize(&mut stream)?;
         Ok(present)
@@ -1527,12 +2457,14 @@ impl NimbusCoreChannel {
     pub async fn get_lld_status(&self) -> Result</* status= */ i16, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 46, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 46, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetLldStatus()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let status = i16::deserialize(&mut stream)?;
         Ok(status)
@@ -1541,40 +2473,46 @@ impl NimbusCoreChannel {
     pub async fn get_sensor_status(&self) -> Result</* status= */ i16, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 47, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 47, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetSensorStatus()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let status = i16::deserialize(&mut stream)?;
         Ok(status)
     }
 
-    pub async fn get_y_position(&self) -> Result</* position= */ i32, Error> {
+    pub async fn get_y_position(&self) -> Result</* position= */ Millimeters, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 48, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 48, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetYPosition()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let position = i32::deserialize(&mut stream)?;
+        let position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
         Ok(position)
     }
 
     pub async fn get_y_home_offset(&self) -> Result</* home_offset= */ i32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 49, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 49, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetYHomeOffset()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let home_offset = i32::deserialize(&mut stream)?;
         Ok(home_offset)
@@ -1584,7 +2522,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         home_offset.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 50, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 50, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  home_offset: {:?}", home_offset)];
                 format!(
@@ -1595,34 +2535,38 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn get_z_position(&self) -> Result</* position= */ i32, Error> {
+    pub async fn get_z_position(&self) -> Result</* position= */ Millimeters, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 51, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 51, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetZPosition()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let position = i32::deserialize(&mut stream)?;
+        let position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
         Ok(position)
     }
 
     pub async fn get_z_home_offset(&self) -> Result</* home_offset= */ i32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 52, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 52, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetZHomeOffset()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let home_offset = i32::deserialize(&mut stream)?;
         Ok(home_offset)
@@ -1632,7 +2576,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         home_offset.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 53, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 53, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  home_offset: {:?}", home_offset)];
                 format!(
@@ -1643,7 +2589,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1651,12 +2597,14 @@ impl NimbusCoreChannel {
     pub async fn calibrate_position_reset(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 54, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 54, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.CalibratePositionReset()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1664,12 +2612,14 @@ impl NimbusCoreChannel {
     pub async fn calibrate_lld(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 55, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 55, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.CalibrateLld()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1677,14 +2627,16 @@ impl NimbusCoreChannel {
     pub async fn calibrate_squeeze(
         &self,
 
-        z_start_position: i32,
-        z_stop_position: i32,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 56, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 56, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  z_start_position: {:?}", z_start_position),
@@ -1698,7 +2650,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1706,20 +2658,22 @@ impl NimbusCoreChannel {
     pub async fn calibrate_tip_height(
         &self,
 
-        z_start_position: i32,
-        z_stop_position: i32,
-        z_final: i32,
-        volume: u32,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
         collet_check: i16,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
-        z_final.serialize(&mut args);
-        volume.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        volume.to_wire::<u32>()?.serialize(&mut args);
         collet_check.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 57, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 57, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  z_start_position: {:?}", z_start_position),
@@ -1736,7 +2690,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1744,20 +2698,22 @@ impl NimbusCoreChannel {
     pub async fn calibrate_dispense_check(
         &self,
 
-        z_start_position: i32,
-        z_stop_position: i32,
-        z_final: i32,
-        volume: u32,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
         collet_check: i16,
     ) -> Result</* steps= */ i32, Error> {
         let mut args = BytesMut::new();
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
-        z_final.serialize(&mut args);
-        volume.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        volume.to_wire::<u32>()?.serialize(&mut args);
         collet_check.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 58, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 58, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  z_start_position: {:?}", z_start_position),
@@ -1774,7 +2730,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let steps = i32::deserialize(&mut stream)?;
         Ok(steps)
@@ -1790,7 +2746,9 @@ impl NimbusCoreChannel {
         direction.serialize(&mut args);
         index.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 59, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 59, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  direction: {:?}", direction),
@@ -1804,7 +2762,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let result = String::deserialize(&mut stream)?;
         Ok(result)
@@ -1813,12 +2771,14 @@ impl NimbusCoreChannel {
     pub async fn calibrate_touchoff(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 60, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 60, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.CalibrateTouchoff()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1826,12 +2786,14 @@ impl NimbusCoreChannel {
     pub async fn erase_limit_curves(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 61, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 61, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.EraseLimitCurves()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1852,7 +2814,9 @@ impl NimbusCoreChannel {
         upper_limit_x.as_ref().serialize(&mut args);
         upper_limit_y.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 62, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 62, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  name: {:?}", name),
@@ -1869,7 +2833,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let index = u32::deserialize(&mut stream)?;
         Ok(CreateLimitCurveReply { index })
@@ -1878,12 +2842,14 @@ impl NimbusCoreChannel {
     pub async fn reset_tadm_fifo(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 63, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 63, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.ResetTadmFifo()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1891,12 +2857,14 @@ impl NimbusCoreChannel {
     pub async fn retrieve_tadm_data(&self) -> Result<RetrieveTadmDataReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 64, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 64, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.RetrieveTadmData()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let tadm_data = Vec::<i16>::deserialize(&mut stream)?;
         Ok(RetrieveTadmDataReply { tadm_data })
@@ -1905,12 +2873,14 @@ impl NimbusCoreChannel {
     pub async fn get_limit_curve_names(&self) -> Result<GetLimitCurveNamesReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 65, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 65, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetLimitCurveNames()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let names = String::deserialize(&mut stream)?;
         Ok(GetLimitCurveNamesReply { names })
@@ -1920,7 +2890,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         name.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 66, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 66, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  name: {:?}", name)];
                 format!(
@@ -1931,7 +2903,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let index = u32::deserialize(&mut stream)?;
         let lower_limits = u16::deserialize(&mut stream)?;
@@ -1947,7 +2919,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         enable.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 67, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 67, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  enable: {:?}", enable)];
                 format!(
@@ -1958,7 +2932,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1966,12 +2940,14 @@ impl NimbusCoreChannel {
     pub async fn get_tadm_enable(&self) -> Result</* enable= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 68, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 68, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetTADMEnable()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let enable = bool::deserialize(&mut stream)?;
         Ok(enable)
@@ -1989,7 +2965,9 @@ impl NimbusCoreChannel {
         minimum_pressure_difference.serialize(&mut args);
         maximum_pressure_difference.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 69, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 69, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  pressure_threshold: {:?}", pressure_threshold),
@@ -2010,7 +2988,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2018,12 +2996,14 @@ impl NimbusCoreChannel {
     pub async fn disable_mad(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 70, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 70, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.DisableMAD()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2031,12 +3011,14 @@ impl NimbusCoreChannel {
     pub async fn enable_adc(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 71, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 71, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.EnableADC()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2044,21 +3026,25 @@ impl NimbusCoreChannel {
     pub async fn disable_adc(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 72, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 72, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.DisableADC()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn set_z_default_speed(&self, speed: i32) -> Result<(), Error> {
+    pub async fn set_z_default_speed(&self, speed: MmPerSecond) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        speed.serialize(&mut args);
+        speed.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 73, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 73, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  speed: {:?}", speed)];
                 format!(
@@ -2069,40 +3055,44 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn get_z_default_speed(&self) -> Result</* speed= */ i32, Error> {
+    pub async fn get_z_default_speed(&self) -> Result</* speed= */ MmPerSecond, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 74, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 74, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetZDefaultSpeed()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let speed = i32::deserialize(&mut stream)?;
+        let speed = MmPerSecond::from_wire(i32::deserialize(&mut stream)?);
         Ok(speed)
     }
 
     pub async fn z_seek_obstacle(
         &self,
 
-        z_position: i32,
-        z_min_position: i32,
-        z_final_position: i32,
+        z_position: Millimeters,
+        z_min_position: Millimeters,
+        z_final_position: Millimeters,
         z_velocity: u32,
     ) -> Result</* obstacle_detected= */ bool, Error> {
         let mut args = BytesMut::new();
-        z_position.serialize(&mut args);
-        z_min_position.serialize(&mut args);
-        z_final_position.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        z_min_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final_position.to_wire::<i32>()?.serialize(&mut args);
         z_velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 75, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 75, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  z_position: {:?}", z_position),
@@ -2118,7 +3108,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let obstacle_detected = bool::deserialize(&mut stream)?;
         Ok(obstacle_detected)
@@ -2127,12 +3117,14 @@ impl NimbusCoreChannel {
     pub async fn get_pressure(&self) -> Result</* pressure= */ i16, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 76, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 76, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetPressure()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let pressure = i16::deserialize(&mut stream)?;
         Ok(pressure)
@@ -2141,12 +3133,14 @@ impl NimbusCoreChannel {
     pub async fn get_y_resolution(&self) -> Result</* resolution= */ i32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 77, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 77, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetYResolution()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let resolution = i32::deserialize(&mut stream)?;
         Ok(resolution)
@@ -2156,7 +3150,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         resolution.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 78, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 78, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  resolution: {:?}", resolution)];
                 format!(
@@ -2167,7 +3163,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2176,7 +3172,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         mode.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 79, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 79, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  mode: {:?}", mode)];
                 format!(
@@ -2187,7 +3185,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2196,7 +3194,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         gain.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 80, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 80, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  gain: {:?}", gain)];
                 format!(
@@ -2207,7 +3207,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2216,7 +3216,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         offset.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 81, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 81, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  offset: {:?}", offset)];
                 format!(
@@ -2227,7 +3229,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2236,7 +3238,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         shift.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 82, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 82, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  shift: {:?}", shift)];
                 format!(
@@ -2247,7 +3251,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2256,7 +3260,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         data_register.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 83, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 83, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  data_register: {:?}", data_register)];
                 format!(
@@ -2267,7 +3273,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2276,7 +3282,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         data_register.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 84, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 84, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  data_register: {:?}", data_register)];
                 format!(
@@ -2287,7 +3295,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2295,24 +3303,26 @@ impl NimbusCoreChannel {
     pub async fn leak_check(
         &self,
 
-        z_start_position: i32,
-        z_stop_position: i32,
-        z_final: i32,
-        tip_volume: u32,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
         collet_check: i16,
         time: u32,
         test_type: bool,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
-        z_final.serialize(&mut args);
-        tip_volume.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        tip_volume.to_wire::<u32>()?.serialize(&mut args);
         collet_check.serialize(&mut args);
         time.serialize(&mut args);
         test_type.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 85, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 85, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  z_start_position: {:?}", z_start_position),
@@ -2331,7 +3341,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2340,7 +3350,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         index.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 86, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 86, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  index: {:?}", index)];
                 format!(
@@ -2351,7 +3363,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let enabled = bool::deserialize(&mut stream)?;
         Ok(GetConfigurationReply { enabled })
@@ -2362,7 +3374,9 @@ impl NimbusCoreChannel {
         index.serialize(&mut args);
         enable.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 87, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 87, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  index: {:?}", index),
@@ -2376,7 +3390,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2384,12 +3398,14 @@ impl NimbusCoreChannel {
     pub async fn get_potentiometer_data(&self) -> Result<GetPotentiometerDataReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 88, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 88, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetPotentiometerData()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let gain = i16::deserialize(&mut stream)?;
         let offset = i16::deserialize(&mut stream)?;
@@ -2399,12 +3415,14 @@ impl NimbusCoreChannel {
     pub async fn get_pressure_sensor_ad(&self) -> Result<GetPressureSensorADReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 89, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 89, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetPressureSensorAD()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let pressure_lld = i16::deserialize(&mut stream)?;
         let tadm = i16::deserialize(&mut stream)?;
@@ -2414,12 +3432,14 @@ impl NimbusCoreChannel {
     pub async fn calibrate_tadm_offset(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 90, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 90, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.CalibrateTadmOffset()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2427,11 +3447,11 @@ impl NimbusCoreChannel {
     pub async fn prepare_on_the_fly_dispense(
         &self,
 
-        volume: u32,
-        stop_back_volume: u32,
-        transport_air_volume: u32,
-        dispense_speed: u32,
-        cutoff_speed: u32,
+        volume: Microliters,
+        stop_back_volume: Microliters,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
         dispense_pattern: &str,
         tadm_enabled: bool,
         limit_curve_index: u32,
@@ -2439,18 +3459,20 @@ impl NimbusCoreChannel {
         measurement_id: &str,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        volume.serialize(&mut args);
-        stop_back_volume.serialize(&mut args);
-        transport_air_volume.serialize(&mut args);
-        dispense_speed.serialize(&mut args);
-        cutoff_speed.serialize(&mut args);
+        volume.to_wire::<u32>()?.serialize(&mut args);
+        stop_back_volume.to_wire::<u32>()?.serialize(&mut args);
+        transport_air_volume.to_wire::<u32>()?.serialize(&mut args);
+        dispense_speed.to_wire::<u32>()?.serialize(&mut args);
+        cutoff_speed.to_wire::<u32>()?.serialize(&mut args);
         dispense_pattern.serialize(&mut args);
         tadm_enabled.serialize(&mut args);
         limit_curve_index.serialize(&mut args);
         recording_mode.serialize(&mut args);
         measurement_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 91, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 91, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  volume: {:?}", volume),
@@ -2472,20 +3494,81 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn prepare_on_the_fly_dispense_with(
+        &self,
+        request: &PrepareOnTheFlyDispenseRequest,
+    ) -> Result<(), Error> {
+        self.prepare_on_the_fly_dispense(
+            piglet_client::client::required(
+                request.volume,
+                "PrepareOnTheFlyDispenseRequest",
+                "volume",
+            )?,
+            piglet_client::client::required(
+                request.stop_back_volume,
+                "PrepareOnTheFlyDispenseRequest",
+                "stop_back_volume",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume,
+                "PrepareOnTheFlyDispenseRequest",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_speed,
+                "PrepareOnTheFlyDispenseRequest",
+                "dispense_speed",
+            )?,
+            piglet_client::client::required(
+                request.cutoff_speed,
+                "PrepareOnTheFlyDispenseRequest",
+                "cutoff_speed",
+            )?,
+            piglet_client::client::required(
+                request.dispense_pattern.as_deref(),
+                "PrepareOnTheFlyDispenseRequest",
+                "dispense_pattern",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "PrepareOnTheFlyDispenseRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index,
+                "PrepareOnTheFlyDispenseRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "PrepareOnTheFlyDispenseRequest",
+                "recording_mode",
+            )?,
+            piglet_client::client::required(
+                request.measurement_id.as_deref(),
+                "PrepareOnTheFlyDispenseRequest",
+                "measurement_id",
+            )?,
+        )
+        .await
+    }
+
     pub async fn start_on_the_fly_dispense(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 92, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 92, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.StartOnTheFlyDispense()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2493,12 +3576,14 @@ impl NimbusCoreChannel {
     pub async fn stop_on_the_fly_dispense(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 93, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 93, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.StopOnTheFlyDispense()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2508,23 +3593,32 @@ impl NimbusCoreChannel {
     ) -> Result<ReadOnTheFlyDispenseErrorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 94, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 94, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.ReadOnTheFlyDispenseErrors()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let status = String::deserialize(&mut stream)?;
         Ok(ReadOnTheFlyDispenseErrorsReply { status })
     }
 
-    pub async fn dispenser_aspirate(&self, volume: u32, aspirate_speed: u32) -> Result<(), Error> {
+    pub async fn dispenser_aspirate(
+        &self,
+
+        volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
+    ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        volume.serialize(&mut args);
-        aspirate_speed.serialize(&mut args);
+        volume.to_wire::<u32>()?.serialize(&mut args);
+        aspirate_speed.to_wire::<u32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 95, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 95, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  volume: {:?}", volume),
@@ -2538,7 +3632,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2546,18 +3640,20 @@ impl NimbusCoreChannel {
     pub async fn dispenser_dispense(
         &self,
 
-        volume: u32,
-        stop_back_volume: u32,
-        dispense_speed: u32,
-        cutoff_speed: u32,
+        volume: Microliters,
+        stop_back_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        volume.serialize(&mut args);
-        stop_back_volume.serialize(&mut args);
-        dispense_speed.serialize(&mut args);
-        cutoff_speed.serialize(&mut args);
+        volume.to_wire::<u32>()?.serialize(&mut args);
+        stop_back_volume.to_wire::<u32>()?.serialize(&mut args);
+        dispense_speed.to_wire::<u32>()?.serialize(&mut args);
+        cutoff_speed.to_wire::<u32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 96, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 96, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  volume: {:?}", volume),
@@ -2573,55 +3669,61 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn get_current_dispenser_volume(&self) -> Result</* volume= */ u32, Error> {
+    pub async fn get_current_dispenser_volume(&self) -> Result</* volume= */ Microliters, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 97, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 97, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetCurrentDispenserVolume()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let volume = u32::deserialize(&mut stream)?;
+        let volume = Microliters::from_wire(u32::deserialize(&mut stream)?);
         Ok(volume)
     }
 
-    pub async fn get_liquid_height(&self) -> Result</* liquid_height= */ i32, Error> {
+    pub async fn get_liquid_height(&self) -> Result</* liquid_height= */ Millimeters, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 98, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 98, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.GetLiquidHeight()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let liquid_height = i32::deserialize(&mut stream)?;
+        let liquid_height = Millimeters::from_wire(i32::deserialize(&mut stream)?);
         Ok(liquid_height)
     }
 
     pub async fn z_seek_obstacle_position(
         &self,
 
-        z_position: i32,
-        z_min_position: i32,
-        z_final_position: i32,
+        z_position: Millimeters,
+        z_min_position: Millimeters,
+        z_final_position: Millimeters,
         z_velocity: u32,
     ) -> Result<ZSeekObstaclePositionReply, Error> {
         let mut args = BytesMut::new();
-        z_position.serialize(&mut args);
-        z_min_position.serialize(&mut args);
-        z_final_position.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        z_min_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final_position.to_wire::<i32>()?.serialize(&mut args);
         z_velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 99, args.freeze()).await,
-            || {
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 99, args.freeze(), self.timeout)
+                .await,
+            || {
                 let parameters = vec![
                     format!("  z_position: {:?}", z_position),
                     format!("  z_min_position: {:?}", z_min_position),
@@ -2636,9 +3738,9 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
-        let position = i32::deserialize(&mut stream)?;
+        let position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
         let obstacle_detected = bool::deserialize(&mut stream)?;
         Ok(ZSeekObstaclePositionReply {
             position,
@@ -2649,23 +3751,23 @@ impl NimbusCoreChannel {
     pub async fn z_seek_lld_position(
         &self,
 
-        z_position: i32,
-        z_min_position: i32,
-        z_final_position: i32,
+        z_position: Millimeters,
+        z_min_position: Millimeters,
+        z_final_position: Millimeters,
         z_velocity: u32,
         capacitive_lld_steepness: u32,
         capacitive_lld_offset: u32,
     ) -> Result<ZSeekLldPositionReply, Error> {
         let mut args = BytesMut::new();
-        z_position.serialize(&mut args);
-        z_min_position.serialize(&mut args);
-        z_final_position.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
+        z_min_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final_position.to_wire::<i32>()?.serialize(&mut args);
         z_velocity.serialize(&mut args);
         capacitive_lld_steepness.serialize(&mut args);
         capacitive_lld_offset.serialize(&mut args);
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 100, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 100, args.freeze(), self.timeout)
                 .await,
             || {
                 let parameters = vec![
@@ -2684,9 +3786,9 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
-        let position = i32::deserialize(&mut stream)?;
+        let position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
         let lld_detected = bool::deserialize(&mut stream)?;
         Ok(ZSeekLldPositionReply {
             position,
@@ -2698,13 +3800,13 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 101, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 101, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannel.CalibrateSqueezeCheckTorque()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2713,13 +3815,13 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 102, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 102, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannel.CalibrateSqueezePosition()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2729,7 +3831,7 @@ impl NimbusCoreChannel {
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 103, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 103, args.freeze(), self.timeout)
                 .await,
             || {
                 let parameters = vec![format!("  velocity: {:?}", velocity)];
@@ -2741,7 +3843,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2750,13 +3852,13 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 0, 104, args.freeze())
+                .act_with_timeout(&self.address, 1, 0, 104, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannel.GetZLiquidSeekSpeed()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let velocity = u32::deserialize(&mut stream)?;
         Ok(velocity)
@@ -2765,19 +3867,21 @@ impl NimbusCoreChannel {
     pub async fn z_move_absolute_2_speed(
         &self,
 
-        position: i32,
-        speed_switching_position: i32,
+        position: Millimeters,
+        speed_switching_position: Millimeters,
         velocity: u32,
         lower_section_velocity: u32,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
-        speed_switching_position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
+        speed_switching_position
+            .to_wire::<i32>()?
+            .serialize(&mut args);
         velocity.serialize(&mut args);
         lower_section_velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 105, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 105, args.freeze(), self.timeout)
                 .await,
             || {
                 let parameters = vec![
@@ -2794,7 +3898,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2803,13 +3907,13 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 106, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 106, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannel.ZServoOff()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2818,13 +3922,13 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 107, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 107, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannel.YServoOff()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2833,13 +3937,13 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 108, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 108, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannel.SaveCounters()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2848,13 +3952,13 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 0, 109, args.freeze())
+                .act_with_timeout(&self.address, 1, 0, 109, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannel.GetCalibrationValues()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let dispense_drive = i16::deserialize(&mut stream)?;
         let squeeze_drive = i16::deserialize(&mut stream)?;
@@ -2874,13 +3978,13 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 0, 110, args.freeze())
+                .act_with_timeout(&self.address, 1, 0, 110, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannel.GetExtendedCalibrationValues()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let tip_type_adjustment = i16::deserialize(&mut stream)?;
         let pressure_conversion = i16::deserialize(&mut stream)?;
@@ -2898,13 +4002,13 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 0, 111, args.freeze())
+                .act_with_timeout(&self.address, 1, 0, 111, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannel.GetCounters()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let tip_pickup_counter = u32::deserialize(&mut stream)?;
         let tip_eject_counter = u32::deserialize(&mut stream)?;
@@ -2922,13 +4026,13 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 0, 112, args.freeze())
+                .act_with_timeout(&self.address, 1, 0, 112, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannel.GetTechnicalInfo()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let channel_info = String::deserialize(&mut stream)?;
         Ok(channel_info)
@@ -2949,7 +4053,7 @@ impl NimbusCoreChannel {
         dispense_counter.serialize(&mut args);
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 113, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 113, args.freeze(), self.timeout)
                 .await,
             || {
                 let parameters = vec![
@@ -2966,7 +4070,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2980,7 +4084,7 @@ impl NimbusCoreChannel {
         seconds.serialize(&mut args);
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 114, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 114, args.freeze(), self.timeout)
                 .await,
             || {
                 let parameters = vec![format!("  seconds: {:?}", seconds)];
@@ -2992,7 +4096,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let detects_per_minute = u16::deserialize(&mut stream)?;
         Ok(detects_per_minute)
@@ -3002,22 +4106,22 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 0, 115, args.freeze())
+                .act_with_timeout(&self.address, 1, 0, 115, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannel.GetPositions()".to_string(),
         )?;
 
         if count != 8 {
-            return Err(ConnectionError(anyhow!("Expected 8 values, not {}", count)));
-        }
-        let y_position = i32::deserialize(&mut stream)?;
-        let y_encoder_position = i32::deserialize(&mut stream)?;
-        let z_position = i32::deserialize(&mut stream)?;
-        let z_encoder_position = i32::deserialize(&mut stream)?;
-        let d_position = i32::deserialize(&mut stream)?;
-        let d_encoder_position = i32::deserialize(&mut stream)?;
-        let s_position = i32::deserialize(&mut stream)?;
-        let s_encoder_position = i32::deserialize(&mut stream)?;
+            return Err(ProtocolError(anyhow!("Expected 8 values, not {}", count)));
+        }
+        let y_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let y_encoder_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let z_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let z_encoder_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let d_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let d_encoder_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let s_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let s_encoder_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
         Ok(GetPositionsReply {
             y_position,
             y_encoder_position,
@@ -3034,13 +4138,13 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 116, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 116, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannel.Reset()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -3048,12 +4152,14 @@ impl NimbusCoreChannel {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -3071,7 +4177,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -3082,7 +4190,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -3104,7 +4212,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -3115,7 +4225,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -3130,12 +4240,14 @@ impl NimbusCoreChannel {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannel.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -3149,7 +4261,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -3160,7 +4274,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -3178,7 +4292,9 @@ impl NimbusCoreChannel {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -3189,7 +4305,7 @@ impl NimbusCoreChannel {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -3204,193 +4320,4217 @@ impl NimbusCoreChannel {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub enum ColletChecks {
-    ColletCheckNone = 0,
-    ColletCheckLow = 1,
-    ColletCheckStandard = 2,
-    ColletCheckHigh = 3,
-    ColletCheck384 = 4,
-    ColletCheck5ml = 5,
-}
-
-impl TryFrom<i32> for ColletChecks {
-    type Error = piglet_client::client::Error;
+// The methods above as a trait, so code using them can be tested against NimbusCoreChannelFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreChannelApi {
+    fn command(
+        &self,
+        command_timeout: i32,
+        request: &str,
+    ) -> impl Future<Output = Result</* response= */ String, Error>> + Send;
+    fn send(&self, request: &str) -> impl Future<Output = Result<(), Error>> + Send;
+    fn receive_no_wait(&self)
+    -> impl Future<Output = Result</* response= */ String, Error>> + Send;
+    fn receive(
+        &self,
+        command_timeout: i32,
+    ) -> impl Future<Output = Result</* response= */ String, Error>> + Send;
+    fn last_command(&self) -> impl Future<Output = Result</* request= */ String, Error>> + Send;
+    fn pickup_tip(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pickup_needle(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        length: u16,
+        collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn drop_tip(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn drop_tip_no_z_final(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn aspirate_capacitive_lld(
+        &self,
+        aspirate_mode: i16,
+        liquid_seek_height: Millimeters,
+        submerge_depth: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        clot_check_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        blowout_volume: Microliters,
+        prewet_volume: Microliters,
+        aspirate_volume: Microliters,
+        transport_air_volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        capacitive_lld_steepness: u32,
+        capacitive_lld_offset: u32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-    fn try_from(v: i32) -> Result<Self, Self::Error> {
-        match v {
-            0 => Ok(ColletChecks::ColletCheckNone),
-            1 => Ok(ColletChecks::ColletCheckLow),
-            2 => Ok(ColletChecks::ColletCheckStandard),
-            3 => Ok(ColletChecks::ColletCheckHigh),
-            4 => Ok(ColletChecks::ColletCheck384),
-            5 => Ok(ColletChecks::ColletCheck5ml),
+    fn aspirate_capacitive_lld_with(
+        &self,
+        request: &AspirateCapacitiveLldRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.aspirate_capacitive_lld(
+                piglet_client::client::required(
+                    request.aspirate_mode,
+                    "AspirateCapacitiveLldRequest",
+                    "aspirate_mode",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_seek_height,
+                    "AspirateCapacitiveLldRequest",
+                    "liquid_seek_height",
+                )?,
+                piglet_client::client::required(
+                    request.submerge_depth,
+                    "AspirateCapacitiveLldRequest",
+                    "submerge_depth",
+                )?,
+                piglet_client::client::required(
+                    request.follow_depth,
+                    "AspirateCapacitiveLldRequest",
+                    "follow_depth",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position,
+                    "AspirateCapacitiveLldRequest",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(
+                    request.clot_check_height,
+                    "AspirateCapacitiveLldRequest",
+                    "clot_check_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "AspirateCapacitiveLldRequest",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_exit_speed,
+                    "AspirateCapacitiveLldRequest",
+                    "liquid_exit_speed",
+                )?,
+                piglet_client::client::required(
+                    request.blowout_volume,
+                    "AspirateCapacitiveLldRequest",
+                    "blowout_volume",
+                )?,
+                piglet_client::client::required(
+                    request.prewet_volume,
+                    "AspirateCapacitiveLldRequest",
+                    "prewet_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_volume,
+                    "AspirateCapacitiveLldRequest",
+                    "aspirate_volume",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume,
+                    "AspirateCapacitiveLldRequest",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_speed,
+                    "AspirateCapacitiveLldRequest",
+                    "aspirate_speed",
+                )?,
+                piglet_client::client::required(
+                    request.settling_time,
+                    "AspirateCapacitiveLldRequest",
+                    "settling_time",
+                )?,
+                piglet_client::client::required(
+                    request.mix_volume,
+                    "AspirateCapacitiveLldRequest",
+                    "mix_volume",
+                )?,
+                piglet_client::client::required(
+                    request.mix_cycles,
+                    "AspirateCapacitiveLldRequest",
+                    "mix_cycles",
+                )?,
+                piglet_client::client::required(
+                    request.mix_position,
+                    "AspirateCapacitiveLldRequest",
+                    "mix_position",
+                )?,
+                piglet_client::client::required(
+                    request.mix_follow_distance,
+                    "AspirateCapacitiveLldRequest",
+                    "mix_follow_distance",
+                )?,
+                piglet_client::client::required(
+                    request.mix_speed,
+                    "AspirateCapacitiveLldRequest",
+                    "mix_speed",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_height,
+                    "AspirateCapacitiveLldRequest",
+                    "tube_section_height",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_ratio,
+                    "AspirateCapacitiveLldRequest",
+                    "tube_section_ratio",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_steepness,
+                    "AspirateCapacitiveLldRequest",
+                    "capacitive_lld_steepness",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_offset,
+                    "AspirateCapacitiveLldRequest",
+                    "capacitive_lld_offset",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "AspirateCapacitiveLldRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index,
+                    "AspirateCapacitiveLldRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "AspirateCapacitiveLldRequest",
+                    "recording_mode",
+                )?,
+                piglet_client::client::required(
+                    request.measurement_id.as_deref(),
+                    "AspirateCapacitiveLldRequest",
+                    "measurement_id",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn aspirate_pressure_lld(
+        &self,
+        aspirate_mode: i16,
+        liquid_seek_height: Millimeters,
+        submerge_depth: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        blowout_volume: Microliters,
+        prewet_volume: Microliters,
+        aspirate_volume: Microliters,
+        transport_air_volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        lld_mode: i16,
+        capacitive_lld_steepness: u32,
+        capacitive_lld_offset: u32,
+        pressure_lld_steepness: u32,
+        pressure_lld_offset: u32,
+        seek_speed: MmPerSecond,
+        lld_height_difference: i32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+        tip_is_filtered: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-            _ => Err(ConnectionError(anyhow!("Unknown ColletChecks value {}", v))),
-        }
-    }
-}
+    fn aspirate_pressure_lld_with(
+        &self,
+        request: &AspiratePressureLldRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.aspirate_pressure_lld(
+                piglet_client::client::required(
+                    request.aspirate_mode,
+                    "AspiratePressureLldRequest",
+                    "aspirate_mode",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_seek_height,
+                    "AspiratePressureLldRequest",
+                    "liquid_seek_height",
+                )?,
+                piglet_client::client::required(
+                    request.submerge_depth,
+                    "AspiratePressureLldRequest",
+                    "submerge_depth",
+                )?,
+                piglet_client::client::required(
+                    request.follow_depth,
+                    "AspiratePressureLldRequest",
+                    "follow_depth",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position,
+                    "AspiratePressureLldRequest",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "AspiratePressureLldRequest",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_exit_speed,
+                    "AspiratePressureLldRequest",
+                    "liquid_exit_speed",
+                )?,
+                piglet_client::client::required(
+                    request.blowout_volume,
+                    "AspiratePressureLldRequest",
+                    "blowout_volume",
+                )?,
+                piglet_client::client::required(
+                    request.prewet_volume,
+                    "AspiratePressureLldRequest",
+                    "prewet_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_volume,
+                    "AspiratePressureLldRequest",
+                    "aspirate_volume",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume,
+                    "AspiratePressureLldRequest",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_speed,
+                    "AspiratePressureLldRequest",
+                    "aspirate_speed",
+                )?,
+                piglet_client::client::required(
+                    request.settling_time,
+                    "AspiratePressureLldRequest",
+                    "settling_time",
+                )?,
+                piglet_client::client::required(
+                    request.mix_volume,
+                    "AspiratePressureLldRequest",
+                    "mix_volume",
+                )?,
+                piglet_client::client::required(
+                    request.mix_cycles,
+                    "AspiratePressureLldRequest",
+                    "mix_cycles",
+                )?,
+                piglet_client::client::required(
+                    request.mix_position,
+                    "AspiratePressureLldRequest",
+                    "mix_position",
+                )?,
+                piglet_client::client::required(
+                    request.mix_follow_distance,
+                    "AspiratePressureLldRequest",
+                    "mix_follow_distance",
+                )?,
+                piglet_client::client::required(
+                    request.mix_speed,
+                    "AspiratePressureLldRequest",
+                    "mix_speed",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_height,
+                    "AspiratePressureLldRequest",
+                    "tube_section_height",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_ratio,
+                    "AspiratePressureLldRequest",
+                    "tube_section_ratio",
+                )?,
+                piglet_client::client::required(
+                    request.lld_mode,
+                    "AspiratePressureLldRequest",
+                    "lld_mode",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_steepness,
+                    "AspiratePressureLldRequest",
+                    "capacitive_lld_steepness",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_offset,
+                    "AspiratePressureLldRequest",
+                    "capacitive_lld_offset",
+                )?,
+                piglet_client::client::required(
+                    request.pressure_lld_steepness,
+                    "AspiratePressureLldRequest",
+                    "pressure_lld_steepness",
+                )?,
+                piglet_client::client::required(
+                    request.pressure_lld_offset,
+                    "AspiratePressureLldRequest",
+                    "pressure_lld_offset",
+                )?,
+                piglet_client::client::required(
+                    request.seek_speed,
+                    "AspiratePressureLldRequest",
+                    "seek_speed",
+                )?,
+                piglet_client::client::required(
+                    request.lld_height_difference,
+                    "AspiratePressureLldRequest",
+                    "lld_height_difference",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "AspiratePressureLldRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index,
+                    "AspiratePressureLldRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "AspiratePressureLldRequest",
+                    "recording_mode",
+                )?,
+                piglet_client::client::required(
+                    request.measurement_id.as_deref(),
+                    "AspiratePressureLldRequest",
+                    "measurement_id",
+                )?,
+                piglet_client::client::required(
+                    request.tip_is_filtered,
+                    "AspiratePressureLldRequest",
+                    "tip_is_filtered",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn aspirate(
+        &self,
+        aspirate_mode: i16,
+        aspirate_height: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        air_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        blowout_volume: Microliters,
+        prewet_volume: Microliters,
+        aspirate_volume: Microliters,
+        transport_air_volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        bottom_search: bool,
+        aspirate_offset: i32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-impl PigletCodec for ColletChecks {
-    const TYPE_ID: u8 = 32;
-}
+    fn aspirate_with(
+        &self,
+        request: &AspirateRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.aspirate(
+                piglet_client::client::required(
+                    request.aspirate_mode,
+                    "AspirateRequest",
+                    "aspirate_mode",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_height,
+                    "AspirateRequest",
+                    "aspirate_height",
+                )?,
+                piglet_client::client::required(
+                    request.follow_depth,
+                    "AspirateRequest",
+                    "follow_depth",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position,
+                    "AspirateRequest",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(
+                    request.air_height,
+                    "AspirateRequest",
+                    "air_height",
+                )?,
+                piglet_client::client::required(request.z_final, "AspirateRequest", "z_final")?,
+                piglet_client::client::required(
+                    request.liquid_exit_speed,
+                    "AspirateRequest",
+                    "liquid_exit_speed",
+                )?,
+                piglet_client::client::required(
+                    request.blowout_volume,
+                    "AspirateRequest",
+                    "blowout_volume",
+                )?,
+                piglet_client::client::required(
+                    request.prewet_volume,
+                    "AspirateRequest",
+                    "prewet_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_volume,
+                    "AspirateRequest",
+                    "aspirate_volume",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume,
+                    "AspirateRequest",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_speed,
+                    "AspirateRequest",
+                    "aspirate_speed",
+                )?,
+                piglet_client::client::required(
+                    request.settling_time,
+                    "AspirateRequest",
+                    "settling_time",
+                )?,
+                piglet_client::client::required(
+                    request.mix_volume,
+                    "AspirateRequest",
+                    "mix_volume",
+                )?,
+                piglet_client::client::required(
+                    request.mix_cycles,
+                    "AspirateRequest",
+                    "mix_cycles",
+                )?,
+                piglet_client::client::required(
+                    request.mix_position,
+                    "AspirateRequest",
+                    "mix_position",
+                )?,
+                piglet_client::client::required(
+                    request.mix_follow_distance,
+                    "AspirateRequest",
+                    "mix_follow_distance",
+                )?,
+                piglet_client::client::required(request.mix_speed, "AspirateRequest", "mix_speed")?,
+                piglet_client::client::required(
+                    request.tube_section_height,
+                    "AspirateRequest",
+                    "tube_section_height",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_ratio,
+                    "AspirateRequest",
+                    "tube_section_ratio",
+                )?,
+                piglet_client::client::required(
+                    request.bottom_search,
+                    "AspirateRequest",
+                    "bottom_search",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_offset,
+                    "AspirateRequest",
+                    "aspirate_offset",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "AspirateRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index,
+                    "AspirateRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "AspirateRequest",
+                    "recording_mode",
+                )?,
+                piglet_client::client::required(
+                    request.measurement_id.as_deref(),
+                    "AspirateRequest",
+                    "measurement_id",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn dispense_lld(
+        &self,
+        liquid_seek_height: Millimeters,
+        submerge_depth: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        dispense_volume: Microliters,
+        stop_back_volume: Microliters,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        capacitive_lld_steepness: u32,
+        capacitive_lld_offset: u32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-impl PigletDeserialize for ColletChecks {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        stream.get_i32_le().try_into()
-    }
-}
+    fn dispense_lld_with(
+        &self,
+        request: &DispenseLldRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.dispense_lld(
+                piglet_client::client::required(
+                    request.liquid_seek_height,
+                    "DispenseLldRequest",
+                    "liquid_seek_height",
+                )?,
+                piglet_client::client::required(
+                    request.submerge_depth,
+                    "DispenseLldRequest",
+                    "submerge_depth",
+                )?,
+                piglet_client::client::required(
+                    request.follow_depth,
+                    "DispenseLldRequest",
+                    "follow_depth",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position,
+                    "DispenseLldRequest",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(request.z_final, "DispenseLldRequest", "z_final")?,
+                piglet_client::client::required(
+                    request.liquid_exit_speed,
+                    "DispenseLldRequest",
+                    "liquid_exit_speed",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_volume,
+                    "DispenseLldRequest",
+                    "dispense_volume",
+                )?,
+                piglet_client::client::required(
+                    request.stop_back_volume,
+                    "DispenseLldRequest",
+                    "stop_back_volume",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume,
+                    "DispenseLldRequest",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_speed,
+                    "DispenseLldRequest",
+                    "dispense_speed",
+                )?,
+                piglet_client::client::required(
+                    request.cutoff_speed,
+                    "DispenseLldRequest",
+                    "cutoff_speed",
+                )?,
+                piglet_client::client::required(
+                    request.settling_time,
+                    "DispenseLldRequest",
+                    "settling_time",
+                )?,
+                piglet_client::client::required(
+                    request.mix_volume,
+                    "DispenseLldRequest",
+                    "mix_volume",
+                )?,
+                piglet_client::client::required(
+                    request.mix_cycles,
+                    "DispenseLldRequest",
+                    "mix_cycles",
+                )?,
+                piglet_client::client::required(
+                    request.mix_position,
+                    "DispenseLldRequest",
+                    "mix_position",
+                )?,
+                piglet_client::client::required(
+                    request.mix_follow_distance,
+                    "DispenseLldRequest",
+                    "mix_follow_distance",
+                )?,
+                piglet_client::client::required(
+                    request.mix_speed,
+                    "DispenseLldRequest",
+                    "mix_speed",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_height,
+                    "DispenseLldRequest",
+                    "tube_section_height",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_ratio,
+                    "DispenseLldRequest",
+                    "tube_section_ratio",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_steepness,
+                    "DispenseLldRequest",
+                    "capacitive_lld_steepness",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_offset,
+                    "DispenseLldRequest",
+                    "capacitive_lld_offset",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "DispenseLldRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index,
+                    "DispenseLldRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "DispenseLldRequest",
+                    "recording_mode",
+                )?,
+                piglet_client::client::required(
+                    request.measurement_id.as_deref(),
+                    "DispenseLldRequest",
+                    "measurement_id",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn dispense(
+        &self,
+        dispense_height: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        air_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        dispense_volume: Microliters,
+        stop_back_volume: Microliters,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        bottom_search: bool,
+        dispense_offset: i32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-impl PigletSerialize for ColletChecks {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        stream.put_u16_le(4);
-        stream.put_i32_le(*self as i32);
-    }
-}
+    fn dispense_with(
+        &self,
+        request: &DispenseRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.dispense(
+                piglet_client::client::required(
+                    request.dispense_height,
+                    "DispenseRequest",
+                    "dispense_height",
+                )?,
+                piglet_client::client::required(
+                    request.follow_depth,
+                    "DispenseRequest",
+                    "follow_depth",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position,
+                    "DispenseRequest",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(
+                    request.air_height,
+                    "DispenseRequest",
+                    "air_height",
+                )?,
+                piglet_client::client::required(request.z_final, "DispenseRequest", "z_final")?,
+                piglet_client::client::required(
+                    request.liquid_exit_speed,
+                    "DispenseRequest",
+                    "liquid_exit_speed",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_volume,
+                    "DispenseRequest",
+                    "dispense_volume",
+                )?,
+                piglet_client::client::required(
+                    request.stop_back_volume,
+                    "DispenseRequest",
+                    "stop_back_volume",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume,
+                    "DispenseRequest",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_speed,
+                    "DispenseRequest",
+                    "dispense_speed",
+                )?,
+                piglet_client::client::required(
+                    request.cutoff_speed,
+                    "DispenseRequest",
+                    "cutoff_speed",
+                )?,
+                piglet_client::client::required(
+                    request.settling_time,
+                    "DispenseRequest",
+                    "settling_time",
+                )?,
+                piglet_client::client::required(
+                    request.mix_volume,
+                    "DispenseRequest",
+                    "mix_volume",
+                )?,
+                piglet_client::client::required(
+                    request.mix_cycles,
+                    "DispenseRequest",
+                    "mix_cycles",
+                )?,
+                piglet_client::client::required(
+                    request.mix_position,
+                    "DispenseRequest",
+                    "mix_position",
+                )?,
+                piglet_client::client::required(
+                    request.mix_follow_distance,
+                    "DispenseRequest",
+                    "mix_follow_distance",
+                )?,
+                piglet_client::client::required(request.mix_speed, "DispenseRequest", "mix_speed")?,
+                piglet_client::client::required(
+                    request.tube_section_height,
+                    "DispenseRequest",
+                    "tube_section_height",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_ratio,
+                    "DispenseRequest",
+                    "tube_section_ratio",
+                )?,
+                piglet_client::client::required(
+                    request.bottom_search,
+                    "DispenseRequest",
+                    "bottom_search",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_offset,
+                    "DispenseRequest",
+                    "dispense_offset",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "DispenseRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index,
+                    "DispenseRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "DispenseRequest",
+                    "recording_mode",
+                )?,
+                piglet_client::client::required(
+                    request.measurement_id.as_deref(),
+                    "DispenseRequest",
+                    "measurement_id",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn empty_tip(
+        &self,
+        dispense_height: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        air_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-impl PigletCodec for MSlice<'_, ColletChecks> {
-    const TYPE_ID: u8 = 35;
-}
+    fn empty_tip_with(
+        &self,
+        request: &EmptyTipRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.empty_tip(
+                piglet_client::client::required(
+                    request.dispense_height,
+                    "EmptyTipRequest",
+                    "dispense_height",
+                )?,
+                piglet_client::client::required(
+                    request.follow_depth,
+                    "EmptyTipRequest",
+                    "follow_depth",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position,
+                    "EmptyTipRequest",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(
+                    request.air_height,
+                    "EmptyTipRequest",
+                    "air_height",
+                )?,
+                piglet_client::client::required(request.z_final, "EmptyTipRequest", "z_final")?,
+                piglet_client::client::required(
+                    request.liquid_exit_speed,
+                    "EmptyTipRequest",
+                    "liquid_exit_speed",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume,
+                    "EmptyTipRequest",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_speed,
+                    "EmptyTipRequest",
+                    "dispense_speed",
+                )?,
+                piglet_client::client::required(
+                    request.cutoff_speed,
+                    "EmptyTipRequest",
+                    "cutoff_speed",
+                )?,
+                piglet_client::client::required(
+                    request.settling_time,
+                    "EmptyTipRequest",
+                    "settling_time",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_height,
+                    "EmptyTipRequest",
+                    "tube_section_height",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_ratio,
+                    "EmptyTipRequest",
+                    "tube_section_ratio",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "EmptyTipRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index,
+                    "EmptyTipRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "EmptyTipRequest",
+                    "recording_mode",
+                )?,
+                piglet_client::client::required(
+                    request.measurement_id.as_deref(),
+                    "EmptyTipRequest",
+                    "measurement_id",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn y_pre_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_relative(
+        &self,
+        distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_relative_speed(
+        &self,
+        distance: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_absolute_speed(
+        &self,
+        position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_seek_lld(
+        &self,
+        position: Millimeters,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_negative_stall(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_absolute_gripper(
+        &self,
+        position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_safe(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_relative(
+        &self,
+        distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_relative_speed(
+        &self,
+        distance: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_absolute_speed(
+        &self,
+        position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_traverse(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_seek_lld(
+        &self,
+        position: Millimeters,
+        min_position: Millimeters,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_absolute_gripper(
+        &self,
+        position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn dispense_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn dispense_empty(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn dispense_move_relative(
+        &self,
+        distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn squeeze_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn squeeze_on(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn squeeze_off(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn lld_on(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn lld_off(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn paired_channel(&self, channel: i16) -> impl Future<Output = Result<(), Error>> + Send;
+    fn synchronize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn is_initialized(&self)
+    -> impl Future<Output = Result</* initialized= */ bool, Error>> + Send;
+    fn is_tip_present(&self) -> impl Future<Output = Result</* present= */ bool, Error>> + Send;
+    fn get_lld_status(&self) -> impl Future<Output = Result</* status= */ i16, Error>> + Send;
+    fn get_sensor_status(&self) -> impl Future<Output = Result</* status= */ i16, Error>> + Send;
+    fn get_y_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ Millimeters, Error>> + Send;
+    fn get_y_home_offset(
+        &self,
+    ) -> impl Future<Output = Result</* home_offset= */ i32, Error>> + Send;
+    fn set_y_home_offset(&self, home_offset: i32)
+    -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_z_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ Millimeters, Error>> + Send;
+    fn get_z_home_offset(
+        &self,
+    ) -> impl Future<Output = Result</* home_offset= */ i32, Error>> + Send;
+    fn set_z_home_offset(&self, home_offset: i32)
+    -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_position_reset(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_lld(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_squeeze(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_tip_height(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_dispense_check(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result</* steps= */ i32, Error>> + Send;
+    fn calibrate_dispense_check_data(
+        &self,
+        direction: i16,
+        index: i32,
+    ) -> impl Future<Output = Result</* result= */ String, Error>> + Send;
+    fn calibrate_touchoff(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn erase_limit_curves(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn create_limit_curve(
+        &self,
+        name: &str,
+        lower_limit_x: impl AsRef<[u16]> + Send,
+        lower_limit_y: impl AsRef<[i16]> + Send,
+        upper_limit_x: impl AsRef<[u16]> + Send,
+        upper_limit_y: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<CreateLimitCurveReply, Error>> + Send;
+    fn reset_tadm_fifo(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn retrieve_tadm_data(
+        &self,
+    ) -> impl Future<Output = Result<RetrieveTadmDataReply, Error>> + Send;
+    fn get_limit_curve_names(
+        &self,
+    ) -> impl Future<Output = Result<GetLimitCurveNamesReply, Error>> + Send;
+    fn get_limit_curve_info(
+        &self,
+        name: &str,
+    ) -> impl Future<Output = Result<GetLimitCurveInfoReply, Error>> + Send;
+    fn set_tadm_enable(&self, enable: bool) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_tadm_enable(&self) -> impl Future<Output = Result</* enable= */ bool, Error>> + Send;
+    fn enable_mad(
+        &self,
+        pressure_threshold: u16,
+        minimum_pressure_difference: u16,
+        maximum_pressure_difference: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn disable_mad(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn enable_adc(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn disable_adc(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_z_default_speed(
+        &self,
+        speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_z_default_speed(
+        &self,
+    ) -> impl Future<Output = Result</* speed= */ MmPerSecond, Error>> + Send;
+    fn z_seek_obstacle(
+        &self,
+        z_position: Millimeters,
+        z_min_position: Millimeters,
+        z_final_position: Millimeters,
+        z_velocity: u32,
+    ) -> impl Future<Output = Result</* obstacle_detected= */ bool, Error>> + Send;
+    fn get_pressure(&self) -> impl Future<Output = Result</* pressure= */ i16, Error>> + Send;
+    fn get_y_resolution(&self)
+    -> impl Future<Output = Result</* resolution= */ i32, Error>> + Send;
+    fn set_y_resolution(&self, resolution: i32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pressure_mode(&self, mode: i16) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pressure_gain(&self, gain: i16) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pressure_offset(&self, offset: i16) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pressure_shift(&self, shift: i16) -> impl Future<Output = Result<(), Error>> + Send;
+    fn store_pressure_gain(
+        &self,
+        data_register: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn store_pressure_offset(
+        &self,
+        data_register: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn leak_check(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
+        collet_check: i16,
+        time: u32,
+        test_type: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_configuration(
+        &self,
+        index: i16,
+    ) -> impl Future<Output = Result<GetConfigurationReply, Error>> + Send;
+    fn set_configuration(
+        &self,
+        index: i16,
+        enable: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_potentiometer_data(
+        &self,
+    ) -> impl Future<Output = Result<GetPotentiometerDataReply, Error>> + Send;
+    fn get_pressure_sensor_ad(
+        &self,
+    ) -> impl Future<Output = Result<GetPressureSensorADReply, Error>> + Send;
+    fn calibrate_tadm_offset(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn prepare_on_the_fly_dispense(
+        &self,
+        volume: Microliters,
+        stop_back_volume: Microliters,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+        dispense_pattern: &str,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-impl PigletCodec for MVec<ColletChecks> {
-    const TYPE_ID: u8 = 35;
+    fn prepare_on_the_fly_dispense_with(
+        &self,
+        request: &PrepareOnTheFlyDispenseRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.prepare_on_the_fly_dispense(
+                piglet_client::client::required(
+                    request.volume,
+                    "PrepareOnTheFlyDispenseRequest",
+                    "volume",
+                )?,
+                piglet_client::client::required(
+                    request.stop_back_volume,
+                    "PrepareOnTheFlyDispenseRequest",
+                    "stop_back_volume",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume,
+                    "PrepareOnTheFlyDispenseRequest",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_speed,
+                    "PrepareOnTheFlyDispenseRequest",
+                    "dispense_speed",
+                )?,
+                piglet_client::client::required(
+                    request.cutoff_speed,
+                    "PrepareOnTheFlyDispenseRequest",
+                    "cutoff_speed",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_pattern.as_deref(),
+                    "PrepareOnTheFlyDispenseRequest",
+                    "dispense_pattern",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "PrepareOnTheFlyDispenseRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index,
+                    "PrepareOnTheFlyDispenseRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "PrepareOnTheFlyDispenseRequest",
+                    "recording_mode",
+                )?,
+                piglet_client::client::required(
+                    request.measurement_id.as_deref(),
+                    "PrepareOnTheFlyDispenseRequest",
+                    "measurement_id",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn start_on_the_fly_dispense(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn stop_on_the_fly_dispense(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn read_on_the_fly_dispense_errors(
+        &self,
+    ) -> impl Future<Output = Result<ReadOnTheFlyDispenseErrorsReply, Error>> + Send;
+    fn dispenser_aspirate(
+        &self,
+        volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn dispenser_dispense(
+        &self,
+        volume: Microliters,
+        stop_back_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_current_dispenser_volume(
+        &self,
+    ) -> impl Future<Output = Result</* volume= */ Microliters, Error>> + Send;
+    fn get_liquid_height(
+        &self,
+    ) -> impl Future<Output = Result</* liquid_height= */ Millimeters, Error>> + Send;
+    fn z_seek_obstacle_position(
+        &self,
+        z_position: Millimeters,
+        z_min_position: Millimeters,
+        z_final_position: Millimeters,
+        z_velocity: u32,
+    ) -> impl Future<Output = Result<ZSeekObstaclePositionReply, Error>> + Send;
+    fn z_seek_lld_position(
+        &self,
+        z_position: Millimeters,
+        z_min_position: Millimeters,
+        z_final_position: Millimeters,
+        z_velocity: u32,
+        capacitive_lld_steepness: u32,
+        capacitive_lld_offset: u32,
+    ) -> impl Future<Output = Result<ZSeekLldPositionReply, Error>> + Send;
+    fn calibrate_squeeze_check_torque(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_squeeze_position(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_z_liquid_seek_speed(
+        &self,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_z_liquid_seek_speed(
+        &self,
+    ) -> impl Future<Output = Result</* velocity= */ u32, Error>> + Send;
+    fn z_move_absolute_2_speed(
+        &self,
+        position: Millimeters,
+        speed_switching_position: Millimeters,
+        velocity: u32,
+        lower_section_velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_servo_off(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_servo_off(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn save_counters(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetCalibrationValuesReply, Error>> + Send;
+    fn get_extended_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetExtendedCalibrationValuesReply, Error>> + Send;
+    fn get_counters(&self) -> impl Future<Output = Result<GetCountersReply, Error>> + Send;
+    fn get_technical_info(
+        &self,
+    ) -> impl Future<Output = Result</* channel_info= */ String, Error>> + Send;
+    fn set_counters(
+        &self,
+        tip_pickup_counter: u32,
+        tip_eject_counter: u32,
+        aspirate_counter: u32,
+        dispense_counter: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn measure_lld_frequency(
+        &self,
+        seconds: u16,
+    ) -> impl Future<Output = Result</* detects_per_minute= */ u16, Error>> + Send;
+    fn get_positions(&self) -> impl Future<Output = Result<GetPositionsReply, Error>> + Send;
+    fn reset(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
 }
 
-impl PigletDeserialize for MVec<ColletChecks> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut arr = Vec::new();
-        for i in 0..(length / 4) {
-            arr.push(stream.get_i32_le().try_into()?);
-        }
-        Ok(MVec(arr))
+impl NimbusCoreChannelApi for NimbusCoreChannel {
+    fn command(
+        &self,
+        command_timeout: i32,
+        request: &str,
+    ) -> impl Future<Output = Result</* response= */ String, Error>> + Send {
+        NimbusCoreChannel::command(self, command_timeout, request)
     }
-}
 
-impl PigletSerialize for MSlice<'_, ColletChecks> {
-    fn serialize(&self, bytes: &mut BytesMut) {
-        bytes.put_u8(Self::TYPE_ID);
-        bytes.put_u8(0);
-        bytes.put_u16_le(4 * self.0.len() as u16);
-        for v in self.0.as_ref() {
-            bytes.put_i32_le(*v as i32);
-        }
+    fn send(&self, request: &str) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::send(self, request)
     }
-}
-
-#[derive(Clone, Copy, Debug)]
-pub enum TadmModes {
-    TadmModeNone = 0,
-    TadmModeError = 1,
-    TadmModeAll = 2,
-}
-
-impl TryFrom<i32> for TadmModes {
-    type Error = piglet_client::client::Error;
 
-    fn try_from(v: i32) -> Result<Self, Self::Error> {
-        match v {
-            0 => Ok(TadmModes::TadmModeNone),
-            1 => Ok(TadmModes::TadmModeError),
-            2 => Ok(TadmModes::TadmModeAll),
-
-            _ => Err(ConnectionError(anyhow!("Unknown TadmModes value {}", v))),
-        }
+    fn receive_no_wait(
+        &self,
+    ) -> impl Future<Output = Result</* response= */ String, Error>> + Send {
+        NimbusCoreChannel::receive_no_wait(self)
     }
-}
-
-impl PigletCodec for TadmModes {
-    const TYPE_ID: u8 = 32;
-}
 
-impl PigletDeserialize for TadmModes {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        stream.get_i32_le().try_into()
+    fn receive(
+        &self,
+        command_timeout: i32,
+    ) -> impl Future<Output = Result</* response= */ String, Error>> + Send {
+        NimbusCoreChannel::receive(self, command_timeout)
     }
-}
 
-impl PigletSerialize for TadmModes {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        stream.put_u16_le(4);
-        stream.put_i32_le(*self as i32);
+    fn last_command(&self) -> impl Future<Output = Result</* request= */ String, Error>> + Send {
+        NimbusCoreChannel::last_command(self)
     }
-}
 
-impl PigletCodec for MSlice<'_, TadmModes> {
-    const TYPE_ID: u8 = 35;
-}
+    fn pickup_tip(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::pickup_tip(
+            self,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            volume,
+            collet_check,
+        )
+    }
+
+    fn pickup_needle(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        length: u16,
+        collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::pickup_needle(
+            self,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            volume,
+            length,
+            collet_check,
+        )
+    }
+
+    fn drop_tip(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::drop_tip(self, z_start_position, z_stop_position, z_final)
+    }
 
-impl PigletCodec for MVec<TadmModes> {
-    const TYPE_ID: u8 = 35;
-}
+    fn drop_tip_no_z_final(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::drop_tip_no_z_final(self, z_start_position, z_stop_position)
+    }
 
-impl PigletDeserialize for MVec<TadmModes> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut arr = Vec::new();
-        for i in 0..(length / 4) {
-            arr.push(stream.get_i32_le().try_into()?);
-        }
-        Ok(MVec(arr))
+    fn aspirate_capacitive_lld(
+        &self,
+        aspirate_mode: i16,
+        liquid_seek_height: Millimeters,
+        submerge_depth: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        clot_check_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        blowout_volume: Microliters,
+        prewet_volume: Microliters,
+        aspirate_volume: Microliters,
+        transport_air_volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        capacitive_lld_steepness: u32,
+        capacitive_lld_offset: u32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::aspirate_capacitive_lld(
+            self,
+            aspirate_mode,
+            liquid_seek_height,
+            submerge_depth,
+            follow_depth,
+            z_min_position,
+            clot_check_height,
+            z_final,
+            liquid_exit_speed,
+            blowout_volume,
+            prewet_volume,
+            aspirate_volume,
+            transport_air_volume,
+            aspirate_speed,
+            settling_time,
+            mix_volume,
+            mix_cycles,
+            mix_position,
+            mix_follow_distance,
+            mix_speed,
+            tube_section_height,
+            tube_section_ratio,
+            capacitive_lld_steepness,
+            capacitive_lld_offset,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+            measurement_id,
+        )
+    }
+
+    fn aspirate_pressure_lld(
+        &self,
+        aspirate_mode: i16,
+        liquid_seek_height: Millimeters,
+        submerge_depth: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        blowout_volume: Microliters,
+        prewet_volume: Microliters,
+        aspirate_volume: Microliters,
+        transport_air_volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        lld_mode: i16,
+        capacitive_lld_steepness: u32,
+        capacitive_lld_offset: u32,
+        pressure_lld_steepness: u32,
+        pressure_lld_offset: u32,
+        seek_speed: MmPerSecond,
+        lld_height_difference: i32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+        tip_is_filtered: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::aspirate_pressure_lld(
+            self,
+            aspirate_mode,
+            liquid_seek_height,
+            submerge_depth,
+            follow_depth,
+            z_min_position,
+            z_final,
+            liquid_exit_speed,
+            blowout_volume,
+            prewet_volume,
+            aspirate_volume,
+            transport_air_volume,
+            aspirate_speed,
+            settling_time,
+            mix_volume,
+            mix_cycles,
+            mix_position,
+            mix_follow_distance,
+            mix_speed,
+            tube_section_height,
+            tube_section_ratio,
+            lld_mode,
+            capacitive_lld_steepness,
+            capacitive_lld_offset,
+            pressure_lld_steepness,
+            pressure_lld_offset,
+            seek_speed,
+            lld_height_difference,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+            measurement_id,
+            tip_is_filtered,
+        )
+    }
+
+    fn aspirate(
+        &self,
+        aspirate_mode: i16,
+        aspirate_height: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        air_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        blowout_volume: Microliters,
+        prewet_volume: Microliters,
+        aspirate_volume: Microliters,
+        transport_air_volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        bottom_search: bool,
+        aspirate_offset: i32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::aspirate(
+            self,
+            aspirate_mode,
+            aspirate_height,
+            follow_depth,
+            z_min_position,
+            air_height,
+            z_final,
+            liquid_exit_speed,
+            blowout_volume,
+            prewet_volume,
+            aspirate_volume,
+            transport_air_volume,
+            aspirate_speed,
+            settling_time,
+            mix_volume,
+            mix_cycles,
+            mix_position,
+            mix_follow_distance,
+            mix_speed,
+            tube_section_height,
+            tube_section_ratio,
+            bottom_search,
+            aspirate_offset,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+            measurement_id,
+        )
+    }
+
+    fn dispense_lld(
+        &self,
+        liquid_seek_height: Millimeters,
+        submerge_depth: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        dispense_volume: Microliters,
+        stop_back_volume: Microliters,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        capacitive_lld_steepness: u32,
+        capacitive_lld_offset: u32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::dispense_lld(
+            self,
+            liquid_seek_height,
+            submerge_depth,
+            follow_depth,
+            z_min_position,
+            z_final,
+            liquid_exit_speed,
+            dispense_volume,
+            stop_back_volume,
+            transport_air_volume,
+            dispense_speed,
+            cutoff_speed,
+            settling_time,
+            mix_volume,
+            mix_cycles,
+            mix_position,
+            mix_follow_distance,
+            mix_speed,
+            tube_section_height,
+            tube_section_ratio,
+            capacitive_lld_steepness,
+            capacitive_lld_offset,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+            measurement_id,
+        )
+    }
+
+    fn dispense(
+        &self,
+        dispense_height: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        air_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        dispense_volume: Microliters,
+        stop_back_volume: Microliters,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        bottom_search: bool,
+        dispense_offset: i32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::dispense(
+            self,
+            dispense_height,
+            follow_depth,
+            z_min_position,
+            air_height,
+            z_final,
+            liquid_exit_speed,
+            dispense_volume,
+            stop_back_volume,
+            transport_air_volume,
+            dispense_speed,
+            cutoff_speed,
+            settling_time,
+            mix_volume,
+            mix_cycles,
+            mix_position,
+            mix_follow_distance,
+            mix_speed,
+            tube_section_height,
+            tube_section_ratio,
+            bottom_search,
+            dispense_offset,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+            measurement_id,
+        )
+    }
+
+    fn empty_tip(
+        &self,
+        dispense_height: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        air_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::empty_tip(
+            self,
+            dispense_height,
+            follow_depth,
+            z_min_position,
+            air_height,
+            z_final,
+            liquid_exit_speed,
+            transport_air_volume,
+            dispense_speed,
+            cutoff_speed,
+            settling_time,
+            tube_section_height,
+            tube_section_ratio,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+            measurement_id,
+        )
+    }
+
+    fn y_pre_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::y_pre_initialize(self)
+    }
+
+    fn y_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::y_initialize(self)
+    }
+
+    fn y_move_relative(
+        &self,
+        distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::y_move_relative(self, distance)
+    }
+
+    fn y_move_relative_speed(
+        &self,
+        distance: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::y_move_relative_speed(self, distance, acceleration, velocity)
+    }
+
+    fn y_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::y_move_absolute(self, position)
+    }
+
+    fn y_move_absolute_speed(
+        &self,
+        position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::y_move_absolute_speed(self, position, acceleration, velocity)
+    }
+
+    fn y_seek_lld(
+        &self,
+        position: Millimeters,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::y_seek_lld(self, position, velocity)
+    }
+
+    fn y_move_negative_stall(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::y_move_negative_stall(self)
+    }
+
+    fn y_move_absolute_gripper(
+        &self,
+        position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::y_move_absolute_gripper(self, position, acceleration, velocity)
+    }
+
+    fn z_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::z_initialize(self)
+    }
+
+    fn z_move_safe(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::z_move_safe(self)
+    }
+
+    fn z_move_relative(
+        &self,
+        distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::z_move_relative(self, distance)
+    }
+
+    fn z_move_relative_speed(
+        &self,
+        distance: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::z_move_relative_speed(self, distance, acceleration, velocity)
+    }
+
+    fn z_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::z_move_absolute(self, position)
+    }
+
+    fn z_move_absolute_speed(
+        &self,
+        position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::z_move_absolute_speed(self, position, acceleration, velocity)
+    }
+
+    fn z_move_traverse(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::z_move_traverse(self, position)
+    }
+
+    fn z_seek_lld(
+        &self,
+        position: Millimeters,
+        min_position: Millimeters,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::z_seek_lld(self, position, min_position, velocity)
+    }
+
+    fn z_move_absolute_gripper(
+        &self,
+        position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::z_move_absolute_gripper(self, position, acceleration, velocity)
+    }
+
+    fn dispense_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::dispense_initialize(self)
+    }
+
+    fn dispense_empty(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::dispense_empty(self)
+    }
+
+    fn dispense_move_relative(
+        &self,
+        distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::dispense_move_relative(self, distance)
+    }
+
+    fn squeeze_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::squeeze_initialize(self)
+    }
+
+    fn squeeze_on(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::squeeze_on(self)
+    }
+
+    fn squeeze_off(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::squeeze_off(self)
+    }
+
+    fn lld_on(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::lld_on(self)
+    }
+
+    fn lld_off(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::lld_off(self)
+    }
+
+    fn paired_channel(&self, channel: i16) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::paired_channel(self, channel)
+    }
+
+    fn synchronize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::synchronize(self)
+    }
+
+    fn is_initialized(
+        &self,
+    ) -> impl Future<Output = Result</* initialized= */ bool, Error>> + Send {
+        NimbusCoreChannel::is_initialized(self)
+    }
+
+    fn is_tip_present(&self) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        NimbusCoreChannel::is_tip_present(self)
+    }
+
+    fn get_lld_status(&self) -> impl Future<Output = Result</* status= */ i16, Error>> + Send {
+        NimbusCoreChannel::get_lld_status(self)
+    }
+
+    fn get_sensor_status(&self) -> impl Future<Output = Result</* status= */ i16, Error>> + Send {
+        NimbusCoreChannel::get_sensor_status(self)
+    }
+
+    fn get_y_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ Millimeters, Error>> + Send {
+        NimbusCoreChannel::get_y_position(self)
+    }
+
+    fn get_y_home_offset(
+        &self,
+    ) -> impl Future<Output = Result</* home_offset= */ i32, Error>> + Send {
+        NimbusCoreChannel::get_y_home_offset(self)
+    }
+
+    fn set_y_home_offset(
+        &self,
+        home_offset: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::set_y_home_offset(self, home_offset)
+    }
+
+    fn get_z_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ Millimeters, Error>> + Send {
+        NimbusCoreChannel::get_z_position(self)
+    }
+
+    fn get_z_home_offset(
+        &self,
+    ) -> impl Future<Output = Result</* home_offset= */ i32, Error>> + Send {
+        NimbusCoreChannel::get_z_home_offset(self)
+    }
+
+    fn set_z_home_offset(
+        &self,
+        home_offset: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::set_z_home_offset(self, home_offset)
+    }
+
+    fn calibrate_position_reset(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::calibrate_position_reset(self)
+    }
+
+    fn calibrate_lld(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::calibrate_lld(self)
+    }
+
+    fn calibrate_squeeze(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::calibrate_squeeze(self, z_start_position, z_stop_position)
+    }
+
+    fn calibrate_tip_height(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::calibrate_tip_height(
+            self,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            volume,
+            collet_check,
+        )
+    }
+
+    fn calibrate_dispense_check(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result</* steps= */ i32, Error>> + Send {
+        NimbusCoreChannel::calibrate_dispense_check(
+            self,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            volume,
+            collet_check,
+        )
+    }
+
+    fn calibrate_dispense_check_data(
+        &self,
+        direction: i16,
+        index: i32,
+    ) -> impl Future<Output = Result</* result= */ String, Error>> + Send {
+        NimbusCoreChannel::calibrate_dispense_check_data(self, direction, index)
+    }
+
+    fn calibrate_touchoff(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::calibrate_touchoff(self)
+    }
+
+    fn erase_limit_curves(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::erase_limit_curves(self)
+    }
+
+    fn create_limit_curve(
+        &self,
+        name: &str,
+        lower_limit_x: impl AsRef<[u16]> + Send,
+        lower_limit_y: impl AsRef<[i16]> + Send,
+        upper_limit_x: impl AsRef<[u16]> + Send,
+        upper_limit_y: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<CreateLimitCurveReply, Error>> + Send {
+        NimbusCoreChannel::create_limit_curve(
+            self,
+            name,
+            lower_limit_x,
+            lower_limit_y,
+            upper_limit_x,
+            upper_limit_y,
+        )
+    }
+
+    fn reset_tadm_fifo(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::reset_tadm_fifo(self)
+    }
+
+    fn retrieve_tadm_data(
+        &self,
+    ) -> impl Future<Output = Result<RetrieveTadmDataReply, Error>> + Send {
+        NimbusCoreChannel::retrieve_tadm_data(self)
+    }
+
+    fn get_limit_curve_names(
+        &self,
+    ) -> impl Future<Output = Result<GetLimitCurveNamesReply, Error>> + Send {
+        NimbusCoreChannel::get_limit_curve_names(self)
+    }
+
+    fn get_limit_curve_info(
+        &self,
+        name: &str,
+    ) -> impl Future<Output = Result<GetLimitCurveInfoReply, Error>> + Send {
+        NimbusCoreChannel::get_limit_curve_info(self, name)
+    }
+
+    fn set_tadm_enable(&self, enable: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::set_tadm_enable(self, enable)
+    }
+
+    fn get_tadm_enable(&self) -> impl Future<Output = Result</* enable= */ bool, Error>> + Send {
+        NimbusCoreChannel::get_tadm_enable(self)
+    }
+
+    fn enable_mad(
+        &self,
+        pressure_threshold: u16,
+        minimum_pressure_difference: u16,
+        maximum_pressure_difference: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::enable_mad(
+            self,
+            pressure_threshold,
+            minimum_pressure_difference,
+            maximum_pressure_difference,
+        )
+    }
+
+    fn disable_mad(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::disable_mad(self)
+    }
+
+    fn enable_adc(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::enable_adc(self)
+    }
+
+    fn disable_adc(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::disable_adc(self)
+    }
+
+    fn set_z_default_speed(
+        &self,
+        speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::set_z_default_speed(self, speed)
+    }
+
+    fn get_z_default_speed(
+        &self,
+    ) -> impl Future<Output = Result</* speed= */ MmPerSecond, Error>> + Send {
+        NimbusCoreChannel::get_z_default_speed(self)
+    }
+
+    fn z_seek_obstacle(
+        &self,
+        z_position: Millimeters,
+        z_min_position: Millimeters,
+        z_final_position: Millimeters,
+        z_velocity: u32,
+    ) -> impl Future<Output = Result</* obstacle_detected= */ bool, Error>> + Send {
+        NimbusCoreChannel::z_seek_obstacle(
+            self,
+            z_position,
+            z_min_position,
+            z_final_position,
+            z_velocity,
+        )
+    }
+
+    fn get_pressure(&self) -> impl Future<Output = Result</* pressure= */ i16, Error>> + Send {
+        NimbusCoreChannel::get_pressure(self)
+    }
+
+    fn get_y_resolution(
+        &self,
+    ) -> impl Future<Output = Result</* resolution= */ i32, Error>> + Send {
+        NimbusCoreChannel::get_y_resolution(self)
+    }
+
+    fn set_y_resolution(&self, resolution: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::set_y_resolution(self, resolution)
+    }
+
+    fn pressure_mode(&self, mode: i16) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::pressure_mode(self, mode)
+    }
+
+    fn pressure_gain(&self, gain: i16) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::pressure_gain(self, gain)
+    }
+
+    fn pressure_offset(&self, offset: i16) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::pressure_offset(self, offset)
+    }
+
+    fn pressure_shift(&self, shift: i16) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::pressure_shift(self, shift)
+    }
+
+    fn store_pressure_gain(
+        &self,
+        data_register: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::store_pressure_gain(self, data_register)
+    }
+
+    fn store_pressure_offset(
+        &self,
+        data_register: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::store_pressure_offset(self, data_register)
+    }
+
+    fn leak_check(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
+        collet_check: i16,
+        time: u32,
+        test_type: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::leak_check(
+            self,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            tip_volume,
+            collet_check,
+            time,
+            test_type,
+        )
+    }
+
+    fn get_configuration(
+        &self,
+        index: i16,
+    ) -> impl Future<Output = Result<GetConfigurationReply, Error>> + Send {
+        NimbusCoreChannel::get_configuration(self, index)
+    }
+
+    fn set_configuration(
+        &self,
+        index: i16,
+        enable: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::set_configuration(self, index, enable)
+    }
+
+    fn get_potentiometer_data(
+        &self,
+    ) -> impl Future<Output = Result<GetPotentiometerDataReply, Error>> + Send {
+        NimbusCoreChannel::get_potentiometer_data(self)
+    }
+
+    fn get_pressure_sensor_ad(
+        &self,
+    ) -> impl Future<Output = Result<GetPressureSensorADReply, Error>> + Send {
+        NimbusCoreChannel::get_pressure_sensor_ad(self)
+    }
+
+    fn calibrate_tadm_offset(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::calibrate_tadm_offset(self)
+    }
+
+    fn prepare_on_the_fly_dispense(
+        &self,
+        volume: Microliters,
+        stop_back_volume: Microliters,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+        dispense_pattern: &str,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::prepare_on_the_fly_dispense(
+            self,
+            volume,
+            stop_back_volume,
+            transport_air_volume,
+            dispense_speed,
+            cutoff_speed,
+            dispense_pattern,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+            measurement_id,
+        )
+    }
+
+    fn start_on_the_fly_dispense(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::start_on_the_fly_dispense(self)
+    }
+
+    fn stop_on_the_fly_dispense(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::stop_on_the_fly_dispense(self)
+    }
+
+    fn read_on_the_fly_dispense_errors(
+        &self,
+    ) -> impl Future<Output = Result<ReadOnTheFlyDispenseErrorsReply, Error>> + Send {
+        NimbusCoreChannel::read_on_the_fly_dispense_errors(self)
+    }
+
+    fn dispenser_aspirate(
+        &self,
+        volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::dispenser_aspirate(self, volume, aspirate_speed)
+    }
+
+    fn dispenser_dispense(
+        &self,
+        volume: Microliters,
+        stop_back_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::dispenser_dispense(
+            self,
+            volume,
+            stop_back_volume,
+            dispense_speed,
+            cutoff_speed,
+        )
+    }
+
+    fn get_current_dispenser_volume(
+        &self,
+    ) -> impl Future<Output = Result</* volume= */ Microliters, Error>> + Send {
+        NimbusCoreChannel::get_current_dispenser_volume(self)
+    }
+
+    fn get_liquid_height(
+        &self,
+    ) -> impl Future<Output = Result</* liquid_height= */ Millimeters, Error>> + Send {
+        NimbusCoreChannel::get_liquid_height(self)
+    }
+
+    fn z_seek_obstacle_position(
+        &self,
+        z_position: Millimeters,
+        z_min_position: Millimeters,
+        z_final_position: Millimeters,
+        z_velocity: u32,
+    ) -> impl Future<Output = Result<ZSeekObstaclePositionReply, Error>> + Send {
+        NimbusCoreChannel::z_seek_obstacle_position(
+            self,
+            z_position,
+            z_min_position,
+            z_final_position,
+            z_velocity,
+        )
+    }
+
+    fn z_seek_lld_position(
+        &self,
+        z_position: Millimeters,
+        z_min_position: Millimeters,
+        z_final_position: Millimeters,
+        z_velocity: u32,
+        capacitive_lld_steepness: u32,
+        capacitive_lld_offset: u32,
+    ) -> impl Future<Output = Result<ZSeekLldPositionReply, Error>> + Send {
+        NimbusCoreChannel::z_seek_lld_position(
+            self,
+            z_position,
+            z_min_position,
+            z_final_position,
+            z_velocity,
+            capacitive_lld_steepness,
+            capacitive_lld_offset,
+        )
+    }
+
+    fn calibrate_squeeze_check_torque(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::calibrate_squeeze_check_torque(self)
+    }
+
+    fn calibrate_squeeze_position(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::calibrate_squeeze_position(self)
+    }
+
+    fn set_z_liquid_seek_speed(
+        &self,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::set_z_liquid_seek_speed(self, velocity)
+    }
+
+    fn get_z_liquid_seek_speed(
+        &self,
+    ) -> impl Future<Output = Result</* velocity= */ u32, Error>> + Send {
+        NimbusCoreChannel::get_z_liquid_seek_speed(self)
+    }
+
+    fn z_move_absolute_2_speed(
+        &self,
+        position: Millimeters,
+        speed_switching_position: Millimeters,
+        velocity: u32,
+        lower_section_velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::z_move_absolute_2_speed(
+            self,
+            position,
+            speed_switching_position,
+            velocity,
+            lower_section_velocity,
+        )
+    }
+
+    fn z_servo_off(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::z_servo_off(self)
+    }
+
+    fn y_servo_off(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::y_servo_off(self)
+    }
+
+    fn save_counters(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::save_counters(self)
+    }
+
+    fn get_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetCalibrationValuesReply, Error>> + Send {
+        NimbusCoreChannel::get_calibration_values(self)
+    }
+
+    fn get_extended_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetExtendedCalibrationValuesReply, Error>> + Send {
+        NimbusCoreChannel::get_extended_calibration_values(self)
+    }
+
+    fn get_counters(&self) -> impl Future<Output = Result<GetCountersReply, Error>> + Send {
+        NimbusCoreChannel::get_counters(self)
+    }
+
+    fn get_technical_info(
+        &self,
+    ) -> impl Future<Output = Result</* channel_info= */ String, Error>> + Send {
+        NimbusCoreChannel::get_technical_info(self)
+    }
+
+    fn set_counters(
+        &self,
+        tip_pickup_counter: u32,
+        tip_eject_counter: u32,
+        aspirate_counter: u32,
+        dispense_counter: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::set_counters(
+            self,
+            tip_pickup_counter,
+            tip_eject_counter,
+            aspirate_counter,
+            dispense_counter,
+        )
+    }
+
+    fn measure_lld_frequency(
+        &self,
+        seconds: u16,
+    ) -> impl Future<Output = Result</* detects_per_minute= */ u16, Error>> + Send {
+        NimbusCoreChannel::measure_lld_frequency(self, seconds)
+    }
+
+    fn get_positions(&self) -> impl Future<Output = Result<GetPositionsReply, Error>> + Send {
+        NimbusCoreChannel::get_positions(self)
+    }
+
+    fn reset(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannel::reset(self)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreChannel::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreChannel::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreChannel::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreChannel::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreChannel::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreChannel::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreChannelFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreChannelApi for NimbusCoreChannelFake {
+    fn command(
+        &self,
+        command_timeout: i32,
+        request: &str,
+    ) -> impl Future<Output = Result</* response= */ String, Error>> + Send {
+        let reply = self.recorder.record(
+            "command",
+            vec![
+                ("command_timeout", format!("{:?}", command_timeout)),
+                ("request", format!("{:?}", request)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn send(&self, request: &str) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("send", vec![("request", format!("{:?}", request))]);
+        async move { reply }
+    }
+
+    fn receive_no_wait(
+        &self,
+    ) -> impl Future<Output = Result</* response= */ String, Error>> + Send {
+        let reply = self.recorder.record("receive_no_wait", vec![]);
+        async move { reply }
+    }
+
+    fn receive(
+        &self,
+        command_timeout: i32,
+    ) -> impl Future<Output = Result</* response= */ String, Error>> + Send {
+        let reply = self.recorder.record(
+            "receive",
+            vec![("command_timeout", format!("{:?}", command_timeout))],
+        );
+        async move { reply }
+    }
+
+    fn last_command(&self) -> impl Future<Output = Result</* request= */ String, Error>> + Send {
+        let reply = self.recorder.record("last_command", vec![]);
+        async move { reply }
+    }
+
+    fn pickup_tip(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pickup_tip",
+            vec![
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("z_final", format!("{:?}", z_final)),
+                ("volume", format!("{:?}", volume)),
+                ("collet_check", format!("{:?}", collet_check)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn pickup_needle(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        length: u16,
+        collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pickup_needle",
+            vec![
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("z_final", format!("{:?}", z_final)),
+                ("volume", format!("{:?}", volume)),
+                ("length", format!("{:?}", length)),
+                ("collet_check", format!("{:?}", collet_check)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn drop_tip(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "drop_tip",
+            vec![
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("z_final", format!("{:?}", z_final)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn drop_tip_no_z_final(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "drop_tip_no_z_final",
+            vec![
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn aspirate_capacitive_lld(
+        &self,
+        aspirate_mode: i16,
+        liquid_seek_height: Millimeters,
+        submerge_depth: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        clot_check_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        blowout_volume: Microliters,
+        prewet_volume: Microliters,
+        aspirate_volume: Microliters,
+        transport_air_volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        capacitive_lld_steepness: u32,
+        capacitive_lld_offset: u32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "aspirate_capacitive_lld",
+            vec![
+                ("aspirate_mode", format!("{:?}", aspirate_mode)),
+                ("liquid_seek_height", format!("{:?}", liquid_seek_height)),
+                ("submerge_depth", format!("{:?}", submerge_depth)),
+                ("follow_depth", format!("{:?}", follow_depth)),
+                ("z_min_position", format!("{:?}", z_min_position)),
+                ("clot_check_height", format!("{:?}", clot_check_height)),
+                ("z_final", format!("{:?}", z_final)),
+                ("liquid_exit_speed", format!("{:?}", liquid_exit_speed)),
+                ("blowout_volume", format!("{:?}", blowout_volume)),
+                ("prewet_volume", format!("{:?}", prewet_volume)),
+                ("aspirate_volume", format!("{:?}", aspirate_volume)),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume),
+                ),
+                ("aspirate_speed", format!("{:?}", aspirate_speed)),
+                ("settling_time", format!("{:?}", settling_time)),
+                ("mix_volume", format!("{:?}", mix_volume)),
+                ("mix_cycles", format!("{:?}", mix_cycles)),
+                ("mix_position", format!("{:?}", mix_position)),
+                ("mix_follow_distance", format!("{:?}", mix_follow_distance)),
+                ("mix_speed", format!("{:?}", mix_speed)),
+                ("tube_section_height", format!("{:?}", tube_section_height)),
+                ("tube_section_ratio", format!("{:?}", tube_section_ratio)),
+                (
+                    "capacitive_lld_steepness",
+                    format!("{:?}", capacitive_lld_steepness),
+                ),
+                (
+                    "capacitive_lld_offset",
+                    format!("{:?}", capacitive_lld_offset),
+                ),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                ("limit_curve_index", format!("{:?}", limit_curve_index)),
+                ("recording_mode", format!("{:?}", recording_mode)),
+                ("measurement_id", format!("{:?}", measurement_id)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn aspirate_pressure_lld(
+        &self,
+        aspirate_mode: i16,
+        liquid_seek_height: Millimeters,
+        submerge_depth: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        blowout_volume: Microliters,
+        prewet_volume: Microliters,
+        aspirate_volume: Microliters,
+        transport_air_volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        lld_mode: i16,
+        capacitive_lld_steepness: u32,
+        capacitive_lld_offset: u32,
+        pressure_lld_steepness: u32,
+        pressure_lld_offset: u32,
+        seek_speed: MmPerSecond,
+        lld_height_difference: i32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+        tip_is_filtered: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "aspirate_pressure_lld",
+            vec![
+                ("aspirate_mode", format!("{:?}", aspirate_mode)),
+                ("liquid_seek_height", format!("{:?}", liquid_seek_height)),
+                ("submerge_depth", format!("{:?}", submerge_depth)),
+                ("follow_depth", format!("{:?}", follow_depth)),
+                ("z_min_position", format!("{:?}", z_min_position)),
+                ("z_final", format!("{:?}", z_final)),
+                ("liquid_exit_speed", format!("{:?}", liquid_exit_speed)),
+                ("blowout_volume", format!("{:?}", blowout_volume)),
+                ("prewet_volume", format!("{:?}", prewet_volume)),
+                ("aspirate_volume", format!("{:?}", aspirate_volume)),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume),
+                ),
+                ("aspirate_speed", format!("{:?}", aspirate_speed)),
+                ("settling_time", format!("{:?}", settling_time)),
+                ("mix_volume", format!("{:?}", mix_volume)),
+                ("mix_cycles", format!("{:?}", mix_cycles)),
+                ("mix_position", format!("{:?}", mix_position)),
+                ("mix_follow_distance", format!("{:?}", mix_follow_distance)),
+                ("mix_speed", format!("{:?}", mix_speed)),
+                ("tube_section_height", format!("{:?}", tube_section_height)),
+                ("tube_section_ratio", format!("{:?}", tube_section_ratio)),
+                ("lld_mode", format!("{:?}", lld_mode)),
+                (
+                    "capacitive_lld_steepness",
+                    format!("{:?}", capacitive_lld_steepness),
+                ),
+                (
+                    "capacitive_lld_offset",
+                    format!("{:?}", capacitive_lld_offset),
+                ),
+                (
+                    "pressure_lld_steepness",
+                    format!("{:?}", pressure_lld_steepness),
+                ),
+                ("pressure_lld_offset", format!("{:?}", pressure_lld_offset)),
+                ("seek_speed", format!("{:?}", seek_speed)),
+                (
+                    "lld_height_difference",
+                    format!("{:?}", lld_height_difference),
+                ),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                ("limit_curve_index", format!("{:?}", limit_curve_index)),
+                ("recording_mode", format!("{:?}", recording_mode)),
+                ("measurement_id", format!("{:?}", measurement_id)),
+                ("tip_is_filtered", format!("{:?}", tip_is_filtered)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn aspirate(
+        &self,
+        aspirate_mode: i16,
+        aspirate_height: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        air_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        blowout_volume: Microliters,
+        prewet_volume: Microliters,
+        aspirate_volume: Microliters,
+        transport_air_volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        bottom_search: bool,
+        aspirate_offset: i32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "aspirate",
+            vec![
+                ("aspirate_mode", format!("{:?}", aspirate_mode)),
+                ("aspirate_height", format!("{:?}", aspirate_height)),
+                ("follow_depth", format!("{:?}", follow_depth)),
+                ("z_min_position", format!("{:?}", z_min_position)),
+                ("air_height", format!("{:?}", air_height)),
+                ("z_final", format!("{:?}", z_final)),
+                ("liquid_exit_speed", format!("{:?}", liquid_exit_speed)),
+                ("blowout_volume", format!("{:?}", blowout_volume)),
+                ("prewet_volume", format!("{:?}", prewet_volume)),
+                ("aspirate_volume", format!("{:?}", aspirate_volume)),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume),
+                ),
+                ("aspirate_speed", format!("{:?}", aspirate_speed)),
+                ("settling_time", format!("{:?}", settling_time)),
+                ("mix_volume", format!("{:?}", mix_volume)),
+                ("mix_cycles", format!("{:?}", mix_cycles)),
+                ("mix_position", format!("{:?}", mix_position)),
+                ("mix_follow_distance", format!("{:?}", mix_follow_distance)),
+                ("mix_speed", format!("{:?}", mix_speed)),
+                ("tube_section_height", format!("{:?}", tube_section_height)),
+                ("tube_section_ratio", format!("{:?}", tube_section_ratio)),
+                ("bottom_search", format!("{:?}", bottom_search)),
+                ("aspirate_offset", format!("{:?}", aspirate_offset)),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                ("limit_curve_index", format!("{:?}", limit_curve_index)),
+                ("recording_mode", format!("{:?}", recording_mode)),
+                ("measurement_id", format!("{:?}", measurement_id)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn dispense_lld(
+        &self,
+        liquid_seek_height: Millimeters,
+        submerge_depth: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        dispense_volume: Microliters,
+        stop_back_volume: Microliters,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        capacitive_lld_steepness: u32,
+        capacitive_lld_offset: u32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "dispense_lld",
+            vec![
+                ("liquid_seek_height", format!("{:?}", liquid_seek_height)),
+                ("submerge_depth", format!("{:?}", submerge_depth)),
+                ("follow_depth", format!("{:?}", follow_depth)),
+                ("z_min_position", format!("{:?}", z_min_position)),
+                ("z_final", format!("{:?}", z_final)),
+                ("liquid_exit_speed", format!("{:?}", liquid_exit_speed)),
+                ("dispense_volume", format!("{:?}", dispense_volume)),
+                ("stop_back_volume", format!("{:?}", stop_back_volume)),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume),
+                ),
+                ("dispense_speed", format!("{:?}", dispense_speed)),
+                ("cutoff_speed", format!("{:?}", cutoff_speed)),
+                ("settling_time", format!("{:?}", settling_time)),
+                ("mix_volume", format!("{:?}", mix_volume)),
+                ("mix_cycles", format!("{:?}", mix_cycles)),
+                ("mix_position", format!("{:?}", mix_position)),
+                ("mix_follow_distance", format!("{:?}", mix_follow_distance)),
+                ("mix_speed", format!("{:?}", mix_speed)),
+                ("tube_section_height", format!("{:?}", tube_section_height)),
+                ("tube_section_ratio", format!("{:?}", tube_section_ratio)),
+                (
+                    "capacitive_lld_steepness",
+                    format!("{:?}", capacitive_lld_steepness),
+                ),
+                (
+                    "capacitive_lld_offset",
+                    format!("{:?}", capacitive_lld_offset),
+                ),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                ("limit_curve_index", format!("{:?}", limit_curve_index)),
+                ("recording_mode", format!("{:?}", recording_mode)),
+                ("measurement_id", format!("{:?}", measurement_id)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn dispense(
+        &self,
+        dispense_height: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        air_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        dispense_volume: Microliters,
+        stop_back_volume: Microliters,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        mix_volume: Microliters,
+        mix_cycles: u32,
+        mix_position: Millimeters,
+        mix_follow_distance: Millimeters,
+        mix_speed: MicrolitersPerSecond,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        bottom_search: bool,
+        dispense_offset: i32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "dispense",
+            vec![
+                ("dispense_height", format!("{:?}", dispense_height)),
+                ("follow_depth", format!("{:?}", follow_depth)),
+                ("z_min_position", format!("{:?}", z_min_position)),
+                ("air_height", format!("{:?}", air_height)),
+                ("z_final", format!("{:?}", z_final)),
+                ("liquid_exit_speed", format!("{:?}", liquid_exit_speed)),
+                ("dispense_volume", format!("{:?}", dispense_volume)),
+                ("stop_back_volume", format!("{:?}", stop_back_volume)),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume),
+                ),
+                ("dispense_speed", format!("{:?}", dispense_speed)),
+                ("cutoff_speed", format!("{:?}", cutoff_speed)),
+                ("settling_time", format!("{:?}", settling_time)),
+                ("mix_volume", format!("{:?}", mix_volume)),
+                ("mix_cycles", format!("{:?}", mix_cycles)),
+                ("mix_position", format!("{:?}", mix_position)),
+                ("mix_follow_distance", format!("{:?}", mix_follow_distance)),
+                ("mix_speed", format!("{:?}", mix_speed)),
+                ("tube_section_height", format!("{:?}", tube_section_height)),
+                ("tube_section_ratio", format!("{:?}", tube_section_ratio)),
+                ("bottom_search", format!("{:?}", bottom_search)),
+                ("dispense_offset", format!("{:?}", dispense_offset)),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                ("limit_curve_index", format!("{:?}", limit_curve_index)),
+                ("recording_mode", format!("{:?}", recording_mode)),
+                ("measurement_id", format!("{:?}", measurement_id)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn empty_tip(
+        &self,
+        dispense_height: Millimeters,
+        follow_depth: Millimeters,
+        z_min_position: Millimeters,
+        air_height: Millimeters,
+        z_final: Millimeters,
+        liquid_exit_speed: MmPerSecond,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+        settling_time: u32,
+        tube_section_height: Millimeters,
+        tube_section_ratio: i32,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "empty_tip",
+            vec![
+                ("dispense_height", format!("{:?}", dispense_height)),
+                ("follow_depth", format!("{:?}", follow_depth)),
+                ("z_min_position", format!("{:?}", z_min_position)),
+                ("air_height", format!("{:?}", air_height)),
+                ("z_final", format!("{:?}", z_final)),
+                ("liquid_exit_speed", format!("{:?}", liquid_exit_speed)),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume),
+                ),
+                ("dispense_speed", format!("{:?}", dispense_speed)),
+                ("cutoff_speed", format!("{:?}", cutoff_speed)),
+                ("settling_time", format!("{:?}", settling_time)),
+                ("tube_section_height", format!("{:?}", tube_section_height)),
+                ("tube_section_ratio", format!("{:?}", tube_section_ratio)),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                ("limit_curve_index", format!("{:?}", limit_curve_index)),
+                ("recording_mode", format!("{:?}", recording_mode)),
+                ("measurement_id", format!("{:?}", measurement_id)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn y_pre_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("y_pre_initialize", vec![]);
+        async move { reply }
+    }
+
+    fn y_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("y_initialize", vec![]);
+        async move { reply }
+    }
+
+    fn y_move_relative(
+        &self,
+        distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_relative",
+            vec![("distance", format!("{:?}", distance))],
+        );
+        async move { reply }
+    }
+
+    fn y_move_relative_speed(
+        &self,
+        distance: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_relative_speed",
+            vec![
+                ("distance", format!("{:?}", distance)),
+                ("acceleration", format!("{:?}", acceleration)),
+                ("velocity", format!("{:?}", velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn y_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_absolute",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn y_move_absolute_speed(
+        &self,
+        position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_absolute_speed",
+            vec![
+                ("position", format!("{:?}", position)),
+                ("acceleration", format!("{:?}", acceleration)),
+                ("velocity", format!("{:?}", velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn y_seek_lld(
+        &self,
+        position: Millimeters,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_seek_lld",
+            vec![
+                ("position", format!("{:?}", position)),
+                ("velocity", format!("{:?}", velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn y_move_negative_stall(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("y_move_negative_stall", vec![]);
+        async move { reply }
+    }
+
+    fn y_move_absolute_gripper(
+        &self,
+        position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_absolute_gripper",
+            vec![
+                ("position", format!("{:?}", position)),
+                ("acceleration", format!("{:?}", acceleration)),
+                ("velocity", format!("{:?}", velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("z_initialize", vec![]);
+        async move { reply }
+    }
+
+    fn z_move_safe(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("z_move_safe", vec![]);
+        async move { reply }
+    }
+
+    fn z_move_relative(
+        &self,
+        distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_relative",
+            vec![("distance", format!("{:?}", distance))],
+        );
+        async move { reply }
+    }
+
+    fn z_move_relative_speed(
+        &self,
+        distance: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_relative_speed",
+            vec![
+                ("distance", format!("{:?}", distance)),
+                ("acceleration", format!("{:?}", acceleration)),
+                ("velocity", format!("{:?}", velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_absolute",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn z_move_absolute_speed(
+        &self,
+        position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_absolute_speed",
+            vec![
+                ("position", format!("{:?}", position)),
+                ("acceleration", format!("{:?}", acceleration)),
+                ("velocity", format!("{:?}", velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_move_traverse(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_traverse",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn z_seek_lld(
+        &self,
+        position: Millimeters,
+        min_position: Millimeters,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_seek_lld",
+            vec![
+                ("position", format!("{:?}", position)),
+                ("min_position", format!("{:?}", min_position)),
+                ("velocity", format!("{:?}", velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_move_absolute_gripper(
+        &self,
+        position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_absolute_gripper",
+            vec![
+                ("position", format!("{:?}", position)),
+                ("acceleration", format!("{:?}", acceleration)),
+                ("velocity", format!("{:?}", velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn dispense_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("dispense_initialize", vec![]);
+        async move { reply }
+    }
+
+    fn dispense_empty(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("dispense_empty", vec![]);
+        async move { reply }
+    }
+
+    fn dispense_move_relative(
+        &self,
+        distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "dispense_move_relative",
+            vec![("distance", format!("{:?}", distance))],
+        );
+        async move { reply }
+    }
+
+    fn squeeze_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("squeeze_initialize", vec![]);
+        async move { reply }
+    }
+
+    fn squeeze_on(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("squeeze_on", vec![]);
+        async move { reply }
+    }
+
+    fn squeeze_off(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("squeeze_off", vec![]);
+        async move { reply }
+    }
+
+    fn lld_on(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("lld_on", vec![]);
+        async move { reply }
+    }
+
+    fn lld_off(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("lld_off", vec![]);
+        async move { reply }
+    }
+
+    fn paired_channel(&self, channel: i16) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "paired_channel",
+            vec![("channel", format!("{:?}", channel))],
+        );
+        async move { reply }
+    }
+
+    fn synchronize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("synchronize", vec![]);
+        async move { reply }
+    }
+
+    fn is_initialized(
+        &self,
+    ) -> impl Future<Output = Result</* initialized= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_initialized", vec![]);
+        async move { reply }
+    }
+
+    fn is_tip_present(&self) -> impl Future<Output = Result</* present= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_tip_present", vec![]);
+        async move { reply }
+    }
+
+    fn get_lld_status(&self) -> impl Future<Output = Result</* status= */ i16, Error>> + Send {
+        let reply = self.recorder.record("get_lld_status", vec![]);
+        async move { reply }
+    }
+
+    fn get_sensor_status(&self) -> impl Future<Output = Result</* status= */ i16, Error>> + Send {
+        let reply = self.recorder.record("get_sensor_status", vec![]);
+        async move { reply }
+    }
+
+    fn get_y_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ Millimeters, Error>> + Send {
+        let reply = self.recorder.record("get_y_position", vec![]);
+        async move { reply }
+    }
+
+    fn get_y_home_offset(
+        &self,
+    ) -> impl Future<Output = Result</* home_offset= */ i32, Error>> + Send {
+        let reply = self.recorder.record("get_y_home_offset", vec![]);
+        async move { reply }
+    }
+
+    fn set_y_home_offset(
+        &self,
+        home_offset: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_y_home_offset",
+            vec![("home_offset", format!("{:?}", home_offset))],
+        );
+        async move { reply }
+    }
+
+    fn get_z_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ Millimeters, Error>> + Send {
+        let reply = self.recorder.record("get_z_position", vec![]);
+        async move { reply }
+    }
+
+    fn get_z_home_offset(
+        &self,
+    ) -> impl Future<Output = Result</* home_offset= */ i32, Error>> + Send {
+        let reply = self.recorder.record("get_z_home_offset", vec![]);
+        async move { reply }
+    }
+
+    fn set_z_home_offset(
+        &self,
+        home_offset: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_z_home_offset",
+            vec![("home_offset", format!("{:?}", home_offset))],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_position_reset(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("calibrate_position_reset", vec![]);
+        async move { reply }
+    }
+
+    fn calibrate_lld(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("calibrate_lld", vec![]);
+        async move { reply }
+    }
+
+    fn calibrate_squeeze(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_squeeze",
+            vec![
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_tip_height(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_tip_height",
+            vec![
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("z_final", format!("{:?}", z_final)),
+                ("volume", format!("{:?}", volume)),
+                ("collet_check", format!("{:?}", collet_check)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_dispense_check(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result</* steps= */ i32, Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_dispense_check",
+            vec![
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("z_final", format!("{:?}", z_final)),
+                ("volume", format!("{:?}", volume)),
+                ("collet_check", format!("{:?}", collet_check)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_dispense_check_data(
+        &self,
+        direction: i16,
+        index: i32,
+    ) -> impl Future<Output = Result</* result= */ String, Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_dispense_check_data",
+            vec![
+                ("direction", format!("{:?}", direction)),
+                ("index", format!("{:?}", index)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_touchoff(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("calibrate_touchoff", vec![]);
+        async move { reply }
+    }
+
+    fn erase_limit_curves(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("erase_limit_curves", vec![]);
+        async move { reply }
+    }
+
+    fn create_limit_curve(
+        &self,
+        name: &str,
+        lower_limit_x: impl AsRef<[u16]> + Send,
+        lower_limit_y: impl AsRef<[i16]> + Send,
+        upper_limit_x: impl AsRef<[u16]> + Send,
+        upper_limit_y: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<CreateLimitCurveReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "create_limit_curve",
+            vec![
+                ("name", format!("{:?}", name)),
+                ("lower_limit_x", format!("{:?}", lower_limit_x.as_ref())),
+                ("lower_limit_y", format!("{:?}", lower_limit_y.as_ref())),
+                ("upper_limit_x", format!("{:?}", upper_limit_x.as_ref())),
+                ("upper_limit_y", format!("{:?}", upper_limit_y.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn reset_tadm_fifo(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("reset_tadm_fifo", vec![]);
+        async move { reply }
+    }
+
+    fn retrieve_tadm_data(
+        &self,
+    ) -> impl Future<Output = Result<RetrieveTadmDataReply, Error>> + Send {
+        let reply = self.recorder.record("retrieve_tadm_data", vec![]);
+        async move { reply }
+    }
+
+    fn get_limit_curve_names(
+        &self,
+    ) -> impl Future<Output = Result<GetLimitCurveNamesReply, Error>> + Send {
+        let reply = self.recorder.record("get_limit_curve_names", vec![]);
+        async move { reply }
+    }
+
+    fn get_limit_curve_info(
+        &self,
+        name: &str,
+    ) -> impl Future<Output = Result<GetLimitCurveInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "get_limit_curve_info",
+            vec![("name", format!("{:?}", name))],
+        );
+        async move { reply }
+    }
+
+    fn set_tadm_enable(&self, enable: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("set_tadm_enable", vec![("enable", format!("{:?}", enable))]);
+        async move { reply }
+    }
+
+    fn get_tadm_enable(&self) -> impl Future<Output = Result</* enable= */ bool, Error>> + Send {
+        let reply = self.recorder.record("get_tadm_enable", vec![]);
+        async move { reply }
+    }
+
+    fn enable_mad(
+        &self,
+        pressure_threshold: u16,
+        minimum_pressure_difference: u16,
+        maximum_pressure_difference: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "enable_mad",
+            vec![
+                ("pressure_threshold", format!("{:?}", pressure_threshold)),
+                (
+                    "minimum_pressure_difference",
+                    format!("{:?}", minimum_pressure_difference),
+                ),
+                (
+                    "maximum_pressure_difference",
+                    format!("{:?}", maximum_pressure_difference),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn disable_mad(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("disable_mad", vec![]);
+        async move { reply }
+    }
+
+    fn enable_adc(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("enable_adc", vec![]);
+        async move { reply }
+    }
+
+    fn disable_adc(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("disable_adc", vec![]);
+        async move { reply }
+    }
+
+    fn set_z_default_speed(
+        &self,
+        speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_z_default_speed",
+            vec![("speed", format!("{:?}", speed))],
+        );
+        async move { reply }
+    }
+
+    fn get_z_default_speed(
+        &self,
+    ) -> impl Future<Output = Result</* speed= */ MmPerSecond, Error>> + Send {
+        let reply = self.recorder.record("get_z_default_speed", vec![]);
+        async move { reply }
+    }
+
+    fn z_seek_obstacle(
+        &self,
+        z_position: Millimeters,
+        z_min_position: Millimeters,
+        z_final_position: Millimeters,
+        z_velocity: u32,
+    ) -> impl Future<Output = Result</* obstacle_detected= */ bool, Error>> + Send {
+        let reply = self.recorder.record(
+            "z_seek_obstacle",
+            vec![
+                ("z_position", format!("{:?}", z_position)),
+                ("z_min_position", format!("{:?}", z_min_position)),
+                ("z_final_position", format!("{:?}", z_final_position)),
+                ("z_velocity", format!("{:?}", z_velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_pressure(&self) -> impl Future<Output = Result</* pressure= */ i16, Error>> + Send {
+        let reply = self.recorder.record("get_pressure", vec![]);
+        async move { reply }
+    }
+
+    fn get_y_resolution(
+        &self,
+    ) -> impl Future<Output = Result</* resolution= */ i32, Error>> + Send {
+        let reply = self.recorder.record("get_y_resolution", vec![]);
+        async move { reply }
+    }
+
+    fn set_y_resolution(&self, resolution: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_y_resolution",
+            vec![("resolution", format!("{:?}", resolution))],
+        );
+        async move { reply }
+    }
+
+    fn pressure_mode(&self, mode: i16) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("pressure_mode", vec![("mode", format!("{:?}", mode))]);
+        async move { reply }
+    }
+
+    fn pressure_gain(&self, gain: i16) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("pressure_gain", vec![("gain", format!("{:?}", gain))]);
+        async move { reply }
+    }
+
+    fn pressure_offset(&self, offset: i16) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("pressure_offset", vec![("offset", format!("{:?}", offset))]);
+        async move { reply }
+    }
+
+    fn pressure_shift(&self, shift: i16) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("pressure_shift", vec![("shift", format!("{:?}", shift))]);
+        async move { reply }
+    }
+
+    fn store_pressure_gain(
+        &self,
+        data_register: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "store_pressure_gain",
+            vec![("data_register", format!("{:?}", data_register))],
+        );
+        async move { reply }
+    }
+
+    fn store_pressure_offset(
+        &self,
+        data_register: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "store_pressure_offset",
+            vec![("data_register", format!("{:?}", data_register))],
+        );
+        async move { reply }
+    }
+
+    fn leak_check(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
+        collet_check: i16,
+        time: u32,
+        test_type: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "leak_check",
+            vec![
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("z_final", format!("{:?}", z_final)),
+                ("tip_volume", format!("{:?}", tip_volume)),
+                ("collet_check", format!("{:?}", collet_check)),
+                ("time", format!("{:?}", time)),
+                ("test_type", format!("{:?}", test_type)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_configuration(
+        &self,
+        index: i16,
+    ) -> impl Future<Output = Result<GetConfigurationReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("get_configuration", vec![("index", format!("{:?}", index))]);
+        async move { reply }
+    }
+
+    fn set_configuration(
+        &self,
+        index: i16,
+        enable: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_configuration",
+            vec![
+                ("index", format!("{:?}", index)),
+                ("enable", format!("{:?}", enable)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_potentiometer_data(
+        &self,
+    ) -> impl Future<Output = Result<GetPotentiometerDataReply, Error>> + Send {
+        let reply = self.recorder.record("get_potentiometer_data", vec![]);
+        async move { reply }
+    }
+
+    fn get_pressure_sensor_ad(
+        &self,
+    ) -> impl Future<Output = Result<GetPressureSensorADReply, Error>> + Send {
+        let reply = self.recorder.record("get_pressure_sensor_ad", vec![]);
+        async move { reply }
+    }
+
+    fn calibrate_tadm_offset(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("calibrate_tadm_offset", vec![]);
+        async move { reply }
+    }
+
+    fn prepare_on_the_fly_dispense(
+        &self,
+        volume: Microliters,
+        stop_back_volume: Microliters,
+        transport_air_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+        dispense_pattern: &str,
+        tadm_enabled: bool,
+        limit_curve_index: u32,
+        recording_mode: u16,
+        measurement_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "prepare_on_the_fly_dispense",
+            vec![
+                ("volume", format!("{:?}", volume)),
+                ("stop_back_volume", format!("{:?}", stop_back_volume)),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume),
+                ),
+                ("dispense_speed", format!("{:?}", dispense_speed)),
+                ("cutoff_speed", format!("{:?}", cutoff_speed)),
+                ("dispense_pattern", format!("{:?}", dispense_pattern)),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                ("limit_curve_index", format!("{:?}", limit_curve_index)),
+                ("recording_mode", format!("{:?}", recording_mode)),
+                ("measurement_id", format!("{:?}", measurement_id)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn start_on_the_fly_dispense(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("start_on_the_fly_dispense", vec![]);
+        async move { reply }
+    }
+
+    fn stop_on_the_fly_dispense(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("stop_on_the_fly_dispense", vec![]);
+        async move { reply }
+    }
+
+    fn read_on_the_fly_dispense_errors(
+        &self,
+    ) -> impl Future<Output = Result<ReadOnTheFlyDispenseErrorsReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("read_on_the_fly_dispense_errors", vec![]);
+        async move { reply }
+    }
+
+    fn dispenser_aspirate(
+        &self,
+        volume: Microliters,
+        aspirate_speed: MicrolitersPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "dispenser_aspirate",
+            vec![
+                ("volume", format!("{:?}", volume)),
+                ("aspirate_speed", format!("{:?}", aspirate_speed)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn dispenser_dispense(
+        &self,
+        volume: Microliters,
+        stop_back_volume: Microliters,
+        dispense_speed: MicrolitersPerSecond,
+        cutoff_speed: MicrolitersPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "dispenser_dispense",
+            vec![
+                ("volume", format!("{:?}", volume)),
+                ("stop_back_volume", format!("{:?}", stop_back_volume)),
+                ("dispense_speed", format!("{:?}", dispense_speed)),
+                ("cutoff_speed", format!("{:?}", cutoff_speed)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_current_dispenser_volume(
+        &self,
+    ) -> impl Future<Output = Result</* volume= */ Microliters, Error>> + Send {
+        let reply = self.recorder.record("get_current_dispenser_volume", vec![]);
+        async move { reply }
     }
-}
 
-impl PigletSerialize for MSlice<'_, TadmModes> {
-    fn serialize(&self, bytes: &mut BytesMut) {
-        bytes.put_u8(Self::TYPE_ID);
-        bytes.put_u8(0);
-        bytes.put_u16_le(4 * self.0.len() as u16);
-        for v in self.0.as_ref() {
-            bytes.put_i32_le(*v as i32);
-        }
+    fn get_liquid_height(
+        &self,
+    ) -> impl Future<Output = Result</* liquid_height= */ Millimeters, Error>> + Send {
+        let reply = self.recorder.record("get_liquid_height", vec![]);
+        async move { reply }
+    }
+
+    fn z_seek_obstacle_position(
+        &self,
+        z_position: Millimeters,
+        z_min_position: Millimeters,
+        z_final_position: Millimeters,
+        z_velocity: u32,
+    ) -> impl Future<Output = Result<ZSeekObstaclePositionReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "z_seek_obstacle_position",
+            vec![
+                ("z_position", format!("{:?}", z_position)),
+                ("z_min_position", format!("{:?}", z_min_position)),
+                ("z_final_position", format!("{:?}", z_final_position)),
+                ("z_velocity", format!("{:?}", z_velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_seek_lld_position(
+        &self,
+        z_position: Millimeters,
+        z_min_position: Millimeters,
+        z_final_position: Millimeters,
+        z_velocity: u32,
+        capacitive_lld_steepness: u32,
+        capacitive_lld_offset: u32,
+    ) -> impl Future<Output = Result<ZSeekLldPositionReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "z_seek_lld_position",
+            vec![
+                ("z_position", format!("{:?}", z_position)),
+                ("z_min_position", format!("{:?}", z_min_position)),
+                ("z_final_position", format!("{:?}", z_final_position)),
+                ("z_velocity", format!("{:?}", z_velocity)),
+                (
+                    "capacitive_lld_steepness",
+                    format!("{:?}", capacitive_lld_steepness),
+                ),
+                (
+                    "capacitive_lld_offset",
+                    format!("{:?}", capacitive_lld_offset),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_squeeze_check_torque(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("calibrate_squeeze_check_torque", vec![]);
+        async move { reply }
+    }
+
+    fn calibrate_squeeze_position(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("calibrate_squeeze_position", vec![]);
+        async move { reply }
+    }
+
+    fn set_z_liquid_seek_speed(
+        &self,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_z_liquid_seek_speed",
+            vec![("velocity", format!("{:?}", velocity))],
+        );
+        async move { reply }
+    }
+
+    fn get_z_liquid_seek_speed(
+        &self,
+    ) -> impl Future<Output = Result</* velocity= */ u32, Error>> + Send {
+        let reply = self.recorder.record("get_z_liquid_seek_speed", vec![]);
+        async move { reply }
+    }
+
+    fn z_move_absolute_2_speed(
+        &self,
+        position: Millimeters,
+        speed_switching_position: Millimeters,
+        velocity: u32,
+        lower_section_velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_absolute_2_speed",
+            vec![
+                ("position", format!("{:?}", position)),
+                (
+                    "speed_switching_position",
+                    format!("{:?}", speed_switching_position),
+                ),
+                ("velocity", format!("{:?}", velocity)),
+                (
+                    "lower_section_velocity",
+                    format!("{:?}", lower_section_velocity),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_servo_off(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("z_servo_off", vec![]);
+        async move { reply }
+    }
+
+    fn y_servo_off(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("y_servo_off", vec![]);
+        async move { reply }
+    }
+
+    fn save_counters(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("save_counters", vec![]);
+        async move { reply }
+    }
+
+    fn get_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetCalibrationValuesReply, Error>> + Send {
+        let reply = self.recorder.record("get_calibration_values", vec![]);
+        async move { reply }
+    }
+
+    fn get_extended_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetExtendedCalibrationValuesReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("get_extended_calibration_values", vec![]);
+        async move { reply }
+    }
+
+    fn get_counters(&self) -> impl Future<Output = Result<GetCountersReply, Error>> + Send {
+        let reply = self.recorder.record("get_counters", vec![]);
+        async move { reply }
+    }
+
+    fn get_technical_info(
+        &self,
+    ) -> impl Future<Output = Result</* channel_info= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_technical_info", vec![]);
+        async move { reply }
+    }
+
+    fn set_counters(
+        &self,
+        tip_pickup_counter: u32,
+        tip_eject_counter: u32,
+        aspirate_counter: u32,
+        dispense_counter: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_counters",
+            vec![
+                ("tip_pickup_counter", format!("{:?}", tip_pickup_counter)),
+                ("tip_eject_counter", format!("{:?}", tip_eject_counter)),
+                ("aspirate_counter", format!("{:?}", aspirate_counter)),
+                ("dispense_counter", format!("{:?}", dispense_counter)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn measure_lld_frequency(
+        &self,
+        seconds: u16,
+    ) -> impl Future<Output = Result</* detects_per_minute= */ u16, Error>> + Send {
+        let reply = self.recorder.record(
+            "measure_lld_frequency",
+            vec![("seconds", format!("{:?}", seconds))],
+        );
+        async move { reply }
+    }
+
+    fn get_positions(&self) -> impl Future<Output = Result<GetPositionsReply, Error>> + Send {
+        let reply = self.recorder.record("get_positions", vec![]);
+        async move { reply }
+    }
+
+    fn reset(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("reset", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColletChecks {
+    ColletCheckNone = 0,
+    ColletCheckLow = 1,
+    ColletCheckStandard = 2,
+    ColletCheckHigh = 3,
+    ColletCheck384 = 4,
+    ColletCheck5ml = 5,
+}
+
+#[derive(Clone, Copy, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TadmModes {
+    TadmModeNone = 0,
+    TadmModeError = 1,
+    TadmModeAll = 2,
+}
+
+#[derive(Clone, Copy, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConfigurationIndexes {
     ConfigIndexInvalid = 0,
     ConfigIndexTip = 1,
@@ -3399,162 +8539,935 @@ pub enum ConfigurationIndexes {
     ConfigIndexClot = 4,
 }
 
-impl TryFrom<i32> for ConfigurationIndexes {
-    type Error = piglet_client::client::Error;
-
-    fn try_from(v: i32) -> Result<Self, Self::Error> {
-        match v {
-            0 => Ok(ConfigurationIndexes::ConfigIndexInvalid),
-            1 => Ok(ConfigurationIndexes::ConfigIndexTip),
-            2 => Ok(ConfigurationIndexes::ConfigIndexPlld),
-            3 => Ok(ConfigurationIndexes::ConfigIndexClld),
-            4 => Ok(ConfigurationIndexes::ConfigIndexClot),
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AspirateCapacitiveLldRequest {
+    pub aspirate_mode: Option<i16>,
+    pub liquid_seek_height: Option<Millimeters>,
+    pub submerge_depth: Option<Millimeters>,
+    pub follow_depth: Option<Millimeters>,
+    pub z_min_position: Option<Millimeters>,
+    pub clot_check_height: Option<Millimeters>,
+    pub z_final: Option<Millimeters>,
+    pub liquid_exit_speed: Option<MmPerSecond>,
+    pub blowout_volume: Option<Microliters>,
+    pub prewet_volume: Option<Microliters>,
+    pub aspirate_volume: Option<Microliters>,
+    pub transport_air_volume: Option<Microliters>,
+    pub aspirate_speed: Option<MicrolitersPerSecond>,
+    pub settling_time: Option<u32>,
+    pub mix_volume: Option<Microliters>,
+    pub mix_cycles: Option<u32>,
+    pub mix_position: Option<Millimeters>,
+    pub mix_follow_distance: Option<Millimeters>,
+    pub mix_speed: Option<MicrolitersPerSecond>,
+    pub tube_section_height: Option<Millimeters>,
+    pub tube_section_ratio: Option<i32>,
+    pub capacitive_lld_steepness: Option<u32>,
+    pub capacitive_lld_offset: Option<u32>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<u32>,
+    pub recording_mode: Option<u16>,
+    pub measurement_id: Option<String>,
+}
 
-            _ => Err(ConnectionError(anyhow!(
-                "Unknown ConfigurationIndexes value {}",
-                v
-            ))),
-        }
+impl AspirateCapacitiveLldRequest {
+    pub fn aspirate_mode(mut self, aspirate_mode: i16) -> Self {
+        self.aspirate_mode = Some(aspirate_mode);
+        self
+    }
+    pub fn liquid_seek_height(mut self, liquid_seek_height: Millimeters) -> Self {
+        self.liquid_seek_height = Some(liquid_seek_height);
+        self
+    }
+    pub fn submerge_depth(mut self, submerge_depth: Millimeters) -> Self {
+        self.submerge_depth = Some(submerge_depth);
+        self
+    }
+    pub fn follow_depth(mut self, follow_depth: Millimeters) -> Self {
+        self.follow_depth = Some(follow_depth);
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: Millimeters) -> Self {
+        self.z_min_position = Some(z_min_position);
+        self
+    }
+    pub fn clot_check_height(mut self, clot_check_height: Millimeters) -> Self {
+        self.clot_check_height = Some(clot_check_height);
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn liquid_exit_speed(mut self, liquid_exit_speed: MmPerSecond) -> Self {
+        self.liquid_exit_speed = Some(liquid_exit_speed);
+        self
+    }
+    pub fn blowout_volume(mut self, blowout_volume: Microliters) -> Self {
+        self.blowout_volume = Some(blowout_volume);
+        self
+    }
+    pub fn prewet_volume(mut self, prewet_volume: Microliters) -> Self {
+        self.prewet_volume = Some(prewet_volume);
+        self
+    }
+    pub fn aspirate_volume(mut self, aspirate_volume: Microliters) -> Self {
+        self.aspirate_volume = Some(aspirate_volume);
+        self
+    }
+    pub fn transport_air_volume(mut self, transport_air_volume: Microliters) -> Self {
+        self.transport_air_volume = Some(transport_air_volume);
+        self
+    }
+    pub fn aspirate_speed(mut self, aspirate_speed: MicrolitersPerSecond) -> Self {
+        self.aspirate_speed = Some(aspirate_speed);
+        self
+    }
+    pub fn settling_time(mut self, settling_time: u32) -> Self {
+        self.settling_time = Some(settling_time);
+        self
+    }
+    pub fn mix_volume(mut self, mix_volume: Microliters) -> Self {
+        self.mix_volume = Some(mix_volume);
+        self
+    }
+    pub fn mix_cycles(mut self, mix_cycles: u32) -> Self {
+        self.mix_cycles = Some(mix_cycles);
+        self
+    }
+    pub fn mix_position(mut self, mix_position: Millimeters) -> Self {
+        self.mix_position = Some(mix_position);
+        self
+    }
+    pub fn mix_follow_distance(mut self, mix_follow_distance: Millimeters) -> Self {
+        self.mix_follow_distance = Some(mix_follow_distance);
+        self
+    }
+    pub fn mix_speed(mut self, mix_speed: MicrolitersPerSecond) -> Self {
+        self.mix_speed = Some(mix_speed);
+        self
+    }
+    pub fn tube_section_height(mut self, tube_section_height: Millimeters) -> Self {
+        self.tube_section_height = Some(tube_section_height);
+        self
+    }
+    pub fn tube_section_ratio(mut self, tube_section_ratio: i32) -> Self {
+        self.tube_section_ratio = Some(tube_section_ratio);
+        self
+    }
+    pub fn capacitive_lld_steepness(mut self, capacitive_lld_steepness: u32) -> Self {
+        self.capacitive_lld_steepness = Some(capacitive_lld_steepness);
+        self
+    }
+    pub fn capacitive_lld_offset(mut self, capacitive_lld_offset: u32) -> Self {
+        self.capacitive_lld_offset = Some(capacitive_lld_offset);
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: u32) -> Self {
+        self.limit_curve_index = Some(limit_curve_index);
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+    pub fn measurement_id(mut self, measurement_id: impl Into<String>) -> Self {
+        self.measurement_id = Some(measurement_id.into());
+        self
     }
 }
 
-impl PigletCodec for ConfigurationIndexes {
-    const TYPE_ID: u8 = 32;
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AspiratePressureLldRequest {
+    pub aspirate_mode: Option<i16>,
+    pub liquid_seek_height: Option<Millimeters>,
+    pub submerge_depth: Option<Millimeters>,
+    pub follow_depth: Option<Millimeters>,
+    pub z_min_position: Option<Millimeters>,
+    pub z_final: Option<Millimeters>,
+    pub liquid_exit_speed: Option<MmPerSecond>,
+    pub blowout_volume: Option<Microliters>,
+    pub prewet_volume: Option<Microliters>,
+    pub aspirate_volume: Option<Microliters>,
+    pub transport_air_volume: Option<Microliters>,
+    pub aspirate_speed: Option<MicrolitersPerSecond>,
+    pub settling_time: Option<u32>,
+    pub mix_volume: Option<Microliters>,
+    pub mix_cycles: Option<u32>,
+    pub mix_position: Option<Millimeters>,
+    pub mix_follow_distance: Option<Millimeters>,
+    pub mix_speed: Option<MicrolitersPerSecond>,
+    pub tube_section_height: Option<Millimeters>,
+    pub tube_section_ratio: Option<i32>,
+    pub lld_mode: Option<i16>,
+    pub capacitive_lld_steepness: Option<u32>,
+    pub capacitive_lld_offset: Option<u32>,
+    pub pressure_lld_steepness: Option<u32>,
+    pub pressure_lld_offset: Option<u32>,
+    pub seek_speed: Option<MmPerSecond>,
+    pub lld_height_difference: Option<i32>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<u32>,
+    pub recording_mode: Option<u16>,
+    pub measurement_id: Option<String>,
+    pub tip_is_filtered: Option<bool>,
 }
 
-impl PigletDeserialize for ConfigurationIndexes {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        stream.get_i32_le().try_into()
+impl AspiratePressureLldRequest {
+    pub fn aspirate_mode(mut self, aspirate_mode: i16) -> Self {
+        self.aspirate_mode = Some(aspirate_mode);
+        self
+    }
+    pub fn liquid_seek_height(mut self, liquid_seek_height: Millimeters) -> Self {
+        self.liquid_seek_height = Some(liquid_seek_height);
+        self
+    }
+    pub fn submerge_depth(mut self, submerge_depth: Millimeters) -> Self {
+        self.submerge_depth = Some(submerge_depth);
+        self
+    }
+    pub fn follow_depth(mut self, follow_depth: Millimeters) -> Self {
+        self.follow_depth = Some(follow_depth);
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: Millimeters) -> Self {
+        self.z_min_position = Some(z_min_position);
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn liquid_exit_speed(mut self, liquid_exit_speed: MmPerSecond) -> Self {
+        self.liquid_exit_speed = Some(liquid_exit_speed);
+        self
+    }
+    pub fn blowout_volume(mut self, blowout_volume: Microliters) -> Self {
+        self.blowout_volume = Some(blowout_volume);
+        self
+    }
+    pub fn prewet_volume(mut self, prewet_volume: Microliters) -> Self {
+        self.prewet_volume = Some(prewet_volume);
+        self
+    }
+    pub fn aspirate_volume(mut self, aspirate_volume: Microliters) -> Self {
+        self.aspirate_volume = Some(aspirate_volume);
+        self
+    }
+    pub fn transport_air_volume(mut self, transport_air_volume: Microliters) -> Self {
+        self.transport_air_volume = Some(transport_air_volume);
+        self
+    }
+    pub fn aspirate_speed(mut self, aspirate_speed: MicrolitersPerSecond) -> Self {
+        self.aspirate_speed = Some(aspirate_speed);
+        self
+    }
+    pub fn settling_time(mut self, settling_time: u32) -> Self {
+        self.settling_time = Some(settling_time);
+        self
+    }
+    pub fn mix_volume(mut self, mix_volume: Microliters) -> Self {
+        self.mix_volume = Some(mix_volume);
+        self
+    }
+    pub fn mix_cycles(mut self, mix_cycles: u32) -> Self {
+        self.mix_cycles = Some(mix_cycles);
+        self
+    }
+    pub fn mix_position(mut self, mix_position: Millimeters) -> Self {
+        self.mix_position = Some(mix_position);
+        self
+    }
+    pub fn mix_follow_distance(mut self, mix_follow_distance: Millimeters) -> Self {
+        self.mix_follow_distance = Some(mix_follow_distance);
+        self
+    }
+    pub fn mix_speed(mut self, mix_speed: MicrolitersPerSecond) -> Self {
+        self.mix_speed = Some(mix_speed);
+        self
+    }
+    pub fn tube_section_height(mut self, tube_section_height: Millimeters) -> Self {
+        self.tube_section_height = Some(tube_section_height);
+        self
+    }
+    pub fn tube_section_ratio(mut self, tube_section_ratio: i32) -> Self {
+        self.tube_section_ratio = Some(tube_section_ratio);
+        self
+    }
+    pub fn lld_mode(mut self, lld_mode: i16) -> Self {
+        self.lld_mode = Some(lld_mode);
+        self
+    }
+    pub fn capacitive_lld_steepness(mut self, capacitive_lld_steepness: u32) -> Self {
+        self.capacitive_lld_steepness = Some(capacitive_lld_steepness);
+        self
+    }
+    pub fn capacitive_lld_offset(mut self, capacitive_lld_offset: u32) -> Self {
+        self.capacitive_lld_offset = Some(capacitive_lld_offset);
+        self
+    }
+    pub fn pressure_lld_steepness(mut self, pressure_lld_steepness: u32) -> Self {
+        self.pressure_lld_steepness = Some(pressure_lld_steepness);
+        self
+    }
+    pub fn pressure_lld_offset(mut self, pressure_lld_offset: u32) -> Self {
+        self.pressure_lld_offset = Some(pressure_lld_offset);
+        self
+    }
+    pub fn seek_speed(mut self, seek_speed: MmPerSecond) -> Self {
+        self.seek_speed = Some(seek_speed);
+        self
+    }
+    pub fn lld_height_difference(mut self, lld_height_difference: i32) -> Self {
+        self.lld_height_difference = Some(lld_height_difference);
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: u32) -> Self {
+        self.limit_curve_index = Some(limit_curve_index);
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+    pub fn measurement_id(mut self, measurement_id: impl Into<String>) -> Self {
+        self.measurement_id = Some(measurement_id.into());
+        self
+    }
+    pub fn tip_is_filtered(mut self, tip_is_filtered: bool) -> Self {
+        self.tip_is_filtered = Some(tip_is_filtered);
+        self
     }
 }
 
-impl PigletSerialize for ConfigurationIndexes {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        stream.put_u16_le(4);
-        stream.put_i32_le(*self as i32);
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AspirateRequest {
+    pub aspirate_mode: Option<i16>,
+    pub aspirate_height: Option<Millimeters>,
+    pub follow_depth: Option<Millimeters>,
+    pub z_min_position: Option<Millimeters>,
+    pub air_height: Option<Millimeters>,
+    pub z_final: Option<Millimeters>,
+    pub liquid_exit_speed: Option<MmPerSecond>,
+    pub blowout_volume: Option<Microliters>,
+    pub prewet_volume: Option<Microliters>,
+    pub aspirate_volume: Option<Microliters>,
+    pub transport_air_volume: Option<Microliters>,
+    pub aspirate_speed: Option<MicrolitersPerSecond>,
+    pub settling_time: Option<u32>,
+    pub mix_volume: Option<Microliters>,
+    pub mix_cycles: Option<u32>,
+    pub mix_position: Option<Millimeters>,
+    pub mix_follow_distance: Option<Millimeters>,
+    pub mix_speed: Option<MicrolitersPerSecond>,
+    pub tube_section_height: Option<Millimeters>,
+    pub tube_section_ratio: Option<i32>,
+    pub bottom_search: Option<bool>,
+    pub aspirate_offset: Option<i32>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<u32>,
+    pub recording_mode: Option<u16>,
+    pub measurement_id: Option<String>,
+}
+
+impl AspirateRequest {
+    pub fn aspirate_mode(mut self, aspirate_mode: i16) -> Self {
+        self.aspirate_mode = Some(aspirate_mode);
+        self
+    }
+    pub fn aspirate_height(mut self, aspirate_height: Millimeters) -> Self {
+        self.aspirate_height = Some(aspirate_height);
+        self
+    }
+    pub fn follow_depth(mut self, follow_depth: Millimeters) -> Self {
+        self.follow_depth = Some(follow_depth);
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: Millimeters) -> Self {
+        self.z_min_position = Some(z_min_position);
+        self
+    }
+    pub fn air_height(mut self, air_height: Millimeters) -> Self {
+        self.air_height = Some(air_height);
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn liquid_exit_speed(mut self, liquid_exit_speed: MmPerSecond) -> Self {
+        self.liquid_exit_speed = Some(liquid_exit_speed);
+        self
+    }
+    pub fn blowout_volume(mut self, blowout_volume: Microliters) -> Self {
+        self.blowout_volume = Some(blowout_volume);
+        self
+    }
+    pub fn prewet_volume(mut self, prewet_volume: Microliters) -> Self {
+        self.prewet_volume = Some(prewet_volume);
+        self
+    }
+    pub fn aspirate_volume(mut self, aspirate_volume: Microliters) -> Self {
+        self.aspirate_volume = Some(aspirate_volume);
+        self
+    }
+    pub fn transport_air_volume(mut self, transport_air_volume: Microliters) -> Self {
+        self.transport_air_volume = Some(transport_air_volume);
+        self
+    }
+    pub fn aspirate_speed(mut self, aspirate_speed: MicrolitersPerSecond) -> Self {
+        self.aspirate_speed = Some(aspirate_speed);
+        self
+    }
+    pub fn settling_time(mut self, settling_time: u32) -> Self {
+        self.settling_time = Some(settling_time);
+        self
+    }
+    pub fn mix_volume(mut self, mix_volume: Microliters) -> Self {
+        self.mix_volume = Some(mix_volume);
+        self
+    }
+    pub fn mix_cycles(mut self, mix_cycles: u32) -> Self {
+        self.mix_cycles = Some(mix_cycles);
+        self
     }
+    pub fn mix_position(mut self, mix_position: Millimeters) -> Self {
+        self.mix_position = Some(mix_position);
+        self
+    }
+    pub fn mix_follow_distance(mut self, mix_follow_distance: Millimeters) -> Self {
+        self.mix_follow_distance = Some(mix_follow_distance);
+        self
+    }
+    pub fn mix_speed(mut self, mix_speed: MicrolitersPerSecond) -> Self {
+        self.mix_speed = Some(mix_speed);
+        self
+    }
+    pub fn tube_section_height(mut self, tube_section_height: Millimeters) -> Self {
+        self.tube_section_height = Some(tube_section_height);
+        self
+    }
+    pub fn tube_section_ratio(mut self, tube_section_ratio: i32) -> Self {
+        self.tube_section_ratio = Some(tube_section_ratio);
+        self
+    }
+    pub fn bottom_search(mut self, bottom_search: bool) -> Self {
+        self.bottom_search = Some(bottom_search);
+        self
+    }
+    pub fn aspirate_offset(mut self, aspirate_offset: i32) -> Self {
+        self.aspirate_offset = Some(aspirate_offset);
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: u32) -> Self {
+        self.limit_curve_index = Some(limit_curve_index);
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+    pub fn measurement_id(mut self, measurement_id: impl Into<String>) -> Self {
+        self.measurement_id = Some(measurement_id.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DispenseLldRequest {
+    pub liquid_seek_height: Option<Millimeters>,
+    pub submerge_depth: Option<Millimeters>,
+    pub follow_depth: Option<Millimeters>,
+    pub z_min_position: Option<Millimeters>,
+    pub z_final: Option<Millimeters>,
+    pub liquid_exit_speed: Option<MmPerSecond>,
+    pub dispense_volume: Option<Microliters>,
+    pub stop_back_volume: Option<Microliters>,
+    pub transport_air_volume: Option<Microliters>,
+    pub dispense_speed: Option<MicrolitersPerSecond>,
+    pub cutoff_speed: Option<MicrolitersPerSecond>,
+    pub settling_time: Option<u32>,
+    pub mix_volume: Option<Microliters>,
+    pub mix_cycles: Option<u32>,
+    pub mix_position: Option<Millimeters>,
+    pub mix_follow_distance: Option<Millimeters>,
+    pub mix_speed: Option<MicrolitersPerSecond>,
+    pub tube_section_height: Option<Millimeters>,
+    pub tube_section_ratio: Option<i32>,
+    pub capacitive_lld_steepness: Option<u32>,
+    pub capacitive_lld_offset: Option<u32>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<u32>,
+    pub recording_mode: Option<u16>,
+    pub measurement_id: Option<String>,
 }
 
-impl PigletCodec for MSlice<'_, ConfigurationIndexes> {
-    const TYPE_ID: u8 = 35;
+impl DispenseLldRequest {
+    pub fn liquid_seek_height(mut self, liquid_seek_height: Millimeters) -> Self {
+        self.liquid_seek_height = Some(liquid_seek_height);
+        self
+    }
+    pub fn submerge_depth(mut self, submerge_depth: Millimeters) -> Self {
+        self.submerge_depth = Some(submerge_depth);
+        self
+    }
+    pub fn follow_depth(mut self, follow_depth: Millimeters) -> Self {
+        self.follow_depth = Some(follow_depth);
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: Millimeters) -> Self {
+        self.z_min_position = Some(z_min_position);
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn liquid_exit_speed(mut self, liquid_exit_speed: MmPerSecond) -> Self {
+        self.liquid_exit_speed = Some(liquid_exit_speed);
+        self
+    }
+    pub fn dispense_volume(mut self, dispense_volume: Microliters) -> Self {
+        self.dispense_volume = Some(dispense_volume);
+        self
+    }
+    pub fn stop_back_volume(mut self, stop_back_volume: Microliters) -> Self {
+        self.stop_back_volume = Some(stop_back_volume);
+        self
+    }
+    pub fn transport_air_volume(mut self, transport_air_volume: Microliters) -> Self {
+        self.transport_air_volume = Some(transport_air_volume);
+        self
+    }
+    pub fn dispense_speed(mut self, dispense_speed: MicrolitersPerSecond) -> Self {
+        self.dispense_speed = Some(dispense_speed);
+        self
+    }
+    pub fn cutoff_speed(mut self, cutoff_speed: MicrolitersPerSecond) -> Self {
+        self.cutoff_speed = Some(cutoff_speed);
+        self
+    }
+    pub fn settling_time(mut self, settling_time: u32) -> Self {
+        self.settling_time = Some(settling_time);
+        self
+    }
+    pub fn mix_volume(mut self, mix_volume: Microliters) -> Self {
+        self.mix_volume = Some(mix_volume);
+        self
+    }
+    pub fn mix_cycles(mut self, mix_cycles: u32) -> Self {
+        self.mix_cycles = Some(mix_cycles);
+        self
+    }
+    pub fn mix_position(mut self, mix_position: Millimeters) -> Self {
+        self.mix_position = Some(mix_position);
+        self
+    }
+    pub fn mix_follow_distance(mut self, mix_follow_distance: Millimeters) -> Self {
+        self.mix_follow_distance = Some(mix_follow_distance);
+        self
+    }
+    pub fn mix_speed(mut self, mix_speed: MicrolitersPerSecond) -> Self {
+        self.mix_speed = Some(mix_speed);
+        self
+    }
+    pub fn tube_section_height(mut self, tube_section_height: Millimeters) -> Self {
+        self.tube_section_height = Some(tube_section_height);
+        self
+    }
+    pub fn tube_section_ratio(mut self, tube_section_ratio: i32) -> Self {
+        self.tube_section_ratio = Some(tube_section_ratio);
+        self
+    }
+    pub fn capacitive_lld_steepness(mut self, capacitive_lld_steepness: u32) -> Self {
+        self.capacitive_lld_steepness = Some(capacitive_lld_steepness);
+        self
+    }
+    pub fn capacitive_lld_offset(mut self, capacitive_lld_offset: u32) -> Self {
+        self.capacitive_lld_offset = Some(capacitive_lld_offset);
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: u32) -> Self {
+        self.limit_curve_index = Some(limit_curve_index);
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+    pub fn measurement_id(mut self, measurement_id: impl Into<String>) -> Self {
+        self.measurement_id = Some(measurement_id.into());
+        self
+    }
 }
 
-impl PigletCodec for MVec<ConfigurationIndexes> {
-    const TYPE_ID: u8 = 35;
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DispenseRequest {
+    pub dispense_height: Option<Millimeters>,
+    pub follow_depth: Option<Millimeters>,
+    pub z_min_position: Option<Millimeters>,
+    pub air_height: Option<Millimeters>,
+    pub z_final: Option<Millimeters>,
+    pub liquid_exit_speed: Option<MmPerSecond>,
+    pub dispense_volume: Option<Microliters>,
+    pub stop_back_volume: Option<Microliters>,
+    pub transport_air_volume: Option<Microliters>,
+    pub dispense_speed: Option<MicrolitersPerSecond>,
+    pub cutoff_speed: Option<MicrolitersPerSecond>,
+    pub settling_time: Option<u32>,
+    pub mix_volume: Option<Microliters>,
+    pub mix_cycles: Option<u32>,
+    pub mix_position: Option<Millimeters>,
+    pub mix_follow_distance: Option<Millimeters>,
+    pub mix_speed: Option<MicrolitersPerSecond>,
+    pub tube_section_height: Option<Millimeters>,
+    pub tube_section_ratio: Option<i32>,
+    pub bottom_search: Option<bool>,
+    pub dispense_offset: Option<i32>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<u32>,
+    pub recording_mode: Option<u16>,
+    pub measurement_id: Option<String>,
 }
 
-impl PigletDeserialize for MVec<ConfigurationIndexes> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut arr = Vec::new();
-        for i in 0..(length / 4) {
-            arr.push(stream.get_i32_le().try_into()?);
-        }
-        Ok(MVec(arr))
+impl DispenseRequest {
+    pub fn dispense_height(mut self, dispense_height: Millimeters) -> Self {
+        self.dispense_height = Some(dispense_height);
+        self
+    }
+    pub fn follow_depth(mut self, follow_depth: Millimeters) -> Self {
+        self.follow_depth = Some(follow_depth);
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: Millimeters) -> Self {
+        self.z_min_position = Some(z_min_position);
+        self
+    }
+    pub fn air_height(mut self, air_height: Millimeters) -> Self {
+        self.air_height = Some(air_height);
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn liquid_exit_speed(mut self, liquid_exit_speed: MmPerSecond) -> Self {
+        self.liquid_exit_speed = Some(liquid_exit_speed);
+        self
+    }
+    pub fn dispense_volume(mut self, dispense_volume: Microliters) -> Self {
+        self.dispense_volume = Some(dispense_volume);
+        self
+    }
+    pub fn stop_back_volume(mut self, stop_back_volume: Microliters) -> Self {
+        self.stop_back_volume = Some(stop_back_volume);
+        self
+    }
+    pub fn transport_air_volume(mut self, transport_air_volume: Microliters) -> Self {
+        self.transport_air_volume = Some(transport_air_volume);
+        self
+    }
+    pub fn dispense_speed(mut self, dispense_speed: MicrolitersPerSecond) -> Self {
+        self.dispense_speed = Some(dispense_speed);
+        self
+    }
+    pub fn cutoff_speed(mut self, cutoff_speed: MicrolitersPerSecond) -> Self {
+        self.cutoff_speed = Some(cutoff_speed);
+        self
+    }
+    pub fn settling_time(mut self, settling_time: u32) -> Self {
+        self.settling_time = Some(settling_time);
+        self
+    }
+    pub fn mix_volume(mut self, mix_volume: Microliters) -> Self {
+        self.mix_volume = Some(mix_volume);
+        self
+    }
+    pub fn mix_cycles(mut self, mix_cycles: u32) -> Self {
+        self.mix_cycles = Some(mix_cycles);
+        self
+    }
+    pub fn mix_position(mut self, mix_position: Millimeters) -> Self {
+        self.mix_position = Some(mix_position);
+        self
+    }
+    pub fn mix_follow_distance(mut self, mix_follow_distance: Millimeters) -> Self {
+        self.mix_follow_distance = Some(mix_follow_distance);
+        self
+    }
+    pub fn mix_speed(mut self, mix_speed: MicrolitersPerSecond) -> Self {
+        self.mix_speed = Some(mix_speed);
+        self
+    }
+    pub fn tube_section_height(mut self, tube_section_height: Millimeters) -> Self {
+        self.tube_section_height = Some(tube_section_height);
+        self
+    }
+    pub fn tube_section_ratio(mut self, tube_section_ratio: i32) -> Self {
+        self.tube_section_ratio = Some(tube_section_ratio);
+        self
+    }
+    pub fn bottom_search(mut self, bottom_search: bool) -> Self {
+        self.bottom_search = Some(bottom_search);
+        self
+    }
+    pub fn dispense_offset(mut self, dispense_offset: i32) -> Self {
+        self.dispense_offset = Some(dispense_offset);
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: u32) -> Self {
+        self.limit_curve_index = Some(limit_curve_index);
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+    pub fn measurement_id(mut self, measurement_id: impl Into<String>) -> Self {
+        self.measurement_id = Some(measurement_id.into());
+        self
     }
 }
 
-impl PigletSerialize for MSlice<'_, ConfigurationIndexes> {
-    fn serialize(&self, bytes: &mut BytesMut) {
-        bytes.put_u8(Self::TYPE_ID);
-        bytes.put_u8(0);
-        bytes.put_u16_le(4 * self.0.len() as u16);
-        for v in self.0.as_ref() {
-            bytes.put_i32_le(*v as i32);
-        }
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmptyTipRequest {
+    pub dispense_height: Option<Millimeters>,
+    pub follow_depth: Option<Millimeters>,
+    pub z_min_position: Option<Millimeters>,
+    pub air_height: Option<Millimeters>,
+    pub z_final: Option<Millimeters>,
+    pub liquid_exit_speed: Option<MmPerSecond>,
+    pub transport_air_volume: Option<Microliters>,
+    pub dispense_speed: Option<MicrolitersPerSecond>,
+    pub cutoff_speed: Option<MicrolitersPerSecond>,
+    pub settling_time: Option<u32>,
+    pub tube_section_height: Option<Millimeters>,
+    pub tube_section_ratio: Option<i32>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<u32>,
+    pub recording_mode: Option<u16>,
+    pub measurement_id: Option<String>,
+}
+
+impl EmptyTipRequest {
+    pub fn dispense_height(mut self, dispense_height: Millimeters) -> Self {
+        self.dispense_height = Some(dispense_height);
+        self
+    }
+    pub fn follow_depth(mut self, follow_depth: Millimeters) -> Self {
+        self.follow_depth = Some(follow_depth);
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: Millimeters) -> Self {
+        self.z_min_position = Some(z_min_position);
+        self
+    }
+    pub fn air_height(mut self, air_height: Millimeters) -> Self {
+        self.air_height = Some(air_height);
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn liquid_exit_speed(mut self, liquid_exit_speed: MmPerSecond) -> Self {
+        self.liquid_exit_speed = Some(liquid_exit_speed);
+        self
+    }
+    pub fn transport_air_volume(mut self, transport_air_volume: Microliters) -> Self {
+        self.transport_air_volume = Some(transport_air_volume);
+        self
+    }
+    pub fn dispense_speed(mut self, dispense_speed: MicrolitersPerSecond) -> Self {
+        self.dispense_speed = Some(dispense_speed);
+        self
+    }
+    pub fn cutoff_speed(mut self, cutoff_speed: MicrolitersPerSecond) -> Self {
+        self.cutoff_speed = Some(cutoff_speed);
+        self
+    }
+    pub fn settling_time(mut self, settling_time: u32) -> Self {
+        self.settling_time = Some(settling_time);
+        self
+    }
+    pub fn tube_section_height(mut self, tube_section_height: Millimeters) -> Self {
+        self.tube_section_height = Some(tube_section_height);
+        self
+    }
+    pub fn tube_section_ratio(mut self, tube_section_ratio: i32) -> Self {
+        self.tube_section_ratio = Some(tube_section_ratio);
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: u32) -> Self {
+        self.limit_curve_index = Some(limit_curve_index);
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+    pub fn measurement_id(mut self, measurement_id: impl Into<String>) -> Self {
+        self.measurement_id = Some(measurement_id.into());
+        self
     }
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreateLimitCurveReply {
     pub index: u32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RetrieveTadmDataReply {
     pub tadm_data: Vec<i16>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetLimitCurveNamesReply {
     pub names: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetLimitCurveInfoReply {
     pub index: u32,
     pub lower_limits: u16,
     pub upper_limits: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetConfigurationReply {
     pub enabled: bool,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetPotentiometerDataReply {
     pub gain: i16,
     pub offset: i16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetPressureSensorADReply {
     pub pressure_lld: i16,
     pub tadm: i16,
 }
 
-#[allow(non_camel_case_types)]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrepareOnTheFlyDispenseRequest {
+    pub volume: Option<Microliters>,
+    pub stop_back_volume: Option<Microliters>,
+    pub transport_air_volume: Option<Microliters>,
+    pub dispense_speed: Option<MicrolitersPerSecond>,
+    pub cutoff_speed: Option<MicrolitersPerSecond>,
+    pub dispense_pattern: Option<String>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<u32>,
+    pub recording_mode: Option<u16>,
+    pub measurement_id: Option<String>,
+}
+
+impl PrepareOnTheFlyDispenseRequest {
+    pub fn volume(mut self, volume: Microliters) -> Self {
+        self.volume = Some(volume);
+        self
+    }
+    pub fn stop_back_volume(mut self, stop_back_volume: Microliters) -> Self {
+        self.stop_back_volume = Some(stop_back_volume);
+        self
+    }
+    pub fn transport_air_volume(mut self, transport_air_volume: Microliters) -> Self {
+        self.transport_air_volume = Some(transport_air_volume);
+        self
+    }
+    pub fn dispense_speed(mut self, dispense_speed: MicrolitersPerSecond) -> Self {
+        self.dispense_speed = Some(dispense_speed);
+        self
+    }
+    pub fn cutoff_speed(mut self, cutoff_speed: MicrolitersPerSecond) -> Self {
+        self.cutoff_speed = Some(cutoff_speed);
+        self
+    }
+    pub fn dispense_pattern(mut self, dispense_pattern: impl Into<String>) -> Self {
+        self.dispense_pattern = Some(dispense_pattern.into());
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: u32) -> Self {
+        self.limit_curve_index = Some(limit_curve_index);
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+    pub fn measurement_id(mut self, measurement_id: impl Into<String>) -> Self {
+        self.measurement_id = Some(measurement_id.into());
+        self
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReadOnTheFlyDispenseErrorsReply {
     pub status: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZSeekObstaclePositionReply {
-    pub position: i32,
+    pub position: Millimeters,
     pub obstacle_detected: bool,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZSeekLldPositionReply {
-    pub position: i32,
+    pub position: Millimeters,
     pub lld_detected: bool,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetCalibrationValuesReply {
     pub dispense_drive: i16,
     pub squeeze_drive: i16,
@@ -3562,8 +9475,8 @@ pub struct GetCalibrationValuesReply {
     pub clot_detection: i16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetExtendedCalibrationValuesReply {
     pub tip_type_adjustment: i16,
     pub pressure_conversion: i16,
@@ -3571,8 +9484,8 @@ pub struct GetExtendedCalibrationValuesReply {
     pub z_bottom_search: i16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetCountersReply {
     pub tip_pickup_counter: u32,
     pub tip_eject_counter: u32,
@@ -3580,21 +9493,21 @@ pub struct GetCountersReply {
     pub dispense_counter: u32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetPositionsReply {
-    pub y_position: i32,
-    pub y_encoder_position: i32,
-    pub z_position: i32,
-    pub z_encoder_position: i32,
-    pub d_position: i32,
-    pub d_encoder_position: i32,
-    pub s_position: i32,
-    pub s_encoder_position: i32,
+    pub y_position: Millimeters,
+    pub y_encoder_position: Millimeters,
+    pub z_position: Millimeters,
+    pub z_encoder_position: Millimeters,
+    pub d_position: Millimeters,
+    pub d_encoder_position: Millimeters,
+    pub s_position: Millimeters,
+    pub s_encoder_position: Millimeters,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -3602,8 +9515,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -3613,23 +9526,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -3637,8 +9550,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
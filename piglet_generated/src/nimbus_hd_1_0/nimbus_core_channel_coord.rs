@@ -1,14 +1,14 @@
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
+use piglet_client::units::{Microliters, MicrolitersPerSecond, Millimeters, MmPerSecond};
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +16,13 @@ use std::sync::Arc;
 pub struct NimbusCoreChannelCoord {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreChannelCoord {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Coord";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,6 +32,26 @@ impl NimbusCoreChannelCoord {
                 object_id: 262,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Channel[1].Coord").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
@@ -36,19 +59,31 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
-        z_final: i32,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
         tip_type: impl AsRef<[u16]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
         tip_type.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 1, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -65,7 +100,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -74,15 +109,27 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -97,7 +144,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -107,27 +154,27 @@ impl NimbusCoreChannelCoord {
 
         aspirate_type: impl AsRef<[i16]>,
         tips_used: impl AsRef<[u16]>,
-        liquid_seek_height: impl AsRef<[i32]>,
-        liquid_surface_height: impl AsRef<[i32]>,
-        submerge_depth: impl AsRef<[i32]>,
-        follow_depth: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
-        clot_check_height: impl AsRef<[i32]>,
-        z_final: i32,
-        liquid_exit_speed: impl AsRef<[u32]>,
-        blowout_volume: impl AsRef<[u32]>,
-        prewet_volume: impl AsRef<[u32]>,
-        aspirate_volume: impl AsRef<[u32]>,
-        transport_air_volume: impl AsRef<[u32]>,
-        aspirate_speed: impl AsRef<[u32]>,
+        liquid_seek_height: impl AsRef<[Millimeters]>,
+        liquid_surface_height: impl AsRef<[Millimeters]>,
+        submerge_depth: impl AsRef<[Millimeters]>,
+        follow_depth: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
+        clot_check_height: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]>,
+        blowout_volume: impl AsRef<[Microliters]>,
+        prewet_volume: impl AsRef<[Microliters]>,
+        aspirate_volume: impl AsRef<[Microliters]>,
+        transport_air_volume: impl AsRef<[Microliters]>,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]>,
         settling_time: impl AsRef<[u32]>,
-        mix_volume: impl AsRef<[u32]>,
+        mix_volume: impl AsRef<[Microliters]>,
         mix_cycles: impl AsRef<[u32]>,
-        mix_position: impl AsRef<[i32]>,
-        mix_follow_distance: impl AsRef<[i32]>,
-        mix_speed: impl AsRef<[u32]>,
+        mix_position: impl AsRef<[Millimeters]>,
+        mix_follow_distance: impl AsRef<[Millimeters]>,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]>,
         aspirate_offset: impl AsRef<[i32]>,
-        tube_section_height: impl AsRef<[i32]>,
+        tube_section_height: impl AsRef<[Millimeters]>,
         tube_section_ratio: impl AsRef<[i32]>,
         lld_mode: impl AsRef<[i16]>,
         capacitive_lld_sensitivity: impl AsRef<[i16]>,
@@ -140,27 +187,112 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         aspirate_type.as_ref().serialize(&mut args);
         tips_used.as_ref().serialize(&mut args);
-        liquid_seek_height.as_ref().serialize(&mut args);
-        liquid_surface_height.as_ref().serialize(&mut args);
-        submerge_depth.as_ref().serialize(&mut args);
-        follow_depth.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
-        clot_check_height.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
-        liquid_exit_speed.as_ref().serialize(&mut args);
-        blowout_volume.as_ref().serialize(&mut args);
-        prewet_volume.as_ref().serialize(&mut args);
-        aspirate_volume.as_ref().serialize(&mut args);
-        transport_air_volume.as_ref().serialize(&mut args);
-        aspirate_speed.as_ref().serialize(&mut args);
+        liquid_seek_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        liquid_surface_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        submerge_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        follow_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        clot_check_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        liquid_exit_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        blowout_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        prewet_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        aspirate_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        transport_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        aspirate_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         settling_time.as_ref().serialize(&mut args);
-        mix_volume.as_ref().serialize(&mut args);
+        mix_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         mix_cycles.as_ref().serialize(&mut args);
-        mix_position.as_ref().serialize(&mut args);
-        mix_follow_distance.as_ref().serialize(&mut args);
-        mix_speed.as_ref().serialize(&mut args);
+        mix_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_follow_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         aspirate_offset.as_ref().serialize(&mut args);
-        tube_section_height.as_ref().serialize(&mut args);
+        tube_section_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         tube_section_ratio.as_ref().serialize(&mut args);
         lld_mode.as_ref().serialize(&mut args);
         capacitive_lld_sensitivity.as_ref().serialize(&mut args);
@@ -170,7 +302,9 @@ impl NimbusCoreChannelCoord {
         limit_curve_index.as_ref().serialize(&mut args);
         recording_mode.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  aspirate_type: {:?}", aspirate_type.as_ref()),
@@ -228,38 +362,195 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn aspirate_with(&self, request: &AspirateRequest) -> Result<(), Error> {
+        self.aspirate(
+            piglet_client::client::required(
+                request.aspirate_type.as_ref(),
+                "AspirateRequest",
+                "aspirate_type",
+            )?,
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "AspirateRequest",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.liquid_seek_height.as_ref(),
+                "AspirateRequest",
+                "liquid_seek_height",
+            )?,
+            piglet_client::client::required(
+                request.liquid_surface_height.as_ref(),
+                "AspirateRequest",
+                "liquid_surface_height",
+            )?,
+            piglet_client::client::required(
+                request.submerge_depth.as_ref(),
+                "AspirateRequest",
+                "submerge_depth",
+            )?,
+            piglet_client::client::required(
+                request.follow_depth.as_ref(),
+                "AspirateRequest",
+                "follow_depth",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position.as_ref(),
+                "AspirateRequest",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(
+                request.clot_check_height.as_ref(),
+                "AspirateRequest",
+                "clot_check_height",
+            )?,
+            piglet_client::client::required(request.z_final, "AspirateRequest", "z_final")?,
+            piglet_client::client::required(
+                request.liquid_exit_speed.as_ref(),
+                "AspirateRequest",
+                "liquid_exit_speed",
+            )?,
+            piglet_client::client::required(
+                request.blowout_volume.as_ref(),
+                "AspirateRequest",
+                "blowout_volume",
+            )?,
+            piglet_client::client::required(
+                request.prewet_volume.as_ref(),
+                "AspirateRequest",
+                "prewet_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_volume.as_ref(),
+                "AspirateRequest",
+                "aspirate_volume",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume.as_ref(),
+                "AspirateRequest",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_speed.as_ref(),
+                "AspirateRequest",
+                "aspirate_speed",
+            )?,
+            piglet_client::client::required(
+                request.settling_time.as_ref(),
+                "AspirateRequest",
+                "settling_time",
+            )?,
+            piglet_client::client::required(
+                request.mix_volume.as_ref(),
+                "AspirateRequest",
+                "mix_volume",
+            )?,
+            piglet_client::client::required(
+                request.mix_cycles.as_ref(),
+                "AspirateRequest",
+                "mix_cycles",
+            )?,
+            piglet_client::client::required(
+                request.mix_position.as_ref(),
+                "AspirateRequest",
+                "mix_position",
+            )?,
+            piglet_client::client::required(
+                request.mix_follow_distance.as_ref(),
+                "AspirateRequest",
+                "mix_follow_distance",
+            )?,
+            piglet_client::client::required(
+                request.mix_speed.as_ref(),
+                "AspirateRequest",
+                "mix_speed",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_offset.as_ref(),
+                "AspirateRequest",
+                "aspirate_offset",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_height.as_ref(),
+                "AspirateRequest",
+                "tube_section_height",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_ratio.as_ref(),
+                "AspirateRequest",
+                "tube_section_ratio",
+            )?,
+            piglet_client::client::required(
+                request.lld_mode.as_ref(),
+                "AspirateRequest",
+                "lld_mode",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_sensitivity.as_ref(),
+                "AspirateRequest",
+                "capacitive_lld_sensitivity",
+            )?,
+            piglet_client::client::required(
+                request.pressure_lld_sensitivity.as_ref(),
+                "AspirateRequest",
+                "pressure_lld_sensitivity",
+            )?,
+            piglet_client::client::required(
+                request.lld_height_difference.as_ref(),
+                "AspirateRequest",
+                "lld_height_difference",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "AspirateRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index.as_ref(),
+                "AspirateRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "AspirateRequest",
+                "recording_mode",
+            )?,
+        )
+        .await
+    }
+
     pub async fn dispense(
         &self,
 
         dispense_type: impl AsRef<[i16]>,
         tips_used: impl AsRef<[u16]>,
-        liquid_seek_height: impl AsRef<[i32]>,
-        dispense_height: impl AsRef<[i32]>,
-        submerge_depth: impl AsRef<[i32]>,
-        follow_depth: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
-        z_final: i32,
-        liquid_exit_speed: impl AsRef<[u32]>,
-        transport_air_volume: impl AsRef<[u32]>,
-        dispense_volume: impl AsRef<[u32]>,
-        stop_back_volume: impl AsRef<[u32]>,
-        blowout_volume: impl AsRef<[u32]>,
-        dispense_speed: impl AsRef<[u32]>,
-        cutoff_speed: impl AsRef<[u32]>,
+        liquid_seek_height: impl AsRef<[Millimeters]>,
+        dispense_height: impl AsRef<[Millimeters]>,
+        submerge_depth: impl AsRef<[Millimeters]>,
+        follow_depth: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]>,
+        transport_air_volume: impl AsRef<[Microliters]>,
+        dispense_volume: impl AsRef<[Microliters]>,
+        stop_back_volume: impl AsRef<[Microliters]>,
+        blowout_volume: impl AsRef<[Microliters]>,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]>,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]>,
         settling_time: impl AsRef<[u32]>,
-        mix_volume: impl AsRef<[u32]>,
+        mix_volume: impl AsRef<[Microliters]>,
         mix_cycles: impl AsRef<[u32]>,
-        mix_position: impl AsRef<[i32]>,
-        mix_follow_distance: impl AsRef<[i32]>,
-        mix_speed: impl AsRef<[u32]>,
-        touchoff_distance: i32,
+        mix_position: impl AsRef<[Millimeters]>,
+        mix_follow_distance: impl AsRef<[Millimeters]>,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]>,
+        touchoff_distance: Millimeters,
         dispense_offset: impl AsRef<[i32]>,
-        tube_section_height: impl AsRef<[i32]>,
+        tube_section_height: impl AsRef<[Millimeters]>,
         tube_section_ratio: impl AsRef<[i32]>,
         lld_mode: impl AsRef<[i16]>,
         capacitive_lld_sensitivity: impl AsRef<[i16]>,
@@ -270,28 +561,113 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         dispense_type.as_ref().serialize(&mut args);
         tips_used.as_ref().serialize(&mut args);
-        liquid_seek_height.as_ref().serialize(&mut args);
-        dispense_height.as_ref().serialize(&mut args);
-        submerge_depth.as_ref().serialize(&mut args);
-        follow_depth.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
-        liquid_exit_speed.as_ref().serialize(&mut args);
-        transport_air_volume.as_ref().serialize(&mut args);
-        dispense_volume.as_ref().serialize(&mut args);
-        stop_back_volume.as_ref().serialize(&mut args);
-        blowout_volume.as_ref().serialize(&mut args);
-        dispense_speed.as_ref().serialize(&mut args);
-        cutoff_speed.as_ref().serialize(&mut args);
+        liquid_seek_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        dispense_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        submerge_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        follow_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        liquid_exit_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        transport_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        stop_back_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        blowout_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        cutoff_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         settling_time.as_ref().serialize(&mut args);
-        mix_volume.as_ref().serialize(&mut args);
+        mix_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         mix_cycles.as_ref().serialize(&mut args);
-        mix_position.as_ref().serialize(&mut args);
-        mix_follow_distance.as_ref().serialize(&mut args);
-        mix_speed.as_ref().serialize(&mut args);
-        touchoff_distance.serialize(&mut args);
+        mix_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_follow_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        touchoff_distance.to_wire::<i32>()?.serialize(&mut args);
         dispense_offset.as_ref().serialize(&mut args);
-        tube_section_height.as_ref().serialize(&mut args);
+        tube_section_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         tube_section_ratio.as_ref().serialize(&mut args);
         lld_mode.as_ref().serialize(&mut args);
         capacitive_lld_sensitivity.as_ref().serialize(&mut args);
@@ -299,7 +675,9 @@ impl NimbusCoreChannelCoord {
         limit_curve_index.as_ref().serialize(&mut args);
         recording_mode.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  dispense_type: {:?}", dispense_type.as_ref()),
@@ -347,11 +725,163 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn dispense_with(&self, request: &DispenseRequest) -> Result<(), Error> {
+        self.dispense(
+            piglet_client::client::required(
+                request.dispense_type.as_ref(),
+                "DispenseRequest",
+                "dispense_type",
+            )?,
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "DispenseRequest",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.liquid_seek_height.as_ref(),
+                "DispenseRequest",
+                "liquid_seek_height",
+            )?,
+            piglet_client::client::required(
+                request.dispense_height.as_ref(),
+                "DispenseRequest",
+                "dispense_height",
+            )?,
+            piglet_client::client::required(
+                request.submerge_depth.as_ref(),
+                "DispenseRequest",
+                "submerge_depth",
+            )?,
+            piglet_client::client::required(
+                request.follow_depth.as_ref(),
+                "DispenseRequest",
+                "follow_depth",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position.as_ref(),
+                "DispenseRequest",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(request.z_final, "DispenseRequest", "z_final")?,
+            piglet_client::client::required(
+                request.liquid_exit_speed.as_ref(),
+                "DispenseRequest",
+                "liquid_exit_speed",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume.as_ref(),
+                "DispenseRequest",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_volume.as_ref(),
+                "DispenseRequest",
+                "dispense_volume",
+            )?,
+            piglet_client::client::required(
+                request.stop_back_volume.as_ref(),
+                "DispenseRequest",
+                "stop_back_volume",
+            )?,
+            piglet_client::client::required(
+                request.blowout_volume.as_ref(),
+                "DispenseRequest",
+                "blowout_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_speed.as_ref(),
+                "DispenseRequest",
+                "dispense_speed",
+            )?,
+            piglet_client::client::required(
+                request.cutoff_speed.as_ref(),
+                "DispenseRequest",
+                "cutoff_speed",
+            )?,
+            piglet_client::client::required(
+                request.settling_time.as_ref(),
+                "DispenseRequest",
+                "settling_time",
+            )?,
+            piglet_client::client::required(
+                request.mix_volume.as_ref(),
+                "DispenseRequest",
+                "mix_volume",
+            )?,
+            piglet_client::client::required(
+                request.mix_cycles.as_ref(),
+                "DispenseRequest",
+                "mix_cycles",
+            )?,
+            piglet_client::client::required(
+                request.mix_position.as_ref(),
+                "DispenseRequest",
+                "mix_position",
+            )?,
+            piglet_client::client::required(
+                request.mix_follow_distance.as_ref(),
+                "DispenseRequest",
+                "mix_follow_distance",
+            )?,
+            piglet_client::client::required(
+                request.mix_speed.as_ref(),
+                "DispenseRequest",
+                "mix_speed",
+            )?,
+            piglet_client::client::required(
+                request.touchoff_distance,
+                "DispenseRequest",
+                "touchoff_distance",
+            )?,
+            piglet_client::client::required(
+                request.dispense_offset.as_ref(),
+                "DispenseRequest",
+                "dispense_offset",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_height.as_ref(),
+                "DispenseRequest",
+                "tube_section_height",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_ratio.as_ref(),
+                "DispenseRequest",
+                "tube_section_ratio",
+            )?,
+            piglet_client::client::required(
+                request.lld_mode.as_ref(),
+                "DispenseRequest",
+                "lld_mode",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_sensitivity.as_ref(),
+                "DispenseRequest",
+                "capacitive_lld_sensitivity",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "DispenseRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index.as_ref(),
+                "DispenseRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "DispenseRequest",
+                "recording_mode",
+            )?,
+        )
+        .await
+    }
+
     pub async fn aspirate_and_dispense(
         &self,
 
@@ -359,31 +889,31 @@ impl NimbusCoreChannelCoord {
         dispense_type: impl AsRef<[i16]>,
         tips_used: impl AsRef<[u16]>,
         aspirate_dispense_pattern: impl AsRef<[i16]>,
-        liquid_seek_height: impl AsRef<[i32]>,
-        liquid_surface_height: impl AsRef<[i32]>,
-        submerge_depth: impl AsRef<[i32]>,
-        follow_depth: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
-        clot_check_height: impl AsRef<[i32]>,
-        z_final: i32,
-        liquid_exit_speed: impl AsRef<[u32]>,
-        blowout_volume: impl AsRef<[u32]>,
-        prewet_volume: impl AsRef<[u32]>,
-        aspirate_volume: impl AsRef<[u32]>,
-        transport_air_volume: impl AsRef<[u32]>,
-        dispense_volume: impl AsRef<[u32]>,
-        stop_back_volume: impl AsRef<[u32]>,
-        aspirate_speed: impl AsRef<[u32]>,
-        dispense_speed: impl AsRef<[u32]>,
-        cutoff_speed: impl AsRef<[u32]>,
+        liquid_seek_height: impl AsRef<[Millimeters]>,
+        liquid_surface_height: impl AsRef<[Millimeters]>,
+        submerge_depth: impl AsRef<[Millimeters]>,
+        follow_depth: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
+        clot_check_height: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]>,
+        blowout_volume: impl AsRef<[Microliters]>,
+        prewet_volume: impl AsRef<[Microliters]>,
+        aspirate_volume: impl AsRef<[Microliters]>,
+        transport_air_volume: impl AsRef<[Microliters]>,
+        dispense_volume: impl AsRef<[Microliters]>,
+        stop_back_volume: impl AsRef<[Microliters]>,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]>,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]>,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]>,
         settling_time: impl AsRef<[u32]>,
-        mix_volume: impl AsRef<[u32]>,
+        mix_volume: impl AsRef<[Microliters]>,
         mix_cycles: impl AsRef<[u32]>,
-        mix_position: impl AsRef<[i32]>,
-        mix_follow_distance: impl AsRef<[i32]>,
-        mix_speed: impl AsRef<[u32]>,
+        mix_position: impl AsRef<[Millimeters]>,
+        mix_follow_distance: impl AsRef<[Millimeters]>,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]>,
         offset: impl AsRef<[i32]>,
-        tube_section_height: impl AsRef<[i32]>,
+        tube_section_height: impl AsRef<[Millimeters]>,
         tube_section_ratio: impl AsRef<[i32]>,
         lld_mode: impl AsRef<[i16]>,
         capacitive_lld_sensitivity: impl AsRef<[i16]>,
@@ -398,31 +928,136 @@ impl NimbusCoreChannelCoord {
         dispense_type.as_ref().serialize(&mut args);
         tips_used.as_ref().serialize(&mut args);
         aspirate_dispense_pattern.as_ref().serialize(&mut args);
-        liquid_seek_height.as_ref().serialize(&mut args);
-        liquid_surface_height.as_ref().serialize(&mut args);
-        submerge_depth.as_ref().serialize(&mut args);
-        follow_depth.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
-        clot_check_height.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
-        liquid_exit_speed.as_ref().serialize(&mut args);
-        blowout_volume.as_ref().serialize(&mut args);
-        prewet_volume.as_ref().serialize(&mut args);
-        aspirate_volume.as_ref().serialize(&mut args);
-        transport_air_volume.as_ref().serialize(&mut args);
-        dispense_volume.as_ref().serialize(&mut args);
-        stop_back_volume.as_ref().serialize(&mut args);
-        aspirate_speed.as_ref().serialize(&mut args);
-        dispense_speed.as_ref().serialize(&mut args);
-        cutoff_speed.as_ref().serialize(&mut args);
+        liquid_seek_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        liquid_surface_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        submerge_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        follow_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        clot_check_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        liquid_exit_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        blowout_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        prewet_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        aspirate_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        transport_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        stop_back_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        aspirate_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        cutoff_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         settling_time.as_ref().serialize(&mut args);
-        mix_volume.as_ref().serialize(&mut args);
+        mix_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         mix_cycles.as_ref().serialize(&mut args);
-        mix_position.as_ref().serialize(&mut args);
-        mix_follow_distance.as_ref().serialize(&mut args);
-        mix_speed.as_ref().serialize(&mut args);
+        mix_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_follow_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         offset.as_ref().serialize(&mut args);
-        tube_section_height.as_ref().serialize(&mut args);
+        tube_section_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         tube_section_ratio.as_ref().serialize(&mut args);
         lld_mode.as_ref().serialize(&mut args);
         capacitive_lld_sensitivity.as_ref().serialize(&mut args);
@@ -432,7 +1067,9 @@ impl NimbusCoreChannelCoord {
         limit_curve_index.as_ref().serialize(&mut args);
         recording_mode.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  aspirate_type: {:?}", aspirate_type.as_ref()),
@@ -499,30 +1136,226 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn aspirate_and_dispense_with(
+        &self,
+        request: &AspirateAndDispenseRequest,
+    ) -> Result<(), Error> {
+        self.aspirate_and_dispense(
+            piglet_client::client::required(
+                request.aspirate_type.as_ref(),
+                "AspirateAndDispenseRequest",
+                "aspirate_type",
+            )?,
+            piglet_client::client::required(
+                request.dispense_type.as_ref(),
+                "AspirateAndDispenseRequest",
+                "dispense_type",
+            )?,
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "AspirateAndDispenseRequest",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_dispense_pattern.as_ref(),
+                "AspirateAndDispenseRequest",
+                "aspirate_dispense_pattern",
+            )?,
+            piglet_client::client::required(
+                request.liquid_seek_height.as_ref(),
+                "AspirateAndDispenseRequest",
+                "liquid_seek_height",
+            )?,
+            piglet_client::client::required(
+                request.liquid_surface_height.as_ref(),
+                "AspirateAndDispenseRequest",
+                "liquid_surface_height",
+            )?,
+            piglet_client::client::required(
+                request.submerge_depth.as_ref(),
+                "AspirateAndDispenseRequest",
+                "submerge_depth",
+            )?,
+            piglet_client::client::required(
+                request.follow_depth.as_ref(),
+                "AspirateAndDispenseRequest",
+                "follow_depth",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position.as_ref(),
+                "AspirateAndDispenseRequest",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(
+                request.clot_check_height.as_ref(),
+                "AspirateAndDispenseRequest",
+                "clot_check_height",
+            )?,
+            piglet_client::client::required(
+                request.z_final,
+                "AspirateAndDispenseRequest",
+                "z_final",
+            )?,
+            piglet_client::client::required(
+                request.liquid_exit_speed.as_ref(),
+                "AspirateAndDispenseRequest",
+                "liquid_exit_speed",
+            )?,
+            piglet_client::client::required(
+                request.blowout_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "blowout_volume",
+            )?,
+            piglet_client::client::required(
+                request.prewet_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "prewet_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "aspirate_volume",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "dispense_volume",
+            )?,
+            piglet_client::client::required(
+                request.stop_back_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "stop_back_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_speed.as_ref(),
+                "AspirateAndDispenseRequest",
+                "aspirate_speed",
+            )?,
+            piglet_client::client::required(
+                request.dispense_speed.as_ref(),
+                "AspirateAndDispenseRequest",
+                "dispense_speed",
+            )?,
+            piglet_client::client::required(
+                request.cutoff_speed.as_ref(),
+                "AspirateAndDispenseRequest",
+                "cutoff_speed",
+            )?,
+            piglet_client::client::required(
+                request.settling_time.as_ref(),
+                "AspirateAndDispenseRequest",
+                "settling_time",
+            )?,
+            piglet_client::client::required(
+                request.mix_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "mix_volume",
+            )?,
+            piglet_client::client::required(
+                request.mix_cycles.as_ref(),
+                "AspirateAndDispenseRequest",
+                "mix_cycles",
+            )?,
+            piglet_client::client::required(
+                request.mix_position.as_ref(),
+                "AspirateAndDispenseRequest",
+                "mix_position",
+            )?,
+            piglet_client::client::required(
+                request.mix_follow_distance.as_ref(),
+                "AspirateAndDispenseRequest",
+                "mix_follow_distance",
+            )?,
+            piglet_client::client::required(
+                request.mix_speed.as_ref(),
+                "AspirateAndDispenseRequest",
+                "mix_speed",
+            )?,
+            piglet_client::client::required(
+                request.offset.as_ref(),
+                "AspirateAndDispenseRequest",
+                "offset",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_height.as_ref(),
+                "AspirateAndDispenseRequest",
+                "tube_section_height",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_ratio.as_ref(),
+                "AspirateAndDispenseRequest",
+                "tube_section_ratio",
+            )?,
+            piglet_client::client::required(
+                request.lld_mode.as_ref(),
+                "AspirateAndDispenseRequest",
+                "lld_mode",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_sensitivity.as_ref(),
+                "AspirateAndDispenseRequest",
+                "capacitive_lld_sensitivity",
+            )?,
+            piglet_client::client::required(
+                request.pressure_lld_sensitivity.as_ref(),
+                "AspirateAndDispenseRequest",
+                "pressure_lld_sensitivity",
+            )?,
+            piglet_client::client::required(
+                request.lld_height_difference.as_ref(),
+                "AspirateAndDispenseRequest",
+                "lld_height_difference",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "AspirateAndDispenseRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index.as_ref(),
+                "AspirateAndDispenseRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "AspirateAndDispenseRequest",
+                "recording_mode",
+            )?,
+        )
+        .await
+    }
+
     pub async fn pickup_gripper_tool(
         &self,
 
-        z_start_position: i32,
-        z_stop_position: i32,
-        z_final: i32,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
         tip_type: u16,
         first_channel_number: u16,
         second_channel_number: u16,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
-        z_final.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
         tip_type.serialize(&mut args);
         first_channel_number.serialize(&mut args);
         second_channel_number.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  z_start_position: {:?}", z_start_position),
@@ -540,7 +1373,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -548,20 +1381,22 @@ impl NimbusCoreChannelCoord {
     pub async fn drop_gripper_tool(
         &self,
 
-        z_start_position: i32,
-        z_stop_position: i32,
-        z_final: i32,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
         first_channel_number: u16,
         second_channel_number: u16,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
-        z_final.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
         first_channel_number.serialize(&mut args);
         second_channel_number.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  z_start_position: {:?}", z_start_position),
@@ -578,7 +1413,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -587,7 +1422,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 8, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -598,7 +1435,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -607,7 +1444,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 9, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -618,7 +1457,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -627,13 +1466,20 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        y_distance: impl AsRef<[i32]>,
+        y_distance: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        y_distance.as_ref().serialize(&mut args);
+        y_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 10, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -647,7 +1493,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -656,13 +1502,20 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        y_position: impl AsRef<[i32]>,
+        y_position: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 11, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -676,7 +1529,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -685,17 +1538,24 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        y_distance: impl AsRef<[i32]>,
+        y_distance: impl AsRef<[Millimeters]>,
         acceleration: impl AsRef<[u32]>,
         velocity: impl AsRef<[u32]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        y_distance.as_ref().serialize(&mut args);
+        y_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         acceleration.as_ref().serialize(&mut args);
         velocity.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 12, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -711,7 +1571,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -720,17 +1580,24 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        y_position: impl AsRef<[i32]>,
+        y_position: impl AsRef<[Millimeters]>,
         acceleration: impl AsRef<[u32]>,
         velocity: impl AsRef<[u32]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         acceleration.as_ref().serialize(&mut args);
         velocity.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 13, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 13, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -746,7 +1613,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -755,15 +1622,22 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        y_position: impl AsRef<[i32]>,
+        y_position: impl AsRef<[Millimeters]>,
         velocity: impl AsRef<[u32]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         velocity.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 14, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 14, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -778,7 +1652,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -787,17 +1661,24 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        y_position: impl AsRef<[i32]>,
+        y_position: impl AsRef<[Millimeters]>,
         acceleration: impl AsRef<[u32]>,
         velocity: impl AsRef<[u32]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         acceleration.as_ref().serialize(&mut args);
         velocity.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 15, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 15, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -813,7 +1694,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -822,7 +1703,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 16, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 16, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -833,7 +1716,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -842,7 +1725,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 17, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 17, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -853,7 +1738,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -862,13 +1747,20 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_distance: impl AsRef<[i32]>,
+        z_distance: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_distance.as_ref().serialize(&mut args);
+        z_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 18, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 18, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -882,7 +1774,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -891,13 +1783,20 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_position: impl AsRef<[i32]>,
+        z_position: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_position.as_ref().serialize(&mut args);
+        z_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 19, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 19, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -911,7 +1810,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -920,17 +1819,24 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_distance: impl AsRef<[i32]>,
+        z_distance: impl AsRef<[Millimeters]>,
         acceleration: impl AsRef<[u32]>,
         velocity: impl AsRef<[u32]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_distance.as_ref().serialize(&mut args);
+        z_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         acceleration.as_ref().serialize(&mut args);
         velocity.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 20, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 20, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -946,7 +1852,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -955,17 +1861,24 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_position: impl AsRef<[i32]>,
+        z_position: impl AsRef<[Millimeters]>,
         acceleration: impl AsRef<[u32]>,
         velocity: impl AsRef<[u32]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_position.as_ref().serialize(&mut args);
+        z_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         acceleration.as_ref().serialize(&mut args);
         velocity.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 21, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 21, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -981,7 +1894,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -990,13 +1903,20 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_position: impl AsRef<[i32]>,
+        z_position: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_position.as_ref().serialize(&mut args);
+        z_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 22, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 22, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1010,7 +1930,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1019,17 +1939,29 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_position: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
+        z_position: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
         velocity: impl AsRef<[u32]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_position.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
+        z_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         velocity.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 23, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 23, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1045,7 +1977,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1054,17 +1986,24 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_position: impl AsRef<[i32]>,
+        z_position: impl AsRef<[Millimeters]>,
         acceleration: impl AsRef<[u32]>,
         velocity: impl AsRef<[u32]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_position.as_ref().serialize(&mut args);
+        z_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         acceleration.as_ref().serialize(&mut args);
         velocity.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 24, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 24, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1080,7 +2019,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1089,7 +2028,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 25, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 25, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1100,7 +2041,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1109,7 +2050,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 26, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 26, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1120,7 +2063,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1129,13 +2072,20 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        volume: impl AsRef<[i32]>,
+        volume: impl AsRef<[Microliters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        volume.as_ref().serialize(&mut args);
+        volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 27, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 27, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1149,7 +2099,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1158,7 +2108,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 28, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 28, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1169,7 +2121,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1178,7 +2130,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 29, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 29, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1189,7 +2143,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1198,7 +2152,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 30, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 30, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1209,7 +2165,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1218,7 +2174,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 31, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 31, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1229,34 +2187,41 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn get_y_positions(&self) -> Result</* y_positions= */ Vec<i32>, Error> {
+    pub async fn get_y_positions(&self) -> Result</* y_positions= */ Vec<Millimeters>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 32, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 32, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetYPositions()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let y_positions = Vec::<i32>::deserialize(&mut stream)?;
+        let y_positions = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         Ok(y_positions)
     }
 
     pub async fn get_y_home_offsets(&self) -> Result</* y_home_offsets= */ Vec<i32>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 33, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 33, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetYHomeOffsets()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let y_home_offsets = Vec::<i32>::deserialize(&mut stream)?;
         Ok(y_home_offsets)
@@ -1272,7 +2237,9 @@ impl NimbusCoreChannelCoord {
         tips_used.as_ref().serialize(&mut args);
         y_home_offsets.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 34, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 34, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1286,34 +2253,41 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn get_z_positions(&self) -> Result</* z_positions= */ Vec<i32>, Error> {
+    pub async fn get_z_positions(&self) -> Result</* z_positions= */ Vec<Millimeters>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 35, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 35, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetZPositions()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let z_positions = Vec::<i32>::deserialize(&mut stream)?;
+        let z_positions = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         Ok(z_positions)
     }
 
     pub async fn get_z_home_offsets(&self) -> Result</* z_home_offsets= */ Vec<i32>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 36, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 36, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetZHomeOffsets()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let z_home_offsets = Vec::<i32>::deserialize(&mut stream)?;
         Ok(z_home_offsets)
@@ -1329,7 +2303,9 @@ impl NimbusCoreChannelCoord {
         tips_used.as_ref().serialize(&mut args);
         z_home_offsets.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 37, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 37, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1343,7 +2319,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1351,12 +2327,14 @@ impl NimbusCoreChannelCoord {
     pub async fn get_init_status(&self) -> Result</* initialization_status= */ Vec<i16>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 38, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 38, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetInitStatus()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let initialization_status = Vec::<i16>::deserialize(&mut stream)?;
         Ok(initialization_status)
@@ -1365,12 +2343,14 @@ impl NimbusCoreChannelCoord {
     pub async fn get_tip_status(&self) -> Result</* tip_present= */ Vec<i16>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 39, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 39, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetTipStatus()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let tip_present = Vec::<i16>::deserialize(&mut stream)?;
         Ok(tip_present)
@@ -1379,12 +2359,14 @@ impl NimbusCoreChannelCoord {
     pub async fn get_lld_status(&self) -> Result</* lld_status= */ Vec<i16>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 40, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 40, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetLldStatus()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let lld_status = Vec::<i16>::deserialize(&mut stream)?;
         Ok(lld_status)
@@ -1394,21 +2376,23 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_start_position: i32,
-        z_stop_position: i32,
-        z_final: i32,
-        volume: u32,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
         collet_check: i16,
     ) -> Result</* steps= */ Vec<i32>, Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
-        z_final.serialize(&mut args);
-        volume.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        volume.to_wire::<u32>()?.serialize(&mut args);
         collet_check.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 41, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 41, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1426,7 +2410,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let steps = Vec::<i32>::deserialize(&mut stream)?;
         Ok(steps)
@@ -1440,7 +2424,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 42, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 42, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1451,7 +2437,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1460,15 +2446,17 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_start_position: i32,
-        z_stop_position: i32,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 43, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 43, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1483,7 +2471,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1492,21 +2480,23 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_start_position: i32,
-        z_stop_position: i32,
-        z_final: i32,
-        tip_volume: u32,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
         collet_check: i16,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
-        z_final.serialize(&mut args);
-        tip_volume.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        tip_volume.to_wire::<u32>()?.serialize(&mut args);
         collet_check.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 44, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 44, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1524,7 +2514,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1533,7 +2523,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 45, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 45, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1544,7 +2536,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1553,7 +2545,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 46, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 46, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -1564,7 +2558,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1587,7 +2581,9 @@ impl NimbusCoreChannelCoord {
         upper_limit_x.as_ref().serialize(&mut args);
         upper_limit_y.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 47, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 47, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -1605,7 +2601,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let index = u32::deserialize(&mut stream)?;
         Ok(CreateLimitCurveReply { index })
@@ -1615,7 +2611,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 48, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 48, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1626,7 +2624,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1639,7 +2637,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 49, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 49, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1650,7 +2650,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let offsets = Vec::<u16>::deserialize(&mut stream)?;
         let tadm_data = Vec::<i16>::deserialize(&mut stream)?;
@@ -1665,7 +2665,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 50, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 50, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -1676,7 +2678,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let names = String::deserialize(&mut stream)?;
         Ok(GetLimitCurveNamesReply { names })
@@ -1692,7 +2694,9 @@ impl NimbusCoreChannelCoord {
         channel.serialize(&mut args);
         name.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 51, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 51, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -1706,7 +2710,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let index = u32::deserialize(&mut stream)?;
         let lower_limits = u16::deserialize(&mut stream)?;
@@ -1728,7 +2732,9 @@ impl NimbusCoreChannelCoord {
         tips_used.as_ref().serialize(&mut args);
         enable.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 52, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 52, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1742,7 +2748,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1750,12 +2756,14 @@ impl NimbusCoreChannelCoord {
     pub async fn get_tadm_enable(&self) -> Result<GetTADMEnableReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 53, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 53, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetTADMEnable()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let enable = Vec::<i16>::deserialize(&mut stream)?;
         Ok(GetTADMEnableReply { enable })
@@ -1775,7 +2783,9 @@ impl NimbusCoreChannelCoord {
         minimum_pressure_difference.as_ref().serialize(&mut args);
         maximum_pressure_difference.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 54, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 54, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1797,7 +2807,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1806,7 +2816,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 55, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 55, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1817,7 +2829,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1826,7 +2838,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 56, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 56, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1837,7 +2851,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1846,7 +2860,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 57, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 57, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1857,7 +2873,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1866,13 +2882,20 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        speed: impl AsRef<[i32]>,
+        speed: impl AsRef<[MmPerSecond]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        speed.as_ref().serialize(&mut args);
+        speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 58, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 58, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1886,7 +2909,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1894,14 +2917,19 @@ impl NimbusCoreChannelCoord {
     pub async fn get_z_default_speed(&self) -> Result<GetZDefaultSpeedReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 59, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 59, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetZDefaultSpeed()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let speed = Vec::<i32>::deserialize(&mut stream)?;
+        let speed = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(MmPerSecond::from_wire)
+            .collect::<Vec<_>>();
         Ok(GetZDefaultSpeedReply { speed })
     }
 
@@ -1909,19 +2937,36 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        obstacle_seek_height: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
-        z_final: i32,
-        seek_speed: impl AsRef<[u32]>,
+        obstacle_seek_height: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]>,
     ) -> Result</* obstacle_detected= */ Vec<i16>, Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        obstacle_seek_height.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
-        seek_speed.as_ref().serialize(&mut args);
+        obstacle_seek_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        seek_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 60, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 60, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1941,7 +2986,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let obstacle_detected = Vec::<i16>::deserialize(&mut stream)?;
         Ok(obstacle_detected)
@@ -1950,12 +2995,14 @@ impl NimbusCoreChannelCoord {
     pub async fn get_pressure(&self) -> Result</* pressures= */ Vec<i16>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 61, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 61, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetPressure()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let pressures = Vec::<i16>::deserialize(&mut stream)?;
         Ok(pressures)
@@ -1964,12 +3011,14 @@ impl NimbusCoreChannelCoord {
     pub async fn get_potentiometer_settings(&self) -> Result<GetPotentiometerSettingsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 62, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 62, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetPotentiometerSettings()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let gain = Vec::<i16>::deserialize(&mut stream)?;
         let offset = Vec::<i16>::deserialize(&mut stream)?;
@@ -1980,7 +3029,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 63, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 63, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1991,7 +3042,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2001,7 +3052,9 @@ impl NimbusCoreChannelCoord {
         channel.serialize(&mut args);
         mode.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 64, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 64, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -2015,7 +3068,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2025,7 +3078,9 @@ impl NimbusCoreChannelCoord {
         channel.serialize(&mut args);
         gain.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 65, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 65, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -2039,7 +3094,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2049,7 +3104,9 @@ impl NimbusCoreChannelCoord {
         channel.serialize(&mut args);
         offset.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 66, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 66, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -2063,7 +3120,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2073,7 +3130,9 @@ impl NimbusCoreChannelCoord {
         channel.serialize(&mut args);
         shift.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 67, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 67, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -2087,7 +3146,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2096,7 +3155,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 68, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 68, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -2107,7 +3168,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2116,7 +3177,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 69, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 69, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -2127,7 +3190,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2136,7 +3199,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 70, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 70, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -2147,7 +3212,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2156,11 +3221,11 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        volume: impl AsRef<[u32]>,
-        stop_back_volume: impl AsRef<[u32]>,
-        transport_air_volume: impl AsRef<[u32]>,
-        dispense_speed: impl AsRef<[u32]>,
-        cutoff_speed: impl AsRef<[u32]>,
+        volume: impl AsRef<[Microliters]>,
+        stop_back_volume: impl AsRef<[Microliters]>,
+        transport_air_volume: impl AsRef<[Microliters]>,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]>,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]>,
         tadm_enabled: impl AsRef<[i16]>,
         limit_curve_index: impl AsRef<[u32]>,
         dispense_pattern: impl AsRef<[u16]>,
@@ -2168,17 +3233,44 @@ impl NimbusCoreChannelCoord {
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        volume.as_ref().serialize(&mut args);
-        stop_back_volume.as_ref().serialize(&mut args);
-        transport_air_volume.as_ref().serialize(&mut args);
-        dispense_speed.as_ref().serialize(&mut args);
-        cutoff_speed.as_ref().serialize(&mut args);
+        volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        stop_back_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        transport_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        cutoff_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         tadm_enabled.as_ref().serialize(&mut args);
         limit_curve_index.as_ref().serialize(&mut args);
         dispense_pattern.as_ref().serialize(&mut args);
         recording_mode.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 71, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 71, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2203,11 +3295,70 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn prepare_on_the_fly_dispense_with(
+        &self,
+        request: &PrepareOnTheFlyDispenseRequest,
+    ) -> Result<(), Error> {
+        self.prepare_on_the_fly_dispense(
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "PrepareOnTheFlyDispenseRequest",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.volume.as_ref(),
+                "PrepareOnTheFlyDispenseRequest",
+                "volume",
+            )?,
+            piglet_client::client::required(
+                request.stop_back_volume.as_ref(),
+                "PrepareOnTheFlyDispenseRequest",
+                "stop_back_volume",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume.as_ref(),
+                "PrepareOnTheFlyDispenseRequest",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_speed.as_ref(),
+                "PrepareOnTheFlyDispenseRequest",
+                "dispense_speed",
+            )?,
+            piglet_client::client::required(
+                request.cutoff_speed.as_ref(),
+                "PrepareOnTheFlyDispenseRequest",
+                "cutoff_speed",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled.as_ref(),
+                "PrepareOnTheFlyDispenseRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index.as_ref(),
+                "PrepareOnTheFlyDispenseRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.dispense_pattern.as_ref(),
+                "PrepareOnTheFlyDispenseRequest",
+                "dispense_pattern",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "PrepareOnTheFlyDispenseRequest",
+                "recording_mode",
+            )?,
+        )
+        .await
+    }
+
     pub async fn start_on_the_fly_dispense(
         &self,
 
@@ -2216,7 +3367,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 72, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 72, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2227,7 +3380,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2240,7 +3393,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 73, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 73, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2251,7 +3406,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2264,7 +3419,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 74, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 74, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2275,7 +3432,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let error = bool::deserialize(&mut stream)?;
         let results = Vec::<u16>::deserialize(&mut stream)?;
@@ -2285,12 +3442,14 @@ impl NimbusCoreChannelCoord {
     pub async fn send_synch_signal(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 75, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 75, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.SendSynchSignal()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2299,15 +3458,27 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        aspirate_volume: impl AsRef<[u32]>,
-        aspirate_speed: impl AsRef<[u32]>,
+        aspirate_volume: impl AsRef<[Microliters]>,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        aspirate_volume.as_ref().serialize(&mut args);
-        aspirate_speed.as_ref().serialize(&mut args);
+        aspirate_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        aspirate_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 76, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 76, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2322,7 +3493,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2331,19 +3502,41 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        dispense_volume: impl AsRef<[u32]>,
-        stop_back_volume: impl AsRef<[u32]>,
-        dispense_speed: impl AsRef<[u32]>,
-        cutoff_speed: impl AsRef<[u32]>,
+        dispense_volume: impl AsRef<[Microliters]>,
+        stop_back_volume: impl AsRef<[Microliters]>,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]>,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        dispense_volume.as_ref().serialize(&mut args);
-        stop_back_volume.as_ref().serialize(&mut args);
-        dispense_speed.as_ref().serialize(&mut args);
-        cutoff_speed.as_ref().serialize(&mut args);
+        dispense_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        stop_back_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        cutoff_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 77, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 77, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2360,36 +3553,48 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn get_current_dispenser_volume(&self) -> Result</* volume= */ Vec<u32>, Error> {
+    pub async fn get_current_dispenser_volume(
+        &self,
+    ) -> Result</* volume= */ Vec<Microliters>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 78, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 78, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetCurrentDispenserVolume()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let volume = Vec::<u32>::deserialize(&mut stream)?;
+        let volume = Vec::<u32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Microliters::from_wire)
+            .collect::<Vec<_>>();
         Ok(volume)
     }
 
-    pub async fn get_liquid_height(&self) -> Result</* liquid_height= */ Vec<i32>, Error> {
+    pub async fn get_liquid_height(&self) -> Result</* liquid_height= */ Vec<Millimeters>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 79, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 79, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetLiquidHeight()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let liquid_height = Vec::<i32>::deserialize(&mut stream)?;
+        let liquid_height = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         Ok(liquid_height)
     }
 
@@ -2403,7 +3608,9 @@ impl NimbusCoreChannelCoord {
         channel.serialize(&mut args);
         indexes.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 80, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 80, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -2417,7 +3624,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let enabled = Vec::<bool>::deserialize(&mut stream)?;
         Ok(GetChannelConfigurationReply { enabled })
@@ -2435,7 +3642,9 @@ impl NimbusCoreChannelCoord {
         indexes.as_ref().serialize(&mut args);
         enables.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 81, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 81, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -2450,7 +3659,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2459,23 +3668,40 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
-        z_final: impl AsRef<[i32]>,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
+        z_final: impl AsRef<[Millimeters]>,
         tip_type: impl AsRef<[u16]>,
         time: impl AsRef<[u32]>,
         test_type: impl AsRef<[bool]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
-        z_final.as_ref().serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         tip_type.as_ref().serialize(&mut args);
         time.as_ref().serialize(&mut args);
         test_type.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 82, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 82, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2494,7 +3720,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2503,7 +3729,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 83, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 83, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -2514,7 +3742,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2523,19 +3751,36 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        obstacle_seek_height: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
-        z_final: i32,
-        seek_speed: impl AsRef<[u32]>,
+        obstacle_seek_height: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]>,
     ) -> Result<ZSeekObstaclePositionReply, Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        obstacle_seek_height.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
-        seek_speed.as_ref().serialize(&mut args);
+        obstacle_seek_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        seek_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 84, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 84, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2555,9 +3800,12 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
-        let z_position = Vec::<i32>::deserialize(&mut stream)?;
+        let z_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         let obstacle_detected = Vec::<i16>::deserialize(&mut stream)?;
         Ok(ZSeekObstaclePositionReply {
             z_position,
@@ -2569,21 +3817,38 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        seek_height: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
-        z_final: i32,
-        seek_speed: impl AsRef<[u32]>,
+        seek_height: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]>,
         capacitive_lld_sensitivity: impl AsRef<[i16]>,
     ) -> Result<ZSeekLldPositionReply, Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        seek_height.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
-        seek_speed.as_ref().serialize(&mut args);
+        seek_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        seek_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         capacitive_lld_sensitivity.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 85, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 85, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2604,9 +3869,12 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
-        let z_position = Vec::<i32>::deserialize(&mut stream)?;
+        let z_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         let lld_detected = Vec::<i16>::deserialize(&mut stream)?;
         Ok(ZSeekLldPositionReply {
             z_position,
@@ -2622,7 +3890,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 86, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 86, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2633,7 +3903,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2646,7 +3916,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 87, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 87, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2657,7 +3929,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2666,13 +3938,20 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        seek_speeds: impl AsRef<[u32]>,
+        seek_speeds: impl AsRef<[MmPerSecond]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        seek_speeds.as_ref().serialize(&mut args);
+        seek_speeds
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 88, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 88, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2686,22 +3965,29 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn get_z_liquid_seek_speed(&self) -> Result</* seek_speeds= */ Vec<u32>, Error> {
+    pub async fn get_z_liquid_seek_speed(
+        &self,
+    ) -> Result</* seek_speeds= */ Vec<MmPerSecond>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 89, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 89, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetZLiquidSeekSpeed()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let seek_speeds = Vec::<u32>::deserialize(&mut stream)?;
+        let seek_speeds = Vec::<u32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(MmPerSecond::from_wire)
+            .collect::<Vec<_>>();
         Ok(seek_speeds)
     }
 
@@ -2709,19 +3995,31 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_position: impl AsRef<[i32]>,
-        z_speed_switch_position: impl AsRef<[i32]>,
+        z_position: impl AsRef<[Millimeters]>,
+        z_speed_switch_position: impl AsRef<[Millimeters]>,
         velocity: impl AsRef<[u32]>,
         lower_section_velocity: impl AsRef<[u32]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_position.as_ref().serialize(&mut args);
-        z_speed_switch_position.as_ref().serialize(&mut args);
+        z_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_speed_switch_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         velocity.as_ref().serialize(&mut args);
         lower_section_velocity.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 90, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 90, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2744,7 +4042,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2753,7 +4051,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 91, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 91, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2764,7 +4064,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2773,7 +4073,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 92, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 92, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2784,7 +4086,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2793,7 +4095,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 93, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 93, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2804,7 +4108,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2812,12 +4116,14 @@ impl NimbusCoreChannelCoord {
     pub async fn get_home_sensors(&self) -> Result<GetHomeSensorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 94, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 94, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetHomeSensors()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let y_home = Vec::<bool>::deserialize(&mut stream)?;
         let z_home = Vec::<bool>::deserialize(&mut stream)?;
@@ -2834,12 +4140,14 @@ impl NimbusCoreChannelCoord {
     pub async fn get_calibration_values(&self) -> Result<GetCalibrationValuesReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 95, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 95, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetCalibrationValues()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let dispense_drive = Vec::<i16>::deserialize(&mut stream)?;
         let squeeze_drive = Vec::<i16>::deserialize(&mut stream)?;
@@ -2858,12 +4166,14 @@ impl NimbusCoreChannelCoord {
     ) -> Result<GetExtendedCalibrationValuesReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 96, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 96, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetExtendedCalibrationValues()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let tip_type_adjustment = Vec::<i16>::deserialize(&mut stream)?;
         let pressure_conversion = Vec::<i16>::deserialize(&mut stream)?;
@@ -2880,12 +4190,14 @@ impl NimbusCoreChannelCoord {
     pub async fn get_counters(&self) -> Result<GetCountersReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 97, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 97, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.GetCounters()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let tip_pickup_counter = Vec::<u32>::deserialize(&mut stream)?;
         let tip_eject_counter = Vec::<u32>::deserialize(&mut stream)?;
@@ -2907,7 +4219,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 98, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 98, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -2918,7 +4232,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let channel_info = String::deserialize(&mut stream)?;
         Ok(channel_info)
@@ -2940,7 +4254,9 @@ impl NimbusCoreChannelCoord {
         aspirate_counter.as_ref().serialize(&mut args);
         dispense_counter.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 99, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 99, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2957,7 +4273,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2973,7 +4289,7 @@ impl NimbusCoreChannelCoord {
         seconds.serialize(&mut args);
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 100, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 100, args.freeze(), self.timeout)
                 .await,
             || {
                 let parameters = vec![
@@ -2988,7 +4304,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let detects_per_minute = u16::deserialize(&mut stream)?;
         Ok(detects_per_minute)
@@ -2998,20 +4314,25 @@ impl NimbusCoreChannelCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        y_position: impl AsRef<[i32]>,
+        y_position: impl AsRef<[Millimeters]>,
         acceleration: impl AsRef<[u32]>,
         velocity: impl AsRef<[u32]>,
         delay: impl AsRef<[u32]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         acceleration.as_ref().serialize(&mut args);
         velocity.as_ref().serialize(&mut args);
         delay.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 101, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 101, args.freeze(), self.timeout)
                 .await,
             || {
                 let parameters = vec![
@@ -3029,7 +4350,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -3039,7 +4360,7 @@ impl NimbusCoreChannelCoord {
 
         tips_used: impl AsRef<[u16]>,
         gripper_tips_used: impl AsRef<[u16]>,
-        y_position: impl AsRef<[i32]>,
+        y_position: impl AsRef<[Millimeters]>,
         acceleration: impl AsRef<[u32]>,
         velocity: impl AsRef<[u32]>,
         delay: impl AsRef<[u32]>,
@@ -3047,13 +4368,18 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         gripper_tips_used.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         acceleration.as_ref().serialize(&mut args);
         velocity.as_ref().serialize(&mut args);
         delay.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 3, 102, args.freeze())
+                .act_with_timeout(&self.address, 1, 3, 102, args.freeze(), self.timeout)
                 .await,
             || {
                 let parameters = vec![
@@ -3072,7 +4398,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -3081,22 +4407,46 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 0, 103, args.freeze())
+                .act_with_timeout(&self.address, 1, 0, 103, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannelCoord.GetPositions()".to_string(),
         )?;
 
         if count != 8 {
-            return Err(ConnectionError(anyhow!("Expected 8 values, not {}", count)));
-        }
-        let y_position = Vec::<i32>::deserialize(&mut stream)?;
-        let y_encoder_position = Vec::<i32>::deserialize(&mut stream)?;
-        let z_position = Vec::<i32>::deserialize(&mut stream)?;
-        let z_encoder_position = Vec::<i32>::deserialize(&mut stream)?;
-        let d_position = Vec::<i32>::deserialize(&mut stream)?;
-        let d_encoder_position = Vec::<i32>::deserialize(&mut stream)?;
-        let s_position = Vec::<i32>::deserialize(&mut stream)?;
-        let s_encoder_position = Vec::<i32>::deserialize(&mut stream)?;
+            return Err(ProtocolError(anyhow!("Expected 8 values, not {}", count)));
+        }
+        let y_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let y_encoder_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let z_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let z_encoder_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let d_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let d_encoder_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let s_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let s_encoder_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         Ok(GetPositionsReply {
             y_position,
             y_encoder_position,
@@ -3113,13 +4463,13 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
             self.robot
-                .act(&self.address, 1, 0, 104, args.freeze())
+                .act_with_timeout(&self.address, 1, 0, 104, args.freeze(), self.timeout)
                 .await,
             || "in call to NimbusCoreChannelCoord.GetTipTypes()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let tip_present = Vec::<i16>::deserialize(&mut stream)?;
         Ok(tip_present)
@@ -3128,12 +4478,14 @@ impl NimbusCoreChannelCoord {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -3151,7 +4503,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -3162,7 +4516,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -3184,7 +4538,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -3195,7 +4551,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -3210,12 +4566,14 @@ impl NimbusCoreChannelCoord {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreChannelCoord.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -3229,7 +4587,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -3240,7 +4600,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -3258,7 +4618,9 @@ impl NimbusCoreChannelCoord {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -3269,7 +4631,7 @@ impl NimbusCoreChannelCoord {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -3284,81 +4646,4959 @@ impl NimbusCoreChannelCoord {
     }
 }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct CreateLimitCurveReply {
-    pub index: u32,
-}
+// The methods above as a trait, so code using them can be tested against NimbusCoreChannelCoordFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreChannelCoordApi {
+    fn pickup_tips(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        tip_type: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn drop_tips(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn aspirate(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        aspirate_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct RetrieveTadmDataReply {
-    pub offsets: Vec<u16>,
-    pub tadm_data: Vec<i16>,
-}
+    fn aspirate_with(
+        &self,
+        request: &AspirateRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.aspirate(
+                piglet_client::client::required(
+                    request.aspirate_type.as_ref(),
+                    "AspirateRequest",
+                    "aspirate_type",
+                )?,
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "AspirateRequest",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_seek_height.as_ref(),
+                    "AspirateRequest",
+                    "liquid_seek_height",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_surface_height.as_ref(),
+                    "AspirateRequest",
+                    "liquid_surface_height",
+                )?,
+                piglet_client::client::required(
+                    request.submerge_depth.as_ref(),
+                    "AspirateRequest",
+                    "submerge_depth",
+                )?,
+                piglet_client::client::required(
+                    request.follow_depth.as_ref(),
+                    "AspirateRequest",
+                    "follow_depth",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position.as_ref(),
+                    "AspirateRequest",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(
+                    request.clot_check_height.as_ref(),
+                    "AspirateRequest",
+                    "clot_check_height",
+                )?,
+                piglet_client::client::required(request.z_final, "AspirateRequest", "z_final")?,
+                piglet_client::client::required(
+                    request.liquid_exit_speed.as_ref(),
+                    "AspirateRequest",
+                    "liquid_exit_speed",
+                )?,
+                piglet_client::client::required(
+                    request.blowout_volume.as_ref(),
+                    "AspirateRequest",
+                    "blowout_volume",
+                )?,
+                piglet_client::client::required(
+                    request.prewet_volume.as_ref(),
+                    "AspirateRequest",
+                    "prewet_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_volume.as_ref(),
+                    "AspirateRequest",
+                    "aspirate_volume",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume.as_ref(),
+                    "AspirateRequest",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_speed.as_ref(),
+                    "AspirateRequest",
+                    "aspirate_speed",
+                )?,
+                piglet_client::client::required(
+                    request.settling_time.as_ref(),
+                    "AspirateRequest",
+                    "settling_time",
+                )?,
+                piglet_client::client::required(
+                    request.mix_volume.as_ref(),
+                    "AspirateRequest",
+                    "mix_volume",
+                )?,
+                piglet_client::client::required(
+                    request.mix_cycles.as_ref(),
+                    "AspirateRequest",
+                    "mix_cycles",
+                )?,
+                piglet_client::client::required(
+                    request.mix_position.as_ref(),
+                    "AspirateRequest",
+                    "mix_position",
+                )?,
+                piglet_client::client::required(
+                    request.mix_follow_distance.as_ref(),
+                    "AspirateRequest",
+                    "mix_follow_distance",
+                )?,
+                piglet_client::client::required(
+                    request.mix_speed.as_ref(),
+                    "AspirateRequest",
+                    "mix_speed",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_offset.as_ref(),
+                    "AspirateRequest",
+                    "aspirate_offset",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_height.as_ref(),
+                    "AspirateRequest",
+                    "tube_section_height",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_ratio.as_ref(),
+                    "AspirateRequest",
+                    "tube_section_ratio",
+                )?,
+                piglet_client::client::required(
+                    request.lld_mode.as_ref(),
+                    "AspirateRequest",
+                    "lld_mode",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_sensitivity.as_ref(),
+                    "AspirateRequest",
+                    "capacitive_lld_sensitivity",
+                )?,
+                piglet_client::client::required(
+                    request.pressure_lld_sensitivity.as_ref(),
+                    "AspirateRequest",
+                    "pressure_lld_sensitivity",
+                )?,
+                piglet_client::client::required(
+                    request.lld_height_difference.as_ref(),
+                    "AspirateRequest",
+                    "lld_height_difference",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "AspirateRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index.as_ref(),
+                    "AspirateRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "AspirateRequest",
+                    "recording_mode",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn dispense(
+        &self,
+        dispense_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        touchoff_distance: Millimeters,
+        dispense_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GetLimitCurveNamesReply {
-    pub names: String,
-}
+    fn dispense_with(
+        &self,
+        request: &DispenseRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.dispense(
+                piglet_client::client::required(
+                    request.dispense_type.as_ref(),
+                    "DispenseRequest",
+                    "dispense_type",
+                )?,
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "DispenseRequest",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_seek_height.as_ref(),
+                    "DispenseRequest",
+                    "liquid_seek_height",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_height.as_ref(),
+                    "DispenseRequest",
+                    "dispense_height",
+                )?,
+                piglet_client::client::required(
+                    request.submerge_depth.as_ref(),
+                    "DispenseRequest",
+                    "submerge_depth",
+                )?,
+                piglet_client::client::required(
+                    request.follow_depth.as_ref(),
+                    "DispenseRequest",
+                    "follow_depth",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position.as_ref(),
+                    "DispenseRequest",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(request.z_final, "DispenseRequest", "z_final")?,
+                piglet_client::client::required(
+                    request.liquid_exit_speed.as_ref(),
+                    "DispenseRequest",
+                    "liquid_exit_speed",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume.as_ref(),
+                    "DispenseRequest",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_volume.as_ref(),
+                    "DispenseRequest",
+                    "dispense_volume",
+                )?,
+                piglet_client::client::required(
+                    request.stop_back_volume.as_ref(),
+                    "DispenseRequest",
+                    "stop_back_volume",
+                )?,
+                piglet_client::client::required(
+                    request.blowout_volume.as_ref(),
+                    "DispenseRequest",
+                    "blowout_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_speed.as_ref(),
+                    "DispenseRequest",
+                    "dispense_speed",
+                )?,
+                piglet_client::client::required(
+                    request.cutoff_speed.as_ref(),
+                    "DispenseRequest",
+                    "cutoff_speed",
+                )?,
+                piglet_client::client::required(
+                    request.settling_time.as_ref(),
+                    "DispenseRequest",
+                    "settling_time",
+                )?,
+                piglet_client::client::required(
+                    request.mix_volume.as_ref(),
+                    "DispenseRequest",
+                    "mix_volume",
+                )?,
+                piglet_client::client::required(
+                    request.mix_cycles.as_ref(),
+                    "DispenseRequest",
+                    "mix_cycles",
+                )?,
+                piglet_client::client::required(
+                    request.mix_position.as_ref(),
+                    "DispenseRequest",
+                    "mix_position",
+                )?,
+                piglet_client::client::required(
+                    request.mix_follow_distance.as_ref(),
+                    "DispenseRequest",
+                    "mix_follow_distance",
+                )?,
+                piglet_client::client::required(
+                    request.mix_speed.as_ref(),
+                    "DispenseRequest",
+                    "mix_speed",
+                )?,
+                piglet_client::client::required(
+                    request.touchoff_distance,
+                    "DispenseRequest",
+                    "touchoff_distance",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_offset.as_ref(),
+                    "DispenseRequest",
+                    "dispense_offset",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_height.as_ref(),
+                    "DispenseRequest",
+                    "tube_section_height",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_ratio.as_ref(),
+                    "DispenseRequest",
+                    "tube_section_ratio",
+                )?,
+                piglet_client::client::required(
+                    request.lld_mode.as_ref(),
+                    "DispenseRequest",
+                    "lld_mode",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_sensitivity.as_ref(),
+                    "DispenseRequest",
+                    "capacitive_lld_sensitivity",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "DispenseRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index.as_ref(),
+                    "DispenseRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "DispenseRequest",
+                    "recording_mode",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn aspirate_and_dispense(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        dispense_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        aspirate_dispense_pattern: impl AsRef<[i16]> + Send,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn aspirate_and_dispense_with(
+        &self,
+        request: &AspirateAndDispenseRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.aspirate_and_dispense(
+                piglet_client::client::required(
+                    request.aspirate_type.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "aspirate_type",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_type.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "dispense_type",
+                )?,
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_dispense_pattern.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "aspirate_dispense_pattern",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_seek_height.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "liquid_seek_height",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_surface_height.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "liquid_surface_height",
+                )?,
+                piglet_client::client::required(
+                    request.submerge_depth.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "submerge_depth",
+                )?,
+                piglet_client::client::required(
+                    request.follow_depth.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "follow_depth",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(
+                    request.clot_check_height.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "clot_check_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "AspirateAndDispenseRequest",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_exit_speed.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "liquid_exit_speed",
+                )?,
+                piglet_client::client::required(
+                    request.blowout_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "blowout_volume",
+                )?,
+                piglet_client::client::required(
+                    request.prewet_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "prewet_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "aspirate_volume",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "dispense_volume",
+                )?,
+                piglet_client::client::required(
+                    request.stop_back_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "stop_back_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_speed.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "aspirate_speed",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_speed.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "dispense_speed",
+                )?,
+                piglet_client::client::required(
+                    request.cutoff_speed.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "cutoff_speed",
+                )?,
+                piglet_client::client::required(
+                    request.settling_time.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "settling_time",
+                )?,
+                piglet_client::client::required(
+                    request.mix_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "mix_volume",
+                )?,
+                piglet_client::client::required(
+                    request.mix_cycles.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "mix_cycles",
+                )?,
+                piglet_client::client::required(
+                    request.mix_position.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "mix_position",
+                )?,
+                piglet_client::client::required(
+                    request.mix_follow_distance.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "mix_follow_distance",
+                )?,
+                piglet_client::client::required(
+                    request.mix_speed.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "mix_speed",
+                )?,
+                piglet_client::client::required(
+                    request.offset.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "offset",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_height.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "tube_section_height",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_ratio.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "tube_section_ratio",
+                )?,
+                piglet_client::client::required(
+                    request.lld_mode.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "lld_mode",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_sensitivity.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "capacitive_lld_sensitivity",
+                )?,
+                piglet_client::client::required(
+                    request.pressure_lld_sensitivity.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "pressure_lld_sensitivity",
+                )?,
+                piglet_client::client::required(
+                    request.lld_height_difference.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "lld_height_difference",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "AspirateAndDispenseRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "AspirateAndDispenseRequest",
+                    "recording_mode",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn pickup_gripper_tool(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_type: u16,
+        first_channel_number: u16,
+        second_channel_number: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn drop_gripper_tool(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        first_channel_number: u16,
+        second_channel_number: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_pre_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_absolute(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_relative_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_distance: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_absolute_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_seek_lld(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_absolute_gripper(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_safe(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_absolute(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_relative_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_distance: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_absolute_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_traverse(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_seek_lld(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_absolute_gripper(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn dispenser_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn dispenser_empty(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn dispenser_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn squeeze_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn squeeze_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn lld_on(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn lld_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_y_positions(
+        &self,
+    ) -> impl Future<Output = Result</* y_positions= */ Vec<Millimeters>, Error>> + Send;
+    fn get_y_home_offsets(
+        &self,
+    ) -> impl Future<Output = Result</* y_home_offsets= */ Vec<i32>, Error>> + Send;
+    fn set_y_home_offsets(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_home_offsets: impl AsRef<[i32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_z_positions(
+        &self,
+    ) -> impl Future<Output = Result</* z_positions= */ Vec<Millimeters>, Error>> + Send;
+    fn get_z_home_offsets(
+        &self,
+    ) -> impl Future<Output = Result</* z_home_offsets= */ Vec<i32>, Error>> + Send;
+    fn set_z_home_offsets(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_home_offsets: impl AsRef<[i32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_init_status(
+        &self,
+    ) -> impl Future<Output = Result</* initialization_status= */ Vec<i16>, Error>> + Send;
+    fn get_tip_status(
+        &self,
+    ) -> impl Future<Output = Result</* tip_present= */ Vec<i16>, Error>> + Send;
+    fn get_lld_status(
+        &self,
+    ) -> impl Future<Output = Result</* lld_status= */ Vec<i16>, Error>> + Send;
+    fn calibrate_dispense_check(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result</* steps= */ Vec<i32>, Error>> + Send;
+    fn calibrate_position_reset(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_squeeze(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_tip_height(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_touchoff(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn erase_limit_curves(&self, channel: u16) -> impl Future<Output = Result<(), Error>> + Send;
+    fn create_limit_curve(
+        &self,
+        channel: u16,
+        name: &str,
+        lower_limit_x: impl AsRef<[u16]> + Send,
+        lower_limit_y: impl AsRef<[i16]> + Send,
+        upper_limit_x: impl AsRef<[u16]> + Send,
+        upper_limit_y: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<CreateLimitCurveReply, Error>> + Send;
+    fn reset_tadm_fifo(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn retrieve_tadm_data(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<RetrieveTadmDataReply, Error>> + Send;
+    fn get_limit_curve_names(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<GetLimitCurveNamesReply, Error>> + Send;
+    fn get_limit_curve_info(
+        &self,
+        channel: u16,
+        name: &str,
+    ) -> impl Future<Output = Result<GetLimitCurveInfoReply, Error>> + Send;
+    fn set_tadm_enable(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        enable: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_tadm_enable(&self) -> impl Future<Output = Result<GetTADMEnableReply, Error>> + Send;
+    fn enable_mad(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        pressure_threshold: impl AsRef<[u16]> + Send,
+        minimum_pressure_difference: impl AsRef<[u16]> + Send,
+        maximum_pressure_difference: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn disable_mad(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn enable_adc(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn disable_adc(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_z_default_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_z_default_speed(
+        &self,
+    ) -> impl Future<Output = Result<GetZDefaultSpeedReply, Error>> + Send;
+    fn z_seek_obstacle(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        obstacle_seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result</* obstacle_detected= */ Vec<i16>, Error>> + Send;
+    fn get_pressure(&self)
+    -> impl Future<Output = Result</* pressures= */ Vec<i16>, Error>> + Send;
+    fn get_potentiometer_settings(
+        &self,
+    ) -> impl Future<Output = Result<GetPotentiometerSettingsReply, Error>> + Send;
+    fn squeeze_on(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pressure_measurement_mode(
+        &self,
+        channel: u16,
+        mode: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pressure_sensor_gain(
+        &self,
+        channel: u16,
+        gain: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pressure_sensor_offset(
+        &self,
+        channel: u16,
+        offset: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pressure_sensor_shift(
+        &self,
+        channel: u16,
+        shift: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn store_pressure_sensor_gain(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn store_pressure_sensor_offset(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_tadm_offset(&self, channel: u16)
+    -> impl Future<Output = Result<(), Error>> + Send;
+    fn prepare_on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        tadm_enabled: impl AsRef<[i16]> + Send,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        dispense_pattern: impl AsRef<[u16]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn prepare_on_the_fly_dispense_with(
+        &self,
+        request: &PrepareOnTheFlyDispenseRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.prepare_on_the_fly_dispense(
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "PrepareOnTheFlyDispenseRequest",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.volume.as_ref(),
+                    "PrepareOnTheFlyDispenseRequest",
+                    "volume",
+                )?,
+                piglet_client::client::required(
+                    request.stop_back_volume.as_ref(),
+                    "PrepareOnTheFlyDispenseRequest",
+                    "stop_back_volume",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume.as_ref(),
+                    "PrepareOnTheFlyDispenseRequest",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_speed.as_ref(),
+                    "PrepareOnTheFlyDispenseRequest",
+                    "dispense_speed",
+                )?,
+                piglet_client::client::required(
+                    request.cutoff_speed.as_ref(),
+                    "PrepareOnTheFlyDispenseRequest",
+                    "cutoff_speed",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled.as_ref(),
+                    "PrepareOnTheFlyDispenseRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index.as_ref(),
+                    "PrepareOnTheFlyDispenseRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_pattern.as_ref(),
+                    "PrepareOnTheFlyDispenseRequest",
+                    "dispense_pattern",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "PrepareOnTheFlyDispenseRequest",
+                    "recording_mode",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn start_on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn stop_on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn read_on_the_fly_dispense_errors(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<ReadOnTheFlyDispenseErrorsReply, Error>> + Send;
+    fn send_synch_signal(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn dispenser_aspirate(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn dispenser_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_current_dispenser_volume(
+        &self,
+    ) -> impl Future<Output = Result</* volume= */ Vec<Microliters>, Error>> + Send;
+    fn get_liquid_height(
+        &self,
+    ) -> impl Future<Output = Result</* liquid_height= */ Vec<Millimeters>, Error>> + Send;
+    fn get_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<GetChannelConfigurationReply, Error>> + Send;
+    fn set_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+        enables: impl AsRef<[bool]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn leak_check(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        tip_type: impl AsRef<[u16]> + Send,
+        time: impl AsRef<[u32]> + Send,
+        test_type: impl AsRef<[bool]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_lld(&self, channel: u16) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_seek_obstacle_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        obstacle_seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<ZSeekObstaclePositionReply, Error>> + Send;
+    fn z_seek_lld_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<ZSeekLldPositionReply, Error>> + Send;
+    fn calibration_squeeze_check_torque(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn calibrate_squeeze_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_z_liquid_seek_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        seek_speeds: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_z_liquid_seek_speed(
+        &self,
+    ) -> impl Future<Output = Result</* seek_speeds= */ Vec<MmPerSecond>, Error>> + Send;
+    fn z_move_absolute_2_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        z_speed_switch_position: impl AsRef<[Millimeters]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+        lower_section_velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn save_counters(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_home_sensors(&self) -> impl Future<Output = Result<GetHomeSensorsReply, Error>> + Send;
+    fn get_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetCalibrationValuesReply, Error>> + Send;
+    fn get_extended_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetExtendedCalibrationValuesReply, Error>> + Send;
+    fn get_counters(&self) -> impl Future<Output = Result<GetCountersReply, Error>> + Send;
+    fn get_technical_info(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result</* channel_info= */ String, Error>> + Send;
+    fn set_counters(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        tip_pickup_counter: impl AsRef<[u32]> + Send,
+        tip_eject_counter: impl AsRef<[u32]> + Send,
+        aspirate_counter: impl AsRef<[u32]> + Send,
+        dispense_counter: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn measure_lld_frequency(
+        &self,
+        channel: u16,
+        seconds: u16,
+    ) -> impl Future<Output = Result</* detects_per_minute= */ u16, Error>> + Send;
+    fn y_move_absolute_stagger(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+        delay: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_absolute_gripper_stagger(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        gripper_tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+        delay: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_positions(&self) -> impl Future<Output = Result<GetPositionsReply, Error>> + Send;
+    fn get_tip_types(
+        &self,
+    ) -> impl Future<Output = Result</* tip_present= */ Vec<i16>, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreChannelCoordApi for NimbusCoreChannelCoord {
+    fn pickup_tips(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        tip_type: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::pickup_tips(
+            self,
+            tips_used,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            tip_type,
+        )
+    }
+
+    fn drop_tips(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::drop_tips(self, tips_used, z_start_position, z_stop_position)
+    }
+
+    fn aspirate(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        aspirate_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::aspirate(
+            self,
+            aspirate_type,
+            tips_used,
+            liquid_seek_height,
+            liquid_surface_height,
+            submerge_depth,
+            follow_depth,
+            z_min_position,
+            clot_check_height,
+            z_final,
+            liquid_exit_speed,
+            blowout_volume,
+            prewet_volume,
+            aspirate_volume,
+            transport_air_volume,
+            aspirate_speed,
+            settling_time,
+            mix_volume,
+            mix_cycles,
+            mix_position,
+            mix_follow_distance,
+            mix_speed,
+            aspirate_offset,
+            tube_section_height,
+            tube_section_ratio,
+            lld_mode,
+            capacitive_lld_sensitivity,
+            pressure_lld_sensitivity,
+            lld_height_difference,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+        )
+    }
+
+    fn dispense(
+        &self,
+        dispense_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        touchoff_distance: Millimeters,
+        dispense_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::dispense(
+            self,
+            dispense_type,
+            tips_used,
+            liquid_seek_height,
+            dispense_height,
+            submerge_depth,
+            follow_depth,
+            z_min_position,
+            z_final,
+            liquid_exit_speed,
+            transport_air_volume,
+            dispense_volume,
+            stop_back_volume,
+            blowout_volume,
+            dispense_speed,
+            cutoff_speed,
+            settling_time,
+            mix_volume,
+            mix_cycles,
+            mix_position,
+            mix_follow_distance,
+            mix_speed,
+            touchoff_distance,
+            dispense_offset,
+            tube_section_height,
+            tube_section_ratio,
+            lld_mode,
+            capacitive_lld_sensitivity,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+        )
+    }
+
+    fn aspirate_and_dispense(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        dispense_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        aspirate_dispense_pattern: impl AsRef<[i16]> + Send,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::aspirate_and_dispense(
+            self,
+            aspirate_type,
+            dispense_type,
+            tips_used,
+            aspirate_dispense_pattern,
+            liquid_seek_height,
+            liquid_surface_height,
+            submerge_depth,
+            follow_depth,
+            z_min_position,
+            clot_check_height,
+            z_final,
+            liquid_exit_speed,
+            blowout_volume,
+            prewet_volume,
+            aspirate_volume,
+            transport_air_volume,
+            dispense_volume,
+            stop_back_volume,
+            aspirate_speed,
+            dispense_speed,
+            cutoff_speed,
+            settling_time,
+            mix_volume,
+            mix_cycles,
+            mix_position,
+            mix_follow_distance,
+            mix_speed,
+            offset,
+            tube_section_height,
+            tube_section_ratio,
+            lld_mode,
+            capacitive_lld_sensitivity,
+            pressure_lld_sensitivity,
+            lld_height_difference,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+        )
+    }
+
+    fn pickup_gripper_tool(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_type: u16,
+        first_channel_number: u16,
+        second_channel_number: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::pickup_gripper_tool(
+            self,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            tip_type,
+            first_channel_number,
+            second_channel_number,
+        )
+    }
+
+    fn drop_gripper_tool(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        first_channel_number: u16,
+        second_channel_number: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::drop_gripper_tool(
+            self,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            first_channel_number,
+            second_channel_number,
+        )
+    }
+
+    fn y_pre_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::y_pre_initialize(self, tips_used)
+    }
+
+    fn y_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::y_initialize(self, tips_used)
+    }
+
+    fn y_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::y_move_relative(self, tips_used, y_distance)
+    }
+
+    fn y_move_absolute(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::y_move_absolute(self, tips_used, y_position)
+    }
+
+    fn y_move_relative_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_distance: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::y_move_relative_speed(
+            self,
+            tips_used,
+            y_distance,
+            acceleration,
+            velocity,
+        )
+    }
+
+    fn y_move_absolute_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::y_move_absolute_speed(
+            self,
+            tips_used,
+            y_position,
+            acceleration,
+            velocity,
+        )
+    }
+
+    fn y_seek_lld(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::y_seek_lld(self, tips_used, y_position, velocity)
+    }
+
+    fn y_move_absolute_gripper(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::y_move_absolute_gripper(
+            self,
+            tips_used,
+            y_position,
+            acceleration,
+            velocity,
+        )
+    }
+
+    fn z_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::z_initialize(self, tips_used)
+    }
+
+    fn z_move_safe(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::z_move_safe(self, tips_used)
+    }
+
+    fn z_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::z_move_relative(self, tips_used, z_distance)
+    }
+
+    fn z_move_absolute(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::z_move_absolute(self, tips_used, z_position)
+    }
+
+    fn z_move_relative_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_distance: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::z_move_relative_speed(
+            self,
+            tips_used,
+            z_distance,
+            acceleration,
+            velocity,
+        )
+    }
+
+    fn z_move_absolute_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::z_move_absolute_speed(
+            self,
+            tips_used,
+            z_position,
+            acceleration,
+            velocity,
+        )
+    }
+
+    fn z_move_traverse(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::z_move_traverse(self, tips_used, z_position)
+    }
+
+    fn z_seek_lld(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::z_seek_lld(self, tips_used, z_position, z_min_position, velocity)
+    }
+
+    fn z_move_absolute_gripper(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::z_move_absolute_gripper(
+            self,
+            tips_used,
+            z_position,
+            acceleration,
+            velocity,
+        )
+    }
+
+    fn dispenser_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::dispenser_initialize(self, tips_used)
+    }
+
+    fn dispenser_empty(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::dispenser_empty(self, tips_used)
+    }
+
+    fn dispenser_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::dispenser_move_relative(self, tips_used, volume)
+    }
+
+    fn squeeze_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::squeeze_initialize(self, tips_used)
+    }
+
+    fn squeeze_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::squeeze_off(self, tips_used)
+    }
+
+    fn lld_on(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::lld_on(self, tips_used)
+    }
+
+    fn lld_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::lld_off(self, tips_used)
+    }
+
+    fn get_y_positions(
+        &self,
+    ) -> impl Future<Output = Result</* y_positions= */ Vec<Millimeters>, Error>> + Send {
+        NimbusCoreChannelCoord::get_y_positions(self)
+    }
+
+    fn get_y_home_offsets(
+        &self,
+    ) -> impl Future<Output = Result</* y_home_offsets= */ Vec<i32>, Error>> + Send {
+        NimbusCoreChannelCoord::get_y_home_offsets(self)
+    }
+
+    fn set_y_home_offsets(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_home_offsets: impl AsRef<[i32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::set_y_home_offsets(self, tips_used, y_home_offsets)
+    }
+
+    fn get_z_positions(
+        &self,
+    ) -> impl Future<Output = Result</* z_positions= */ Vec<Millimeters>, Error>> + Send {
+        NimbusCoreChannelCoord::get_z_positions(self)
+    }
+
+    fn get_z_home_offsets(
+        &self,
+    ) -> impl Future<Output = Result</* z_home_offsets= */ Vec<i32>, Error>> + Send {
+        NimbusCoreChannelCoord::get_z_home_offsets(self)
+    }
+
+    fn set_z_home_offsets(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_home_offsets: impl AsRef<[i32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::set_z_home_offsets(self, tips_used, z_home_offsets)
+    }
+
+    fn get_init_status(
+        &self,
+    ) -> impl Future<Output = Result</* initialization_status= */ Vec<i16>, Error>> + Send {
+        NimbusCoreChannelCoord::get_init_status(self)
+    }
+
+    fn get_tip_status(
+        &self,
+    ) -> impl Future<Output = Result</* tip_present= */ Vec<i16>, Error>> + Send {
+        NimbusCoreChannelCoord::get_tip_status(self)
+    }
+
+    fn get_lld_status(
+        &self,
+    ) -> impl Future<Output = Result</* lld_status= */ Vec<i16>, Error>> + Send {
+        NimbusCoreChannelCoord::get_lld_status(self)
+    }
+
+    fn calibrate_dispense_check(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result</* steps= */ Vec<i32>, Error>> + Send {
+        NimbusCoreChannelCoord::calibrate_dispense_check(
+            self,
+            tips_used,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            volume,
+            collet_check,
+        )
+    }
+
+    fn calibrate_position_reset(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::calibrate_position_reset(self, tips_used)
+    }
+
+    fn calibrate_squeeze(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::calibrate_squeeze(
+            self,
+            tips_used,
+            z_start_position,
+            z_stop_position,
+        )
+    }
+
+    fn calibrate_tip_height(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::calibrate_tip_height(
+            self,
+            tips_used,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            tip_volume,
+            collet_check,
+        )
+    }
+
+    fn calibrate_touchoff(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::calibrate_touchoff(self, tips_used)
+    }
+
+    fn erase_limit_curves(&self, channel: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::erase_limit_curves(self, channel)
+    }
+
+    fn create_limit_curve(
+        &self,
+        channel: u16,
+        name: &str,
+        lower_limit_x: impl AsRef<[u16]> + Send,
+        lower_limit_y: impl AsRef<[i16]> + Send,
+        upper_limit_x: impl AsRef<[u16]> + Send,
+        upper_limit_y: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<CreateLimitCurveReply, Error>> + Send {
+        NimbusCoreChannelCoord::create_limit_curve(
+            self,
+            channel,
+            name,
+            lower_limit_x,
+            lower_limit_y,
+            upper_limit_x,
+            upper_limit_y,
+        )
+    }
+
+    fn reset_tadm_fifo(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::reset_tadm_fifo(self, tips_used)
+    }
+
+    fn retrieve_tadm_data(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<RetrieveTadmDataReply, Error>> + Send {
+        NimbusCoreChannelCoord::retrieve_tadm_data(self, tips_used)
+    }
+
+    fn get_limit_curve_names(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<GetLimitCurveNamesReply, Error>> + Send {
+        NimbusCoreChannelCoord::get_limit_curve_names(self, channel)
+    }
+
+    fn get_limit_curve_info(
+        &self,
+        channel: u16,
+        name: &str,
+    ) -> impl Future<Output = Result<GetLimitCurveInfoReply, Error>> + Send {
+        NimbusCoreChannelCoord::get_limit_curve_info(self, channel, name)
+    }
+
+    fn set_tadm_enable(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        enable: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::set_tadm_enable(self, tips_used, enable)
+    }
+
+    fn get_tadm_enable(&self) -> impl Future<Output = Result<GetTADMEnableReply, Error>> + Send {
+        NimbusCoreChannelCoord::get_tadm_enable(self)
+    }
+
+    fn enable_mad(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        pressure_threshold: impl AsRef<[u16]> + Send,
+        minimum_pressure_difference: impl AsRef<[u16]> + Send,
+        maximum_pressure_difference: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::enable_mad(
+            self,
+            tips_used,
+            pressure_threshold,
+            minimum_pressure_difference,
+            maximum_pressure_difference,
+        )
+    }
+
+    fn disable_mad(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::disable_mad(self, tips_used)
+    }
+
+    fn enable_adc(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::enable_adc(self, tips_used)
+    }
+
+    fn disable_adc(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::disable_adc(self, tips_used)
+    }
+
+    fn set_z_default_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::set_z_default_speed(self, tips_used, speed)
+    }
+
+    fn get_z_default_speed(
+        &self,
+    ) -> impl Future<Output = Result<GetZDefaultSpeedReply, Error>> + Send {
+        NimbusCoreChannelCoord::get_z_default_speed(self)
+    }
+
+    fn z_seek_obstacle(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        obstacle_seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result</* obstacle_detected= */ Vec<i16>, Error>> + Send {
+        NimbusCoreChannelCoord::z_seek_obstacle(
+            self,
+            tips_used,
+            obstacle_seek_height,
+            z_min_position,
+            z_final,
+            seek_speed,
+        )
+    }
+
+    fn get_pressure(
+        &self,
+    ) -> impl Future<Output = Result</* pressures= */ Vec<i16>, Error>> + Send {
+        NimbusCoreChannelCoord::get_pressure(self)
+    }
+
+    fn get_potentiometer_settings(
+        &self,
+    ) -> impl Future<Output = Result<GetPotentiometerSettingsReply, Error>> + Send {
+        NimbusCoreChannelCoord::get_potentiometer_settings(self)
+    }
+
+    fn squeeze_on(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::squeeze_on(self, tips_used)
+    }
+
+    fn pressure_measurement_mode(
+        &self,
+        channel: u16,
+        mode: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::pressure_measurement_mode(self, channel, mode)
+    }
+
+    fn pressure_sensor_gain(
+        &self,
+        channel: u16,
+        gain: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::pressure_sensor_gain(self, channel, gain)
+    }
+
+    fn pressure_sensor_offset(
+        &self,
+        channel: u16,
+        offset: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::pressure_sensor_offset(self, channel, offset)
+    }
+
+    fn pressure_sensor_shift(
+        &self,
+        channel: u16,
+        shift: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::pressure_sensor_shift(self, channel, shift)
+    }
+
+    fn store_pressure_sensor_gain(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::store_pressure_sensor_gain(self, channel)
+    }
+
+    fn store_pressure_sensor_offset(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::store_pressure_sensor_offset(self, channel)
+    }
+
+    fn calibrate_tadm_offset(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::calibrate_tadm_offset(self, channel)
+    }
+
+    fn prepare_on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        tadm_enabled: impl AsRef<[i16]> + Send,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        dispense_pattern: impl AsRef<[u16]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::prepare_on_the_fly_dispense(
+            self,
+            tips_used,
+            volume,
+            stop_back_volume,
+            transport_air_volume,
+            dispense_speed,
+            cutoff_speed,
+            tadm_enabled,
+            limit_curve_index,
+            dispense_pattern,
+            recording_mode,
+        )
+    }
+
+    fn start_on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::start_on_the_fly_dispense(self, tips_used)
+    }
+
+    fn stop_on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::stop_on_the_fly_dispense(self, tips_used)
+    }
+
+    fn read_on_the_fly_dispense_errors(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<ReadOnTheFlyDispenseErrorsReply, Error>> + Send {
+        NimbusCoreChannelCoord::read_on_the_fly_dispense_errors(self, tips_used)
+    }
+
+    fn send_synch_signal(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::send_synch_signal(self)
+    }
+
+    fn dispenser_aspirate(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::dispenser_aspirate(self, tips_used, aspirate_volume, aspirate_speed)
+    }
+
+    fn dispenser_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::dispenser_dispense(
+            self,
+            tips_used,
+            dispense_volume,
+            stop_back_volume,
+            dispense_speed,
+            cutoff_speed,
+        )
+    }
+
+    fn get_current_dispenser_volume(
+        &self,
+    ) -> impl Future<Output = Result</* volume= */ Vec<Microliters>, Error>> + Send {
+        NimbusCoreChannelCoord::get_current_dispenser_volume(self)
+    }
+
+    fn get_liquid_height(
+        &self,
+    ) -> impl Future<Output = Result</* liquid_height= */ Vec<Millimeters>, Error>> + Send {
+        NimbusCoreChannelCoord::get_liquid_height(self)
+    }
+
+    fn get_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<GetChannelConfigurationReply, Error>> + Send {
+        NimbusCoreChannelCoord::get_channel_configuration(self, channel, indexes)
+    }
+
+    fn set_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+        enables: impl AsRef<[bool]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::set_channel_configuration(self, channel, indexes, enables)
+    }
+
+    fn leak_check(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        tip_type: impl AsRef<[u16]> + Send,
+        time: impl AsRef<[u32]> + Send,
+        test_type: impl AsRef<[bool]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::leak_check(
+            self,
+            tips_used,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            tip_type,
+            time,
+            test_type,
+        )
+    }
+
+    fn calibrate_lld(&self, channel: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::calibrate_lld(self, channel)
+    }
+
+    fn z_seek_obstacle_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        obstacle_seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<ZSeekObstaclePositionReply, Error>> + Send {
+        NimbusCoreChannelCoord::z_seek_obstacle_position(
+            self,
+            tips_used,
+            obstacle_seek_height,
+            z_min_position,
+            z_final,
+            seek_speed,
+        )
+    }
+
+    fn z_seek_lld_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<ZSeekLldPositionReply, Error>> + Send {
+        NimbusCoreChannelCoord::z_seek_lld_position(
+            self,
+            tips_used,
+            seek_height,
+            z_min_position,
+            z_final,
+            seek_speed,
+            capacitive_lld_sensitivity,
+        )
+    }
+
+    fn calibration_squeeze_check_torque(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::calibration_squeeze_check_torque(self, tips_used)
+    }
+
+    fn calibrate_squeeze_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::calibrate_squeeze_position(self, tips_used)
+    }
+
+    fn set_z_liquid_seek_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        seek_speeds: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::set_z_liquid_seek_speed(self, tips_used, seek_speeds)
+    }
+
+    fn get_z_liquid_seek_speed(
+        &self,
+    ) -> impl Future<Output = Result</* seek_speeds= */ Vec<MmPerSecond>, Error>> + Send {
+        NimbusCoreChannelCoord::get_z_liquid_seek_speed(self)
+    }
+
+    fn z_move_absolute_2_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        z_speed_switch_position: impl AsRef<[Millimeters]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+        lower_section_velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::z_move_absolute_2_speed(
+            self,
+            tips_used,
+            z_position,
+            z_speed_switch_position,
+            velocity,
+            lower_section_velocity,
+        )
+    }
+
+    fn z_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::z_servo_off(self, tips_used)
+    }
+
+    fn y_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::y_servo_off(self, tips_used)
+    }
+
+    fn save_counters(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::save_counters(self, tips_used)
+    }
+
+    fn get_home_sensors(&self) -> impl Future<Output = Result<GetHomeSensorsReply, Error>> + Send {
+        NimbusCoreChannelCoord::get_home_sensors(self)
+    }
+
+    fn get_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetCalibrationValuesReply, Error>> + Send {
+        NimbusCoreChannelCoord::get_calibration_values(self)
+    }
+
+    fn get_extended_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetExtendedCalibrationValuesReply, Error>> + Send {
+        NimbusCoreChannelCoord::get_extended_calibration_values(self)
+    }
+
+    fn get_counters(&self) -> impl Future<Output = Result<GetCountersReply, Error>> + Send {
+        NimbusCoreChannelCoord::get_counters(self)
+    }
+
+    fn get_technical_info(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result</* channel_info= */ String, Error>> + Send {
+        NimbusCoreChannelCoord::get_technical_info(self, channel)
+    }
+
+    fn set_counters(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        tip_pickup_counter: impl AsRef<[u32]> + Send,
+        tip_eject_counter: impl AsRef<[u32]> + Send,
+        aspirate_counter: impl AsRef<[u32]> + Send,
+        dispense_counter: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::set_counters(
+            self,
+            tips_used,
+            tip_pickup_counter,
+            tip_eject_counter,
+            aspirate_counter,
+            dispense_counter,
+        )
+    }
+
+    fn measure_lld_frequency(
+        &self,
+        channel: u16,
+        seconds: u16,
+    ) -> impl Future<Output = Result</* detects_per_minute= */ u16, Error>> + Send {
+        NimbusCoreChannelCoord::measure_lld_frequency(self, channel, seconds)
+    }
+
+    fn y_move_absolute_stagger(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+        delay: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::y_move_absolute_stagger(
+            self,
+            tips_used,
+            y_position,
+            acceleration,
+            velocity,
+            delay,
+        )
+    }
+
+    fn y_move_absolute_gripper_stagger(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        gripper_tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+        delay: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreChannelCoord::y_move_absolute_gripper_stagger(
+            self,
+            tips_used,
+            gripper_tips_used,
+            y_position,
+            acceleration,
+            velocity,
+            delay,
+        )
+    }
+
+    fn get_positions(&self) -> impl Future<Output = Result<GetPositionsReply, Error>> + Send {
+        NimbusCoreChannelCoord::get_positions(self)
+    }
+
+    fn get_tip_types(
+        &self,
+    ) -> impl Future<Output = Result</* tip_present= */ Vec<i16>, Error>> + Send {
+        NimbusCoreChannelCoord::get_tip_types(self)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreChannelCoord::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreChannelCoord::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreChannelCoord::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreChannelCoord::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreChannelCoord::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreChannelCoord::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreChannelCoordFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreChannelCoordApi for NimbusCoreChannelCoordFake {
+    fn pickup_tips(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        tip_type: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pickup_tips",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("z_final", format!("{:?}", z_final)),
+                ("tip_type", format!("{:?}", tip_type.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn drop_tips(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "drop_tips",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn aspirate(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        aspirate_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "aspirate",
+            vec![
+                ("aspirate_type", format!("{:?}", aspirate_type.as_ref())),
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "liquid_seek_height",
+                    format!("{:?}", liquid_seek_height.as_ref()),
+                ),
+                (
+                    "liquid_surface_height",
+                    format!("{:?}", liquid_surface_height.as_ref()),
+                ),
+                ("submerge_depth", format!("{:?}", submerge_depth.as_ref())),
+                ("follow_depth", format!("{:?}", follow_depth.as_ref())),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                (
+                    "clot_check_height",
+                    format!("{:?}", clot_check_height.as_ref()),
+                ),
+                ("z_final", format!("{:?}", z_final)),
+                (
+                    "liquid_exit_speed",
+                    format!("{:?}", liquid_exit_speed.as_ref()),
+                ),
+                ("blowout_volume", format!("{:?}", blowout_volume.as_ref())),
+                ("prewet_volume", format!("{:?}", prewet_volume.as_ref())),
+                ("aspirate_volume", format!("{:?}", aspirate_volume.as_ref())),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume.as_ref()),
+                ),
+                ("aspirate_speed", format!("{:?}", aspirate_speed.as_ref())),
+                ("settling_time", format!("{:?}", settling_time.as_ref())),
+                ("mix_volume", format!("{:?}", mix_volume.as_ref())),
+                ("mix_cycles", format!("{:?}", mix_cycles.as_ref())),
+                ("mix_position", format!("{:?}", mix_position.as_ref())),
+                (
+                    "mix_follow_distance",
+                    format!("{:?}", mix_follow_distance.as_ref()),
+                ),
+                ("mix_speed", format!("{:?}", mix_speed.as_ref())),
+                ("aspirate_offset", format!("{:?}", aspirate_offset.as_ref())),
+                (
+                    "tube_section_height",
+                    format!("{:?}", tube_section_height.as_ref()),
+                ),
+                (
+                    "tube_section_ratio",
+                    format!("{:?}", tube_section_ratio.as_ref()),
+                ),
+                ("lld_mode", format!("{:?}", lld_mode.as_ref())),
+                (
+                    "capacitive_lld_sensitivity",
+                    format!("{:?}", capacitive_lld_sensitivity.as_ref()),
+                ),
+                (
+                    "pressure_lld_sensitivity",
+                    format!("{:?}", pressure_lld_sensitivity.as_ref()),
+                ),
+                (
+                    "lld_height_difference",
+                    format!("{:?}", lld_height_difference.as_ref()),
+                ),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                (
+                    "limit_curve_index",
+                    format!("{:?}", limit_curve_index.as_ref()),
+                ),
+                ("recording_mode", format!("{:?}", recording_mode)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn dispense(
+        &self,
+        dispense_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        touchoff_distance: Millimeters,
+        dispense_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "dispense",
+            vec![
+                ("dispense_type", format!("{:?}", dispense_type.as_ref())),
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "liquid_seek_height",
+                    format!("{:?}", liquid_seek_height.as_ref()),
+                ),
+                ("dispense_height", format!("{:?}", dispense_height.as_ref())),
+                ("submerge_depth", format!("{:?}", submerge_depth.as_ref())),
+                ("follow_depth", format!("{:?}", follow_depth.as_ref())),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                ("z_final", format!("{:?}", z_final)),
+                (
+                    "liquid_exit_speed",
+                    format!("{:?}", liquid_exit_speed.as_ref()),
+                ),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume.as_ref()),
+                ),
+                ("dispense_volume", format!("{:?}", dispense_volume.as_ref())),
+                (
+                    "stop_back_volume",
+                    format!("{:?}", stop_back_volume.as_ref()),
+                ),
+                ("blowout_volume", format!("{:?}", blowout_volume.as_ref())),
+                ("dispense_speed", format!("{:?}", dispense_speed.as_ref())),
+                ("cutoff_speed", format!("{:?}", cutoff_speed.as_ref())),
+                ("settling_time", format!("{:?}", settling_time.as_ref())),
+                ("mix_volume", format!("{:?}", mix_volume.as_ref())),
+                ("mix_cycles", format!("{:?}", mix_cycles.as_ref())),
+                ("mix_position", format!("{:?}", mix_position.as_ref())),
+                (
+                    "mix_follow_distance",
+                    format!("{:?}", mix_follow_distance.as_ref()),
+                ),
+                ("mix_speed", format!("{:?}", mix_speed.as_ref())),
+                ("touchoff_distance", format!("{:?}", touchoff_distance)),
+                ("dispense_offset", format!("{:?}", dispense_offset.as_ref())),
+                (
+                    "tube_section_height",
+                    format!("{:?}", tube_section_height.as_ref()),
+                ),
+                (
+                    "tube_section_ratio",
+                    format!("{:?}", tube_section_ratio.as_ref()),
+                ),
+                ("lld_mode", format!("{:?}", lld_mode.as_ref())),
+                (
+                    "capacitive_lld_sensitivity",
+                    format!("{:?}", capacitive_lld_sensitivity.as_ref()),
+                ),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                (
+                    "limit_curve_index",
+                    format!("{:?}", limit_curve_index.as_ref()),
+                ),
+                ("recording_mode", format!("{:?}", recording_mode)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn aspirate_and_dispense(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        dispense_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        aspirate_dispense_pattern: impl AsRef<[i16]> + Send,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "aspirate_and_dispense",
+            vec![
+                ("aspirate_type", format!("{:?}", aspirate_type.as_ref())),
+                ("dispense_type", format!("{:?}", dispense_type.as_ref())),
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "aspirate_dispense_pattern",
+                    format!("{:?}", aspirate_dispense_pattern.as_ref()),
+                ),
+                (
+                    "liquid_seek_height",
+                    format!("{:?}", liquid_seek_height.as_ref()),
+                ),
+                (
+                    "liquid_surface_height",
+                    format!("{:?}", liquid_surface_height.as_ref()),
+                ),
+                ("submerge_depth", format!("{:?}", submerge_depth.as_ref())),
+                ("follow_depth", format!("{:?}", follow_depth.as_ref())),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                (
+                    "clot_check_height",
+                    format!("{:?}", clot_check_height.as_ref()),
+                ),
+                ("z_final", format!("{:?}", z_final)),
+                (
+                    "liquid_exit_speed",
+                    format!("{:?}", liquid_exit_speed.as_ref()),
+                ),
+                ("blowout_volume", format!("{:?}", blowout_volume.as_ref())),
+                ("prewet_volume", format!("{:?}", prewet_volume.as_ref())),
+                ("aspirate_volume", format!("{:?}", aspirate_volume.as_ref())),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume.as_ref()),
+                ),
+                ("dispense_volume", format!("{:?}", dispense_volume.as_ref())),
+                (
+                    "stop_back_volume",
+                    format!("{:?}", stop_back_volume.as_ref()),
+                ),
+                ("aspirate_speed", format!("{:?}", aspirate_speed.as_ref())),
+                ("dispense_speed", format!("{:?}", dispense_speed.as_ref())),
+                ("cutoff_speed", format!("{:?}", cutoff_speed.as_ref())),
+                ("settling_time", format!("{:?}", settling_time.as_ref())),
+                ("mix_volume", format!("{:?}", mix_volume.as_ref())),
+                ("mix_cycles", format!("{:?}", mix_cycles.as_ref())),
+                ("mix_position", format!("{:?}", mix_position.as_ref())),
+                (
+                    "mix_follow_distance",
+                    format!("{:?}", mix_follow_distance.as_ref()),
+                ),
+                ("mix_speed", format!("{:?}", mix_speed.as_ref())),
+                ("offset", format!("{:?}", offset.as_ref())),
+                (
+                    "tube_section_height",
+                    format!("{:?}", tube_section_height.as_ref()),
+                ),
+                (
+                    "tube_section_ratio",
+                    format!("{:?}", tube_section_ratio.as_ref()),
+                ),
+                ("lld_mode", format!("{:?}", lld_mode.as_ref())),
+                (
+                    "capacitive_lld_sensitivity",
+                    format!("{:?}", capacitive_lld_sensitivity.as_ref()),
+                ),
+                (
+                    "pressure_lld_sensitivity",
+                    format!("{:?}", pressure_lld_sensitivity.as_ref()),
+                ),
+                (
+                    "lld_height_difference",
+                    format!("{:?}", lld_height_difference.as_ref()),
+                ),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                (
+                    "limit_curve_index",
+                    format!("{:?}", limit_curve_index.as_ref()),
+                ),
+                ("recording_mode", format!("{:?}", recording_mode)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn pickup_gripper_tool(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_type: u16,
+        first_channel_number: u16,
+        second_channel_number: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pickup_gripper_tool",
+            vec![
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("z_final", format!("{:?}", z_final)),
+                ("tip_type", format!("{:?}", tip_type)),
+                (
+                    "first_channel_number",
+                    format!("{:?}", first_channel_number),
+                ),
+                (
+                    "second_channel_number",
+                    format!("{:?}", second_channel_number),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn drop_gripper_tool(
+        &self,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        first_channel_number: u16,
+        second_channel_number: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "drop_gripper_tool",
+            vec![
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("z_final", format!("{:?}", z_final)),
+                (
+                    "first_channel_number",
+                    format!("{:?}", first_channel_number),
+                ),
+                (
+                    "second_channel_number",
+                    format!("{:?}", second_channel_number),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn y_pre_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_pre_initialize",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn y_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_initialize",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn y_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_relative",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("y_distance", format!("{:?}", y_distance.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn y_move_absolute(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_absolute",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn y_move_relative_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_distance: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_relative_speed",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("y_distance", format!("{:?}", y_distance.as_ref())),
+                ("acceleration", format!("{:?}", acceleration.as_ref())),
+                ("velocity", format!("{:?}", velocity.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn y_move_absolute_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_absolute_speed",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("acceleration", format!("{:?}", acceleration.as_ref())),
+                ("velocity", format!("{:?}", velocity.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn y_seek_lld(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_seek_lld",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("velocity", format!("{:?}", velocity.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn y_move_absolute_gripper(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_absolute_gripper",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("acceleration", format!("{:?}", acceleration.as_ref())),
+                ("velocity", format!("{:?}", velocity.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_initialize",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn z_move_safe(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_safe",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn z_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_relative",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_distance", format!("{:?}", z_distance.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_move_absolute(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_absolute",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_position", format!("{:?}", z_position.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_move_relative_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_distance: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_relative_speed",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_distance", format!("{:?}", z_distance.as_ref())),
+                ("acceleration", format!("{:?}", acceleration.as_ref())),
+                ("velocity", format!("{:?}", velocity.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_move_absolute_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_absolute_speed",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_position", format!("{:?}", z_position.as_ref())),
+                ("acceleration", format!("{:?}", acceleration.as_ref())),
+                ("velocity", format!("{:?}", velocity.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_move_traverse(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_traverse",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_position", format!("{:?}", z_position.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_seek_lld(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_seek_lld",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_position", format!("{:?}", z_position.as_ref())),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                ("velocity", format!("{:?}", velocity.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_move_absolute_gripper(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_absolute_gripper",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_position", format!("{:?}", z_position.as_ref())),
+                ("acceleration", format!("{:?}", acceleration.as_ref())),
+                ("velocity", format!("{:?}", velocity.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn dispenser_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "dispenser_initialize",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn dispenser_empty(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "dispenser_empty",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn dispenser_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "dispenser_move_relative",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("volume", format!("{:?}", volume.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn squeeze_initialize(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "squeeze_initialize",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn squeeze_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "squeeze_off",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn lld_on(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "lld_on",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn lld_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "lld_off",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn get_y_positions(
+        &self,
+    ) -> impl Future<Output = Result</* y_positions= */ Vec<Millimeters>, Error>> + Send {
+        let reply = self.recorder.record("get_y_positions", vec![]);
+        async move { reply }
+    }
+
+    fn get_y_home_offsets(
+        &self,
+    ) -> impl Future<Output = Result</* y_home_offsets= */ Vec<i32>, Error>> + Send {
+        let reply = self.recorder.record("get_y_home_offsets", vec![]);
+        async move { reply }
+    }
+
+    fn set_y_home_offsets(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_home_offsets: impl AsRef<[i32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_y_home_offsets",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("y_home_offsets", format!("{:?}", y_home_offsets.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_z_positions(
+        &self,
+    ) -> impl Future<Output = Result</* z_positions= */ Vec<Millimeters>, Error>> + Send {
+        let reply = self.recorder.record("get_z_positions", vec![]);
+        async move { reply }
+    }
+
+    fn get_z_home_offsets(
+        &self,
+    ) -> impl Future<Output = Result</* z_home_offsets= */ Vec<i32>, Error>> + Send {
+        let reply = self.recorder.record("get_z_home_offsets", vec![]);
+        async move { reply }
+    }
+
+    fn set_z_home_offsets(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_home_offsets: impl AsRef<[i32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_z_home_offsets",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_home_offsets", format!("{:?}", z_home_offsets.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_init_status(
+        &self,
+    ) -> impl Future<Output = Result</* initialization_status= */ Vec<i16>, Error>> + Send {
+        let reply = self.recorder.record("get_init_status", vec![]);
+        async move { reply }
+    }
+
+    fn get_tip_status(
+        &self,
+    ) -> impl Future<Output = Result</* tip_present= */ Vec<i16>, Error>> + Send {
+        let reply = self.recorder.record("get_tip_status", vec![]);
+        async move { reply }
+    }
+
+    fn get_lld_status(
+        &self,
+    ) -> impl Future<Output = Result</* lld_status= */ Vec<i16>, Error>> + Send {
+        let reply = self.recorder.record("get_lld_status", vec![]);
+        async move { reply }
+    }
+
+    fn calibrate_dispense_check(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result</* steps= */ Vec<i32>, Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_dispense_check",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("z_final", format!("{:?}", z_final)),
+                ("volume", format!("{:?}", volume)),
+                ("collet_check", format!("{:?}", collet_check)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_position_reset(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_position_reset",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_squeeze(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_squeeze",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_tip_height(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        tip_volume: Microliters,
+        collet_check: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_tip_height",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("z_final", format!("{:?}", z_final)),
+                ("tip_volume", format!("{:?}", tip_volume)),
+                ("collet_check", format!("{:?}", collet_check)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_touchoff(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_touchoff",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn erase_limit_curves(&self, channel: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "erase_limit_curves",
+            vec![("channel", format!("{:?}", channel))],
+        );
+        async move { reply }
+    }
+
+    fn create_limit_curve(
+        &self,
+        channel: u16,
+        name: &str,
+        lower_limit_x: impl AsRef<[u16]> + Send,
+        lower_limit_y: impl AsRef<[i16]> + Send,
+        upper_limit_x: impl AsRef<[u16]> + Send,
+        upper_limit_y: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<CreateLimitCurveReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "create_limit_curve",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("name", format!("{:?}", name)),
+                ("lower_limit_x", format!("{:?}", lower_limit_x.as_ref())),
+                ("lower_limit_y", format!("{:?}", lower_limit_y.as_ref())),
+                ("upper_limit_x", format!("{:?}", upper_limit_x.as_ref())),
+                ("upper_limit_y", format!("{:?}", upper_limit_y.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn reset_tadm_fifo(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "reset_tadm_fifo",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn retrieve_tadm_data(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<RetrieveTadmDataReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "retrieve_tadm_data",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn get_limit_curve_names(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<GetLimitCurveNamesReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "get_limit_curve_names",
+            vec![("channel", format!("{:?}", channel))],
+        );
+        async move { reply }
+    }
+
+    fn get_limit_curve_info(
+        &self,
+        channel: u16,
+        name: &str,
+    ) -> impl Future<Output = Result<GetLimitCurveInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "get_limit_curve_info",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("name", format!("{:?}", name)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_tadm_enable(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        enable: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_tadm_enable",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("enable", format!("{:?}", enable.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_tadm_enable(&self) -> impl Future<Output = Result<GetTADMEnableReply, Error>> + Send {
+        let reply = self.recorder.record("get_tadm_enable", vec![]);
+        async move { reply }
+    }
+
+    fn enable_mad(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        pressure_threshold: impl AsRef<[u16]> + Send,
+        minimum_pressure_difference: impl AsRef<[u16]> + Send,
+        maximum_pressure_difference: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "enable_mad",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "pressure_threshold",
+                    format!("{:?}", pressure_threshold.as_ref()),
+                ),
+                (
+                    "minimum_pressure_difference",
+                    format!("{:?}", minimum_pressure_difference.as_ref()),
+                ),
+                (
+                    "maximum_pressure_difference",
+                    format!("{:?}", maximum_pressure_difference.as_ref()),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn disable_mad(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "disable_mad",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn enable_adc(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "enable_adc",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn disable_adc(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "disable_adc",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn set_z_default_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_z_default_speed",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("speed", format!("{:?}", speed.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_z_default_speed(
+        &self,
+    ) -> impl Future<Output = Result<GetZDefaultSpeedReply, Error>> + Send {
+        let reply = self.recorder.record("get_z_default_speed", vec![]);
+        async move { reply }
+    }
+
+    fn z_seek_obstacle(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        obstacle_seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result</* obstacle_detected= */ Vec<i16>, Error>> + Send {
+        let reply = self.recorder.record(
+            "z_seek_obstacle",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "obstacle_seek_height",
+                    format!("{:?}", obstacle_seek_height.as_ref()),
+                ),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                ("z_final", format!("{:?}", z_final)),
+                ("seek_speed", format!("{:?}", seek_speed.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_pressure(
+        &self,
+    ) -> impl Future<Output = Result</* pressures= */ Vec<i16>, Error>> + Send {
+        let reply = self.recorder.record("get_pressure", vec![]);
+        async move { reply }
+    }
+
+    fn get_potentiometer_settings(
+        &self,
+    ) -> impl Future<Output = Result<GetPotentiometerSettingsReply, Error>> + Send {
+        let reply = self.recorder.record("get_potentiometer_settings", vec![]);
+        async move { reply }
+    }
+
+    fn squeeze_on(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "squeeze_on",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn pressure_measurement_mode(
+        &self,
+        channel: u16,
+        mode: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pressure_measurement_mode",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("mode", format!("{:?}", mode)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn pressure_sensor_gain(
+        &self,
+        channel: u16,
+        gain: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pressure_sensor_gain",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("gain", format!("{:?}", gain)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn pressure_sensor_offset(
+        &self,
+        channel: u16,
+        offset: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pressure_sensor_offset",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("offset", format!("{:?}", offset)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn pressure_sensor_shift(
+        &self,
+        channel: u16,
+        shift: i16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pressure_sensor_shift",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("shift", format!("{:?}", shift)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn store_pressure_sensor_gain(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "store_pressure_sensor_gain",
+            vec![("channel", format!("{:?}", channel))],
+        );
+        async move { reply }
+    }
+
+    fn store_pressure_sensor_offset(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "store_pressure_sensor_offset",
+            vec![("channel", format!("{:?}", channel))],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_tadm_offset(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_tadm_offset",
+            vec![("channel", format!("{:?}", channel))],
+        );
+        async move { reply }
+    }
+
+    fn prepare_on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        tadm_enabled: impl AsRef<[i16]> + Send,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        dispense_pattern: impl AsRef<[u16]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "prepare_on_the_fly_dispense",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("volume", format!("{:?}", volume.as_ref())),
+                (
+                    "stop_back_volume",
+                    format!("{:?}", stop_back_volume.as_ref()),
+                ),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume.as_ref()),
+                ),
+                ("dispense_speed", format!("{:?}", dispense_speed.as_ref())),
+                ("cutoff_speed", format!("{:?}", cutoff_speed.as_ref())),
+                ("tadm_enabled", format!("{:?}", tadm_enabled.as_ref())),
+                (
+                    "limit_curve_index",
+                    format!("{:?}", limit_curve_index.as_ref()),
+                ),
+                (
+                    "dispense_pattern",
+                    format!("{:?}", dispense_pattern.as_ref()),
+                ),
+                ("recording_mode", format!("{:?}", recording_mode)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn start_on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "start_on_the_fly_dispense",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn stop_on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "stop_on_the_fly_dispense",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn read_on_the_fly_dispense_errors(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<ReadOnTheFlyDispenseErrorsReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "read_on_the_fly_dispense_errors",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn send_synch_signal(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("send_synch_signal", vec![]);
+        async move { reply }
+    }
+
+    fn dispenser_aspirate(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "dispenser_aspirate",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("aspirate_volume", format!("{:?}", aspirate_volume.as_ref())),
+                ("aspirate_speed", format!("{:?}", aspirate_speed.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn dispenser_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "dispenser_dispense",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("dispense_volume", format!("{:?}", dispense_volume.as_ref())),
+                (
+                    "stop_back_volume",
+                    format!("{:?}", stop_back_volume.as_ref()),
+                ),
+                ("dispense_speed", format!("{:?}", dispense_speed.as_ref())),
+                ("cutoff_speed", format!("{:?}", cutoff_speed.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_current_dispenser_volume(
+        &self,
+    ) -> impl Future<Output = Result</* volume= */ Vec<Microliters>, Error>> + Send {
+        let reply = self.recorder.record("get_current_dispenser_volume", vec![]);
+        async move { reply }
+    }
+
+    fn get_liquid_height(
+        &self,
+    ) -> impl Future<Output = Result</* liquid_height= */ Vec<Millimeters>, Error>> + Send {
+        let reply = self.recorder.record("get_liquid_height", vec![]);
+        async move { reply }
+    }
+
+    fn get_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<GetChannelConfigurationReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "get_channel_configuration",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("indexes", format!("{:?}", indexes.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+        enables: impl AsRef<[bool]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_channel_configuration",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("indexes", format!("{:?}", indexes.as_ref())),
+                ("enables", format!("{:?}", enables.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn leak_check(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        tip_type: impl AsRef<[u16]> + Send,
+        time: impl AsRef<[u32]> + Send,
+        test_type: impl AsRef<[bool]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "leak_check",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("z_final", format!("{:?}", z_final.as_ref())),
+                ("tip_type", format!("{:?}", tip_type.as_ref())),
+                ("time", format!("{:?}", time.as_ref())),
+                ("test_type", format!("{:?}", test_type.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_lld(&self, channel: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("calibrate_lld", vec![("channel", format!("{:?}", channel))]);
+        async move { reply }
+    }
+
+    fn z_seek_obstacle_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        obstacle_seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<ZSeekObstaclePositionReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "z_seek_obstacle_position",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "obstacle_seek_height",
+                    format!("{:?}", obstacle_seek_height.as_ref()),
+                ),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                ("z_final", format!("{:?}", z_final)),
+                ("seek_speed", format!("{:?}", seek_speed.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_seek_lld_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<ZSeekLldPositionReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "z_seek_lld_position",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("seek_height", format!("{:?}", seek_height.as_ref())),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                ("z_final", format!("{:?}", z_final)),
+                ("seek_speed", format!("{:?}", seek_speed.as_ref())),
+                (
+                    "capacitive_lld_sensitivity",
+                    format!("{:?}", capacitive_lld_sensitivity.as_ref()),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn calibration_squeeze_check_torque(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibration_squeeze_check_torque",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn calibrate_squeeze_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "calibrate_squeeze_position",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn set_z_liquid_seek_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        seek_speeds: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_z_liquid_seek_speed",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("seek_speeds", format!("{:?}", seek_speeds.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_z_liquid_seek_speed(
+        &self,
+    ) -> impl Future<Output = Result</* seek_speeds= */ Vec<MmPerSecond>, Error>> + Send {
+        let reply = self.recorder.record("get_z_liquid_seek_speed", vec![]);
+        async move { reply }
+    }
+
+    fn z_move_absolute_2_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        z_speed_switch_position: impl AsRef<[Millimeters]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+        lower_section_velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_absolute_2_speed",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_position", format!("{:?}", z_position.as_ref())),
+                (
+                    "z_speed_switch_position",
+                    format!("{:?}", z_speed_switch_position.as_ref()),
+                ),
+                ("velocity", format!("{:?}", velocity.as_ref())),
+                (
+                    "lower_section_velocity",
+                    format!("{:?}", lower_section_velocity.as_ref()),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_servo_off",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn y_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_servo_off",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn save_counters(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "save_counters",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn get_home_sensors(&self) -> impl Future<Output = Result<GetHomeSensorsReply, Error>> + Send {
+        let reply = self.recorder.record("get_home_sensors", vec![]);
+        async move { reply }
+    }
+
+    fn get_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetCalibrationValuesReply, Error>> + Send {
+        let reply = self.recorder.record("get_calibration_values", vec![]);
+        async move { reply }
+    }
+
+    fn get_extended_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetExtendedCalibrationValuesReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("get_extended_calibration_values", vec![]);
+        async move { reply }
+    }
+
+    fn get_counters(&self) -> impl Future<Output = Result<GetCountersReply, Error>> + Send {
+        let reply = self.recorder.record("get_counters", vec![]);
+        async move { reply }
+    }
+
+    fn get_technical_info(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result</* channel_info= */ String, Error>> + Send {
+        let reply = self.recorder.record(
+            "get_technical_info",
+            vec![("channel", format!("{:?}", channel))],
+        );
+        async move { reply }
+    }
+
+    fn set_counters(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        tip_pickup_counter: impl AsRef<[u32]> + Send,
+        tip_eject_counter: impl AsRef<[u32]> + Send,
+        aspirate_counter: impl AsRef<[u32]> + Send,
+        dispense_counter: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_counters",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "tip_pickup_counter",
+                    format!("{:?}", tip_pickup_counter.as_ref()),
+                ),
+                (
+                    "tip_eject_counter",
+                    format!("{:?}", tip_eject_counter.as_ref()),
+                ),
+                (
+                    "aspirate_counter",
+                    format!("{:?}", aspirate_counter.as_ref()),
+                ),
+                (
+                    "dispense_counter",
+                    format!("{:?}", dispense_counter.as_ref()),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn measure_lld_frequency(
+        &self,
+        channel: u16,
+        seconds: u16,
+    ) -> impl Future<Output = Result</* detects_per_minute= */ u16, Error>> + Send {
+        let reply = self.recorder.record(
+            "measure_lld_frequency",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("seconds", format!("{:?}", seconds)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn y_move_absolute_stagger(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+        delay: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_absolute_stagger",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("acceleration", format!("{:?}", acceleration.as_ref())),
+                ("velocity", format!("{:?}", velocity.as_ref())),
+                ("delay", format!("{:?}", delay.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn y_move_absolute_gripper_stagger(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        gripper_tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        acceleration: impl AsRef<[u32]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+        delay: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_absolute_gripper_stagger",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "gripper_tips_used",
+                    format!("{:?}", gripper_tips_used.as_ref()),
+                ),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("acceleration", format!("{:?}", acceleration.as_ref())),
+                ("velocity", format!("{:?}", velocity.as_ref())),
+                ("delay", format!("{:?}", delay.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_positions(&self) -> impl Future<Output = Result<GetPositionsReply, Error>> + Send {
+        let reply = self.recorder.record("get_positions", vec![]);
+        async move { reply }
+    }
+
+    fn get_tip_types(
+        &self,
+    ) -> impl Future<Output = Result</* tip_present= */ Vec<i16>, Error>> + Send {
+        let reply = self.recorder.record("get_tip_types", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AspirateRequest {
+    pub aspirate_type: Option<Vec<i16>>,
+    pub tips_used: Option<Vec<u16>>,
+    pub liquid_seek_height: Option<Vec<Millimeters>>,
+    pub liquid_surface_height: Option<Vec<Millimeters>>,
+    pub submerge_depth: Option<Vec<Millimeters>>,
+    pub follow_depth: Option<Vec<Millimeters>>,
+    pub z_min_position: Option<Vec<Millimeters>>,
+    pub clot_check_height: Option<Vec<Millimeters>>,
+    pub z_final: Option<Millimeters>,
+    pub liquid_exit_speed: Option<Vec<MmPerSecond>>,
+    pub blowout_volume: Option<Vec<Microliters>>,
+    pub prewet_volume: Option<Vec<Microliters>>,
+    pub aspirate_volume: Option<Vec<Microliters>>,
+    pub transport_air_volume: Option<Vec<Microliters>>,
+    pub aspirate_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub settling_time: Option<Vec<u32>>,
+    pub mix_volume: Option<Vec<Microliters>>,
+    pub mix_cycles: Option<Vec<u32>>,
+    pub mix_position: Option<Vec<Millimeters>>,
+    pub mix_follow_distance: Option<Vec<Millimeters>>,
+    pub mix_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub aspirate_offset: Option<Vec<i32>>,
+    pub tube_section_height: Option<Vec<Millimeters>>,
+    pub tube_section_ratio: Option<Vec<i32>>,
+    pub lld_mode: Option<Vec<i16>>,
+    pub capacitive_lld_sensitivity: Option<Vec<i16>>,
+    pub pressure_lld_sensitivity: Option<Vec<i16>>,
+    pub lld_height_difference: Option<Vec<i32>>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<Vec<u32>>,
+    pub recording_mode: Option<u16>,
+}
+
+impl AspirateRequest {
+    pub fn aspirate_type(mut self, aspirate_type: impl Into<Vec<i16>>) -> Self {
+        self.aspirate_type = Some(aspirate_type.into());
+        self
+    }
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn liquid_seek_height(mut self, liquid_seek_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.liquid_seek_height = Some(liquid_seek_height.into());
+        self
+    }
+    pub fn liquid_surface_height(
+        mut self,
+        liquid_surface_height: impl Into<Vec<Millimeters>>,
+    ) -> Self {
+        self.liquid_surface_height = Some(liquid_surface_height.into());
+        self
+    }
+    pub fn submerge_depth(mut self, submerge_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.submerge_depth = Some(submerge_depth.into());
+        self
+    }
+    pub fn follow_depth(mut self, follow_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.follow_depth = Some(follow_depth.into());
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.z_min_position = Some(z_min_position.into());
+        self
+    }
+    pub fn clot_check_height(mut self, clot_check_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.clot_check_height = Some(clot_check_height.into());
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn liquid_exit_speed(mut self, liquid_exit_speed: impl Into<Vec<MmPerSecond>>) -> Self {
+        self.liquid_exit_speed = Some(liquid_exit_speed.into());
+        self
+    }
+    pub fn blowout_volume(mut self, blowout_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.blowout_volume = Some(blowout_volume.into());
+        self
+    }
+    pub fn prewet_volume(mut self, prewet_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.prewet_volume = Some(prewet_volume.into());
+        self
+    }
+    pub fn aspirate_volume(mut self, aspirate_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.aspirate_volume = Some(aspirate_volume.into());
+        self
+    }
+    pub fn transport_air_volume(
+        mut self,
+        transport_air_volume: impl Into<Vec<Microliters>>,
+    ) -> Self {
+        self.transport_air_volume = Some(transport_air_volume.into());
+        self
+    }
+    pub fn aspirate_speed(mut self, aspirate_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.aspirate_speed = Some(aspirate_speed.into());
+        self
+    }
+    pub fn settling_time(mut self, settling_time: impl Into<Vec<u32>>) -> Self {
+        self.settling_time = Some(settling_time.into());
+        self
+    }
+    pub fn mix_volume(mut self, mix_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.mix_volume = Some(mix_volume.into());
+        self
+    }
+    pub fn mix_cycles(mut self, mix_cycles: impl Into<Vec<u32>>) -> Self {
+        self.mix_cycles = Some(mix_cycles.into());
+        self
+    }
+    pub fn mix_position(mut self, mix_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_position = Some(mix_position.into());
+        self
+    }
+    pub fn mix_follow_distance(mut self, mix_follow_distance: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_follow_distance = Some(mix_follow_distance.into());
+        self
+    }
+    pub fn mix_speed(mut self, mix_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.mix_speed = Some(mix_speed.into());
+        self
+    }
+    pub fn aspirate_offset(mut self, aspirate_offset: impl Into<Vec<i32>>) -> Self {
+        self.aspirate_offset = Some(aspirate_offset.into());
+        self
+    }
+    pub fn tube_section_height(mut self, tube_section_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.tube_section_height = Some(tube_section_height.into());
+        self
+    }
+    pub fn tube_section_ratio(mut self, tube_section_ratio: impl Into<Vec<i32>>) -> Self {
+        self.tube_section_ratio = Some(tube_section_ratio.into());
+        self
+    }
+    pub fn lld_mode(mut self, lld_mode: impl Into<Vec<i16>>) -> Self {
+        self.lld_mode = Some(lld_mode.into());
+        self
+    }
+    pub fn capacitive_lld_sensitivity(
+        mut self,
+        capacitive_lld_sensitivity: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.capacitive_lld_sensitivity = Some(capacitive_lld_sensitivity.into());
+        self
+    }
+    pub fn pressure_lld_sensitivity(
+        mut self,
+        pressure_lld_sensitivity: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.pressure_lld_sensitivity = Some(pressure_lld_sensitivity.into());
+        self
+    }
+    pub fn lld_height_difference(mut self, lld_height_difference: impl Into<Vec<i32>>) -> Self {
+        self.lld_height_difference = Some(lld_height_difference.into());
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: impl Into<Vec<u32>>) -> Self {
+        self.limit_curve_index = Some(limit_curve_index.into());
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DispenseRequest {
+    pub dispense_type: Option<Vec<i16>>,
+    pub tips_used: Option<Vec<u16>>,
+    pub liquid_seek_height: Option<Vec<Millimeters>>,
+    pub dispense_height: Option<Vec<Millimeters>>,
+    pub submerge_depth: Option<Vec<Millimeters>>,
+    pub follow_depth: Option<Vec<Millimeters>>,
+    pub z_min_position: Option<Vec<Millimeters>>,
+    pub z_final: Option<Millimeters>,
+    pub liquid_exit_speed: Option<Vec<MmPerSecond>>,
+    pub transport_air_volume: Option<Vec<Microliters>>,
+    pub dispense_volume: Option<Vec<Microliters>>,
+    pub stop_back_volume: Option<Vec<Microliters>>,
+    pub blowout_volume: Option<Vec<Microliters>>,
+    pub dispense_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub cutoff_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub settling_time: Option<Vec<u32>>,
+    pub mix_volume: Option<Vec<Microliters>>,
+    pub mix_cycles: Option<Vec<u32>>,
+    pub mix_position: Option<Vec<Millimeters>>,
+    pub mix_follow_distance: Option<Vec<Millimeters>>,
+    pub mix_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub touchoff_distance: Option<Millimeters>,
+    pub dispense_offset: Option<Vec<i32>>,
+    pub tube_section_height: Option<Vec<Millimeters>>,
+    pub tube_section_ratio: Option<Vec<i32>>,
+    pub lld_mode: Option<Vec<i16>>,
+    pub capacitive_lld_sensitivity: Option<Vec<i16>>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<Vec<u32>>,
+    pub recording_mode: Option<u16>,
+}
+
+impl DispenseRequest {
+    pub fn dispense_type(mut self, dispense_type: impl Into<Vec<i16>>) -> Self {
+        self.dispense_type = Some(dispense_type.into());
+        self
+    }
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn liquid_seek_height(mut self, liquid_seek_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.liquid_seek_height = Some(liquid_seek_height.into());
+        self
+    }
+    pub fn dispense_height(mut self, dispense_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.dispense_height = Some(dispense_height.into());
+        self
+    }
+    pub fn submerge_depth(mut self, submerge_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.submerge_depth = Some(submerge_depth.into());
+        self
+    }
+    pub fn follow_depth(mut self, follow_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.follow_depth = Some(follow_depth.into());
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.z_min_position = Some(z_min_position.into());
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn liquid_exit_speed(mut self, liquid_exit_speed: impl Into<Vec<MmPerSecond>>) -> Self {
+        self.liquid_exit_speed = Some(liquid_exit_speed.into());
+        self
+    }
+    pub fn transport_air_volume(
+        mut self,
+        transport_air_volume: impl Into<Vec<Microliters>>,
+    ) -> Self {
+        self.transport_air_volume = Some(transport_air_volume.into());
+        self
+    }
+    pub fn dispense_volume(mut self, dispense_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.dispense_volume = Some(dispense_volume.into());
+        self
+    }
+    pub fn stop_back_volume(mut self, stop_back_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.stop_back_volume = Some(stop_back_volume.into());
+        self
+    }
+    pub fn blowout_volume(mut self, blowout_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.blowout_volume = Some(blowout_volume.into());
+        self
+    }
+    pub fn dispense_speed(mut self, dispense_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.dispense_speed = Some(dispense_speed.into());
+        self
+    }
+    pub fn cutoff_speed(mut self, cutoff_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.cutoff_speed = Some(cutoff_speed.into());
+        self
+    }
+    pub fn settling_time(mut self, settling_time: impl Into<Vec<u32>>) -> Self {
+        self.settling_time = Some(settling_time.into());
+        self
+    }
+    pub fn mix_volume(mut self, mix_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.mix_volume = Some(mix_volume.into());
+        self
+    }
+    pub fn mix_cycles(mut self, mix_cycles: impl Into<Vec<u32>>) -> Self {
+        self.mix_cycles = Some(mix_cycles.into());
+        self
+    }
+    pub fn mix_position(mut self, mix_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_position = Some(mix_position.into());
+        self
+    }
+    pub fn mix_follow_distance(mut self, mix_follow_distance: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_follow_distance = Some(mix_follow_distance.into());
+        self
+    }
+    pub fn mix_speed(mut self, mix_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.mix_speed = Some(mix_speed.into());
+        self
+    }
+    pub fn touchoff_distance(mut self, touchoff_distance: Millimeters) -> Self {
+        self.touchoff_distance = Some(touchoff_distance);
+        self
+    }
+    pub fn dispense_offset(mut self, dispense_offset: impl Into<Vec<i32>>) -> Self {
+        self.dispense_offset = Some(dispense_offset.into());
+        self
+    }
+    pub fn tube_section_height(mut self, tube_section_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.tube_section_height = Some(tube_section_height.into());
+        self
+    }
+    pub fn tube_section_ratio(mut self, tube_section_ratio: impl Into<Vec<i32>>) -> Self {
+        self.tube_section_ratio = Some(tube_section_ratio.into());
+        self
+    }
+    pub fn lld_mode(mut self, lld_mode: impl Into<Vec<i16>>) -> Self {
+        self.lld_mode = Some(lld_mode.into());
+        self
+    }
+    pub fn capacitive_lld_sensitivity(
+        mut self,
+        capacitive_lld_sensitivity: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.capacitive_lld_sensitivity = Some(capacitive_lld_sensitivity.into());
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: impl Into<Vec<u32>>) -> Self {
+        self.limit_curve_index = Some(limit_curve_index.into());
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AspirateAndDispenseRequest {
+    pub aspirate_type: Option<Vec<i16>>,
+    pub dispense_type: Option<Vec<i16>>,
+    pub tips_used: Option<Vec<u16>>,
+    pub aspirate_dispense_pattern: Option<Vec<i16>>,
+    pub liquid_seek_height: Option<Vec<Millimeters>>,
+    pub liquid_surface_height: Option<Vec<Millimeters>>,
+    pub submerge_depth: Option<Vec<Millimeters>>,
+    pub follow_depth: Option<Vec<Millimeters>>,
+    pub z_min_position: Option<Vec<Millimeters>>,
+    pub clot_check_height: Option<Vec<Millimeters>>,
+    pub z_final: Option<Millimeters>,
+    pub liquid_exit_speed: Option<Vec<MmPerSecond>>,
+    pub blowout_volume: Option<Vec<Microliters>>,
+    pub prewet_volume: Option<Vec<Microliters>>,
+    pub aspirate_volume: Option<Vec<Microliters>>,
+    pub transport_air_volume: Option<Vec<Microliters>>,
+    pub dispense_volume: Option<Vec<Microliters>>,
+    pub stop_back_volume: Option<Vec<Microliters>>,
+    pub aspirate_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub dispense_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub cutoff_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub settling_time: Option<Vec<u32>>,
+    pub mix_volume: Option<Vec<Microliters>>,
+    pub mix_cycles: Option<Vec<u32>>,
+    pub mix_position: Option<Vec<Millimeters>>,
+    pub mix_follow_distance: Option<Vec<Millimeters>>,
+    pub mix_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub offset: Option<Vec<i32>>,
+    pub tube_section_height: Option<Vec<Millimeters>>,
+    pub tube_section_ratio: Option<Vec<i32>>,
+    pub lld_mode: Option<Vec<i16>>,
+    pub capacitive_lld_sensitivity: Option<Vec<i16>>,
+    pub pressure_lld_sensitivity: Option<Vec<i16>>,
+    pub lld_height_difference: Option<Vec<i32>>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<Vec<u32>>,
+    pub recording_mode: Option<u16>,
+}
+
+impl AspirateAndDispenseRequest {
+    pub fn aspirate_type(mut self, aspirate_type: impl Into<Vec<i16>>) -> Self {
+        self.aspirate_type = Some(aspirate_type.into());
+        self
+    }
+    pub fn dispense_type(mut self, dispense_type: impl Into<Vec<i16>>) -> Self {
+        self.dispense_type = Some(dispense_type.into());
+        self
+    }
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn aspirate_dispense_pattern(
+        mut self,
+        aspirate_dispense_pattern: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.aspirate_dispense_pattern = Some(aspirate_dispense_pattern.into());
+        self
+    }
+    pub fn liquid_seek_height(mut self, liquid_seek_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.liquid_seek_height = Some(liquid_seek_height.into());
+        self
+    }
+    pub fn liquid_surface_height(
+        mut self,
+        liquid_surface_height: impl Into<Vec<Millimeters>>,
+    ) -> Self {
+        self.liquid_surface_height = Some(liquid_surface_height.into());
+        self
+    }
+    pub fn submerge_depth(mut self, submerge_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.submerge_depth = Some(submerge_depth.into());
+        self
+    }
+    pub fn follow_depth(mut self, follow_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.follow_depth = Some(follow_depth.into());
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.z_min_position = Some(z_min_position.into());
+        self
+    }
+    pub fn clot_check_height(mut self, clot_check_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.clot_check_height = Some(clot_check_height.into());
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn liquid_exit_speed(mut self, liquid_exit_speed: impl Into<Vec<MmPerSecond>>) -> Self {
+        self.liquid_exit_speed = Some(liquid_exit_speed.into());
+        self
+    }
+    pub fn blowout_volume(mut self, blowout_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.blowout_volume = Some(blowout_volume.into());
+        self
+    }
+    pub fn prewet_volume(mut self, prewet_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.prewet_volume = Some(prewet_volume.into());
+        self
+    }
+    pub fn aspirate_volume(mut self, aspirate_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.aspirate_volume = Some(aspirate_volume.into());
+        self
+    }
+    pub fn transport_air_volume(
+        mut self,
+        transport_air_volume: impl Into<Vec<Microliters>>,
+    ) -> Self {
+        self.transport_air_volume = Some(transport_air_volume.into());
+        self
+    }
+    pub fn dispense_volume(mut self, dispense_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.dispense_volume = Some(dispense_volume.into());
+        self
+    }
+    pub fn stop_back_volume(mut self, stop_back_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.stop_back_volume = Some(stop_back_volume.into());
+        self
+    }
+    pub fn aspirate_speed(mut self, aspirate_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.aspirate_speed = Some(aspirate_speed.into());
+        self
+    }
+    pub fn dispense_speed(mut self, dispense_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.dispense_speed = Some(dispense_speed.into());
+        self
+    }
+    pub fn cutoff_speed(mut self, cutoff_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.cutoff_speed = Some(cutoff_speed.into());
+        self
+    }
+    pub fn settling_time(mut self, settling_time: impl Into<Vec<u32>>) -> Self {
+        self.settling_time = Some(settling_time.into());
+        self
+    }
+    pub fn mix_volume(mut self, mix_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.mix_volume = Some(mix_volume.into());
+        self
+    }
+    pub fn mix_cycles(mut self, mix_cycles: impl Into<Vec<u32>>) -> Self {
+        self.mix_cycles = Some(mix_cycles.into());
+        self
+    }
+    pub fn mix_position(mut self, mix_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_position = Some(mix_position.into());
+        self
+    }
+    pub fn mix_follow_distance(mut self, mix_follow_distance: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_follow_distance = Some(mix_follow_distance.into());
+        self
+    }
+    pub fn mix_speed(mut self, mix_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.mix_speed = Some(mix_speed.into());
+        self
+    }
+    pub fn offset(mut self, offset: impl Into<Vec<i32>>) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+    pub fn tube_section_height(mut self, tube_section_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.tube_section_height = Some(tube_section_height.into());
+        self
+    }
+    pub fn tube_section_ratio(mut self, tube_section_ratio: impl Into<Vec<i32>>) -> Self {
+        self.tube_section_ratio = Some(tube_section_ratio.into());
+        self
+    }
+    pub fn lld_mode(mut self, lld_mode: impl Into<Vec<i16>>) -> Self {
+        self.lld_mode = Some(lld_mode.into());
+        self
+    }
+    pub fn capacitive_lld_sensitivity(
+        mut self,
+        capacitive_lld_sensitivity: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.capacitive_lld_sensitivity = Some(capacitive_lld_sensitivity.into());
+        self
+    }
+    pub fn pressure_lld_sensitivity(
+        mut self,
+        pressure_lld_sensitivity: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.pressure_lld_sensitivity = Some(pressure_lld_sensitivity.into());
+        self
+    }
+    pub fn lld_height_difference(mut self, lld_height_difference: impl Into<Vec<i32>>) -> Self {
+        self.lld_height_difference = Some(lld_height_difference.into());
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: impl Into<Vec<u32>>) -> Self {
+        self.limit_curve_index = Some(limit_curve_index.into());
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateLimitCurveReply {
+    pub index: u32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RetrieveTadmDataReply {
+    pub offsets: Vec<u16>,
+    pub tadm_data: Vec<i16>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetLimitCurveNamesReply {
+    pub names: String,
+}
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetLimitCurveInfoReply {
     pub index: u32,
     pub lower_limits: u16,
     pub upper_limits: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetTADMEnableReply {
     pub enable: Vec<i16>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetZDefaultSpeedReply {
-    pub speed: Vec<i32>,
+    pub speed: Vec<MmPerSecond>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetPotentiometerSettingsReply {
     pub gain: Vec<i16>,
     pub offset: Vec<i16>,
 }
 
-#[allow(non_camel_case_types)]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrepareOnTheFlyDispenseRequest {
+    pub tips_used: Option<Vec<u16>>,
+    pub volume: Option<Vec<Microliters>>,
+    pub stop_back_volume: Option<Vec<Microliters>>,
+    pub transport_air_volume: Option<Vec<Microliters>>,
+    pub dispense_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub cutoff_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub tadm_enabled: Option<Vec<i16>>,
+    pub limit_curve_index: Option<Vec<u32>>,
+    pub dispense_pattern: Option<Vec<u16>>,
+    pub recording_mode: Option<u16>,
+}
+
+impl PrepareOnTheFlyDispenseRequest {
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn volume(mut self, volume: impl Into<Vec<Microliters>>) -> Self {
+        self.volume = Some(volume.into());
+        self
+    }
+    pub fn stop_back_volume(mut self, stop_back_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.stop_back_volume = Some(stop_back_volume.into());
+        self
+    }
+    pub fn transport_air_volume(
+        mut self,
+        transport_air_volume: impl Into<Vec<Microliters>>,
+    ) -> Self {
+        self.transport_air_volume = Some(transport_air_volume.into());
+        self
+    }
+    pub fn dispense_speed(mut self, dispense_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.dispense_speed = Some(dispense_speed.into());
+        self
+    }
+    pub fn cutoff_speed(mut self, cutoff_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.cutoff_speed = Some(cutoff_speed.into());
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: impl Into<Vec<i16>>) -> Self {
+        self.tadm_enabled = Some(tadm_enabled.into());
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: impl Into<Vec<u32>>) -> Self {
+        self.limit_curve_index = Some(limit_curve_index.into());
+        self
+    }
+    pub fn dispense_pattern(mut self, dispense_pattern: impl Into<Vec<u16>>) -> Self {
+        self.dispense_pattern = Some(dispense_pattern.into());
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReadOnTheFlyDispenseErrorsReply {
     pub error: bool,
     pub results: Vec<u16>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetChannelConfigurationReply {
     pub enabled: Vec<bool>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZSeekObstaclePositionReply {
-    pub z_position: Vec<i32>,
+    pub z_position: Vec<Millimeters>,
     pub obstacle_detected: Vec<i16>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZSeekLldPositionReply {
-    pub z_position: Vec<i32>,
+    pub z_position: Vec<Millimeters>,
     pub lld_detected: Vec<i16>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetHomeSensorsReply {
     pub y_home: Vec<bool>,
     pub z_home: Vec<bool>,
@@ -3366,8 +9606,8 @@ pub struct GetHomeSensorsReply {
     pub dispense_home: Vec<bool>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetCalibrationValuesReply {
     pub dispense_drive: Vec<i16>,
     pub squeeze_drive: Vec<i16>,
@@ -3375,8 +9615,8 @@ pub struct GetCalibrationValuesReply {
     pub clot_detection: Vec<i16>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetExtendedCalibrationValuesReply {
     pub tip_type_adjustment: Vec<i16>,
     pub pressure_conversion: Vec<i16>,
@@ -3384,8 +9624,8 @@ pub struct GetExtendedCalibrationValuesReply {
     pub z_bottom_search: Vec<i16>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetCountersReply {
     pub tip_pickup_counter: Vec<u32>,
     pub tip_eject_counter: Vec<u32>,
@@ -3393,21 +9633,21 @@ pub struct GetCountersReply {
     pub dispense_counter: Vec<u32>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetPositionsReply {
-    pub y_position: Vec<i32>,
-    pub y_encoder_position: Vec<i32>,
-    pub z_position: Vec<i32>,
-    pub z_encoder_position: Vec<i32>,
-    pub d_position: Vec<i32>,
-    pub d_encoder_position: Vec<i32>,
-    pub s_position: Vec<i32>,
-    pub s_encoder_position: Vec<i32>,
+    pub y_position: Vec<Millimeters>,
+    pub y_encoder_position: Vec<Millimeters>,
+    pub z_position: Vec<Millimeters>,
+    pub z_encoder_position: Vec<Millimeters>,
+    pub d_position: Vec<Millimeters>,
+    pub d_encoder_position: Vec<Millimeters>,
+    pub s_position: Vec<Millimeters>,
+    pub s_encoder_position: Vec<Millimeters>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -3415,8 +9655,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -3426,23 +9666,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -3450,8 +9690,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
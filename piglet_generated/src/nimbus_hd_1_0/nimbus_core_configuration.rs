@@ -2,13 +2,12 @@ use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +15,13 @@ use std::sync::Arc;
 pub struct NimbusCoreConfiguration {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreConfiguration {
-    // version
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Configuration";
+    pub const VERSION: &'static str = "";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,18 +31,40 @@ impl NimbusCoreConfiguration {
                 object_id: 384,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Configuration").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn is_configuration_saved(&self) -> Result</* saved= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreConfiguration.IsConfigurationSaved()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let saved = bool::deserialize(&mut stream)?;
         Ok(saved)
@@ -49,12 +73,14 @@ impl NimbusCoreConfiguration {
     pub async fn invalidate_configuration(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreConfiguration.InvalidateConfiguration()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -63,7 +89,9 @@ impl NimbusCoreConfiguration {
         let mut args = BytesMut::new();
         offset.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  offset: {:?}", offset)];
                 format!(
@@ -74,7 +102,7 @@ impl NimbusCoreConfiguration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -82,12 +110,14 @@ impl NimbusCoreConfiguration {
     pub async fn get_x_offset(&self) -> Result</* offset= */ i32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreConfiguration.GetXOffset()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let offset = i32::deserialize(&mut stream)?;
         Ok(offset)
@@ -103,7 +133,9 @@ impl NimbusCoreConfiguration {
         negative_limit.serialize(&mut args);
         positive_limit.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  negative_limit: {:?}", negative_limit),
@@ -117,7 +149,7 @@ impl NimbusCoreConfiguration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -125,12 +157,14 @@ impl NimbusCoreConfiguration {
     pub async fn get_x_limits(&self) -> Result<GetXLimitsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 6, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreConfiguration.GetXLimits()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let negative_limit = i32::deserialize(&mut stream)?;
         let positive_limit = i32::deserialize(&mut stream)?;
@@ -150,7 +184,9 @@ impl NimbusCoreConfiguration {
         negative_limit.serialize(&mut args);
         positive_limit.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  negative_limit: {:?}", negative_limit),
@@ -164,7 +200,7 @@ impl NimbusCoreConfiguration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -172,12 +208,14 @@ impl NimbusCoreConfiguration {
     pub async fn get_x_hazard_limits(&self) -> Result<GetXHazardLimitsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 8, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreConfiguration.GetXHazardLimits()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let negative_limit = i32::deserialize(&mut stream)?;
         let positive_limit = i32::deserialize(&mut stream)?;
@@ -191,7 +229,9 @@ impl NimbusCoreConfiguration {
         let mut args = BytesMut::new();
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 9, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  velocity: {:?}", velocity)];
                 format!(
@@ -202,7 +242,7 @@ impl NimbusCoreConfiguration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -210,12 +250,14 @@ impl NimbusCoreConfiguration {
     pub async fn get_x_max_velocity(&self) -> Result</* velocity= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 10, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreConfiguration.GetXMaxVelocity()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let velocity = u32::deserialize(&mut stream)?;
         Ok(velocity)
@@ -225,7 +267,9 @@ impl NimbusCoreConfiguration {
         let mut args = BytesMut::new();
         offset.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 11, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  offset: {:?}", offset)];
                 format!(
@@ -236,7 +280,7 @@ impl NimbusCoreConfiguration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -244,12 +288,14 @@ impl NimbusCoreConfiguration {
     pub async fn get_y_offset(&self) -> Result</* offset= */ i32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 12, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreConfiguration.GetYOffset()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let offset = i32::deserialize(&mut stream)?;
         Ok(offset)
@@ -265,7 +311,9 @@ impl NimbusCoreConfiguration {
         negative_limit.serialize(&mut args);
         positive_limit.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 13, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 13, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  negative_limit: {:?}", negative_limit),
@@ -279,7 +327,7 @@ impl NimbusCoreConfiguration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -287,12 +335,14 @@ impl NimbusCoreConfiguration {
     pub async fn get_y_limits(&self) -> Result<GetYLimitsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 14, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 14, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreConfiguration.GetYLimits()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let negative_limit = i32::deserialize(&mut stream)?;
         let positive_limit = i32::deserialize(&mut stream)?;
@@ -306,7 +356,9 @@ impl NimbusCoreConfiguration {
         let mut args = BytesMut::new();
         dual.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 15, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 15, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  dual: {:?}", dual)];
                 format!(
@@ -317,7 +369,7 @@ impl NimbusCoreConfiguration {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -325,12 +377,14 @@ impl NimbusCoreConfiguration {
     pub async fn get_dual_rail_gantry(&self) -> Result</* dual= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 16, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 16, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreConfiguration.GetDualRailGantry()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let dual = bool::deserialize(&mut stream)?;
         Ok(dual)
@@ -339,12 +393,14 @@ impl NimbusCoreConfiguration {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreConfiguration.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -362,7 +418,9 @@ impl NimbusCoreConfiguration {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -373,7 +431,7 @@ impl NimbusCoreConfiguration {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -395,7 +453,9 @@ impl NimbusCoreConfiguration {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -406,7 +466,7 @@ impl NimbusCoreConfiguration {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -421,12 +481,14 @@ impl NimbusCoreConfiguration {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreConfiguration.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -440,7 +502,9 @@ impl NimbusCoreConfiguration {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -451,7 +515,7 @@ impl NimbusCoreConfiguration {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -469,7 +533,9 @@ impl NimbusCoreConfiguration {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -480,7 +546,7 @@ impl NimbusCoreConfiguration {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -495,29 +561,400 @@ impl NimbusCoreConfiguration {
     }
 }
 
-#[allow(non_camel_case_types)]
+// The methods above as a trait, so code using them can be tested against NimbusCoreConfigurationFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreConfigurationApi {
+    fn is_configuration_saved(
+        &self,
+    ) -> impl Future<Output = Result</* saved= */ bool, Error>> + Send;
+    fn invalidate_configuration(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_x_offset(&self, offset: i32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_x_offset(&self) -> impl Future<Output = Result</* offset= */ i32, Error>> + Send;
+    fn set_x_limits(
+        &self,
+        negative_limit: i32,
+        positive_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_x_limits(&self) -> impl Future<Output = Result<GetXLimitsReply, Error>> + Send;
+    fn set_x_hazard_limits(
+        &self,
+        negative_limit: i32,
+        positive_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_x_hazard_limits(
+        &self,
+    ) -> impl Future<Output = Result<GetXHazardLimitsReply, Error>> + Send;
+    fn set_x_max_velocity(&self, velocity: u32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_x_max_velocity(&self)
+    -> impl Future<Output = Result</* velocity= */ u32, Error>> + Send;
+    fn set_y_offset(&self, offset: i32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_y_offset(&self) -> impl Future<Output = Result</* offset= */ i32, Error>> + Send;
+    fn set_y_limits(
+        &self,
+        negative_limit: i32,
+        positive_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_y_limits(&self) -> impl Future<Output = Result<GetYLimitsReply, Error>> + Send;
+    fn set_dual_rail_gantry(&self, dual: bool) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_dual_rail_gantry(&self) -> impl Future<Output = Result</* dual= */ bool, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreConfigurationApi for NimbusCoreConfiguration {
+    fn is_configuration_saved(
+        &self,
+    ) -> impl Future<Output = Result</* saved= */ bool, Error>> + Send {
+        NimbusCoreConfiguration::is_configuration_saved(self)
+    }
+
+    fn invalidate_configuration(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreConfiguration::invalidate_configuration(self)
+    }
+
+    fn set_x_offset(&self, offset: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreConfiguration::set_x_offset(self, offset)
+    }
+
+    fn get_x_offset(&self) -> impl Future<Output = Result</* offset= */ i32, Error>> + Send {
+        NimbusCoreConfiguration::get_x_offset(self)
+    }
+
+    fn set_x_limits(
+        &self,
+        negative_limit: i32,
+        positive_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreConfiguration::set_x_limits(self, negative_limit, positive_limit)
+    }
+
+    fn get_x_limits(&self) -> impl Future<Output = Result<GetXLimitsReply, Error>> + Send {
+        NimbusCoreConfiguration::get_x_limits(self)
+    }
+
+    fn set_x_hazard_limits(
+        &self,
+        negative_limit: i32,
+        positive_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreConfiguration::set_x_hazard_limits(self, negative_limit, positive_limit)
+    }
+
+    fn get_x_hazard_limits(
+        &self,
+    ) -> impl Future<Output = Result<GetXHazardLimitsReply, Error>> + Send {
+        NimbusCoreConfiguration::get_x_hazard_limits(self)
+    }
+
+    fn set_x_max_velocity(&self, velocity: u32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreConfiguration::set_x_max_velocity(self, velocity)
+    }
+
+    fn get_x_max_velocity(
+        &self,
+    ) -> impl Future<Output = Result</* velocity= */ u32, Error>> + Send {
+        NimbusCoreConfiguration::get_x_max_velocity(self)
+    }
+
+    fn set_y_offset(&self, offset: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreConfiguration::set_y_offset(self, offset)
+    }
+
+    fn get_y_offset(&self) -> impl Future<Output = Result</* offset= */ i32, Error>> + Send {
+        NimbusCoreConfiguration::get_y_offset(self)
+    }
+
+    fn set_y_limits(
+        &self,
+        negative_limit: i32,
+        positive_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreConfiguration::set_y_limits(self, negative_limit, positive_limit)
+    }
+
+    fn get_y_limits(&self) -> impl Future<Output = Result<GetYLimitsReply, Error>> + Send {
+        NimbusCoreConfiguration::get_y_limits(self)
+    }
+
+    fn set_dual_rail_gantry(&self, dual: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreConfiguration::set_dual_rail_gantry(self, dual)
+    }
+
+    fn get_dual_rail_gantry(&self) -> impl Future<Output = Result</* dual= */ bool, Error>> + Send {
+        NimbusCoreConfiguration::get_dual_rail_gantry(self)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreConfiguration::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreConfiguration::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreConfiguration::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreConfiguration::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreConfiguration::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreConfiguration::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreConfigurationFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreConfigurationApi for NimbusCoreConfigurationFake {
+    fn is_configuration_saved(
+        &self,
+    ) -> impl Future<Output = Result</* saved= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_configuration_saved", vec![]);
+        async move { reply }
+    }
+
+    fn invalidate_configuration(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("invalidate_configuration", vec![]);
+        async move { reply }
+    }
+
+    fn set_x_offset(&self, offset: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("set_x_offset", vec![("offset", format!("{:?}", offset))]);
+        async move { reply }
+    }
+
+    fn get_x_offset(&self) -> impl Future<Output = Result</* offset= */ i32, Error>> + Send {
+        let reply = self.recorder.record("get_x_offset", vec![]);
+        async move { reply }
+    }
+
+    fn set_x_limits(
+        &self,
+        negative_limit: i32,
+        positive_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_x_limits",
+            vec![
+                ("negative_limit", format!("{:?}", negative_limit)),
+                ("positive_limit", format!("{:?}", positive_limit)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_x_limits(&self) -> impl Future<Output = Result<GetXLimitsReply, Error>> + Send {
+        let reply = self.recorder.record("get_x_limits", vec![]);
+        async move { reply }
+    }
+
+    fn set_x_hazard_limits(
+        &self,
+        negative_limit: i32,
+        positive_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_x_hazard_limits",
+            vec![
+                ("negative_limit", format!("{:?}", negative_limit)),
+                ("positive_limit", format!("{:?}", positive_limit)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_x_hazard_limits(
+        &self,
+    ) -> impl Future<Output = Result<GetXHazardLimitsReply, Error>> + Send {
+        let reply = self.recorder.record("get_x_hazard_limits", vec![]);
+        async move { reply }
+    }
+
+    fn set_x_max_velocity(&self, velocity: u32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_x_max_velocity",
+            vec![("velocity", format!("{:?}", velocity))],
+        );
+        async move { reply }
+    }
+
+    fn get_x_max_velocity(
+        &self,
+    ) -> impl Future<Output = Result</* velocity= */ u32, Error>> + Send {
+        let reply = self.recorder.record("get_x_max_velocity", vec![]);
+        async move { reply }
+    }
+
+    fn set_y_offset(&self, offset: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("set_y_offset", vec![("offset", format!("{:?}", offset))]);
+        async move { reply }
+    }
+
+    fn get_y_offset(&self) -> impl Future<Output = Result</* offset= */ i32, Error>> + Send {
+        let reply = self.recorder.record("get_y_offset", vec![]);
+        async move { reply }
+    }
+
+    fn set_y_limits(
+        &self,
+        negative_limit: i32,
+        positive_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_y_limits",
+            vec![
+                ("negative_limit", format!("{:?}", negative_limit)),
+                ("positive_limit", format!("{:?}", positive_limit)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_y_limits(&self) -> impl Future<Output = Result<GetYLimitsReply, Error>> + Send {
+        let reply = self.recorder.record("get_y_limits", vec![]);
+        async move { reply }
+    }
+
+    fn set_dual_rail_gantry(&self, dual: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_dual_rail_gantry",
+            vec![("dual", format!("{:?}", dual))],
+        );
+        async move { reply }
+    }
+
+    fn get_dual_rail_gantry(&self) -> impl Future<Output = Result</* dual= */ bool, Error>> + Send {
+        let reply = self.recorder.record("get_dual_rail_gantry", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetXLimitsReply {
     pub negative_limit: i32,
     pub positive_limit: i32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetXHazardLimitsReply {
     pub negative_limit: i32,
     pub positive_limit: i32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetYLimitsReply {
     pub negative_limit: i32,
     pub positive_limit: i32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -525,8 +962,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -536,23 +973,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -560,8 +997,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
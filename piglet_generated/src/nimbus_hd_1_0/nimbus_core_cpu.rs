@@ -2,13 +2,12 @@ use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +15,13 @@ use std::sync::Arc;
 pub struct NimbusCoreCpu {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreCpu {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Cpu";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new_1(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,9 +31,20 @@ impl NimbusCoreCpu {
                 object_id: 49152,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_1 is the default
+    pub async fn resolve_1(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Cpu[1]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_2(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -40,9 +53,20 @@ impl NimbusCoreCpu {
                 object_id: 49408,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_2 is the default
+    pub async fn resolve_2(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Cpu[2]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_3(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -51,9 +75,20 @@ impl NimbusCoreCpu {
                 object_id: 49409,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_3 is the default
+    pub async fn resolve_3(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Cpu[3]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_4(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -62,9 +97,20 @@ impl NimbusCoreCpu {
                 object_id: 49410,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_4 is the default
+    pub async fn resolve_4(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Cpu[4]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_5(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -73,9 +119,20 @@ impl NimbusCoreCpu {
                 object_id: 49411,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_5 is the default
+    pub async fn resolve_5(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Cpu[5]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_6(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -84,9 +141,20 @@ impl NimbusCoreCpu {
                 object_id: 49412,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_6 is the default
+    pub async fn resolve_6(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Cpu[6]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_7(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -95,9 +163,20 @@ impl NimbusCoreCpu {
                 object_id: 49413,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_7 is the default
+    pub async fn resolve_7(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Cpu[7]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_8(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -106,9 +185,20 @@ impl NimbusCoreCpu {
                 object_id: 49414,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_8 is the default
+    pub async fn resolve_8(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Cpu[8]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
     pub fn new_9(robot: &Arc<RobotClient>) -> Self {
         Self {
             address: ObjectAddress {
@@ -117,18 +207,39 @@ impl NimbusCoreCpu {
                 object_id: 49415,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so
+    // new_9 is the default
+    pub async fn resolve_9(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Cpu[9]").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn download_info(&self) -> Result<DownloadInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.DownloadInfo()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let buffer_size = i32::deserialize(&mut stream)?;
         let file_name_template = String::deserialize(&mut stream)?;
@@ -141,12 +252,14 @@ impl NimbusCoreCpu {
     pub async fn download_initiate(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.DownloadInitiate()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -155,7 +268,9 @@ impl NimbusCoreCpu {
         let mut args = BytesMut::new();
         download_data.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  download_data: {:?}", download_data.as_ref())];
                 format!(
@@ -166,7 +281,7 @@ impl NimbusCoreCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -175,7 +290,9 @@ impl NimbusCoreCpu {
         let mut args = BytesMut::new();
         success.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  success: {:?}", success)];
                 format!(
@@ -186,7 +303,7 @@ impl NimbusCoreCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -194,12 +311,14 @@ impl NimbusCoreCpu {
     pub async fn version(&self) -> Result<VersionReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 5, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.Version()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let firmware_version = String::deserialize(&mut stream)?;
         Ok(VersionReply { firmware_version })
@@ -208,12 +327,14 @@ impl NimbusCoreCpu {
     pub async fn is_in_boot(&self) -> Result</* in_boot= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 6, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.IsInBoot()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let in_boot = bool::deserialize(&mut stream)?;
         Ok(in_boot)
@@ -223,7 +344,9 @@ impl NimbusCoreCpu {
         let mut args = BytesMut::new();
         address.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  address: {:?}", address)];
                 format!(
@@ -234,7 +357,7 @@ impl NimbusCoreCpu {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = u8::deserialize(&mut stream)?;
         Ok(value)
@@ -244,7 +367,9 @@ impl NimbusCoreCpu {
         let mut args = BytesMut::new();
         address.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 8, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  address: {:?}", address)];
                 format!(
@@ -255,7 +380,7 @@ impl NimbusCoreCpu {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = u32::deserialize(&mut stream)?;
         Ok(value)
@@ -266,7 +391,9 @@ impl NimbusCoreCpu {
         address.serialize(&mut args);
         value.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 9, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  address: {:?}", address),
@@ -280,7 +407,7 @@ impl NimbusCoreCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -290,7 +417,9 @@ impl NimbusCoreCpu {
         address.serialize(&mut args);
         value.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 10, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  address: {:?}", address),
@@ -304,7 +433,7 @@ impl NimbusCoreCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -312,12 +441,14 @@ impl NimbusCoreCpu {
     pub async fn reg_table_entries(&self) -> Result</* entries= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 11, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.RegTableEntries()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let entries = u32::deserialize(&mut stream)?;
         Ok(entries)
@@ -327,7 +458,9 @@ impl NimbusCoreCpu {
         let mut args = BytesMut::new();
         entry.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 12, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  entry: {:?}", entry)];
                 format!(
@@ -338,7 +471,7 @@ impl NimbusCoreCpu {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -356,7 +489,9 @@ impl NimbusCoreCpu {
         let mut args = BytesMut::new();
         delay_ms.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 2, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 2, 3, 1, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  delay_ms: {:?}", delay_ms)];
                 format!(
@@ -367,7 +502,7 @@ impl NimbusCoreCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -375,12 +510,14 @@ impl NimbusCoreCpu {
     pub async fn boot_loader_version(&self) -> Result</* boot_loader_version= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 2, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 2, 0, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.BootLoaderVersion()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let boot_loader_version = String::deserialize(&mut stream)?;
         Ok(boot_loader_version)
@@ -389,12 +526,14 @@ impl NimbusCoreCpu {
     pub async fn get_up_time(&self) -> Result</* value= */ SUpTime, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 2, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 2, 0, 3, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetUpTime()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = SUpTime::deserialize(&mut stream)?;
         Ok(value)
@@ -403,12 +542,14 @@ impl NimbusCoreCpu {
     pub async fn get_test_address(&self) -> Result</* value= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 2, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 2, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetTestAddress()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = u32::deserialize(&mut stream)?;
         Ok(value)
@@ -417,12 +558,14 @@ impl NimbusCoreCpu {
     pub async fn get_module_name(&self) -> Result</* value= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 3, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 3, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetModuleName()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = String::deserialize(&mut stream)?;
         Ok(value)
@@ -431,12 +574,14 @@ impl NimbusCoreCpu {
     pub async fn get_operating_mode(&self) -> Result</* value= */ OperatingMode, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 3, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 3, 0, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetOperatingMode()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = OperatingMode::deserialize(&mut stream)?;
         Ok(value)
@@ -445,12 +590,14 @@ impl NimbusCoreCpu {
     pub async fn get_firmware_version(&self) -> Result</* value= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 3, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 3, 0, 3, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetFirmwareVersion()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = String::deserialize(&mut stream)?;
         Ok(value)
@@ -459,12 +606,14 @@ impl NimbusCoreCpu {
     pub async fn get_compatibility_string(&self) -> Result</* value= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 3, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 3, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetCompatibilityString()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = String::deserialize(&mut stream)?;
         Ok(value)
@@ -473,12 +622,14 @@ impl NimbusCoreCpu {
     pub async fn get_firmware_part_number(&self) -> Result</* value= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 3, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 3, 0, 5, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetFirmwarePartNumber()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = String::deserialize(&mut stream)?;
         Ok(value)
@@ -487,12 +638,14 @@ impl NimbusCoreCpu {
     pub async fn get_firmware_revision(&self) -> Result</* value= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 3, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 3, 0, 6, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetFirmwareRevision()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = String::deserialize(&mut stream)?;
         Ok(value)
@@ -503,26 +656,30 @@ impl NimbusCoreCpu {
     ) -> Result</* value= */ Vec<CalibrationInformation>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 3, 0, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 3, 0, 7, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetCalibrationInformation()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let value = MVec::<CalibrationInformation>::deserialize(&mut stream)?.0;
+        let value = Vec::<CalibrationInformation>::deserialize(&mut stream)?;
         Ok(value)
     }
 
     pub async fn get_module_version(&self) -> Result</* value= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 3, 0, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 3, 0, 8, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetModuleVersion()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = String::deserialize(&mut stream)?;
         Ok(value)
@@ -531,12 +688,14 @@ impl NimbusCoreCpu {
     pub async fn get_serial_number(&self) -> Result</* value= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 3, 0, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 3, 0, 9, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetSerialNumber()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = String::deserialize(&mut stream)?;
         Ok(value)
@@ -545,12 +704,14 @@ impl NimbusCoreCpu {
     pub async fn get_hardware_part_number(&self) -> Result</* value= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 3, 0, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 3, 0, 10, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetHardwarePartNumber()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = String::deserialize(&mut stream)?;
         Ok(value)
@@ -559,12 +720,14 @@ impl NimbusCoreCpu {
     pub async fn get_hardware_revision(&self) -> Result</* value= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 3, 0, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 3, 0, 11, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetHardwareRevision()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = String::deserialize(&mut stream)?;
         Ok(value)
@@ -573,12 +736,14 @@ impl NimbusCoreCpu {
     pub async fn get_other(&self) -> Result</* value= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 3, 0, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 3, 0, 12, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.GetOther()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = String::deserialize(&mut stream)?;
         Ok(value)
@@ -587,12 +752,14 @@ impl NimbusCoreCpu {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -610,7 +777,9 @@ impl NimbusCoreCpu {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -621,7 +790,7 @@ impl NimbusCoreCpu {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -643,7 +812,9 @@ impl NimbusCoreCpu {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -654,7 +825,7 @@ impl NimbusCoreCpu {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -669,12 +840,14 @@ impl NimbusCoreCpu {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreCpu.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -688,7 +861,9 @@ impl NimbusCoreCpu {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -699,7 +874,7 @@ impl NimbusCoreCpu {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -717,7 +892,9 @@ impl NimbusCoreCpu {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -728,7 +905,7 @@ impl NimbusCoreCpu {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -743,339 +920,600 @@ impl NimbusCoreCpu {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub enum OperatingMode {
-    Bootloader = 0,
-    Runtime = 1,
+// The methods above as a trait, so code using them can be tested against NimbusCoreCpuFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreCpuApi {
+    fn download_info(&self) -> impl Future<Output = Result<DownloadInfoReply, Error>> + Send;
+    fn download_initiate(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn download_write(
+        &self,
+        download_data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn download_complete(&self, success: bool) -> impl Future<Output = Result<(), Error>> + Send;
+    fn version(&self) -> impl Future<Output = Result<VersionReply, Error>> + Send;
+    fn is_in_boot(&self) -> impl Future<Output = Result</* in_boot= */ bool, Error>> + Send;
+    fn read_uint_8(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u8, Error>> + Send;
+    fn read_uint_32(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u32, Error>> + Send;
+    fn write_uint_8(
+        &self,
+        address: u32,
+        value: u8,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn write_uint_32(
+        &self,
+        address: u32,
+        value: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn reg_table_entries(&self) -> impl Future<Output = Result</* entries= */ u32, Error>> + Send;
+    fn reg_table_entry(
+        &self,
+        entry: u32,
+    ) -> impl Future<Output = Result<RegTableEntryReply, Error>> + Send;
+    fn reset(&self, delay_ms: u32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn boot_loader_version(
+        &self,
+    ) -> impl Future<Output = Result</* boot_loader_version= */ String, Error>> + Send;
+    fn get_up_time(&self) -> impl Future<Output = Result</* value= */ SUpTime, Error>> + Send;
+    fn get_test_address(&self) -> impl Future<Output = Result</* value= */ u32, Error>> + Send;
+    fn get_module_name(&self) -> impl Future<Output = Result</* value= */ String, Error>> + Send;
+    fn get_operating_mode(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ OperatingMode, Error>> + Send;
+    fn get_firmware_version(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send;
+    fn get_compatibility_string(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send;
+    fn get_firmware_part_number(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send;
+    fn get_firmware_revision(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send;
+    fn get_calibration_information(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ Vec<CalibrationInformation>, Error>> + Send;
+    fn get_module_version(&self)
+    -> impl Future<Output = Result</* value= */ String, Error>> + Send;
+    fn get_serial_number(&self) -> impl Future<Output = Result</* value= */ String, Error>> + Send;
+    fn get_hardware_part_number(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send;
+    fn get_hardware_revision(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send;
+    fn get_other(&self) -> impl Future<Output = Result</* value= */ String, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
 }
 
-impl TryFrom<i32> for OperatingMode {
-    type Error = piglet_client::client::Error;
+impl NimbusCoreCpuApi for NimbusCoreCpu {
+    fn download_info(&self) -> impl Future<Output = Result<DownloadInfoReply, Error>> + Send {
+        NimbusCoreCpu::download_info(self)
+    }
 
-    fn try_from(v: i32) -> Result<Self, Self::Error> {
-        match v {
-            0 => Ok(OperatingMode::Bootloader),
-            1 => Ok(OperatingMode::Runtime),
+    fn download_initiate(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCpu::download_initiate(self)
+    }
 
-            _ => Err(ConnectionError(anyhow!(
-                "Unknown OperatingMode value {}",
-                v
-            ))),
-        }
+    fn download_write(
+        &self,
+        download_data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCpu::download_write(self, download_data)
     }
-}
 
-impl PigletCodec for OperatingMode {
-    const TYPE_ID: u8 = 32;
-}
+    fn download_complete(&self, success: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCpu::download_complete(self, success)
+    }
 
-impl PigletDeserialize for OperatingMode {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        stream.get_i32_le().try_into()
+    fn version(&self) -> impl Future<Output = Result<VersionReply, Error>> + Send {
+        NimbusCoreCpu::version(self)
     }
-}
 
-impl PigletSerialize for OperatingMode {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        stream.put_u16_le(4);
-        stream.put_i32_le(*self as i32);
+    fn is_in_boot(&self) -> impl Future<Output = Result</* in_boot= */ bool, Error>> + Send {
+        NimbusCoreCpu::is_in_boot(self)
     }
-}
 
-impl PigletCodec for MSlice<'_, OperatingMode> {
-    const TYPE_ID: u8 = 35;
-}
+    fn read_uint_8(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u8, Error>> + Send {
+        NimbusCoreCpu::read_uint_8(self, address)
+    }
 
-impl PigletCodec for MVec<OperatingMode> {
-    const TYPE_ID: u8 = 35;
-}
+    fn read_uint_32(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u32, Error>> + Send {
+        NimbusCoreCpu::read_uint_32(self, address)
+    }
 
-impl PigletDeserialize for MVec<OperatingMode> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut arr = Vec::new();
-        for i in 0..(length / 4) {
-            arr.push(stream.get_i32_le().try_into()?);
-        }
-        Ok(MVec(arr))
+    fn write_uint_8(
+        &self,
+        address: u32,
+        value: u8,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCpu::write_uint_8(self, address, value)
     }
-}
 
-impl PigletSerialize for MSlice<'_, OperatingMode> {
-    fn serialize(&self, bytes: &mut BytesMut) {
-        bytes.put_u8(Self::TYPE_ID);
-        bytes.put_u8(0);
-        bytes.put_u16_le(4 * self.0.len() as u16);
-        for v in self.0.as_ref() {
-            bytes.put_i32_le(*v as i32);
-        }
+    fn write_uint_32(
+        &self,
+        address: u32,
+        value: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCpu::write_uint_32(self, address, value)
     }
-}
 
-#[derive(Clone, Debug)]
-pub struct SUpTime {
-    pub days: u8,
-    pub hours: u8,
-    pub minutes: u8,
-    pub seconds: u8,
-    pub milliseconds: u16,
-}
+    fn reg_table_entries(&self) -> impl Future<Output = Result</* entries= */ u32, Error>> + Send {
+        NimbusCoreCpu::reg_table_entries(self)
+    }
 
-impl PigletCodec for SUpTime {
-    const TYPE_ID: u8 = 30;
-}
+    fn reg_table_entry(
+        &self,
+        entry: u32,
+    ) -> impl Future<Output = Result<RegTableEntryReply, Error>> + Send {
+        NimbusCoreCpu::reg_table_entry(self, entry)
+    }
 
-impl PigletSerialize for SUpTime {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        let mut buffer = BytesMut::new();
-        let s = self;
+    fn reset(&self, delay_ms: u32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreCpu::reset(self, delay_ms)
+    }
 
-        s.days.serialize(&mut buffer);
-        s.hours.serialize(&mut buffer);
-        s.minutes.serialize(&mut buffer);
-        s.seconds.serialize(&mut buffer);
-        s.milliseconds.serialize(&mut buffer);
+    fn boot_loader_version(
+        &self,
+    ) -> impl Future<Output = Result</* boot_loader_version= */ String, Error>> + Send {
+        NimbusCoreCpu::boot_loader_version(self)
+    }
 
-        stream.put_u16_le(buffer.len() as u16);
-        stream.put(buffer);
+    fn get_up_time(&self) -> impl Future<Output = Result</* value= */ SUpTime, Error>> + Send {
+        NimbusCoreCpu::get_up_time(self)
     }
-}
 
-impl PigletDeserialize for SUpTime {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(Self {
-            days: u8::deserialize(&mut bytes)?,
-            hours: u8::deserialize(&mut bytes)?,
-            minutes: u8::deserialize(&mut bytes)?,
-            seconds: u8::deserialize(&mut bytes)?,
-            milliseconds: u16::deserialize(&mut bytes)?,
-        })
+    fn get_test_address(&self) -> impl Future<Output = Result</* value= */ u32, Error>> + Send {
+        NimbusCoreCpu::get_test_address(self)
     }
-}
 
-impl PigletCodec for MSlice<'_, SUpTime> {
-    const TYPE_ID: u8 = 31;
-}
+    fn get_module_name(&self) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        NimbusCoreCpu::get_module_name(self)
+    }
 
-impl PigletCodec for MVec<SUpTime> {
-    const TYPE_ID: u8 = 31;
-}
+    fn get_operating_mode(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ OperatingMode, Error>> + Send {
+        NimbusCoreCpu::get_operating_mode(self)
+    }
 
-impl PigletSerialize for MSlice<'_, SUpTime> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
+    fn get_firmware_version(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        NimbusCoreCpu::get_firmware_version(self)
+    }
 
-        let mut outer = BytesMut::new();
-        for s in self.0.as_ref() {
-            let mut buffer = BytesMut::new();
+    fn get_compatibility_string(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        NimbusCoreCpu::get_compatibility_string(self)
+    }
 
-            s.days.serialize(&mut buffer);
-            s.hours.serialize(&mut buffer);
-            s.minutes.serialize(&mut buffer);
-            s.seconds.serialize(&mut buffer);
-            s.milliseconds.serialize(&mut buffer);
+    fn get_firmware_part_number(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        NimbusCoreCpu::get_firmware_part_number(self)
+    }
 
-            outer.put_u16_le(buffer.len() as u16);
-            outer.put(buffer);
-        }
+    fn get_firmware_revision(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        NimbusCoreCpu::get_firmware_revision(self)
+    }
 
-        stream.put_u16_le(outer.len() as u16);
-        stream.put(outer);
+    fn get_calibration_information(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ Vec<CalibrationInformation>, Error>> + Send {
+        NimbusCoreCpu::get_calibration_information(self)
     }
-}
 
-impl PigletSerialize for MVec<SUpTime> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        MSlice(&self.0).serialize(stream)
+    fn get_module_version(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        NimbusCoreCpu::get_module_version(self)
     }
-}
 
-impl PigletDeserialize for MVec<SUpTime> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let mut length = stream.get_u16_le() as usize;
-        let mut outer = stream.copy_to_bytes(length);
-        let mut arr = Vec::new();
-        while length > 0 {
-            let mut length = outer.get_u16_le() as usize;
-            let mut bytes = outer.copy_to_bytes(length);
-            arr.push(SUpTime {
-                days: u8::deserialize(&mut bytes)?,
-                hours: u8::deserialize(&mut bytes)?,
-                minutes: u8::deserialize(&mut bytes)?,
-                seconds: u8::deserialize(&mut bytes)?,
-                milliseconds: u16::deserialize(&mut bytes)?,
-            });
-        }
-        Ok(MVec(arr))
+    fn get_serial_number(&self) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        NimbusCoreCpu::get_serial_number(self)
     }
-}
 
-#[derive(Clone, Debug)]
-pub struct CalibrationInformation {
-    pub description: String,
-    pub date: NetworkResult,
-}
+    fn get_hardware_part_number(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        NimbusCoreCpu::get_hardware_part_number(self)
+    }
 
-impl PigletCodec for CalibrationInformation {
-    const TYPE_ID: u8 = 30;
-}
+    fn get_hardware_revision(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        NimbusCoreCpu::get_hardware_revision(self)
+    }
 
-impl PigletSerialize for CalibrationInformation {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        let mut buffer = BytesMut::new();
-        let s = self;
+    fn get_other(&self) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        NimbusCoreCpu::get_other(self)
+    }
 
-        s.description.serialize(&mut buffer);
-        s.date.serialize(&mut buffer);
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreCpu::object_info(self)
+    }
 
-        stream.put_u16_le(buffer.len() as u16);
-        stream.put(buffer);
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreCpu::method_info(self, method)
     }
-}
 
-impl PigletDeserialize for CalibrationInformation {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(Self {
-            description: String::deserialize(&mut bytes)?,
-            date: NetworkResult::deserialize(&mut bytes)?,
-        })
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreCpu::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreCpu::interface_descriptors(self)
     }
-}
 
-impl PigletCodec for MSlice<'_, CalibrationInformation> {
-    const TYPE_ID: u8 = 31;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreCpu::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreCpu::struct_info(self, interface_id)
+    }
 }
 
-impl PigletCodec for MVec<CalibrationInformation> {
-    const TYPE_ID: u8 = 31;
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreCpuFake {
+    pub recorder: piglet_client::testing::Recorder,
 }
 
-impl PigletSerialize for MSlice<'_, CalibrationInformation> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
+#[cfg(feature = "testing")]
+impl NimbusCoreCpuApi for NimbusCoreCpuFake {
+    fn download_info(&self) -> impl Future<Output = Result<DownloadInfoReply, Error>> + Send {
+        let reply = self.recorder.record("download_info", vec![]);
+        async move { reply }
+    }
+
+    fn download_initiate(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("download_initiate", vec![]);
+        async move { reply }
+    }
+
+    fn download_write(
+        &self,
+        download_data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "download_write",
+            vec![("download_data", format!("{:?}", download_data.as_ref()))],
+        );
+        async move { reply }
+    }
 
-        let mut outer = BytesMut::new();
-        for s in self.0.as_ref() {
-            let mut buffer = BytesMut::new();
+    fn download_complete(&self, success: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "download_complete",
+            vec![("success", format!("{:?}", success))],
+        );
+        async move { reply }
+    }
 
-            s.description.serialize(&mut buffer);
-            s.date.serialize(&mut buffer);
+    fn version(&self) -> impl Future<Output = Result<VersionReply, Error>> + Send {
+        let reply = self.recorder.record("version", vec![]);
+        async move { reply }
+    }
 
-            outer.put_u16_le(buffer.len() as u16);
-            outer.put(buffer);
-        }
+    fn is_in_boot(&self) -> impl Future<Output = Result</* in_boot= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_in_boot", vec![]);
+        async move { reply }
+    }
 
-        stream.put_u16_le(outer.len() as u16);
-        stream.put(outer);
+    fn read_uint_8(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u8, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("read_uint_8", vec![("address", format!("{:?}", address))]);
+        async move { reply }
     }
-}
 
-impl PigletSerialize for MVec<CalibrationInformation> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        MSlice(&self.0).serialize(stream)
+    fn read_uint_32(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u32, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("read_uint_32", vec![("address", format!("{:?}", address))]);
+        async move { reply }
     }
-}
 
-impl PigletDeserialize for MVec<CalibrationInformation> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let mut length = stream.get_u16_le() as usize;
-        let mut outer = stream.copy_to_bytes(length);
-        let mut arr = Vec::new();
-        while length > 0 {
-            let mut length = outer.get_u16_le() as usize;
-            let mut bytes = outer.copy_to_bytes(length);
-            arr.push(CalibrationInformation {
-                description: String::deserialize(&mut bytes)?,
-                date: NetworkResult::deserialize(&mut bytes)?,
-            });
-        }
-        Ok(MVec(arr))
+    fn write_uint_8(
+        &self,
+        address: u32,
+        value: u8,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "write_uint_8",
+            vec![
+                ("address", format!("{:?}", address)),
+                ("value", format!("{:?}", value)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn write_uint_32(
+        &self,
+        address: u32,
+        value: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "write_uint_32",
+            vec![
+                ("address", format!("{:?}", address)),
+                ("value", format!("{:?}", value)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn reg_table_entries(&self) -> impl Future<Output = Result</* entries= */ u32, Error>> + Send {
+        let reply = self.recorder.record("reg_table_entries", vec![]);
+        async move { reply }
+    }
+
+    fn reg_table_entry(
+        &self,
+        entry: u32,
+    ) -> impl Future<Output = Result<RegTableEntryReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("reg_table_entry", vec![("entry", format!("{:?}", entry))]);
+        async move { reply }
+    }
+
+    fn reset(&self, delay_ms: u32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("reset", vec![("delay_ms", format!("{:?}", delay_ms))]);
+        async move { reply }
+    }
+
+    fn boot_loader_version(
+        &self,
+    ) -> impl Future<Output = Result</* boot_loader_version= */ String, Error>> + Send {
+        let reply = self.recorder.record("boot_loader_version", vec![]);
+        async move { reply }
+    }
+
+    fn get_up_time(&self) -> impl Future<Output = Result</* value= */ SUpTime, Error>> + Send {
+        let reply = self.recorder.record("get_up_time", vec![]);
+        async move { reply }
+    }
+
+    fn get_test_address(&self) -> impl Future<Output = Result</* value= */ u32, Error>> + Send {
+        let reply = self.recorder.record("get_test_address", vec![]);
+        async move { reply }
+    }
+
+    fn get_module_name(&self) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_module_name", vec![]);
+        async move { reply }
     }
+
+    fn get_operating_mode(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ OperatingMode, Error>> + Send {
+        let reply = self.recorder.record("get_operating_mode", vec![]);
+        async move { reply }
+    }
+
+    fn get_firmware_version(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_firmware_version", vec![]);
+        async move { reply }
+    }
+
+    fn get_compatibility_string(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_compatibility_string", vec![]);
+        async move { reply }
+    }
+
+    fn get_firmware_part_number(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_firmware_part_number", vec![]);
+        async move { reply }
+    }
+
+    fn get_firmware_revision(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_firmware_revision", vec![]);
+        async move { reply }
+    }
+
+    fn get_calibration_information(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ Vec<CalibrationInformation>, Error>> + Send {
+        let reply = self.recorder.record("get_calibration_information", vec![]);
+        async move { reply }
+    }
+
+    fn get_module_version(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_module_version", vec![]);
+        async move { reply }
+    }
+
+    fn get_serial_number(&self) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_serial_number", vec![]);
+        async move { reply }
+    }
+
+    fn get_hardware_part_number(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_hardware_part_number", vec![]);
+        async move { reply }
+    }
+
+    fn get_hardware_revision(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_hardware_revision", vec![]);
+        async move { reply }
+    }
+
+    fn get_other(&self) -> impl Future<Output = Result</* value= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_other", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OperatingMode {
+    Bootloader = 0,
+    Runtime = 1,
+}
+
+#[derive(Clone, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SUpTime {
+    pub days: u8,
+    pub hours: u8,
+    pub minutes: u8,
+    pub seconds: u8,
+    pub milliseconds: u16,
+}
+
+#[derive(Clone, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalibrationInformation {
+    pub description: String,
+    pub date: NetworkResult,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DownloadInfoReply {
     pub buffer_size: i32,
     pub file_name_template: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VersionReply {
     pub firmware_version: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegTableEntryReply {
     pub module_id: u16,
     pub node_id: u16,
@@ -1083,8 +1521,8 @@ pub struct RegTableEntryReply {
     pub link_handle: u32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -1092,8 +1530,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -1103,23 +1541,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -1127,8 +1565,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
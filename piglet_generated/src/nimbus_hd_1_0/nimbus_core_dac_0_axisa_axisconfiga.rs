@@ -2,13 +2,12 @@ use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +15,13 @@ use std::sync::Arc;
 pub struct NimbusCoreDac0AxisaAxisconfiga {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreDac0AxisaAxisconfiga {
-    // version
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Axisconfiga";
+    pub const VERSION: &'static str = "";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,8 +31,44 @@ impl NimbusCoreDac0AxisaAxisconfiga {
                 object_id: 272,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Dac0.Axisa.Axisconfiga").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
+        }
+    }
+}
+
+// The methods above as a trait, so code using them can be tested against NimbusCoreDac0AxisaAxisconfigaFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreDac0AxisaAxisconfigaApi {}
+
+impl NimbusCoreDac0AxisaAxisconfigaApi for NimbusCoreDac0AxisaAxisconfiga {}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreDac0AxisaAxisconfigaFake {
+    pub recorder: piglet_client::testing::Recorder,
 }
 
+#[cfg(feature = "testing")]
+impl NimbusCoreDac0AxisaAxisconfigaApi for NimbusCoreDac0AxisaAxisconfigaFake {}
+
 // module was not present on the dumping machine
@@ -2,13 +2,12 @@ use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +15,13 @@ use std::sync::Arc;
 pub struct NimbusCoreEthernet {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreEthernet {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Ethernet";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,6 +31,26 @@ impl NimbusCoreEthernet {
                 object_id: 259,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Ethernet").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
@@ -36,7 +58,9 @@ impl NimbusCoreEthernet {
         let mut args = BytesMut::new();
         enable_dhcp.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 1, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  enable_dhcp: {:?}", enable_dhcp)];
                 format!(
@@ -47,7 +71,7 @@ impl NimbusCoreEthernet {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -55,12 +79,14 @@ impl NimbusCoreEthernet {
     pub async fn get_dhcp_enable(&self) -> Result</* use_dhcp= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetDhcpEnable()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let use_dhcp = bool::deserialize(&mut stream)?;
         Ok(use_dhcp)
@@ -70,7 +96,9 @@ impl NimbusCoreEthernet {
         let mut args = BytesMut::new();
         host_name.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  host_name: {:?}", host_name)];
                 format!(
@@ -81,7 +109,7 @@ impl NimbusCoreEthernet {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -89,12 +117,14 @@ impl NimbusCoreEthernet {
     pub async fn get_host_name(&self) -> Result</* host_name= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetHostName()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let host_name = String::deserialize(&mut stream)?;
         Ok(host_name)
@@ -103,12 +133,14 @@ impl NimbusCoreEthernet {
     pub async fn get_current_host_name(&self) -> Result</* host_name= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 5, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetCurrentHostName()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let host_name = String::deserialize(&mut stream)?;
         Ok(host_name)
@@ -117,12 +149,14 @@ impl NimbusCoreEthernet {
     pub async fn get_default_host_name(&self) -> Result</* host_name= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 6, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetDefaultHostName()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let host_name = String::deserialize(&mut stream)?;
         Ok(host_name)
@@ -138,7 +172,9 @@ impl NimbusCoreEthernet {
         ip_address.serialize(&mut args);
         subnet_mask.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  ip_address: {:?}", ip_address),
@@ -152,7 +188,7 @@ impl NimbusCoreEthernet {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -160,12 +196,14 @@ impl NimbusCoreEthernet {
     pub async fn get_static_ip_address(&self) -> Result<GetStaticIpAddressReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 8, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetStaticIpAddress()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let ip_address = String::deserialize(&mut stream)?;
         let subnet_mask = String::deserialize(&mut stream)?;
@@ -178,12 +216,14 @@ impl NimbusCoreEthernet {
     pub async fn get_current_ip_address(&self) -> Result<GetCurrentIpAddressReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 9, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetCurrentIpAddress()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let ip_address = String::deserialize(&mut stream)?;
         let subnet_mask = String::deserialize(&mut stream)?;
@@ -196,12 +236,14 @@ impl NimbusCoreEthernet {
     pub async fn get_mac_address(&self) -> Result</* mac_address= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 10, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetMacAddress()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let mac_address = String::deserialize(&mut stream)?;
         Ok(mac_address)
@@ -211,7 +253,9 @@ impl NimbusCoreEthernet {
         let mut args = BytesMut::new();
         dhcp_timeout.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 11, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  dhcp_timeout: {:?}", dhcp_timeout)];
                 format!(
@@ -222,7 +266,7 @@ impl NimbusCoreEthernet {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -230,12 +274,14 @@ impl NimbusCoreEthernet {
     pub async fn get_dhcp_timeout(&self) -> Result</* dhcp_timeout= */ i32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 12, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetDhcpTimeout()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let dhcp_timeout = i32::deserialize(&mut stream)?;
         Ok(dhcp_timeout)
@@ -245,7 +291,9 @@ impl NimbusCoreEthernet {
         let mut args = BytesMut::new();
         location.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 13, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 13, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  location: {:?}", location)];
                 format!(
@@ -256,7 +304,7 @@ impl NimbusCoreEthernet {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -264,12 +312,14 @@ impl NimbusCoreEthernet {
     pub async fn get_location(&self) -> Result</* location= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 14, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 14, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetLocation()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let location = String::deserialize(&mut stream)?;
         Ok(location)
@@ -279,7 +329,9 @@ impl NimbusCoreEthernet {
         let mut args = BytesMut::new();
         comment.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 15, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 15, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  comment: {:?}", comment)];
                 format!(
@@ -290,7 +342,7 @@ impl NimbusCoreEthernet {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -298,12 +350,14 @@ impl NimbusCoreEthernet {
     pub async fn get_comment(&self) -> Result</* comment= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 16, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 16, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetComment()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let comment = String::deserialize(&mut stream)?;
         Ok(comment)
@@ -313,7 +367,9 @@ impl NimbusCoreEthernet {
         let mut args = BytesMut::new();
         instrument_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 17, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 17, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  instrument_id: {:?}", instrument_id)];
                 format!(
@@ -324,7 +380,7 @@ impl NimbusCoreEthernet {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -332,12 +388,14 @@ impl NimbusCoreEthernet {
     pub async fn get_instrument_id(&self) -> Result</* instrument_id= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 18, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 18, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetInstrumentID()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let instrument_id = String::deserialize(&mut stream)?;
         Ok(instrument_id)
@@ -346,12 +404,14 @@ impl NimbusCoreEthernet {
     pub async fn get_instrument_type(&self) -> Result</* instrument_type= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 19, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 19, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetInstrumentType()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let instrument_type = String::deserialize(&mut stream)?;
         Ok(instrument_type)
@@ -360,12 +420,14 @@ impl NimbusCoreEthernet {
     pub async fn save_settings(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 20, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 20, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.SaveSettings()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -373,12 +435,14 @@ impl NimbusCoreEthernet {
     pub async fn recall_settings(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 21, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 21, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.RecallSettings()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -386,12 +450,14 @@ impl NimbusCoreEthernet {
     pub async fn invalidate_settings(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 22, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 22, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.InvalidateSettings()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -399,12 +465,14 @@ impl NimbusCoreEthernet {
     pub async fn are_stored_settings_valid(&self) -> Result</* valid= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 23, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 23, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.AreStoredSettingsValid()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let valid = bool::deserialize(&mut stream)?;
         Ok(valid)
@@ -413,12 +481,14 @@ impl NimbusCoreEthernet {
     pub async fn get_tcp_port(&self) -> Result</* port= */ u16, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 24, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 24, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetTCPPort()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let port = u16::deserialize(&mut stream)?;
         Ok(port)
@@ -428,7 +498,9 @@ impl NimbusCoreEthernet {
         let mut args = BytesMut::new();
         port.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 25, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 25, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  port: {:?}", port)];
                 format!(
@@ -439,7 +511,7 @@ impl NimbusCoreEthernet {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -447,12 +519,14 @@ impl NimbusCoreEthernet {
     pub async fn get_udp_port(&self) -> Result</* port= */ u16, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 26, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 26, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.GetUDPPort()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let port = u16::deserialize(&mut stream)?;
         Ok(port)
@@ -462,7 +536,9 @@ impl NimbusCoreEthernet {
         let mut args = BytesMut::new();
         port.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 27, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 27, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  port: {:?}", port)];
                 format!(
@@ -473,7 +549,7 @@ impl NimbusCoreEthernet {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -481,12 +557,14 @@ impl NimbusCoreEthernet {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -504,7 +582,9 @@ impl NimbusCoreEthernet {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -515,7 +595,7 @@ impl NimbusCoreEthernet {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -537,7 +617,9 @@ impl NimbusCoreEthernet {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -548,7 +630,7 @@ impl NimbusCoreEthernet {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -563,12 +645,14 @@ impl NimbusCoreEthernet {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreEthernet.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -582,7 +666,9 @@ impl NimbusCoreEthernet {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -593,7 +679,7 @@ impl NimbusCoreEthernet {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -611,7 +697,9 @@ impl NimbusCoreEthernet {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -622,7 +710,7 @@ impl NimbusCoreEthernet {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -637,22 +725,531 @@ impl NimbusCoreEthernet {
     }
 }
 
-#[allow(non_camel_case_types)]
+// The methods above as a trait, so code using them can be tested against NimbusCoreEthernetFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreEthernetApi {
+    fn set_dhcp_enable(&self, enable_dhcp: bool) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_dhcp_enable(&self) -> impl Future<Output = Result</* use_dhcp= */ bool, Error>> + Send;
+    fn set_host_name(&self, host_name: &str) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_host_name(&self) -> impl Future<Output = Result</* host_name= */ String, Error>> + Send;
+    fn get_current_host_name(
+        &self,
+    ) -> impl Future<Output = Result</* host_name= */ String, Error>> + Send;
+    fn get_default_host_name(
+        &self,
+    ) -> impl Future<Output = Result</* host_name= */ String, Error>> + Send;
+    fn set_static_ip_address(
+        &self,
+        ip_address: &str,
+        subnet_mask: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_static_ip_address(
+        &self,
+    ) -> impl Future<Output = Result<GetStaticIpAddressReply, Error>> + Send;
+    fn get_current_ip_address(
+        &self,
+    ) -> impl Future<Output = Result<GetCurrentIpAddressReply, Error>> + Send;
+    fn get_mac_address(
+        &self,
+    ) -> impl Future<Output = Result</* mac_address= */ String, Error>> + Send;
+    fn set_dhcp_timeout(&self, dhcp_timeout: i32)
+    -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_dhcp_timeout(
+        &self,
+    ) -> impl Future<Output = Result</* dhcp_timeout= */ i32, Error>> + Send;
+    fn set_location(&self, location: &str) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_location(&self) -> impl Future<Output = Result</* location= */ String, Error>> + Send;
+    fn set_comment(&self, comment: &str) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_comment(&self) -> impl Future<Output = Result</* comment= */ String, Error>> + Send;
+    fn set_instrument_id(
+        &self,
+        instrument_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_instrument_id(
+        &self,
+    ) -> impl Future<Output = Result</* instrument_id= */ String, Error>> + Send;
+    fn get_instrument_type(
+        &self,
+    ) -> impl Future<Output = Result</* instrument_type= */ String, Error>> + Send;
+    fn save_settings(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn recall_settings(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn invalidate_settings(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn are_stored_settings_valid(
+        &self,
+    ) -> impl Future<Output = Result</* valid= */ bool, Error>> + Send;
+    fn get_tcp_port(&self) -> impl Future<Output = Result</* port= */ u16, Error>> + Send;
+    fn set_tcp_port(&self, port: u16) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_udp_port(&self) -> impl Future<Output = Result</* port= */ u16, Error>> + Send;
+    fn set_udp_port(&self, port: u16) -> impl Future<Output = Result<(), Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreEthernetApi for NimbusCoreEthernet {
+    fn set_dhcp_enable(&self, enable_dhcp: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreEthernet::set_dhcp_enable(self, enable_dhcp)
+    }
+
+    fn get_dhcp_enable(&self) -> impl Future<Output = Result</* use_dhcp= */ bool, Error>> + Send {
+        NimbusCoreEthernet::get_dhcp_enable(self)
+    }
+
+    fn set_host_name(&self, host_name: &str) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreEthernet::set_host_name(self, host_name)
+    }
+
+    fn get_host_name(&self) -> impl Future<Output = Result</* host_name= */ String, Error>> + Send {
+        NimbusCoreEthernet::get_host_name(self)
+    }
+
+    fn get_current_host_name(
+        &self,
+    ) -> impl Future<Output = Result</* host_name= */ String, Error>> + Send {
+        NimbusCoreEthernet::get_current_host_name(self)
+    }
+
+    fn get_default_host_name(
+        &self,
+    ) -> impl Future<Output = Result</* host_name= */ String, Error>> + Send {
+        NimbusCoreEthernet::get_default_host_name(self)
+    }
+
+    fn set_static_ip_address(
+        &self,
+        ip_address: &str,
+        subnet_mask: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreEthernet::set_static_ip_address(self, ip_address, subnet_mask)
+    }
+
+    fn get_static_ip_address(
+        &self,
+    ) -> impl Future<Output = Result<GetStaticIpAddressReply, Error>> + Send {
+        NimbusCoreEthernet::get_static_ip_address(self)
+    }
+
+    fn get_current_ip_address(
+        &self,
+    ) -> impl Future<Output = Result<GetCurrentIpAddressReply, Error>> + Send {
+        NimbusCoreEthernet::get_current_ip_address(self)
+    }
+
+    fn get_mac_address(
+        &self,
+    ) -> impl Future<Output = Result</* mac_address= */ String, Error>> + Send {
+        NimbusCoreEthernet::get_mac_address(self)
+    }
+
+    fn set_dhcp_timeout(
+        &self,
+        dhcp_timeout: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreEthernet::set_dhcp_timeout(self, dhcp_timeout)
+    }
+
+    fn get_dhcp_timeout(
+        &self,
+    ) -> impl Future<Output = Result</* dhcp_timeout= */ i32, Error>> + Send {
+        NimbusCoreEthernet::get_dhcp_timeout(self)
+    }
+
+    fn set_location(&self, location: &str) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreEthernet::set_location(self, location)
+    }
+
+    fn get_location(&self) -> impl Future<Output = Result</* location= */ String, Error>> + Send {
+        NimbusCoreEthernet::get_location(self)
+    }
+
+    fn set_comment(&self, comment: &str) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreEthernet::set_comment(self, comment)
+    }
+
+    fn get_comment(&self) -> impl Future<Output = Result</* comment= */ String, Error>> + Send {
+        NimbusCoreEthernet::get_comment(self)
+    }
+
+    fn set_instrument_id(
+        &self,
+        instrument_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreEthernet::set_instrument_id(self, instrument_id)
+    }
+
+    fn get_instrument_id(
+        &self,
+    ) -> impl Future<Output = Result</* instrument_id= */ String, Error>> + Send {
+        NimbusCoreEthernet::get_instrument_id(self)
+    }
+
+    fn get_instrument_type(
+        &self,
+    ) -> impl Future<Output = Result</* instrument_type= */ String, Error>> + Send {
+        NimbusCoreEthernet::get_instrument_type(self)
+    }
+
+    fn save_settings(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreEthernet::save_settings(self)
+    }
+
+    fn recall_settings(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreEthernet::recall_settings(self)
+    }
+
+    fn invalidate_settings(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreEthernet::invalidate_settings(self)
+    }
+
+    fn are_stored_settings_valid(
+        &self,
+    ) -> impl Future<Output = Result</* valid= */ bool, Error>> + Send {
+        NimbusCoreEthernet::are_stored_settings_valid(self)
+    }
+
+    fn get_tcp_port(&self) -> impl Future<Output = Result</* port= */ u16, Error>> + Send {
+        NimbusCoreEthernet::get_tcp_port(self)
+    }
+
+    fn set_tcp_port(&self, port: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreEthernet::set_tcp_port(self, port)
+    }
+
+    fn get_udp_port(&self) -> impl Future<Output = Result</* port= */ u16, Error>> + Send {
+        NimbusCoreEthernet::get_udp_port(self)
+    }
+
+    fn set_udp_port(&self, port: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreEthernet::set_udp_port(self, port)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreEthernet::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreEthernet::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreEthernet::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreEthernet::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreEthernet::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreEthernet::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreEthernetFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreEthernetApi for NimbusCoreEthernetFake {
+    fn set_dhcp_enable(&self, enable_dhcp: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_dhcp_enable",
+            vec![("enable_dhcp", format!("{:?}", enable_dhcp))],
+        );
+        async move { reply }
+    }
+
+    fn get_dhcp_enable(&self) -> impl Future<Output = Result</* use_dhcp= */ bool, Error>> + Send {
+        let reply = self.recorder.record("get_dhcp_enable", vec![]);
+        async move { reply }
+    }
+
+    fn set_host_name(&self, host_name: &str) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_host_name",
+            vec![("host_name", format!("{:?}", host_name))],
+        );
+        async move { reply }
+    }
+
+    fn get_host_name(&self) -> impl Future<Output = Result</* host_name= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_host_name", vec![]);
+        async move { reply }
+    }
+
+    fn get_current_host_name(
+        &self,
+    ) -> impl Future<Output = Result</* host_name= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_current_host_name", vec![]);
+        async move { reply }
+    }
+
+    fn get_default_host_name(
+        &self,
+    ) -> impl Future<Output = Result</* host_name= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_default_host_name", vec![]);
+        async move { reply }
+    }
+
+    fn set_static_ip_address(
+        &self,
+        ip_address: &str,
+        subnet_mask: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_static_ip_address",
+            vec![
+                ("ip_address", format!("{:?}", ip_address)),
+                ("subnet_mask", format!("{:?}", subnet_mask)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_static_ip_address(
+        &self,
+    ) -> impl Future<Output = Result<GetStaticIpAddressReply, Error>> + Send {
+        let reply = self.recorder.record("get_static_ip_address", vec![]);
+        async move { reply }
+    }
+
+    fn get_current_ip_address(
+        &self,
+    ) -> impl Future<Output = Result<GetCurrentIpAddressReply, Error>> + Send {
+        let reply = self.recorder.record("get_current_ip_address", vec![]);
+        async move { reply }
+    }
+
+    fn get_mac_address(
+        &self,
+    ) -> impl Future<Output = Result</* mac_address= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_mac_address", vec![]);
+        async move { reply }
+    }
+
+    fn set_dhcp_timeout(
+        &self,
+        dhcp_timeout: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_dhcp_timeout",
+            vec![("dhcp_timeout", format!("{:?}", dhcp_timeout))],
+        );
+        async move { reply }
+    }
+
+    fn get_dhcp_timeout(
+        &self,
+    ) -> impl Future<Output = Result</* dhcp_timeout= */ i32, Error>> + Send {
+        let reply = self.recorder.record("get_dhcp_timeout", vec![]);
+        async move { reply }
+    }
+
+    fn set_location(&self, location: &str) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_location",
+            vec![("location", format!("{:?}", location))],
+        );
+        async move { reply }
+    }
+
+    fn get_location(&self) -> impl Future<Output = Result</* location= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_location", vec![]);
+        async move { reply }
+    }
+
+    fn set_comment(&self, comment: &str) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("set_comment", vec![("comment", format!("{:?}", comment))]);
+        async move { reply }
+    }
+
+    fn get_comment(&self) -> impl Future<Output = Result</* comment= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_comment", vec![]);
+        async move { reply }
+    }
+
+    fn set_instrument_id(
+        &self,
+        instrument_id: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_instrument_id",
+            vec![("instrument_id", format!("{:?}", instrument_id))],
+        );
+        async move { reply }
+    }
+
+    fn get_instrument_id(
+        &self,
+    ) -> impl Future<Output = Result</* instrument_id= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_instrument_id", vec![]);
+        async move { reply }
+    }
+
+    fn get_instrument_type(
+        &self,
+    ) -> impl Future<Output = Result</* instrument_type= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_instrument_type", vec![]);
+        async move { reply }
+    }
+
+    fn save_settings(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("save_settings", vec![]);
+        async move { reply }
+    }
+
+    fn recall_settings(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("recall_settings", vec![]);
+        async move { reply }
+    }
+
+    fn invalidate_settings(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("invalidate_settings", vec![]);
+        async move { reply }
+    }
+
+    fn are_stored_settings_valid(
+        &self,
+    ) -> impl Future<Output = Result</* valid= */ bool, Error>> + Send {
+        let reply = self.recorder.record("are_stored_settings_valid", vec![]);
+        async move { reply }
+    }
+
+    fn get_tcp_port(&self) -> impl Future<Output = Result</* port= */ u16, Error>> + Send {
+        let reply = self.recorder.record("get_tcp_port", vec![]);
+        async move { reply }
+    }
+
+    fn set_tcp_port(&self, port: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("set_tcp_port", vec![("port", format!("{:?}", port))]);
+        async move { reply }
+    }
+
+    fn get_udp_port(&self) -> impl Future<Output = Result</* port= */ u16, Error>> + Send {
+        let reply = self.recorder.record("get_udp_port", vec![]);
+        async move { reply }
+    }
+
+    fn set_udp_port(&self, port: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("set_udp_port", vec![("port", format!("{:?}", port))]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetStaticIpAddressReply {
     pub ip_address: String,
     pub subnet_mask: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetCurrentIpAddressReply {
     pub ip_address: String,
     pub subnet_mask: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -660,8 +1257,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -671,23 +1268,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -695,8 +1292,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
@@ -1,10 +1,9 @@
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -12,10 +11,13 @@ use std::sync::Arc;
 pub struct NimbusCoreGlobalObjects {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreGlobalObjects {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "NimbusCoreGlobalObjects";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -25,18 +27,40 @@ impl NimbusCoreGlobalObjects {
                 object_id: 48897,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCoreGlobalObjects").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGlobalObjects.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -54,7 +78,9 @@ impl NimbusCoreGlobalObjects {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -65,7 +91,7 @@ impl NimbusCoreGlobalObjects {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -87,7 +113,9 @@ impl NimbusCoreGlobalObjects {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -98,7 +126,7 @@ impl NimbusCoreGlobalObjects {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -113,12 +141,14 @@ impl NimbusCoreGlobalObjects {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGlobalObjects.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -132,7 +162,9 @@ impl NimbusCoreGlobalObjects {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -143,7 +175,7 @@ impl NimbusCoreGlobalObjects {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -161,7 +193,9 @@ impl NimbusCoreGlobalObjects {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -172,7 +206,7 @@ impl NimbusCoreGlobalObjects {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -187,187 +221,154 @@ impl NimbusCoreGlobalObjects {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub enum Rail {
-    Left = 0,
-    Right = 1,
-}
-
-impl TryFrom<i32> for Rail {
-    type Error = piglet_client::client::Error;
-
-    fn try_from(v: i32) -> Result<Self, Self::Error> {
-        match v {
-            0 => Ok(Rail::Left),
-            1 => Ok(Rail::Right),
-
-            _ => Err(ConnectionError(anyhow!("Unknown Rail value {}", v))),
-        }
+// The methods above as a trait, so code using them can be tested against NimbusCoreGlobalObjectsFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreGlobalObjectsApi {
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreGlobalObjectsApi for NimbusCoreGlobalObjects {
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreGlobalObjects::object_info(self)
     }
-}
 
-impl PigletCodec for Rail {
-    const TYPE_ID: u8 = 32;
-}
-
-impl PigletDeserialize for Rail {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        stream.get_i32_le().try_into()
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreGlobalObjects::method_info(self, method)
     }
-}
 
-impl PigletSerialize for Rail {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        stream.put_u16_le(4);
-        stream.put_i32_le(*self as i32);
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreGlobalObjects::sub_object_info(self, subobject)
     }
-}
 
-impl PigletCodec for MSlice<'_, Rail> {
-    const TYPE_ID: u8 = 35;
-}
-
-impl PigletCodec for MVec<Rail> {
-    const TYPE_ID: u8 = 35;
-}
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreGlobalObjects::interface_descriptors(self)
+    }
 
-impl PigletDeserialize for MVec<Rail> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut arr = Vec::new();
-        for i in 0..(length / 4) {
-            arr.push(stream.get_i32_le().try_into()?);
-        }
-        Ok(MVec(arr))
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreGlobalObjects::enum_info(self, interface_id)
     }
-}
 
-impl PigletSerialize for MSlice<'_, Rail> {
-    fn serialize(&self, bytes: &mut BytesMut) {
-        bytes.put_u8(Self::TYPE_ID);
-        bytes.put_u8(0);
-        bytes.put_u16_le(4 * self.0.len() as u16);
-        for v in self.0.as_ref() {
-            bytes.put_i32_le(*v as i32);
-        }
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreGlobalObjects::struct_info(self, interface_id)
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub enum ChannelType {
-    None = 0,
-    Channel300ul = 1,
-    Channel1000ul = 2,
-    Channel5000ul = 3,
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreGlobalObjectsFake {
+    pub recorder: piglet_client::testing::Recorder,
 }
 
-impl TryFrom<i32> for ChannelType {
-    type Error = piglet_client::client::Error;
-
-    fn try_from(v: i32) -> Result<Self, Self::Error> {
-        match v {
-            0 => Ok(ChannelType::None),
-            1 => Ok(ChannelType::Channel300ul),
-            2 => Ok(ChannelType::Channel1000ul),
-            3 => Ok(ChannelType::Channel5000ul),
-
-            _ => Err(ConnectionError(anyhow!("Unknown ChannelType value {}", v))),
-        }
+#[cfg(feature = "testing")]
+impl NimbusCoreGlobalObjectsApi for NimbusCoreGlobalObjectsFake {
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
     }
-}
 
-impl PigletCodec for ChannelType {
-    const TYPE_ID: u8 = 32;
-}
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
 
-impl PigletDeserialize for ChannelType {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        stream.get_i32_le().try_into()
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
     }
-}
 
-impl PigletSerialize for ChannelType {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        stream.put_u16_le(4);
-        stream.put_i32_le(*self as i32);
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
     }
-}
 
-impl PigletCodec for MSlice<'_, ChannelType> {
-    const TYPE_ID: u8 = 35;
-}
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
 
-impl PigletCodec for MVec<ChannelType> {
-    const TYPE_ID: u8 = 35;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
 }
 
-impl PigletDeserialize for MVec<ChannelType> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut arr = Vec::new();
-        for i in 0..(length / 4) {
-            arr.push(stream.get_i32_le().try_into()?);
-        }
-        Ok(MVec(arr))
-    }
+#[derive(Clone, Copy, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rail {
+    Left = 0,
+    Right = 1,
 }
 
-impl PigletSerialize for MSlice<'_, ChannelType> {
-    fn serialize(&self, bytes: &mut BytesMut) {
-        bytes.put_u8(Self::TYPE_ID);
-        bytes.put_u8(0);
-        bytes.put_u16_le(4 * self.0.len() as u16);
-        for v in self.0.as_ref() {
-            bytes.put_i32_le(*v as i32);
-        }
-    }
+#[derive(Clone, Copy, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChannelType {
+    None = 0,
+    Channel300ul = 1,
+    Channel1000ul = 2,
+    Channel5000ul = 3,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChannelConfiguration {
     pub type_: ChannelType,
     pub rail: Rail,
@@ -376,120 +377,8 @@ pub struct ChannelConfiguration {
     pub can_address: u8,
 }
 
-impl PigletCodec for ChannelConfiguration {
-    const TYPE_ID: u8 = 30;
-}
-
-impl PigletSerialize for ChannelConfiguration {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        let mut buffer = BytesMut::new();
-        let s = self;
-
-        s.type_.serialize(&mut buffer);
-        s.rail.serialize(&mut buffer);
-        s.previous_neighbor_spacing.serialize(&mut buffer);
-        s.next_neighbor_spacing.serialize(&mut buffer);
-        s.can_address.serialize(&mut buffer);
-
-        stream.put_u16_le(buffer.len() as u16);
-        stream.put(buffer);
-    }
-}
-
-impl PigletDeserialize for ChannelConfiguration {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(Self {
-            type_: ChannelType::deserialize(&mut bytes)?,
-            rail: Rail::deserialize(&mut bytes)?,
-            previous_neighbor_spacing: u16::deserialize(&mut bytes)?,
-            next_neighbor_spacing: u16::deserialize(&mut bytes)?,
-            can_address: u8::deserialize(&mut bytes)?,
-        })
-    }
-}
-
-impl PigletCodec for MSlice<'_, ChannelConfiguration> {
-    const TYPE_ID: u8 = 31;
-}
-
-impl PigletCodec for MVec<ChannelConfiguration> {
-    const TYPE_ID: u8 = 31;
-}
-
-impl PigletSerialize for MSlice<'_, ChannelConfiguration> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-
-        let mut outer = BytesMut::new();
-        for s in self.0.as_ref() {
-            let mut buffer = BytesMut::new();
-
-            s.type_.serialize(&mut buffer);
-            s.rail.serialize(&mut buffer);
-            s.previous_neighbor_spacing.serialize(&mut buffer);
-            s.next_neighbor_spacing.serialize(&mut buffer);
-            s.can_address.serialize(&mut buffer);
-
-            outer.put_u16_le(buffer.len() as u16);
-            outer.put(buffer);
-        }
-
-        stream.put_u16_le(outer.len() as u16);
-        stream.put(outer);
-    }
-}
-
-impl PigletSerialize for MVec<ChannelConfiguration> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        MSlice(&self.0).serialize(stream)
-    }
-}
-
-impl PigletDeserialize for MVec<ChannelConfiguration> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let mut length = stream.get_u16_le() as usize;
-        let mut outer = stream.copy_to_bytes(length);
-        let mut arr = Vec::new();
-        while length > 0 {
-            let mut length = outer.get_u16_le() as usize;
-            let mut bytes = outer.copy_to_bytes(length);
-            arr.push(ChannelConfiguration {
-                type_: ChannelType::deserialize(&mut bytes)?,
-                rail: Rail::deserialize(&mut bytes)?,
-                previous_neighbor_spacing: u16::deserialize(&mut bytes)?,
-                next_neighbor_spacing: u16::deserialize(&mut bytes)?,
-                can_address: u8::deserialize(&mut bytes)?,
-            });
-        }
-        Ok(MVec(arr))
-    }
-}
-
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -497,8 +386,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -508,23 +397,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -532,8 +421,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
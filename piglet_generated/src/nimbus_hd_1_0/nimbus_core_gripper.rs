@@ -1,14 +1,14 @@
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
+use piglet_client::units::Millimeters;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +16,13 @@ use std::sync::Arc;
 pub struct NimbusCoreGripper {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreGripper {
-    // version 0.2
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Gripper";
+    pub const VERSION: &'static str = "0.2";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,18 +32,40 @@ impl NimbusCoreGripper {
                 object_id: 264,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Gripper").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn initialize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripper.Initialize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -48,12 +73,14 @@ impl NimbusCoreGripper {
     pub async fn initialize_smart(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripper.InitializeSmart()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -61,12 +88,14 @@ impl NimbusCoreGripper {
     pub async fn park(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripper.Park()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -74,26 +103,32 @@ impl NimbusCoreGripper {
     pub async fn pick_up_plate(
         &self,
 
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
         grip_angle: i32,
-        plate_width: i32,
-        channel_traverse_height: i32,
-        gripper_traverse_height: i32,
-        final_z_position: i32,
+        plate_width: Millimeters,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
         grip_angle.serialize(&mut args);
-        plate_width.serialize(&mut args);
-        channel_traverse_height.serialize(&mut args);
-        gripper_traverse_height.serialize(&mut args);
-        final_z_position.serialize(&mut args);
+        plate_width.to_wire::<i32>()?.serialize(&mut args);
+        channel_traverse_height
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        gripper_traverse_height
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        final_z_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -113,7 +148,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -121,26 +156,32 @@ impl NimbusCoreGripper {
     pub async fn drop_plate(
         &self,
 
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
         grip_angle: i32,
-        plate_width: i32,
-        channel_traverse_height: i32,
-        gripper_traverse_height: i32,
-        final_z_position: i32,
+        plate_width: Millimeters,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
         grip_angle.serialize(&mut args);
-        plate_width.serialize(&mut args);
-        channel_traverse_height.serialize(&mut args);
-        gripper_traverse_height.serialize(&mut args);
-        final_z_position.serialize(&mut args);
+        plate_width.to_wire::<i32>()?.serialize(&mut args);
+        channel_traverse_height
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        gripper_traverse_height
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        final_z_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -160,7 +201,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -168,30 +209,36 @@ impl NimbusCoreGripper {
     pub async fn pick_up_plate_force(
         &self,
 
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
         grip_angle: i32,
-        plate_width: i32,
-        grip_open_distance: i32,
+        plate_width: Millimeters,
+        grip_open_distance: Millimeters,
         force: i32,
-        channel_traverse_height: i32,
-        gripper_traverse_height: i32,
-        final_z_position: i32,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
         grip_angle.serialize(&mut args);
-        plate_width.serialize(&mut args);
-        grip_open_distance.serialize(&mut args);
+        plate_width.to_wire::<i32>()?.serialize(&mut args);
+        grip_open_distance.to_wire::<i32>()?.serialize(&mut args);
         force.serialize(&mut args);
-        channel_traverse_height.serialize(&mut args);
-        gripper_traverse_height.serialize(&mut args);
-        final_z_position.serialize(&mut args);
+        channel_traverse_height
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        gripper_traverse_height
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        final_z_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -213,36 +260,97 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn pick_up_plate_force_with(
+        &self,
+        request: &PickUpPlateForceRequest,
+    ) -> Result<(), Error> {
+        self.pick_up_plate_force(
+            piglet_client::client::required(
+                request.x_position,
+                "PickUpPlateForceRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_position,
+                "PickUpPlateForceRequest",
+                "y_position",
+            )?,
+            piglet_client::client::required(
+                request.z_position,
+                "PickUpPlateForceRequest",
+                "z_position",
+            )?,
+            piglet_client::client::required(
+                request.grip_angle,
+                "PickUpPlateForceRequest",
+                "grip_angle",
+            )?,
+            piglet_client::client::required(
+                request.plate_width,
+                "PickUpPlateForceRequest",
+                "plate_width",
+            )?,
+            piglet_client::client::required(
+                request.grip_open_distance,
+                "PickUpPlateForceRequest",
+                "grip_open_distance",
+            )?,
+            piglet_client::client::required(request.force, "PickUpPlateForceRequest", "force")?,
+            piglet_client::client::required(
+                request.channel_traverse_height,
+                "PickUpPlateForceRequest",
+                "channel_traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.gripper_traverse_height,
+                "PickUpPlateForceRequest",
+                "gripper_traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.final_z_position,
+                "PickUpPlateForceRequest",
+                "final_z_position",
+            )?,
+        )
+        .await
+    }
+
     pub async fn drop_plate_force(
         &self,
 
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
         grip_angle: i32,
-        plate_width: i32,
-        grip_open_distance: i32,
-        channel_traverse_height: i32,
-        gripper_traverse_height: i32,
-        final_z_position: i32,
+        plate_width: Millimeters,
+        grip_open_distance: Millimeters,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
         grip_angle.serialize(&mut args);
-        plate_width.serialize(&mut args);
-        grip_open_distance.serialize(&mut args);
-        channel_traverse_height.serialize(&mut args);
-        gripper_traverse_height.serialize(&mut args);
-        final_z_position.serialize(&mut args);
+        plate_width.to_wire::<i32>()?.serialize(&mut args);
+        grip_open_distance.to_wire::<i32>()?.serialize(&mut args);
+        channel_traverse_height
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        gripper_traverse_height
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        final_z_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -263,30 +371,90 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn drop_plate_force_with(
+        &self,
+        request: &DropPlateForceRequest,
+    ) -> Result<(), Error> {
+        self.drop_plate_force(
+            piglet_client::client::required(
+                request.x_position,
+                "DropPlateForceRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_position,
+                "DropPlateForceRequest",
+                "y_position",
+            )?,
+            piglet_client::client::required(
+                request.z_position,
+                "DropPlateForceRequest",
+                "z_position",
+            )?,
+            piglet_client::client::required(
+                request.grip_angle,
+                "DropPlateForceRequest",
+                "grip_angle",
+            )?,
+            piglet_client::client::required(
+                request.plate_width,
+                "DropPlateForceRequest",
+                "plate_width",
+            )?,
+            piglet_client::client::required(
+                request.grip_open_distance,
+                "DropPlateForceRequest",
+                "grip_open_distance",
+            )?,
+            piglet_client::client::required(
+                request.channel_traverse_height,
+                "DropPlateForceRequest",
+                "channel_traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.gripper_traverse_height,
+                "DropPlateForceRequest",
+                "gripper_traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.final_z_position,
+                "DropPlateForceRequest",
+                "final_z_position",
+            )?,
+        )
+        .await
+    }
+
     pub async fn move_to_position(
         &self,
 
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
         wrist_angle: i32,
-        channel_traverse_height: i32,
-        gripper_traverse_height: i32,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
         wrist_angle.serialize(&mut args);
-        channel_traverse_height.serialize(&mut args);
-        gripper_traverse_height.serialize(&mut args);
+        channel_traverse_height
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        gripper_traverse_height
+            .to_wire::<i32>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 8, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -304,7 +472,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -312,22 +480,28 @@ impl NimbusCoreGripper {
     pub async fn move_to_position_zw(
         &self,
 
-        x_position: i32,
-        y_position: i32,
-        z_position: i32,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
         wrist_angle: i32,
-        channel_traverse_height: i32,
-        gripper_traverse_height: i32,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
-        z_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
+        z_position.to_wire::<i32>()?.serialize(&mut args);
         wrist_angle.serialize(&mut args);
-        channel_traverse_height.serialize(&mut args);
-        gripper_traverse_height.serialize(&mut args);
+        channel_traverse_height
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        gripper_traverse_height
+            .to_wire::<i32>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 9, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -345,17 +519,24 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn xy_move_absolute(&self, x_position: i32, y_position: i32) -> Result<(), Error> {
+    pub async fn xy_move_absolute(
+        &self,
+
+        x_position: Millimeters,
+        y_position: Millimeters,
+    ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 10, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -369,7 +550,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -377,21 +558,25 @@ impl NimbusCoreGripper {
     pub async fn x_initialize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 11, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripper.XInitialize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn x_move_absolute(&self, position: i32) -> Result<(), Error> {
+    pub async fn x_move_absolute(&self, position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 12, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -402,16 +587,18 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn x_move_relative(&self, position: i32) -> Result<(), Error> {
+    pub async fn x_move_relative(&self, position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 13, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 13, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -422,7 +609,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -430,21 +617,25 @@ impl NimbusCoreGripper {
     pub async fn y_initialize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 14, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 14, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripper.YInitialize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn y_move_absolute(&self, position: i32) -> Result<(), Error> {
+    pub async fn y_move_absolute(&self, position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 15, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 15, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -455,16 +646,18 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn y_move_relative(&self, position: i32) -> Result<(), Error> {
+    pub async fn y_move_relative(&self, position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 16, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 16, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -475,7 +668,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -483,21 +676,25 @@ impl NimbusCoreGripper {
     pub async fn z_initialize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 17, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 17, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripper.ZInitialize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn z_move_absolute(&self, position: i32) -> Result<(), Error> {
+    pub async fn z_move_absolute(&self, position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 18, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 18, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -508,16 +705,18 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn z_move_relative(&self, position: i32) -> Result<(), Error> {
+    pub async fn z_move_relative(&self, position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 19, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 19, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -528,7 +727,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -536,12 +735,14 @@ impl NimbusCoreGripper {
     pub async fn z_up(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 20, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 20, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripper.ZUp()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -549,12 +750,14 @@ impl NimbusCoreGripper {
     pub async fn w_initialize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 21, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 21, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripper.WInitialize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -563,7 +766,9 @@ impl NimbusCoreGripper {
         let mut args = BytesMut::new();
         position.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 22, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 22, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -574,7 +779,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -583,7 +788,9 @@ impl NimbusCoreGripper {
         let mut args = BytesMut::new();
         position.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 23, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 23, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -594,7 +801,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -602,21 +809,25 @@ impl NimbusCoreGripper {
     pub async fn g_initialize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 24, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 24, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripper.GInitialize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn g_move_absolute(&self, position: i32) -> Result<(), Error> {
+    pub async fn g_move_absolute(&self, position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 25, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 25, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -627,16 +838,18 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn g_move_relative(&self, position: i32) -> Result<(), Error> {
+    pub async fn g_move_relative(&self, position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 26, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 26, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -647,7 +860,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -655,18 +868,20 @@ impl NimbusCoreGripper {
     pub async fn get_desired_position(&self) -> Result<GetDesiredPositionReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 27, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 27, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripper.GetDesiredPosition()".to_string(),
         )?;
 
         if count != 5 {
-            return Err(ConnectionError(anyhow!("Expected 5 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 5 values, not {}", count)));
         }
-        let x_position = i32::deserialize(&mut stream)?;
-        let y_position = i32::deserialize(&mut stream)?;
-        let z_position = i32::deserialize(&mut stream)?;
-        let w_position = i32::deserialize(&mut stream)?;
-        let g_position = i32::deserialize(&mut stream)?;
+        let x_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let y_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let z_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let w_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let g_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
         Ok(GetDesiredPositionReply {
             x_position,
             y_position,
@@ -680,7 +895,9 @@ impl NimbusCoreGripper {
         let mut args = BytesMut::new();
         smart.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 28, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 28, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  smart: {:?}", smart)];
                 format!(
@@ -691,7 +908,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -699,12 +916,14 @@ impl NimbusCoreGripper {
     pub async fn w_hold(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 29, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 29, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripper.WHold()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -713,7 +932,9 @@ impl NimbusCoreGripper {
         let mut args = BytesMut::new();
         tips.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 30, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 30, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips: {:?}", tips)];
                 format!(
@@ -724,7 +945,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let z_safe = bool::deserialize(&mut stream)?;
         Ok(z_safe)
@@ -733,12 +954,14 @@ impl NimbusCoreGripper {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripper.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -756,7 +979,9 @@ impl NimbusCoreGripper {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -767,7 +992,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -789,7 +1014,9 @@ impl NimbusCoreGripper {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -800,7 +1027,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -815,12 +1042,14 @@ impl NimbusCoreGripper {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripper.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -834,7 +1063,9 @@ impl NimbusCoreGripper {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -845,7 +1076,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -863,7 +1094,9 @@ impl NimbusCoreGripper {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -874,7 +1107,7 @@ impl NimbusCoreGripper {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -889,18 +1122,1154 @@ impl NimbusCoreGripper {
     }
 }
 
-#[allow(non_camel_case_types)]
+// The methods above as a trait, so code using them can be tested against NimbusCoreGripperFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreGripperApi {
+    fn initialize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn initialize_smart(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn park(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pick_up_plate(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_angle: i32,
+        plate_width: Millimeters,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn drop_plate(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_angle: i32,
+        plate_width: Millimeters,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pick_up_plate_force(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_angle: i32,
+        plate_width: Millimeters,
+        grip_open_distance: Millimeters,
+        force: i32,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn pick_up_plate_force_with(
+        &self,
+        request: &PickUpPlateForceRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.pick_up_plate_force(
+                piglet_client::client::required(
+                    request.x_position,
+                    "PickUpPlateForceRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_position,
+                    "PickUpPlateForceRequest",
+                    "y_position",
+                )?,
+                piglet_client::client::required(
+                    request.z_position,
+                    "PickUpPlateForceRequest",
+                    "z_position",
+                )?,
+                piglet_client::client::required(
+                    request.grip_angle,
+                    "PickUpPlateForceRequest",
+                    "grip_angle",
+                )?,
+                piglet_client::client::required(
+                    request.plate_width,
+                    "PickUpPlateForceRequest",
+                    "plate_width",
+                )?,
+                piglet_client::client::required(
+                    request.grip_open_distance,
+                    "PickUpPlateForceRequest",
+                    "grip_open_distance",
+                )?,
+                piglet_client::client::required(request.force, "PickUpPlateForceRequest", "force")?,
+                piglet_client::client::required(
+                    request.channel_traverse_height,
+                    "PickUpPlateForceRequest",
+                    "channel_traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.gripper_traverse_height,
+                    "PickUpPlateForceRequest",
+                    "gripper_traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.final_z_position,
+                    "PickUpPlateForceRequest",
+                    "final_z_position",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn drop_plate_force(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_angle: i32,
+        plate_width: Millimeters,
+        grip_open_distance: Millimeters,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn drop_plate_force_with(
+        &self,
+        request: &DropPlateForceRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.drop_plate_force(
+                piglet_client::client::required(
+                    request.x_position,
+                    "DropPlateForceRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_position,
+                    "DropPlateForceRequest",
+                    "y_position",
+                )?,
+                piglet_client::client::required(
+                    request.z_position,
+                    "DropPlateForceRequest",
+                    "z_position",
+                )?,
+                piglet_client::client::required(
+                    request.grip_angle,
+                    "DropPlateForceRequest",
+                    "grip_angle",
+                )?,
+                piglet_client::client::required(
+                    request.plate_width,
+                    "DropPlateForceRequest",
+                    "plate_width",
+                )?,
+                piglet_client::client::required(
+                    request.grip_open_distance,
+                    "DropPlateForceRequest",
+                    "grip_open_distance",
+                )?,
+                piglet_client::client::required(
+                    request.channel_traverse_height,
+                    "DropPlateForceRequest",
+                    "channel_traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.gripper_traverse_height,
+                    "DropPlateForceRequest",
+                    "gripper_traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.final_z_position,
+                    "DropPlateForceRequest",
+                    "final_z_position",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn move_to_position(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        wrist_angle: i32,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_to_position_zw(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        wrist_angle: i32,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn xy_move_absolute(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn x_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn x_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn x_move_relative(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_relative(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_relative(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_up(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn w_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn w_move_absolute(&self, position: i32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn w_move_relative(&self, position: i32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn g_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn g_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn g_move_relative(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_desired_position(
+        &self,
+    ) -> impl Future<Output = Result<GetDesiredPositionReply, Error>> + Send;
+    fn initialize_zwy(&self, smart: bool) -> impl Future<Output = Result<(), Error>> + Send;
+    fn w_hold(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn w_hold_2(
+        &self,
+        tips: bool,
+    ) -> impl Future<Output = Result</* z_safe= */ bool, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreGripperApi for NimbusCoreGripper {
+    fn initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::initialize(self)
+    }
+
+    fn initialize_smart(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::initialize_smart(self)
+    }
+
+    fn park(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::park(self)
+    }
+
+    fn pick_up_plate(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_angle: i32,
+        plate_width: Millimeters,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::pick_up_plate(
+            self,
+            x_position,
+            y_position,
+            z_position,
+            grip_angle,
+            plate_width,
+            channel_traverse_height,
+            gripper_traverse_height,
+            final_z_position,
+        )
+    }
+
+    fn drop_plate(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_angle: i32,
+        plate_width: Millimeters,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::drop_plate(
+            self,
+            x_position,
+            y_position,
+            z_position,
+            grip_angle,
+            plate_width,
+            channel_traverse_height,
+            gripper_traverse_height,
+            final_z_position,
+        )
+    }
+
+    fn pick_up_plate_force(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_angle: i32,
+        plate_width: Millimeters,
+        grip_open_distance: Millimeters,
+        force: i32,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::pick_up_plate_force(
+            self,
+            x_position,
+            y_position,
+            z_position,
+            grip_angle,
+            plate_width,
+            grip_open_distance,
+            force,
+            channel_traverse_height,
+            gripper_traverse_height,
+            final_z_position,
+        )
+    }
+
+    fn drop_plate_force(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_angle: i32,
+        plate_width: Millimeters,
+        grip_open_distance: Millimeters,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::drop_plate_force(
+            self,
+            x_position,
+            y_position,
+            z_position,
+            grip_angle,
+            plate_width,
+            grip_open_distance,
+            channel_traverse_height,
+            gripper_traverse_height,
+            final_z_position,
+        )
+    }
+
+    fn move_to_position(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        wrist_angle: i32,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::move_to_position(
+            self,
+            x_position,
+            y_position,
+            z_position,
+            wrist_angle,
+            channel_traverse_height,
+            gripper_traverse_height,
+        )
+    }
+
+    fn move_to_position_zw(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        wrist_angle: i32,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::move_to_position_zw(
+            self,
+            x_position,
+            y_position,
+            z_position,
+            wrist_angle,
+            channel_traverse_height,
+            gripper_traverse_height,
+        )
+    }
+
+    fn xy_move_absolute(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::xy_move_absolute(self, x_position, y_position)
+    }
+
+    fn x_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::x_initialize(self)
+    }
+
+    fn x_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::x_move_absolute(self, position)
+    }
+
+    fn x_move_relative(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::x_move_relative(self, position)
+    }
+
+    fn y_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::y_initialize(self)
+    }
+
+    fn y_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::y_move_absolute(self, position)
+    }
+
+    fn y_move_relative(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::y_move_relative(self, position)
+    }
+
+    fn z_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::z_initialize(self)
+    }
+
+    fn z_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::z_move_absolute(self, position)
+    }
+
+    fn z_move_relative(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::z_move_relative(self, position)
+    }
+
+    fn z_up(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::z_up(self)
+    }
+
+    fn w_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::w_initialize(self)
+    }
+
+    fn w_move_absolute(&self, position: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::w_move_absolute(self, position)
+    }
+
+    fn w_move_relative(&self, position: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::w_move_relative(self, position)
+    }
+
+    fn g_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::g_initialize(self)
+    }
+
+    fn g_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::g_move_absolute(self, position)
+    }
+
+    fn g_move_relative(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::g_move_relative(self, position)
+    }
+
+    fn get_desired_position(
+        &self,
+    ) -> impl Future<Output = Result<GetDesiredPositionReply, Error>> + Send {
+        NimbusCoreGripper::get_desired_position(self)
+    }
+
+    fn initialize_zwy(&self, smart: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::initialize_zwy(self, smart)
+    }
+
+    fn w_hold(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripper::w_hold(self)
+    }
+
+    fn w_hold_2(
+        &self,
+        tips: bool,
+    ) -> impl Future<Output = Result</* z_safe= */ bool, Error>> + Send {
+        NimbusCoreGripper::w_hold_2(self, tips)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreGripper::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreGripper::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreGripper::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreGripper::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreGripper::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreGripper::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreGripperFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreGripperApi for NimbusCoreGripperFake {
+    fn initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("initialize", vec![]);
+        async move { reply }
+    }
+
+    fn initialize_smart(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("initialize_smart", vec![]);
+        async move { reply }
+    }
+
+    fn park(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("park", vec![]);
+        async move { reply }
+    }
+
+    fn pick_up_plate(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_angle: i32,
+        plate_width: Millimeters,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pick_up_plate",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("grip_angle", format!("{:?}", grip_angle)),
+                ("plate_width", format!("{:?}", plate_width)),
+                (
+                    "channel_traverse_height",
+                    format!("{:?}", channel_traverse_height),
+                ),
+                (
+                    "gripper_traverse_height",
+                    format!("{:?}", gripper_traverse_height),
+                ),
+                ("final_z_position", format!("{:?}", final_z_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn drop_plate(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_angle: i32,
+        plate_width: Millimeters,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "drop_plate",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("grip_angle", format!("{:?}", grip_angle)),
+                ("plate_width", format!("{:?}", plate_width)),
+                (
+                    "channel_traverse_height",
+                    format!("{:?}", channel_traverse_height),
+                ),
+                (
+                    "gripper_traverse_height",
+                    format!("{:?}", gripper_traverse_height),
+                ),
+                ("final_z_position", format!("{:?}", final_z_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn pick_up_plate_force(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_angle: i32,
+        plate_width: Millimeters,
+        grip_open_distance: Millimeters,
+        force: i32,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pick_up_plate_force",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("grip_angle", format!("{:?}", grip_angle)),
+                ("plate_width", format!("{:?}", plate_width)),
+                ("grip_open_distance", format!("{:?}", grip_open_distance)),
+                ("force", format!("{:?}", force)),
+                (
+                    "channel_traverse_height",
+                    format!("{:?}", channel_traverse_height),
+                ),
+                (
+                    "gripper_traverse_height",
+                    format!("{:?}", gripper_traverse_height),
+                ),
+                ("final_z_position", format!("{:?}", final_z_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn drop_plate_force(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        grip_angle: i32,
+        plate_width: Millimeters,
+        grip_open_distance: Millimeters,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+        final_z_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "drop_plate_force",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("grip_angle", format!("{:?}", grip_angle)),
+                ("plate_width", format!("{:?}", plate_width)),
+                ("grip_open_distance", format!("{:?}", grip_open_distance)),
+                (
+                    "channel_traverse_height",
+                    format!("{:?}", channel_traverse_height),
+                ),
+                (
+                    "gripper_traverse_height",
+                    format!("{:?}", gripper_traverse_height),
+                ),
+                ("final_z_position", format!("{:?}", final_z_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_to_position(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        wrist_angle: i32,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_to_position",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("wrist_angle", format!("{:?}", wrist_angle)),
+                (
+                    "channel_traverse_height",
+                    format!("{:?}", channel_traverse_height),
+                ),
+                (
+                    "gripper_traverse_height",
+                    format!("{:?}", gripper_traverse_height),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_to_position_zw(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+        z_position: Millimeters,
+        wrist_angle: i32,
+        channel_traverse_height: Millimeters,
+        gripper_traverse_height: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_to_position_zw",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+                ("z_position", format!("{:?}", z_position)),
+                ("wrist_angle", format!("{:?}", wrist_angle)),
+                (
+                    "channel_traverse_height",
+                    format!("{:?}", channel_traverse_height),
+                ),
+                (
+                    "gripper_traverse_height",
+                    format!("{:?}", gripper_traverse_height),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn xy_move_absolute(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "xy_move_absolute",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn x_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("x_initialize", vec![]);
+        async move { reply }
+    }
+
+    fn x_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "x_move_absolute",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn x_move_relative(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "x_move_relative",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn y_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("y_initialize", vec![]);
+        async move { reply }
+    }
+
+    fn y_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_absolute",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn y_move_relative(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_relative",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn z_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("z_initialize", vec![]);
+        async move { reply }
+    }
+
+    fn z_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_absolute",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn z_move_relative(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_relative",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn z_up(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("z_up", vec![]);
+        async move { reply }
+    }
+
+    fn w_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("w_initialize", vec![]);
+        async move { reply }
+    }
+
+    fn w_move_absolute(&self, position: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "w_move_absolute",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn w_move_relative(&self, position: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "w_move_relative",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn g_initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("g_initialize", vec![]);
+        async move { reply }
+    }
+
+    fn g_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "g_move_absolute",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn g_move_relative(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "g_move_relative",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn get_desired_position(
+        &self,
+    ) -> impl Future<Output = Result<GetDesiredPositionReply, Error>> + Send {
+        let reply = self.recorder.record("get_desired_position", vec![]);
+        async move { reply }
+    }
+
+    fn initialize_zwy(&self, smart: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("initialize_zwy", vec![("smart", format!("{:?}", smart))]);
+        async move { reply }
+    }
+
+    fn w_hold(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("w_hold", vec![]);
+        async move { reply }
+    }
+
+    fn w_hold_2(
+        &self,
+        tips: bool,
+    ) -> impl Future<Output = Result</* z_safe= */ bool, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("w_hold_2", vec![("tips", format!("{:?}", tips))]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PickUpPlateForceRequest {
+    pub x_position: Option<Millimeters>,
+    pub y_position: Option<Millimeters>,
+    pub z_position: Option<Millimeters>,
+    pub grip_angle: Option<i32>,
+    pub plate_width: Option<Millimeters>,
+    pub grip_open_distance: Option<Millimeters>,
+    pub force: Option<i32>,
+    pub channel_traverse_height: Option<Millimeters>,
+    pub gripper_traverse_height: Option<Millimeters>,
+    pub final_z_position: Option<Millimeters>,
+}
+
+impl PickUpPlateForceRequest {
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn y_position(mut self, y_position: Millimeters) -> Self {
+        self.y_position = Some(y_position);
+        self
+    }
+    pub fn z_position(mut self, z_position: Millimeters) -> Self {
+        self.z_position = Some(z_position);
+        self
+    }
+    pub fn grip_angle(mut self, grip_angle: i32) -> Self {
+        self.grip_angle = Some(grip_angle);
+        self
+    }
+    pub fn plate_width(mut self, plate_width: Millimeters) -> Self {
+        self.plate_width = Some(plate_width);
+        self
+    }
+    pub fn grip_open_distance(mut self, grip_open_distance: Millimeters) -> Self {
+        self.grip_open_distance = Some(grip_open_distance);
+        self
+    }
+    pub fn force(mut self, force: i32) -> Self {
+        self.force = Some(force);
+        self
+    }
+    pub fn channel_traverse_height(mut self, channel_traverse_height: Millimeters) -> Self {
+        self.channel_traverse_height = Some(channel_traverse_height);
+        self
+    }
+    pub fn gripper_traverse_height(mut self, gripper_traverse_height: Millimeters) -> Self {
+        self.gripper_traverse_height = Some(gripper_traverse_height);
+        self
+    }
+    pub fn final_z_position(mut self, final_z_position: Millimeters) -> Self {
+        self.final_z_position = Some(final_z_position);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropPlateForceRequest {
+    pub x_position: Option<Millimeters>,
+    pub y_position: Option<Millimeters>,
+    pub z_position: Option<Millimeters>,
+    pub grip_angle: Option<i32>,
+    pub plate_width: Option<Millimeters>,
+    pub grip_open_distance: Option<Millimeters>,
+    pub channel_traverse_height: Option<Millimeters>,
+    pub gripper_traverse_height: Option<Millimeters>,
+    pub final_z_position: Option<Millimeters>,
+}
+
+impl DropPlateForceRequest {
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn y_position(mut self, y_position: Millimeters) -> Self {
+        self.y_position = Some(y_position);
+        self
+    }
+    pub fn z_position(mut self, z_position: Millimeters) -> Self {
+        self.z_position = Some(z_position);
+        self
+    }
+    pub fn grip_angle(mut self, grip_angle: i32) -> Self {
+        self.grip_angle = Some(grip_angle);
+        self
+    }
+    pub fn plate_width(mut self, plate_width: Millimeters) -> Self {
+        self.plate_width = Some(plate_width);
+        self
+    }
+    pub fn grip_open_distance(mut self, grip_open_distance: Millimeters) -> Self {
+        self.grip_open_distance = Some(grip_open_distance);
+        self
+    }
+    pub fn channel_traverse_height(mut self, channel_traverse_height: Millimeters) -> Self {
+        self.channel_traverse_height = Some(channel_traverse_height);
+        self
+    }
+    pub fn gripper_traverse_height(mut self, gripper_traverse_height: Millimeters) -> Self {
+        self.gripper_traverse_height = Some(gripper_traverse_height);
+        self
+    }
+    pub fn final_z_position(mut self, final_z_position: Millimeters) -> Self {
+        self.final_z_position = Some(final_z_position);
+        self
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetDesiredPositionReply {
-    pub x_position: i32,
-    pub y_position: i32,
-    pub z_position: i32,
-    pub w_position: i32,
-    pub g_position: i32,
+    pub x_position: Millimeters,
+    pub y_position: Millimeters,
+    pub z_position: Millimeters,
+    pub w_position: Millimeters,
+    pub g_position: Millimeters,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -908,8 +2277,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -919,23 +2288,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -943,8 +2312,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
@@ -1,14 +1,14 @@
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
+use piglet_client::units::Millimeters;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +16,13 @@ use std::sync::Arc;
 pub struct NimbusCoreGripperXyCoord {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreGripperXyCoord {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "XyCoord";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,28 +32,57 @@ impl NimbusCoreGripperXyCoord {
                 object_id: 265,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Gripper.XyCoord").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn initialize_xy(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripperXyCoord.InitializeXY()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn move_xy_absolute(&self, x_position: i32, y_position: i32) -> Result<(), Error> {
+    pub async fn move_xy_absolute(
+        &self,
+
+        x_position: Millimeters,
+        y_position: Millimeters,
+    ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -64,7 +96,7 @@ impl NimbusCoreGripperXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -72,21 +104,25 @@ impl NimbusCoreGripperXyCoord {
     pub async fn initialize_x(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripperXyCoord.InitializeX()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn move_x_absolute(&self, x_position: i32) -> Result<(), Error> {
+    pub async fn move_x_absolute(&self, x_position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  x_position: {:?}", x_position)];
                 format!(
@@ -97,16 +133,18 @@ impl NimbusCoreGripperXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn move_x_relative_1(&self, x_distance: i32) -> Result<(), Error> {
+    pub async fn move_x_relative_1(&self, x_distance: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_distance.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  x_distance: {:?}", x_distance)];
                 format!(
@@ -117,16 +155,18 @@ impl NimbusCoreGripperXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn move_y_absolute(&self, y_position: i32) -> Result<(), Error> {
+    pub async fn move_y_absolute(&self, y_position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        y_position.serialize(&mut args);
+        y_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  y_position: {:?}", y_position)];
                 format!(
@@ -137,16 +177,18 @@ impl NimbusCoreGripperXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn move_y_relative_1(&self, y_distance: i32) -> Result<(), Error> {
+    pub async fn move_y_relative_1(&self, y_distance: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        y_distance.serialize(&mut args);
+        y_distance.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  y_distance: {:?}", y_distance)];
                 format!(
@@ -157,7 +199,7 @@ impl NimbusCoreGripperXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -166,7 +208,9 @@ impl NimbusCoreGripperXyCoord {
         let mut args = BytesMut::new();
         x_offset.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 8, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  x_offset: {:?}", x_offset)];
                 format!(
@@ -177,7 +221,7 @@ impl NimbusCoreGripperXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -185,12 +229,14 @@ impl NimbusCoreGripperXyCoord {
     pub async fn get_x_calibration(&self) -> Result<GetXCalibrationReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 9, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripperXyCoord.GetXCalibration()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let x_offset = i32::deserialize(&mut stream)?;
         Ok(GetXCalibrationReply { x_offset })
@@ -199,12 +245,14 @@ impl NimbusCoreGripperXyCoord {
     pub async fn is_x_initialized(&self) -> Result<IsXInitializedReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 10, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripperXyCoord.IsXInitialized()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let initialized = bool::deserialize(&mut stream)?;
         Ok(IsXInitializedReply { initialized })
@@ -213,15 +261,17 @@ impl NimbusCoreGripperXyCoord {
     pub async fn get_desired_position(&self) -> Result<GetDesiredPositionReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 11, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripperXyCoord.GetDesiredPosition()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
-        let x_position = i32::deserialize(&mut stream)?;
-        let y_position = i32::deserialize(&mut stream)?;
+        let x_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let y_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
         Ok(GetDesiredPositionReply {
             x_position,
             y_position,
@@ -231,16 +281,18 @@ impl NimbusCoreGripperXyCoord {
     pub async fn move_x_relative_2(
         &self,
 
-        x_distance: i32,
+        x_distance: Millimeters,
         acceleration: u32,
         velocity: u32,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_distance.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
         acceleration.serialize(&mut args);
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 12, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_distance: {:?}", x_distance),
@@ -255,17 +307,24 @@ impl NimbusCoreGripperXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn move_y_relative_2(&self, y_distance: i32, velocity: u32) -> Result<(), Error> {
+    pub async fn move_y_relative_2(
+        &self,
+
+        y_distance: Millimeters,
+        velocity: u32,
+    ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        y_distance.serialize(&mut args);
+        y_distance.to_wire::<i32>()?.serialize(&mut args);
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 13, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 13, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  y_distance: {:?}", y_distance),
@@ -279,7 +338,7 @@ impl NimbusCoreGripperXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -287,12 +346,14 @@ impl NimbusCoreGripperXyCoord {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripperXyCoord.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -310,7 +371,9 @@ impl NimbusCoreGripperXyCoord {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -321,7 +384,7 @@ impl NimbusCoreGripperXyCoord {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -343,7 +406,9 @@ impl NimbusCoreGripperXyCoord {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -354,7 +419,7 @@ impl NimbusCoreGripperXyCoord {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -369,12 +434,14 @@ impl NimbusCoreGripperXyCoord {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreGripperXyCoord.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -388,7 +455,9 @@ impl NimbusCoreGripperXyCoord {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -399,7 +468,7 @@ impl NimbusCoreGripperXyCoord {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -417,7 +486,9 @@ impl NimbusCoreGripperXyCoord {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -428,7 +499,7 @@ impl NimbusCoreGripperXyCoord {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -443,27 +514,407 @@ impl NimbusCoreGripperXyCoord {
     }
 }
 
-#[allow(non_camel_case_types)]
+// The methods above as a trait, so code using them can be tested against NimbusCoreGripperXyCoordFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreGripperXyCoordApi {
+    fn initialize_xy(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_xy_absolute(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn initialize_x(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_x_absolute(
+        &self,
+        x_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_x_relative_1(
+        &self,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_y_absolute(
+        &self,
+        y_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_y_relative_1(
+        &self,
+        y_distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_x_calibration(&self, x_offset: i32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_x_calibration(&self)
+    -> impl Future<Output = Result<GetXCalibrationReply, Error>> + Send;
+    fn is_x_initialized(&self) -> impl Future<Output = Result<IsXInitializedReply, Error>> + Send;
+    fn get_desired_position(
+        &self,
+    ) -> impl Future<Output = Result<GetDesiredPositionReply, Error>> + Send;
+    fn move_x_relative_2(
+        &self,
+        x_distance: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_y_relative_2(
+        &self,
+        y_distance: Millimeters,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreGripperXyCoordApi for NimbusCoreGripperXyCoord {
+    fn initialize_xy(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripperXyCoord::initialize_xy(self)
+    }
+
+    fn move_xy_absolute(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripperXyCoord::move_xy_absolute(self, x_position, y_position)
+    }
+
+    fn initialize_x(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripperXyCoord::initialize_x(self)
+    }
+
+    fn move_x_absolute(
+        &self,
+        x_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripperXyCoord::move_x_absolute(self, x_position)
+    }
+
+    fn move_x_relative_1(
+        &self,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripperXyCoord::move_x_relative_1(self, x_distance)
+    }
+
+    fn move_y_absolute(
+        &self,
+        y_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripperXyCoord::move_y_absolute(self, y_position)
+    }
+
+    fn move_y_relative_1(
+        &self,
+        y_distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripperXyCoord::move_y_relative_1(self, y_distance)
+    }
+
+    fn set_x_calibration(&self, x_offset: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripperXyCoord::set_x_calibration(self, x_offset)
+    }
+
+    fn get_x_calibration(
+        &self,
+    ) -> impl Future<Output = Result<GetXCalibrationReply, Error>> + Send {
+        NimbusCoreGripperXyCoord::get_x_calibration(self)
+    }
+
+    fn is_x_initialized(&self) -> impl Future<Output = Result<IsXInitializedReply, Error>> + Send {
+        NimbusCoreGripperXyCoord::is_x_initialized(self)
+    }
+
+    fn get_desired_position(
+        &self,
+    ) -> impl Future<Output = Result<GetDesiredPositionReply, Error>> + Send {
+        NimbusCoreGripperXyCoord::get_desired_position(self)
+    }
+
+    fn move_x_relative_2(
+        &self,
+        x_distance: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripperXyCoord::move_x_relative_2(self, x_distance, acceleration, velocity)
+    }
+
+    fn move_y_relative_2(
+        &self,
+        y_distance: Millimeters,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreGripperXyCoord::move_y_relative_2(self, y_distance, velocity)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreGripperXyCoord::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreGripperXyCoord::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreGripperXyCoord::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreGripperXyCoord::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreGripperXyCoord::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreGripperXyCoord::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreGripperXyCoordFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreGripperXyCoordApi for NimbusCoreGripperXyCoordFake {
+    fn initialize_xy(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("initialize_xy", vec![]);
+        async move { reply }
+    }
+
+    fn move_xy_absolute(
+        &self,
+        x_position: Millimeters,
+        y_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_xy_absolute",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn initialize_x(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("initialize_x", vec![]);
+        async move { reply }
+    }
+
+    fn move_x_absolute(
+        &self,
+        x_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_x_absolute",
+            vec![("x_position", format!("{:?}", x_position))],
+        );
+        async move { reply }
+    }
+
+    fn move_x_relative_1(
+        &self,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_x_relative_1",
+            vec![("x_distance", format!("{:?}", x_distance))],
+        );
+        async move { reply }
+    }
+
+    fn move_y_absolute(
+        &self,
+        y_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_y_absolute",
+            vec![("y_position", format!("{:?}", y_position))],
+        );
+        async move { reply }
+    }
+
+    fn move_y_relative_1(
+        &self,
+        y_distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_y_relative_1",
+            vec![("y_distance", format!("{:?}", y_distance))],
+        );
+        async move { reply }
+    }
+
+    fn set_x_calibration(&self, x_offset: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_x_calibration",
+            vec![("x_offset", format!("{:?}", x_offset))],
+        );
+        async move { reply }
+    }
+
+    fn get_x_calibration(
+        &self,
+    ) -> impl Future<Output = Result<GetXCalibrationReply, Error>> + Send {
+        let reply = self.recorder.record("get_x_calibration", vec![]);
+        async move { reply }
+    }
+
+    fn is_x_initialized(&self) -> impl Future<Output = Result<IsXInitializedReply, Error>> + Send {
+        let reply = self.recorder.record("is_x_initialized", vec![]);
+        async move { reply }
+    }
+
+    fn get_desired_position(
+        &self,
+    ) -> impl Future<Output = Result<GetDesiredPositionReply, Error>> + Send {
+        let reply = self.recorder.record("get_desired_position", vec![]);
+        async move { reply }
+    }
+
+    fn move_x_relative_2(
+        &self,
+        x_distance: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_x_relative_2",
+            vec![
+                ("x_distance", format!("{:?}", x_distance)),
+                ("acceleration", format!("{:?}", acceleration)),
+                ("velocity", format!("{:?}", velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_y_relative_2(
+        &self,
+        y_distance: Millimeters,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_y_relative_2",
+            vec![
+                ("y_distance", format!("{:?}", y_distance)),
+                ("velocity", format!("{:?}", velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetXCalibrationReply {
     pub x_offset: i32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IsXInitializedReply {
     pub initialized: bool,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetDesiredPositionReply {
-    pub x_position: i32,
-    pub y_position: i32,
+    pub x_position: Millimeters,
+    pub y_position: Millimeters,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -471,8 +922,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -482,23 +933,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -506,8 +957,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
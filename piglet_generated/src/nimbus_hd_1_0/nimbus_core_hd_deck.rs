@@ -2,13 +2,12 @@ use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +15,13 @@ use std::sync::Arc;
 pub struct NimbusCoreHdDeck {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreHdDeck {
-    // version
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "HdDeck";
+    pub const VERSION: &'static str = "";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,18 +31,40 @@ impl NimbusCoreHdDeck {
                 object_id: 266,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.HdDeck").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn is_deck_monitoring_available(&self) -> Result</* monitoring= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreHdDeck.IsDeckMonitoringAvailable()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let monitoring = bool::deserialize(&mut stream)?;
         Ok(monitoring)
@@ -49,12 +73,14 @@ impl NimbusCoreHdDeck {
     pub async fn get_tracks(&self) -> Result</* track= */ u8, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreHdDeck.GetTracks()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let track = u8::deserialize(&mut stream)?;
         Ok(track)
@@ -63,12 +89,14 @@ impl NimbusCoreHdDeck {
     pub async fn get_track_sensor_states(&self) -> Result</* sensors= */ Vec<bool>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 3, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreHdDeck.GetTrackSensorStates()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let sensors = Vec::<bool>::deserialize(&mut stream)?;
         Ok(sensors)
@@ -77,12 +105,14 @@ impl NimbusCoreHdDeck {
     pub async fn get_gantry_status(&self) -> Result</* status= */ GantryState, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreHdDeck.GetGantryStatus()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let status = GantryState::deserialize(&mut stream)?;
         Ok(status)
@@ -96,7 +126,9 @@ impl NimbusCoreHdDeck {
         let mut args = BytesMut::new();
         configuring.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  configuring: {:?}", configuring)];
                 format!(
@@ -107,7 +139,7 @@ impl NimbusCoreHdDeck {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let sensors = Vec::<bool>::deserialize(&mut stream)?;
         Ok(sensors)
@@ -125,7 +157,9 @@ impl NimbusCoreHdDeck {
         track.serialize(&mut args);
         width.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  load: {:?}", load),
@@ -140,7 +174,7 @@ impl NimbusCoreHdDeck {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let sensors = Vec::<bool>::deserialize(&mut stream)?;
         Ok(sensors)
@@ -149,12 +183,14 @@ impl NimbusCoreHdDeck {
     pub async fn cancel_track(&self) -> Result</* sensors= */ Vec<bool>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreHdDeck.CancelTrack()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let sensors = Vec::<bool>::deserialize(&mut stream)?;
         Ok(sensors)
@@ -163,14 +199,16 @@ impl NimbusCoreHdDeck {
     pub async fn get_track_led_states(&self) -> Result</* leds= */ Vec<LedState>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 8, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreHdDeck.GetTrackLedStates()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let leds = MVec::<LedState>::deserialize(&mut stream)?.0;
+        let leds = Vec::<LedState>::deserialize(&mut stream)?;
         Ok(leds)
     }
 
@@ -180,9 +218,11 @@ impl NimbusCoreHdDeck {
         leds: impl AsRef<[LedConfiguration]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        MSlice(leds.as_ref()).serialize(&mut args);
+        leds.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 9, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  leds: {:?}", leds.as_ref())];
                 format!(
@@ -193,7 +233,7 @@ impl NimbusCoreHdDeck {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -201,12 +241,14 @@ impl NimbusCoreHdDeck {
     pub async fn is_deck_monitored(&self) -> Result</* monitoring= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 10, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreHdDeck.IsDeckMonitored()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let monitoring = bool::deserialize(&mut stream)?;
         Ok(monitoring)
@@ -216,7 +258,9 @@ impl NimbusCoreHdDeck {
         let mut args = BytesMut::new();
         monitor.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 11, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  monitor: {:?}", monitor)];
                 format!(
@@ -227,7 +271,7 @@ impl NimbusCoreHdDeck {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -235,12 +279,14 @@ impl NimbusCoreHdDeck {
     pub async fn have_deck_sensors_changed(&self) -> Result</* changed= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 12, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreHdDeck.HaveDeckSensorsChanged()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let changed = bool::deserialize(&mut stream)?;
         Ok(changed)
@@ -258,7 +304,9 @@ impl NimbusCoreHdDeck {
         tracks.as_ref().serialize(&mut args);
         widths.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 13, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 13, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  load: {:?}", load),
@@ -273,7 +321,7 @@ impl NimbusCoreHdDeck {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let sensors = Vec::<bool>::deserialize(&mut stream)?;
         Ok(sensors)
@@ -282,12 +330,14 @@ impl NimbusCoreHdDeck {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreHdDeck.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -305,7 +355,9 @@ impl NimbusCoreHdDeck {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -316,7 +368,7 @@ impl NimbusCoreHdDeck {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -338,7 +390,9 @@ impl NimbusCoreHdDeck {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -349,7 +403,7 @@ impl NimbusCoreHdDeck {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -364,12 +418,14 @@ impl NimbusCoreHdDeck {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreHdDeck.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -383,7 +439,9 @@ impl NimbusCoreHdDeck {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -394,7 +452,7 @@ impl NimbusCoreHdDeck {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -412,7 +470,9 @@ impl NimbusCoreHdDeck {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -423,7 +483,7 @@ impl NimbusCoreHdDeck {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -438,496 +498,419 @@ impl NimbusCoreHdDeck {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub enum GantryState {
-    Normal = 0,
-    Locked = 1,
+// The methods above as a trait, so code using them can be tested against NimbusCoreHdDeckFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreHdDeckApi {
+    fn is_deck_monitoring_available(
+        &self,
+    ) -> impl Future<Output = Result</* monitoring= */ bool, Error>> + Send;
+    fn get_tracks(&self) -> impl Future<Output = Result</* track= */ u8, Error>> + Send;
+    fn get_track_sensor_states(
+        &self,
+    ) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send;
+    fn get_gantry_status(
+        &self,
+    ) -> impl Future<Output = Result</* status= */ GantryState, Error>> + Send;
+    fn configure_tracks(
+        &self,
+        configuring: bool,
+    ) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send;
+    fn load_tracks(
+        &self,
+        load: bool,
+        track: u8,
+        width: u8,
+    ) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send;
+    fn cancel_track(&self) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send;
+    fn get_track_led_states(
+        &self,
+    ) -> impl Future<Output = Result</* leds= */ Vec<LedState>, Error>> + Send;
+    fn configure_track_leds(
+        &self,
+        leds: impl AsRef<[LedConfiguration]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn is_deck_monitored(
+        &self,
+    ) -> impl Future<Output = Result</* monitoring= */ bool, Error>> + Send;
+    fn monitor_deck(&self, monitor: bool) -> impl Future<Output = Result<(), Error>> + Send;
+    fn have_deck_sensors_changed(
+        &self,
+    ) -> impl Future<Output = Result</* changed= */ bool, Error>> + Send;
+    fn load_tracks_2(
+        &self,
+        load: bool,
+        tracks: impl AsRef<[u8]> + Send,
+        widths: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
 }
 
-impl TryFrom<i32> for GantryState {
-    type Error = piglet_client::client::Error;
-
-    fn try_from(v: i32) -> Result<Self, Self::Error> {
-        match v {
-            0 => Ok(GantryState::Normal),
-            1 => Ok(GantryState::Locked),
-
-            _ => Err(ConnectionError(anyhow!("Unknown GantryState value {}", v))),
-        }
+impl NimbusCoreHdDeckApi for NimbusCoreHdDeck {
+    fn is_deck_monitoring_available(
+        &self,
+    ) -> impl Future<Output = Result</* monitoring= */ bool, Error>> + Send {
+        NimbusCoreHdDeck::is_deck_monitoring_available(self)
     }
-}
-
-impl PigletCodec for GantryState {
-    const TYPE_ID: u8 = 32;
-}
 
-impl PigletDeserialize for GantryState {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        stream.get_i32_le().try_into()
+    fn get_tracks(&self) -> impl Future<Output = Result</* track= */ u8, Error>> + Send {
+        NimbusCoreHdDeck::get_tracks(self)
     }
-}
 
-impl PigletSerialize for GantryState {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        stream.put_u16_le(4);
-        stream.put_i32_le(*self as i32);
+    fn get_track_sensor_states(
+        &self,
+    ) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send {
+        NimbusCoreHdDeck::get_track_sensor_states(self)
     }
-}
 
-impl PigletCodec for MSlice<'_, GantryState> {
-    const TYPE_ID: u8 = 35;
-}
-
-impl PigletCodec for MVec<GantryState> {
-    const TYPE_ID: u8 = 35;
-}
-
-impl PigletDeserialize for MVec<GantryState> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut arr = Vec::new();
-        for i in 0..(length / 4) {
-            arr.push(stream.get_i32_le().try_into()?);
-        }
-        Ok(MVec(arr))
+    fn get_gantry_status(
+        &self,
+    ) -> impl Future<Output = Result</* status= */ GantryState, Error>> + Send {
+        NimbusCoreHdDeck::get_gantry_status(self)
     }
-}
 
-impl PigletSerialize for MSlice<'_, GantryState> {
-    fn serialize(&self, bytes: &mut BytesMut) {
-        bytes.put_u8(Self::TYPE_ID);
-        bytes.put_u8(0);
-        bytes.put_u16_le(4 * self.0.len() as u16);
-        for v in self.0.as_ref() {
-            bytes.put_i32_le(*v as i32);
-        }
+    fn configure_tracks(
+        &self,
+        configuring: bool,
+    ) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send {
+        NimbusCoreHdDeck::configure_tracks(self, configuring)
     }
-}
 
-#[derive(Clone, Copy, Debug)]
-pub enum LedState {
-    Empty = 0,
-    Loaded = 1,
-    Loading = 2,
-    Unloading = 3,
-}
-
-impl TryFrom<i32> for LedState {
-    type Error = piglet_client::client::Error;
-
-    fn try_from(v: i32) -> Result<Self, Self::Error> {
-        match v {
-            0 => Ok(LedState::Empty),
-            1 => Ok(LedState::Loaded),
-            2 => Ok(LedState::Loading),
-            3 => Ok(LedState::Unloading),
-
-            _ => Err(ConnectionError(anyhow!("Unknown LedState value {}", v))),
-        }
+    fn load_tracks(
+        &self,
+        load: bool,
+        track: u8,
+        width: u8,
+    ) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send {
+        NimbusCoreHdDeck::load_tracks(self, load, track, width)
     }
-}
-
-impl PigletCodec for LedState {
-    const TYPE_ID: u8 = 32;
-}
 
-impl PigletDeserialize for LedState {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        stream.get_i32_le().try_into()
+    fn cancel_track(&self) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send {
+        NimbusCoreHdDeck::cancel_track(self)
     }
-}
 
-impl PigletSerialize for LedState {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        stream.put_u16_le(4);
-        stream.put_i32_le(*self as i32);
+    fn get_track_led_states(
+        &self,
+    ) -> impl Future<Output = Result</* leds= */ Vec<LedState>, Error>> + Send {
+        NimbusCoreHdDeck::get_track_led_states(self)
     }
-}
-
-impl PigletCodec for MSlice<'_, LedState> {
-    const TYPE_ID: u8 = 35;
-}
 
-impl PigletCodec for MVec<LedState> {
-    const TYPE_ID: u8 = 35;
-}
-
-impl PigletDeserialize for MVec<LedState> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut arr = Vec::new();
-        for i in 0..(length / 4) {
-            arr.push(stream.get_i32_le().try_into()?);
-        }
-        Ok(MVec(arr))
+    fn configure_track_leds(
+        &self,
+        leds: impl AsRef<[LedConfiguration]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreHdDeck::configure_track_leds(self, leds)
     }
-}
 
-impl PigletSerialize for MSlice<'_, LedState> {
-    fn serialize(&self, bytes: &mut BytesMut) {
-        bytes.put_u8(Self::TYPE_ID);
-        bytes.put_u8(0);
-        bytes.put_u16_le(4 * self.0.len() as u16);
-        for v in self.0.as_ref() {
-            bytes.put_i32_le(*v as i32);
-        }
+    fn is_deck_monitored(
+        &self,
+    ) -> impl Future<Output = Result</* monitoring= */ bool, Error>> + Send {
+        NimbusCoreHdDeck::is_deck_monitored(self)
     }
-}
-
-#[derive(Clone, Debug)]
-pub struct LedConfiguration {
-    pub position: u8,
-    pub state: LedState,
-}
-
-impl PigletCodec for LedConfiguration {
-    const TYPE_ID: u8 = 30;
-}
 
-impl PigletSerialize for LedConfiguration {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        let mut buffer = BytesMut::new();
-        let s = self;
-
-        s.position.serialize(&mut buffer);
-        s.state.serialize(&mut buffer);
-
-        stream.put_u16_le(buffer.len() as u16);
-        stream.put(buffer);
+    fn monitor_deck(&self, monitor: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreHdDeck::monitor_deck(self, monitor)
     }
-}
 
-impl PigletDeserialize for LedConfiguration {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(Self {
-            position: u8::deserialize(&mut bytes)?,
-            state: LedState::deserialize(&mut bytes)?,
-        })
+    fn have_deck_sensors_changed(
+        &self,
+    ) -> impl Future<Output = Result</* changed= */ bool, Error>> + Send {
+        NimbusCoreHdDeck::have_deck_sensors_changed(self)
     }
-}
 
-impl PigletCodec for MSlice<'_, LedConfiguration> {
-    const TYPE_ID: u8 = 31;
-}
-
-impl PigletCodec for MVec<LedConfiguration> {
-    const TYPE_ID: u8 = 31;
-}
-
-impl PigletSerialize for MSlice<'_, LedConfiguration> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-
-        let mut outer = BytesMut::new();
-        for s in self.0.as_ref() {
-            let mut buffer = BytesMut::new();
+    fn load_tracks_2(
+        &self,
+        load: bool,
+        tracks: impl AsRef<[u8]> + Send,
+        widths: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send {
+        NimbusCoreHdDeck::load_tracks_2(self, load, tracks, widths)
+    }
 
-            s.position.serialize(&mut buffer);
-            s.state.serialize(&mut buffer);
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreHdDeck::object_info(self)
+    }
 
-            outer.put_u16_le(buffer.len() as u16);
-            outer.put(buffer);
-        }
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreHdDeck::method_info(self, method)
+    }
 
-        stream.put_u16_le(outer.len() as u16);
-        stream.put(outer);
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreHdDeck::sub_object_info(self, subobject)
     }
-}
 
-impl PigletSerialize for MVec<LedConfiguration> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        MSlice(&self.0).serialize(stream)
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreHdDeck::interface_descriptors(self)
     }
-}
 
-impl PigletDeserialize for MVec<LedConfiguration> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let mut length = stream.get_u16_le() as usize;
-        let mut outer = stream.copy_to_bytes(length);
-        let mut arr = Vec::new();
-        while length > 0 {
-            let mut length = outer.get_u16_le() as usize;
-            let mut bytes = outer.copy_to_bytes(length);
-            arr.push(LedConfiguration {
-                position: u8::deserialize(&mut bytes)?,
-                state: LedState::deserialize(&mut bytes)?,
-            });
-        }
-        Ok(MVec(arr))
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreHdDeck::enum_info(self, interface_id)
     }
-}
 
-#[derive(Clone, Debug)]
-pub struct EventTrackSensors {
-    pub sensors: Vec<bool>,
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreHdDeck::struct_info(self, interface_id)
+    }
 }
 
-impl PigletCodec for EventTrackSensors {
-    const TYPE_ID: u8 = 30;
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreHdDeckFake {
+    pub recorder: piglet_client::testing::Recorder,
 }
 
-impl PigletSerialize for EventTrackSensors {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        let mut buffer = BytesMut::new();
-        let s = self;
-
-        s.sensors.serialize(&mut buffer);
-
-        stream.put_u16_le(buffer.len() as u16);
-        stream.put(buffer);
+#[cfg(feature = "testing")]
+impl NimbusCoreHdDeckApi for NimbusCoreHdDeckFake {
+    fn is_deck_monitoring_available(
+        &self,
+    ) -> impl Future<Output = Result</* monitoring= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_deck_monitoring_available", vec![]);
+        async move { reply }
     }
-}
 
-impl PigletDeserialize for EventTrackSensors {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(Self {
-            sensors: Vec::<bool>::deserialize(&mut bytes)?,
-        })
+    fn get_tracks(&self) -> impl Future<Output = Result</* track= */ u8, Error>> + Send {
+        let reply = self.recorder.record("get_tracks", vec![]);
+        async move { reply }
     }
-}
 
-impl PigletCodec for MSlice<'_, EventTrackSensors> {
-    const TYPE_ID: u8 = 31;
-}
+    fn get_track_sensor_states(
+        &self,
+    ) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send {
+        let reply = self.recorder.record("get_track_sensor_states", vec![]);
+        async move { reply }
+    }
 
-impl PigletCodec for MVec<EventTrackSensors> {
-    const TYPE_ID: u8 = 31;
-}
+    fn get_gantry_status(
+        &self,
+    ) -> impl Future<Output = Result</* status= */ GantryState, Error>> + Send {
+        let reply = self.recorder.record("get_gantry_status", vec![]);
+        async move { reply }
+    }
 
-impl PigletSerialize for MSlice<'_, EventTrackSensors> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
+    fn configure_tracks(
+        &self,
+        configuring: bool,
+    ) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send {
+        let reply = self.recorder.record(
+            "configure_tracks",
+            vec![("configuring", format!("{:?}", configuring))],
+        );
+        async move { reply }
+    }
 
-        let mut outer = BytesMut::new();
-        for s in self.0.as_ref() {
-            let mut buffer = BytesMut::new();
+    fn load_tracks(
+        &self,
+        load: bool,
+        track: u8,
+        width: u8,
+    ) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send {
+        let reply = self.recorder.record(
+            "load_tracks",
+            vec![
+                ("load", format!("{:?}", load)),
+                ("track", format!("{:?}", track)),
+                ("width", format!("{:?}", width)),
+            ],
+        );
+        async move { reply }
+    }
 
-            s.sensors.serialize(&mut buffer);
+    fn cancel_track(&self) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send {
+        let reply = self.recorder.record("cancel_track", vec![]);
+        async move { reply }
+    }
 
-            outer.put_u16_le(buffer.len() as u16);
-            outer.put(buffer);
-        }
+    fn get_track_led_states(
+        &self,
+    ) -> impl Future<Output = Result</* leds= */ Vec<LedState>, Error>> + Send {
+        let reply = self.recorder.record("get_track_led_states", vec![]);
+        async move { reply }
+    }
 
-        stream.put_u16_le(outer.len() as u16);
-        stream.put(outer);
+    fn configure_track_leds(
+        &self,
+        leds: impl AsRef<[LedConfiguration]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "configure_track_leds",
+            vec![("leds", format!("{:?}", leds.as_ref()))],
+        );
+        async move { reply }
     }
-}
 
-impl PigletSerialize for MVec<EventTrackSensors> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        MSlice(&self.0).serialize(stream)
+    fn is_deck_monitored(
+        &self,
+    ) -> impl Future<Output = Result</* monitoring= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_deck_monitored", vec![]);
+        async move { reply }
     }
-}
 
-impl PigletDeserialize for MVec<EventTrackSensors> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let mut length = stream.get_u16_le() as usize;
-        let mut outer = stream.copy_to_bytes(length);
-        let mut arr = Vec::new();
-        while length > 0 {
-            let mut length = outer.get_u16_le() as usize;
-            let mut bytes = outer.copy_to_bytes(length);
-            arr.push(EventTrackSensors {
-                sensors: Vec::<bool>::deserialize(&mut bytes)?,
-            });
-        }
-        Ok(MVec(arr))
+    fn monitor_deck(&self, monitor: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("monitor_deck", vec![("monitor", format!("{:?}", monitor))]);
+        async move { reply }
     }
-}
 
-#[derive(Clone, Debug)]
-pub struct EventGantryState {
-    pub state: GantryState,
-}
+    fn have_deck_sensors_changed(
+        &self,
+    ) -> impl Future<Output = Result</* changed= */ bool, Error>> + Send {
+        let reply = self.recorder.record("have_deck_sensors_changed", vec![]);
+        async move { reply }
+    }
 
-impl PigletCodec for EventGantryState {
-    const TYPE_ID: u8 = 30;
-}
+    fn load_tracks_2(
+        &self,
+        load: bool,
+        tracks: impl AsRef<[u8]> + Send,
+        widths: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result</* sensors= */ Vec<bool>, Error>> + Send {
+        let reply = self.recorder.record(
+            "load_tracks_2",
+            vec![
+                ("load", format!("{:?}", load)),
+                ("tracks", format!("{:?}", tracks.as_ref())),
+                ("widths", format!("{:?}", widths.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
 
-impl PigletSerialize for EventGantryState {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        let mut buffer = BytesMut::new();
-        let s = self;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
 
-        s.state.serialize(&mut buffer);
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
 
-        stream.put_u16_le(buffer.len() as u16);
-        stream.put(buffer);
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
     }
-}
 
-impl PigletDeserialize for EventGantryState {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(Self {
-            state: GantryState::deserialize(&mut bytes)?,
-        })
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
     }
 }
 
-impl PigletCodec for MSlice<'_, EventGantryState> {
-    const TYPE_ID: u8 = 31;
+#[derive(Clone, Copy, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GantryState {
+    Normal = 0,
+    Locked = 1,
 }
 
-impl PigletCodec for MVec<EventGantryState> {
-    const TYPE_ID: u8 = 31;
+#[derive(Clone, Copy, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LedState {
+    Empty = 0,
+    Loaded = 1,
+    Loading = 2,
+    Unloading = 3,
 }
 
-impl PigletSerialize for MSlice<'_, EventGantryState> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-
-        let mut outer = BytesMut::new();
-        for s in self.0.as_ref() {
-            let mut buffer = BytesMut::new();
-
-            s.state.serialize(&mut buffer);
+#[derive(Clone, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LedConfiguration {
+    pub position: u8,
+    pub state: LedState,
+}
 
-            outer.put_u16_le(buffer.len() as u16);
-            outer.put(buffer);
-        }
+#[derive(Clone, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventTrackSensors {
+    pub sensors: Vec<bool>,
+}
 
-        stream.put_u16_le(outer.len() as u16);
-        stream.put(outer);
-    }
+impl piglet_client::events::PigletEvent for EventTrackSensors {
+    const INTERFACE_ID: u8 = 1;
+    const CALL_TYPE_ID: u16 = 1;
 }
 
-impl PigletSerialize for MVec<EventGantryState> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        MSlice(&self.0).serialize(stream)
-    }
+#[derive(Clone, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventGantryState {
+    pub state: GantryState,
 }
 
-impl PigletDeserialize for MVec<EventGantryState> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let mut length = stream.get_u16_le() as usize;
-        let mut outer = stream.copy_to_bytes(length);
-        let mut arr = Vec::new();
-        while length > 0 {
-            let mut length = outer.get_u16_le() as usize;
-            let mut bytes = outer.copy_to_bytes(length);
-            arr.push(EventGantryState {
-                state: GantryState::deserialize(&mut bytes)?,
-            });
-        }
-        Ok(MVec(arr))
-    }
+impl piglet_client::events::PigletEvent for EventGantryState {
+    const INTERFACE_ID: u8 = 1;
+    const CALL_TYPE_ID: u16 = 2;
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -935,8 +918,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -946,23 +929,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -970,8 +953,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
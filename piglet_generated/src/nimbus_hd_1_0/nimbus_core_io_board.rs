@@ -2,13 +2,12 @@ use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +15,13 @@ use std::sync::Arc;
 pub struct NimbusCoreIoBoard {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreIoBoard {
-    // version
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "IoBoard";
+    pub const VERSION: &'static str = "";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,6 +31,26 @@ impl NimbusCoreIoBoard {
                 object_id: 48896,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.IoBoard").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
@@ -36,7 +58,9 @@ impl NimbusCoreIoBoard {
         let mut args = BytesMut::new();
         dw_input.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 1, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  dw_input: {:?}", dw_input)];
                 format!(
@@ -47,7 +71,7 @@ impl NimbusCoreIoBoard {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let lpb_state = bool::deserialize(&mut stream)?;
         Ok(lpb_state)
@@ -56,12 +80,14 @@ impl NimbusCoreIoBoard {
     pub async fn get_ttl_inputs(&self) -> Result</* lpdw_mask= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoard.GetTTLInputs()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let lpdw_mask = u32::deserialize(&mut stream)?;
         Ok(lpdw_mask)
@@ -71,7 +97,9 @@ impl NimbusCoreIoBoard {
         let mut args = BytesMut::new();
         dw_output.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  dw_output: {:?}", dw_output)];
                 format!(
@@ -82,7 +110,7 @@ impl NimbusCoreIoBoard {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let lpb_state = bool::deserialize(&mut stream)?;
         Ok(lpb_state)
@@ -91,12 +119,14 @@ impl NimbusCoreIoBoard {
     pub async fn get_ttl_outputs(&self) -> Result</* lpdw_mask= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoard.GetTTLOutputs()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let lpdw_mask = u32::deserialize(&mut stream)?;
         Ok(lpdw_mask)
@@ -107,7 +137,9 @@ impl NimbusCoreIoBoard {
         dw_output.serialize(&mut args);
         b_state.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  dw_output: {:?}", dw_output),
@@ -121,7 +153,7 @@ impl NimbusCoreIoBoard {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -130,7 +162,9 @@ impl NimbusCoreIoBoard {
         let mut args = BytesMut::new();
         dw_mask.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  dw_mask: {:?}", dw_mask)];
                 format!(
@@ -141,7 +175,7 @@ impl NimbusCoreIoBoard {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -154,7 +188,9 @@ impl NimbusCoreIoBoard {
         let mut args = BytesMut::new();
         dw_output.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  dw_output: {:?}", dw_output)];
                 format!(
@@ -165,7 +201,7 @@ impl NimbusCoreIoBoard {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let lpb_state = bool::deserialize(&mut stream)?;
         Ok(lpb_state)
@@ -174,12 +210,14 @@ impl NimbusCoreIoBoard {
     pub async fn get_open_drain_outputs(&self) -> Result</* lpdw_mask= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 8, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoard.GetOpenDrainOutputs()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let lpdw_mask = u32::deserialize(&mut stream)?;
         Ok(lpdw_mask)
@@ -190,7 +228,9 @@ impl NimbusCoreIoBoard {
         dw_output.serialize(&mut args);
         b_state.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 9, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  dw_output: {:?}", dw_output),
@@ -204,7 +244,7 @@ impl NimbusCoreIoBoard {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -213,7 +253,9 @@ impl NimbusCoreIoBoard {
         let mut args = BytesMut::new();
         dw_mask.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 10, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  dw_mask: {:?}", dw_mask)];
                 format!(
@@ -224,7 +266,7 @@ impl NimbusCoreIoBoard {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -232,12 +274,14 @@ impl NimbusCoreIoBoard {
     pub async fn is_park_button_pressed(&self) -> Result</* lpb_pressed= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 11, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoard.IsParkButtonPressed()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let lpb_pressed = bool::deserialize(&mut stream)?;
         Ok(lpb_pressed)
@@ -246,12 +290,14 @@ impl NimbusCoreIoBoard {
     pub async fn is_pause_button_pressed(&self) -> Result</* lpb_pressed= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 12, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoard.IsPauseButtonPressed()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let lpb_pressed = bool::deserialize(&mut stream)?;
         Ok(lpb_pressed)
@@ -260,12 +306,14 @@ impl NimbusCoreIoBoard {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoard.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -283,7 +331,9 @@ impl NimbusCoreIoBoard {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -294,7 +344,7 @@ impl NimbusCoreIoBoard {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -316,7 +366,9 @@ impl NimbusCoreIoBoard {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -327,7 +379,7 @@ impl NimbusCoreIoBoard {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -342,12 +394,14 @@ impl NimbusCoreIoBoard {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoard.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -361,7 +415,9 @@ impl NimbusCoreIoBoard {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -372,7 +428,7 @@ impl NimbusCoreIoBoard {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -390,7 +446,9 @@ impl NimbusCoreIoBoard {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -401,7 +459,7 @@ impl NimbusCoreIoBoard {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -416,8 +474,363 @@ impl NimbusCoreIoBoard {
     }
 }
 
-#[allow(non_camel_case_types)]
+// The methods above as a trait, so code using them can be tested against NimbusCoreIoBoardFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreIoBoardApi {
+    fn get_ttl_input(
+        &self,
+        dw_input: u32,
+    ) -> impl Future<Output = Result</* lpb_state= */ bool, Error>> + Send;
+    fn get_ttl_inputs(&self) -> impl Future<Output = Result</* lpdw_mask= */ u32, Error>> + Send;
+    fn get_ttl_output(
+        &self,
+        dw_output: u32,
+    ) -> impl Future<Output = Result</* lpb_state= */ bool, Error>> + Send;
+    fn get_ttl_outputs(&self) -> impl Future<Output = Result</* lpdw_mask= */ u32, Error>> + Send;
+    fn set_ttl_output(
+        &self,
+        dw_output: u32,
+        b_state: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_ttl_outputs(&self, dw_mask: u32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_open_drain_output(
+        &self,
+        dw_output: u32,
+    ) -> impl Future<Output = Result</* lpb_state= */ bool, Error>> + Send;
+    fn get_open_drain_outputs(
+        &self,
+    ) -> impl Future<Output = Result</* lpdw_mask= */ u32, Error>> + Send;
+    fn set_open_drain_output(
+        &self,
+        dw_output: u32,
+        b_state: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_open_drain_outputs(
+        &self,
+        dw_mask: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn is_park_button_pressed(
+        &self,
+    ) -> impl Future<Output = Result</* lpb_pressed= */ bool, Error>> + Send;
+    fn is_pause_button_pressed(
+        &self,
+    ) -> impl Future<Output = Result</* lpb_pressed= */ bool, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreIoBoardApi for NimbusCoreIoBoard {
+    fn get_ttl_input(
+        &self,
+        dw_input: u32,
+    ) -> impl Future<Output = Result</* lpb_state= */ bool, Error>> + Send {
+        NimbusCoreIoBoard::get_ttl_input(self, dw_input)
+    }
+
+    fn get_ttl_inputs(&self) -> impl Future<Output = Result</* lpdw_mask= */ u32, Error>> + Send {
+        NimbusCoreIoBoard::get_ttl_inputs(self)
+    }
+
+    fn get_ttl_output(
+        &self,
+        dw_output: u32,
+    ) -> impl Future<Output = Result</* lpb_state= */ bool, Error>> + Send {
+        NimbusCoreIoBoard::get_ttl_output(self, dw_output)
+    }
+
+    fn get_ttl_outputs(&self) -> impl Future<Output = Result</* lpdw_mask= */ u32, Error>> + Send {
+        NimbusCoreIoBoard::get_ttl_outputs(self)
+    }
+
+    fn set_ttl_output(
+        &self,
+        dw_output: u32,
+        b_state: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreIoBoard::set_ttl_output(self, dw_output, b_state)
+    }
+
+    fn set_ttl_outputs(&self, dw_mask: u32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreIoBoard::set_ttl_outputs(self, dw_mask)
+    }
+
+    fn get_open_drain_output(
+        &self,
+        dw_output: u32,
+    ) -> impl Future<Output = Result</* lpb_state= */ bool, Error>> + Send {
+        NimbusCoreIoBoard::get_open_drain_output(self, dw_output)
+    }
+
+    fn get_open_drain_outputs(
+        &self,
+    ) -> impl Future<Output = Result</* lpdw_mask= */ u32, Error>> + Send {
+        NimbusCoreIoBoard::get_open_drain_outputs(self)
+    }
+
+    fn set_open_drain_output(
+        &self,
+        dw_output: u32,
+        b_state: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreIoBoard::set_open_drain_output(self, dw_output, b_state)
+    }
+
+    fn set_open_drain_outputs(
+        &self,
+        dw_mask: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreIoBoard::set_open_drain_outputs(self, dw_mask)
+    }
+
+    fn is_park_button_pressed(
+        &self,
+    ) -> impl Future<Output = Result</* lpb_pressed= */ bool, Error>> + Send {
+        NimbusCoreIoBoard::is_park_button_pressed(self)
+    }
+
+    fn is_pause_button_pressed(
+        &self,
+    ) -> impl Future<Output = Result</* lpb_pressed= */ bool, Error>> + Send {
+        NimbusCoreIoBoard::is_pause_button_pressed(self)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreIoBoard::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreIoBoard::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreIoBoard::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreIoBoard::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreIoBoard::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreIoBoard::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreIoBoardFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreIoBoardApi for NimbusCoreIoBoardFake {
+    fn get_ttl_input(
+        &self,
+        dw_input: u32,
+    ) -> impl Future<Output = Result</* lpb_state= */ bool, Error>> + Send {
+        let reply = self.recorder.record(
+            "get_ttl_input",
+            vec![("dw_input", format!("{:?}", dw_input))],
+        );
+        async move { reply }
+    }
+
+    fn get_ttl_inputs(&self) -> impl Future<Output = Result</* lpdw_mask= */ u32, Error>> + Send {
+        let reply = self.recorder.record("get_ttl_inputs", vec![]);
+        async move { reply }
+    }
+
+    fn get_ttl_output(
+        &self,
+        dw_output: u32,
+    ) -> impl Future<Output = Result</* lpb_state= */ bool, Error>> + Send {
+        let reply = self.recorder.record(
+            "get_ttl_output",
+            vec![("dw_output", format!("{:?}", dw_output))],
+        );
+        async move { reply }
+    }
+
+    fn get_ttl_outputs(&self) -> impl Future<Output = Result</* lpdw_mask= */ u32, Error>> + Send {
+        let reply = self.recorder.record("get_ttl_outputs", vec![]);
+        async move { reply }
+    }
+
+    fn set_ttl_output(
+        &self,
+        dw_output: u32,
+        b_state: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_ttl_output",
+            vec![
+                ("dw_output", format!("{:?}", dw_output)),
+                ("b_state", format!("{:?}", b_state)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_ttl_outputs(&self, dw_mask: u32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_ttl_outputs",
+            vec![("dw_mask", format!("{:?}", dw_mask))],
+        );
+        async move { reply }
+    }
+
+    fn get_open_drain_output(
+        &self,
+        dw_output: u32,
+    ) -> impl Future<Output = Result</* lpb_state= */ bool, Error>> + Send {
+        let reply = self.recorder.record(
+            "get_open_drain_output",
+            vec![("dw_output", format!("{:?}", dw_output))],
+        );
+        async move { reply }
+    }
+
+    fn get_open_drain_outputs(
+        &self,
+    ) -> impl Future<Output = Result</* lpdw_mask= */ u32, Error>> + Send {
+        let reply = self.recorder.record("get_open_drain_outputs", vec![]);
+        async move { reply }
+    }
+
+    fn set_open_drain_output(
+        &self,
+        dw_output: u32,
+        b_state: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_open_drain_output",
+            vec![
+                ("dw_output", format!("{:?}", dw_output)),
+                ("b_state", format!("{:?}", b_state)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_open_drain_outputs(
+        &self,
+        dw_mask: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_open_drain_outputs",
+            vec![("dw_mask", format!("{:?}", dw_mask))],
+        );
+        async move { reply }
+    }
+
+    fn is_park_button_pressed(
+        &self,
+    ) -> impl Future<Output = Result</* lpb_pressed= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_park_button_pressed", vec![]);
+        async move { reply }
+    }
+
+    fn is_pause_button_pressed(
+        &self,
+    ) -> impl Future<Output = Result</* lpb_pressed= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_pause_button_pressed", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -425,8 +838,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -436,23 +849,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -460,8 +873,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
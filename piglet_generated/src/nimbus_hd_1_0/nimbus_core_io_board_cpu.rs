@@ -2,13 +2,12 @@ use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +15,13 @@ use std::sync::Arc;
 pub struct NimbusCoreIoBoardCpu {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreIoBoardCpu {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Cpu";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,18 +31,40 @@ impl NimbusCoreIoBoardCpu {
                 object_id: 49408,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.IoBoard.Cpu").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn download_info(&self) -> Result<DownloadInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoardCpu.DownloadInfo()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let buffer_size = i32::deserialize(&mut stream)?;
         let file_name_template = String::deserialize(&mut stream)?;
@@ -53,12 +77,14 @@ impl NimbusCoreIoBoardCpu {
     pub async fn download_initiate(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoardCpu.DownloadInitiate()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -67,7 +93,9 @@ impl NimbusCoreIoBoardCpu {
         let mut args = BytesMut::new();
         download_data.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  download_data: {:?}", download_data.as_ref())];
                 format!(
@@ -78,7 +106,7 @@ impl NimbusCoreIoBoardCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -87,7 +115,9 @@ impl NimbusCoreIoBoardCpu {
         let mut args = BytesMut::new();
         success.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  success: {:?}", success)];
                 format!(
@@ -98,7 +128,7 @@ impl NimbusCoreIoBoardCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -106,12 +136,14 @@ impl NimbusCoreIoBoardCpu {
     pub async fn version(&self) -> Result<VersionReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 5, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoardCpu.Version()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let firmware_version = String::deserialize(&mut stream)?;
         Ok(VersionReply { firmware_version })
@@ -120,12 +152,14 @@ impl NimbusCoreIoBoardCpu {
     pub async fn is_in_boot(&self) -> Result</* in_boot= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 6, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoardCpu.IsInBoot()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let in_boot = bool::deserialize(&mut stream)?;
         Ok(in_boot)
@@ -135,7 +169,9 @@ impl NimbusCoreIoBoardCpu {
         let mut args = BytesMut::new();
         address.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  address: {:?}", address)];
                 format!(
@@ -146,7 +182,7 @@ impl NimbusCoreIoBoardCpu {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = u8::deserialize(&mut stream)?;
         Ok(value)
@@ -156,7 +192,9 @@ impl NimbusCoreIoBoardCpu {
         let mut args = BytesMut::new();
         address.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 8, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  address: {:?}", address)];
                 format!(
@@ -167,7 +205,7 @@ impl NimbusCoreIoBoardCpu {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = u32::deserialize(&mut stream)?;
         Ok(value)
@@ -178,7 +216,9 @@ impl NimbusCoreIoBoardCpu {
         address.serialize(&mut args);
         value.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 9, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  address: {:?}", address),
@@ -192,7 +232,7 @@ impl NimbusCoreIoBoardCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -202,7 +242,9 @@ impl NimbusCoreIoBoardCpu {
         address.serialize(&mut args);
         value.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 10, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  address: {:?}", address),
@@ -216,7 +258,7 @@ impl NimbusCoreIoBoardCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -224,12 +266,14 @@ impl NimbusCoreIoBoardCpu {
     pub async fn reg_table_entries(&self) -> Result</* entries= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 11, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoardCpu.RegTableEntries()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let entries = u32::deserialize(&mut stream)?;
         Ok(entries)
@@ -239,7 +283,9 @@ impl NimbusCoreIoBoardCpu {
         let mut args = BytesMut::new();
         entry.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 12, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  entry: {:?}", entry)];
                 format!(
@@ -250,7 +296,7 @@ impl NimbusCoreIoBoardCpu {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -268,7 +314,9 @@ impl NimbusCoreIoBoardCpu {
         let mut args = BytesMut::new();
         delay_ms.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 2, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 2, 3, 1, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  delay_ms: {:?}", delay_ms)];
                 format!(
@@ -279,7 +327,7 @@ impl NimbusCoreIoBoardCpu {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -287,12 +335,14 @@ impl NimbusCoreIoBoardCpu {
     pub async fn boot_loader_version(&self) -> Result</* boot_loader_version= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 2, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 2, 0, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoardCpu.BootLoaderVersion()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let boot_loader_version = String::deserialize(&mut stream)?;
         Ok(boot_loader_version)
@@ -301,12 +351,14 @@ impl NimbusCoreIoBoardCpu {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoardCpu.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -324,7 +376,9 @@ impl NimbusCoreIoBoardCpu {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -335,7 +389,7 @@ impl NimbusCoreIoBoardCpu {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -357,7 +411,9 @@ impl NimbusCoreIoBoardCpu {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -368,7 +424,7 @@ impl NimbusCoreIoBoardCpu {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -383,12 +439,14 @@ impl NimbusCoreIoBoardCpu {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoardCpu.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -402,7 +460,9 @@ impl NimbusCoreIoBoardCpu {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -413,7 +473,7 @@ impl NimbusCoreIoBoardCpu {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -431,7 +491,9 @@ impl NimbusCoreIoBoardCpu {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -442,7 +504,7 @@ impl NimbusCoreIoBoardCpu {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -457,21 +519,383 @@ impl NimbusCoreIoBoardCpu {
     }
 }
 
-#[allow(non_camel_case_types)]
+// The methods above as a trait, so code using them can be tested against NimbusCoreIoBoardCpuFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreIoBoardCpuApi {
+    fn download_info(&self) -> impl Future<Output = Result<DownloadInfoReply, Error>> + Send;
+    fn download_initiate(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn download_write(
+        &self,
+        download_data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn download_complete(&self, success: bool) -> impl Future<Output = Result<(), Error>> + Send;
+    fn version(&self) -> impl Future<Output = Result<VersionReply, Error>> + Send;
+    fn is_in_boot(&self) -> impl Future<Output = Result</* in_boot= */ bool, Error>> + Send;
+    fn read_uint_8(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u8, Error>> + Send;
+    fn read_uint_32(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u32, Error>> + Send;
+    fn write_uint_8(
+        &self,
+        address: u32,
+        value: u8,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn write_uint_32(
+        &self,
+        address: u32,
+        value: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn reg_table_entries(&self) -> impl Future<Output = Result</* entries= */ u32, Error>> + Send;
+    fn reg_table_entry(
+        &self,
+        entry: u32,
+    ) -> impl Future<Output = Result<RegTableEntryReply, Error>> + Send;
+    fn reset(&self, delay_ms: u32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn boot_loader_version(
+        &self,
+    ) -> impl Future<Output = Result</* boot_loader_version= */ String, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreIoBoardCpuApi for NimbusCoreIoBoardCpu {
+    fn download_info(&self) -> impl Future<Output = Result<DownloadInfoReply, Error>> + Send {
+        NimbusCoreIoBoardCpu::download_info(self)
+    }
+
+    fn download_initiate(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreIoBoardCpu::download_initiate(self)
+    }
+
+    fn download_write(
+        &self,
+        download_data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreIoBoardCpu::download_write(self, download_data)
+    }
+
+    fn download_complete(&self, success: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreIoBoardCpu::download_complete(self, success)
+    }
+
+    fn version(&self) -> impl Future<Output = Result<VersionReply, Error>> + Send {
+        NimbusCoreIoBoardCpu::version(self)
+    }
+
+    fn is_in_boot(&self) -> impl Future<Output = Result</* in_boot= */ bool, Error>> + Send {
+        NimbusCoreIoBoardCpu::is_in_boot(self)
+    }
+
+    fn read_uint_8(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u8, Error>> + Send {
+        NimbusCoreIoBoardCpu::read_uint_8(self, address)
+    }
+
+    fn read_uint_32(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u32, Error>> + Send {
+        NimbusCoreIoBoardCpu::read_uint_32(self, address)
+    }
+
+    fn write_uint_8(
+        &self,
+        address: u32,
+        value: u8,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreIoBoardCpu::write_uint_8(self, address, value)
+    }
+
+    fn write_uint_32(
+        &self,
+        address: u32,
+        value: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreIoBoardCpu::write_uint_32(self, address, value)
+    }
+
+    fn reg_table_entries(&self) -> impl Future<Output = Result</* entries= */ u32, Error>> + Send {
+        NimbusCoreIoBoardCpu::reg_table_entries(self)
+    }
+
+    fn reg_table_entry(
+        &self,
+        entry: u32,
+    ) -> impl Future<Output = Result<RegTableEntryReply, Error>> + Send {
+        NimbusCoreIoBoardCpu::reg_table_entry(self, entry)
+    }
+
+    fn reset(&self, delay_ms: u32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreIoBoardCpu::reset(self, delay_ms)
+    }
+
+    fn boot_loader_version(
+        &self,
+    ) -> impl Future<Output = Result</* boot_loader_version= */ String, Error>> + Send {
+        NimbusCoreIoBoardCpu::boot_loader_version(self)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreIoBoardCpu::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreIoBoardCpu::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreIoBoardCpu::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreIoBoardCpu::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreIoBoardCpu::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreIoBoardCpu::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreIoBoardCpuFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreIoBoardCpuApi for NimbusCoreIoBoardCpuFake {
+    fn download_info(&self) -> impl Future<Output = Result<DownloadInfoReply, Error>> + Send {
+        let reply = self.recorder.record("download_info", vec![]);
+        async move { reply }
+    }
+
+    fn download_initiate(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("download_initiate", vec![]);
+        async move { reply }
+    }
+
+    fn download_write(
+        &self,
+        download_data: impl AsRef<[u8]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "download_write",
+            vec![("download_data", format!("{:?}", download_data.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn download_complete(&self, success: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "download_complete",
+            vec![("success", format!("{:?}", success))],
+        );
+        async move { reply }
+    }
+
+    fn version(&self) -> impl Future<Output = Result<VersionReply, Error>> + Send {
+        let reply = self.recorder.record("version", vec![]);
+        async move { reply }
+    }
+
+    fn is_in_boot(&self) -> impl Future<Output = Result</* in_boot= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_in_boot", vec![]);
+        async move { reply }
+    }
+
+    fn read_uint_8(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u8, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("read_uint_8", vec![("address", format!("{:?}", address))]);
+        async move { reply }
+    }
+
+    fn read_uint_32(
+        &self,
+        address: u32,
+    ) -> impl Future<Output = Result</* value= */ u32, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("read_uint_32", vec![("address", format!("{:?}", address))]);
+        async move { reply }
+    }
+
+    fn write_uint_8(
+        &self,
+        address: u32,
+        value: u8,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "write_uint_8",
+            vec![
+                ("address", format!("{:?}", address)),
+                ("value", format!("{:?}", value)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn write_uint_32(
+        &self,
+        address: u32,
+        value: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "write_uint_32",
+            vec![
+                ("address", format!("{:?}", address)),
+                ("value", format!("{:?}", value)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn reg_table_entries(&self) -> impl Future<Output = Result</* entries= */ u32, Error>> + Send {
+        let reply = self.recorder.record("reg_table_entries", vec![]);
+        async move { reply }
+    }
+
+    fn reg_table_entry(
+        &self,
+        entry: u32,
+    ) -> impl Future<Output = Result<RegTableEntryReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("reg_table_entry", vec![("entry", format!("{:?}", entry))]);
+        async move { reply }
+    }
+
+    fn reset(&self, delay_ms: u32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("reset", vec![("delay_ms", format!("{:?}", delay_ms))]);
+        async move { reply }
+    }
+
+    fn boot_loader_version(
+        &self,
+    ) -> impl Future<Output = Result</* boot_loader_version= */ String, Error>> + Send {
+        let reply = self.recorder.record("boot_loader_version", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DownloadInfoReply {
     pub buffer_size: i32,
     pub file_name_template: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VersionReply {
     pub firmware_version: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegTableEntryReply {
     pub module_id: u16,
     pub node_id: u16,
@@ -479,8 +903,8 @@ pub struct RegTableEntryReply {
     pub link_handle: u32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -488,8 +912,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -499,23 +923,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -523,8 +947,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
@@ -2,13 +2,12 @@ use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +15,13 @@ use std::sync::Arc;
 pub struct NimbusCoreIoBoardDeck {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreIoBoardDeck {
-    // version
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Deck";
+    pub const VERSION: &'static str = "";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,18 +31,40 @@ impl NimbusCoreIoBoardDeck {
                 object_id: 8192,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.IoBoard.Deck").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn get_positions(&self) -> Result</* value= */ u8, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoardDeck.GetPositions()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = u8::deserialize(&mut stream)?;
         Ok(value)
@@ -49,12 +73,14 @@ impl NimbusCoreIoBoardDeck {
     pub async fn get_track_sensor_states(&self) -> Result</* value= */ Vec<bool>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoardDeck.GetTrackSensorStates()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let value = Vec::<bool>::deserialize(&mut stream)?;
         Ok(value)
@@ -63,22 +89,26 @@ impl NimbusCoreIoBoardDeck {
     pub async fn get_track_led_states(&self) -> Result</* leds= */ Vec<LedState>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 3, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoardDeck.GetTrackLedStates()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let leds = MVec::<LedState>::deserialize(&mut stream)?.0;
+        let leds = Vec::<LedState>::deserialize(&mut stream)?;
         Ok(leds)
     }
 
     pub async fn set_track_led_state(&self, leds: impl AsRef<[LedState]>) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        MSlice(leds.as_ref()).serialize(&mut args);
+        leds.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  leds: {:?}", leds.as_ref())];
                 format!(
@@ -89,7 +119,7 @@ impl NimbusCoreIoBoardDeck {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -100,9 +130,11 @@ impl NimbusCoreIoBoardDeck {
         leds: impl AsRef<[LedConfiguration]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        MSlice(leds.as_ref()).serialize(&mut args);
+        leds.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  leds: {:?}", leds.as_ref())];
                 format!(
@@ -113,7 +145,7 @@ impl NimbusCoreIoBoardDeck {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -121,12 +153,14 @@ impl NimbusCoreIoBoardDeck {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoardDeck.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -144,7 +178,9 @@ impl NimbusCoreIoBoardDeck {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -155,7 +191,7 @@ impl NimbusCoreIoBoardDeck {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -177,7 +213,9 @@ impl NimbusCoreIoBoardDeck {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -188,7 +226,7 @@ impl NimbusCoreIoBoardDeck {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -203,12 +241,14 @@ impl NimbusCoreIoBoardDeck {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreIoBoardDeck.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -222,7 +262,9 @@ impl NimbusCoreIoBoardDeck {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -233,7 +275,7 @@ impl NimbusCoreIoBoardDeck {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -251,7 +293,9 @@ impl NimbusCoreIoBoardDeck {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -262,7 +306,7 @@ impl NimbusCoreIoBoardDeck {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -277,312 +321,247 @@ impl NimbusCoreIoBoardDeck {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub enum LedState {
-    Empty = 0,
-    Loaded = 1,
-    Loading = 2,
-    Unloading = 3,
+// The methods above as a trait, so code using them can be tested against NimbusCoreIoBoardDeckFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreIoBoardDeckApi {
+    fn get_positions(&self) -> impl Future<Output = Result</* value= */ u8, Error>> + Send;
+    fn get_track_sensor_states(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ Vec<bool>, Error>> + Send;
+    fn get_track_led_states(
+        &self,
+    ) -> impl Future<Output = Result</* leds= */ Vec<LedState>, Error>> + Send;
+    fn set_track_led_state(
+        &self,
+        leds: impl AsRef<[LedState]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn configure_track_leds(
+        &self,
+        leds: impl AsRef<[LedConfiguration]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
 }
 
-impl TryFrom<i32> for LedState {
-    type Error = piglet_client::client::Error;
-
-    fn try_from(v: i32) -> Result<Self, Self::Error> {
-        match v {
-            0 => Ok(LedState::Empty),
-            1 => Ok(LedState::Loaded),
-            2 => Ok(LedState::Loading),
-            3 => Ok(LedState::Unloading),
-
-            _ => Err(ConnectionError(anyhow!("Unknown LedState value {}", v))),
-        }
+impl NimbusCoreIoBoardDeckApi for NimbusCoreIoBoardDeck {
+    fn get_positions(&self) -> impl Future<Output = Result</* value= */ u8, Error>> + Send {
+        NimbusCoreIoBoardDeck::get_positions(self)
     }
-}
 
-impl PigletCodec for LedState {
-    const TYPE_ID: u8 = 32;
-}
-
-impl PigletDeserialize for LedState {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        stream.get_i32_le().try_into()
+    fn get_track_sensor_states(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ Vec<bool>, Error>> + Send {
+        NimbusCoreIoBoardDeck::get_track_sensor_states(self)
     }
-}
 
-impl PigletSerialize for LedState {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        stream.put_u16_le(4);
-        stream.put_i32_le(*self as i32);
+    fn get_track_led_states(
+        &self,
+    ) -> impl Future<Output = Result</* leds= */ Vec<LedState>, Error>> + Send {
+        NimbusCoreIoBoardDeck::get_track_led_states(self)
     }
-}
-
-impl PigletCodec for MSlice<'_, LedState> {
-    const TYPE_ID: u8 = 35;
-}
-
-impl PigletCodec for MVec<LedState> {
-    const TYPE_ID: u8 = 35;
-}
 
-impl PigletDeserialize for MVec<LedState> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut arr = Vec::new();
-        for i in 0..(length / 4) {
-            arr.push(stream.get_i32_le().try_into()?);
-        }
-        Ok(MVec(arr))
+    fn set_track_led_state(
+        &self,
+        leds: impl AsRef<[LedState]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreIoBoardDeck::set_track_led_state(self, leds)
     }
-}
 
-impl PigletSerialize for MSlice<'_, LedState> {
-    fn serialize(&self, bytes: &mut BytesMut) {
-        bytes.put_u8(Self::TYPE_ID);
-        bytes.put_u8(0);
-        bytes.put_u16_le(4 * self.0.len() as u16);
-        for v in self.0.as_ref() {
-            bytes.put_i32_le(*v as i32);
-        }
+    fn configure_track_leds(
+        &self,
+        leds: impl AsRef<[LedConfiguration]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreIoBoardDeck::configure_track_leds(self, leds)
     }
-}
 
-#[derive(Clone, Debug)]
-pub struct LedConfiguration {
-    pub position: u8,
-    pub state: LedState,
-}
-
-impl PigletCodec for LedConfiguration {
-    const TYPE_ID: u8 = 30;
-}
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreIoBoardDeck::object_info(self)
+    }
 
-impl PigletSerialize for LedConfiguration {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        let mut buffer = BytesMut::new();
-        let s = self;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreIoBoardDeck::method_info(self, method)
+    }
 
-        s.position.serialize(&mut buffer);
-        s.state.serialize(&mut buffer);
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreIoBoardDeck::sub_object_info(self, subobject)
+    }
 
-        stream.put_u16_le(buffer.len() as u16);
-        stream.put(buffer);
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreIoBoardDeck::interface_descriptors(self)
     }
-}
 
-impl PigletDeserialize for LedConfiguration {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(Self {
-            position: u8::deserialize(&mut bytes)?,
-            state: LedState::deserialize(&mut bytes)?,
-        })
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreIoBoardDeck::enum_info(self, interface_id)
     }
-}
 
-impl PigletCodec for MSlice<'_, LedConfiguration> {
-    const TYPE_ID: u8 = 31;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreIoBoardDeck::struct_info(self, interface_id)
+    }
 }
 
-impl PigletCodec for MVec<LedConfiguration> {
-    const TYPE_ID: u8 = 31;
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreIoBoardDeckFake {
+    pub recorder: piglet_client::testing::Recorder,
 }
 
-impl PigletSerialize for MSlice<'_, LedConfiguration> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-
-        let mut outer = BytesMut::new();
-        for s in self.0.as_ref() {
-            let mut buffer = BytesMut::new();
-
-            s.position.serialize(&mut buffer);
-            s.state.serialize(&mut buffer);
-
-            outer.put_u16_le(buffer.len() as u16);
-            outer.put(buffer);
-        }
-
-        stream.put_u16_le(outer.len() as u16);
-        stream.put(outer);
+#[cfg(feature = "testing")]
+impl NimbusCoreIoBoardDeckApi for NimbusCoreIoBoardDeckFake {
+    fn get_positions(&self) -> impl Future<Output = Result</* value= */ u8, Error>> + Send {
+        let reply = self.recorder.record("get_positions", vec![]);
+        async move { reply }
     }
-}
 
-impl PigletSerialize for MVec<LedConfiguration> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        MSlice(&self.0).serialize(stream)
+    fn get_track_sensor_states(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ Vec<bool>, Error>> + Send {
+        let reply = self.recorder.record("get_track_sensor_states", vec![]);
+        async move { reply }
     }
-}
 
-impl PigletDeserialize for MVec<LedConfiguration> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let mut length = stream.get_u16_le() as usize;
-        let mut outer = stream.copy_to_bytes(length);
-        let mut arr = Vec::new();
-        while length > 0 {
-            let mut length = outer.get_u16_le() as usize;
-            let mut bytes = outer.copy_to_bytes(length);
-            arr.push(LedConfiguration {
-                position: u8::deserialize(&mut bytes)?,
-                state: LedState::deserialize(&mut bytes)?,
-            });
-        }
-        Ok(MVec(arr))
+    fn get_track_led_states(
+        &self,
+    ) -> impl Future<Output = Result</* leds= */ Vec<LedState>, Error>> + Send {
+        let reply = self.recorder.record("get_track_led_states", vec![]);
+        async move { reply }
     }
-}
 
-#[derive(Clone, Debug)]
-pub struct TrackSensorStateChangedData {
-    pub previous_sensor_states: Vec<bool>,
-    pub current_sensor_states: Vec<bool>,
-}
+    fn set_track_led_state(
+        &self,
+        leds: impl AsRef<[LedState]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_track_led_state",
+            vec![("leds", format!("{:?}", leds.as_ref()))],
+        );
+        async move { reply }
+    }
 
-impl PigletCodec for TrackSensorStateChangedData {
-    const TYPE_ID: u8 = 30;
-}
+    fn configure_track_leds(
+        &self,
+        leds: impl AsRef<[LedConfiguration]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "configure_track_leds",
+            vec![("leds", format!("{:?}", leds.as_ref()))],
+        );
+        async move { reply }
+    }
 
-impl PigletSerialize for TrackSensorStateChangedData {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        let mut buffer = BytesMut::new();
-        let s = self;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
 
-        s.previous_sensor_states.serialize(&mut buffer);
-        s.current_sensor_states.serialize(&mut buffer);
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
 
-        stream.put_u16_le(buffer.len() as u16);
-        stream.put(buffer);
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
     }
-}
 
-impl PigletDeserialize for TrackSensorStateChangedData {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        Ok(Self {
-            previous_sensor_states: Vec::<bool>::deserialize(&mut bytes)?,
-            current_sensor_states: Vec::<bool>::deserialize(&mut bytes)?,
-        })
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
     }
-}
 
-impl PigletCodec for MSlice<'_, TrackSensorStateChangedData> {
-    const TYPE_ID: u8 = 31;
-}
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
 
-impl PigletCodec for MVec<TrackSensorStateChangedData> {
-    const TYPE_ID: u8 = 31;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
 }
 
-impl PigletSerialize for MSlice<'_, TrackSensorStateChangedData> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-
-        let mut outer = BytesMut::new();
-        for s in self.0.as_ref() {
-            let mut buffer = BytesMut::new();
-
-            s.previous_sensor_states.serialize(&mut buffer);
-            s.current_sensor_states.serialize(&mut buffer);
-
-            outer.put_u16_le(buffer.len() as u16);
-            outer.put(buffer);
-        }
-
-        stream.put_u16_le(outer.len() as u16);
-        stream.put(outer);
-    }
+#[derive(Clone, Copy, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LedState {
+    Empty = 0,
+    Loaded = 1,
+    Loading = 2,
+    Unloading = 3,
 }
 
-impl PigletSerialize for MVec<TrackSensorStateChangedData> {
-    fn serialize(&self, stream: &mut BytesMut) {
-        MSlice(&self.0).serialize(stream)
-    }
+#[derive(Clone, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LedConfiguration {
+    pub position: u8,
+    pub state: LedState,
 }
 
-impl PigletDeserialize for MVec<TrackSensorStateChangedData> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let mut length = stream.get_u16_le() as usize;
-        let mut outer = stream.copy_to_bytes(length);
-        let mut arr = Vec::new();
-        while length > 0 {
-            let mut length = outer.get_u16_le() as usize;
-            let mut bytes = outer.copy_to_bytes(length);
-            arr.push(TrackSensorStateChangedData {
-                previous_sensor_states: Vec::<bool>::deserialize(&mut bytes)?,
-                current_sensor_states: Vec::<bool>::deserialize(&mut bytes)?,
-            });
-        }
-        Ok(MVec(arr))
-    }
+#[derive(Clone, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrackSensorStateChangedData {
+    pub previous_sensor_states: Vec<bool>,
+    pub current_sensor_states: Vec<bool>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -590,8 +569,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -601,23 +580,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -625,8 +604,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
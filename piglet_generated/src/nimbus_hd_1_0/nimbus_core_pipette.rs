@@ -1,14 +1,14 @@
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
+use piglet_client::units::{Microliters, MicrolitersPerSecond, Millimeters, MmPerSecond};
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +16,13 @@ use std::sync::Arc;
 pub struct NimbusCorePipette {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCorePipette {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Pipette";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,26 +32,73 @@ impl NimbusCorePipette {
                 object_id: 257,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Pipette").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn initialize(
         &self,
 
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
-        z_final: impl AsRef<[i32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
+        z_final: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
-        z_final.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 1, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position.as_ref()),
@@ -65,7 +115,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -73,20 +123,47 @@ impl NimbusCorePipette {
     pub async fn initialize_smart(
         &self,
 
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
-        z_final: impl AsRef<[i32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
+        z_final: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
-        z_final.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position.as_ref()),
@@ -103,7 +180,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -112,7 +189,7 @@ impl NimbusCorePipette {
         &self,
 
         tip_type: u16,
-        volume: u32,
+        volume: Microliters,
         length: u16,
         collar_type: i8,
         filtered: bool,
@@ -120,13 +197,15 @@ impl NimbusCorePipette {
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tip_type.serialize(&mut args);
-        volume.serialize(&mut args);
+        volume.to_wire::<u32>()?.serialize(&mut args);
         length.serialize(&mut args);
         collar_type.serialize(&mut args);
         filtered.serialize(&mut args);
         needle.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tip_type: {:?}", tip_type),
@@ -144,7 +223,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -153,23 +232,45 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        traverse_height: i32,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
         tip_type: impl AsRef<[u16]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         tip_type.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -188,7 +289,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -197,25 +298,52 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        traverse_height: i32,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
-        z_final: impl AsRef<[i32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
+        z_final: impl AsRef<[Millimeters]>,
         default_waste: bool,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
-        z_final.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         default_waste.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -235,7 +363,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -245,29 +373,29 @@ impl NimbusCorePipette {
 
         aspirate_type: impl AsRef<[i16]>,
         tips_used: impl AsRef<[u16]>,
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        traverse_height: i32,
-        liquid_seek_height: impl AsRef<[i32]>,
-        liquid_surface_height: impl AsRef<[i32]>,
-        submerge_depth: impl AsRef<[i32]>,
-        follow_depth: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
-        clot_check_height: impl AsRef<[i32]>,
-        z_final: i32,
-        liquid_exit_speed: impl AsRef<[u32]>,
-        blowout_volume: impl AsRef<[u32]>,
-        prewet_volume: impl AsRef<[u32]>,
-        aspirate_volume: impl AsRef<[u32]>,
-        transport_air_volume: impl AsRef<[u32]>,
-        aspirate_speed: impl AsRef<[u32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]>,
+        liquid_surface_height: impl AsRef<[Millimeters]>,
+        submerge_depth: impl AsRef<[Millimeters]>,
+        follow_depth: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
+        clot_check_height: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]>,
+        blowout_volume: impl AsRef<[Microliters]>,
+        prewet_volume: impl AsRef<[Microliters]>,
+        aspirate_volume: impl AsRef<[Microliters]>,
+        transport_air_volume: impl AsRef<[Microliters]>,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]>,
         settling_time: impl AsRef<[u32]>,
-        mix_volume: impl AsRef<[u32]>,
+        mix_volume: impl AsRef<[Microliters]>,
         mix_cycles: impl AsRef<[u32]>,
-        mix_position: impl AsRef<[i32]>,
-        mix_follow_distance: impl AsRef<[i32]>,
-        mix_speed: impl AsRef<[u32]>,
-        tube_section_height: impl AsRef<[i32]>,
+        mix_position: impl AsRef<[Millimeters]>,
+        mix_follow_distance: impl AsRef<[Millimeters]>,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]>,
+        tube_section_height: impl AsRef<[Millimeters]>,
         tube_section_ratio: impl AsRef<[i32]>,
         lld_mode: impl AsRef<[i16]>,
         capacitive_lld_sensitivity: impl AsRef<[i16]>,
@@ -280,29 +408,124 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         aspirate_type.as_ref().serialize(&mut args);
         tips_used.as_ref().serialize(&mut args);
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        liquid_seek_height.as_ref().serialize(&mut args);
-        liquid_surface_height.as_ref().serialize(&mut args);
-        submerge_depth.as_ref().serialize(&mut args);
-        follow_depth.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
-        clot_check_height.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
-        liquid_exit_speed.as_ref().serialize(&mut args);
-        blowout_volume.as_ref().serialize(&mut args);
-        prewet_volume.as_ref().serialize(&mut args);
-        aspirate_volume.as_ref().serialize(&mut args);
-        transport_air_volume.as_ref().serialize(&mut args);
-        aspirate_speed.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        liquid_seek_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        liquid_surface_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        submerge_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        follow_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        clot_check_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        liquid_exit_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        blowout_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        prewet_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        aspirate_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        transport_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        aspirate_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         settling_time.as_ref().serialize(&mut args);
-        mix_volume.as_ref().serialize(&mut args);
+        mix_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         mix_cycles.as_ref().serialize(&mut args);
-        mix_position.as_ref().serialize(&mut args);
-        mix_follow_distance.as_ref().serialize(&mut args);
-        mix_speed.as_ref().serialize(&mut args);
-        tube_section_height.as_ref().serialize(&mut args);
+        mix_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_follow_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        tube_section_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         tube_section_ratio.as_ref().serialize(&mut args);
         lld_mode.as_ref().serialize(&mut args);
         capacitive_lld_sensitivity.as_ref().serialize(&mut args);
@@ -312,7 +535,9 @@ impl NimbusCorePipette {
         limit_curve_index.as_ref().serialize(&mut args);
         recording_mode.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  aspirate_type: {:?}", aspirate_type.as_ref()),
@@ -372,41 +597,208 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn aspirate_1_with(&self, request: &Aspirate1Request) -> Result<(), Error> {
+        self.aspirate_1(
+            piglet_client::client::required(
+                request.aspirate_type.as_ref(),
+                "Aspirate1Request",
+                "aspirate_type",
+            )?,
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "Aspirate1Request",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.x_position.as_ref(),
+                "Aspirate1Request",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_position.as_ref(),
+                "Aspirate1Request",
+                "y_position",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "Aspirate1Request",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.liquid_seek_height.as_ref(),
+                "Aspirate1Request",
+                "liquid_seek_height",
+            )?,
+            piglet_client::client::required(
+                request.liquid_surface_height.as_ref(),
+                "Aspirate1Request",
+                "liquid_surface_height",
+            )?,
+            piglet_client::client::required(
+                request.submerge_depth.as_ref(),
+                "Aspirate1Request",
+                "submerge_depth",
+            )?,
+            piglet_client::client::required(
+                request.follow_depth.as_ref(),
+                "Aspirate1Request",
+                "follow_depth",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position.as_ref(),
+                "Aspirate1Request",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(
+                request.clot_check_height.as_ref(),
+                "Aspirate1Request",
+                "clot_check_height",
+            )?,
+            piglet_client::client::required(request.z_final, "Aspirate1Request", "z_final")?,
+            piglet_client::client::required(
+                request.liquid_exit_speed.as_ref(),
+                "Aspirate1Request",
+                "liquid_exit_speed",
+            )?,
+            piglet_client::client::required(
+                request.blowout_volume.as_ref(),
+                "Aspirate1Request",
+                "blowout_volume",
+            )?,
+            piglet_client::client::required(
+                request.prewet_volume.as_ref(),
+                "Aspirate1Request",
+                "prewet_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_volume.as_ref(),
+                "Aspirate1Request",
+                "aspirate_volume",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume.as_ref(),
+                "Aspirate1Request",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_speed.as_ref(),
+                "Aspirate1Request",
+                "aspirate_speed",
+            )?,
+            piglet_client::client::required(
+                request.settling_time.as_ref(),
+                "Aspirate1Request",
+                "settling_time",
+            )?,
+            piglet_client::client::required(
+                request.mix_volume.as_ref(),
+                "Aspirate1Request",
+                "mix_volume",
+            )?,
+            piglet_client::client::required(
+                request.mix_cycles.as_ref(),
+                "Aspirate1Request",
+                "mix_cycles",
+            )?,
+            piglet_client::client::required(
+                request.mix_position.as_ref(),
+                "Aspirate1Request",
+                "mix_position",
+            )?,
+            piglet_client::client::required(
+                request.mix_follow_distance.as_ref(),
+                "Aspirate1Request",
+                "mix_follow_distance",
+            )?,
+            piglet_client::client::required(
+                request.mix_speed.as_ref(),
+                "Aspirate1Request",
+                "mix_speed",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_height.as_ref(),
+                "Aspirate1Request",
+                "tube_section_height",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_ratio.as_ref(),
+                "Aspirate1Request",
+                "tube_section_ratio",
+            )?,
+            piglet_client::client::required(
+                request.lld_mode.as_ref(),
+                "Aspirate1Request",
+                "lld_mode",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_sensitivity.as_ref(),
+                "Aspirate1Request",
+                "capacitive_lld_sensitivity",
+            )?,
+            piglet_client::client::required(
+                request.pressure_lld_sensitivity.as_ref(),
+                "Aspirate1Request",
+                "pressure_lld_sensitivity",
+            )?,
+            piglet_client::client::required(
+                request.lld_height_difference.as_ref(),
+                "Aspirate1Request",
+                "lld_height_difference",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "Aspirate1Request",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index.as_ref(),
+                "Aspirate1Request",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "Aspirate1Request",
+                "recording_mode",
+            )?,
+        )
+        .await
+    }
+
     pub async fn dispense(
         &self,
 
         dispense_type: impl AsRef<[i16]>,
         tips_used: impl AsRef<[u16]>,
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        traverse_height: i32,
-        liquid_seek_height: impl AsRef<[i32]>,
-        dispense_height: impl AsRef<[i32]>,
-        submerge_depth: impl AsRef<[i32]>,
-        follow_depth: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
-        z_final: i32,
-        liquid_exit_speed: impl AsRef<[u32]>,
-        transport_air_volume: impl AsRef<[u32]>,
-        dispense_volume: impl AsRef<[u32]>,
-        stop_back_volume: impl AsRef<[u32]>,
-        blowout_volume: impl AsRef<[u32]>,
-        dispense_speed: impl AsRef<[u32]>,
-        cutoff_speed: impl AsRef<[u32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]>,
+        dispense_height: impl AsRef<[Millimeters]>,
+        submerge_depth: impl AsRef<[Millimeters]>,
+        follow_depth: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]>,
+        transport_air_volume: impl AsRef<[Microliters]>,
+        dispense_volume: impl AsRef<[Microliters]>,
+        stop_back_volume: impl AsRef<[Microliters]>,
+        blowout_volume: impl AsRef<[Microliters]>,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]>,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]>,
         settling_time: impl AsRef<[u32]>,
-        mix_volume: impl AsRef<[u32]>,
+        mix_volume: impl AsRef<[Microliters]>,
         mix_cycles: impl AsRef<[u32]>,
-        mix_position: impl AsRef<[i32]>,
-        mix_follow_distance: impl AsRef<[i32]>,
-        mix_speed: impl AsRef<[u32]>,
-        touch_off_distance: i32,
+        mix_position: impl AsRef<[Millimeters]>,
+        mix_follow_distance: impl AsRef<[Millimeters]>,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]>,
+        touch_off_distance: Millimeters,
         dispense_offset: impl AsRef<[i32]>,
-        tube_section_height: impl AsRef<[i32]>,
+        tube_section_height: impl AsRef<[Millimeters]>,
         tube_section_ratio: impl AsRef<[i32]>,
         lld_mode: impl AsRef<[i16]>,
         capacitive_lld_sensitivity: impl AsRef<[i16]>,
@@ -417,31 +809,126 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         dispense_type.as_ref().serialize(&mut args);
         tips_used.as_ref().serialize(&mut args);
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        liquid_seek_height.as_ref().serialize(&mut args);
-        dispense_height.as_ref().serialize(&mut args);
-        submerge_depth.as_ref().serialize(&mut args);
-        follow_depth.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
-        liquid_exit_speed.as_ref().serialize(&mut args);
-        transport_air_volume.as_ref().serialize(&mut args);
-        dispense_volume.as_ref().serialize(&mut args);
-        stop_back_volume.as_ref().serialize(&mut args);
-        blowout_volume.as_ref().serialize(&mut args);
-        dispense_speed.as_ref().serialize(&mut args);
-        cutoff_speed.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        liquid_seek_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        dispense_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        submerge_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        follow_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        liquid_exit_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        transport_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        stop_back_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        blowout_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        cutoff_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         settling_time.as_ref().serialize(&mut args);
-        mix_volume.as_ref().serialize(&mut args);
+        mix_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         mix_cycles.as_ref().serialize(&mut args);
-        mix_position.as_ref().serialize(&mut args);
-        mix_follow_distance.as_ref().serialize(&mut args);
-        mix_speed.as_ref().serialize(&mut args);
-        touch_off_distance.serialize(&mut args);
+        mix_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_follow_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        touch_off_distance.to_wire::<i32>()?.serialize(&mut args);
         dispense_offset.as_ref().serialize(&mut args);
-        tube_section_height.as_ref().serialize(&mut args);
+        tube_section_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         tube_section_ratio.as_ref().serialize(&mut args);
         lld_mode.as_ref().serialize(&mut args);
         capacitive_lld_sensitivity.as_ref().serialize(&mut args);
@@ -449,7 +936,9 @@ impl NimbusCorePipette {
         limit_curve_index.as_ref().serialize(&mut args);
         recording_mode.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  dispense_type: {:?}", dispense_type.as_ref()),
@@ -500,11 +989,178 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn dispense_with(&self, request: &DispenseRequest) -> Result<(), Error> {
+        self.dispense(
+            piglet_client::client::required(
+                request.dispense_type.as_ref(),
+                "DispenseRequest",
+                "dispense_type",
+            )?,
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "DispenseRequest",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.x_position.as_ref(),
+                "DispenseRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_position.as_ref(),
+                "DispenseRequest",
+                "y_position",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "DispenseRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.liquid_seek_height.as_ref(),
+                "DispenseRequest",
+                "liquid_seek_height",
+            )?,
+            piglet_client::client::required(
+                request.dispense_height.as_ref(),
+                "DispenseRequest",
+                "dispense_height",
+            )?,
+            piglet_client::client::required(
+                request.submerge_depth.as_ref(),
+                "DispenseRequest",
+                "submerge_depth",
+            )?,
+            piglet_client::client::required(
+                request.follow_depth.as_ref(),
+                "DispenseRequest",
+                "follow_depth",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position.as_ref(),
+                "DispenseRequest",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(request.z_final, "DispenseRequest", "z_final")?,
+            piglet_client::client::required(
+                request.liquid_exit_speed.as_ref(),
+                "DispenseRequest",
+                "liquid_exit_speed",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume.as_ref(),
+                "DispenseRequest",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_volume.as_ref(),
+                "DispenseRequest",
+                "dispense_volume",
+            )?,
+            piglet_client::client::required(
+                request.stop_back_volume.as_ref(),
+                "DispenseRequest",
+                "stop_back_volume",
+            )?,
+            piglet_client::client::required(
+                request.blowout_volume.as_ref(),
+                "DispenseRequest",
+                "blowout_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_speed.as_ref(),
+                "DispenseRequest",
+                "dispense_speed",
+            )?,
+            piglet_client::client::required(
+                request.cutoff_speed.as_ref(),
+                "DispenseRequest",
+                "cutoff_speed",
+            )?,
+            piglet_client::client::required(
+                request.settling_time.as_ref(),
+                "DispenseRequest",
+                "settling_time",
+            )?,
+            piglet_client::client::required(
+                request.mix_volume.as_ref(),
+                "DispenseRequest",
+                "mix_volume",
+            )?,
+            piglet_client::client::required(
+                request.mix_cycles.as_ref(),
+                "DispenseRequest",
+                "mix_cycles",
+            )?,
+            piglet_client::client::required(
+                request.mix_position.as_ref(),
+                "DispenseRequest",
+                "mix_position",
+            )?,
+            piglet_client::client::required(
+                request.mix_follow_distance.as_ref(),
+                "DispenseRequest",
+                "mix_follow_distance",
+            )?,
+            piglet_client::client::required(
+                request.mix_speed.as_ref(),
+                "DispenseRequest",
+                "mix_speed",
+            )?,
+            piglet_client::client::required(
+                request.touch_off_distance,
+                "DispenseRequest",
+                "touch_off_distance",
+            )?,
+            piglet_client::client::required(
+                request.dispense_offset.as_ref(),
+                "DispenseRequest",
+                "dispense_offset",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_height.as_ref(),
+                "DispenseRequest",
+                "tube_section_height",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_ratio.as_ref(),
+                "DispenseRequest",
+                "tube_section_ratio",
+            )?,
+            piglet_client::client::required(
+                request.lld_mode.as_ref(),
+                "DispenseRequest",
+                "lld_mode",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_sensitivity.as_ref(),
+                "DispenseRequest",
+                "capacitive_lld_sensitivity",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "DispenseRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index.as_ref(),
+                "DispenseRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "DispenseRequest",
+                "recording_mode",
+            )?,
+        )
+        .await
+    }
+
     pub async fn aspirate_and_dispense(
         &self,
 
@@ -512,34 +1168,34 @@ impl NimbusCorePipette {
         dispense_type: impl AsRef<[i16]>,
         tips_used: impl AsRef<[u16]>,
         aspirate_dispense_pattern: impl AsRef<[i16]>,
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        traverse_height: i32,
-        liquid_seek_height: impl AsRef<[i32]>,
-        liquid_surface_height: impl AsRef<[i32]>,
-        submerge_depth: impl AsRef<[i32]>,
-        follow_depth: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
-        clot_check_height: impl AsRef<[i32]>,
-        z_final: i32,
-        liquid_exit_speed: impl AsRef<[u32]>,
-        blowout_volume: impl AsRef<[u32]>,
-        prewet_volume: impl AsRef<[u32]>,
-        aspirate_volume: impl AsRef<[u32]>,
-        transport_air_volume: impl AsRef<[u32]>,
-        dispense_volume: impl AsRef<[u32]>,
-        stop_back_volume: impl AsRef<[u32]>,
-        aspirate_speed: impl AsRef<[u32]>,
-        dispense_speed: impl AsRef<[u32]>,
-        cutoff_speed: impl AsRef<[u32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]>,
+        liquid_surface_height: impl AsRef<[Millimeters]>,
+        submerge_depth: impl AsRef<[Millimeters]>,
+        follow_depth: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
+        clot_check_height: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]>,
+        blowout_volume: impl AsRef<[Microliters]>,
+        prewet_volume: impl AsRef<[Microliters]>,
+        aspirate_volume: impl AsRef<[Microliters]>,
+        transport_air_volume: impl AsRef<[Microliters]>,
+        dispense_volume: impl AsRef<[Microliters]>,
+        stop_back_volume: impl AsRef<[Microliters]>,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]>,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]>,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]>,
         settling_time: impl AsRef<[u32]>,
-        mix_volume: impl AsRef<[u32]>,
+        mix_volume: impl AsRef<[Microliters]>,
         mix_cycles: impl AsRef<[u32]>,
-        mix_position: impl AsRef<[i32]>,
-        mix_follow_distance: impl AsRef<[i32]>,
-        mix_speed: impl AsRef<[u32]>,
+        mix_position: impl AsRef<[Millimeters]>,
+        mix_follow_distance: impl AsRef<[Millimeters]>,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]>,
         dispense_offset: impl AsRef<[i32]>,
-        tube_section_height: impl AsRef<[i32]>,
+        tube_section_height: impl AsRef<[Millimeters]>,
         tube_section_ratio: impl AsRef<[i32]>,
         lld_mode: impl AsRef<[i16]>,
         capacitive_lld_sensitivity: impl AsRef<[i16]>,
@@ -554,34 +1210,149 @@ impl NimbusCorePipette {
         dispense_type.as_ref().serialize(&mut args);
         tips_used.as_ref().serialize(&mut args);
         aspirate_dispense_pattern.as_ref().serialize(&mut args);
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        liquid_seek_height.as_ref().serialize(&mut args);
-        liquid_surface_height.as_ref().serialize(&mut args);
-        submerge_depth.as_ref().serialize(&mut args);
-        follow_depth.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
-        clot_check_height.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
-        liquid_exit_speed.as_ref().serialize(&mut args);
-        blowout_volume.as_ref().serialize(&mut args);
-        prewet_volume.as_ref().serialize(&mut args);
-        aspirate_volume.as_ref().serialize(&mut args);
-        transport_air_volume.as_ref().serialize(&mut args);
-        dispense_volume.as_ref().serialize(&mut args);
-        stop_back_volume.as_ref().serialize(&mut args);
-        aspirate_speed.as_ref().serialize(&mut args);
-        dispense_speed.as_ref().serialize(&mut args);
-        cutoff_speed.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        liquid_seek_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        liquid_surface_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        submerge_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        follow_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        clot_check_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        liquid_exit_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        blowout_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        prewet_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        aspirate_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        transport_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        stop_back_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        aspirate_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        cutoff_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         settling_time.as_ref().serialize(&mut args);
-        mix_volume.as_ref().serialize(&mut args);
+        mix_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         mix_cycles.as_ref().serialize(&mut args);
-        mix_position.as_ref().serialize(&mut args);
-        mix_follow_distance.as_ref().serialize(&mut args);
-        mix_speed.as_ref().serialize(&mut args);
+        mix_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_follow_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         dispense_offset.as_ref().serialize(&mut args);
-        tube_section_height.as_ref().serialize(&mut args);
+        tube_section_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         tube_section_ratio.as_ref().serialize(&mut args);
         lld_mode.as_ref().serialize(&mut args);
         capacitive_lld_sensitivity.as_ref().serialize(&mut args);
@@ -591,7 +1362,9 @@ impl NimbusCorePipette {
         limit_curve_index.as_ref().serialize(&mut args);
         recording_mode.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 8, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  aspirate_type: {:?}", aspirate_type.as_ref()),
@@ -661,38 +1434,253 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn aspirate_and_dispense_with(
+        &self,
+        request: &AspirateAndDispenseRequest,
+    ) -> Result<(), Error> {
+        self.aspirate_and_dispense(
+            piglet_client::client::required(
+                request.aspirate_type.as_ref(),
+                "AspirateAndDispenseRequest",
+                "aspirate_type",
+            )?,
+            piglet_client::client::required(
+                request.dispense_type.as_ref(),
+                "AspirateAndDispenseRequest",
+                "dispense_type",
+            )?,
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "AspirateAndDispenseRequest",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_dispense_pattern.as_ref(),
+                "AspirateAndDispenseRequest",
+                "aspirate_dispense_pattern",
+            )?,
+            piglet_client::client::required(
+                request.x_position.as_ref(),
+                "AspirateAndDispenseRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_position.as_ref(),
+                "AspirateAndDispenseRequest",
+                "y_position",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "AspirateAndDispenseRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.liquid_seek_height.as_ref(),
+                "AspirateAndDispenseRequest",
+                "liquid_seek_height",
+            )?,
+            piglet_client::client::required(
+                request.liquid_surface_height.as_ref(),
+                "AspirateAndDispenseRequest",
+                "liquid_surface_height",
+            )?,
+            piglet_client::client::required(
+                request.submerge_depth.as_ref(),
+                "AspirateAndDispenseRequest",
+                "submerge_depth",
+            )?,
+            piglet_client::client::required(
+                request.follow_depth.as_ref(),
+                "AspirateAndDispenseRequest",
+                "follow_depth",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position.as_ref(),
+                "AspirateAndDispenseRequest",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(
+                request.clot_check_height.as_ref(),
+                "AspirateAndDispenseRequest",
+                "clot_check_height",
+            )?,
+            piglet_client::client::required(
+                request.z_final,
+                "AspirateAndDispenseRequest",
+                "z_final",
+            )?,
+            piglet_client::client::required(
+                request.liquid_exit_speed.as_ref(),
+                "AspirateAndDispenseRequest",
+                "liquid_exit_speed",
+            )?,
+            piglet_client::client::required(
+                request.blowout_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "blowout_volume",
+            )?,
+            piglet_client::client::required(
+                request.prewet_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "prewet_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "aspirate_volume",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "dispense_volume",
+            )?,
+            piglet_client::client::required(
+                request.stop_back_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "stop_back_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_speed.as_ref(),
+                "AspirateAndDispenseRequest",
+                "aspirate_speed",
+            )?,
+            piglet_client::client::required(
+                request.dispense_speed.as_ref(),
+                "AspirateAndDispenseRequest",
+                "dispense_speed",
+            )?,
+            piglet_client::client::required(
+                request.cutoff_speed.as_ref(),
+                "AspirateAndDispenseRequest",
+                "cutoff_speed",
+            )?,
+            piglet_client::client::required(
+                request.settling_time.as_ref(),
+                "AspirateAndDispenseRequest",
+                "settling_time",
+            )?,
+            piglet_client::client::required(
+                request.mix_volume.as_ref(),
+                "AspirateAndDispenseRequest",
+                "mix_volume",
+            )?,
+            piglet_client::client::required(
+                request.mix_cycles.as_ref(),
+                "AspirateAndDispenseRequest",
+                "mix_cycles",
+            )?,
+            piglet_client::client::required(
+                request.mix_position.as_ref(),
+                "AspirateAndDispenseRequest",
+                "mix_position",
+            )?,
+            piglet_client::client::required(
+                request.mix_follow_distance.as_ref(),
+                "AspirateAndDispenseRequest",
+                "mix_follow_distance",
+            )?,
+            piglet_client::client::required(
+                request.mix_speed.as_ref(),
+                "AspirateAndDispenseRequest",
+                "mix_speed",
+            )?,
+            piglet_client::client::required(
+                request.dispense_offset.as_ref(),
+                "AspirateAndDispenseRequest",
+                "dispense_offset",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_height.as_ref(),
+                "AspirateAndDispenseRequest",
+                "tube_section_height",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_ratio.as_ref(),
+                "AspirateAndDispenseRequest",
+                "tube_section_ratio",
+            )?,
+            piglet_client::client::required(
+                request.lld_mode.as_ref(),
+                "AspirateAndDispenseRequest",
+                "lld_mode",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_sensitivity.as_ref(),
+                "AspirateAndDispenseRequest",
+                "capacitive_lld_sensitivity",
+            )?,
+            piglet_client::client::required(
+                request.pressure_lld_sensitivity.as_ref(),
+                "AspirateAndDispenseRequest",
+                "pressure_lld_sensitivity",
+            )?,
+            piglet_client::client::required(
+                request.lld_height_difference.as_ref(),
+                "AspirateAndDispenseRequest",
+                "lld_height_difference",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "AspirateAndDispenseRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index.as_ref(),
+                "AspirateAndDispenseRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "AspirateAndDispenseRequest",
+                "recording_mode",
+            )?,
+        )
+        .await
+    }
+
     pub async fn pickup_gripper_tool(
         &self,
 
-        x_position: i32,
-        y_position_1_st_channel: i32,
-        y_position_2_nd_channel: i32,
-        traverse_height: i32,
-        z_start_position: i32,
-        z_stop_position: i32,
+        x_position: Millimeters,
+        y_position_1_st_channel: Millimeters,
+        y_position_2_nd_channel: Millimeters,
+        traverse_height: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
         tip_type: u16,
         first_channel_number: u16,
         second_channel_number: u16,
-        tool_width: i32,
+        tool_width: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_position_1_st_channel.serialize(&mut args);
-        y_position_2_nd_channel.serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position_1_st_channel
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        y_position_2_nd_channel
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
         tip_type.serialize(&mut args);
         first_channel_number.serialize(&mut args);
         second_channel_number.serialize(&mut args);
-        tool_width.serialize(&mut args);
+        tool_width.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 9, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -714,36 +1702,101 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn pickup_gripper_tool_with(
+        &self,
+        request: &PickupGripperToolRequest,
+    ) -> Result<(), Error> {
+        self.pickup_gripper_tool(
+            piglet_client::client::required(
+                request.x_position,
+                "PickupGripperToolRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_position_1_st_channel,
+                "PickupGripperToolRequest",
+                "y_position_1_st_channel",
+            )?,
+            piglet_client::client::required(
+                request.y_position_2_nd_channel,
+                "PickupGripperToolRequest",
+                "y_position_2_nd_channel",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "PickupGripperToolRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.z_start_position,
+                "PickupGripperToolRequest",
+                "z_start_position",
+            )?,
+            piglet_client::client::required(
+                request.z_stop_position,
+                "PickupGripperToolRequest",
+                "z_stop_position",
+            )?,
+            piglet_client::client::required(
+                request.tip_type,
+                "PickupGripperToolRequest",
+                "tip_type",
+            )?,
+            piglet_client::client::required(
+                request.first_channel_number,
+                "PickupGripperToolRequest",
+                "first_channel_number",
+            )?,
+            piglet_client::client::required(
+                request.second_channel_number,
+                "PickupGripperToolRequest",
+                "second_channel_number",
+            )?,
+            piglet_client::client::required(
+                request.tool_width,
+                "PickupGripperToolRequest",
+                "tool_width",
+            )?,
+        )
+        .await
+    }
+
     pub async fn drop_gripper_tool(
         &self,
 
-        x_position: i32,
-        y_position_1_st_channel: i32,
-        y_position_2_nd_channel: i32,
-        traverse_height: i32,
-        z_start_position: i32,
-        z_stop_position: i32,
-        z_final: i32,
+        x_position: Millimeters,
+        y_position_1_st_channel: Millimeters,
+        y_position_2_nd_channel: Millimeters,
+        traverse_height: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
         first_channel_number: u16,
         second_channel_number: u16,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_position_1_st_channel.serialize(&mut args);
-        y_position_2_nd_channel.serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_start_position.serialize(&mut args);
-        z_stop_position.serialize(&mut args);
-        z_final.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position_1_st_channel
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        y_position_2_nd_channel
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_start_position.to_wire::<i32>()?.serialize(&mut args);
+        z_stop_position.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
         first_channel_number.serialize(&mut args);
         second_channel_number.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 10, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -764,38 +1817,92 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn drop_gripper_tool_with(
+        &self,
+        request: &DropGripperToolRequest,
+    ) -> Result<(), Error> {
+        self.drop_gripper_tool(
+            piglet_client::client::required(
+                request.x_position,
+                "DropGripperToolRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_position_1_st_channel,
+                "DropGripperToolRequest",
+                "y_position_1_st_channel",
+            )?,
+            piglet_client::client::required(
+                request.y_position_2_nd_channel,
+                "DropGripperToolRequest",
+                "y_position_2_nd_channel",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "DropGripperToolRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.z_start_position,
+                "DropGripperToolRequest",
+                "z_start_position",
+            )?,
+            piglet_client::client::required(
+                request.z_stop_position,
+                "DropGripperToolRequest",
+                "z_stop_position",
+            )?,
+            piglet_client::client::required(request.z_final, "DropGripperToolRequest", "z_final")?,
+            piglet_client::client::required(
+                request.first_channel_number,
+                "DropGripperToolRequest",
+                "first_channel_number",
+            )?,
+            piglet_client::client::required(
+                request.second_channel_number,
+                "DropGripperToolRequest",
+                "second_channel_number",
+            )?,
+        )
+        .await
+    }
+
     pub async fn pickup_plate(
         &self,
 
-        x_position: i32,
-        y_plate_center_position: i32,
-        y_plate_width: u32,
-        y_open_position: i32,
-        y_grip_speed: u32,
+        x_position: Millimeters,
+        y_plate_center_position: Millimeters,
+        y_plate_width: Millimeters,
+        y_open_position: Millimeters,
+        y_grip_speed: MmPerSecond,
         y_grip_strength: u32,
-        traverse_height: i32,
-        z_grip_height: i32,
-        z_final: i32,
-        z_speed: u32,
+        traverse_height: Millimeters,
+        z_grip_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_plate_center_position.serialize(&mut args);
-        y_plate_width.serialize(&mut args);
-        y_open_position.serialize(&mut args);
-        y_grip_speed.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_plate_center_position
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        y_plate_width.to_wire::<u32>()?.serialize(&mut args);
+        y_open_position.to_wire::<i32>()?.serialize(&mut args);
+        y_grip_speed.to_wire::<u32>()?.serialize(&mut args);
         y_grip_strength.serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_grip_height.serialize(&mut args);
-        z_final.serialize(&mut args);
-        z_speed.serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_grip_height.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        z_speed.to_wire::<u32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 11, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -817,36 +1924,88 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn pickup_plate_with(&self, request: &PickupPlateRequest) -> Result<(), Error> {
+        self.pickup_plate(
+            piglet_client::client::required(
+                request.x_position,
+                "PickupPlateRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_plate_center_position,
+                "PickupPlateRequest",
+                "y_plate_center_position",
+            )?,
+            piglet_client::client::required(
+                request.y_plate_width,
+                "PickupPlateRequest",
+                "y_plate_width",
+            )?,
+            piglet_client::client::required(
+                request.y_open_position,
+                "PickupPlateRequest",
+                "y_open_position",
+            )?,
+            piglet_client::client::required(
+                request.y_grip_speed,
+                "PickupPlateRequest",
+                "y_grip_speed",
+            )?,
+            piglet_client::client::required(
+                request.y_grip_strength,
+                "PickupPlateRequest",
+                "y_grip_strength",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "PickupPlateRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.z_grip_height,
+                "PickupPlateRequest",
+                "z_grip_height",
+            )?,
+            piglet_client::client::required(request.z_final, "PickupPlateRequest", "z_final")?,
+            piglet_client::client::required(request.z_speed, "PickupPlateRequest", "z_speed")?,
+        )
+        .await
+    }
+
     pub async fn drop_plate(
         &self,
 
-        x_position: i32,
+        x_position: Millimeters,
         x_acceleration: u32,
-        y_plate_center_position: i32,
-        y_open_position: i32,
-        traverse_height: i32,
-        z_drop_height: i32,
-        z_press_distance: i32,
-        z_final: i32,
-        z_speed: u32,
+        y_plate_center_position: Millimeters,
+        y_open_position: Millimeters,
+        traverse_height: Millimeters,
+        z_drop_height: Millimeters,
+        z_press_distance: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
         x_acceleration.serialize(&mut args);
-        y_plate_center_position.serialize(&mut args);
-        y_open_position.serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_drop_height.serialize(&mut args);
-        z_press_distance.serialize(&mut args);
-        z_final.serialize(&mut args);
-        z_speed.serialize(&mut args);
+        y_plate_center_position
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        y_open_position.to_wire::<i32>()?.serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_drop_height.to_wire::<i32>()?.serialize(&mut args);
+        z_press_distance.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        z_speed.to_wire::<u32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 12, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -867,30 +2026,73 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn drop_plate_with(&self, request: &DropPlateRequest) -> Result<(), Error> {
+        self.drop_plate(
+            piglet_client::client::required(request.x_position, "DropPlateRequest", "x_position")?,
+            piglet_client::client::required(
+                request.x_acceleration,
+                "DropPlateRequest",
+                "x_acceleration",
+            )?,
+            piglet_client::client::required(
+                request.y_plate_center_position,
+                "DropPlateRequest",
+                "y_plate_center_position",
+            )?,
+            piglet_client::client::required(
+                request.y_open_position,
+                "DropPlateRequest",
+                "y_open_position",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "DropPlateRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.z_drop_height,
+                "DropPlateRequest",
+                "z_drop_height",
+            )?,
+            piglet_client::client::required(
+                request.z_press_distance,
+                "DropPlateRequest",
+                "z_press_distance",
+            )?,
+            piglet_client::client::required(request.z_final, "DropPlateRequest", "z_final")?,
+            piglet_client::client::required(request.z_speed, "DropPlateRequest", "z_speed")?,
+        )
+        .await
+    }
+
     pub async fn move_plate(
         &self,
 
-        x_position: i32,
+        x_position: Millimeters,
         x_acceleration: u32,
-        y_plate_center_position: i32,
-        traverse_height: i32,
-        z_final: i32,
-        z_speed: u32,
+        y_plate_center_position: Millimeters,
+        traverse_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
         x_acceleration.serialize(&mut args);
-        y_plate_center_position.serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_final.serialize(&mut args);
-        z_speed.serialize(&mut args);
+        y_plate_center_position
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        z_speed.to_wire::<u32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 13, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 13, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -908,7 +2110,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -923,7 +2125,9 @@ impl NimbusCorePipette {
         first_channel_number.serialize(&mut args);
         second_channel_number.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 14, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 14, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  first_channel_number: {:?}", first_channel_number),
@@ -937,7 +2141,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -945,12 +2149,14 @@ impl NimbusCorePipette {
     pub async fn is_initialized(&self) -> Result</* initialized= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 15, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 15, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.IsInitialized()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let initialized = bool::deserialize(&mut stream)?;
         Ok(initialized)
@@ -959,12 +2165,14 @@ impl NimbusCorePipette {
     pub async fn is_tip_present(&self) -> Result</* tip_present= */ Vec<i16>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 16, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 16, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.IsTipPresent()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let tip_present = Vec::<i16>::deserialize(&mut stream)?;
         Ok(tip_present)
@@ -973,12 +2181,14 @@ impl NimbusCorePipette {
     pub async fn is_core_gripper_tool_held(&self) -> Result<IsCoreGripperToolHeldReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 17, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 17, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.IsCoreGripperToolHeld()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let gripped = bool::deserialize(&mut stream)?;
         let tip_type = Vec::<u16>::deserialize(&mut stream)?;
@@ -988,12 +2198,14 @@ impl NimbusCorePipette {
     pub async fn is_core_gripper_plate_gripped(&self) -> Result</* gripped= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 18, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 18, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.IsCoreGripperPlateGripped()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let gripped = bool::deserialize(&mut stream)?;
         Ok(gripped)
@@ -1002,12 +2214,14 @@ impl NimbusCorePipette {
     pub async fn get_tip_and_needle_types(&self) -> Result</* tip_type= */ Vec<u16>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 19, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 19, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.GetTipAndNeedleTypes()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let tip_type = Vec::<u16>::deserialize(&mut stream)?;
         Ok(tip_type)
@@ -1016,16 +2230,24 @@ impl NimbusCorePipette {
     pub async fn get_position(&self) -> Result<GetPositionReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 20, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 20, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.GetPosition()".to_string(),
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
-        let x_position = i32::deserialize(&mut stream)?;
-        let y_position = Vec::<i32>::deserialize(&mut stream)?;
-        let z_position = Vec::<i32>::deserialize(&mut stream)?;
+        let x_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let y_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let z_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         Ok(GetPositionReply {
             x_position,
             y_position,
@@ -1036,12 +2258,14 @@ impl NimbusCorePipette {
     pub async fn park(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 21, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 21, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.Park()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1050,19 +2274,31 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: i32,
-        y_position: impl AsRef<[i32]>,
-        traverse_height: i32,
-        z_position: impl AsRef<[i32]>,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]>,
+        traverse_height: Millimeters,
+        z_position: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_position.as_ref().serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 22, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 22, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1079,7 +2315,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1088,17 +2324,29 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: i32,
-        y_position: impl AsRef<[i32]>,
-        z_position: impl AsRef<[i32]>,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]>,
+        z_position: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        z_position.as_ref().serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 23, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 23, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1114,7 +2362,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1123,17 +2371,24 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: i32,
-        y_position: impl AsRef<[i32]>,
-        traverse_height: i32,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]>,
+        traverse_height: Millimeters,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 24, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 24, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1149,7 +2404,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1158,15 +2413,22 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: i32,
-        y_position: impl AsRef<[i32]>,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 25, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 25, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1181,16 +2443,18 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn move_absolute_x(&self, x_position: i32) -> Result<(), Error> {
+    pub async fn move_absolute_x(&self, x_position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 26, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 26, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  x_position: {:?}", x_position)];
                 format!(
@@ -1201,16 +2465,18 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn move_relative_x(&self, x_distance: i32) -> Result<(), Error> {
+    pub async fn move_relative_x(&self, x_distance: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_distance.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 27, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 27, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  x_distance: {:?}", x_distance)];
                 format!(
@@ -1221,7 +2487,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1230,13 +2496,20 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        y_position: impl AsRef<[i32]>,
+        y_position: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 28, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 28, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1250,7 +2523,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1259,13 +2532,20 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        y_distance: impl AsRef<[i32]>,
+        y_distance: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        y_distance.as_ref().serialize(&mut args);
+        y_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 29, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 29, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1279,7 +2559,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1288,13 +2568,20 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_position: impl AsRef<[i32]>,
+        z_position: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_position.as_ref().serialize(&mut args);
+        z_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 30, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 30, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1308,7 +2595,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1317,13 +2604,20 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_distance: impl AsRef<[i32]>,
+        z_distance: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_distance.as_ref().serialize(&mut args);
+        z_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 31, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 31, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1337,7 +2631,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1345,12 +2639,14 @@ impl NimbusCorePipette {
     pub async fn preinitialize_smart(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 32, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 32, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.PreinitializeSmart()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1359,7 +2655,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 33, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 33, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -1370,7 +2668,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1393,7 +2691,9 @@ impl NimbusCorePipette {
         upper_limit_x.as_ref().serialize(&mut args);
         upper_limit_y.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 34, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 34, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -1411,7 +2711,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let index = u32::deserialize(&mut stream)?;
         Ok(CreateLimitCurveReply { index })
@@ -1421,7 +2721,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 35, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 35, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1432,7 +2734,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1445,7 +2747,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 36, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 36, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1456,7 +2760,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let offsets = Vec::<u16>::deserialize(&mut stream)?;
         let tadm_data = Vec::<i16>::deserialize(&mut stream)?;
@@ -1471,7 +2775,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         channel.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 37, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 37, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel: {:?}", channel)];
                 format!(
@@ -1482,7 +2788,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let names = String::deserialize(&mut stream)?;
         Ok(GetLimitCurveNamesReply { names })
@@ -1498,7 +2804,9 @@ impl NimbusCorePipette {
         channel.serialize(&mut args);
         name.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 38, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 38, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -1512,7 +2820,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let index = u32::deserialize(&mut stream)?;
         let lower_limits = u16::deserialize(&mut stream)?;
@@ -1534,7 +2842,9 @@ impl NimbusCorePipette {
         tips_used.as_ref().serialize(&mut args);
         enable.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 39, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 39, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1548,7 +2858,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1556,12 +2866,14 @@ impl NimbusCorePipette {
     pub async fn get_tadm_enable(&self) -> Result<GetTADMEnableReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 40, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 40, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.GetTADMEnable()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let enable = Vec::<i16>::deserialize(&mut stream)?;
         Ok(GetTADMEnableReply { enable })
@@ -1581,7 +2893,9 @@ impl NimbusCorePipette {
         minimum_pressure_difference.as_ref().serialize(&mut args);
         maximum_pressure_difference.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 41, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 41, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1603,7 +2917,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1612,7 +2926,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 42, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 42, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1623,7 +2939,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1632,7 +2948,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 43, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 43, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1643,7 +2961,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1652,7 +2970,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 44, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 44, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1663,7 +2983,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1672,13 +2992,20 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_position: impl AsRef<[i32]>,
+        z_position: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_position.as_ref().serialize(&mut args);
+        z_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 45, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 45, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1692,7 +3019,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1700,30 +3027,34 @@ impl NimbusCorePipette {
     pub async fn pickup_plate_suction(
         &self,
 
-        x_position: i32,
-        y_plate_center_position: i32,
+        x_position: Millimeters,
+        y_plate_center_position: Millimeters,
         y_suction_offset: i32,
-        blowout_volume: u32,
-        suction_volume: u32,
+        blowout_volume: Microliters,
+        suction_volume: Microliters,
         pressure_differential: u16,
-        traverse_height: i32,
-        z_grip_height: i32,
-        z_final: i32,
-        z_speed: u32,
+        traverse_height: Millimeters,
+        z_grip_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
     ) -> Result<PickupPlateSuctionReply, Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_plate_center_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_plate_center_position
+            .to_wire::<i32>()?
+            .serialize(&mut args);
         y_suction_offset.serialize(&mut args);
-        blowout_volume.serialize(&mut args);
-        suction_volume.serialize(&mut args);
+        blowout_volume.to_wire::<u32>()?.serialize(&mut args);
+        suction_volume.to_wire::<u32>()?.serialize(&mut args);
         pressure_differential.serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_grip_height.serialize(&mut args);
-        z_final.serialize(&mut args);
-        z_speed.serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_grip_height.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        z_speed.to_wire::<u32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 46, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 46, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -1745,7 +3076,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let first_pressure_change = i16::deserialize(&mut stream)?;
         let second_pressure_change = i16::deserialize(&mut stream)?;
@@ -1755,33 +3086,96 @@ impl NimbusCorePipette {
         })
     }
 
+    pub async fn pickup_plate_suction_with(
+        &self,
+        request: &PickupPlateSuctionRequest,
+    ) -> Result<PickupPlateSuctionReply, Error> {
+        self.pickup_plate_suction(
+            piglet_client::client::required(
+                request.x_position,
+                "PickupPlateSuctionRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_plate_center_position,
+                "PickupPlateSuctionRequest",
+                "y_plate_center_position",
+            )?,
+            piglet_client::client::required(
+                request.y_suction_offset,
+                "PickupPlateSuctionRequest",
+                "y_suction_offset",
+            )?,
+            piglet_client::client::required(
+                request.blowout_volume,
+                "PickupPlateSuctionRequest",
+                "blowout_volume",
+            )?,
+            piglet_client::client::required(
+                request.suction_volume,
+                "PickupPlateSuctionRequest",
+                "suction_volume",
+            )?,
+            piglet_client::client::required(
+                request.pressure_differential,
+                "PickupPlateSuctionRequest",
+                "pressure_differential",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "PickupPlateSuctionRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.z_grip_height,
+                "PickupPlateSuctionRequest",
+                "z_grip_height",
+            )?,
+            piglet_client::client::required(
+                request.z_final,
+                "PickupPlateSuctionRequest",
+                "z_final",
+            )?,
+            piglet_client::client::required(
+                request.z_speed,
+                "PickupPlateSuctionRequest",
+                "z_speed",
+            )?,
+        )
+        .await
+    }
+
     pub async fn drop_plate_suction(
         &self,
 
-        x_position: i32,
+        x_position: Millimeters,
         x_acceleration: u32,
-        y_plate_center_position: i32,
+        y_plate_center_position: Millimeters,
         pressure_differential: u16,
-        traverse_height: i32,
-        z_drop_height: i32,
-        z_press_distance: i32,
-        z_lift_distance: i32,
-        z_final: i32,
-        z_speed: u32,
+        traverse_height: Millimeters,
+        z_drop_height: Millimeters,
+        z_press_distance: Millimeters,
+        z_lift_distance: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
     ) -> Result<DropPlateSuctionReply, Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
         x_acceleration.serialize(&mut args);
-        y_plate_center_position.serialize(&mut args);
+        y_plate_center_position
+            .to_wire::<i32>()?
+            .serialize(&mut args);
         pressure_differential.serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_drop_height.serialize(&mut args);
-        z_press_distance.serialize(&mut args);
-        z_lift_distance.serialize(&mut args);
-        z_final.serialize(&mut args);
-        z_speed.serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_drop_height.to_wire::<i32>()?.serialize(&mut args);
+        z_press_distance.to_wire::<i32>()?.serialize(&mut args);
+        z_lift_distance.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        z_speed.to_wire::<u32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 47, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 47, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -1803,7 +3197,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let first_pressure_change = i16::deserialize(&mut stream)?;
         let second_pressure_change = i16::deserialize(&mut stream)?;
@@ -1813,27 +3207,82 @@ impl NimbusCorePipette {
         })
     }
 
+    pub async fn drop_plate_suction_with(
+        &self,
+        request: &DropPlateSuctionRequest,
+    ) -> Result<DropPlateSuctionReply, Error> {
+        self.drop_plate_suction(
+            piglet_client::client::required(
+                request.x_position,
+                "DropPlateSuctionRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.x_acceleration,
+                "DropPlateSuctionRequest",
+                "x_acceleration",
+            )?,
+            piglet_client::client::required(
+                request.y_plate_center_position,
+                "DropPlateSuctionRequest",
+                "y_plate_center_position",
+            )?,
+            piglet_client::client::required(
+                request.pressure_differential,
+                "DropPlateSuctionRequest",
+                "pressure_differential",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "DropPlateSuctionRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.z_drop_height,
+                "DropPlateSuctionRequest",
+                "z_drop_height",
+            )?,
+            piglet_client::client::required(
+                request.z_press_distance,
+                "DropPlateSuctionRequest",
+                "z_press_distance",
+            )?,
+            piglet_client::client::required(
+                request.z_lift_distance,
+                "DropPlateSuctionRequest",
+                "z_lift_distance",
+            )?,
+            piglet_client::client::required(request.z_final, "DropPlateSuctionRequest", "z_final")?,
+            piglet_client::client::required(request.z_speed, "DropPlateSuctionRequest", "z_speed")?,
+        )
+        .await
+    }
+
     pub async fn move_plate_suction(
         &self,
 
-        x_position: i32,
+        x_position: Millimeters,
         x_acceleration: u32,
-        y_plate_center_position: i32,
+        y_plate_center_position: Millimeters,
         pressure_differential: u16,
-        traverse_height: i32,
-        z_final: i32,
-        z_speed: u32,
+        traverse_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
         x_acceleration.serialize(&mut args);
-        y_plate_center_position.serialize(&mut args);
+        y_plate_center_position
+            .to_wire::<i32>()?
+            .serialize(&mut args);
         pressure_differential.serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_final.serialize(&mut args);
-        z_speed.serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        z_speed.to_wire::<u32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 48, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 48, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -1852,7 +3301,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1861,25 +3310,52 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        traverse_height: i32,
-        obstacle_seek_height: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
-        z_final: i32,
-        seek_speed: impl AsRef<[u32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        traverse_height: Millimeters,
+        obstacle_seek_height: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]>,
     ) -> Result</* obstacle_detected= */ Vec<i16>, Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        obstacle_seek_height.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
-        seek_speed.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        obstacle_seek_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        seek_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 49, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 49, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -1902,7 +3378,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let obstacle_detected = Vec::<i16>::deserialize(&mut stream)?;
         Ok(obstacle_detected)
@@ -1911,12 +3387,14 @@ impl NimbusCorePipette {
     pub async fn get_remaining_channels(&self) -> Result<GetRemainingChannelsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 50, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 50, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.GetRemainingChannels()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let channels = Vec::<u16>::deserialize(&mut stream)?;
         Ok(GetRemainingChannelsReply { channels })
@@ -1925,16 +3403,18 @@ impl NimbusCorePipette {
     pub async fn set_performance_parameters(
         &self,
 
-        z_speed: i32,
+        z_speed: MmPerSecond,
         time: u16,
         time_limit: u16,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        z_speed.serialize(&mut args);
+        z_speed.to_wire::<i32>()?.serialize(&mut args);
         time.serialize(&mut args);
         time_limit.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 51, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 51, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  z_speed: {:?}", z_speed),
@@ -1949,7 +3429,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1957,12 +3437,14 @@ impl NimbusCorePipette {
     pub async fn reset_performance_parameters(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 52, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 52, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.ResetPerformanceParameters()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1970,12 +3452,14 @@ impl NimbusCorePipette {
     pub async fn initialize_x(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 53, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 53, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.InitializeX()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -1984,7 +3468,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 54, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 54, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -1995,7 +3481,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2004,7 +3490,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 55, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 55, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2015,7 +3503,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2024,7 +3512,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 56, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 56, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2035,7 +3525,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2044,7 +3534,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 57, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 57, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2055,7 +3547,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2064,7 +3556,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 58, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 58, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2075,7 +3569,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2084,7 +3578,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 59, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 59, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2095,7 +3591,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2104,7 +3600,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 60, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 60, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -2115,7 +3613,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2124,13 +3622,20 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        volumes: impl AsRef<[i32]>,
+        volumes: impl AsRef<[Microliters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        volumes.as_ref().serialize(&mut args);
+        volumes
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 61, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 61, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2144,7 +3649,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2153,43 +3658,85 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: i32,
-        x_well_distance: i32,
-        x_well_width: u32,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
         x_number_of_wells: u16,
         x_velocity_scale: u16,
-        y_position: impl AsRef<[i32]>,
-        y_well_distance: impl AsRef<[i32]>,
-        volume: impl AsRef<[u32]>,
-        tip_air_volume: impl AsRef<[u32]>,
-        stop_back_volume: impl AsRef<[u32]>,
-        dispense_speed: impl AsRef<[u32]>,
-        cutoff_speed: impl AsRef<[u32]>,
-        traverse_height: i32,
-        dispense_height: impl AsRef<[i32]>,
-        z_final: i32,
+        y_position: impl AsRef<[Millimeters]>,
+        y_well_distance: impl AsRef<[Millimeters]>,
+        volume: impl AsRef<[Microliters]>,
+        tip_air_volume: impl AsRef<[Microliters]>,
+        stop_back_volume: impl AsRef<[Microliters]>,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]>,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]>,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
         dispense_map: impl AsRef<[u16]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.serialize(&mut args);
-        x_well_distance.serialize(&mut args);
-        x_well_width.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        x_well_distance.to_wire::<i32>()?.serialize(&mut args);
+        x_well_width.to_wire::<u32>()?.serialize(&mut args);
         x_number_of_wells.serialize(&mut args);
         x_velocity_scale.serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        y_well_distance.as_ref().serialize(&mut args);
-        volume.as_ref().serialize(&mut args);
-        tip_air_volume.as_ref().serialize(&mut args);
-        stop_back_volume.as_ref().serialize(&mut args);
-        dispense_speed.as_ref().serialize(&mut args);
-        cutoff_speed.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        dispense_height.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_well_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        tip_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        stop_back_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        cutoff_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        dispense_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
         dispense_map.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 62, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 62, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2218,58 +3765,190 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn multiple_dispense_with(
+        &self,
+        request: &MultipleDispenseRequest,
+    ) -> Result<(), Error> {
+        self.multiple_dispense(
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "MultipleDispenseRequest",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.x_position,
+                "MultipleDispenseRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.x_well_distance,
+                "MultipleDispenseRequest",
+                "x_well_distance",
+            )?,
+            piglet_client::client::required(
+                request.x_well_width,
+                "MultipleDispenseRequest",
+                "x_well_width",
+            )?,
+            piglet_client::client::required(
+                request.x_number_of_wells,
+                "MultipleDispenseRequest",
+                "x_number_of_wells",
+            )?,
+            piglet_client::client::required(
+                request.x_velocity_scale,
+                "MultipleDispenseRequest",
+                "x_velocity_scale",
+            )?,
+            piglet_client::client::required(
+                request.y_position.as_ref(),
+                "MultipleDispenseRequest",
+                "y_position",
+            )?,
+            piglet_client::client::required(
+                request.y_well_distance.as_ref(),
+                "MultipleDispenseRequest",
+                "y_well_distance",
+            )?,
+            piglet_client::client::required(
+                request.volume.as_ref(),
+                "MultipleDispenseRequest",
+                "volume",
+            )?,
+            piglet_client::client::required(
+                request.tip_air_volume.as_ref(),
+                "MultipleDispenseRequest",
+                "tip_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.stop_back_volume.as_ref(),
+                "MultipleDispenseRequest",
+                "stop_back_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_speed.as_ref(),
+                "MultipleDispenseRequest",
+                "dispense_speed",
+            )?,
+            piglet_client::client::required(
+                request.cutoff_speed.as_ref(),
+                "MultipleDispenseRequest",
+                "cutoff_speed",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "MultipleDispenseRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.dispense_height.as_ref(),
+                "MultipleDispenseRequest",
+                "dispense_height",
+            )?,
+            piglet_client::client::required(request.z_final, "MultipleDispenseRequest", "z_final")?,
+            piglet_client::client::required(
+                request.dispense_map.as_ref(),
+                "MultipleDispenseRequest",
+                "dispense_map",
+            )?,
+        )
+        .await
+    }
+
     pub async fn on_the_fly_dispense(
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: i32,
-        x_well_distance: i32,
-        x_well_width: u32,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
         x_number_of_wells: u16,
         x_velocity_scale: u16,
-        y_position: impl AsRef<[i32]>,
-        y_well_distance: impl AsRef<[i32]>,
-        volume: impl AsRef<[u32]>,
-        tip_air_volume: impl AsRef<[u32]>,
-        stop_back_volume: impl AsRef<[u32]>,
-        dispense_speed: impl AsRef<[u32]>,
-        cutoff_speed: impl AsRef<[u32]>,
+        y_position: impl AsRef<[Millimeters]>,
+        y_well_distance: impl AsRef<[Millimeters]>,
+        volume: impl AsRef<[Microliters]>,
+        tip_air_volume: impl AsRef<[Microliters]>,
+        stop_back_volume: impl AsRef<[Microliters]>,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]>,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]>,
         tadm_enabled: impl AsRef<[i16]>,
         limit_curve_index: impl AsRef<[u32]>,
         recording_mode: u16,
-        traverse_height: i32,
-        dispense_height: impl AsRef<[i32]>,
-        z_final: i32,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
         dispense_map: impl AsRef<[u16]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.serialize(&mut args);
-        x_well_distance.serialize(&mut args);
-        x_well_width.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        x_well_distance.to_wire::<i32>()?.serialize(&mut args);
+        x_well_width.to_wire::<u32>()?.serialize(&mut args);
         x_number_of_wells.serialize(&mut args);
         x_velocity_scale.serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        y_well_distance.as_ref().serialize(&mut args);
-        volume.as_ref().serialize(&mut args);
-        tip_air_volume.as_ref().serialize(&mut args);
-        stop_back_volume.as_ref().serialize(&mut args);
-        dispense_speed.as_ref().serialize(&mut args);
-        cutoff_speed.as_ref().serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_well_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        tip_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        stop_back_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        cutoff_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         tadm_enabled.as_ref().serialize(&mut args);
         limit_curve_index.as_ref().serialize(&mut args);
         recording_mode.serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        dispense_height.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        dispense_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
         dispense_map.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 63, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 63, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2301,36 +3980,153 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn get_current_dispenser_volume(&self) -> Result</* volume= */ Vec<u32>, Error> {
+    pub async fn on_the_fly_dispense_with(
+        &self,
+        request: &OnTheFlyDispenseRequest,
+    ) -> Result<(), Error> {
+        self.on_the_fly_dispense(
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "OnTheFlyDispenseRequest",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.x_position,
+                "OnTheFlyDispenseRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.x_well_distance,
+                "OnTheFlyDispenseRequest",
+                "x_well_distance",
+            )?,
+            piglet_client::client::required(
+                request.x_well_width,
+                "OnTheFlyDispenseRequest",
+                "x_well_width",
+            )?,
+            piglet_client::client::required(
+                request.x_number_of_wells,
+                "OnTheFlyDispenseRequest",
+                "x_number_of_wells",
+            )?,
+            piglet_client::client::required(
+                request.x_velocity_scale,
+                "OnTheFlyDispenseRequest",
+                "x_velocity_scale",
+            )?,
+            piglet_client::client::required(
+                request.y_position.as_ref(),
+                "OnTheFlyDispenseRequest",
+                "y_position",
+            )?,
+            piglet_client::client::required(
+                request.y_well_distance.as_ref(),
+                "OnTheFlyDispenseRequest",
+                "y_well_distance",
+            )?,
+            piglet_client::client::required(
+                request.volume.as_ref(),
+                "OnTheFlyDispenseRequest",
+                "volume",
+            )?,
+            piglet_client::client::required(
+                request.tip_air_volume.as_ref(),
+                "OnTheFlyDispenseRequest",
+                "tip_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.stop_back_volume.as_ref(),
+                "OnTheFlyDispenseRequest",
+                "stop_back_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_speed.as_ref(),
+                "OnTheFlyDispenseRequest",
+                "dispense_speed",
+            )?,
+            piglet_client::client::required(
+                request.cutoff_speed.as_ref(),
+                "OnTheFlyDispenseRequest",
+                "cutoff_speed",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled.as_ref(),
+                "OnTheFlyDispenseRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index.as_ref(),
+                "OnTheFlyDispenseRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "OnTheFlyDispenseRequest",
+                "recording_mode",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "OnTheFlyDispenseRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.dispense_height.as_ref(),
+                "OnTheFlyDispenseRequest",
+                "dispense_height",
+            )?,
+            piglet_client::client::required(request.z_final, "OnTheFlyDispenseRequest", "z_final")?,
+            piglet_client::client::required(
+                request.dispense_map.as_ref(),
+                "OnTheFlyDispenseRequest",
+                "dispense_map",
+            )?,
+        )
+        .await
+    }
+
+    pub async fn get_current_dispenser_volume(
+        &self,
+    ) -> Result</* volume= */ Vec<Microliters>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 64, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 64, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.GetCurrentDispenserVolume()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let volume = Vec::<u32>::deserialize(&mut stream)?;
+        let volume = Vec::<u32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Microliters::from_wire)
+            .collect::<Vec<_>>();
         Ok(volume)
     }
 
-    pub async fn get_liquid_height(&self) -> Result</* liquid_height= */ Vec<i32>, Error> {
+    pub async fn get_liquid_height(&self) -> Result</* liquid_height= */ Vec<Millimeters>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 65, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 65, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.GetLiquidHeight()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let liquid_height = Vec::<i32>::deserialize(&mut stream)?;
+        let liquid_height = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         Ok(liquid_height)
     }
 
@@ -2344,7 +4140,9 @@ impl NimbusCorePipette {
         channel.serialize(&mut args);
         indexes.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 66, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 66, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -2358,7 +4156,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let enabled = Vec::<bool>::deserialize(&mut stream)?;
         Ok(GetChannelConfigurationReply { enabled })
@@ -2376,7 +4174,9 @@ impl NimbusCorePipette {
         indexes.as_ref().serialize(&mut args);
         enables.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 67, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 67, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -2391,7 +4191,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2400,25 +4200,52 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        traverse_height: i32,
-        obstacle_seek_height: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
-        z_final: i32,
-        seek_speed: impl AsRef<[u32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        traverse_height: Millimeters,
+        obstacle_seek_height: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]>,
     ) -> Result<ZSeekObstaclePositionReply, Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        obstacle_seek_height.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
-        seek_speed.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        obstacle_seek_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        seek_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 68, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 68, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2441,9 +4268,12 @@ impl NimbusCorePipette {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
-        let z_position = Vec::<i32>::deserialize(&mut stream)?;
+        let z_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         let obstacle_detected = Vec::<i16>::deserialize(&mut stream)?;
         Ok(ZSeekObstaclePositionReply {
             z_position,
@@ -2455,27 +4285,54 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        traverse_height: i32,
-        seek_height: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
-        z_final: i32,
-        seek_speed: impl AsRef<[u32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        traverse_height: Millimeters,
+        seek_height: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]>,
         capacitive_lld_sensitivity: impl AsRef<[i16]>,
     ) -> Result<ZSeekLldPositionReply, Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        seek_height.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
-        seek_speed.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        seek_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        seek_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         capacitive_lld_sensitivity.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 69, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 69, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2499,9 +4356,12 @@ impl NimbusCorePipette {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
-        let z_position = Vec::<i32>::deserialize(&mut stream)?;
+        let z_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         let lld_detected = Vec::<i16>::deserialize(&mut stream)?;
         Ok(ZSeekLldPositionReply {
             z_position,
@@ -2509,21 +4369,88 @@ impl NimbusCorePipette {
         })
     }
 
+    pub async fn z_seek_lld_position_with(
+        &self,
+        request: &ZSeekLldPositionRequest,
+    ) -> Result<ZSeekLldPositionReply, Error> {
+        self.z_seek_lld_position(
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "ZSeekLldPositionRequest",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.x_position.as_ref(),
+                "ZSeekLldPositionRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_position.as_ref(),
+                "ZSeekLldPositionRequest",
+                "y_position",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "ZSeekLldPositionRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.seek_height.as_ref(),
+                "ZSeekLldPositionRequest",
+                "seek_height",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position.as_ref(),
+                "ZSeekLldPositionRequest",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(request.z_final, "ZSeekLldPositionRequest", "z_final")?,
+            piglet_client::client::required(
+                request.seek_speed.as_ref(),
+                "ZSeekLldPositionRequest",
+                "seek_speed",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_sensitivity.as_ref(),
+                "ZSeekLldPositionRequest",
+                "capacitive_lld_sensitivity",
+            )?,
+        )
+        .await
+    }
+
     pub async fn dispense_initialize_to_waste(
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        z_position: impl AsRef<[i32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        z_position: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        z_position.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 70, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 70, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2539,7 +4466,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2548,45 +4475,92 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: i32,
-        x_well_distance: i32,
-        x_well_width: u32,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
         x_number_of_wells: u16,
         x_velocity_scale: u16,
-        y_position: impl AsRef<[i32]>,
-        y_well_distance: impl AsRef<[i32]>,
-        volume: impl AsRef<[u32]>,
-        tip_air_volume: impl AsRef<[u32]>,
-        stop_back_volume: impl AsRef<[u32]>,
-        dispense_speed: impl AsRef<[u32]>,
-        cutoff_speed: impl AsRef<[u32]>,
-        traverse_height: i32,
-        dispense_height: impl AsRef<[i32]>,
-        z_final: i32,
+        y_position: impl AsRef<[Millimeters]>,
+        y_well_distance: impl AsRef<[Millimeters]>,
+        volume: impl AsRef<[Microliters]>,
+        tip_air_volume: impl AsRef<[Microliters]>,
+        stop_back_volume: impl AsRef<[Microliters]>,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]>,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]>,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
         dispense_map: impl AsRef<[u16]>,
-        transport_air_volume: impl AsRef<[u32]>,
+        transport_air_volume: impl AsRef<[Microliters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.serialize(&mut args);
-        x_well_distance.serialize(&mut args);
-        x_well_width.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        x_well_distance.to_wire::<i32>()?.serialize(&mut args);
+        x_well_width.to_wire::<u32>()?.serialize(&mut args);
         x_number_of_wells.serialize(&mut args);
         x_velocity_scale.serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        y_well_distance.as_ref().serialize(&mut args);
-        volume.as_ref().serialize(&mut args);
-        tip_air_volume.as_ref().serialize(&mut args);
-        stop_back_volume.as_ref().serialize(&mut args);
-        dispense_speed.as_ref().serialize(&mut args);
-        cutoff_speed.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        dispense_height.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_well_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        tip_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        stop_back_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        cutoff_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        dispense_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
         dispense_map.as_ref().serialize(&mut args);
-        transport_air_volume.as_ref().serialize(&mut args);
+        transport_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 71, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 71, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2619,60 +4593,206 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn multiple_dispense_transport_air_with(
+        &self,
+        request: &MultipleDispenseTransportAirRequest,
+    ) -> Result<(), Error> {
+        self.multiple_dispense_transport_air(
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "MultipleDispenseTransportAirRequest",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.x_position,
+                "MultipleDispenseTransportAirRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.x_well_distance,
+                "MultipleDispenseTransportAirRequest",
+                "x_well_distance",
+            )?,
+            piglet_client::client::required(
+                request.x_well_width,
+                "MultipleDispenseTransportAirRequest",
+                "x_well_width",
+            )?,
+            piglet_client::client::required(
+                request.x_number_of_wells,
+                "MultipleDispenseTransportAirRequest",
+                "x_number_of_wells",
+            )?,
+            piglet_client::client::required(
+                request.x_velocity_scale,
+                "MultipleDispenseTransportAirRequest",
+                "x_velocity_scale",
+            )?,
+            piglet_client::client::required(
+                request.y_position.as_ref(),
+                "MultipleDispenseTransportAirRequest",
+                "y_position",
+            )?,
+            piglet_client::client::required(
+                request.y_well_distance.as_ref(),
+                "MultipleDispenseTransportAirRequest",
+                "y_well_distance",
+            )?,
+            piglet_client::client::required(
+                request.volume.as_ref(),
+                "MultipleDispenseTransportAirRequest",
+                "volume",
+            )?,
+            piglet_client::client::required(
+                request.tip_air_volume.as_ref(),
+                "MultipleDispenseTransportAirRequest",
+                "tip_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.stop_back_volume.as_ref(),
+                "MultipleDispenseTransportAirRequest",
+                "stop_back_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_speed.as_ref(),
+                "MultipleDispenseTransportAirRequest",
+                "dispense_speed",
+            )?,
+            piglet_client::client::required(
+                request.cutoff_speed.as_ref(),
+                "MultipleDispenseTransportAirRequest",
+                "cutoff_speed",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "MultipleDispenseTransportAirRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.dispense_height.as_ref(),
+                "MultipleDispenseTransportAirRequest",
+                "dispense_height",
+            )?,
+            piglet_client::client::required(
+                request.z_final,
+                "MultipleDispenseTransportAirRequest",
+                "z_final",
+            )?,
+            piglet_client::client::required(
+                request.dispense_map.as_ref(),
+                "MultipleDispenseTransportAirRequest",
+                "dispense_map",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume.as_ref(),
+                "MultipleDispenseTransportAirRequest",
+                "transport_air_volume",
+            )?,
+        )
+        .await
+    }
+
     pub async fn on_the_fly_dispense_transport_air(
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: i32,
-        x_well_distance: i32,
-        x_well_width: u32,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
         x_number_of_wells: u16,
         x_velocity_scale: u16,
-        y_position: impl AsRef<[i32]>,
-        y_well_distance: impl AsRef<[i32]>,
-        volume: impl AsRef<[u32]>,
-        tip_air_volume: impl AsRef<[u32]>,
-        stop_back_volume: impl AsRef<[u32]>,
-        dispense_speed: impl AsRef<[u32]>,
-        cutoff_speed: impl AsRef<[u32]>,
+        y_position: impl AsRef<[Millimeters]>,
+        y_well_distance: impl AsRef<[Millimeters]>,
+        volume: impl AsRef<[Microliters]>,
+        tip_air_volume: impl AsRef<[Microliters]>,
+        stop_back_volume: impl AsRef<[Microliters]>,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]>,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]>,
         tadm_enabled: impl AsRef<[i16]>,
         limit_curve_index: impl AsRef<[u32]>,
         recording_mode: u16,
-        traverse_height: i32,
-        dispense_height: impl AsRef<[i32]>,
-        z_final: i32,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
         dispense_map: impl AsRef<[u16]>,
-        transport_air_volume: impl AsRef<[u32]>,
+        transport_air_volume: impl AsRef<[Microliters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.serialize(&mut args);
-        x_well_distance.serialize(&mut args);
-        x_well_width.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        x_well_distance.to_wire::<i32>()?.serialize(&mut args);
+        x_well_width.to_wire::<u32>()?.serialize(&mut args);
         x_number_of_wells.serialize(&mut args);
         x_velocity_scale.serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        y_well_distance.as_ref().serialize(&mut args);
-        volume.as_ref().serialize(&mut args);
-        tip_air_volume.as_ref().serialize(&mut args);
-        stop_back_volume.as_ref().serialize(&mut args);
-        dispense_speed.as_ref().serialize(&mut args);
-        cutoff_speed.as_ref().serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_well_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        tip_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        stop_back_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        dispense_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        cutoff_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         tadm_enabled.as_ref().serialize(&mut args);
         limit_curve_index.as_ref().serialize(&mut args);
         recording_mode.serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        dispense_height.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        dispense_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
         dispense_map.as_ref().serialize(&mut args);
-        transport_air_volume.as_ref().serialize(&mut args);
+        transport_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 72, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 72, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2708,22 +4828,143 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn on_the_fly_dispense_transport_air_with(
+        &self,
+        request: &OnTheFlyDispenseTransportAirRequest,
+    ) -> Result<(), Error> {
+        self.on_the_fly_dispense_transport_air(
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "OnTheFlyDispenseTransportAirRequest",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.x_position,
+                "OnTheFlyDispenseTransportAirRequest",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.x_well_distance,
+                "OnTheFlyDispenseTransportAirRequest",
+                "x_well_distance",
+            )?,
+            piglet_client::client::required(
+                request.x_well_width,
+                "OnTheFlyDispenseTransportAirRequest",
+                "x_well_width",
+            )?,
+            piglet_client::client::required(
+                request.x_number_of_wells,
+                "OnTheFlyDispenseTransportAirRequest",
+                "x_number_of_wells",
+            )?,
+            piglet_client::client::required(
+                request.x_velocity_scale,
+                "OnTheFlyDispenseTransportAirRequest",
+                "x_velocity_scale",
+            )?,
+            piglet_client::client::required(
+                request.y_position.as_ref(),
+                "OnTheFlyDispenseTransportAirRequest",
+                "y_position",
+            )?,
+            piglet_client::client::required(
+                request.y_well_distance.as_ref(),
+                "OnTheFlyDispenseTransportAirRequest",
+                "y_well_distance",
+            )?,
+            piglet_client::client::required(
+                request.volume.as_ref(),
+                "OnTheFlyDispenseTransportAirRequest",
+                "volume",
+            )?,
+            piglet_client::client::required(
+                request.tip_air_volume.as_ref(),
+                "OnTheFlyDispenseTransportAirRequest",
+                "tip_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.stop_back_volume.as_ref(),
+                "OnTheFlyDispenseTransportAirRequest",
+                "stop_back_volume",
+            )?,
+            piglet_client::client::required(
+                request.dispense_speed.as_ref(),
+                "OnTheFlyDispenseTransportAirRequest",
+                "dispense_speed",
+            )?,
+            piglet_client::client::required(
+                request.cutoff_speed.as_ref(),
+                "OnTheFlyDispenseTransportAirRequest",
+                "cutoff_speed",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled.as_ref(),
+                "OnTheFlyDispenseTransportAirRequest",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index.as_ref(),
+                "OnTheFlyDispenseTransportAirRequest",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "OnTheFlyDispenseTransportAirRequest",
+                "recording_mode",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "OnTheFlyDispenseTransportAirRequest",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.dispense_height.as_ref(),
+                "OnTheFlyDispenseTransportAirRequest",
+                "dispense_height",
+            )?,
+            piglet_client::client::required(
+                request.z_final,
+                "OnTheFlyDispenseTransportAirRequest",
+                "z_final",
+            )?,
+            piglet_client::client::required(
+                request.dispense_map.as_ref(),
+                "OnTheFlyDispenseTransportAirRequest",
+                "dispense_map",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume.as_ref(),
+                "OnTheFlyDispenseTransportAirRequest",
+                "transport_air_volume",
+            )?,
+        )
+        .await
+    }
+
     pub async fn set_z_liquid_seek_speed(
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        seek_speeds: impl AsRef<[u32]>,
+        seek_speeds: impl AsRef<[MmPerSecond]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        seek_speeds.as_ref().serialize(&mut args);
+        seek_speeds
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 73, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 73, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2737,22 +4978,29 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn get_z_liquid_seek_speed(&self) -> Result</* seek_speeds= */ Vec<u32>, Error> {
+    pub async fn get_z_liquid_seek_speed(
+        &self,
+    ) -> Result</* seek_speeds= */ Vec<MmPerSecond>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 74, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 74, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.GetZLiquidSeekSpeed()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let seek_speeds = Vec::<u32>::deserialize(&mut stream)?;
+        let seek_speeds = Vec::<u32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(MmPerSecond::from_wire)
+            .collect::<Vec<_>>();
         Ok(seek_speeds)
     }
 
@@ -2760,19 +5008,31 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        z_position: impl AsRef<[i32]>,
-        z_speed_switch_position: impl AsRef<[i32]>,
+        z_position: impl AsRef<[Millimeters]>,
+        z_speed_switch_position: impl AsRef<[Millimeters]>,
         velocity: impl AsRef<[u32]>,
         lower_section_velocity: impl AsRef<[u32]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        z_position.as_ref().serialize(&mut args);
-        z_speed_switch_position.as_ref().serialize(&mut args);
+        z_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_speed_switch_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         velocity.as_ref().serialize(&mut args);
         lower_section_velocity.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 75, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 75, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -2795,7 +5055,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2803,28 +5063,32 @@ impl NimbusCorePipette {
     pub async fn pickup_plate_suction_2(
         &self,
 
-        x_position: i32,
-        y_plate_center_position: i32,
+        x_position: Millimeters,
+        y_plate_center_position: Millimeters,
         y_suction_offset: i32,
-        blowout_volume: u32,
-        suction_volume: u32,
-        traverse_height: i32,
-        z_grip_height: i32,
-        z_final: i32,
-        z_speed: u32,
+        blowout_volume: Microliters,
+        suction_volume: Microliters,
+        traverse_height: Millimeters,
+        z_grip_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
-        y_plate_center_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_plate_center_position
+            .to_wire::<i32>()?
+            .serialize(&mut args);
         y_suction_offset.serialize(&mut args);
-        blowout_volume.serialize(&mut args);
-        suction_volume.serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_grip_height.serialize(&mut args);
-        z_final.serialize(&mut args);
-        z_speed.serialize(&mut args);
+        blowout_volume.to_wire::<u32>()?.serialize(&mut args);
+        suction_volume.to_wire::<u32>()?.serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_grip_height.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        z_speed.to_wire::<u32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 76, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 76, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -2845,36 +5109,94 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn pickup_plate_suction_2_with(
+        &self,
+        request: &PickupPlateSuction2Request,
+    ) -> Result<(), Error> {
+        self.pickup_plate_suction_2(
+            piglet_client::client::required(
+                request.x_position,
+                "PickupPlateSuction2Request",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_plate_center_position,
+                "PickupPlateSuction2Request",
+                "y_plate_center_position",
+            )?,
+            piglet_client::client::required(
+                request.y_suction_offset,
+                "PickupPlateSuction2Request",
+                "y_suction_offset",
+            )?,
+            piglet_client::client::required(
+                request.blowout_volume,
+                "PickupPlateSuction2Request",
+                "blowout_volume",
+            )?,
+            piglet_client::client::required(
+                request.suction_volume,
+                "PickupPlateSuction2Request",
+                "suction_volume",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "PickupPlateSuction2Request",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.z_grip_height,
+                "PickupPlateSuction2Request",
+                "z_grip_height",
+            )?,
+            piglet_client::client::required(
+                request.z_final,
+                "PickupPlateSuction2Request",
+                "z_final",
+            )?,
+            piglet_client::client::required(
+                request.z_speed,
+                "PickupPlateSuction2Request",
+                "z_speed",
+            )?,
+        )
+        .await
+    }
+
     pub async fn drop_plate_suction_2(
         &self,
 
-        x_position: i32,
+        x_position: Millimeters,
         x_acceleration: u32,
-        y_plate_center_position: i32,
-        traverse_height: i32,
-        z_drop_height: i32,
-        z_press_distance: i32,
-        z_lift_distance: i32,
-        z_final: i32,
-        z_speed: u32,
+        y_plate_center_position: Millimeters,
+        traverse_height: Millimeters,
+        z_drop_height: Millimeters,
+        z_press_distance: Millimeters,
+        z_lift_distance: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
         x_acceleration.serialize(&mut args);
-        y_plate_center_position.serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_drop_height.serialize(&mut args);
-        z_press_distance.serialize(&mut args);
-        z_lift_distance.serialize(&mut args);
-        z_final.serialize(&mut args);
-        z_speed.serialize(&mut args);
+        y_plate_center_position
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_drop_height.to_wire::<i32>()?.serialize(&mut args);
+        z_press_distance.to_wire::<i32>()?.serialize(&mut args);
+        z_lift_distance.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        z_speed.to_wire::<u32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 77, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 77, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -2895,30 +5217,88 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn drop_plate_suction_2_with(
+        &self,
+        request: &DropPlateSuction2Request,
+    ) -> Result<(), Error> {
+        self.drop_plate_suction_2(
+            piglet_client::client::required(
+                request.x_position,
+                "DropPlateSuction2Request",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.x_acceleration,
+                "DropPlateSuction2Request",
+                "x_acceleration",
+            )?,
+            piglet_client::client::required(
+                request.y_plate_center_position,
+                "DropPlateSuction2Request",
+                "y_plate_center_position",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "DropPlateSuction2Request",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.z_drop_height,
+                "DropPlateSuction2Request",
+                "z_drop_height",
+            )?,
+            piglet_client::client::required(
+                request.z_press_distance,
+                "DropPlateSuction2Request",
+                "z_press_distance",
+            )?,
+            piglet_client::client::required(
+                request.z_lift_distance,
+                "DropPlateSuction2Request",
+                "z_lift_distance",
+            )?,
+            piglet_client::client::required(
+                request.z_final,
+                "DropPlateSuction2Request",
+                "z_final",
+            )?,
+            piglet_client::client::required(
+                request.z_speed,
+                "DropPlateSuction2Request",
+                "z_speed",
+            )?,
+        )
+        .await
+    }
+
     pub async fn move_plate_suction_2(
         &self,
 
-        x_position: i32,
+        x_position: Millimeters,
         x_acceleration: u32,
-        y_plate_center_position: i32,
-        traverse_height: i32,
-        z_final: i32,
-        z_speed: u32,
+        y_plate_center_position: Millimeters,
+        traverse_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
         x_acceleration.serialize(&mut args);
-        y_plate_center_position.serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_final.serialize(&mut args);
-        z_speed.serialize(&mut args);
+        y_plate_center_position
+            .to_wire::<i32>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        z_speed.to_wire::<u32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 78, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 78, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -2936,7 +5316,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -2946,14 +5326,16 @@ impl NimbusCorePipette {
 
         first_channel_number: u16,
         second_channel_number: u16,
-        suction_volume: u32,
+        suction_volume: Microliters,
     ) -> Result<CheckPlateSuctionReply, Error> {
         let mut args = BytesMut::new();
         first_channel_number.serialize(&mut args);
         second_channel_number.serialize(&mut args);
-        suction_volume.serialize(&mut args);
+        suction_volume.to_wire::<u32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 79, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 79, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  first_channel_number: {:?}", first_channel_number),
@@ -2968,7 +5350,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let first_channel_has_plate = i16::deserialize(&mut stream)?;
         let second_channel_has_plate = i16::deserialize(&mut stream)?;
@@ -2981,22 +5363,54 @@ impl NimbusCorePipette {
     pub async fn initialize_roll(
         &self,
 
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
-        z_final: impl AsRef<[i32]>,
-        roll_distance: impl AsRef<[i32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
+        z_final: impl AsRef<[Millimeters]>,
+        roll_distance: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
-        z_final.as_ref().serialize(&mut args);
-        roll_distance.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        roll_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 80, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 80, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position.as_ref()),
@@ -3014,7 +5428,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -3022,22 +5436,54 @@ impl NimbusCorePipette {
     pub async fn initialize_smart_roll(
         &self,
 
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
-        z_final: impl AsRef<[i32]>,
-        roll_distance: impl AsRef<[i32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
+        z_final: impl AsRef<[Millimeters]>,
+        roll_distance: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
-        z_final.as_ref().serialize(&mut args);
-        roll_distance.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        roll_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 81, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 81, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position.as_ref()),
@@ -3055,7 +5501,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -3064,25 +5510,57 @@ impl NimbusCorePipette {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        traverse_height: i32,
-        z_start_position: impl AsRef<[i32]>,
-        z_stop_position: impl AsRef<[i32]>,
-        z_final: impl AsRef<[i32]>,
-        roll_distance: impl AsRef<[i32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]>,
+        z_stop_position: impl AsRef<[Millimeters]>,
+        z_final: impl AsRef<[Millimeters]>,
+        roll_distance: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        z_start_position.as_ref().serialize(&mut args);
-        z_stop_position.as_ref().serialize(&mut args);
-        z_final.as_ref().serialize(&mut args);
-        roll_distance.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        z_start_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_stop_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        roll_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 82, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 82, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -3102,7 +5580,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -3111,7 +5589,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 83, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 83, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -3122,7 +5602,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -3132,30 +5612,30 @@ impl NimbusCorePipette {
 
         aspirate_type: impl AsRef<[i16]>,
         tips_used: impl AsRef<[u16]>,
-        x_position: impl AsRef<[i32]>,
-        y_position: impl AsRef<[i32]>,
-        traverse_height: i32,
-        liquid_seek_height: impl AsRef<[i32]>,
-        liquid_surface_height: impl AsRef<[i32]>,
-        submerge_depth: impl AsRef<[i32]>,
-        follow_depth: impl AsRef<[i32]>,
-        z_min_position: impl AsRef<[i32]>,
-        clot_check_height: impl AsRef<[i32]>,
-        z_final: i32,
-        liquid_exit_speed: impl AsRef<[u32]>,
-        blowout_volume: impl AsRef<[u32]>,
-        prewet_volume: impl AsRef<[u32]>,
-        aspirate_volume: impl AsRef<[u32]>,
-        transport_air_volume: impl AsRef<[u32]>,
-        aspirate_speed: impl AsRef<[u32]>,
+        x_position: impl AsRef<[Millimeters]>,
+        y_position: impl AsRef<[Millimeters]>,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]>,
+        liquid_surface_height: impl AsRef<[Millimeters]>,
+        submerge_depth: impl AsRef<[Millimeters]>,
+        follow_depth: impl AsRef<[Millimeters]>,
+        z_min_position: impl AsRef<[Millimeters]>,
+        clot_check_height: impl AsRef<[Millimeters]>,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]>,
+        blowout_volume: impl AsRef<[Microliters]>,
+        prewet_volume: impl AsRef<[Microliters]>,
+        aspirate_volume: impl AsRef<[Microliters]>,
+        transport_air_volume: impl AsRef<[Microliters]>,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]>,
         settling_time: impl AsRef<[u32]>,
-        mix_volume: impl AsRef<[u32]>,
+        mix_volume: impl AsRef<[Microliters]>,
         mix_cycles: impl AsRef<[u32]>,
-        mix_position: impl AsRef<[i32]>,
-        mix_follow_distance: impl AsRef<[i32]>,
-        mix_speed: impl AsRef<[u32]>,
+        mix_position: impl AsRef<[Millimeters]>,
+        mix_follow_distance: impl AsRef<[Millimeters]>,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]>,
         aspirate_offset: impl AsRef<[i32]>,
-        tube_section_height: impl AsRef<[i32]>,
+        tube_section_height: impl AsRef<[Millimeters]>,
         tube_section_ratio: impl AsRef<[i32]>,
         lld_mode: impl AsRef<[i16]>,
         capacitive_lld_sensitivity: impl AsRef<[i16]>,
@@ -3168,30 +5648,125 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         aspirate_type.as_ref().serialize(&mut args);
         tips_used.as_ref().serialize(&mut args);
-        x_position.as_ref().serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
-        traverse_height.serialize(&mut args);
-        liquid_seek_height.as_ref().serialize(&mut args);
-        liquid_surface_height.as_ref().serialize(&mut args);
-        submerge_depth.as_ref().serialize(&mut args);
-        follow_depth.as_ref().serialize(&mut args);
-        z_min_position.as_ref().serialize(&mut args);
-        clot_check_height.as_ref().serialize(&mut args);
-        z_final.serialize(&mut args);
-        liquid_exit_speed.as_ref().serialize(&mut args);
-        blowout_volume.as_ref().serialize(&mut args);
-        prewet_volume.as_ref().serialize(&mut args);
-        aspirate_volume.as_ref().serialize(&mut args);
-        transport_air_volume.as_ref().serialize(&mut args);
-        aspirate_speed.as_ref().serialize(&mut args);
+        x_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        traverse_height.to_wire::<i32>()?.serialize(&mut args);
+        liquid_seek_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        liquid_surface_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        submerge_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        follow_depth
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_min_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        clot_check_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        z_final.to_wire::<i32>()?.serialize(&mut args);
+        liquid_exit_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        blowout_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        prewet_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        aspirate_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        transport_air_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
+        aspirate_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         settling_time.as_ref().serialize(&mut args);
-        mix_volume.as_ref().serialize(&mut args);
+        mix_volume
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         mix_cycles.as_ref().serialize(&mut args);
-        mix_position.as_ref().serialize(&mut args);
-        mix_follow_distance.as_ref().serialize(&mut args);
-        mix_speed.as_ref().serialize(&mut args);
+        mix_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_follow_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
+        mix_speed
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<u32>())
+            .collect::<Result<Vec<u32>, Error>>()?
+            .serialize(&mut args);
         aspirate_offset.as_ref().serialize(&mut args);
-        tube_section_height.as_ref().serialize(&mut args);
+        tube_section_height
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         tube_section_ratio.as_ref().serialize(&mut args);
         lld_mode.as_ref().serialize(&mut args);
         capacitive_lld_sensitivity.as_ref().serialize(&mut args);
@@ -3201,7 +5776,9 @@ impl NimbusCorePipette {
         limit_curve_index.as_ref().serialize(&mut args);
         recording_mode.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 84, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 84, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  aspirate_type: {:?}", aspirate_type.as_ref()),
@@ -3262,20 +5839,194 @@ impl NimbusCorePipette {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
+    pub async fn aspirate_2_with(&self, request: &Aspirate2Request) -> Result<(), Error> {
+        self.aspirate_2(
+            piglet_client::client::required(
+                request.aspirate_type.as_ref(),
+                "Aspirate2Request",
+                "aspirate_type",
+            )?,
+            piglet_client::client::required(
+                request.tips_used.as_ref(),
+                "Aspirate2Request",
+                "tips_used",
+            )?,
+            piglet_client::client::required(
+                request.x_position.as_ref(),
+                "Aspirate2Request",
+                "x_position",
+            )?,
+            piglet_client::client::required(
+                request.y_position.as_ref(),
+                "Aspirate2Request",
+                "y_position",
+            )?,
+            piglet_client::client::required(
+                request.traverse_height,
+                "Aspirate2Request",
+                "traverse_height",
+            )?,
+            piglet_client::client::required(
+                request.liquid_seek_height.as_ref(),
+                "Aspirate2Request",
+                "liquid_seek_height",
+            )?,
+            piglet_client::client::required(
+                request.liquid_surface_height.as_ref(),
+                "Aspirate2Request",
+                "liquid_surface_height",
+            )?,
+            piglet_client::client::required(
+                request.submerge_depth.as_ref(),
+                "Aspirate2Request",
+                "submerge_depth",
+            )?,
+            piglet_client::client::required(
+                request.follow_depth.as_ref(),
+                "Aspirate2Request",
+                "follow_depth",
+            )?,
+            piglet_client::client::required(
+                request.z_min_position.as_ref(),
+                "Aspirate2Request",
+                "z_min_position",
+            )?,
+            piglet_client::client::required(
+                request.clot_check_height.as_ref(),
+                "Aspirate2Request",
+                "clot_check_height",
+            )?,
+            piglet_client::client::required(request.z_final, "Aspirate2Request", "z_final")?,
+            piglet_client::client::required(
+                request.liquid_exit_speed.as_ref(),
+                "Aspirate2Request",
+                "liquid_exit_speed",
+            )?,
+            piglet_client::client::required(
+                request.blowout_volume.as_ref(),
+                "Aspirate2Request",
+                "blowout_volume",
+            )?,
+            piglet_client::client::required(
+                request.prewet_volume.as_ref(),
+                "Aspirate2Request",
+                "prewet_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_volume.as_ref(),
+                "Aspirate2Request",
+                "aspirate_volume",
+            )?,
+            piglet_client::client::required(
+                request.transport_air_volume.as_ref(),
+                "Aspirate2Request",
+                "transport_air_volume",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_speed.as_ref(),
+                "Aspirate2Request",
+                "aspirate_speed",
+            )?,
+            piglet_client::client::required(
+                request.settling_time.as_ref(),
+                "Aspirate2Request",
+                "settling_time",
+            )?,
+            piglet_client::client::required(
+                request.mix_volume.as_ref(),
+                "Aspirate2Request",
+                "mix_volume",
+            )?,
+            piglet_client::client::required(
+                request.mix_cycles.as_ref(),
+                "Aspirate2Request",
+                "mix_cycles",
+            )?,
+            piglet_client::client::required(
+                request.mix_position.as_ref(),
+                "Aspirate2Request",
+                "mix_position",
+            )?,
+            piglet_client::client::required(
+                request.mix_follow_distance.as_ref(),
+                "Aspirate2Request",
+                "mix_follow_distance",
+            )?,
+            piglet_client::client::required(
+                request.mix_speed.as_ref(),
+                "Aspirate2Request",
+                "mix_speed",
+            )?,
+            piglet_client::client::required(
+                request.aspirate_offset.as_ref(),
+                "Aspirate2Request",
+                "aspirate_offset",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_height.as_ref(),
+                "Aspirate2Request",
+                "tube_section_height",
+            )?,
+            piglet_client::client::required(
+                request.tube_section_ratio.as_ref(),
+                "Aspirate2Request",
+                "tube_section_ratio",
+            )?,
+            piglet_client::client::required(
+                request.lld_mode.as_ref(),
+                "Aspirate2Request",
+                "lld_mode",
+            )?,
+            piglet_client::client::required(
+                request.capacitive_lld_sensitivity.as_ref(),
+                "Aspirate2Request",
+                "capacitive_lld_sensitivity",
+            )?,
+            piglet_client::client::required(
+                request.pressure_lld_sensitivity.as_ref(),
+                "Aspirate2Request",
+                "pressure_lld_sensitivity",
+            )?,
+            piglet_client::client::required(
+                request.lld_height_difference.as_ref(),
+                "Aspirate2Request",
+                "lld_height_difference",
+            )?,
+            piglet_client::client::required(
+                request.tadm_enabled,
+                "Aspirate2Request",
+                "tadm_enabled",
+            )?,
+            piglet_client::client::required(
+                request.limit_curve_index.as_ref(),
+                "Aspirate2Request",
+                "limit_curve_index",
+            )?,
+            piglet_client::client::required(
+                request.recording_mode,
+                "Aspirate2Request",
+                "recording_mode",
+            )?,
+        )
+        .await
+    }
+
     pub async fn is_x_interlock_active(&self) -> Result</* active= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 85, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 85, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.IsXInterlockActive()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let active = bool::deserialize(&mut stream)?;
         Ok(active)
@@ -3284,12 +6035,14 @@ impl NimbusCorePipette {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -3307,7 +6060,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -3318,7 +6073,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -3340,7 +6095,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -3351,7 +6108,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -3366,12 +6123,14 @@ impl NimbusCorePipette {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipette.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -3385,7 +6144,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -3396,7 +6157,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -3414,7 +6175,9 @@ impl NimbusCorePipette {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -3425,7 +6188,7 @@ impl NimbusCorePipette {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -3440,103 +6203,7800 @@ impl NimbusCorePipette {
     }
 }
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct IsCoreGripperToolHeldReply {
-    pub gripped: bool,
-    pub tip_type: Vec<u16>,
-}
-
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GetPositionReply {
-    pub x_position: i32,
-    pub y_position: Vec<i32>,
-    pub z_position: Vec<i32>,
-}
-
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct CreateLimitCurveReply {
-    pub index: u32,
-}
-
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct RetrieveTadmDataReply {
-    pub offsets: Vec<u16>,
-    pub tadm_data: Vec<i16>,
-}
-
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GetLimitCurveNamesReply {
-    pub names: String,
-}
-
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GetLimitCurveInfoReply {
-    pub index: u32,
-    pub lower_limits: u16,
-    pub upper_limits: u16,
-}
+// The methods above as a trait, so code using them can be tested against NimbusCorePipetteFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCorePipetteApi {
+    fn initialize(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn initialize_smart(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn tip_and_needle_definition(
+        &self,
+        tip_type: u16,
+        volume: Microliters,
+        length: u16,
+        collar_type: i8,
+        filtered: bool,
+        needle: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pickup_tips(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        tip_type: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn drop_tips(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        default_waste: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn aspirate_1(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GetTADMEnableReply {
-    pub enable: Vec<i16>,
-}
+    fn aspirate_1_with(
+        &self,
+        request: &Aspirate1Request,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.aspirate_1(
+                piglet_client::client::required(
+                    request.aspirate_type.as_ref(),
+                    "Aspirate1Request",
+                    "aspirate_type",
+                )?,
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "Aspirate1Request",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.x_position.as_ref(),
+                    "Aspirate1Request",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_position.as_ref(),
+                    "Aspirate1Request",
+                    "y_position",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "Aspirate1Request",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_seek_height.as_ref(),
+                    "Aspirate1Request",
+                    "liquid_seek_height",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_surface_height.as_ref(),
+                    "Aspirate1Request",
+                    "liquid_surface_height",
+                )?,
+                piglet_client::client::required(
+                    request.submerge_depth.as_ref(),
+                    "Aspirate1Request",
+                    "submerge_depth",
+                )?,
+                piglet_client::client::required(
+                    request.follow_depth.as_ref(),
+                    "Aspirate1Request",
+                    "follow_depth",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position.as_ref(),
+                    "Aspirate1Request",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(
+                    request.clot_check_height.as_ref(),
+                    "Aspirate1Request",
+                    "clot_check_height",
+                )?,
+                piglet_client::client::required(request.z_final, "Aspirate1Request", "z_final")?,
+                piglet_client::client::required(
+                    request.liquid_exit_speed.as_ref(),
+                    "Aspirate1Request",
+                    "liquid_exit_speed",
+                )?,
+                piglet_client::client::required(
+                    request.blowout_volume.as_ref(),
+                    "Aspirate1Request",
+                    "blowout_volume",
+                )?,
+                piglet_client::client::required(
+                    request.prewet_volume.as_ref(),
+                    "Aspirate1Request",
+                    "prewet_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_volume.as_ref(),
+                    "Aspirate1Request",
+                    "aspirate_volume",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume.as_ref(),
+                    "Aspirate1Request",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_speed.as_ref(),
+                    "Aspirate1Request",
+                    "aspirate_speed",
+                )?,
+                piglet_client::client::required(
+                    request.settling_time.as_ref(),
+                    "Aspirate1Request",
+                    "settling_time",
+                )?,
+                piglet_client::client::required(
+                    request.mix_volume.as_ref(),
+                    "Aspirate1Request",
+                    "mix_volume",
+                )?,
+                piglet_client::client::required(
+                    request.mix_cycles.as_ref(),
+                    "Aspirate1Request",
+                    "mix_cycles",
+                )?,
+                piglet_client::client::required(
+                    request.mix_position.as_ref(),
+                    "Aspirate1Request",
+                    "mix_position",
+                )?,
+                piglet_client::client::required(
+                    request.mix_follow_distance.as_ref(),
+                    "Aspirate1Request",
+                    "mix_follow_distance",
+                )?,
+                piglet_client::client::required(
+                    request.mix_speed.as_ref(),
+                    "Aspirate1Request",
+                    "mix_speed",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_height.as_ref(),
+                    "Aspirate1Request",
+                    "tube_section_height",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_ratio.as_ref(),
+                    "Aspirate1Request",
+                    "tube_section_ratio",
+                )?,
+                piglet_client::client::required(
+                    request.lld_mode.as_ref(),
+                    "Aspirate1Request",
+                    "lld_mode",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_sensitivity.as_ref(),
+                    "Aspirate1Request",
+                    "capacitive_lld_sensitivity",
+                )?,
+                piglet_client::client::required(
+                    request.pressure_lld_sensitivity.as_ref(),
+                    "Aspirate1Request",
+                    "pressure_lld_sensitivity",
+                )?,
+                piglet_client::client::required(
+                    request.lld_height_difference.as_ref(),
+                    "Aspirate1Request",
+                    "lld_height_difference",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "Aspirate1Request",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index.as_ref(),
+                    "Aspirate1Request",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "Aspirate1Request",
+                    "recording_mode",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn dispense(
+        &self,
+        dispense_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        touch_off_distance: Millimeters,
+        dispense_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct PickupPlateSuctionReply {
-    pub first_pressure_change: i16,
-    pub second_pressure_change: i16,
-}
+    fn dispense_with(
+        &self,
+        request: &DispenseRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.dispense(
+                piglet_client::client::required(
+                    request.dispense_type.as_ref(),
+                    "DispenseRequest",
+                    "dispense_type",
+                )?,
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "DispenseRequest",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.x_position.as_ref(),
+                    "DispenseRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_position.as_ref(),
+                    "DispenseRequest",
+                    "y_position",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "DispenseRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_seek_height.as_ref(),
+                    "DispenseRequest",
+                    "liquid_seek_height",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_height.as_ref(),
+                    "DispenseRequest",
+                    "dispense_height",
+                )?,
+                piglet_client::client::required(
+                    request.submerge_depth.as_ref(),
+                    "DispenseRequest",
+                    "submerge_depth",
+                )?,
+                piglet_client::client::required(
+                    request.follow_depth.as_ref(),
+                    "DispenseRequest",
+                    "follow_depth",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position.as_ref(),
+                    "DispenseRequest",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(request.z_final, "DispenseRequest", "z_final")?,
+                piglet_client::client::required(
+                    request.liquid_exit_speed.as_ref(),
+                    "DispenseRequest",
+                    "liquid_exit_speed",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume.as_ref(),
+                    "DispenseRequest",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_volume.as_ref(),
+                    "DispenseRequest",
+                    "dispense_volume",
+                )?,
+                piglet_client::client::required(
+                    request.stop_back_volume.as_ref(),
+                    "DispenseRequest",
+                    "stop_back_volume",
+                )?,
+                piglet_client::client::required(
+                    request.blowout_volume.as_ref(),
+                    "DispenseRequest",
+                    "blowout_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_speed.as_ref(),
+                    "DispenseRequest",
+                    "dispense_speed",
+                )?,
+                piglet_client::client::required(
+                    request.cutoff_speed.as_ref(),
+                    "DispenseRequest",
+                    "cutoff_speed",
+                )?,
+                piglet_client::client::required(
+                    request.settling_time.as_ref(),
+                    "DispenseRequest",
+                    "settling_time",
+                )?,
+                piglet_client::client::required(
+                    request.mix_volume.as_ref(),
+                    "DispenseRequest",
+                    "mix_volume",
+                )?,
+                piglet_client::client::required(
+                    request.mix_cycles.as_ref(),
+                    "DispenseRequest",
+                    "mix_cycles",
+                )?,
+                piglet_client::client::required(
+                    request.mix_position.as_ref(),
+                    "DispenseRequest",
+                    "mix_position",
+                )?,
+                piglet_client::client::required(
+                    request.mix_follow_distance.as_ref(),
+                    "DispenseRequest",
+                    "mix_follow_distance",
+                )?,
+                piglet_client::client::required(
+                    request.mix_speed.as_ref(),
+                    "DispenseRequest",
+                    "mix_speed",
+                )?,
+                piglet_client::client::required(
+                    request.touch_off_distance,
+                    "DispenseRequest",
+                    "touch_off_distance",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_offset.as_ref(),
+                    "DispenseRequest",
+                    "dispense_offset",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_height.as_ref(),
+                    "DispenseRequest",
+                    "tube_section_height",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_ratio.as_ref(),
+                    "DispenseRequest",
+                    "tube_section_ratio",
+                )?,
+                piglet_client::client::required(
+                    request.lld_mode.as_ref(),
+                    "DispenseRequest",
+                    "lld_mode",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_sensitivity.as_ref(),
+                    "DispenseRequest",
+                    "capacitive_lld_sensitivity",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "DispenseRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index.as_ref(),
+                    "DispenseRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "DispenseRequest",
+                    "recording_mode",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn aspirate_and_dispense(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        dispense_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        aspirate_dispense_pattern: impl AsRef<[i16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        dispense_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct DropPlateSuctionReply {
-    pub first_pressure_change: i16,
-    pub second_pressure_change: i16,
-}
+    fn aspirate_and_dispense_with(
+        &self,
+        request: &AspirateAndDispenseRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.aspirate_and_dispense(
+                piglet_client::client::required(
+                    request.aspirate_type.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "aspirate_type",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_type.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "dispense_type",
+                )?,
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_dispense_pattern.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "aspirate_dispense_pattern",
+                )?,
+                piglet_client::client::required(
+                    request.x_position.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_position.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "y_position",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "AspirateAndDispenseRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_seek_height.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "liquid_seek_height",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_surface_height.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "liquid_surface_height",
+                )?,
+                piglet_client::client::required(
+                    request.submerge_depth.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "submerge_depth",
+                )?,
+                piglet_client::client::required(
+                    request.follow_depth.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "follow_depth",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(
+                    request.clot_check_height.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "clot_check_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "AspirateAndDispenseRequest",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_exit_speed.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "liquid_exit_speed",
+                )?,
+                piglet_client::client::required(
+                    request.blowout_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "blowout_volume",
+                )?,
+                piglet_client::client::required(
+                    request.prewet_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "prewet_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "aspirate_volume",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "dispense_volume",
+                )?,
+                piglet_client::client::required(
+                    request.stop_back_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "stop_back_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_speed.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "aspirate_speed",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_speed.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "dispense_speed",
+                )?,
+                piglet_client::client::required(
+                    request.cutoff_speed.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "cutoff_speed",
+                )?,
+                piglet_client::client::required(
+                    request.settling_time.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "settling_time",
+                )?,
+                piglet_client::client::required(
+                    request.mix_volume.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "mix_volume",
+                )?,
+                piglet_client::client::required(
+                    request.mix_cycles.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "mix_cycles",
+                )?,
+                piglet_client::client::required(
+                    request.mix_position.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "mix_position",
+                )?,
+                piglet_client::client::required(
+                    request.mix_follow_distance.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "mix_follow_distance",
+                )?,
+                piglet_client::client::required(
+                    request.mix_speed.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "mix_speed",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_offset.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "dispense_offset",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_height.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "tube_section_height",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_ratio.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "tube_section_ratio",
+                )?,
+                piglet_client::client::required(
+                    request.lld_mode.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "lld_mode",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_sensitivity.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "capacitive_lld_sensitivity",
+                )?,
+                piglet_client::client::required(
+                    request.pressure_lld_sensitivity.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "pressure_lld_sensitivity",
+                )?,
+                piglet_client::client::required(
+                    request.lld_height_difference.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "lld_height_difference",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "AspirateAndDispenseRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index.as_ref(),
+                    "AspirateAndDispenseRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "AspirateAndDispenseRequest",
+                    "recording_mode",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn pickup_gripper_tool(
+        &self,
+        x_position: Millimeters,
+        y_position_1_st_channel: Millimeters,
+        y_position_2_nd_channel: Millimeters,
+        traverse_height: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        tip_type: u16,
+        first_channel_number: u16,
+        second_channel_number: u16,
+        tool_width: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GetRemainingChannelsReply {
-    pub channels: Vec<u16>,
-}
+    fn pickup_gripper_tool_with(
+        &self,
+        request: &PickupGripperToolRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.pickup_gripper_tool(
+                piglet_client::client::required(
+                    request.x_position,
+                    "PickupGripperToolRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_position_1_st_channel,
+                    "PickupGripperToolRequest",
+                    "y_position_1_st_channel",
+                )?,
+                piglet_client::client::required(
+                    request.y_position_2_nd_channel,
+                    "PickupGripperToolRequest",
+                    "y_position_2_nd_channel",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "PickupGripperToolRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_start_position,
+                    "PickupGripperToolRequest",
+                    "z_start_position",
+                )?,
+                piglet_client::client::required(
+                    request.z_stop_position,
+                    "PickupGripperToolRequest",
+                    "z_stop_position",
+                )?,
+                piglet_client::client::required(
+                    request.tip_type,
+                    "PickupGripperToolRequest",
+                    "tip_type",
+                )?,
+                piglet_client::client::required(
+                    request.first_channel_number,
+                    "PickupGripperToolRequest",
+                    "first_channel_number",
+                )?,
+                piglet_client::client::required(
+                    request.second_channel_number,
+                    "PickupGripperToolRequest",
+                    "second_channel_number",
+                )?,
+                piglet_client::client::required(
+                    request.tool_width,
+                    "PickupGripperToolRequest",
+                    "tool_width",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn drop_gripper_tool(
+        &self,
+        x_position: Millimeters,
+        y_position_1_st_channel: Millimeters,
+        y_position_2_nd_channel: Millimeters,
+        traverse_height: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        first_channel_number: u16,
+        second_channel_number: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct GetChannelConfigurationReply {
-    pub enabled: Vec<bool>,
-}
+    fn drop_gripper_tool_with(
+        &self,
+        request: &DropGripperToolRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.drop_gripper_tool(
+                piglet_client::client::required(
+                    request.x_position,
+                    "DropGripperToolRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_position_1_st_channel,
+                    "DropGripperToolRequest",
+                    "y_position_1_st_channel",
+                )?,
+                piglet_client::client::required(
+                    request.y_position_2_nd_channel,
+                    "DropGripperToolRequest",
+                    "y_position_2_nd_channel",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "DropGripperToolRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_start_position,
+                    "DropGripperToolRequest",
+                    "z_start_position",
+                )?,
+                piglet_client::client::required(
+                    request.z_stop_position,
+                    "DropGripperToolRequest",
+                    "z_stop_position",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "DropGripperToolRequest",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.first_channel_number,
+                    "DropGripperToolRequest",
+                    "first_channel_number",
+                )?,
+                piglet_client::client::required(
+                    request.second_channel_number,
+                    "DropGripperToolRequest",
+                    "second_channel_number",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn pickup_plate(
+        &self,
+        x_position: Millimeters,
+        y_plate_center_position: Millimeters,
+        y_plate_width: Millimeters,
+        y_open_position: Millimeters,
+        y_grip_speed: MmPerSecond,
+        y_grip_strength: u32,
+        traverse_height: Millimeters,
+        z_grip_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct ZSeekObstaclePositionReply {
-    pub z_position: Vec<i32>,
-    pub obstacle_detected: Vec<i16>,
-}
+    fn pickup_plate_with(
+        &self,
+        request: &PickupPlateRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.pickup_plate(
+                piglet_client::client::required(
+                    request.x_position,
+                    "PickupPlateRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_plate_center_position,
+                    "PickupPlateRequest",
+                    "y_plate_center_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_plate_width,
+                    "PickupPlateRequest",
+                    "y_plate_width",
+                )?,
+                piglet_client::client::required(
+                    request.y_open_position,
+                    "PickupPlateRequest",
+                    "y_open_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_grip_speed,
+                    "PickupPlateRequest",
+                    "y_grip_speed",
+                )?,
+                piglet_client::client::required(
+                    request.y_grip_strength,
+                    "PickupPlateRequest",
+                    "y_grip_strength",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "PickupPlateRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_grip_height,
+                    "PickupPlateRequest",
+                    "z_grip_height",
+                )?,
+                piglet_client::client::required(request.z_final, "PickupPlateRequest", "z_final")?,
+                piglet_client::client::required(request.z_speed, "PickupPlateRequest", "z_speed")?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn drop_plate(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        y_open_position: Millimeters,
+        traverse_height: Millimeters,
+        z_drop_height: Millimeters,
+        z_press_distance: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn drop_plate_with(
+        &self,
+        request: &DropPlateRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.drop_plate(
+                piglet_client::client::required(
+                    request.x_position,
+                    "DropPlateRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.x_acceleration,
+                    "DropPlateRequest",
+                    "x_acceleration",
+                )?,
+                piglet_client::client::required(
+                    request.y_plate_center_position,
+                    "DropPlateRequest",
+                    "y_plate_center_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_open_position,
+                    "DropPlateRequest",
+                    "y_open_position",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "DropPlateRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_drop_height,
+                    "DropPlateRequest",
+                    "z_drop_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_press_distance,
+                    "DropPlateRequest",
+                    "z_press_distance",
+                )?,
+                piglet_client::client::required(request.z_final, "DropPlateRequest", "z_final")?,
+                piglet_client::client::required(request.z_speed, "DropPlateRequest", "z_speed")?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn move_plate(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        traverse_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn release_plate(
+        &self,
+        first_channel_number: u16,
+        second_channel_number: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn is_initialized(&self)
+    -> impl Future<Output = Result</* initialized= */ bool, Error>> + Send;
+    fn is_tip_present(
+        &self,
+    ) -> impl Future<Output = Result</* tip_present= */ Vec<i16>, Error>> + Send;
+    fn is_core_gripper_tool_held(
+        &self,
+    ) -> impl Future<Output = Result<IsCoreGripperToolHeldReply, Error>> + Send;
+    fn is_core_gripper_plate_gripped(
+        &self,
+    ) -> impl Future<Output = Result</* gripped= */ bool, Error>> + Send;
+    fn get_tip_and_needle_types(
+        &self,
+    ) -> impl Future<Output = Result</* tip_type= */ Vec<u16>, Error>> + Send;
+    fn get_position(&self) -> impl Future<Output = Result<GetPositionReply, Error>> + Send;
+    fn park(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_over(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_to_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_to_position_via_lane(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_absolute_xy(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_absolute_x(
+        &self,
+        x_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_relative_x(
+        &self,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_absolute_y(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_relative_y(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_absolute_z(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_relative_z(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn preinitialize_smart(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn erase_limit_curves(&self, channel: u16) -> impl Future<Output = Result<(), Error>> + Send;
+    fn create_limit_curve(
+        &self,
+        channel: u16,
+        name: &str,
+        lower_limit_x: impl AsRef<[u16]> + Send,
+        lower_limit_y: impl AsRef<[i16]> + Send,
+        upper_limit_x: impl AsRef<[u16]> + Send,
+        upper_limit_y: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<CreateLimitCurveReply, Error>> + Send;
+    fn reset_tadm_fifo(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn retrieve_tadm_data(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<RetrieveTadmDataReply, Error>> + Send;
+    fn get_limit_curve_names(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<GetLimitCurveNamesReply, Error>> + Send;
+    fn get_limit_curve_info(
+        &self,
+        channel: u16,
+        name: &str,
+    ) -> impl Future<Output = Result<GetLimitCurveInfoReply, Error>> + Send;
+    fn set_tadm_enable(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        enable: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_tadm_enable(&self) -> impl Future<Output = Result<GetTADMEnableReply, Error>> + Send;
+    fn enable_mad(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        pressure_threshold: impl AsRef<[u16]> + Send,
+        minimum_pressure_difference: impl AsRef<[u16]> + Send,
+        maximum_pressure_difference: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn disable_mad(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn enable_adc(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn disable_adc(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_traverse_z(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pickup_plate_suction(
+        &self,
+        x_position: Millimeters,
+        y_plate_center_position: Millimeters,
+        y_suction_offset: i32,
+        blowout_volume: Microliters,
+        suction_volume: Microliters,
+        pressure_differential: u16,
+        traverse_height: Millimeters,
+        z_grip_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<PickupPlateSuctionReply, Error>> + Send;
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct ZSeekLldPositionReply {
-    pub z_position: Vec<i32>,
-    pub lld_detected: Vec<i16>,
-}
+    fn pickup_plate_suction_with(
+        &self,
+        request: &PickupPlateSuctionRequest,
+    ) -> impl Future<Output = Result<PickupPlateSuctionReply, Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.pickup_plate_suction(
+                piglet_client::client::required(
+                    request.x_position,
+                    "PickupPlateSuctionRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_plate_center_position,
+                    "PickupPlateSuctionRequest",
+                    "y_plate_center_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_suction_offset,
+                    "PickupPlateSuctionRequest",
+                    "y_suction_offset",
+                )?,
+                piglet_client::client::required(
+                    request.blowout_volume,
+                    "PickupPlateSuctionRequest",
+                    "blowout_volume",
+                )?,
+                piglet_client::client::required(
+                    request.suction_volume,
+                    "PickupPlateSuctionRequest",
+                    "suction_volume",
+                )?,
+                piglet_client::client::required(
+                    request.pressure_differential,
+                    "PickupPlateSuctionRequest",
+                    "pressure_differential",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "PickupPlateSuctionRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_grip_height,
+                    "PickupPlateSuctionRequest",
+                    "z_grip_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "PickupPlateSuctionRequest",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.z_speed,
+                    "PickupPlateSuctionRequest",
+                    "z_speed",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn drop_plate_suction(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        pressure_differential: u16,
+        traverse_height: Millimeters,
+        z_drop_height: Millimeters,
+        z_press_distance: Millimeters,
+        z_lift_distance: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<DropPlateSuctionReply, Error>> + Send;
+
+    fn drop_plate_suction_with(
+        &self,
+        request: &DropPlateSuctionRequest,
+    ) -> impl Future<Output = Result<DropPlateSuctionReply, Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.drop_plate_suction(
+                piglet_client::client::required(
+                    request.x_position,
+                    "DropPlateSuctionRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.x_acceleration,
+                    "DropPlateSuctionRequest",
+                    "x_acceleration",
+                )?,
+                piglet_client::client::required(
+                    request.y_plate_center_position,
+                    "DropPlateSuctionRequest",
+                    "y_plate_center_position",
+                )?,
+                piglet_client::client::required(
+                    request.pressure_differential,
+                    "DropPlateSuctionRequest",
+                    "pressure_differential",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "DropPlateSuctionRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_drop_height,
+                    "DropPlateSuctionRequest",
+                    "z_drop_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_press_distance,
+                    "DropPlateSuctionRequest",
+                    "z_press_distance",
+                )?,
+                piglet_client::client::required(
+                    request.z_lift_distance,
+                    "DropPlateSuctionRequest",
+                    "z_lift_distance",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "DropPlateSuctionRequest",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.z_speed,
+                    "DropPlateSuctionRequest",
+                    "z_speed",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn move_plate_suction(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        pressure_differential: u16,
+        traverse_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_seek_obstacle(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        obstacle_seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result</* obstacle_detected= */ Vec<i16>, Error>> + Send;
+    fn get_remaining_channels(
+        &self,
+    ) -> impl Future<Output = Result<GetRemainingChannelsReply, Error>> + Send;
+    fn set_performance_parameters(
+        &self,
+        z_speed: MmPerSecond,
+        time: u16,
+        time_limit: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn reset_performance_parameters(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn initialize_x(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn initialize_y(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn initialize_z(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn initialize_squeeze(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn initialize_dispenser(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn squeeze_on(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn squeeze_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn empty_dispenser(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_relative_dispenser(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        volumes: impl AsRef<[Microliters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn multiple_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
+        x_number_of_wells: u16,
+        x_velocity_scale: u16,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        y_well_distance: impl AsRef<[Millimeters]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        tip_air_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        dispense_map: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn multiple_dispense_with(
+        &self,
+        request: &MultipleDispenseRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.multiple_dispense(
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "MultipleDispenseRequest",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.x_position,
+                    "MultipleDispenseRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.x_well_distance,
+                    "MultipleDispenseRequest",
+                    "x_well_distance",
+                )?,
+                piglet_client::client::required(
+                    request.x_well_width,
+                    "MultipleDispenseRequest",
+                    "x_well_width",
+                )?,
+                piglet_client::client::required(
+                    request.x_number_of_wells,
+                    "MultipleDispenseRequest",
+                    "x_number_of_wells",
+                )?,
+                piglet_client::client::required(
+                    request.x_velocity_scale,
+                    "MultipleDispenseRequest",
+                    "x_velocity_scale",
+                )?,
+                piglet_client::client::required(
+                    request.y_position.as_ref(),
+                    "MultipleDispenseRequest",
+                    "y_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_well_distance.as_ref(),
+                    "MultipleDispenseRequest",
+                    "y_well_distance",
+                )?,
+                piglet_client::client::required(
+                    request.volume.as_ref(),
+                    "MultipleDispenseRequest",
+                    "volume",
+                )?,
+                piglet_client::client::required(
+                    request.tip_air_volume.as_ref(),
+                    "MultipleDispenseRequest",
+                    "tip_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.stop_back_volume.as_ref(),
+                    "MultipleDispenseRequest",
+                    "stop_back_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_speed.as_ref(),
+                    "MultipleDispenseRequest",
+                    "dispense_speed",
+                )?,
+                piglet_client::client::required(
+                    request.cutoff_speed.as_ref(),
+                    "MultipleDispenseRequest",
+                    "cutoff_speed",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "MultipleDispenseRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_height.as_ref(),
+                    "MultipleDispenseRequest",
+                    "dispense_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "MultipleDispenseRequest",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_map.as_ref(),
+                    "MultipleDispenseRequest",
+                    "dispense_map",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
+        x_number_of_wells: u16,
+        x_velocity_scale: u16,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        y_well_distance: impl AsRef<[Millimeters]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        tip_air_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        tadm_enabled: impl AsRef<[i16]> + Send,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        dispense_map: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
 
-#[allow(non_camel_case_types)]
-#[derive(Clone, Debug)]
-pub struct CheckPlateSuctionReply {
-    pub first_channel_has_plate: i16,
-    pub second_channel_has_plate: i16,
+    fn on_the_fly_dispense_with(
+        &self,
+        request: &OnTheFlyDispenseRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.on_the_fly_dispense(
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "OnTheFlyDispenseRequest",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.x_position,
+                    "OnTheFlyDispenseRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.x_well_distance,
+                    "OnTheFlyDispenseRequest",
+                    "x_well_distance",
+                )?,
+                piglet_client::client::required(
+                    request.x_well_width,
+                    "OnTheFlyDispenseRequest",
+                    "x_well_width",
+                )?,
+                piglet_client::client::required(
+                    request.x_number_of_wells,
+                    "OnTheFlyDispenseRequest",
+                    "x_number_of_wells",
+                )?,
+                piglet_client::client::required(
+                    request.x_velocity_scale,
+                    "OnTheFlyDispenseRequest",
+                    "x_velocity_scale",
+                )?,
+                piglet_client::client::required(
+                    request.y_position.as_ref(),
+                    "OnTheFlyDispenseRequest",
+                    "y_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_well_distance.as_ref(),
+                    "OnTheFlyDispenseRequest",
+                    "y_well_distance",
+                )?,
+                piglet_client::client::required(
+                    request.volume.as_ref(),
+                    "OnTheFlyDispenseRequest",
+                    "volume",
+                )?,
+                piglet_client::client::required(
+                    request.tip_air_volume.as_ref(),
+                    "OnTheFlyDispenseRequest",
+                    "tip_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.stop_back_volume.as_ref(),
+                    "OnTheFlyDispenseRequest",
+                    "stop_back_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_speed.as_ref(),
+                    "OnTheFlyDispenseRequest",
+                    "dispense_speed",
+                )?,
+                piglet_client::client::required(
+                    request.cutoff_speed.as_ref(),
+                    "OnTheFlyDispenseRequest",
+                    "cutoff_speed",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled.as_ref(),
+                    "OnTheFlyDispenseRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index.as_ref(),
+                    "OnTheFlyDispenseRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "OnTheFlyDispenseRequest",
+                    "recording_mode",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "OnTheFlyDispenseRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_height.as_ref(),
+                    "OnTheFlyDispenseRequest",
+                    "dispense_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "OnTheFlyDispenseRequest",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_map.as_ref(),
+                    "OnTheFlyDispenseRequest",
+                    "dispense_map",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn get_current_dispenser_volume(
+        &self,
+    ) -> impl Future<Output = Result</* volume= */ Vec<Microliters>, Error>> + Send;
+    fn get_liquid_height(
+        &self,
+    ) -> impl Future<Output = Result</* liquid_height= */ Vec<Millimeters>, Error>> + Send;
+    fn get_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<GetChannelConfigurationReply, Error>> + Send;
+    fn set_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+        enables: impl AsRef<[bool]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_seek_obstacle_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        obstacle_seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<ZSeekObstaclePositionReply, Error>> + Send;
+    fn z_seek_lld_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<ZSeekLldPositionReply, Error>> + Send;
+
+    fn z_seek_lld_position_with(
+        &self,
+        request: &ZSeekLldPositionRequest,
+    ) -> impl Future<Output = Result<ZSeekLldPositionReply, Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.z_seek_lld_position(
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "ZSeekLldPositionRequest",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.x_position.as_ref(),
+                    "ZSeekLldPositionRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_position.as_ref(),
+                    "ZSeekLldPositionRequest",
+                    "y_position",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "ZSeekLldPositionRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.seek_height.as_ref(),
+                    "ZSeekLldPositionRequest",
+                    "seek_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position.as_ref(),
+                    "ZSeekLldPositionRequest",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "ZSeekLldPositionRequest",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.seek_speed.as_ref(),
+                    "ZSeekLldPositionRequest",
+                    "seek_speed",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_sensitivity.as_ref(),
+                    "ZSeekLldPositionRequest",
+                    "capacitive_lld_sensitivity",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn dispense_initialize_to_waste(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn multiple_dispense_transport_air(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
+        x_number_of_wells: u16,
+        x_velocity_scale: u16,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        y_well_distance: impl AsRef<[Millimeters]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        tip_air_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        dispense_map: impl AsRef<[u16]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn multiple_dispense_transport_air_with(
+        &self,
+        request: &MultipleDispenseTransportAirRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.multiple_dispense_transport_air(
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "MultipleDispenseTransportAirRequest",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.x_position,
+                    "MultipleDispenseTransportAirRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.x_well_distance,
+                    "MultipleDispenseTransportAirRequest",
+                    "x_well_distance",
+                )?,
+                piglet_client::client::required(
+                    request.x_well_width,
+                    "MultipleDispenseTransportAirRequest",
+                    "x_well_width",
+                )?,
+                piglet_client::client::required(
+                    request.x_number_of_wells,
+                    "MultipleDispenseTransportAirRequest",
+                    "x_number_of_wells",
+                )?,
+                piglet_client::client::required(
+                    request.x_velocity_scale,
+                    "MultipleDispenseTransportAirRequest",
+                    "x_velocity_scale",
+                )?,
+                piglet_client::client::required(
+                    request.y_position.as_ref(),
+                    "MultipleDispenseTransportAirRequest",
+                    "y_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_well_distance.as_ref(),
+                    "MultipleDispenseTransportAirRequest",
+                    "y_well_distance",
+                )?,
+                piglet_client::client::required(
+                    request.volume.as_ref(),
+                    "MultipleDispenseTransportAirRequest",
+                    "volume",
+                )?,
+                piglet_client::client::required(
+                    request.tip_air_volume.as_ref(),
+                    "MultipleDispenseTransportAirRequest",
+                    "tip_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.stop_back_volume.as_ref(),
+                    "MultipleDispenseTransportAirRequest",
+                    "stop_back_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_speed.as_ref(),
+                    "MultipleDispenseTransportAirRequest",
+                    "dispense_speed",
+                )?,
+                piglet_client::client::required(
+                    request.cutoff_speed.as_ref(),
+                    "MultipleDispenseTransportAirRequest",
+                    "cutoff_speed",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "MultipleDispenseTransportAirRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_height.as_ref(),
+                    "MultipleDispenseTransportAirRequest",
+                    "dispense_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "MultipleDispenseTransportAirRequest",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_map.as_ref(),
+                    "MultipleDispenseTransportAirRequest",
+                    "dispense_map",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume.as_ref(),
+                    "MultipleDispenseTransportAirRequest",
+                    "transport_air_volume",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn on_the_fly_dispense_transport_air(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
+        x_number_of_wells: u16,
+        x_velocity_scale: u16,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        y_well_distance: impl AsRef<[Millimeters]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        tip_air_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        tadm_enabled: impl AsRef<[i16]> + Send,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        dispense_map: impl AsRef<[u16]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn on_the_fly_dispense_transport_air_with(
+        &self,
+        request: &OnTheFlyDispenseTransportAirRequest,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.on_the_fly_dispense_transport_air(
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.x_position,
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.x_well_distance,
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "x_well_distance",
+                )?,
+                piglet_client::client::required(
+                    request.x_well_width,
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "x_well_width",
+                )?,
+                piglet_client::client::required(
+                    request.x_number_of_wells,
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "x_number_of_wells",
+                )?,
+                piglet_client::client::required(
+                    request.x_velocity_scale,
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "x_velocity_scale",
+                )?,
+                piglet_client::client::required(
+                    request.y_position.as_ref(),
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "y_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_well_distance.as_ref(),
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "y_well_distance",
+                )?,
+                piglet_client::client::required(
+                    request.volume.as_ref(),
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "volume",
+                )?,
+                piglet_client::client::required(
+                    request.tip_air_volume.as_ref(),
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "tip_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.stop_back_volume.as_ref(),
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "stop_back_volume",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_speed.as_ref(),
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "dispense_speed",
+                )?,
+                piglet_client::client::required(
+                    request.cutoff_speed.as_ref(),
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "cutoff_speed",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled.as_ref(),
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index.as_ref(),
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "recording_mode",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_height.as_ref(),
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "dispense_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.dispense_map.as_ref(),
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "dispense_map",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume.as_ref(),
+                    "OnTheFlyDispenseTransportAirRequest",
+                    "transport_air_volume",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn set_z_liquid_seek_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        seek_speeds: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_z_liquid_seek_speed(
+        &self,
+    ) -> impl Future<Output = Result</* seek_speeds= */ Vec<MmPerSecond>, Error>> + Send;
+    fn move_absolute_z_2_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        z_speed_switch_position: impl AsRef<[Millimeters]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+        lower_section_velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn pickup_plate_suction_2(
+        &self,
+        x_position: Millimeters,
+        y_plate_center_position: Millimeters,
+        y_suction_offset: i32,
+        blowout_volume: Microliters,
+        suction_volume: Microliters,
+        traverse_height: Millimeters,
+        z_grip_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn pickup_plate_suction_2_with(
+        &self,
+        request: &PickupPlateSuction2Request,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.pickup_plate_suction_2(
+                piglet_client::client::required(
+                    request.x_position,
+                    "PickupPlateSuction2Request",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_plate_center_position,
+                    "PickupPlateSuction2Request",
+                    "y_plate_center_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_suction_offset,
+                    "PickupPlateSuction2Request",
+                    "y_suction_offset",
+                )?,
+                piglet_client::client::required(
+                    request.blowout_volume,
+                    "PickupPlateSuction2Request",
+                    "blowout_volume",
+                )?,
+                piglet_client::client::required(
+                    request.suction_volume,
+                    "PickupPlateSuction2Request",
+                    "suction_volume",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "PickupPlateSuction2Request",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_grip_height,
+                    "PickupPlateSuction2Request",
+                    "z_grip_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "PickupPlateSuction2Request",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.z_speed,
+                    "PickupPlateSuction2Request",
+                    "z_speed",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn drop_plate_suction_2(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        traverse_height: Millimeters,
+        z_drop_height: Millimeters,
+        z_press_distance: Millimeters,
+        z_lift_distance: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn drop_plate_suction_2_with(
+        &self,
+        request: &DropPlateSuction2Request,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.drop_plate_suction_2(
+                piglet_client::client::required(
+                    request.x_position,
+                    "DropPlateSuction2Request",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.x_acceleration,
+                    "DropPlateSuction2Request",
+                    "x_acceleration",
+                )?,
+                piglet_client::client::required(
+                    request.y_plate_center_position,
+                    "DropPlateSuction2Request",
+                    "y_plate_center_position",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "DropPlateSuction2Request",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_drop_height,
+                    "DropPlateSuction2Request",
+                    "z_drop_height",
+                )?,
+                piglet_client::client::required(
+                    request.z_press_distance,
+                    "DropPlateSuction2Request",
+                    "z_press_distance",
+                )?,
+                piglet_client::client::required(
+                    request.z_lift_distance,
+                    "DropPlateSuction2Request",
+                    "z_lift_distance",
+                )?,
+                piglet_client::client::required(
+                    request.z_final,
+                    "DropPlateSuction2Request",
+                    "z_final",
+                )?,
+                piglet_client::client::required(
+                    request.z_speed,
+                    "DropPlateSuction2Request",
+                    "z_speed",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn move_plate_suction_2(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        traverse_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn check_plate_suction(
+        &self,
+        first_channel_number: u16,
+        second_channel_number: u16,
+        suction_volume: Microliters,
+    ) -> impl Future<Output = Result<CheckPlateSuctionReply, Error>> + Send;
+    fn initialize_roll(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn initialize_smart_roll(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn drop_tips_roll(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_safe(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn aspirate_2(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        aspirate_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn aspirate_2_with(
+        &self,
+        request: &Aspirate2Request,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let call = (|| {
+            Ok::<_, Error>(self.aspirate_2(
+                piglet_client::client::required(
+                    request.aspirate_type.as_ref(),
+                    "Aspirate2Request",
+                    "aspirate_type",
+                )?,
+                piglet_client::client::required(
+                    request.tips_used.as_ref(),
+                    "Aspirate2Request",
+                    "tips_used",
+                )?,
+                piglet_client::client::required(
+                    request.x_position.as_ref(),
+                    "Aspirate2Request",
+                    "x_position",
+                )?,
+                piglet_client::client::required(
+                    request.y_position.as_ref(),
+                    "Aspirate2Request",
+                    "y_position",
+                )?,
+                piglet_client::client::required(
+                    request.traverse_height,
+                    "Aspirate2Request",
+                    "traverse_height",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_seek_height.as_ref(),
+                    "Aspirate2Request",
+                    "liquid_seek_height",
+                )?,
+                piglet_client::client::required(
+                    request.liquid_surface_height.as_ref(),
+                    "Aspirate2Request",
+                    "liquid_surface_height",
+                )?,
+                piglet_client::client::required(
+                    request.submerge_depth.as_ref(),
+                    "Aspirate2Request",
+                    "submerge_depth",
+                )?,
+                piglet_client::client::required(
+                    request.follow_depth.as_ref(),
+                    "Aspirate2Request",
+                    "follow_depth",
+                )?,
+                piglet_client::client::required(
+                    request.z_min_position.as_ref(),
+                    "Aspirate2Request",
+                    "z_min_position",
+                )?,
+                piglet_client::client::required(
+                    request.clot_check_height.as_ref(),
+                    "Aspirate2Request",
+                    "clot_check_height",
+                )?,
+                piglet_client::client::required(request.z_final, "Aspirate2Request", "z_final")?,
+                piglet_client::client::required(
+                    request.liquid_exit_speed.as_ref(),
+                    "Aspirate2Request",
+                    "liquid_exit_speed",
+                )?,
+                piglet_client::client::required(
+                    request.blowout_volume.as_ref(),
+                    "Aspirate2Request",
+                    "blowout_volume",
+                )?,
+                piglet_client::client::required(
+                    request.prewet_volume.as_ref(),
+                    "Aspirate2Request",
+                    "prewet_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_volume.as_ref(),
+                    "Aspirate2Request",
+                    "aspirate_volume",
+                )?,
+                piglet_client::client::required(
+                    request.transport_air_volume.as_ref(),
+                    "Aspirate2Request",
+                    "transport_air_volume",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_speed.as_ref(),
+                    "Aspirate2Request",
+                    "aspirate_speed",
+                )?,
+                piglet_client::client::required(
+                    request.settling_time.as_ref(),
+                    "Aspirate2Request",
+                    "settling_time",
+                )?,
+                piglet_client::client::required(
+                    request.mix_volume.as_ref(),
+                    "Aspirate2Request",
+                    "mix_volume",
+                )?,
+                piglet_client::client::required(
+                    request.mix_cycles.as_ref(),
+                    "Aspirate2Request",
+                    "mix_cycles",
+                )?,
+                piglet_client::client::required(
+                    request.mix_position.as_ref(),
+                    "Aspirate2Request",
+                    "mix_position",
+                )?,
+                piglet_client::client::required(
+                    request.mix_follow_distance.as_ref(),
+                    "Aspirate2Request",
+                    "mix_follow_distance",
+                )?,
+                piglet_client::client::required(
+                    request.mix_speed.as_ref(),
+                    "Aspirate2Request",
+                    "mix_speed",
+                )?,
+                piglet_client::client::required(
+                    request.aspirate_offset.as_ref(),
+                    "Aspirate2Request",
+                    "aspirate_offset",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_height.as_ref(),
+                    "Aspirate2Request",
+                    "tube_section_height",
+                )?,
+                piglet_client::client::required(
+                    request.tube_section_ratio.as_ref(),
+                    "Aspirate2Request",
+                    "tube_section_ratio",
+                )?,
+                piglet_client::client::required(
+                    request.lld_mode.as_ref(),
+                    "Aspirate2Request",
+                    "lld_mode",
+                )?,
+                piglet_client::client::required(
+                    request.capacitive_lld_sensitivity.as_ref(),
+                    "Aspirate2Request",
+                    "capacitive_lld_sensitivity",
+                )?,
+                piglet_client::client::required(
+                    request.pressure_lld_sensitivity.as_ref(),
+                    "Aspirate2Request",
+                    "pressure_lld_sensitivity",
+                )?,
+                piglet_client::client::required(
+                    request.lld_height_difference.as_ref(),
+                    "Aspirate2Request",
+                    "lld_height_difference",
+                )?,
+                piglet_client::client::required(
+                    request.tadm_enabled,
+                    "Aspirate2Request",
+                    "tadm_enabled",
+                )?,
+                piglet_client::client::required(
+                    request.limit_curve_index.as_ref(),
+                    "Aspirate2Request",
+                    "limit_curve_index",
+                )?,
+                piglet_client::client::required(
+                    request.recording_mode,
+                    "Aspirate2Request",
+                    "recording_mode",
+                )?,
+            ))
+        })();
+        async move { call?.await }
+    }
+    fn is_x_interlock_active(
+        &self,
+    ) -> impl Future<Output = Result</* active= */ bool, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCorePipetteApi for NimbusCorePipette {
+    fn initialize(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::initialize(
+            self,
+            x_position,
+            y_position,
+            z_start_position,
+            z_stop_position,
+            z_final,
+        )
+    }
+
+    fn initialize_smart(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::initialize_smart(
+            self,
+            x_position,
+            y_position,
+            z_start_position,
+            z_stop_position,
+            z_final,
+        )
+    }
+
+    fn tip_and_needle_definition(
+        &self,
+        tip_type: u16,
+        volume: Microliters,
+        length: u16,
+        collar_type: i8,
+        filtered: bool,
+        needle: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::tip_and_needle_definition(
+            self,
+            tip_type,
+            volume,
+            length,
+            collar_type,
+            filtered,
+            needle,
+        )
+    }
+
+    fn pickup_tips(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        tip_type: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::pickup_tips(
+            self,
+            tips_used,
+            x_position,
+            y_position,
+            traverse_height,
+            z_start_position,
+            z_stop_position,
+            tip_type,
+        )
+    }
+
+    fn drop_tips(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        default_waste: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::drop_tips(
+            self,
+            tips_used,
+            x_position,
+            y_position,
+            traverse_height,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            default_waste,
+        )
+    }
+
+    fn aspirate_1(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::aspirate_1(
+            self,
+            aspirate_type,
+            tips_used,
+            x_position,
+            y_position,
+            traverse_height,
+            liquid_seek_height,
+            liquid_surface_height,
+            submerge_depth,
+            follow_depth,
+            z_min_position,
+            clot_check_height,
+            z_final,
+            liquid_exit_speed,
+            blowout_volume,
+            prewet_volume,
+            aspirate_volume,
+            transport_air_volume,
+            aspirate_speed,
+            settling_time,
+            mix_volume,
+            mix_cycles,
+            mix_position,
+            mix_follow_distance,
+            mix_speed,
+            tube_section_height,
+            tube_section_ratio,
+            lld_mode,
+            capacitive_lld_sensitivity,
+            pressure_lld_sensitivity,
+            lld_height_difference,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+        )
+    }
+
+    fn dispense(
+        &self,
+        dispense_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        touch_off_distance: Millimeters,
+        dispense_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::dispense(
+            self,
+            dispense_type,
+            tips_used,
+            x_position,
+            y_position,
+            traverse_height,
+            liquid_seek_height,
+            dispense_height,
+            submerge_depth,
+            follow_depth,
+            z_min_position,
+            z_final,
+            liquid_exit_speed,
+            transport_air_volume,
+            dispense_volume,
+            stop_back_volume,
+            blowout_volume,
+            dispense_speed,
+            cutoff_speed,
+            settling_time,
+            mix_volume,
+            mix_cycles,
+            mix_position,
+            mix_follow_distance,
+            mix_speed,
+            touch_off_distance,
+            dispense_offset,
+            tube_section_height,
+            tube_section_ratio,
+            lld_mode,
+            capacitive_lld_sensitivity,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+        )
+    }
+
+    fn aspirate_and_dispense(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        dispense_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        aspirate_dispense_pattern: impl AsRef<[i16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        dispense_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::aspirate_and_dispense(
+            self,
+            aspirate_type,
+            dispense_type,
+            tips_used,
+            aspirate_dispense_pattern,
+            x_position,
+            y_position,
+            traverse_height,
+            liquid_seek_height,
+            liquid_surface_height,
+            submerge_depth,
+            follow_depth,
+            z_min_position,
+            clot_check_height,
+            z_final,
+            liquid_exit_speed,
+            blowout_volume,
+            prewet_volume,
+            aspirate_volume,
+            transport_air_volume,
+            dispense_volume,
+            stop_back_volume,
+            aspirate_speed,
+            dispense_speed,
+            cutoff_speed,
+            settling_time,
+            mix_volume,
+            mix_cycles,
+            mix_position,
+            mix_follow_distance,
+            mix_speed,
+            dispense_offset,
+            tube_section_height,
+            tube_section_ratio,
+            lld_mode,
+            capacitive_lld_sensitivity,
+            pressure_lld_sensitivity,
+            lld_height_difference,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+        )
+    }
+
+    fn pickup_gripper_tool(
+        &self,
+        x_position: Millimeters,
+        y_position_1_st_channel: Millimeters,
+        y_position_2_nd_channel: Millimeters,
+        traverse_height: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        tip_type: u16,
+        first_channel_number: u16,
+        second_channel_number: u16,
+        tool_width: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::pickup_gripper_tool(
+            self,
+            x_position,
+            y_position_1_st_channel,
+            y_position_2_nd_channel,
+            traverse_height,
+            z_start_position,
+            z_stop_position,
+            tip_type,
+            first_channel_number,
+            second_channel_number,
+            tool_width,
+        )
+    }
+
+    fn drop_gripper_tool(
+        &self,
+        x_position: Millimeters,
+        y_position_1_st_channel: Millimeters,
+        y_position_2_nd_channel: Millimeters,
+        traverse_height: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        first_channel_number: u16,
+        second_channel_number: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::drop_gripper_tool(
+            self,
+            x_position,
+            y_position_1_st_channel,
+            y_position_2_nd_channel,
+            traverse_height,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            first_channel_number,
+            second_channel_number,
+        )
+    }
+
+    fn pickup_plate(
+        &self,
+        x_position: Millimeters,
+        y_plate_center_position: Millimeters,
+        y_plate_width: Millimeters,
+        y_open_position: Millimeters,
+        y_grip_speed: MmPerSecond,
+        y_grip_strength: u32,
+        traverse_height: Millimeters,
+        z_grip_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::pickup_plate(
+            self,
+            x_position,
+            y_plate_center_position,
+            y_plate_width,
+            y_open_position,
+            y_grip_speed,
+            y_grip_strength,
+            traverse_height,
+            z_grip_height,
+            z_final,
+            z_speed,
+        )
+    }
+
+    fn drop_plate(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        y_open_position: Millimeters,
+        traverse_height: Millimeters,
+        z_drop_height: Millimeters,
+        z_press_distance: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::drop_plate(
+            self,
+            x_position,
+            x_acceleration,
+            y_plate_center_position,
+            y_open_position,
+            traverse_height,
+            z_drop_height,
+            z_press_distance,
+            z_final,
+            z_speed,
+        )
+    }
+
+    fn move_plate(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        traverse_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_plate(
+            self,
+            x_position,
+            x_acceleration,
+            y_plate_center_position,
+            traverse_height,
+            z_final,
+            z_speed,
+        )
+    }
+
+    fn release_plate(
+        &self,
+        first_channel_number: u16,
+        second_channel_number: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::release_plate(self, first_channel_number, second_channel_number)
+    }
+
+    fn is_initialized(
+        &self,
+    ) -> impl Future<Output = Result</* initialized= */ bool, Error>> + Send {
+        NimbusCorePipette::is_initialized(self)
+    }
+
+    fn is_tip_present(
+        &self,
+    ) -> impl Future<Output = Result</* tip_present= */ Vec<i16>, Error>> + Send {
+        NimbusCorePipette::is_tip_present(self)
+    }
+
+    fn is_core_gripper_tool_held(
+        &self,
+    ) -> impl Future<Output = Result<IsCoreGripperToolHeldReply, Error>> + Send {
+        NimbusCorePipette::is_core_gripper_tool_held(self)
+    }
+
+    fn is_core_gripper_plate_gripped(
+        &self,
+    ) -> impl Future<Output = Result</* gripped= */ bool, Error>> + Send {
+        NimbusCorePipette::is_core_gripper_plate_gripped(self)
+    }
+
+    fn get_tip_and_needle_types(
+        &self,
+    ) -> impl Future<Output = Result</* tip_type= */ Vec<u16>, Error>> + Send {
+        NimbusCorePipette::get_tip_and_needle_types(self)
+    }
+
+    fn get_position(&self) -> impl Future<Output = Result<GetPositionReply, Error>> + Send {
+        NimbusCorePipette::get_position(self)
+    }
+
+    fn park(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::park(self)
+    }
+
+    fn move_over(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_over(
+            self,
+            tips_used,
+            x_position,
+            y_position,
+            traverse_height,
+            z_position,
+        )
+    }
+
+    fn move_to_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_to_position(self, tips_used, x_position, y_position, z_position)
+    }
+
+    fn move_to_position_via_lane(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_to_position_via_lane(
+            self,
+            tips_used,
+            x_position,
+            y_position,
+            traverse_height,
+        )
+    }
+
+    fn move_absolute_xy(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_absolute_xy(self, tips_used, x_position, y_position)
+    }
+
+    fn move_absolute_x(
+        &self,
+        x_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_absolute_x(self, x_position)
+    }
+
+    fn move_relative_x(
+        &self,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_relative_x(self, x_distance)
+    }
+
+    fn move_absolute_y(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_absolute_y(self, tips_used, y_position)
+    }
+
+    fn move_relative_y(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_relative_y(self, tips_used, y_distance)
+    }
+
+    fn move_absolute_z(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_absolute_z(self, tips_used, z_position)
+    }
+
+    fn move_relative_z(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_relative_z(self, tips_used, z_distance)
+    }
+
+    fn preinitialize_smart(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::preinitialize_smart(self)
+    }
+
+    fn erase_limit_curves(&self, channel: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::erase_limit_curves(self, channel)
+    }
+
+    fn create_limit_curve(
+        &self,
+        channel: u16,
+        name: &str,
+        lower_limit_x: impl AsRef<[u16]> + Send,
+        lower_limit_y: impl AsRef<[i16]> + Send,
+        upper_limit_x: impl AsRef<[u16]> + Send,
+        upper_limit_y: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<CreateLimitCurveReply, Error>> + Send {
+        NimbusCorePipette::create_limit_curve(
+            self,
+            channel,
+            name,
+            lower_limit_x,
+            lower_limit_y,
+            upper_limit_x,
+            upper_limit_y,
+        )
+    }
+
+    fn reset_tadm_fifo(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::reset_tadm_fifo(self, tips_used)
+    }
+
+    fn retrieve_tadm_data(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<RetrieveTadmDataReply, Error>> + Send {
+        NimbusCorePipette::retrieve_tadm_data(self, tips_used)
+    }
+
+    fn get_limit_curve_names(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<GetLimitCurveNamesReply, Error>> + Send {
+        NimbusCorePipette::get_limit_curve_names(self, channel)
+    }
+
+    fn get_limit_curve_info(
+        &self,
+        channel: u16,
+        name: &str,
+    ) -> impl Future<Output = Result<GetLimitCurveInfoReply, Error>> + Send {
+        NimbusCorePipette::get_limit_curve_info(self, channel, name)
+    }
+
+    fn set_tadm_enable(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        enable: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::set_tadm_enable(self, tips_used, enable)
+    }
+
+    fn get_tadm_enable(&self) -> impl Future<Output = Result<GetTADMEnableReply, Error>> + Send {
+        NimbusCorePipette::get_tadm_enable(self)
+    }
+
+    fn enable_mad(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        pressure_threshold: impl AsRef<[u16]> + Send,
+        minimum_pressure_difference: impl AsRef<[u16]> + Send,
+        maximum_pressure_difference: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::enable_mad(
+            self,
+            tips_used,
+            pressure_threshold,
+            minimum_pressure_difference,
+            maximum_pressure_difference,
+        )
+    }
+
+    fn disable_mad(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::disable_mad(self, tips_used)
+    }
+
+    fn enable_adc(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::enable_adc(self, tips_used)
+    }
+
+    fn disable_adc(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::disable_adc(self, tips_used)
+    }
+
+    fn move_traverse_z(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_traverse_z(self, tips_used, z_position)
+    }
+
+    fn pickup_plate_suction(
+        &self,
+        x_position: Millimeters,
+        y_plate_center_position: Millimeters,
+        y_suction_offset: i32,
+        blowout_volume: Microliters,
+        suction_volume: Microliters,
+        pressure_differential: u16,
+        traverse_height: Millimeters,
+        z_grip_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<PickupPlateSuctionReply, Error>> + Send {
+        NimbusCorePipette::pickup_plate_suction(
+            self,
+            x_position,
+            y_plate_center_position,
+            y_suction_offset,
+            blowout_volume,
+            suction_volume,
+            pressure_differential,
+            traverse_height,
+            z_grip_height,
+            z_final,
+            z_speed,
+        )
+    }
+
+    fn drop_plate_suction(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        pressure_differential: u16,
+        traverse_height: Millimeters,
+        z_drop_height: Millimeters,
+        z_press_distance: Millimeters,
+        z_lift_distance: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<DropPlateSuctionReply, Error>> + Send {
+        NimbusCorePipette::drop_plate_suction(
+            self,
+            x_position,
+            x_acceleration,
+            y_plate_center_position,
+            pressure_differential,
+            traverse_height,
+            z_drop_height,
+            z_press_distance,
+            z_lift_distance,
+            z_final,
+            z_speed,
+        )
+    }
+
+    fn move_plate_suction(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        pressure_differential: u16,
+        traverse_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_plate_suction(
+            self,
+            x_position,
+            x_acceleration,
+            y_plate_center_position,
+            pressure_differential,
+            traverse_height,
+            z_final,
+            z_speed,
+        )
+    }
+
+    fn z_seek_obstacle(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        obstacle_seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result</* obstacle_detected= */ Vec<i16>, Error>> + Send {
+        NimbusCorePipette::z_seek_obstacle(
+            self,
+            tips_used,
+            x_position,
+            y_position,
+            traverse_height,
+            obstacle_seek_height,
+            z_min_position,
+            z_final,
+            seek_speed,
+        )
+    }
+
+    fn get_remaining_channels(
+        &self,
+    ) -> impl Future<Output = Result<GetRemainingChannelsReply, Error>> + Send {
+        NimbusCorePipette::get_remaining_channels(self)
+    }
+
+    fn set_performance_parameters(
+        &self,
+        z_speed: MmPerSecond,
+        time: u16,
+        time_limit: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::set_performance_parameters(self, z_speed, time, time_limit)
+    }
+
+    fn reset_performance_parameters(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::reset_performance_parameters(self)
+    }
+
+    fn initialize_x(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::initialize_x(self)
+    }
+
+    fn initialize_y(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::initialize_y(self, tips_used)
+    }
+
+    fn initialize_z(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::initialize_z(self, tips_used)
+    }
+
+    fn initialize_squeeze(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::initialize_squeeze(self, tips_used)
+    }
+
+    fn initialize_dispenser(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::initialize_dispenser(self, tips_used)
+    }
+
+    fn squeeze_on(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::squeeze_on(self, tips_used)
+    }
+
+    fn squeeze_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::squeeze_off(self, tips_used)
+    }
+
+    fn empty_dispenser(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::empty_dispenser(self, tips_used)
+    }
+
+    fn move_relative_dispenser(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        volumes: impl AsRef<[Microliters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_relative_dispenser(self, tips_used, volumes)
+    }
+
+    fn multiple_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
+        x_number_of_wells: u16,
+        x_velocity_scale: u16,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        y_well_distance: impl AsRef<[Millimeters]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        tip_air_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        dispense_map: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::multiple_dispense(
+            self,
+            tips_used,
+            x_position,
+            x_well_distance,
+            x_well_width,
+            x_number_of_wells,
+            x_velocity_scale,
+            y_position,
+            y_well_distance,
+            volume,
+            tip_air_volume,
+            stop_back_volume,
+            dispense_speed,
+            cutoff_speed,
+            traverse_height,
+            dispense_height,
+            z_final,
+            dispense_map,
+        )
+    }
+
+    fn on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
+        x_number_of_wells: u16,
+        x_velocity_scale: u16,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        y_well_distance: impl AsRef<[Millimeters]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        tip_air_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        tadm_enabled: impl AsRef<[i16]> + Send,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        dispense_map: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::on_the_fly_dispense(
+            self,
+            tips_used,
+            x_position,
+            x_well_distance,
+            x_well_width,
+            x_number_of_wells,
+            x_velocity_scale,
+            y_position,
+            y_well_distance,
+            volume,
+            tip_air_volume,
+            stop_back_volume,
+            dispense_speed,
+            cutoff_speed,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+            traverse_height,
+            dispense_height,
+            z_final,
+            dispense_map,
+        )
+    }
+
+    fn get_current_dispenser_volume(
+        &self,
+    ) -> impl Future<Output = Result</* volume= */ Vec<Microliters>, Error>> + Send {
+        NimbusCorePipette::get_current_dispenser_volume(self)
+    }
+
+    fn get_liquid_height(
+        &self,
+    ) -> impl Future<Output = Result</* liquid_height= */ Vec<Millimeters>, Error>> + Send {
+        NimbusCorePipette::get_liquid_height(self)
+    }
+
+    fn get_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<GetChannelConfigurationReply, Error>> + Send {
+        NimbusCorePipette::get_channel_configuration(self, channel, indexes)
+    }
+
+    fn set_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+        enables: impl AsRef<[bool]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::set_channel_configuration(self, channel, indexes, enables)
+    }
+
+    fn z_seek_obstacle_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        obstacle_seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<ZSeekObstaclePositionReply, Error>> + Send {
+        NimbusCorePipette::z_seek_obstacle_position(
+            self,
+            tips_used,
+            x_position,
+            y_position,
+            traverse_height,
+            obstacle_seek_height,
+            z_min_position,
+            z_final,
+            seek_speed,
+        )
+    }
+
+    fn z_seek_lld_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<ZSeekLldPositionReply, Error>> + Send {
+        NimbusCorePipette::z_seek_lld_position(
+            self,
+            tips_used,
+            x_position,
+            y_position,
+            traverse_height,
+            seek_height,
+            z_min_position,
+            z_final,
+            seek_speed,
+            capacitive_lld_sensitivity,
+        )
+    }
+
+    fn dispense_initialize_to_waste(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::dispense_initialize_to_waste(
+            self, tips_used, x_position, y_position, z_position,
+        )
+    }
+
+    fn multiple_dispense_transport_air(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
+        x_number_of_wells: u16,
+        x_velocity_scale: u16,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        y_well_distance: impl AsRef<[Millimeters]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        tip_air_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        dispense_map: impl AsRef<[u16]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::multiple_dispense_transport_air(
+            self,
+            tips_used,
+            x_position,
+            x_well_distance,
+            x_well_width,
+            x_number_of_wells,
+            x_velocity_scale,
+            y_position,
+            y_well_distance,
+            volume,
+            tip_air_volume,
+            stop_back_volume,
+            dispense_speed,
+            cutoff_speed,
+            traverse_height,
+            dispense_height,
+            z_final,
+            dispense_map,
+            transport_air_volume,
+        )
+    }
+
+    fn on_the_fly_dispense_transport_air(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
+        x_number_of_wells: u16,
+        x_velocity_scale: u16,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        y_well_distance: impl AsRef<[Millimeters]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        tip_air_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        tadm_enabled: impl AsRef<[i16]> + Send,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        dispense_map: impl AsRef<[u16]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::on_the_fly_dispense_transport_air(
+            self,
+            tips_used,
+            x_position,
+            x_well_distance,
+            x_well_width,
+            x_number_of_wells,
+            x_velocity_scale,
+            y_position,
+            y_well_distance,
+            volume,
+            tip_air_volume,
+            stop_back_volume,
+            dispense_speed,
+            cutoff_speed,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+            traverse_height,
+            dispense_height,
+            z_final,
+            dispense_map,
+            transport_air_volume,
+        )
+    }
+
+    fn set_z_liquid_seek_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        seek_speeds: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::set_z_liquid_seek_speed(self, tips_used, seek_speeds)
+    }
+
+    fn get_z_liquid_seek_speed(
+        &self,
+    ) -> impl Future<Output = Result</* seek_speeds= */ Vec<MmPerSecond>, Error>> + Send {
+        NimbusCorePipette::get_z_liquid_seek_speed(self)
+    }
+
+    fn move_absolute_z_2_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        z_speed_switch_position: impl AsRef<[Millimeters]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+        lower_section_velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_absolute_z_2_speed(
+            self,
+            tips_used,
+            z_position,
+            z_speed_switch_position,
+            velocity,
+            lower_section_velocity,
+        )
+    }
+
+    fn pickup_plate_suction_2(
+        &self,
+        x_position: Millimeters,
+        y_plate_center_position: Millimeters,
+        y_suction_offset: i32,
+        blowout_volume: Microliters,
+        suction_volume: Microliters,
+        traverse_height: Millimeters,
+        z_grip_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::pickup_plate_suction_2(
+            self,
+            x_position,
+            y_plate_center_position,
+            y_suction_offset,
+            blowout_volume,
+            suction_volume,
+            traverse_height,
+            z_grip_height,
+            z_final,
+            z_speed,
+        )
+    }
+
+    fn drop_plate_suction_2(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        traverse_height: Millimeters,
+        z_drop_height: Millimeters,
+        z_press_distance: Millimeters,
+        z_lift_distance: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::drop_plate_suction_2(
+            self,
+            x_position,
+            x_acceleration,
+            y_plate_center_position,
+            traverse_height,
+            z_drop_height,
+            z_press_distance,
+            z_lift_distance,
+            z_final,
+            z_speed,
+        )
+    }
+
+    fn move_plate_suction_2(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        traverse_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::move_plate_suction_2(
+            self,
+            x_position,
+            x_acceleration,
+            y_plate_center_position,
+            traverse_height,
+            z_final,
+            z_speed,
+        )
+    }
+
+    fn check_plate_suction(
+        &self,
+        first_channel_number: u16,
+        second_channel_number: u16,
+        suction_volume: Microliters,
+    ) -> impl Future<Output = Result<CheckPlateSuctionReply, Error>> + Send {
+        NimbusCorePipette::check_plate_suction(
+            self,
+            first_channel_number,
+            second_channel_number,
+            suction_volume,
+        )
+    }
+
+    fn initialize_roll(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::initialize_roll(
+            self,
+            x_position,
+            y_position,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            roll_distance,
+        )
+    }
+
+    fn initialize_smart_roll(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::initialize_smart_roll(
+            self,
+            x_position,
+            y_position,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            roll_distance,
+        )
+    }
+
+    fn drop_tips_roll(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::drop_tips_roll(
+            self,
+            tips_used,
+            x_position,
+            y_position,
+            traverse_height,
+            z_start_position,
+            z_stop_position,
+            z_final,
+            roll_distance,
+        )
+    }
+
+    fn z_move_safe(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::z_move_safe(self, tips_used)
+    }
+
+    fn aspirate_2(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        aspirate_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipette::aspirate_2(
+            self,
+            aspirate_type,
+            tips_used,
+            x_position,
+            y_position,
+            traverse_height,
+            liquid_seek_height,
+            liquid_surface_height,
+            submerge_depth,
+            follow_depth,
+            z_min_position,
+            clot_check_height,
+            z_final,
+            liquid_exit_speed,
+            blowout_volume,
+            prewet_volume,
+            aspirate_volume,
+            transport_air_volume,
+            aspirate_speed,
+            settling_time,
+            mix_volume,
+            mix_cycles,
+            mix_position,
+            mix_follow_distance,
+            mix_speed,
+            aspirate_offset,
+            tube_section_height,
+            tube_section_ratio,
+            lld_mode,
+            capacitive_lld_sensitivity,
+            pressure_lld_sensitivity,
+            lld_height_difference,
+            tadm_enabled,
+            limit_curve_index,
+            recording_mode,
+        )
+    }
+
+    fn is_x_interlock_active(
+        &self,
+    ) -> impl Future<Output = Result</* active= */ bool, Error>> + Send {
+        NimbusCorePipette::is_x_interlock_active(self)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCorePipette::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCorePipette::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCorePipette::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCorePipette::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCorePipette::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCorePipette::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCorePipetteFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCorePipetteApi for NimbusCorePipetteFake {
+    fn initialize(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "initialize",
+            vec![
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("z_final", format!("{:?}", z_final.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn initialize_smart(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "initialize_smart",
+            vec![
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("z_final", format!("{:?}", z_final.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn tip_and_needle_definition(
+        &self,
+        tip_type: u16,
+        volume: Microliters,
+        length: u16,
+        collar_type: i8,
+        filtered: bool,
+        needle: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "tip_and_needle_definition",
+            vec![
+                ("tip_type", format!("{:?}", tip_type)),
+                ("volume", format!("{:?}", volume)),
+                ("length", format!("{:?}", length)),
+                ("collar_type", format!("{:?}", collar_type)),
+                ("filtered", format!("{:?}", filtered)),
+                ("needle", format!("{:?}", needle)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn pickup_tips(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        tip_type: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pickup_tips",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("tip_type", format!("{:?}", tip_type.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn drop_tips(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        default_waste: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "drop_tips",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("z_final", format!("{:?}", z_final.as_ref())),
+                ("default_waste", format!("{:?}", default_waste)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn aspirate_1(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "aspirate_1",
+            vec![
+                ("aspirate_type", format!("{:?}", aspirate_type.as_ref())),
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                (
+                    "liquid_seek_height",
+                    format!("{:?}", liquid_seek_height.as_ref()),
+                ),
+                (
+                    "liquid_surface_height",
+                    format!("{:?}", liquid_surface_height.as_ref()),
+                ),
+                ("submerge_depth", format!("{:?}", submerge_depth.as_ref())),
+                ("follow_depth", format!("{:?}", follow_depth.as_ref())),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                (
+                    "clot_check_height",
+                    format!("{:?}", clot_check_height.as_ref()),
+                ),
+                ("z_final", format!("{:?}", z_final)),
+                (
+                    "liquid_exit_speed",
+                    format!("{:?}", liquid_exit_speed.as_ref()),
+                ),
+                ("blowout_volume", format!("{:?}", blowout_volume.as_ref())),
+                ("prewet_volume", format!("{:?}", prewet_volume.as_ref())),
+                ("aspirate_volume", format!("{:?}", aspirate_volume.as_ref())),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume.as_ref()),
+                ),
+                ("aspirate_speed", format!("{:?}", aspirate_speed.as_ref())),
+                ("settling_time", format!("{:?}", settling_time.as_ref())),
+                ("mix_volume", format!("{:?}", mix_volume.as_ref())),
+                ("mix_cycles", format!("{:?}", mix_cycles.as_ref())),
+                ("mix_position", format!("{:?}", mix_position.as_ref())),
+                (
+                    "mix_follow_distance",
+                    format!("{:?}", mix_follow_distance.as_ref()),
+                ),
+                ("mix_speed", format!("{:?}", mix_speed.as_ref())),
+                (
+                    "tube_section_height",
+                    format!("{:?}", tube_section_height.as_ref()),
+                ),
+                (
+                    "tube_section_ratio",
+                    format!("{:?}", tube_section_ratio.as_ref()),
+                ),
+                ("lld_mode", format!("{:?}", lld_mode.as_ref())),
+                (
+                    "capacitive_lld_sensitivity",
+                    format!("{:?}", capacitive_lld_sensitivity.as_ref()),
+                ),
+                (
+                    "pressure_lld_sensitivity",
+                    format!("{:?}", pressure_lld_sensitivity.as_ref()),
+                ),
+                (
+                    "lld_height_difference",
+                    format!("{:?}", lld_height_difference.as_ref()),
+                ),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                (
+                    "limit_curve_index",
+                    format!("{:?}", limit_curve_index.as_ref()),
+                ),
+                ("recording_mode", format!("{:?}", recording_mode)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn dispense(
+        &self,
+        dispense_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        touch_off_distance: Millimeters,
+        dispense_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "dispense",
+            vec![
+                ("dispense_type", format!("{:?}", dispense_type.as_ref())),
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                (
+                    "liquid_seek_height",
+                    format!("{:?}", liquid_seek_height.as_ref()),
+                ),
+                ("dispense_height", format!("{:?}", dispense_height.as_ref())),
+                ("submerge_depth", format!("{:?}", submerge_depth.as_ref())),
+                ("follow_depth", format!("{:?}", follow_depth.as_ref())),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                ("z_final", format!("{:?}", z_final)),
+                (
+                    "liquid_exit_speed",
+                    format!("{:?}", liquid_exit_speed.as_ref()),
+                ),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume.as_ref()),
+                ),
+                ("dispense_volume", format!("{:?}", dispense_volume.as_ref())),
+                (
+                    "stop_back_volume",
+                    format!("{:?}", stop_back_volume.as_ref()),
+                ),
+                ("blowout_volume", format!("{:?}", blowout_volume.as_ref())),
+                ("dispense_speed", format!("{:?}", dispense_speed.as_ref())),
+                ("cutoff_speed", format!("{:?}", cutoff_speed.as_ref())),
+                ("settling_time", format!("{:?}", settling_time.as_ref())),
+                ("mix_volume", format!("{:?}", mix_volume.as_ref())),
+                ("mix_cycles", format!("{:?}", mix_cycles.as_ref())),
+                ("mix_position", format!("{:?}", mix_position.as_ref())),
+                (
+                    "mix_follow_distance",
+                    format!("{:?}", mix_follow_distance.as_ref()),
+                ),
+                ("mix_speed", format!("{:?}", mix_speed.as_ref())),
+                ("touch_off_distance", format!("{:?}", touch_off_distance)),
+                ("dispense_offset", format!("{:?}", dispense_offset.as_ref())),
+                (
+                    "tube_section_height",
+                    format!("{:?}", tube_section_height.as_ref()),
+                ),
+                (
+                    "tube_section_ratio",
+                    format!("{:?}", tube_section_ratio.as_ref()),
+                ),
+                ("lld_mode", format!("{:?}", lld_mode.as_ref())),
+                (
+                    "capacitive_lld_sensitivity",
+                    format!("{:?}", capacitive_lld_sensitivity.as_ref()),
+                ),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                (
+                    "limit_curve_index",
+                    format!("{:?}", limit_curve_index.as_ref()),
+                ),
+                ("recording_mode", format!("{:?}", recording_mode)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn aspirate_and_dispense(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        dispense_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        aspirate_dispense_pattern: impl AsRef<[i16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        dispense_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        dispense_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "aspirate_and_dispense",
+            vec![
+                ("aspirate_type", format!("{:?}", aspirate_type.as_ref())),
+                ("dispense_type", format!("{:?}", dispense_type.as_ref())),
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "aspirate_dispense_pattern",
+                    format!("{:?}", aspirate_dispense_pattern.as_ref()),
+                ),
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                (
+                    "liquid_seek_height",
+                    format!("{:?}", liquid_seek_height.as_ref()),
+                ),
+                (
+                    "liquid_surface_height",
+                    format!("{:?}", liquid_surface_height.as_ref()),
+                ),
+                ("submerge_depth", format!("{:?}", submerge_depth.as_ref())),
+                ("follow_depth", format!("{:?}", follow_depth.as_ref())),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                (
+                    "clot_check_height",
+                    format!("{:?}", clot_check_height.as_ref()),
+                ),
+                ("z_final", format!("{:?}", z_final)),
+                (
+                    "liquid_exit_speed",
+                    format!("{:?}", liquid_exit_speed.as_ref()),
+                ),
+                ("blowout_volume", format!("{:?}", blowout_volume.as_ref())),
+                ("prewet_volume", format!("{:?}", prewet_volume.as_ref())),
+                ("aspirate_volume", format!("{:?}", aspirate_volume.as_ref())),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume.as_ref()),
+                ),
+                ("dispense_volume", format!("{:?}", dispense_volume.as_ref())),
+                (
+                    "stop_back_volume",
+                    format!("{:?}", stop_back_volume.as_ref()),
+                ),
+                ("aspirate_speed", format!("{:?}", aspirate_speed.as_ref())),
+                ("dispense_speed", format!("{:?}", dispense_speed.as_ref())),
+                ("cutoff_speed", format!("{:?}", cutoff_speed.as_ref())),
+                ("settling_time", format!("{:?}", settling_time.as_ref())),
+                ("mix_volume", format!("{:?}", mix_volume.as_ref())),
+                ("mix_cycles", format!("{:?}", mix_cycles.as_ref())),
+                ("mix_position", format!("{:?}", mix_position.as_ref())),
+                (
+                    "mix_follow_distance",
+                    format!("{:?}", mix_follow_distance.as_ref()),
+                ),
+                ("mix_speed", format!("{:?}", mix_speed.as_ref())),
+                ("dispense_offset", format!("{:?}", dispense_offset.as_ref())),
+                (
+                    "tube_section_height",
+                    format!("{:?}", tube_section_height.as_ref()),
+                ),
+                (
+                    "tube_section_ratio",
+                    format!("{:?}", tube_section_ratio.as_ref()),
+                ),
+                ("lld_mode", format!("{:?}", lld_mode.as_ref())),
+                (
+                    "capacitive_lld_sensitivity",
+                    format!("{:?}", capacitive_lld_sensitivity.as_ref()),
+                ),
+                (
+                    "pressure_lld_sensitivity",
+                    format!("{:?}", pressure_lld_sensitivity.as_ref()),
+                ),
+                (
+                    "lld_height_difference",
+                    format!("{:?}", lld_height_difference.as_ref()),
+                ),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                (
+                    "limit_curve_index",
+                    format!("{:?}", limit_curve_index.as_ref()),
+                ),
+                ("recording_mode", format!("{:?}", recording_mode)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn pickup_gripper_tool(
+        &self,
+        x_position: Millimeters,
+        y_position_1_st_channel: Millimeters,
+        y_position_2_nd_channel: Millimeters,
+        traverse_height: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        tip_type: u16,
+        first_channel_number: u16,
+        second_channel_number: u16,
+        tool_width: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pickup_gripper_tool",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                (
+                    "y_position_1_st_channel",
+                    format!("{:?}", y_position_1_st_channel),
+                ),
+                (
+                    "y_position_2_nd_channel",
+                    format!("{:?}", y_position_2_nd_channel),
+                ),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("tip_type", format!("{:?}", tip_type)),
+                (
+                    "first_channel_number",
+                    format!("{:?}", first_channel_number),
+                ),
+                (
+                    "second_channel_number",
+                    format!("{:?}", second_channel_number),
+                ),
+                ("tool_width", format!("{:?}", tool_width)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn drop_gripper_tool(
+        &self,
+        x_position: Millimeters,
+        y_position_1_st_channel: Millimeters,
+        y_position_2_nd_channel: Millimeters,
+        traverse_height: Millimeters,
+        z_start_position: Millimeters,
+        z_stop_position: Millimeters,
+        z_final: Millimeters,
+        first_channel_number: u16,
+        second_channel_number: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "drop_gripper_tool",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                (
+                    "y_position_1_st_channel",
+                    format!("{:?}", y_position_1_st_channel),
+                ),
+                (
+                    "y_position_2_nd_channel",
+                    format!("{:?}", y_position_2_nd_channel),
+                ),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("z_start_position", format!("{:?}", z_start_position)),
+                ("z_stop_position", format!("{:?}", z_stop_position)),
+                ("z_final", format!("{:?}", z_final)),
+                (
+                    "first_channel_number",
+                    format!("{:?}", first_channel_number),
+                ),
+                (
+                    "second_channel_number",
+                    format!("{:?}", second_channel_number),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn pickup_plate(
+        &self,
+        x_position: Millimeters,
+        y_plate_center_position: Millimeters,
+        y_plate_width: Millimeters,
+        y_open_position: Millimeters,
+        y_grip_speed: MmPerSecond,
+        y_grip_strength: u32,
+        traverse_height: Millimeters,
+        z_grip_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pickup_plate",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                (
+                    "y_plate_center_position",
+                    format!("{:?}", y_plate_center_position),
+                ),
+                ("y_plate_width", format!("{:?}", y_plate_width)),
+                ("y_open_position", format!("{:?}", y_open_position)),
+                ("y_grip_speed", format!("{:?}", y_grip_speed)),
+                ("y_grip_strength", format!("{:?}", y_grip_strength)),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("z_grip_height", format!("{:?}", z_grip_height)),
+                ("z_final", format!("{:?}", z_final)),
+                ("z_speed", format!("{:?}", z_speed)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn drop_plate(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        y_open_position: Millimeters,
+        traverse_height: Millimeters,
+        z_drop_height: Millimeters,
+        z_press_distance: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "drop_plate",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("x_acceleration", format!("{:?}", x_acceleration)),
+                (
+                    "y_plate_center_position",
+                    format!("{:?}", y_plate_center_position),
+                ),
+                ("y_open_position", format!("{:?}", y_open_position)),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("z_drop_height", format!("{:?}", z_drop_height)),
+                ("z_press_distance", format!("{:?}", z_press_distance)),
+                ("z_final", format!("{:?}", z_final)),
+                ("z_speed", format!("{:?}", z_speed)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_plate(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        traverse_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_plate",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("x_acceleration", format!("{:?}", x_acceleration)),
+                (
+                    "y_plate_center_position",
+                    format!("{:?}", y_plate_center_position),
+                ),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("z_final", format!("{:?}", z_final)),
+                ("z_speed", format!("{:?}", z_speed)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn release_plate(
+        &self,
+        first_channel_number: u16,
+        second_channel_number: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "release_plate",
+            vec![
+                (
+                    "first_channel_number",
+                    format!("{:?}", first_channel_number),
+                ),
+                (
+                    "second_channel_number",
+                    format!("{:?}", second_channel_number),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn is_initialized(
+        &self,
+    ) -> impl Future<Output = Result</* initialized= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_initialized", vec![]);
+        async move { reply }
+    }
+
+    fn is_tip_present(
+        &self,
+    ) -> impl Future<Output = Result</* tip_present= */ Vec<i16>, Error>> + Send {
+        let reply = self.recorder.record("is_tip_present", vec![]);
+        async move { reply }
+    }
+
+    fn is_core_gripper_tool_held(
+        &self,
+    ) -> impl Future<Output = Result<IsCoreGripperToolHeldReply, Error>> + Send {
+        let reply = self.recorder.record("is_core_gripper_tool_held", vec![]);
+        async move { reply }
+    }
+
+    fn is_core_gripper_plate_gripped(
+        &self,
+    ) -> impl Future<Output = Result</* gripped= */ bool, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("is_core_gripper_plate_gripped", vec![]);
+        async move { reply }
+    }
+
+    fn get_tip_and_needle_types(
+        &self,
+    ) -> impl Future<Output = Result</* tip_type= */ Vec<u16>, Error>> + Send {
+        let reply = self.recorder.record("get_tip_and_needle_types", vec![]);
+        async move { reply }
+    }
+
+    fn get_position(&self) -> impl Future<Output = Result<GetPositionReply, Error>> + Send {
+        let reply = self.recorder.record("get_position", vec![]);
+        async move { reply }
+    }
+
+    fn park(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("park", vec![]);
+        async move { reply }
+    }
+
+    fn move_over(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_over",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("z_position", format!("{:?}", z_position.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_to_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_to_position",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("z_position", format!("{:?}", z_position.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_to_position_via_lane(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_to_position_via_lane",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_absolute_xy(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_absolute_xy",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_absolute_x(
+        &self,
+        x_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_absolute_x",
+            vec![("x_position", format!("{:?}", x_position))],
+        );
+        async move { reply }
+    }
+
+    fn move_relative_x(
+        &self,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_relative_x",
+            vec![("x_distance", format!("{:?}", x_distance))],
+        );
+        async move { reply }
+    }
+
+    fn move_absolute_y(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_absolute_y",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_relative_y(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_relative_y",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("y_distance", format!("{:?}", y_distance.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_absolute_z(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_absolute_z",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_position", format!("{:?}", z_position.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_relative_z(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_relative_z",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_distance", format!("{:?}", z_distance.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn preinitialize_smart(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("preinitialize_smart", vec![]);
+        async move { reply }
+    }
+
+    fn erase_limit_curves(&self, channel: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "erase_limit_curves",
+            vec![("channel", format!("{:?}", channel))],
+        );
+        async move { reply }
+    }
+
+    fn create_limit_curve(
+        &self,
+        channel: u16,
+        name: &str,
+        lower_limit_x: impl AsRef<[u16]> + Send,
+        lower_limit_y: impl AsRef<[i16]> + Send,
+        upper_limit_x: impl AsRef<[u16]> + Send,
+        upper_limit_y: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<CreateLimitCurveReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "create_limit_curve",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("name", format!("{:?}", name)),
+                ("lower_limit_x", format!("{:?}", lower_limit_x.as_ref())),
+                ("lower_limit_y", format!("{:?}", lower_limit_y.as_ref())),
+                ("upper_limit_x", format!("{:?}", upper_limit_x.as_ref())),
+                ("upper_limit_y", format!("{:?}", upper_limit_y.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn reset_tadm_fifo(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "reset_tadm_fifo",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn retrieve_tadm_data(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<RetrieveTadmDataReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "retrieve_tadm_data",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn get_limit_curve_names(
+        &self,
+        channel: u16,
+    ) -> impl Future<Output = Result<GetLimitCurveNamesReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "get_limit_curve_names",
+            vec![("channel", format!("{:?}", channel))],
+        );
+        async move { reply }
+    }
+
+    fn get_limit_curve_info(
+        &self,
+        channel: u16,
+        name: &str,
+    ) -> impl Future<Output = Result<GetLimitCurveInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "get_limit_curve_info",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("name", format!("{:?}", name)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_tadm_enable(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        enable: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_tadm_enable",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("enable", format!("{:?}", enable.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_tadm_enable(&self) -> impl Future<Output = Result<GetTADMEnableReply, Error>> + Send {
+        let reply = self.recorder.record("get_tadm_enable", vec![]);
+        async move { reply }
+    }
+
+    fn enable_mad(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        pressure_threshold: impl AsRef<[u16]> + Send,
+        minimum_pressure_difference: impl AsRef<[u16]> + Send,
+        maximum_pressure_difference: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "enable_mad",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "pressure_threshold",
+                    format!("{:?}", pressure_threshold.as_ref()),
+                ),
+                (
+                    "minimum_pressure_difference",
+                    format!("{:?}", minimum_pressure_difference.as_ref()),
+                ),
+                (
+                    "maximum_pressure_difference",
+                    format!("{:?}", maximum_pressure_difference.as_ref()),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn disable_mad(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "disable_mad",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn enable_adc(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "enable_adc",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn disable_adc(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "disable_adc",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn move_traverse_z(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_traverse_z",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_position", format!("{:?}", z_position.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn pickup_plate_suction(
+        &self,
+        x_position: Millimeters,
+        y_plate_center_position: Millimeters,
+        y_suction_offset: i32,
+        blowout_volume: Microliters,
+        suction_volume: Microliters,
+        pressure_differential: u16,
+        traverse_height: Millimeters,
+        z_grip_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<PickupPlateSuctionReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "pickup_plate_suction",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                (
+                    "y_plate_center_position",
+                    format!("{:?}", y_plate_center_position),
+                ),
+                ("y_suction_offset", format!("{:?}", y_suction_offset)),
+                ("blowout_volume", format!("{:?}", blowout_volume)),
+                ("suction_volume", format!("{:?}", suction_volume)),
+                (
+                    "pressure_differential",
+                    format!("{:?}", pressure_differential),
+                ),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("z_grip_height", format!("{:?}", z_grip_height)),
+                ("z_final", format!("{:?}", z_final)),
+                ("z_speed", format!("{:?}", z_speed)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn drop_plate_suction(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        pressure_differential: u16,
+        traverse_height: Millimeters,
+        z_drop_height: Millimeters,
+        z_press_distance: Millimeters,
+        z_lift_distance: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<DropPlateSuctionReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "drop_plate_suction",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("x_acceleration", format!("{:?}", x_acceleration)),
+                (
+                    "y_plate_center_position",
+                    format!("{:?}", y_plate_center_position),
+                ),
+                (
+                    "pressure_differential",
+                    format!("{:?}", pressure_differential),
+                ),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("z_drop_height", format!("{:?}", z_drop_height)),
+                ("z_press_distance", format!("{:?}", z_press_distance)),
+                ("z_lift_distance", format!("{:?}", z_lift_distance)),
+                ("z_final", format!("{:?}", z_final)),
+                ("z_speed", format!("{:?}", z_speed)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_plate_suction(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        pressure_differential: u16,
+        traverse_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_plate_suction",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("x_acceleration", format!("{:?}", x_acceleration)),
+                (
+                    "y_plate_center_position",
+                    format!("{:?}", y_plate_center_position),
+                ),
+                (
+                    "pressure_differential",
+                    format!("{:?}", pressure_differential),
+                ),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("z_final", format!("{:?}", z_final)),
+                ("z_speed", format!("{:?}", z_speed)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_seek_obstacle(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        obstacle_seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result</* obstacle_detected= */ Vec<i16>, Error>> + Send {
+        let reply = self.recorder.record(
+            "z_seek_obstacle",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                (
+                    "obstacle_seek_height",
+                    format!("{:?}", obstacle_seek_height.as_ref()),
+                ),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                ("z_final", format!("{:?}", z_final)),
+                ("seek_speed", format!("{:?}", seek_speed.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_remaining_channels(
+        &self,
+    ) -> impl Future<Output = Result<GetRemainingChannelsReply, Error>> + Send {
+        let reply = self.recorder.record("get_remaining_channels", vec![]);
+        async move { reply }
+    }
+
+    fn set_performance_parameters(
+        &self,
+        z_speed: MmPerSecond,
+        time: u16,
+        time_limit: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_performance_parameters",
+            vec![
+                ("z_speed", format!("{:?}", z_speed)),
+                ("time", format!("{:?}", time)),
+                ("time_limit", format!("{:?}", time_limit)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn reset_performance_parameters(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("reset_performance_parameters", vec![]);
+        async move { reply }
+    }
+
+    fn initialize_x(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("initialize_x", vec![]);
+        async move { reply }
+    }
+
+    fn initialize_y(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "initialize_y",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn initialize_z(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "initialize_z",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn initialize_squeeze(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "initialize_squeeze",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn initialize_dispenser(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "initialize_dispenser",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn squeeze_on(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "squeeze_on",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn squeeze_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "squeeze_off",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn empty_dispenser(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "empty_dispenser",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn move_relative_dispenser(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        volumes: impl AsRef<[Microliters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_relative_dispenser",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("volumes", format!("{:?}", volumes.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn multiple_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
+        x_number_of_wells: u16,
+        x_velocity_scale: u16,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        y_well_distance: impl AsRef<[Millimeters]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        tip_air_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        dispense_map: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "multiple_dispense",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position)),
+                ("x_well_distance", format!("{:?}", x_well_distance)),
+                ("x_well_width", format!("{:?}", x_well_width)),
+                ("x_number_of_wells", format!("{:?}", x_number_of_wells)),
+                ("x_velocity_scale", format!("{:?}", x_velocity_scale)),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("y_well_distance", format!("{:?}", y_well_distance.as_ref())),
+                ("volume", format!("{:?}", volume.as_ref())),
+                ("tip_air_volume", format!("{:?}", tip_air_volume.as_ref())),
+                (
+                    "stop_back_volume",
+                    format!("{:?}", stop_back_volume.as_ref()),
+                ),
+                ("dispense_speed", format!("{:?}", dispense_speed.as_ref())),
+                ("cutoff_speed", format!("{:?}", cutoff_speed.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("dispense_height", format!("{:?}", dispense_height.as_ref())),
+                ("z_final", format!("{:?}", z_final)),
+                ("dispense_map", format!("{:?}", dispense_map.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
+        x_number_of_wells: u16,
+        x_velocity_scale: u16,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        y_well_distance: impl AsRef<[Millimeters]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        tip_air_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        tadm_enabled: impl AsRef<[i16]> + Send,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        dispense_map: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "on_the_fly_dispense",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position)),
+                ("x_well_distance", format!("{:?}", x_well_distance)),
+                ("x_well_width", format!("{:?}", x_well_width)),
+                ("x_number_of_wells", format!("{:?}", x_number_of_wells)),
+                ("x_velocity_scale", format!("{:?}", x_velocity_scale)),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("y_well_distance", format!("{:?}", y_well_distance.as_ref())),
+                ("volume", format!("{:?}", volume.as_ref())),
+                ("tip_air_volume", format!("{:?}", tip_air_volume.as_ref())),
+                (
+                    "stop_back_volume",
+                    format!("{:?}", stop_back_volume.as_ref()),
+                ),
+                ("dispense_speed", format!("{:?}", dispense_speed.as_ref())),
+                ("cutoff_speed", format!("{:?}", cutoff_speed.as_ref())),
+                ("tadm_enabled", format!("{:?}", tadm_enabled.as_ref())),
+                (
+                    "limit_curve_index",
+                    format!("{:?}", limit_curve_index.as_ref()),
+                ),
+                ("recording_mode", format!("{:?}", recording_mode)),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("dispense_height", format!("{:?}", dispense_height.as_ref())),
+                ("z_final", format!("{:?}", z_final)),
+                ("dispense_map", format!("{:?}", dispense_map.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_current_dispenser_volume(
+        &self,
+    ) -> impl Future<Output = Result</* volume= */ Vec<Microliters>, Error>> + Send {
+        let reply = self.recorder.record("get_current_dispenser_volume", vec![]);
+        async move { reply }
+    }
+
+    fn get_liquid_height(
+        &self,
+    ) -> impl Future<Output = Result</* liquid_height= */ Vec<Millimeters>, Error>> + Send {
+        let reply = self.recorder.record("get_liquid_height", vec![]);
+        async move { reply }
+    }
+
+    fn get_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<GetChannelConfigurationReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "get_channel_configuration",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("indexes", format!("{:?}", indexes.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+        enables: impl AsRef<[bool]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_channel_configuration",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("indexes", format!("{:?}", indexes.as_ref())),
+                ("enables", format!("{:?}", enables.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_seek_obstacle_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        obstacle_seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<ZSeekObstaclePositionReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "z_seek_obstacle_position",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                (
+                    "obstacle_seek_height",
+                    format!("{:?}", obstacle_seek_height.as_ref()),
+                ),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                ("z_final", format!("{:?}", z_final)),
+                ("seek_speed", format!("{:?}", seek_speed.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_seek_lld_position(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        seek_height: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        seek_speed: impl AsRef<[MmPerSecond]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result<ZSeekLldPositionReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "z_seek_lld_position",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("seek_height", format!("{:?}", seek_height.as_ref())),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                ("z_final", format!("{:?}", z_final)),
+                ("seek_speed", format!("{:?}", seek_speed.as_ref())),
+                (
+                    "capacitive_lld_sensitivity",
+                    format!("{:?}", capacitive_lld_sensitivity.as_ref()),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn dispense_initialize_to_waste(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "dispense_initialize_to_waste",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("z_position", format!("{:?}", z_position.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn multiple_dispense_transport_air(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
+        x_number_of_wells: u16,
+        x_velocity_scale: u16,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        y_well_distance: impl AsRef<[Millimeters]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        tip_air_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        dispense_map: impl AsRef<[u16]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "multiple_dispense_transport_air",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position)),
+                ("x_well_distance", format!("{:?}", x_well_distance)),
+                ("x_well_width", format!("{:?}", x_well_width)),
+                ("x_number_of_wells", format!("{:?}", x_number_of_wells)),
+                ("x_velocity_scale", format!("{:?}", x_velocity_scale)),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("y_well_distance", format!("{:?}", y_well_distance.as_ref())),
+                ("volume", format!("{:?}", volume.as_ref())),
+                ("tip_air_volume", format!("{:?}", tip_air_volume.as_ref())),
+                (
+                    "stop_back_volume",
+                    format!("{:?}", stop_back_volume.as_ref()),
+                ),
+                ("dispense_speed", format!("{:?}", dispense_speed.as_ref())),
+                ("cutoff_speed", format!("{:?}", cutoff_speed.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("dispense_height", format!("{:?}", dispense_height.as_ref())),
+                ("z_final", format!("{:?}", z_final)),
+                ("dispense_map", format!("{:?}", dispense_map.as_ref())),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume.as_ref()),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn on_the_fly_dispense_transport_air(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_well_distance: Millimeters,
+        x_well_width: Millimeters,
+        x_number_of_wells: u16,
+        x_velocity_scale: u16,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        y_well_distance: impl AsRef<[Millimeters]> + Send,
+        volume: impl AsRef<[Microliters]> + Send,
+        tip_air_volume: impl AsRef<[Microliters]> + Send,
+        stop_back_volume: impl AsRef<[Microliters]> + Send,
+        dispense_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        cutoff_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        tadm_enabled: impl AsRef<[i16]> + Send,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+        traverse_height: Millimeters,
+        dispense_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        dispense_map: impl AsRef<[u16]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "on_the_fly_dispense_transport_air",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position)),
+                ("x_well_distance", format!("{:?}", x_well_distance)),
+                ("x_well_width", format!("{:?}", x_well_width)),
+                ("x_number_of_wells", format!("{:?}", x_number_of_wells)),
+                ("x_velocity_scale", format!("{:?}", x_velocity_scale)),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("y_well_distance", format!("{:?}", y_well_distance.as_ref())),
+                ("volume", format!("{:?}", volume.as_ref())),
+                ("tip_air_volume", format!("{:?}", tip_air_volume.as_ref())),
+                (
+                    "stop_back_volume",
+                    format!("{:?}", stop_back_volume.as_ref()),
+                ),
+                ("dispense_speed", format!("{:?}", dispense_speed.as_ref())),
+                ("cutoff_speed", format!("{:?}", cutoff_speed.as_ref())),
+                ("tadm_enabled", format!("{:?}", tadm_enabled.as_ref())),
+                (
+                    "limit_curve_index",
+                    format!("{:?}", limit_curve_index.as_ref()),
+                ),
+                ("recording_mode", format!("{:?}", recording_mode)),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("dispense_height", format!("{:?}", dispense_height.as_ref())),
+                ("z_final", format!("{:?}", z_final)),
+                ("dispense_map", format!("{:?}", dispense_map.as_ref())),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume.as_ref()),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_z_liquid_seek_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        seek_speeds: impl AsRef<[MmPerSecond]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_z_liquid_seek_speed",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("seek_speeds", format!("{:?}", seek_speeds.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_z_liquid_seek_speed(
+        &self,
+    ) -> impl Future<Output = Result</* seek_speeds= */ Vec<MmPerSecond>, Error>> + Send {
+        let reply = self.recorder.record("get_z_liquid_seek_speed", vec![]);
+        async move { reply }
+    }
+
+    fn move_absolute_z_2_speed(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        z_position: impl AsRef<[Millimeters]> + Send,
+        z_speed_switch_position: impl AsRef<[Millimeters]> + Send,
+        velocity: impl AsRef<[u32]> + Send,
+        lower_section_velocity: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_absolute_z_2_speed",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("z_position", format!("{:?}", z_position.as_ref())),
+                (
+                    "z_speed_switch_position",
+                    format!("{:?}", z_speed_switch_position.as_ref()),
+                ),
+                ("velocity", format!("{:?}", velocity.as_ref())),
+                (
+                    "lower_section_velocity",
+                    format!("{:?}", lower_section_velocity.as_ref()),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn pickup_plate_suction_2(
+        &self,
+        x_position: Millimeters,
+        y_plate_center_position: Millimeters,
+        y_suction_offset: i32,
+        blowout_volume: Microliters,
+        suction_volume: Microliters,
+        traverse_height: Millimeters,
+        z_grip_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "pickup_plate_suction_2",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                (
+                    "y_plate_center_position",
+                    format!("{:?}", y_plate_center_position),
+                ),
+                ("y_suction_offset", format!("{:?}", y_suction_offset)),
+                ("blowout_volume", format!("{:?}", blowout_volume)),
+                ("suction_volume", format!("{:?}", suction_volume)),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("z_grip_height", format!("{:?}", z_grip_height)),
+                ("z_final", format!("{:?}", z_final)),
+                ("z_speed", format!("{:?}", z_speed)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn drop_plate_suction_2(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        traverse_height: Millimeters,
+        z_drop_height: Millimeters,
+        z_press_distance: Millimeters,
+        z_lift_distance: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "drop_plate_suction_2",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("x_acceleration", format!("{:?}", x_acceleration)),
+                (
+                    "y_plate_center_position",
+                    format!("{:?}", y_plate_center_position),
+                ),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("z_drop_height", format!("{:?}", z_drop_height)),
+                ("z_press_distance", format!("{:?}", z_press_distance)),
+                ("z_lift_distance", format!("{:?}", z_lift_distance)),
+                ("z_final", format!("{:?}", z_final)),
+                ("z_speed", format!("{:?}", z_speed)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_plate_suction_2(
+        &self,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_plate_center_position: Millimeters,
+        traverse_height: Millimeters,
+        z_final: Millimeters,
+        z_speed: MmPerSecond,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_plate_suction_2",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("x_acceleration", format!("{:?}", x_acceleration)),
+                (
+                    "y_plate_center_position",
+                    format!("{:?}", y_plate_center_position),
+                ),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                ("z_final", format!("{:?}", z_final)),
+                ("z_speed", format!("{:?}", z_speed)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn check_plate_suction(
+        &self,
+        first_channel_number: u16,
+        second_channel_number: u16,
+        suction_volume: Microliters,
+    ) -> impl Future<Output = Result<CheckPlateSuctionReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "check_plate_suction",
+            vec![
+                (
+                    "first_channel_number",
+                    format!("{:?}", first_channel_number),
+                ),
+                (
+                    "second_channel_number",
+                    format!("{:?}", second_channel_number),
+                ),
+                ("suction_volume", format!("{:?}", suction_volume)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn initialize_roll(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "initialize_roll",
+            vec![
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("z_final", format!("{:?}", z_final.as_ref())),
+                ("roll_distance", format!("{:?}", roll_distance.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn initialize_smart_roll(
+        &self,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "initialize_smart_roll",
+            vec![
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("z_final", format!("{:?}", z_final.as_ref())),
+                ("roll_distance", format!("{:?}", roll_distance.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn drop_tips_roll(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        z_start_position: impl AsRef<[Millimeters]> + Send,
+        z_stop_position: impl AsRef<[Millimeters]> + Send,
+        z_final: impl AsRef<[Millimeters]> + Send,
+        roll_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "drop_tips_roll",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                (
+                    "z_start_position",
+                    format!("{:?}", z_start_position.as_ref()),
+                ),
+                ("z_stop_position", format!("{:?}", z_stop_position.as_ref())),
+                ("z_final", format!("{:?}", z_final.as_ref())),
+                ("roll_distance", format!("{:?}", roll_distance.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_move_safe(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_safe",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn aspirate_2(
+        &self,
+        aspirate_type: impl AsRef<[i16]> + Send,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: impl AsRef<[Millimeters]> + Send,
+        y_position: impl AsRef<[Millimeters]> + Send,
+        traverse_height: Millimeters,
+        liquid_seek_height: impl AsRef<[Millimeters]> + Send,
+        liquid_surface_height: impl AsRef<[Millimeters]> + Send,
+        submerge_depth: impl AsRef<[Millimeters]> + Send,
+        follow_depth: impl AsRef<[Millimeters]> + Send,
+        z_min_position: impl AsRef<[Millimeters]> + Send,
+        clot_check_height: impl AsRef<[Millimeters]> + Send,
+        z_final: Millimeters,
+        liquid_exit_speed: impl AsRef<[MmPerSecond]> + Send,
+        blowout_volume: impl AsRef<[Microliters]> + Send,
+        prewet_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_volume: impl AsRef<[Microliters]> + Send,
+        transport_air_volume: impl AsRef<[Microliters]> + Send,
+        aspirate_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        settling_time: impl AsRef<[u32]> + Send,
+        mix_volume: impl AsRef<[Microliters]> + Send,
+        mix_cycles: impl AsRef<[u32]> + Send,
+        mix_position: impl AsRef<[Millimeters]> + Send,
+        mix_follow_distance: impl AsRef<[Millimeters]> + Send,
+        mix_speed: impl AsRef<[MicrolitersPerSecond]> + Send,
+        aspirate_offset: impl AsRef<[i32]> + Send,
+        tube_section_height: impl AsRef<[Millimeters]> + Send,
+        tube_section_ratio: impl AsRef<[i32]> + Send,
+        lld_mode: impl AsRef<[i16]> + Send,
+        capacitive_lld_sensitivity: impl AsRef<[i16]> + Send,
+        pressure_lld_sensitivity: impl AsRef<[i16]> + Send,
+        lld_height_difference: impl AsRef<[i32]> + Send,
+        tadm_enabled: bool,
+        limit_curve_index: impl AsRef<[u32]> + Send,
+        recording_mode: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "aspirate_2",
+            vec![
+                ("aspirate_type", format!("{:?}", aspirate_type.as_ref())),
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position.as_ref())),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+                ("traverse_height", format!("{:?}", traverse_height)),
+                (
+                    "liquid_seek_height",
+                    format!("{:?}", liquid_seek_height.as_ref()),
+                ),
+                (
+                    "liquid_surface_height",
+                    format!("{:?}", liquid_surface_height.as_ref()),
+                ),
+                ("submerge_depth", format!("{:?}", submerge_depth.as_ref())),
+                ("follow_depth", format!("{:?}", follow_depth.as_ref())),
+                ("z_min_position", format!("{:?}", z_min_position.as_ref())),
+                (
+                    "clot_check_height",
+                    format!("{:?}", clot_check_height.as_ref()),
+                ),
+                ("z_final", format!("{:?}", z_final)),
+                (
+                    "liquid_exit_speed",
+                    format!("{:?}", liquid_exit_speed.as_ref()),
+                ),
+                ("blowout_volume", format!("{:?}", blowout_volume.as_ref())),
+                ("prewet_volume", format!("{:?}", prewet_volume.as_ref())),
+                ("aspirate_volume", format!("{:?}", aspirate_volume.as_ref())),
+                (
+                    "transport_air_volume",
+                    format!("{:?}", transport_air_volume.as_ref()),
+                ),
+                ("aspirate_speed", format!("{:?}", aspirate_speed.as_ref())),
+                ("settling_time", format!("{:?}", settling_time.as_ref())),
+                ("mix_volume", format!("{:?}", mix_volume.as_ref())),
+                ("mix_cycles", format!("{:?}", mix_cycles.as_ref())),
+                ("mix_position", format!("{:?}", mix_position.as_ref())),
+                (
+                    "mix_follow_distance",
+                    format!("{:?}", mix_follow_distance.as_ref()),
+                ),
+                ("mix_speed", format!("{:?}", mix_speed.as_ref())),
+                ("aspirate_offset", format!("{:?}", aspirate_offset.as_ref())),
+                (
+                    "tube_section_height",
+                    format!("{:?}", tube_section_height.as_ref()),
+                ),
+                (
+                    "tube_section_ratio",
+                    format!("{:?}", tube_section_ratio.as_ref()),
+                ),
+                ("lld_mode", format!("{:?}", lld_mode.as_ref())),
+                (
+                    "capacitive_lld_sensitivity",
+                    format!("{:?}", capacitive_lld_sensitivity.as_ref()),
+                ),
+                (
+                    "pressure_lld_sensitivity",
+                    format!("{:?}", pressure_lld_sensitivity.as_ref()),
+                ),
+                (
+                    "lld_height_difference",
+                    format!("{:?}", lld_height_difference.as_ref()),
+                ),
+                ("tadm_enabled", format!("{:?}", tadm_enabled)),
+                (
+                    "limit_curve_index",
+                    format!("{:?}", limit_curve_index.as_ref()),
+                ),
+                ("recording_mode", format!("{:?}", recording_mode)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn is_x_interlock_active(
+        &self,
+    ) -> impl Future<Output = Result</* active= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_x_interlock_active", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aspirate1Request {
+    pub aspirate_type: Option<Vec<i16>>,
+    pub tips_used: Option<Vec<u16>>,
+    pub x_position: Option<Vec<Millimeters>>,
+    pub y_position: Option<Vec<Millimeters>>,
+    pub traverse_height: Option<Millimeters>,
+    pub liquid_seek_height: Option<Vec<Millimeters>>,
+    pub liquid_surface_height: Option<Vec<Millimeters>>,
+    pub submerge_depth: Option<Vec<Millimeters>>,
+    pub follow_depth: Option<Vec<Millimeters>>,
+    pub z_min_position: Option<Vec<Millimeters>>,
+    pub clot_check_height: Option<Vec<Millimeters>>,
+    pub z_final: Option<Millimeters>,
+    pub liquid_exit_speed: Option<Vec<MmPerSecond>>,
+    pub blowout_volume: Option<Vec<Microliters>>,
+    pub prewet_volume: Option<Vec<Microliters>>,
+    pub aspirate_volume: Option<Vec<Microliters>>,
+    pub transport_air_volume: Option<Vec<Microliters>>,
+    pub aspirate_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub settling_time: Option<Vec<u32>>,
+    pub mix_volume: Option<Vec<Microliters>>,
+    pub mix_cycles: Option<Vec<u32>>,
+    pub mix_position: Option<Vec<Millimeters>>,
+    pub mix_follow_distance: Option<Vec<Millimeters>>,
+    pub mix_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub tube_section_height: Option<Vec<Millimeters>>,
+    pub tube_section_ratio: Option<Vec<i32>>,
+    pub lld_mode: Option<Vec<i16>>,
+    pub capacitive_lld_sensitivity: Option<Vec<i16>>,
+    pub pressure_lld_sensitivity: Option<Vec<i16>>,
+    pub lld_height_difference: Option<Vec<i32>>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<Vec<u32>>,
+    pub recording_mode: Option<u16>,
+}
+
+impl Aspirate1Request {
+    pub fn aspirate_type(mut self, aspirate_type: impl Into<Vec<i16>>) -> Self {
+        self.aspirate_type = Some(aspirate_type.into());
+        self
+    }
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn x_position(mut self, x_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.x_position = Some(x_position.into());
+        self
+    }
+    pub fn y_position(mut self, y_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_position = Some(y_position.into());
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn liquid_seek_height(mut self, liquid_seek_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.liquid_seek_height = Some(liquid_seek_height.into());
+        self
+    }
+    pub fn liquid_surface_height(
+        mut self,
+        liquid_surface_height: impl Into<Vec<Millimeters>>,
+    ) -> Self {
+        self.liquid_surface_height = Some(liquid_surface_height.into());
+        self
+    }
+    pub fn submerge_depth(mut self, submerge_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.submerge_depth = Some(submerge_depth.into());
+        self
+    }
+    pub fn follow_depth(mut self, follow_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.follow_depth = Some(follow_depth.into());
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.z_min_position = Some(z_min_position.into());
+        self
+    }
+    pub fn clot_check_height(mut self, clot_check_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.clot_check_height = Some(clot_check_height.into());
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn liquid_exit_speed(mut self, liquid_exit_speed: impl Into<Vec<MmPerSecond>>) -> Self {
+        self.liquid_exit_speed = Some(liquid_exit_speed.into());
+        self
+    }
+    pub fn blowout_volume(mut self, blowout_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.blowout_volume = Some(blowout_volume.into());
+        self
+    }
+    pub fn prewet_volume(mut self, prewet_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.prewet_volume = Some(prewet_volume.into());
+        self
+    }
+    pub fn aspirate_volume(mut self, aspirate_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.aspirate_volume = Some(aspirate_volume.into());
+        self
+    }
+    pub fn transport_air_volume(
+        mut self,
+        transport_air_volume: impl Into<Vec<Microliters>>,
+    ) -> Self {
+        self.transport_air_volume = Some(transport_air_volume.into());
+        self
+    }
+    pub fn aspirate_speed(mut self, aspirate_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.aspirate_speed = Some(aspirate_speed.into());
+        self
+    }
+    pub fn settling_time(mut self, settling_time: impl Into<Vec<u32>>) -> Self {
+        self.settling_time = Some(settling_time.into());
+        self
+    }
+    pub fn mix_volume(mut self, mix_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.mix_volume = Some(mix_volume.into());
+        self
+    }
+    pub fn mix_cycles(mut self, mix_cycles: impl Into<Vec<u32>>) -> Self {
+        self.mix_cycles = Some(mix_cycles.into());
+        self
+    }
+    pub fn mix_position(mut self, mix_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_position = Some(mix_position.into());
+        self
+    }
+    pub fn mix_follow_distance(mut self, mix_follow_distance: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_follow_distance = Some(mix_follow_distance.into());
+        self
+    }
+    pub fn mix_speed(mut self, mix_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.mix_speed = Some(mix_speed.into());
+        self
+    }
+    pub fn tube_section_height(mut self, tube_section_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.tube_section_height = Some(tube_section_height.into());
+        self
+    }
+    pub fn tube_section_ratio(mut self, tube_section_ratio: impl Into<Vec<i32>>) -> Self {
+        self.tube_section_ratio = Some(tube_section_ratio.into());
+        self
+    }
+    pub fn lld_mode(mut self, lld_mode: impl Into<Vec<i16>>) -> Self {
+        self.lld_mode = Some(lld_mode.into());
+        self
+    }
+    pub fn capacitive_lld_sensitivity(
+        mut self,
+        capacitive_lld_sensitivity: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.capacitive_lld_sensitivity = Some(capacitive_lld_sensitivity.into());
+        self
+    }
+    pub fn pressure_lld_sensitivity(
+        mut self,
+        pressure_lld_sensitivity: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.pressure_lld_sensitivity = Some(pressure_lld_sensitivity.into());
+        self
+    }
+    pub fn lld_height_difference(mut self, lld_height_difference: impl Into<Vec<i32>>) -> Self {
+        self.lld_height_difference = Some(lld_height_difference.into());
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: impl Into<Vec<u32>>) -> Self {
+        self.limit_curve_index = Some(limit_curve_index.into());
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DispenseRequest {
+    pub dispense_type: Option<Vec<i16>>,
+    pub tips_used: Option<Vec<u16>>,
+    pub x_position: Option<Vec<Millimeters>>,
+    pub y_position: Option<Vec<Millimeters>>,
+    pub traverse_height: Option<Millimeters>,
+    pub liquid_seek_height: Option<Vec<Millimeters>>,
+    pub dispense_height: Option<Vec<Millimeters>>,
+    pub submerge_depth: Option<Vec<Millimeters>>,
+    pub follow_depth: Option<Vec<Millimeters>>,
+    pub z_min_position: Option<Vec<Millimeters>>,
+    pub z_final: Option<Millimeters>,
+    pub liquid_exit_speed: Option<Vec<MmPerSecond>>,
+    pub transport_air_volume: Option<Vec<Microliters>>,
+    pub dispense_volume: Option<Vec<Microliters>>,
+    pub stop_back_volume: Option<Vec<Microliters>>,
+    pub blowout_volume: Option<Vec<Microliters>>,
+    pub dispense_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub cutoff_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub settling_time: Option<Vec<u32>>,
+    pub mix_volume: Option<Vec<Microliters>>,
+    pub mix_cycles: Option<Vec<u32>>,
+    pub mix_position: Option<Vec<Millimeters>>,
+    pub mix_follow_distance: Option<Vec<Millimeters>>,
+    pub mix_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub touch_off_distance: Option<Millimeters>,
+    pub dispense_offset: Option<Vec<i32>>,
+    pub tube_section_height: Option<Vec<Millimeters>>,
+    pub tube_section_ratio: Option<Vec<i32>>,
+    pub lld_mode: Option<Vec<i16>>,
+    pub capacitive_lld_sensitivity: Option<Vec<i16>>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<Vec<u32>>,
+    pub recording_mode: Option<u16>,
+}
+
+impl DispenseRequest {
+    pub fn dispense_type(mut self, dispense_type: impl Into<Vec<i16>>) -> Self {
+        self.dispense_type = Some(dispense_type.into());
+        self
+    }
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn x_position(mut self, x_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.x_position = Some(x_position.into());
+        self
+    }
+    pub fn y_position(mut self, y_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_position = Some(y_position.into());
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn liquid_seek_height(mut self, liquid_seek_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.liquid_seek_height = Some(liquid_seek_height.into());
+        self
+    }
+    pub fn dispense_height(mut self, dispense_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.dispense_height = Some(dispense_height.into());
+        self
+    }
+    pub fn submerge_depth(mut self, submerge_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.submerge_depth = Some(submerge_depth.into());
+        self
+    }
+    pub fn follow_depth(mut self, follow_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.follow_depth = Some(follow_depth.into());
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.z_min_position = Some(z_min_position.into());
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn liquid_exit_speed(mut self, liquid_exit_speed: impl Into<Vec<MmPerSecond>>) -> Self {
+        self.liquid_exit_speed = Some(liquid_exit_speed.into());
+        self
+    }
+    pub fn transport_air_volume(
+        mut self,
+        transport_air_volume: impl Into<Vec<Microliters>>,
+    ) -> Self {
+        self.transport_air_volume = Some(transport_air_volume.into());
+        self
+    }
+    pub fn dispense_volume(mut self, dispense_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.dispense_volume = Some(dispense_volume.into());
+        self
+    }
+    pub fn stop_back_volume(mut self, stop_back_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.stop_back_volume = Some(stop_back_volume.into());
+        self
+    }
+    pub fn blowout_volume(mut self, blowout_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.blowout_volume = Some(blowout_volume.into());
+        self
+    }
+    pub fn dispense_speed(mut self, dispense_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.dispense_speed = Some(dispense_speed.into());
+        self
+    }
+    pub fn cutoff_speed(mut self, cutoff_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.cutoff_speed = Some(cutoff_speed.into());
+        self
+    }
+    pub fn settling_time(mut self, settling_time: impl Into<Vec<u32>>) -> Self {
+        self.settling_time = Some(settling_time.into());
+        self
+    }
+    pub fn mix_volume(mut self, mix_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.mix_volume = Some(mix_volume.into());
+        self
+    }
+    pub fn mix_cycles(mut self, mix_cycles: impl Into<Vec<u32>>) -> Self {
+        self.mix_cycles = Some(mix_cycles.into());
+        self
+    }
+    pub fn mix_position(mut self, mix_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_position = Some(mix_position.into());
+        self
+    }
+    pub fn mix_follow_distance(mut self, mix_follow_distance: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_follow_distance = Some(mix_follow_distance.into());
+        self
+    }
+    pub fn mix_speed(mut self, mix_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.mix_speed = Some(mix_speed.into());
+        self
+    }
+    pub fn touch_off_distance(mut self, touch_off_distance: Millimeters) -> Self {
+        self.touch_off_distance = Some(touch_off_distance);
+        self
+    }
+    pub fn dispense_offset(mut self, dispense_offset: impl Into<Vec<i32>>) -> Self {
+        self.dispense_offset = Some(dispense_offset.into());
+        self
+    }
+    pub fn tube_section_height(mut self, tube_section_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.tube_section_height = Some(tube_section_height.into());
+        self
+    }
+    pub fn tube_section_ratio(mut self, tube_section_ratio: impl Into<Vec<i32>>) -> Self {
+        self.tube_section_ratio = Some(tube_section_ratio.into());
+        self
+    }
+    pub fn lld_mode(mut self, lld_mode: impl Into<Vec<i16>>) -> Self {
+        self.lld_mode = Some(lld_mode.into());
+        self
+    }
+    pub fn capacitive_lld_sensitivity(
+        mut self,
+        capacitive_lld_sensitivity: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.capacitive_lld_sensitivity = Some(capacitive_lld_sensitivity.into());
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: impl Into<Vec<u32>>) -> Self {
+        self.limit_curve_index = Some(limit_curve_index.into());
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AspirateAndDispenseRequest {
+    pub aspirate_type: Option<Vec<i16>>,
+    pub dispense_type: Option<Vec<i16>>,
+    pub tips_used: Option<Vec<u16>>,
+    pub aspirate_dispense_pattern: Option<Vec<i16>>,
+    pub x_position: Option<Vec<Millimeters>>,
+    pub y_position: Option<Vec<Millimeters>>,
+    pub traverse_height: Option<Millimeters>,
+    pub liquid_seek_height: Option<Vec<Millimeters>>,
+    pub liquid_surface_height: Option<Vec<Millimeters>>,
+    pub submerge_depth: Option<Vec<Millimeters>>,
+    pub follow_depth: Option<Vec<Millimeters>>,
+    pub z_min_position: Option<Vec<Millimeters>>,
+    pub clot_check_height: Option<Vec<Millimeters>>,
+    pub z_final: Option<Millimeters>,
+    pub liquid_exit_speed: Option<Vec<MmPerSecond>>,
+    pub blowout_volume: Option<Vec<Microliters>>,
+    pub prewet_volume: Option<Vec<Microliters>>,
+    pub aspirate_volume: Option<Vec<Microliters>>,
+    pub transport_air_volume: Option<Vec<Microliters>>,
+    pub dispense_volume: Option<Vec<Microliters>>,
+    pub stop_back_volume: Option<Vec<Microliters>>,
+    pub aspirate_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub dispense_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub cutoff_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub settling_time: Option<Vec<u32>>,
+    pub mix_volume: Option<Vec<Microliters>>,
+    pub mix_cycles: Option<Vec<u32>>,
+    pub mix_position: Option<Vec<Millimeters>>,
+    pub mix_follow_distance: Option<Vec<Millimeters>>,
+    pub mix_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub dispense_offset: Option<Vec<i32>>,
+    pub tube_section_height: Option<Vec<Millimeters>>,
+    pub tube_section_ratio: Option<Vec<i32>>,
+    pub lld_mode: Option<Vec<i16>>,
+    pub capacitive_lld_sensitivity: Option<Vec<i16>>,
+    pub pressure_lld_sensitivity: Option<Vec<i16>>,
+    pub lld_height_difference: Option<Vec<i32>>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<Vec<u32>>,
+    pub recording_mode: Option<u16>,
+}
+
+impl AspirateAndDispenseRequest {
+    pub fn aspirate_type(mut self, aspirate_type: impl Into<Vec<i16>>) -> Self {
+        self.aspirate_type = Some(aspirate_type.into());
+        self
+    }
+    pub fn dispense_type(mut self, dispense_type: impl Into<Vec<i16>>) -> Self {
+        self.dispense_type = Some(dispense_type.into());
+        self
+    }
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn aspirate_dispense_pattern(
+        mut self,
+        aspirate_dispense_pattern: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.aspirate_dispense_pattern = Some(aspirate_dispense_pattern.into());
+        self
+    }
+    pub fn x_position(mut self, x_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.x_position = Some(x_position.into());
+        self
+    }
+    pub fn y_position(mut self, y_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_position = Some(y_position.into());
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn liquid_seek_height(mut self, liquid_seek_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.liquid_seek_height = Some(liquid_seek_height.into());
+        self
+    }
+    pub fn liquid_surface_height(
+        mut self,
+        liquid_surface_height: impl Into<Vec<Millimeters>>,
+    ) -> Self {
+        self.liquid_surface_height = Some(liquid_surface_height.into());
+        self
+    }
+    pub fn submerge_depth(mut self, submerge_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.submerge_depth = Some(submerge_depth.into());
+        self
+    }
+    pub fn follow_depth(mut self, follow_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.follow_depth = Some(follow_depth.into());
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.z_min_position = Some(z_min_position.into());
+        self
+    }
+    pub fn clot_check_height(mut self, clot_check_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.clot_check_height = Some(clot_check_height.into());
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn liquid_exit_speed(mut self, liquid_exit_speed: impl Into<Vec<MmPerSecond>>) -> Self {
+        self.liquid_exit_speed = Some(liquid_exit_speed.into());
+        self
+    }
+    pub fn blowout_volume(mut self, blowout_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.blowout_volume = Some(blowout_volume.into());
+        self
+    }
+    pub fn prewet_volume(mut self, prewet_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.prewet_volume = Some(prewet_volume.into());
+        self
+    }
+    pub fn aspirate_volume(mut self, aspirate_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.aspirate_volume = Some(aspirate_volume.into());
+        self
+    }
+    pub fn transport_air_volume(
+        mut self,
+        transport_air_volume: impl Into<Vec<Microliters>>,
+    ) -> Self {
+        self.transport_air_volume = Some(transport_air_volume.into());
+        self
+    }
+    pub fn dispense_volume(mut self, dispense_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.dispense_volume = Some(dispense_volume.into());
+        self
+    }
+    pub fn stop_back_volume(mut self, stop_back_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.stop_back_volume = Some(stop_back_volume.into());
+        self
+    }
+    pub fn aspirate_speed(mut self, aspirate_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.aspirate_speed = Some(aspirate_speed.into());
+        self
+    }
+    pub fn dispense_speed(mut self, dispense_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.dispense_speed = Some(dispense_speed.into());
+        self
+    }
+    pub fn cutoff_speed(mut self, cutoff_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.cutoff_speed = Some(cutoff_speed.into());
+        self
+    }
+    pub fn settling_time(mut self, settling_time: impl Into<Vec<u32>>) -> Self {
+        self.settling_time = Some(settling_time.into());
+        self
+    }
+    pub fn mix_volume(mut self, mix_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.mix_volume = Some(mix_volume.into());
+        self
+    }
+    pub fn mix_cycles(mut self, mix_cycles: impl Into<Vec<u32>>) -> Self {
+        self.mix_cycles = Some(mix_cycles.into());
+        self
+    }
+    pub fn mix_position(mut self, mix_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_position = Some(mix_position.into());
+        self
+    }
+    pub fn mix_follow_distance(mut self, mix_follow_distance: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_follow_distance = Some(mix_follow_distance.into());
+        self
+    }
+    pub fn mix_speed(mut self, mix_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.mix_speed = Some(mix_speed.into());
+        self
+    }
+    pub fn dispense_offset(mut self, dispense_offset: impl Into<Vec<i32>>) -> Self {
+        self.dispense_offset = Some(dispense_offset.into());
+        self
+    }
+    pub fn tube_section_height(mut self, tube_section_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.tube_section_height = Some(tube_section_height.into());
+        self
+    }
+    pub fn tube_section_ratio(mut self, tube_section_ratio: impl Into<Vec<i32>>) -> Self {
+        self.tube_section_ratio = Some(tube_section_ratio.into());
+        self
+    }
+    pub fn lld_mode(mut self, lld_mode: impl Into<Vec<i16>>) -> Self {
+        self.lld_mode = Some(lld_mode.into());
+        self
+    }
+    pub fn capacitive_lld_sensitivity(
+        mut self,
+        capacitive_lld_sensitivity: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.capacitive_lld_sensitivity = Some(capacitive_lld_sensitivity.into());
+        self
+    }
+    pub fn pressure_lld_sensitivity(
+        mut self,
+        pressure_lld_sensitivity: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.pressure_lld_sensitivity = Some(pressure_lld_sensitivity.into());
+        self
+    }
+    pub fn lld_height_difference(mut self, lld_height_difference: impl Into<Vec<i32>>) -> Self {
+        self.lld_height_difference = Some(lld_height_difference.into());
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: impl Into<Vec<u32>>) -> Self {
+        self.limit_curve_index = Some(limit_curve_index.into());
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PickupGripperToolRequest {
+    pub x_position: Option<Millimeters>,
+    pub y_position_1_st_channel: Option<Millimeters>,
+    pub y_position_2_nd_channel: Option<Millimeters>,
+    pub traverse_height: Option<Millimeters>,
+    pub z_start_position: Option<Millimeters>,
+    pub z_stop_position: Option<Millimeters>,
+    pub tip_type: Option<u16>,
+    pub first_channel_number: Option<u16>,
+    pub second_channel_number: Option<u16>,
+    pub tool_width: Option<Millimeters>,
+}
+
+impl PickupGripperToolRequest {
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn y_position_1_st_channel(mut self, y_position_1_st_channel: Millimeters) -> Self {
+        self.y_position_1_st_channel = Some(y_position_1_st_channel);
+        self
+    }
+    pub fn y_position_2_nd_channel(mut self, y_position_2_nd_channel: Millimeters) -> Self {
+        self.y_position_2_nd_channel = Some(y_position_2_nd_channel);
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn z_start_position(mut self, z_start_position: Millimeters) -> Self {
+        self.z_start_position = Some(z_start_position);
+        self
+    }
+    pub fn z_stop_position(mut self, z_stop_position: Millimeters) -> Self {
+        self.z_stop_position = Some(z_stop_position);
+        self
+    }
+    pub fn tip_type(mut self, tip_type: u16) -> Self {
+        self.tip_type = Some(tip_type);
+        self
+    }
+    pub fn first_channel_number(mut self, first_channel_number: u16) -> Self {
+        self.first_channel_number = Some(first_channel_number);
+        self
+    }
+    pub fn second_channel_number(mut self, second_channel_number: u16) -> Self {
+        self.second_channel_number = Some(second_channel_number);
+        self
+    }
+    pub fn tool_width(mut self, tool_width: Millimeters) -> Self {
+        self.tool_width = Some(tool_width);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropGripperToolRequest {
+    pub x_position: Option<Millimeters>,
+    pub y_position_1_st_channel: Option<Millimeters>,
+    pub y_position_2_nd_channel: Option<Millimeters>,
+    pub traverse_height: Option<Millimeters>,
+    pub z_start_position: Option<Millimeters>,
+    pub z_stop_position: Option<Millimeters>,
+    pub z_final: Option<Millimeters>,
+    pub first_channel_number: Option<u16>,
+    pub second_channel_number: Option<u16>,
+}
+
+impl DropGripperToolRequest {
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn y_position_1_st_channel(mut self, y_position_1_st_channel: Millimeters) -> Self {
+        self.y_position_1_st_channel = Some(y_position_1_st_channel);
+        self
+    }
+    pub fn y_position_2_nd_channel(mut self, y_position_2_nd_channel: Millimeters) -> Self {
+        self.y_position_2_nd_channel = Some(y_position_2_nd_channel);
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn z_start_position(mut self, z_start_position: Millimeters) -> Self {
+        self.z_start_position = Some(z_start_position);
+        self
+    }
+    pub fn z_stop_position(mut self, z_stop_position: Millimeters) -> Self {
+        self.z_stop_position = Some(z_stop_position);
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn first_channel_number(mut self, first_channel_number: u16) -> Self {
+        self.first_channel_number = Some(first_channel_number);
+        self
+    }
+    pub fn second_channel_number(mut self, second_channel_number: u16) -> Self {
+        self.second_channel_number = Some(second_channel_number);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PickupPlateRequest {
+    pub x_position: Option<Millimeters>,
+    pub y_plate_center_position: Option<Millimeters>,
+    pub y_plate_width: Option<Millimeters>,
+    pub y_open_position: Option<Millimeters>,
+    pub y_grip_speed: Option<MmPerSecond>,
+    pub y_grip_strength: Option<u32>,
+    pub traverse_height: Option<Millimeters>,
+    pub z_grip_height: Option<Millimeters>,
+    pub z_final: Option<Millimeters>,
+    pub z_speed: Option<MmPerSecond>,
+}
+
+impl PickupPlateRequest {
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn y_plate_center_position(mut self, y_plate_center_position: Millimeters) -> Self {
+        self.y_plate_center_position = Some(y_plate_center_position);
+        self
+    }
+    pub fn y_plate_width(mut self, y_plate_width: Millimeters) -> Self {
+        self.y_plate_width = Some(y_plate_width);
+        self
+    }
+    pub fn y_open_position(mut self, y_open_position: Millimeters) -> Self {
+        self.y_open_position = Some(y_open_position);
+        self
+    }
+    pub fn y_grip_speed(mut self, y_grip_speed: MmPerSecond) -> Self {
+        self.y_grip_speed = Some(y_grip_speed);
+        self
+    }
+    pub fn y_grip_strength(mut self, y_grip_strength: u32) -> Self {
+        self.y_grip_strength = Some(y_grip_strength);
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn z_grip_height(mut self, z_grip_height: Millimeters) -> Self {
+        self.z_grip_height = Some(z_grip_height);
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn z_speed(mut self, z_speed: MmPerSecond) -> Self {
+        self.z_speed = Some(z_speed);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropPlateRequest {
+    pub x_position: Option<Millimeters>,
+    pub x_acceleration: Option<u32>,
+    pub y_plate_center_position: Option<Millimeters>,
+    pub y_open_position: Option<Millimeters>,
+    pub traverse_height: Option<Millimeters>,
+    pub z_drop_height: Option<Millimeters>,
+    pub z_press_distance: Option<Millimeters>,
+    pub z_final: Option<Millimeters>,
+    pub z_speed: Option<MmPerSecond>,
+}
+
+impl DropPlateRequest {
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn x_acceleration(mut self, x_acceleration: u32) -> Self {
+        self.x_acceleration = Some(x_acceleration);
+        self
+    }
+    pub fn y_plate_center_position(mut self, y_plate_center_position: Millimeters) -> Self {
+        self.y_plate_center_position = Some(y_plate_center_position);
+        self
+    }
+    pub fn y_open_position(mut self, y_open_position: Millimeters) -> Self {
+        self.y_open_position = Some(y_open_position);
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn z_drop_height(mut self, z_drop_height: Millimeters) -> Self {
+        self.z_drop_height = Some(z_drop_height);
+        self
+    }
+    pub fn z_press_distance(mut self, z_press_distance: Millimeters) -> Self {
+        self.z_press_distance = Some(z_press_distance);
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn z_speed(mut self, z_speed: MmPerSecond) -> Self {
+        self.z_speed = Some(z_speed);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IsCoreGripperToolHeldReply {
+    pub gripped: bool,
+    pub tip_type: Vec<u16>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetPositionReply {
+    pub x_position: Millimeters,
+    pub y_position: Vec<Millimeters>,
+    pub z_position: Vec<Millimeters>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateLimitCurveReply {
+    pub index: u32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RetrieveTadmDataReply {
+    pub offsets: Vec<u16>,
+    pub tadm_data: Vec<i16>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetLimitCurveNamesReply {
+    pub names: String,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetLimitCurveInfoReply {
+    pub index: u32,
+    pub lower_limits: u16,
+    pub upper_limits: u16,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetTADMEnableReply {
+    pub enable: Vec<i16>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PickupPlateSuctionReply {
+    pub first_pressure_change: i16,
+    pub second_pressure_change: i16,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PickupPlateSuctionRequest {
+    pub x_position: Option<Millimeters>,
+    pub y_plate_center_position: Option<Millimeters>,
+    pub y_suction_offset: Option<i32>,
+    pub blowout_volume: Option<Microliters>,
+    pub suction_volume: Option<Microliters>,
+    pub pressure_differential: Option<u16>,
+    pub traverse_height: Option<Millimeters>,
+    pub z_grip_height: Option<Millimeters>,
+    pub z_final: Option<Millimeters>,
+    pub z_speed: Option<MmPerSecond>,
+}
+
+impl PickupPlateSuctionRequest {
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn y_plate_center_position(mut self, y_plate_center_position: Millimeters) -> Self {
+        self.y_plate_center_position = Some(y_plate_center_position);
+        self
+    }
+    pub fn y_suction_offset(mut self, y_suction_offset: i32) -> Self {
+        self.y_suction_offset = Some(y_suction_offset);
+        self
+    }
+    pub fn blowout_volume(mut self, blowout_volume: Microliters) -> Self {
+        self.blowout_volume = Some(blowout_volume);
+        self
+    }
+    pub fn suction_volume(mut self, suction_volume: Microliters) -> Self {
+        self.suction_volume = Some(suction_volume);
+        self
+    }
+    pub fn pressure_differential(mut self, pressure_differential: u16) -> Self {
+        self.pressure_differential = Some(pressure_differential);
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn z_grip_height(mut self, z_grip_height: Millimeters) -> Self {
+        self.z_grip_height = Some(z_grip_height);
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn z_speed(mut self, z_speed: MmPerSecond) -> Self {
+        self.z_speed = Some(z_speed);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropPlateSuctionReply {
+    pub first_pressure_change: i16,
+    pub second_pressure_change: i16,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropPlateSuctionRequest {
+    pub x_position: Option<Millimeters>,
+    pub x_acceleration: Option<u32>,
+    pub y_plate_center_position: Option<Millimeters>,
+    pub pressure_differential: Option<u16>,
+    pub traverse_height: Option<Millimeters>,
+    pub z_drop_height: Option<Millimeters>,
+    pub z_press_distance: Option<Millimeters>,
+    pub z_lift_distance: Option<Millimeters>,
+    pub z_final: Option<Millimeters>,
+    pub z_speed: Option<MmPerSecond>,
+}
+
+impl DropPlateSuctionRequest {
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn x_acceleration(mut self, x_acceleration: u32) -> Self {
+        self.x_acceleration = Some(x_acceleration);
+        self
+    }
+    pub fn y_plate_center_position(mut self, y_plate_center_position: Millimeters) -> Self {
+        self.y_plate_center_position = Some(y_plate_center_position);
+        self
+    }
+    pub fn pressure_differential(mut self, pressure_differential: u16) -> Self {
+        self.pressure_differential = Some(pressure_differential);
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn z_drop_height(mut self, z_drop_height: Millimeters) -> Self {
+        self.z_drop_height = Some(z_drop_height);
+        self
+    }
+    pub fn z_press_distance(mut self, z_press_distance: Millimeters) -> Self {
+        self.z_press_distance = Some(z_press_distance);
+        self
+    }
+    pub fn z_lift_distance(mut self, z_lift_distance: Millimeters) -> Self {
+        self.z_lift_distance = Some(z_lift_distance);
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn z_speed(mut self, z_speed: MmPerSecond) -> Self {
+        self.z_speed = Some(z_speed);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetRemainingChannelsReply {
+    pub channels: Vec<u16>,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultipleDispenseRequest {
+    pub tips_used: Option<Vec<u16>>,
+    pub x_position: Option<Millimeters>,
+    pub x_well_distance: Option<Millimeters>,
+    pub x_well_width: Option<Millimeters>,
+    pub x_number_of_wells: Option<u16>,
+    pub x_velocity_scale: Option<u16>,
+    pub y_position: Option<Vec<Millimeters>>,
+    pub y_well_distance: Option<Vec<Millimeters>>,
+    pub volume: Option<Vec<Microliters>>,
+    pub tip_air_volume: Option<Vec<Microliters>>,
+    pub stop_back_volume: Option<Vec<Microliters>>,
+    pub dispense_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub cutoff_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub traverse_height: Option<Millimeters>,
+    pub dispense_height: Option<Vec<Millimeters>>,
+    pub z_final: Option<Millimeters>,
+    pub dispense_map: Option<Vec<u16>>,
+}
+
+impl MultipleDispenseRequest {
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn x_well_distance(mut self, x_well_distance: Millimeters) -> Self {
+        self.x_well_distance = Some(x_well_distance);
+        self
+    }
+    pub fn x_well_width(mut self, x_well_width: Millimeters) -> Self {
+        self.x_well_width = Some(x_well_width);
+        self
+    }
+    pub fn x_number_of_wells(mut self, x_number_of_wells: u16) -> Self {
+        self.x_number_of_wells = Some(x_number_of_wells);
+        self
+    }
+    pub fn x_velocity_scale(mut self, x_velocity_scale: u16) -> Self {
+        self.x_velocity_scale = Some(x_velocity_scale);
+        self
+    }
+    pub fn y_position(mut self, y_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_position = Some(y_position.into());
+        self
+    }
+    pub fn y_well_distance(mut self, y_well_distance: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_well_distance = Some(y_well_distance.into());
+        self
+    }
+    pub fn volume(mut self, volume: impl Into<Vec<Microliters>>) -> Self {
+        self.volume = Some(volume.into());
+        self
+    }
+    pub fn tip_air_volume(mut self, tip_air_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.tip_air_volume = Some(tip_air_volume.into());
+        self
+    }
+    pub fn stop_back_volume(mut self, stop_back_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.stop_back_volume = Some(stop_back_volume.into());
+        self
+    }
+    pub fn dispense_speed(mut self, dispense_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.dispense_speed = Some(dispense_speed.into());
+        self
+    }
+    pub fn cutoff_speed(mut self, cutoff_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.cutoff_speed = Some(cutoff_speed.into());
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn dispense_height(mut self, dispense_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.dispense_height = Some(dispense_height.into());
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn dispense_map(mut self, dispense_map: impl Into<Vec<u16>>) -> Self {
+        self.dispense_map = Some(dispense_map.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OnTheFlyDispenseRequest {
+    pub tips_used: Option<Vec<u16>>,
+    pub x_position: Option<Millimeters>,
+    pub x_well_distance: Option<Millimeters>,
+    pub x_well_width: Option<Millimeters>,
+    pub x_number_of_wells: Option<u16>,
+    pub x_velocity_scale: Option<u16>,
+    pub y_position: Option<Vec<Millimeters>>,
+    pub y_well_distance: Option<Vec<Millimeters>>,
+    pub volume: Option<Vec<Microliters>>,
+    pub tip_air_volume: Option<Vec<Microliters>>,
+    pub stop_back_volume: Option<Vec<Microliters>>,
+    pub dispense_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub cutoff_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub tadm_enabled: Option<Vec<i16>>,
+    pub limit_curve_index: Option<Vec<u32>>,
+    pub recording_mode: Option<u16>,
+    pub traverse_height: Option<Millimeters>,
+    pub dispense_height: Option<Vec<Millimeters>>,
+    pub z_final: Option<Millimeters>,
+    pub dispense_map: Option<Vec<u16>>,
+}
+
+impl OnTheFlyDispenseRequest {
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn x_well_distance(mut self, x_well_distance: Millimeters) -> Self {
+        self.x_well_distance = Some(x_well_distance);
+        self
+    }
+    pub fn x_well_width(mut self, x_well_width: Millimeters) -> Self {
+        self.x_well_width = Some(x_well_width);
+        self
+    }
+    pub fn x_number_of_wells(mut self, x_number_of_wells: u16) -> Self {
+        self.x_number_of_wells = Some(x_number_of_wells);
+        self
+    }
+    pub fn x_velocity_scale(mut self, x_velocity_scale: u16) -> Self {
+        self.x_velocity_scale = Some(x_velocity_scale);
+        self
+    }
+    pub fn y_position(mut self, y_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_position = Some(y_position.into());
+        self
+    }
+    pub fn y_well_distance(mut self, y_well_distance: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_well_distance = Some(y_well_distance.into());
+        self
+    }
+    pub fn volume(mut self, volume: impl Into<Vec<Microliters>>) -> Self {
+        self.volume = Some(volume.into());
+        self
+    }
+    pub fn tip_air_volume(mut self, tip_air_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.tip_air_volume = Some(tip_air_volume.into());
+        self
+    }
+    pub fn stop_back_volume(mut self, stop_back_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.stop_back_volume = Some(stop_back_volume.into());
+        self
+    }
+    pub fn dispense_speed(mut self, dispense_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.dispense_speed = Some(dispense_speed.into());
+        self
+    }
+    pub fn cutoff_speed(mut self, cutoff_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.cutoff_speed = Some(cutoff_speed.into());
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: impl Into<Vec<i16>>) -> Self {
+        self.tadm_enabled = Some(tadm_enabled.into());
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: impl Into<Vec<u32>>) -> Self {
+        self.limit_curve_index = Some(limit_curve_index.into());
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn dispense_height(mut self, dispense_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.dispense_height = Some(dispense_height.into());
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn dispense_map(mut self, dispense_map: impl Into<Vec<u16>>) -> Self {
+        self.dispense_map = Some(dispense_map.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetChannelConfigurationReply {
+    pub enabled: Vec<bool>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZSeekObstaclePositionReply {
+    pub z_position: Vec<Millimeters>,
+    pub obstacle_detected: Vec<i16>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZSeekLldPositionReply {
+    pub z_position: Vec<Millimeters>,
+    pub lld_detected: Vec<i16>,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZSeekLldPositionRequest {
+    pub tips_used: Option<Vec<u16>>,
+    pub x_position: Option<Vec<Millimeters>>,
+    pub y_position: Option<Vec<Millimeters>>,
+    pub traverse_height: Option<Millimeters>,
+    pub seek_height: Option<Vec<Millimeters>>,
+    pub z_min_position: Option<Vec<Millimeters>>,
+    pub z_final: Option<Millimeters>,
+    pub seek_speed: Option<Vec<MmPerSecond>>,
+    pub capacitive_lld_sensitivity: Option<Vec<i16>>,
+}
+
+impl ZSeekLldPositionRequest {
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn x_position(mut self, x_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.x_position = Some(x_position.into());
+        self
+    }
+    pub fn y_position(mut self, y_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_position = Some(y_position.into());
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn seek_height(mut self, seek_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.seek_height = Some(seek_height.into());
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.z_min_position = Some(z_min_position.into());
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn seek_speed(mut self, seek_speed: impl Into<Vec<MmPerSecond>>) -> Self {
+        self.seek_speed = Some(seek_speed.into());
+        self
+    }
+    pub fn capacitive_lld_sensitivity(
+        mut self,
+        capacitive_lld_sensitivity: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.capacitive_lld_sensitivity = Some(capacitive_lld_sensitivity.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultipleDispenseTransportAirRequest {
+    pub tips_used: Option<Vec<u16>>,
+    pub x_position: Option<Millimeters>,
+    pub x_well_distance: Option<Millimeters>,
+    pub x_well_width: Option<Millimeters>,
+    pub x_number_of_wells: Option<u16>,
+    pub x_velocity_scale: Option<u16>,
+    pub y_position: Option<Vec<Millimeters>>,
+    pub y_well_distance: Option<Vec<Millimeters>>,
+    pub volume: Option<Vec<Microliters>>,
+    pub tip_air_volume: Option<Vec<Microliters>>,
+    pub stop_back_volume: Option<Vec<Microliters>>,
+    pub dispense_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub cutoff_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub traverse_height: Option<Millimeters>,
+    pub dispense_height: Option<Vec<Millimeters>>,
+    pub z_final: Option<Millimeters>,
+    pub dispense_map: Option<Vec<u16>>,
+    pub transport_air_volume: Option<Vec<Microliters>>,
+}
+
+impl MultipleDispenseTransportAirRequest {
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn x_well_distance(mut self, x_well_distance: Millimeters) -> Self {
+        self.x_well_distance = Some(x_well_distance);
+        self
+    }
+    pub fn x_well_width(mut self, x_well_width: Millimeters) -> Self {
+        self.x_well_width = Some(x_well_width);
+        self
+    }
+    pub fn x_number_of_wells(mut self, x_number_of_wells: u16) -> Self {
+        self.x_number_of_wells = Some(x_number_of_wells);
+        self
+    }
+    pub fn x_velocity_scale(mut self, x_velocity_scale: u16) -> Self {
+        self.x_velocity_scale = Some(x_velocity_scale);
+        self
+    }
+    pub fn y_position(mut self, y_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_position = Some(y_position.into());
+        self
+    }
+    pub fn y_well_distance(mut self, y_well_distance: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_well_distance = Some(y_well_distance.into());
+        self
+    }
+    pub fn volume(mut self, volume: impl Into<Vec<Microliters>>) -> Self {
+        self.volume = Some(volume.into());
+        self
+    }
+    pub fn tip_air_volume(mut self, tip_air_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.tip_air_volume = Some(tip_air_volume.into());
+        self
+    }
+    pub fn stop_back_volume(mut self, stop_back_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.stop_back_volume = Some(stop_back_volume.into());
+        self
+    }
+    pub fn dispense_speed(mut self, dispense_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.dispense_speed = Some(dispense_speed.into());
+        self
+    }
+    pub fn cutoff_speed(mut self, cutoff_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.cutoff_speed = Some(cutoff_speed.into());
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn dispense_height(mut self, dispense_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.dispense_height = Some(dispense_height.into());
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn dispense_map(mut self, dispense_map: impl Into<Vec<u16>>) -> Self {
+        self.dispense_map = Some(dispense_map.into());
+        self
+    }
+    pub fn transport_air_volume(
+        mut self,
+        transport_air_volume: impl Into<Vec<Microliters>>,
+    ) -> Self {
+        self.transport_air_volume = Some(transport_air_volume.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OnTheFlyDispenseTransportAirRequest {
+    pub tips_used: Option<Vec<u16>>,
+    pub x_position: Option<Millimeters>,
+    pub x_well_distance: Option<Millimeters>,
+    pub x_well_width: Option<Millimeters>,
+    pub x_number_of_wells: Option<u16>,
+    pub x_velocity_scale: Option<u16>,
+    pub y_position: Option<Vec<Millimeters>>,
+    pub y_well_distance: Option<Vec<Millimeters>>,
+    pub volume: Option<Vec<Microliters>>,
+    pub tip_air_volume: Option<Vec<Microliters>>,
+    pub stop_back_volume: Option<Vec<Microliters>>,
+    pub dispense_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub cutoff_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub tadm_enabled: Option<Vec<i16>>,
+    pub limit_curve_index: Option<Vec<u32>>,
+    pub recording_mode: Option<u16>,
+    pub traverse_height: Option<Millimeters>,
+    pub dispense_height: Option<Vec<Millimeters>>,
+    pub z_final: Option<Millimeters>,
+    pub dispense_map: Option<Vec<u16>>,
+    pub transport_air_volume: Option<Vec<Microliters>>,
+}
+
+impl OnTheFlyDispenseTransportAirRequest {
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn x_well_distance(mut self, x_well_distance: Millimeters) -> Self {
+        self.x_well_distance = Some(x_well_distance);
+        self
+    }
+    pub fn x_well_width(mut self, x_well_width: Millimeters) -> Self {
+        self.x_well_width = Some(x_well_width);
+        self
+    }
+    pub fn x_number_of_wells(mut self, x_number_of_wells: u16) -> Self {
+        self.x_number_of_wells = Some(x_number_of_wells);
+        self
+    }
+    pub fn x_velocity_scale(mut self, x_velocity_scale: u16) -> Self {
+        self.x_velocity_scale = Some(x_velocity_scale);
+        self
+    }
+    pub fn y_position(mut self, y_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_position = Some(y_position.into());
+        self
+    }
+    pub fn y_well_distance(mut self, y_well_distance: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_well_distance = Some(y_well_distance.into());
+        self
+    }
+    pub fn volume(mut self, volume: impl Into<Vec<Microliters>>) -> Self {
+        self.volume = Some(volume.into());
+        self
+    }
+    pub fn tip_air_volume(mut self, tip_air_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.tip_air_volume = Some(tip_air_volume.into());
+        self
+    }
+    pub fn stop_back_volume(mut self, stop_back_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.stop_back_volume = Some(stop_back_volume.into());
+        self
+    }
+    pub fn dispense_speed(mut self, dispense_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.dispense_speed = Some(dispense_speed.into());
+        self
+    }
+    pub fn cutoff_speed(mut self, cutoff_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.cutoff_speed = Some(cutoff_speed.into());
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: impl Into<Vec<i16>>) -> Self {
+        self.tadm_enabled = Some(tadm_enabled.into());
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: impl Into<Vec<u32>>) -> Self {
+        self.limit_curve_index = Some(limit_curve_index.into());
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn dispense_height(mut self, dispense_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.dispense_height = Some(dispense_height.into());
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn dispense_map(mut self, dispense_map: impl Into<Vec<u16>>) -> Self {
+        self.dispense_map = Some(dispense_map.into());
+        self
+    }
+    pub fn transport_air_volume(
+        mut self,
+        transport_air_volume: impl Into<Vec<Microliters>>,
+    ) -> Self {
+        self.transport_air_volume = Some(transport_air_volume.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PickupPlateSuction2Request {
+    pub x_position: Option<Millimeters>,
+    pub y_plate_center_position: Option<Millimeters>,
+    pub y_suction_offset: Option<i32>,
+    pub blowout_volume: Option<Microliters>,
+    pub suction_volume: Option<Microliters>,
+    pub traverse_height: Option<Millimeters>,
+    pub z_grip_height: Option<Millimeters>,
+    pub z_final: Option<Millimeters>,
+    pub z_speed: Option<MmPerSecond>,
+}
+
+impl PickupPlateSuction2Request {
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn y_plate_center_position(mut self, y_plate_center_position: Millimeters) -> Self {
+        self.y_plate_center_position = Some(y_plate_center_position);
+        self
+    }
+    pub fn y_suction_offset(mut self, y_suction_offset: i32) -> Self {
+        self.y_suction_offset = Some(y_suction_offset);
+        self
+    }
+    pub fn blowout_volume(mut self, blowout_volume: Microliters) -> Self {
+        self.blowout_volume = Some(blowout_volume);
+        self
+    }
+    pub fn suction_volume(mut self, suction_volume: Microliters) -> Self {
+        self.suction_volume = Some(suction_volume);
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn z_grip_height(mut self, z_grip_height: Millimeters) -> Self {
+        self.z_grip_height = Some(z_grip_height);
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn z_speed(mut self, z_speed: MmPerSecond) -> Self {
+        self.z_speed = Some(z_speed);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DropPlateSuction2Request {
+    pub x_position: Option<Millimeters>,
+    pub x_acceleration: Option<u32>,
+    pub y_plate_center_position: Option<Millimeters>,
+    pub traverse_height: Option<Millimeters>,
+    pub z_drop_height: Option<Millimeters>,
+    pub z_press_distance: Option<Millimeters>,
+    pub z_lift_distance: Option<Millimeters>,
+    pub z_final: Option<Millimeters>,
+    pub z_speed: Option<MmPerSecond>,
+}
+
+impl DropPlateSuction2Request {
+    pub fn x_position(mut self, x_position: Millimeters) -> Self {
+        self.x_position = Some(x_position);
+        self
+    }
+    pub fn x_acceleration(mut self, x_acceleration: u32) -> Self {
+        self.x_acceleration = Some(x_acceleration);
+        self
+    }
+    pub fn y_plate_center_position(mut self, y_plate_center_position: Millimeters) -> Self {
+        self.y_plate_center_position = Some(y_plate_center_position);
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn z_drop_height(mut self, z_drop_height: Millimeters) -> Self {
+        self.z_drop_height = Some(z_drop_height);
+        self
+    }
+    pub fn z_press_distance(mut self, z_press_distance: Millimeters) -> Self {
+        self.z_press_distance = Some(z_press_distance);
+        self
+    }
+    pub fn z_lift_distance(mut self, z_lift_distance: Millimeters) -> Self {
+        self.z_lift_distance = Some(z_lift_distance);
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn z_speed(mut self, z_speed: MmPerSecond) -> Self {
+        self.z_speed = Some(z_speed);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheckPlateSuctionReply {
+    pub first_channel_has_plate: i16,
+    pub second_channel_has_plate: i16,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aspirate2Request {
+    pub aspirate_type: Option<Vec<i16>>,
+    pub tips_used: Option<Vec<u16>>,
+    pub x_position: Option<Vec<Millimeters>>,
+    pub y_position: Option<Vec<Millimeters>>,
+    pub traverse_height: Option<Millimeters>,
+    pub liquid_seek_height: Option<Vec<Millimeters>>,
+    pub liquid_surface_height: Option<Vec<Millimeters>>,
+    pub submerge_depth: Option<Vec<Millimeters>>,
+    pub follow_depth: Option<Vec<Millimeters>>,
+    pub z_min_position: Option<Vec<Millimeters>>,
+    pub clot_check_height: Option<Vec<Millimeters>>,
+    pub z_final: Option<Millimeters>,
+    pub liquid_exit_speed: Option<Vec<MmPerSecond>>,
+    pub blowout_volume: Option<Vec<Microliters>>,
+    pub prewet_volume: Option<Vec<Microliters>>,
+    pub aspirate_volume: Option<Vec<Microliters>>,
+    pub transport_air_volume: Option<Vec<Microliters>>,
+    pub aspirate_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub settling_time: Option<Vec<u32>>,
+    pub mix_volume: Option<Vec<Microliters>>,
+    pub mix_cycles: Option<Vec<u32>>,
+    pub mix_position: Option<Vec<Millimeters>>,
+    pub mix_follow_distance: Option<Vec<Millimeters>>,
+    pub mix_speed: Option<Vec<MicrolitersPerSecond>>,
+    pub aspirate_offset: Option<Vec<i32>>,
+    pub tube_section_height: Option<Vec<Millimeters>>,
+    pub tube_section_ratio: Option<Vec<i32>>,
+    pub lld_mode: Option<Vec<i16>>,
+    pub capacitive_lld_sensitivity: Option<Vec<i16>>,
+    pub pressure_lld_sensitivity: Option<Vec<i16>>,
+    pub lld_height_difference: Option<Vec<i32>>,
+    pub tadm_enabled: Option<bool>,
+    pub limit_curve_index: Option<Vec<u32>>,
+    pub recording_mode: Option<u16>,
+}
+
+impl Aspirate2Request {
+    pub fn aspirate_type(mut self, aspirate_type: impl Into<Vec<i16>>) -> Self {
+        self.aspirate_type = Some(aspirate_type.into());
+        self
+    }
+    pub fn tips_used(mut self, tips_used: impl Into<Vec<u16>>) -> Self {
+        self.tips_used = Some(tips_used.into());
+        self
+    }
+    pub fn x_position(mut self, x_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.x_position = Some(x_position.into());
+        self
+    }
+    pub fn y_position(mut self, y_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.y_position = Some(y_position.into());
+        self
+    }
+    pub fn traverse_height(mut self, traverse_height: Millimeters) -> Self {
+        self.traverse_height = Some(traverse_height);
+        self
+    }
+    pub fn liquid_seek_height(mut self, liquid_seek_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.liquid_seek_height = Some(liquid_seek_height.into());
+        self
+    }
+    pub fn liquid_surface_height(
+        mut self,
+        liquid_surface_height: impl Into<Vec<Millimeters>>,
+    ) -> Self {
+        self.liquid_surface_height = Some(liquid_surface_height.into());
+        self
+    }
+    pub fn submerge_depth(mut self, submerge_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.submerge_depth = Some(submerge_depth.into());
+        self
+    }
+    pub fn follow_depth(mut self, follow_depth: impl Into<Vec<Millimeters>>) -> Self {
+        self.follow_depth = Some(follow_depth.into());
+        self
+    }
+    pub fn z_min_position(mut self, z_min_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.z_min_position = Some(z_min_position.into());
+        self
+    }
+    pub fn clot_check_height(mut self, clot_check_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.clot_check_height = Some(clot_check_height.into());
+        self
+    }
+    pub fn z_final(mut self, z_final: Millimeters) -> Self {
+        self.z_final = Some(z_final);
+        self
+    }
+    pub fn liquid_exit_speed(mut self, liquid_exit_speed: impl Into<Vec<MmPerSecond>>) -> Self {
+        self.liquid_exit_speed = Some(liquid_exit_speed.into());
+        self
+    }
+    pub fn blowout_volume(mut self, blowout_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.blowout_volume = Some(blowout_volume.into());
+        self
+    }
+    pub fn prewet_volume(mut self, prewet_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.prewet_volume = Some(prewet_volume.into());
+        self
+    }
+    pub fn aspirate_volume(mut self, aspirate_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.aspirate_volume = Some(aspirate_volume.into());
+        self
+    }
+    pub fn transport_air_volume(
+        mut self,
+        transport_air_volume: impl Into<Vec<Microliters>>,
+    ) -> Self {
+        self.transport_air_volume = Some(transport_air_volume.into());
+        self
+    }
+    pub fn aspirate_speed(mut self, aspirate_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.aspirate_speed = Some(aspirate_speed.into());
+        self
+    }
+    pub fn settling_time(mut self, settling_time: impl Into<Vec<u32>>) -> Self {
+        self.settling_time = Some(settling_time.into());
+        self
+    }
+    pub fn mix_volume(mut self, mix_volume: impl Into<Vec<Microliters>>) -> Self {
+        self.mix_volume = Some(mix_volume.into());
+        self
+    }
+    pub fn mix_cycles(mut self, mix_cycles: impl Into<Vec<u32>>) -> Self {
+        self.mix_cycles = Some(mix_cycles.into());
+        self
+    }
+    pub fn mix_position(mut self, mix_position: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_position = Some(mix_position.into());
+        self
+    }
+    pub fn mix_follow_distance(mut self, mix_follow_distance: impl Into<Vec<Millimeters>>) -> Self {
+        self.mix_follow_distance = Some(mix_follow_distance.into());
+        self
+    }
+    pub fn mix_speed(mut self, mix_speed: impl Into<Vec<MicrolitersPerSecond>>) -> Self {
+        self.mix_speed = Some(mix_speed.into());
+        self
+    }
+    pub fn aspirate_offset(mut self, aspirate_offset: impl Into<Vec<i32>>) -> Self {
+        self.aspirate_offset = Some(aspirate_offset.into());
+        self
+    }
+    pub fn tube_section_height(mut self, tube_section_height: impl Into<Vec<Millimeters>>) -> Self {
+        self.tube_section_height = Some(tube_section_height.into());
+        self
+    }
+    pub fn tube_section_ratio(mut self, tube_section_ratio: impl Into<Vec<i32>>) -> Self {
+        self.tube_section_ratio = Some(tube_section_ratio.into());
+        self
+    }
+    pub fn lld_mode(mut self, lld_mode: impl Into<Vec<i16>>) -> Self {
+        self.lld_mode = Some(lld_mode.into());
+        self
+    }
+    pub fn capacitive_lld_sensitivity(
+        mut self,
+        capacitive_lld_sensitivity: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.capacitive_lld_sensitivity = Some(capacitive_lld_sensitivity.into());
+        self
+    }
+    pub fn pressure_lld_sensitivity(
+        mut self,
+        pressure_lld_sensitivity: impl Into<Vec<i16>>,
+    ) -> Self {
+        self.pressure_lld_sensitivity = Some(pressure_lld_sensitivity.into());
+        self
+    }
+    pub fn lld_height_difference(mut self, lld_height_difference: impl Into<Vec<i32>>) -> Self {
+        self.lld_height_difference = Some(lld_height_difference.into());
+        self
+    }
+    pub fn tadm_enabled(mut self, tadm_enabled: bool) -> Self {
+        self.tadm_enabled = Some(tadm_enabled);
+        self
+    }
+    pub fn limit_curve_index(mut self, limit_curve_index: impl Into<Vec<u32>>) -> Self {
+        self.limit_curve_index = Some(limit_curve_index.into());
+        self
+    }
+    pub fn recording_mode(mut self, recording_mode: u16) -> Self {
+        self.recording_mode = Some(recording_mode);
+        self
+    }
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -3544,8 +14004,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -3555,23 +14015,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -3579,8 +14039,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
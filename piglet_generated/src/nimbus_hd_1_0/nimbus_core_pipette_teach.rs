@@ -1,14 +1,14 @@
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
+use piglet_client::units::Millimeters;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +16,13 @@ use std::sync::Arc;
 pub struct NimbusCorePipetteTeach {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCorePipetteTeach {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Teach";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,14 +32,36 @@ impl NimbusCorePipetteTeach {
                 object_id: 270,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
         }
     }
 
-    pub async fn x_move_relative(&self, distance: i32) -> Result<(), Error> {
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Pipette.Teach").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
+        }
+    }
+
+    pub async fn x_move_relative(&self, distance: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        distance.serialize(&mut args);
+        distance.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 1, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  distance: {:?}", distance)];
                 format!(
@@ -47,7 +72,7 @@ impl NimbusCorePipetteTeach {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -56,13 +81,20 @@ impl NimbusCorePipetteTeach {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        distance: impl AsRef<[i32]>,
+        distance: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        distance.as_ref().serialize(&mut args);
+        distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -76,7 +108,7 @@ impl NimbusCorePipetteTeach {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -85,13 +117,20 @@ impl NimbusCorePipetteTeach {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        distance: impl AsRef<[i32]>,
+        distance: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        distance.as_ref().serialize(&mut args);
+        distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -105,7 +144,7 @@ impl NimbusCorePipetteTeach {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -114,7 +153,9 @@ impl NimbusCorePipetteTeach {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -125,7 +166,7 @@ impl NimbusCorePipetteTeach {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -134,7 +175,9 @@ impl NimbusCorePipetteTeach {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -145,16 +188,18 @@ impl NimbusCorePipetteTeach {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn x_move_absolute(&self, position: i32) -> Result<(), Error> {
+    pub async fn x_move_absolute(&self, position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        position.serialize(&mut args);
+        position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -165,7 +210,7 @@ impl NimbusCorePipetteTeach {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -173,12 +218,14 @@ impl NimbusCorePipetteTeach {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipetteTeach.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -196,7 +243,9 @@ impl NimbusCorePipetteTeach {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -207,7 +256,7 @@ impl NimbusCorePipetteTeach {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -229,7 +278,9 @@ impl NimbusCorePipetteTeach {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -240,7 +291,7 @@ impl NimbusCorePipetteTeach {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -255,12 +306,14 @@ impl NimbusCorePipetteTeach {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCorePipetteTeach.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -274,7 +327,9 @@ impl NimbusCorePipetteTeach {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -285,7 +340,7 @@ impl NimbusCorePipetteTeach {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -303,7 +358,9 @@ impl NimbusCorePipetteTeach {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -314,7 +371,7 @@ impl NimbusCorePipetteTeach {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -329,8 +386,282 @@ impl NimbusCorePipetteTeach {
     }
 }
 
-#[allow(non_camel_case_types)]
+// The methods above as a trait, so code using them can be tested against NimbusCorePipetteTeachFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCorePipetteTeachApi {
+    fn x_move_relative(
+        &self,
+        distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn y_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn squeeze_on(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn squeeze_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn x_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCorePipetteTeachApi for NimbusCorePipetteTeach {
+    fn x_move_relative(
+        &self,
+        distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipetteTeach::x_move_relative(self, distance)
+    }
+
+    fn y_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipetteTeach::y_move_relative(self, tips_used, distance)
+    }
+
+    fn z_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipetteTeach::z_move_relative(self, tips_used, distance)
+    }
+
+    fn squeeze_on(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipetteTeach::squeeze_on(self, tips_used)
+    }
+
+    fn squeeze_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipetteTeach::squeeze_off(self, tips_used)
+    }
+
+    fn x_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCorePipetteTeach::x_move_absolute(self, position)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCorePipetteTeach::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCorePipetteTeach::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCorePipetteTeach::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCorePipetteTeach::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCorePipetteTeach::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCorePipetteTeach::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCorePipetteTeachFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCorePipetteTeachApi for NimbusCorePipetteTeachFake {
+    fn x_move_relative(
+        &self,
+        distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "x_move_relative",
+            vec![("distance", format!("{:?}", distance))],
+        );
+        async move { reply }
+    }
+
+    fn y_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_move_relative",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("distance", format!("{:?}", distance.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn z_move_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_move_relative",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("distance", format!("{:?}", distance.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn squeeze_on(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "squeeze_on",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn squeeze_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "squeeze_off",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn x_move_absolute(
+        &self,
+        position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "x_move_absolute",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -338,8 +669,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -349,23 +680,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -373,8 +704,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
@@ -1,14 +1,14 @@
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
+use piglet_client::units::Millimeters;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +16,13 @@ use std::sync::Arc;
 pub struct NimbusCoreService {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreService {
-    // version
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "Service";
+    pub const VERSION: &'static str = "";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,18 +32,40 @@ impl NimbusCoreService {
                 object_id: 48880,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.Service").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn get_x_home_sensor(&self) -> Result</* x_home= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreService.GetXHomeSensor()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let x_home = bool::deserialize(&mut stream)?;
         Ok(x_home)
@@ -49,12 +74,14 @@ impl NimbusCoreService {
     pub async fn get_channel_home_sensors(&self) -> Result<GetChannelHomeSensorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreService.GetChannelHomeSensors()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let y_home = Vec::<bool>::deserialize(&mut stream)?;
         let z_home = Vec::<bool>::deserialize(&mut stream)?;
@@ -71,12 +98,14 @@ impl NimbusCoreService {
     pub async fn get_channel_tip_sensors(&self) -> Result</* tip_presence= */ Vec<i16>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 3, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreService.GetChannelTipSensors()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let tip_presence = Vec::<i16>::deserialize(&mut stream)?;
         Ok(tip_presence)
@@ -87,12 +116,14 @@ impl NimbusCoreService {
     ) -> Result<GetChannelCalibrationValuesReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreService.GetChannelCalibrationValues()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let squeeze = Vec::<i16>::deserialize(&mut stream)?;
         let pressure_lld = Vec::<i16>::deserialize(&mut stream)?;
@@ -111,12 +142,14 @@ impl NimbusCoreService {
     ) -> Result<GetChannelExtendedCalibrationValuesReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 5, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreService.GetChannelExtendedCalibrationValues()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let tip_type_adjustment = Vec::<i16>::deserialize(&mut stream)?;
         let pressure_conversion = Vec::<i16>::deserialize(&mut stream)?;
@@ -133,12 +166,14 @@ impl NimbusCoreService {
     pub async fn get_channel_counters(&self) -> Result<GetChannelCountersReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 6, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreService.GetChannelCounters()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let tip_pickup_counter = Vec::<u32>::deserialize(&mut stream)?;
         let tip_eject_counter = Vec::<u32>::deserialize(&mut stream)?;
@@ -160,7 +195,9 @@ impl NimbusCoreService {
         let mut args = BytesMut::new();
         channel_number.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  channel_number: {:?}", channel_number)];
                 format!(
@@ -171,7 +208,7 @@ impl NimbusCoreService {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let channel_info = String::deserialize(&mut stream)?;
         Ok(channel_info)
@@ -180,12 +217,14 @@ impl NimbusCoreService {
     pub async fn get_channel_types_1(&self) -> Result</* channel_types= */ Vec<i16>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 8, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreService.GetChannelTypes_1()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let channel_types = Vec::<i16>::deserialize(&mut stream)?;
         Ok(channel_types)
@@ -207,7 +246,9 @@ impl NimbusCoreService {
         aspirate_counter.as_ref().serialize(&mut args);
         dispense_counter.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 9, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -224,7 +265,7 @@ impl NimbusCoreService {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -233,7 +274,9 @@ impl NimbusCoreService {
         let mut args = BytesMut::new();
         enable.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 10, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  enable: {:?}", enable)];
                 format!(
@@ -244,7 +287,7 @@ impl NimbusCoreService {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -252,12 +295,14 @@ impl NimbusCoreService {
     pub async fn x_servo_status(&self) -> Result</* enabled= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 11, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreService.XServoStatus()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let enabled = bool::deserialize(&mut stream)?;
         Ok(enabled)
@@ -267,7 +312,9 @@ impl NimbusCoreService {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 12, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -278,7 +325,7 @@ impl NimbusCoreService {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -287,7 +334,9 @@ impl NimbusCoreService {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 13, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 13, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -298,7 +347,7 @@ impl NimbusCoreService {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -313,7 +362,9 @@ impl NimbusCoreService {
         channel.serialize(&mut args);
         indexes.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 14, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 14, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -327,7 +378,7 @@ impl NimbusCoreService {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let enabled = Vec::<bool>::deserialize(&mut stream)?;
         Ok(enabled)
@@ -343,7 +394,9 @@ impl NimbusCoreService {
         channel.serialize(&mut args);
         seconds.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 15, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 15, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  channel: {:?}", channel),
@@ -357,7 +410,7 @@ impl NimbusCoreService {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let detects_per_minute = u16::deserialize(&mut stream)?;
         Ok(detects_per_minute)
@@ -366,26 +419,49 @@ impl NimbusCoreService {
     pub async fn get_positions(&self) -> Result<GetPositionsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 16, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 16, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreService.GetPositions()".to_string(),
         )?;
 
         if count != 10 {
-            return Err(ConnectionError(anyhow!(
-                "Expected 10 values, not {}",
-                count
-            )));
+            return Err(ProtocolError(anyhow!("Expected 10 values, not {}", count)));
         }
-        let x_position = i32::deserialize(&mut stream)?;
-        let x_encoder_position = i32::deserialize(&mut stream)?;
-        let y_position = Vec::<i32>::deserialize(&mut stream)?;
-        let y_encoder_position = Vec::<i32>::deserialize(&mut stream)?;
-        let z_position = Vec::<i32>::deserialize(&mut stream)?;
-        let z_encoder_position = Vec::<i32>::deserialize(&mut stream)?;
-        let d_position = Vec::<i32>::deserialize(&mut stream)?;
-        let d_encoder_position = Vec::<i32>::deserialize(&mut stream)?;
-        let s_position = Vec::<i32>::deserialize(&mut stream)?;
-        let s_encoder_position = Vec::<i32>::deserialize(&mut stream)?;
+        let x_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let x_encoder_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let y_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let y_encoder_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let z_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let z_encoder_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let d_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let d_encoder_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let s_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
+        let s_encoder_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         Ok(GetPositionsReply {
             x_position,
             x_encoder_position,
@@ -403,26 +479,30 @@ impl NimbusCoreService {
     pub async fn get_channel_types_2(&self) -> Result</* value= */ Vec<ChannelType>, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 17, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 17, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreService.GetChannelTypes_2()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let value = MVec::<ChannelType>::deserialize(&mut stream)?.0;
+        let value = Vec::<ChannelType>::deserialize(&mut stream)?;
         Ok(value)
     }
 
     pub async fn get_board_information(&self) -> Result<GetBoardInformationReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 18, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 18, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreService.GetBoardInformation()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let board_version = u32::deserialize(&mut stream)?;
         let pld_version = u32::deserialize(&mut stream)?;
@@ -435,12 +515,14 @@ impl NimbusCoreService {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreService.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -458,7 +540,9 @@ impl NimbusCoreService {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -469,7 +553,7 @@ impl NimbusCoreService {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -491,7 +575,9 @@ impl NimbusCoreService {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -502,7 +588,7 @@ impl NimbusCoreService {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -517,12 +603,14 @@ impl NimbusCoreService {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreService.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -536,7 +624,9 @@ impl NimbusCoreService {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -547,7 +637,7 @@ impl NimbusCoreService {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -565,7 +655,9 @@ impl NimbusCoreService {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -576,7 +668,7 @@ impl NimbusCoreService {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -591,8 +683,493 @@ impl NimbusCoreService {
     }
 }
 
-#[allow(non_camel_case_types)]
+// The methods above as a trait, so code using them can be tested against NimbusCoreServiceFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreServiceApi {
+    fn get_x_home_sensor(&self) -> impl Future<Output = Result</* x_home= */ bool, Error>> + Send;
+    fn get_channel_home_sensors(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelHomeSensorsReply, Error>> + Send;
+    fn get_channel_tip_sensors(
+        &self,
+    ) -> impl Future<Output = Result</* tip_presence= */ Vec<i16>, Error>> + Send;
+    fn get_channel_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelCalibrationValuesReply, Error>> + Send;
+    fn get_channel_extended_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelExtendedCalibrationValuesReply, Error>> + Send;
+    fn get_channel_counters(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelCountersReply, Error>> + Send;
+    fn get_channel_technical_info(
+        &self,
+        channel_number: u16,
+    ) -> impl Future<Output = Result</* channel_info= */ String, Error>> + Send;
+    fn get_channel_types_1(
+        &self,
+    ) -> impl Future<Output = Result</* channel_types= */ Vec<i16>, Error>> + Send;
+    fn set_channel_counters(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        tip_pickup_counter: impl AsRef<[u32]> + Send,
+        tip_eject_counter: impl AsRef<[u32]> + Send,
+        aspirate_counter: impl AsRef<[u32]> + Send,
+        dispense_counter: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn x_servo_control(&self, enable: bool) -> impl Future<Output = Result<(), Error>> + Send;
+    fn x_servo_status(&self) -> impl Future<Output = Result</* enabled= */ bool, Error>> + Send;
+    fn y_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn z_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result</* enabled= */ Vec<bool>, Error>> + Send;
+    fn measure_lld_frequency(
+        &self,
+        channel: u16,
+        seconds: u16,
+    ) -> impl Future<Output = Result</* detects_per_minute= */ u16, Error>> + Send;
+    fn get_positions(&self) -> impl Future<Output = Result<GetPositionsReply, Error>> + Send;
+    fn get_channel_types_2(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ Vec<ChannelType>, Error>> + Send;
+    fn get_board_information(
+        &self,
+    ) -> impl Future<Output = Result<GetBoardInformationReply, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreServiceApi for NimbusCoreService {
+    fn get_x_home_sensor(&self) -> impl Future<Output = Result</* x_home= */ bool, Error>> + Send {
+        NimbusCoreService::get_x_home_sensor(self)
+    }
+
+    fn get_channel_home_sensors(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelHomeSensorsReply, Error>> + Send {
+        NimbusCoreService::get_channel_home_sensors(self)
+    }
+
+    fn get_channel_tip_sensors(
+        &self,
+    ) -> impl Future<Output = Result</* tip_presence= */ Vec<i16>, Error>> + Send {
+        NimbusCoreService::get_channel_tip_sensors(self)
+    }
+
+    fn get_channel_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelCalibrationValuesReply, Error>> + Send {
+        NimbusCoreService::get_channel_calibration_values(self)
+    }
+
+    fn get_channel_extended_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelExtendedCalibrationValuesReply, Error>> + Send {
+        NimbusCoreService::get_channel_extended_calibration_values(self)
+    }
+
+    fn get_channel_counters(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelCountersReply, Error>> + Send {
+        NimbusCoreService::get_channel_counters(self)
+    }
+
+    fn get_channel_technical_info(
+        &self,
+        channel_number: u16,
+    ) -> impl Future<Output = Result</* channel_info= */ String, Error>> + Send {
+        NimbusCoreService::get_channel_technical_info(self, channel_number)
+    }
+
+    fn get_channel_types_1(
+        &self,
+    ) -> impl Future<Output = Result</* channel_types= */ Vec<i16>, Error>> + Send {
+        NimbusCoreService::get_channel_types_1(self)
+    }
+
+    fn set_channel_counters(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        tip_pickup_counter: impl AsRef<[u32]> + Send,
+        tip_eject_counter: impl AsRef<[u32]> + Send,
+        aspirate_counter: impl AsRef<[u32]> + Send,
+        dispense_counter: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreService::set_channel_counters(
+            self,
+            tips_used,
+            tip_pickup_counter,
+            tip_eject_counter,
+            aspirate_counter,
+            dispense_counter,
+        )
+    }
+
+    fn x_servo_control(&self, enable: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreService::x_servo_control(self, enable)
+    }
+
+    fn x_servo_status(&self) -> impl Future<Output = Result</* enabled= */ bool, Error>> + Send {
+        NimbusCoreService::x_servo_status(self)
+    }
+
+    fn y_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreService::y_servo_off(self, tips_used)
+    }
+
+    fn z_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreService::z_servo_off(self, tips_used)
+    }
+
+    fn get_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result</* enabled= */ Vec<bool>, Error>> + Send {
+        NimbusCoreService::get_channel_configuration(self, channel, indexes)
+    }
+
+    fn measure_lld_frequency(
+        &self,
+        channel: u16,
+        seconds: u16,
+    ) -> impl Future<Output = Result</* detects_per_minute= */ u16, Error>> + Send {
+        NimbusCoreService::measure_lld_frequency(self, channel, seconds)
+    }
+
+    fn get_positions(&self) -> impl Future<Output = Result<GetPositionsReply, Error>> + Send {
+        NimbusCoreService::get_positions(self)
+    }
+
+    fn get_channel_types_2(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ Vec<ChannelType>, Error>> + Send {
+        NimbusCoreService::get_channel_types_2(self)
+    }
+
+    fn get_board_information(
+        &self,
+    ) -> impl Future<Output = Result<GetBoardInformationReply, Error>> + Send {
+        NimbusCoreService::get_board_information(self)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreService::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreService::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreService::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreService::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreService::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreService::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreServiceFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreServiceApi for NimbusCoreServiceFake {
+    fn get_x_home_sensor(&self) -> impl Future<Output = Result</* x_home= */ bool, Error>> + Send {
+        let reply = self.recorder.record("get_x_home_sensor", vec![]);
+        async move { reply }
+    }
+
+    fn get_channel_home_sensors(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelHomeSensorsReply, Error>> + Send {
+        let reply = self.recorder.record("get_channel_home_sensors", vec![]);
+        async move { reply }
+    }
+
+    fn get_channel_tip_sensors(
+        &self,
+    ) -> impl Future<Output = Result</* tip_presence= */ Vec<i16>, Error>> + Send {
+        let reply = self.recorder.record("get_channel_tip_sensors", vec![]);
+        async move { reply }
+    }
+
+    fn get_channel_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelCalibrationValuesReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("get_channel_calibration_values", vec![]);
+        async move { reply }
+    }
+
+    fn get_channel_extended_calibration_values(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelExtendedCalibrationValuesReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("get_channel_extended_calibration_values", vec![]);
+        async move { reply }
+    }
+
+    fn get_channel_counters(
+        &self,
+    ) -> impl Future<Output = Result<GetChannelCountersReply, Error>> + Send {
+        let reply = self.recorder.record("get_channel_counters", vec![]);
+        async move { reply }
+    }
+
+    fn get_channel_technical_info(
+        &self,
+        channel_number: u16,
+    ) -> impl Future<Output = Result</* channel_info= */ String, Error>> + Send {
+        let reply = self.recorder.record(
+            "get_channel_technical_info",
+            vec![("channel_number", format!("{:?}", channel_number))],
+        );
+        async move { reply }
+    }
+
+    fn get_channel_types_1(
+        &self,
+    ) -> impl Future<Output = Result</* channel_types= */ Vec<i16>, Error>> + Send {
+        let reply = self.recorder.record("get_channel_types_1", vec![]);
+        async move { reply }
+    }
+
+    fn set_channel_counters(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        tip_pickup_counter: impl AsRef<[u32]> + Send,
+        tip_eject_counter: impl AsRef<[u32]> + Send,
+        aspirate_counter: impl AsRef<[u32]> + Send,
+        dispense_counter: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_channel_counters",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "tip_pickup_counter",
+                    format!("{:?}", tip_pickup_counter.as_ref()),
+                ),
+                (
+                    "tip_eject_counter",
+                    format!("{:?}", tip_eject_counter.as_ref()),
+                ),
+                (
+                    "aspirate_counter",
+                    format!("{:?}", aspirate_counter.as_ref()),
+                ),
+                (
+                    "dispense_counter",
+                    format!("{:?}", dispense_counter.as_ref()),
+                ),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn x_servo_control(&self, enable: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("x_servo_control", vec![("enable", format!("{:?}", enable))]);
+        async move { reply }
+    }
+
+    fn x_servo_status(&self) -> impl Future<Output = Result</* enabled= */ bool, Error>> + Send {
+        let reply = self.recorder.record("x_servo_status", vec![]);
+        async move { reply }
+    }
+
+    fn y_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "y_servo_off",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn z_servo_off(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "z_servo_off",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn get_channel_configuration(
+        &self,
+        channel: u16,
+        indexes: impl AsRef<[i16]> + Send,
+    ) -> impl Future<Output = Result</* enabled= */ Vec<bool>, Error>> + Send {
+        let reply = self.recorder.record(
+            "get_channel_configuration",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("indexes", format!("{:?}", indexes.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn measure_lld_frequency(
+        &self,
+        channel: u16,
+        seconds: u16,
+    ) -> impl Future<Output = Result</* detects_per_minute= */ u16, Error>> + Send {
+        let reply = self.recorder.record(
+            "measure_lld_frequency",
+            vec![
+                ("channel", format!("{:?}", channel)),
+                ("seconds", format!("{:?}", seconds)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_positions(&self) -> impl Future<Output = Result<GetPositionsReply, Error>> + Send {
+        let reply = self.recorder.record("get_positions", vec![]);
+        async move { reply }
+    }
+
+    fn get_channel_types_2(
+        &self,
+    ) -> impl Future<Output = Result</* value= */ Vec<ChannelType>, Error>> + Send {
+        let reply = self.recorder.record("get_channel_types_2", vec![]);
+        async move { reply }
+    }
+
+    fn get_board_information(
+        &self,
+    ) -> impl Future<Output = Result<GetBoardInformationReply, Error>> + Send {
+        let reply = self.recorder.record("get_board_information", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetChannelHomeSensorsReply {
     pub y_home: Vec<bool>,
     pub z_home: Vec<bool>,
@@ -600,8 +1177,8 @@ pub struct GetChannelHomeSensorsReply {
     pub dispense_home: Vec<bool>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetChannelCalibrationValuesReply {
     pub squeeze: Vec<i16>,
     pub pressure_lld: Vec<i16>,
@@ -609,8 +1186,8 @@ pub struct GetChannelCalibrationValuesReply {
     pub dispense_calibration: Vec<i16>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetChannelExtendedCalibrationValuesReply {
     pub tip_type_adjustment: Vec<i16>,
     pub pressure_conversion: Vec<i16>,
@@ -618,8 +1195,8 @@ pub struct GetChannelExtendedCalibrationValuesReply {
     pub z_bottom_search: Vec<i16>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetChannelCountersReply {
     pub tip_pickup_counter: Vec<u32>,
     pub tip_eject_counter: Vec<u32>,
@@ -627,30 +1204,30 @@ pub struct GetChannelCountersReply {
     pub dispense_counter: Vec<u32>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetPositionsReply {
-    pub x_position: i32,
-    pub x_encoder_position: i32,
-    pub y_position: Vec<i32>,
-    pub y_encoder_position: Vec<i32>,
-    pub z_position: Vec<i32>,
-    pub z_encoder_position: Vec<i32>,
-    pub d_position: Vec<i32>,
-    pub d_encoder_position: Vec<i32>,
-    pub s_position: Vec<i32>,
-    pub s_encoder_position: Vec<i32>,
+    pub x_position: Millimeters,
+    pub x_encoder_position: Millimeters,
+    pub y_position: Vec<Millimeters>,
+    pub y_encoder_position: Vec<Millimeters>,
+    pub z_position: Vec<Millimeters>,
+    pub z_encoder_position: Vec<Millimeters>,
+    pub d_position: Vec<Millimeters>,
+    pub d_encoder_position: Vec<Millimeters>,
+    pub s_position: Vec<Millimeters>,
+    pub s_encoder_position: Vec<Millimeters>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetBoardInformationReply {
     pub board_version: u32,
     pub pld_version: u32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -658,8 +1235,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -669,23 +1246,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -693,8 +1270,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
@@ -1,14 +1,14 @@
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
+use piglet_client::units::Millimeters;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +16,13 @@ use std::sync::Arc;
 pub struct NimbusCoreXDrive {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreXDrive {
-    // version 0.5
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "XDrive";
+    pub const VERSION: &'static str = "0.5";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,18 +32,40 @@ impl NimbusCoreXDrive {
                 object_id: 260,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.XDrive").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
     pub async fn initialize(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.Initialize()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -48,12 +73,14 @@ impl NimbusCoreXDrive {
     pub async fn is_initialized(&self) -> Result</* initialized= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 2, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.IsInitialized()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let initialized = bool::deserialize(&mut stream)?;
         Ok(initialized)
@@ -63,7 +90,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         position.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  position: {:?}", position)];
                 format!(
@@ -74,7 +103,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -93,7 +122,9 @@ impl NimbusCoreXDrive {
         acceleration.serialize(&mut args);
         restore_vel_acc.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  position: {:?}", position),
@@ -109,7 +140,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -118,7 +149,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         distance.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  distance: {:?}", distance)];
                 format!(
@@ -129,7 +162,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -148,7 +181,9 @@ impl NimbusCoreXDrive {
         acceleration.serialize(&mut args);
         restore_vel_acc.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  distance: {:?}", distance),
@@ -164,7 +199,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -183,7 +218,9 @@ impl NimbusCoreXDrive {
         trip_sense.serialize(&mut args);
         trip_flag.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  distance: {:?}", distance),
@@ -199,7 +236,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let trip_pos = i32::deserialize(&mut stream)?;
         Ok(trip_pos)
@@ -225,7 +262,9 @@ impl NimbusCoreXDrive {
         acceleration.serialize(&mut args);
         restore_vel_acc.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 8, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  distance: {:?}", distance),
@@ -244,7 +283,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let trip_pos = i32::deserialize(&mut stream)?;
         Ok(trip_pos)
@@ -253,12 +292,14 @@ impl NimbusCoreXDrive {
     pub async fn get_latched_position_flag_position(&self) -> Result</* position= */ i32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 9, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetLatchedPositionFlagPosition()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let position = i32::deserialize(&mut stream)?;
         Ok(position)
@@ -267,12 +308,14 @@ impl NimbusCoreXDrive {
     pub async fn get_commanded_position(&self) -> Result</* position= */ i32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 10, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetCommandedPosition()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let position = i32::deserialize(&mut stream)?;
         Ok(position)
@@ -281,12 +324,14 @@ impl NimbusCoreXDrive {
     pub async fn get_encoder_position(&self) -> Result</* position= */ i32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 11, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetEncoderPosition()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let position = i32::deserialize(&mut stream)?;
         Ok(position)
@@ -296,7 +341,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 12, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  velocity: {:?}", velocity)];
                 format!(
@@ -307,7 +354,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -315,12 +362,14 @@ impl NimbusCoreXDrive {
     pub async fn get_velocity(&self) -> Result</* velocity= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 13, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 13, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetVelocity()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let velocity = u32::deserialize(&mut stream)?;
         Ok(velocity)
@@ -330,7 +379,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         acceleration.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 14, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 14, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  acceleration: {:?}", acceleration)];
                 format!(
@@ -341,7 +392,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -349,12 +400,14 @@ impl NimbusCoreXDrive {
     pub async fn get_acceleration(&self) -> Result</* acceleration= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 15, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 15, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetAcceleration()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let acceleration = u32::deserialize(&mut stream)?;
         Ok(acceleration)
@@ -365,7 +418,9 @@ impl NimbusCoreXDrive {
         velocity.serialize(&mut args);
         acceleration.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 16, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 16, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  velocity: {:?}", velocity),
@@ -379,7 +434,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -387,12 +442,14 @@ impl NimbusCoreXDrive {
     pub async fn get_vel_acc(&self) -> Result<GetVelAccReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 17, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 17, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetVelAcc()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let velocity = u32::deserialize(&mut stream)?;
         let acceleration = u32::deserialize(&mut stream)?;
@@ -407,7 +464,9 @@ impl NimbusCoreXDrive {
         lower_limit.serialize(&mut args);
         upper_limit.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 18, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 18, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  lower_limit: {:?}", lower_limit),
@@ -421,7 +480,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -429,12 +488,14 @@ impl NimbusCoreXDrive {
     pub async fn get_travel_limits(&self) -> Result<GetTravelLimitsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 19, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 19, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetTravelLimits()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let lower_limit = i32::deserialize(&mut stream)?;
         let upper_limit = i32::deserialize(&mut stream)?;
@@ -448,7 +509,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         offset.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 20, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 20, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  offset: {:?}", offset)];
                 format!(
@@ -459,7 +522,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -467,12 +530,14 @@ impl NimbusCoreXDrive {
     pub async fn get_travel_limits_offset(&self) -> Result</* offset= */ i32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 21, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 21, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetTravelLimitsOffset()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let offset = i32::deserialize(&mut stream)?;
         Ok(offset)
@@ -482,7 +547,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         enable.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 22, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 22, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  enable: {:?}", enable)];
                 format!(
@@ -493,7 +560,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -501,22 +568,26 @@ impl NimbusCoreXDrive {
     pub async fn is_travel_limits_enabled(&self) -> Result</* enable= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 23, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 23, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.IsTravelLimitsEnabled()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let enable = bool::deserialize(&mut stream)?;
         Ok(enable)
     }
 
-    pub async fn set_home_position(&self, home_position: i32) -> Result<(), Error> {
+    pub async fn set_home_position(&self, home_position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        home_position.serialize(&mut args);
+        home_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 24, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 24, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  home_position: {:?}", home_position)];
                 format!(
@@ -527,34 +598,38 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn get_home_position(&self) -> Result</* home_position= */ i32, Error> {
+    pub async fn get_home_position(&self) -> Result</* home_position= */ Millimeters, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 25, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 25, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetHomePosition()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
-        let home_position = i32::deserialize(&mut stream)?;
+        let home_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
         Ok(home_position)
     }
 
     pub async fn get_sensor_states(&self) -> Result</* sensor_states= */ u8, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 26, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 26, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetSensorStates()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let sensor_states = u8::deserialize(&mut stream)?;
         Ok(sensor_states)
@@ -563,12 +638,14 @@ impl NimbusCoreXDrive {
     pub async fn get_status(&self) -> Result</* status= */ u32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 27, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 27, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetStatus()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let status = u32::deserialize(&mut stream)?;
         Ok(status)
@@ -577,12 +654,14 @@ impl NimbusCoreXDrive {
     pub async fn is_in_home_sensor(&self) -> Result</* in_home_sensor= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 28, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 28, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.IsInHomeSensor()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let in_home_sensor = bool::deserialize(&mut stream)?;
         Ok(in_home_sensor)
@@ -592,7 +671,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         enable.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 29, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 29, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  enable: {:?}", enable)];
                 format!(
@@ -603,7 +684,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -611,12 +692,14 @@ impl NimbusCoreXDrive {
     pub async fn is_servo_enabled(&self) -> Result</* enable= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 30, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 30, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.IsServoEnabled()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let enable = bool::deserialize(&mut stream)?;
         Ok(enable)
@@ -626,7 +709,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         profile.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 31, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 31, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  profile: {:?}", profile)];
                 format!(
@@ -637,7 +722,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -645,12 +730,14 @@ impl NimbusCoreXDrive {
     pub async fn get_profile(&self) -> Result</* profile= */ i8, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 32, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 32, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetProfile()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let profile = i8::deserialize(&mut stream)?;
         Ok(profile)
@@ -660,7 +747,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         jerk_ratio.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 33, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 33, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  jerk_ratio: {:?}", jerk_ratio)];
                 format!(
@@ -671,7 +760,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -679,12 +768,14 @@ impl NimbusCoreXDrive {
     pub async fn get_jerk_ratio(&self) -> Result</* jerk_ratio= */ u8, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 34, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 34, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetJerkRatio()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let jerk_ratio = u8::deserialize(&mut stream)?;
         Ok(jerk_ratio)
@@ -693,12 +784,14 @@ impl NimbusCoreXDrive {
     pub async fn reset(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 35, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 35, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.Reset()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -707,7 +800,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         hard.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 36, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 36, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  hard: {:?}", hard)];
                 format!(
@@ -718,7 +813,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -727,7 +822,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         j_positive_limit.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 37, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 37, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  j_positive_limit: {:?}", j_positive_limit)];
                 format!(
@@ -738,7 +835,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -746,12 +843,14 @@ impl NimbusCoreXDrive {
     pub async fn get_j_positive_limit(&self) -> Result</* j_positive_limit= */ i32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 38, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 38, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetJPositiveLimit()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let j_positive_limit = i32::deserialize(&mut stream)?;
         Ok(j_positive_limit)
@@ -760,12 +859,14 @@ impl NimbusCoreXDrive {
     pub async fn get_max_j_positive_limit(&self) -> Result</* j_positive_limit_max= */ i32, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 39, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 39, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetMaxJPositiveLimit()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let j_positive_limit_max = i32::deserialize(&mut stream)?;
         Ok(j_positive_limit_max)
@@ -775,7 +876,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         distance.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 40, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 40, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  distance: {:?}", distance)];
                 format!(
@@ -786,7 +889,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let time = u32::deserialize(&mut stream)?;
         Ok(time)
@@ -802,7 +905,9 @@ impl NimbusCoreXDrive {
         distance.serialize(&mut args);
         time.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 41, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 41, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  distance: {:?}", distance),
@@ -816,7 +921,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let j_pos_limit = i32::deserialize(&mut stream)?;
         Ok(j_pos_limit)
@@ -827,7 +932,9 @@ impl NimbusCoreXDrive {
         acc.serialize(&mut args);
         bit_mask.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 42, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 42, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  acc: {:?}", acc),
@@ -841,7 +948,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -850,7 +957,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         label.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 43, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 43, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  label: {:?}", label)];
                 format!(
@@ -861,7 +970,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -869,12 +978,14 @@ impl NimbusCoreXDrive {
     pub async fn get_label(&self) -> Result</* label= */ String, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 44, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 44, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetLabel()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let label = String::deserialize(&mut stream)?;
         Ok(label)
@@ -883,12 +994,14 @@ impl NimbusCoreXDrive {
     pub async fn get_settling_parameters(&self) -> Result<GetSettlingParametersReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 45, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 45, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.GetSettlingParameters()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let time = u16::deserialize(&mut stream)?;
         let time_limit = u16::deserialize(&mut stream)?;
@@ -900,7 +1013,9 @@ impl NimbusCoreXDrive {
         time.serialize(&mut args);
         time_limit.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 46, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 46, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  time: {:?}", time),
@@ -914,7 +1029,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -922,12 +1037,14 @@ impl NimbusCoreXDrive {
     pub async fn is_axis_configured(&self) -> Result</* configured= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 47, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 47, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.IsAxisConfigured()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let configured = bool::deserialize(&mut stream)?;
         Ok(configured)
@@ -936,12 +1053,14 @@ impl NimbusCoreXDrive {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -959,7 +1078,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -970,7 +1091,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -992,7 +1113,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -1003,7 +1126,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -1018,12 +1141,14 @@ impl NimbusCoreXDrive {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXDrive.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -1037,7 +1162,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -1048,7 +1175,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -1066,7 +1193,9 @@ impl NimbusCoreXDrive {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -1077,7 +1206,7 @@ impl NimbusCoreXDrive {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -1092,124 +1221,1036 @@ impl NimbusCoreXDrive {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub enum EMotionprofile {
-    EmotionprofileTrapezoidal = 0,
-    EmotionprofileParabolic = 1,
-    EmotionprofileStep = 2,
-    EmotionprofileScurve = 3,
+// The methods above as a trait, so code using them can be tested against NimbusCoreXDriveFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreXDriveApi {
+    fn initialize(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn is_initialized(&self)
+    -> impl Future<Output = Result</* initialized= */ bool, Error>> + Send;
+    fn move_absolute_1(&self, position: i32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_absolute_2(
+        &self,
+        position: i32,
+        velocity: u32,
+        acceleration: u32,
+        restore_vel_acc: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_relative_1(&self, distance: i32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_relative_2(
+        &self,
+        distance: i32,
+        velocity: u32,
+        acceleration: u32,
+        restore_vel_acc: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn seek_to_position_flag_1(
+        &self,
+        distance: i32,
+        travel_limits_enable: bool,
+        trip_sense: i8,
+        trip_flag: i8,
+    ) -> impl Future<Output = Result</* trip_pos= */ i32, Error>> + Send;
+    fn seek_to_position_flag_2(
+        &self,
+        distance: i32,
+        travel_limits_enable: bool,
+        trip_sense: i8,
+        trip_flag: i8,
+        velocity: u32,
+        acceleration: u32,
+        restore_vel_acc: bool,
+    ) -> impl Future<Output = Result</* trip_pos= */ i32, Error>> + Send;
+    fn get_latched_position_flag_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ i32, Error>> + Send;
+    fn get_commanded_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ i32, Error>> + Send;
+    fn get_encoder_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ i32, Error>> + Send;
+    fn set_velocity(&self, velocity: u32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_velocity(&self) -> impl Future<Output = Result</* velocity= */ u32, Error>> + Send;
+    fn set_acceleration(&self, acceleration: u32)
+    -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_acceleration(
+        &self,
+    ) -> impl Future<Output = Result</* acceleration= */ u32, Error>> + Send;
+    fn set_vel_acc(
+        &self,
+        velocity: u32,
+        acceleration: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_vel_acc(&self) -> impl Future<Output = Result<GetVelAccReply, Error>> + Send;
+    fn set_travel_limits(
+        &self,
+        lower_limit: i32,
+        upper_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_travel_limits(&self)
+    -> impl Future<Output = Result<GetTravelLimitsReply, Error>> + Send;
+    fn set_travel_limits_offset(
+        &self,
+        offset: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_travel_limits_offset(
+        &self,
+    ) -> impl Future<Output = Result</* offset= */ i32, Error>> + Send;
+    fn travel_limits_enable(&self, enable: bool) -> impl Future<Output = Result<(), Error>> + Send;
+    fn is_travel_limits_enabled(
+        &self,
+    ) -> impl Future<Output = Result</* enable= */ bool, Error>> + Send;
+    fn set_home_position(
+        &self,
+        home_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_home_position(
+        &self,
+    ) -> impl Future<Output = Result</* home_position= */ Millimeters, Error>> + Send;
+    fn get_sensor_states(
+        &self,
+    ) -> impl Future<Output = Result</* sensor_states= */ u8, Error>> + Send;
+    fn get_status(&self) -> impl Future<Output = Result</* status= */ u32, Error>> + Send;
+    fn is_in_home_sensor(
+        &self,
+    ) -> impl Future<Output = Result</* in_home_sensor= */ bool, Error>> + Send;
+    fn servo_enable(&self, enable: bool) -> impl Future<Output = Result<(), Error>> + Send;
+    fn is_servo_enabled(&self) -> impl Future<Output = Result</* enable= */ bool, Error>> + Send;
+    fn set_profile(&self, profile: i8) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_profile(&self) -> impl Future<Output = Result</* profile= */ i8, Error>> + Send;
+    fn set_jerk_ratio(&self, jerk_ratio: u8) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_jerk_ratio(&self) -> impl Future<Output = Result</* jerk_ratio= */ u8, Error>> + Send;
+    fn reset(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn stop(&self, hard: bool) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_j_positive_limit(
+        &self,
+        j_positive_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_j_positive_limit(
+        &self,
+    ) -> impl Future<Output = Result</* j_positive_limit= */ i32, Error>> + Send;
+    fn get_max_j_positive_limit(
+        &self,
+    ) -> impl Future<Output = Result</* j_positive_limit_max= */ i32, Error>> + Send;
+    fn compute_par_ms(
+        &self,
+        distance: i32,
+    ) -> impl Future<Output = Result</* time= */ u32, Error>> + Send;
+    fn compute_par_j_pos_limit(
+        &self,
+        distance: i32,
+        time: u32,
+    ) -> impl Future<Output = Result</* j_pos_limit= */ i32, Error>> + Send;
+    fn configure_stop_soft_w_decel(
+        &self,
+        acc: u32,
+        bit_mask: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_label(&self, label: &str) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_label(&self) -> impl Future<Output = Result</* label= */ String, Error>> + Send;
+    fn get_settling_parameters(
+        &self,
+    ) -> impl Future<Output = Result<GetSettlingParametersReply, Error>> + Send;
+    fn set_settling_parameters(
+        &self,
+        time: u16,
+        time_limit: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn is_axis_configured(
+        &self,
+    ) -> impl Future<Output = Result</* configured= */ bool, Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
 }
 
-impl TryFrom<i32> for EMotionprofile {
-    type Error = piglet_client::client::Error;
+impl NimbusCoreXDriveApi for NimbusCoreXDrive {
+    fn initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::initialize(self)
+    }
 
-    fn try_from(v: i32) -> Result<Self, Self::Error> {
-        match v {
-            0 => Ok(EMotionprofile::EmotionprofileTrapezoidal),
-            1 => Ok(EMotionprofile::EmotionprofileParabolic),
-            2 => Ok(EMotionprofile::EmotionprofileStep),
-            3 => Ok(EMotionprofile::EmotionprofileScurve),
+    fn is_initialized(
+        &self,
+    ) -> impl Future<Output = Result</* initialized= */ bool, Error>> + Send {
+        NimbusCoreXDrive::is_initialized(self)
+    }
 
-            _ => Err(ConnectionError(anyhow!(
-                "Unknown EMotionprofile value {}",
-                v
-            ))),
-        }
+    fn move_absolute_1(&self, position: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::move_absolute_1(self, position)
     }
-}
 
-impl PigletCodec for EMotionprofile {
-    const TYPE_ID: u8 = 32;
-}
+    fn move_absolute_2(
+        &self,
+        position: i32,
+        velocity: u32,
+        acceleration: u32,
+        restore_vel_acc: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::move_absolute_2(self, position, velocity, acceleration, restore_vel_acc)
+    }
 
-impl PigletDeserialize for EMotionprofile {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut bytes = stream.copy_to_bytes(length);
-        stream.get_i32_le().try_into()
+    fn move_relative_1(&self, distance: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::move_relative_1(self, distance)
     }
-}
 
-impl PigletSerialize for EMotionprofile {
-    fn serialize(&self, stream: &mut BytesMut) {
-        stream.put_u8(Self::TYPE_ID);
-        stream.put_u8(0);
-        stream.put_u16_le(4);
-        stream.put_i32_le(*self as i32);
+    fn move_relative_2(
+        &self,
+        distance: i32,
+        velocity: u32,
+        acceleration: u32,
+        restore_vel_acc: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::move_relative_2(self, distance, velocity, acceleration, restore_vel_acc)
+    }
+
+    fn seek_to_position_flag_1(
+        &self,
+        distance: i32,
+        travel_limits_enable: bool,
+        trip_sense: i8,
+        trip_flag: i8,
+    ) -> impl Future<Output = Result</* trip_pos= */ i32, Error>> + Send {
+        NimbusCoreXDrive::seek_to_position_flag_1(
+            self,
+            distance,
+            travel_limits_enable,
+            trip_sense,
+            trip_flag,
+        )
+    }
+
+    fn seek_to_position_flag_2(
+        &self,
+        distance: i32,
+        travel_limits_enable: bool,
+        trip_sense: i8,
+        trip_flag: i8,
+        velocity: u32,
+        acceleration: u32,
+        restore_vel_acc: bool,
+    ) -> impl Future<Output = Result</* trip_pos= */ i32, Error>> + Send {
+        NimbusCoreXDrive::seek_to_position_flag_2(
+            self,
+            distance,
+            travel_limits_enable,
+            trip_sense,
+            trip_flag,
+            velocity,
+            acceleration,
+            restore_vel_acc,
+        )
+    }
+
+    fn get_latched_position_flag_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ i32, Error>> + Send {
+        NimbusCoreXDrive::get_latched_position_flag_position(self)
+    }
+
+    fn get_commanded_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ i32, Error>> + Send {
+        NimbusCoreXDrive::get_commanded_position(self)
+    }
+
+    fn get_encoder_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ i32, Error>> + Send {
+        NimbusCoreXDrive::get_encoder_position(self)
+    }
+
+    fn set_velocity(&self, velocity: u32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::set_velocity(self, velocity)
+    }
+
+    fn get_velocity(&self) -> impl Future<Output = Result</* velocity= */ u32, Error>> + Send {
+        NimbusCoreXDrive::get_velocity(self)
+    }
+
+    fn set_acceleration(
+        &self,
+        acceleration: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::set_acceleration(self, acceleration)
+    }
+
+    fn get_acceleration(
+        &self,
+    ) -> impl Future<Output = Result</* acceleration= */ u32, Error>> + Send {
+        NimbusCoreXDrive::get_acceleration(self)
+    }
+
+    fn set_vel_acc(
+        &self,
+        velocity: u32,
+        acceleration: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::set_vel_acc(self, velocity, acceleration)
+    }
+
+    fn get_vel_acc(&self) -> impl Future<Output = Result<GetVelAccReply, Error>> + Send {
+        NimbusCoreXDrive::get_vel_acc(self)
+    }
+
+    fn set_travel_limits(
+        &self,
+        lower_limit: i32,
+        upper_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::set_travel_limits(self, lower_limit, upper_limit)
+    }
+
+    fn get_travel_limits(
+        &self,
+    ) -> impl Future<Output = Result<GetTravelLimitsReply, Error>> + Send {
+        NimbusCoreXDrive::get_travel_limits(self)
+    }
+
+    fn set_travel_limits_offset(
+        &self,
+        offset: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::set_travel_limits_offset(self, offset)
+    }
+
+    fn get_travel_limits_offset(
+        &self,
+    ) -> impl Future<Output = Result</* offset= */ i32, Error>> + Send {
+        NimbusCoreXDrive::get_travel_limits_offset(self)
+    }
+
+    fn travel_limits_enable(&self, enable: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::travel_limits_enable(self, enable)
+    }
+
+    fn is_travel_limits_enabled(
+        &self,
+    ) -> impl Future<Output = Result</* enable= */ bool, Error>> + Send {
+        NimbusCoreXDrive::is_travel_limits_enabled(self)
+    }
+
+    fn set_home_position(
+        &self,
+        home_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::set_home_position(self, home_position)
+    }
+
+    fn get_home_position(
+        &self,
+    ) -> impl Future<Output = Result</* home_position= */ Millimeters, Error>> + Send {
+        NimbusCoreXDrive::get_home_position(self)
+    }
+
+    fn get_sensor_states(
+        &self,
+    ) -> impl Future<Output = Result</* sensor_states= */ u8, Error>> + Send {
+        NimbusCoreXDrive::get_sensor_states(self)
+    }
+
+    fn get_status(&self) -> impl Future<Output = Result</* status= */ u32, Error>> + Send {
+        NimbusCoreXDrive::get_status(self)
+    }
+
+    fn is_in_home_sensor(
+        &self,
+    ) -> impl Future<Output = Result</* in_home_sensor= */ bool, Error>> + Send {
+        NimbusCoreXDrive::is_in_home_sensor(self)
+    }
+
+    fn servo_enable(&self, enable: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::servo_enable(self, enable)
+    }
+
+    fn is_servo_enabled(&self) -> impl Future<Output = Result</* enable= */ bool, Error>> + Send {
+        NimbusCoreXDrive::is_servo_enabled(self)
+    }
+
+    fn set_profile(&self, profile: i8) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::set_profile(self, profile)
+    }
+
+    fn get_profile(&self) -> impl Future<Output = Result</* profile= */ i8, Error>> + Send {
+        NimbusCoreXDrive::get_profile(self)
+    }
+
+    fn set_jerk_ratio(&self, jerk_ratio: u8) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::set_jerk_ratio(self, jerk_ratio)
+    }
+
+    fn get_jerk_ratio(&self) -> impl Future<Output = Result</* jerk_ratio= */ u8, Error>> + Send {
+        NimbusCoreXDrive::get_jerk_ratio(self)
+    }
+
+    fn reset(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::reset(self)
+    }
+
+    fn stop(&self, hard: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::stop(self, hard)
+    }
+
+    fn set_j_positive_limit(
+        &self,
+        j_positive_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::set_j_positive_limit(self, j_positive_limit)
+    }
+
+    fn get_j_positive_limit(
+        &self,
+    ) -> impl Future<Output = Result</* j_positive_limit= */ i32, Error>> + Send {
+        NimbusCoreXDrive::get_j_positive_limit(self)
+    }
+
+    fn get_max_j_positive_limit(
+        &self,
+    ) -> impl Future<Output = Result</* j_positive_limit_max= */ i32, Error>> + Send {
+        NimbusCoreXDrive::get_max_j_positive_limit(self)
+    }
+
+    fn compute_par_ms(
+        &self,
+        distance: i32,
+    ) -> impl Future<Output = Result</* time= */ u32, Error>> + Send {
+        NimbusCoreXDrive::compute_par_ms(self, distance)
+    }
+
+    fn compute_par_j_pos_limit(
+        &self,
+        distance: i32,
+        time: u32,
+    ) -> impl Future<Output = Result</* j_pos_limit= */ i32, Error>> + Send {
+        NimbusCoreXDrive::compute_par_j_pos_limit(self, distance, time)
+    }
+
+    fn configure_stop_soft_w_decel(
+        &self,
+        acc: u32,
+        bit_mask: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::configure_stop_soft_w_decel(self, acc, bit_mask)
     }
-}
 
-impl PigletCodec for MSlice<'_, EMotionprofile> {
-    const TYPE_ID: u8 = 35;
+    fn set_label(&self, label: &str) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::set_label(self, label)
+    }
+
+    fn get_label(&self) -> impl Future<Output = Result</* label= */ String, Error>> + Send {
+        NimbusCoreXDrive::get_label(self)
+    }
+
+    fn get_settling_parameters(
+        &self,
+    ) -> impl Future<Output = Result<GetSettlingParametersReply, Error>> + Send {
+        NimbusCoreXDrive::get_settling_parameters(self)
+    }
+
+    fn set_settling_parameters(
+        &self,
+        time: u16,
+        time_limit: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXDrive::set_settling_parameters(self, time, time_limit)
+    }
+
+    fn is_axis_configured(
+        &self,
+    ) -> impl Future<Output = Result</* configured= */ bool, Error>> + Send {
+        NimbusCoreXDrive::is_axis_configured(self)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreXDrive::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreXDrive::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreXDrive::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreXDrive::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreXDrive::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreXDrive::struct_info(self, interface_id)
+    }
 }
 
-impl PigletCodec for MVec<EMotionprofile> {
-    const TYPE_ID: u8 = 35;
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreXDriveFake {
+    pub recorder: piglet_client::testing::Recorder,
 }
 
-impl PigletDeserialize for MVec<EMotionprofile> {
-    fn deserialize(stream: &mut Bytes) -> Result<Self, Error> {
-        let type_id = stream.get_u8();
-        if Self::TYPE_ID != type_id {
-            return Err(ConnectionError(anyhow!(
-                "Expected {} but got {}",
-                Self::TYPE_ID,
-                type_id
-            )));
-        }
-        let _flags = stream.get_u8();
-        let length = stream.get_u16_le() as usize;
-        let mut arr = Vec::new();
-        for i in 0..(length / 4) {
-            arr.push(stream.get_i32_le().try_into()?);
-        }
-        Ok(MVec(arr))
+#[cfg(feature = "testing")]
+impl NimbusCoreXDriveApi for NimbusCoreXDriveFake {
+    fn initialize(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("initialize", vec![]);
+        async move { reply }
     }
-}
 
-impl PigletSerialize for MSlice<'_, EMotionprofile> {
-    fn serialize(&self, bytes: &mut BytesMut) {
-        bytes.put_u8(Self::TYPE_ID);
-        bytes.put_u8(0);
-        bytes.put_u16_le(4 * self.0.len() as u16);
-        for v in self.0.as_ref() {
-            bytes.put_i32_le(*v as i32);
-        }
+    fn is_initialized(
+        &self,
+    ) -> impl Future<Output = Result</* initialized= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_initialized", vec![]);
+        async move { reply }
+    }
+
+    fn move_absolute_1(&self, position: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_absolute_1",
+            vec![("position", format!("{:?}", position))],
+        );
+        async move { reply }
     }
+
+    fn move_absolute_2(
+        &self,
+        position: i32,
+        velocity: u32,
+        acceleration: u32,
+        restore_vel_acc: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_absolute_2",
+            vec![
+                ("position", format!("{:?}", position)),
+                ("velocity", format!("{:?}", velocity)),
+                ("acceleration", format!("{:?}", acceleration)),
+                ("restore_vel_acc", format!("{:?}", restore_vel_acc)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_relative_1(&self, distance: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_relative_1",
+            vec![("distance", format!("{:?}", distance))],
+        );
+        async move { reply }
+    }
+
+    fn move_relative_2(
+        &self,
+        distance: i32,
+        velocity: u32,
+        acceleration: u32,
+        restore_vel_acc: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_relative_2",
+            vec![
+                ("distance", format!("{:?}", distance)),
+                ("velocity", format!("{:?}", velocity)),
+                ("acceleration", format!("{:?}", acceleration)),
+                ("restore_vel_acc", format!("{:?}", restore_vel_acc)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn seek_to_position_flag_1(
+        &self,
+        distance: i32,
+        travel_limits_enable: bool,
+        trip_sense: i8,
+        trip_flag: i8,
+    ) -> impl Future<Output = Result</* trip_pos= */ i32, Error>> + Send {
+        let reply = self.recorder.record(
+            "seek_to_position_flag_1",
+            vec![
+                ("distance", format!("{:?}", distance)),
+                (
+                    "travel_limits_enable",
+                    format!("{:?}", travel_limits_enable),
+                ),
+                ("trip_sense", format!("{:?}", trip_sense)),
+                ("trip_flag", format!("{:?}", trip_flag)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn seek_to_position_flag_2(
+        &self,
+        distance: i32,
+        travel_limits_enable: bool,
+        trip_sense: i8,
+        trip_flag: i8,
+        velocity: u32,
+        acceleration: u32,
+        restore_vel_acc: bool,
+    ) -> impl Future<Output = Result</* trip_pos= */ i32, Error>> + Send {
+        let reply = self.recorder.record(
+            "seek_to_position_flag_2",
+            vec![
+                ("distance", format!("{:?}", distance)),
+                (
+                    "travel_limits_enable",
+                    format!("{:?}", travel_limits_enable),
+                ),
+                ("trip_sense", format!("{:?}", trip_sense)),
+                ("trip_flag", format!("{:?}", trip_flag)),
+                ("velocity", format!("{:?}", velocity)),
+                ("acceleration", format!("{:?}", acceleration)),
+                ("restore_vel_acc", format!("{:?}", restore_vel_acc)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_latched_position_flag_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ i32, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("get_latched_position_flag_position", vec![]);
+        async move { reply }
+    }
+
+    fn get_commanded_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ i32, Error>> + Send {
+        let reply = self.recorder.record("get_commanded_position", vec![]);
+        async move { reply }
+    }
+
+    fn get_encoder_position(
+        &self,
+    ) -> impl Future<Output = Result</* position= */ i32, Error>> + Send {
+        let reply = self.recorder.record("get_encoder_position", vec![]);
+        async move { reply }
+    }
+
+    fn set_velocity(&self, velocity: u32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_velocity",
+            vec![("velocity", format!("{:?}", velocity))],
+        );
+        async move { reply }
+    }
+
+    fn get_velocity(&self) -> impl Future<Output = Result</* velocity= */ u32, Error>> + Send {
+        let reply = self.recorder.record("get_velocity", vec![]);
+        async move { reply }
+    }
+
+    fn set_acceleration(
+        &self,
+        acceleration: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_acceleration",
+            vec![("acceleration", format!("{:?}", acceleration))],
+        );
+        async move { reply }
+    }
+
+    fn get_acceleration(
+        &self,
+    ) -> impl Future<Output = Result</* acceleration= */ u32, Error>> + Send {
+        let reply = self.recorder.record("get_acceleration", vec![]);
+        async move { reply }
+    }
+
+    fn set_vel_acc(
+        &self,
+        velocity: u32,
+        acceleration: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_vel_acc",
+            vec![
+                ("velocity", format!("{:?}", velocity)),
+                ("acceleration", format!("{:?}", acceleration)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_vel_acc(&self) -> impl Future<Output = Result<GetVelAccReply, Error>> + Send {
+        let reply = self.recorder.record("get_vel_acc", vec![]);
+        async move { reply }
+    }
+
+    fn set_travel_limits(
+        &self,
+        lower_limit: i32,
+        upper_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_travel_limits",
+            vec![
+                ("lower_limit", format!("{:?}", lower_limit)),
+                ("upper_limit", format!("{:?}", upper_limit)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn get_travel_limits(
+        &self,
+    ) -> impl Future<Output = Result<GetTravelLimitsReply, Error>> + Send {
+        let reply = self.recorder.record("get_travel_limits", vec![]);
+        async move { reply }
+    }
+
+    fn set_travel_limits_offset(
+        &self,
+        offset: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_travel_limits_offset",
+            vec![("offset", format!("{:?}", offset))],
+        );
+        async move { reply }
+    }
+
+    fn get_travel_limits_offset(
+        &self,
+    ) -> impl Future<Output = Result</* offset= */ i32, Error>> + Send {
+        let reply = self.recorder.record("get_travel_limits_offset", vec![]);
+        async move { reply }
+    }
+
+    fn travel_limits_enable(&self, enable: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "travel_limits_enable",
+            vec![("enable", format!("{:?}", enable))],
+        );
+        async move { reply }
+    }
+
+    fn is_travel_limits_enabled(
+        &self,
+    ) -> impl Future<Output = Result</* enable= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_travel_limits_enabled", vec![]);
+        async move { reply }
+    }
+
+    fn set_home_position(
+        &self,
+        home_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_home_position",
+            vec![("home_position", format!("{:?}", home_position))],
+        );
+        async move { reply }
+    }
+
+    fn get_home_position(
+        &self,
+    ) -> impl Future<Output = Result</* home_position= */ Millimeters, Error>> + Send {
+        let reply = self.recorder.record("get_home_position", vec![]);
+        async move { reply }
+    }
+
+    fn get_sensor_states(
+        &self,
+    ) -> impl Future<Output = Result</* sensor_states= */ u8, Error>> + Send {
+        let reply = self.recorder.record("get_sensor_states", vec![]);
+        async move { reply }
+    }
+
+    fn get_status(&self) -> impl Future<Output = Result</* status= */ u32, Error>> + Send {
+        let reply = self.recorder.record("get_status", vec![]);
+        async move { reply }
+    }
+
+    fn is_in_home_sensor(
+        &self,
+    ) -> impl Future<Output = Result</* in_home_sensor= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_in_home_sensor", vec![]);
+        async move { reply }
+    }
+
+    fn servo_enable(&self, enable: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("servo_enable", vec![("enable", format!("{:?}", enable))]);
+        async move { reply }
+    }
+
+    fn is_servo_enabled(&self) -> impl Future<Output = Result</* enable= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_servo_enabled", vec![]);
+        async move { reply }
+    }
+
+    fn set_profile(&self, profile: i8) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("set_profile", vec![("profile", format!("{:?}", profile))]);
+        async move { reply }
+    }
+
+    fn get_profile(&self) -> impl Future<Output = Result</* profile= */ i8, Error>> + Send {
+        let reply = self.recorder.record("get_profile", vec![]);
+        async move { reply }
+    }
+
+    fn set_jerk_ratio(&self, jerk_ratio: u8) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_jerk_ratio",
+            vec![("jerk_ratio", format!("{:?}", jerk_ratio))],
+        );
+        async move { reply }
+    }
+
+    fn get_jerk_ratio(&self) -> impl Future<Output = Result</* jerk_ratio= */ u8, Error>> + Send {
+        let reply = self.recorder.record("get_jerk_ratio", vec![]);
+        async move { reply }
+    }
+
+    fn reset(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("reset", vec![]);
+        async move { reply }
+    }
+
+    fn stop(&self, hard: bool) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("stop", vec![("hard", format!("{:?}", hard))]);
+        async move { reply }
+    }
+
+    fn set_j_positive_limit(
+        &self,
+        j_positive_limit: i32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_j_positive_limit",
+            vec![("j_positive_limit", format!("{:?}", j_positive_limit))],
+        );
+        async move { reply }
+    }
+
+    fn get_j_positive_limit(
+        &self,
+    ) -> impl Future<Output = Result</* j_positive_limit= */ i32, Error>> + Send {
+        let reply = self.recorder.record("get_j_positive_limit", vec![]);
+        async move { reply }
+    }
+
+    fn get_max_j_positive_limit(
+        &self,
+    ) -> impl Future<Output = Result</* j_positive_limit_max= */ i32, Error>> + Send {
+        let reply = self.recorder.record("get_max_j_positive_limit", vec![]);
+        async move { reply }
+    }
+
+    fn compute_par_ms(
+        &self,
+        distance: i32,
+    ) -> impl Future<Output = Result</* time= */ u32, Error>> + Send {
+        let reply = self.recorder.record(
+            "compute_par_ms",
+            vec![("distance", format!("{:?}", distance))],
+        );
+        async move { reply }
+    }
+
+    fn compute_par_j_pos_limit(
+        &self,
+        distance: i32,
+        time: u32,
+    ) -> impl Future<Output = Result</* j_pos_limit= */ i32, Error>> + Send {
+        let reply = self.recorder.record(
+            "compute_par_j_pos_limit",
+            vec![
+                ("distance", format!("{:?}", distance)),
+                ("time", format!("{:?}", time)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn configure_stop_soft_w_decel(
+        &self,
+        acc: u32,
+        bit_mask: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "configure_stop_soft_w_decel",
+            vec![
+                ("acc", format!("{:?}", acc)),
+                ("bit_mask", format!("{:?}", bit_mask)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_label(&self, label: &str) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self
+            .recorder
+            .record("set_label", vec![("label", format!("{:?}", label))]);
+        async move { reply }
+    }
+
+    fn get_label(&self) -> impl Future<Output = Result</* label= */ String, Error>> + Send {
+        let reply = self.recorder.record("get_label", vec![]);
+        async move { reply }
+    }
+
+    fn get_settling_parameters(
+        &self,
+    ) -> impl Future<Output = Result<GetSettlingParametersReply, Error>> + Send {
+        let reply = self.recorder.record("get_settling_parameters", vec![]);
+        async move { reply }
+    }
+
+    fn set_settling_parameters(
+        &self,
+        time: u16,
+        time_limit: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_settling_parameters",
+            vec![
+                ("time", format!("{:?}", time)),
+                ("time_limit", format!("{:?}", time_limit)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn is_axis_configured(
+        &self,
+    ) -> impl Future<Output = Result</* configured= */ bool, Error>> + Send {
+        let reply = self.recorder.record("is_axis_configured", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PigletCodec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EMotionprofile {
+    EmotionprofileTrapezoidal = 0,
+    EmotionprofileParabolic = 1,
+    EmotionprofileStep = 2,
+    EmotionprofileScurve = 3,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetVelAccReply {
     pub velocity: u32,
     pub acceleration: u32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetTravelLimitsReply {
     pub lower_limit: i32,
     pub upper_limit: i32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetSettlingParametersReply {
     pub time: u16,
     pub time_limit: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -1217,8 +2258,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -1228,23 +2269,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -1252,8 +2293,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
@@ -1,14 +1,14 @@
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelConfiguration;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::ChannelType;
 use crate::nimbus_hd_1_0::nimbus_core_global_objects::Rail;
+use piglet_client::units::Millimeters;
 
-use crate::traits::{MSlice, MVec};
 use anyhow::anyhow;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use piglet_client::{
-    client::{Error, Error::ConnectionError, RobotClient, with_context},
+    client::{Error, Error::ProtocolError, RobotClient, Timeout, with_context},
     object_address::ObjectAddress,
-    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize},
+    values::{NetworkResult, PigletCodec, PigletDeserialize, PigletSerialize, read_header, take},
 };
 use std::sync::Arc;
 
@@ -16,10 +16,13 @@ use std::sync::Arc;
 pub struct NimbusCoreXyCoord {
     address: ObjectAddress,
     robot: Arc<RobotClient>,
+    timeout: Timeout,
 }
 
 impl NimbusCoreXyCoord {
-    // version 1.0
+    // What the reconstructed dump calls this object and its version, not checked against a robot
+    pub const NAME: &'static str = "XyCoord";
+    pub const VERSION: &'static str = "1.0";
 
     pub fn new(robot: &Arc<RobotClient>) -> Self {
         Self {
@@ -29,6 +32,26 @@ impl NimbusCoreXyCoord {
                 object_id: 261,
             },
             robot: robot.clone(),
+            timeout: Timeout::Default,
+        }
+    }
+
+    // Like new, but looks the object up by name in case the robot numbers its objects differently
+    // The path is from a reconstructed dump and hasn't been checked against a robot, so new is
+    // the default
+    pub async fn resolve(robot: &Arc<RobotClient>) -> Result<Self, Error> {
+        Ok(Self {
+            address: robot.resolve("NimbusCore.XyCoord").await?,
+            robot: robot.clone(),
+            timeout: Timeout::Default,
+        })
+    }
+
+    // Calls made through the returned object wait as long as timeout says
+    pub fn with_timeout(&self, timeout: Timeout) -> Self {
+        Self {
+            timeout,
+            ..self.clone()
         }
     }
 
@@ -36,7 +59,9 @@ impl NimbusCoreXyCoord {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 1, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  tips_used: {:?}", tips_used.as_ref())];
                 format!(
@@ -47,7 +72,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -56,15 +81,22 @@ impl NimbusCoreXyCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: i32,
-        y_position: impl AsRef<[i32]>,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -79,7 +111,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -89,18 +121,25 @@ impl NimbusCoreXyCoord {
 
         tips_used: impl AsRef<[u16]>,
         gripper_tips_used: impl AsRef<[u16]>,
-        x_position: i32,
+        x_position: Millimeters,
         x_acceleration: u32,
-        y_position: impl AsRef<[i32]>,
+        y_position: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
         gripper_tips_used.as_ref().serialize(&mut args);
-        x_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
         x_acceleration.serialize(&mut args);
-        y_position.as_ref().serialize(&mut args);
+        y_position
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -117,7 +156,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -125,21 +164,25 @@ impl NimbusCoreXyCoord {
     pub async fn initialize_x(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXyCoord.InitializeX()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn move_x_absolute(&self, x_position: i32) -> Result<(), Error> {
+    pub async fn move_x_absolute(&self, x_position: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  x_position: {:?}", x_position)];
                 format!(
@@ -150,16 +193,18 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
 
-    pub async fn move_x_relative_1(&self, x_distance: i32) -> Result<(), Error> {
+    pub async fn move_x_relative_1(&self, x_distance: Millimeters) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_distance.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  x_distance: {:?}", x_distance)];
                 format!(
@@ -170,7 +215,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -178,16 +223,18 @@ impl NimbusCoreXyCoord {
     pub async fn move_x_absolute_speed(
         &self,
 
-        x_position: i32,
+        x_position: Millimeters,
         acceleration: u32,
         velocity: u32,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
         acceleration.serialize(&mut args);
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 7, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 7, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_position: {:?}", x_position),
@@ -202,7 +249,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -211,7 +258,9 @@ impl NimbusCoreXyCoord {
         let mut args = BytesMut::new();
         x_offset.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 8, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 8, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  x_offset: {:?}", x_offset)];
                 format!(
@@ -222,7 +271,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -230,12 +279,14 @@ impl NimbusCoreXyCoord {
     pub async fn get_x_calibration(&self) -> Result<GetXCalibrationReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 9, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 9, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXyCoord.GetXCalibration()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let x_offset = i32::deserialize(&mut stream)?;
         Ok(GetXCalibrationReply { x_offset })
@@ -245,7 +296,9 @@ impl NimbusCoreXyCoord {
         let mut args = BytesMut::new();
         enable.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 10, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 10, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  enable: {:?}", enable)];
                 format!(
@@ -256,7 +309,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -264,12 +317,14 @@ impl NimbusCoreXyCoord {
     pub async fn get_x_indication_enable(&self) -> Result</* enable= */ bool, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 11, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 11, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXyCoord.GetXIndicationEnable()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let enable = bool::deserialize(&mut stream)?;
         Ok(enable)
@@ -278,12 +333,14 @@ impl NimbusCoreXyCoord {
     pub async fn is_x_initialized(&self) -> Result<IsXInitializedReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 12, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 12, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXyCoord.IsXInitialized()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let initialized = bool::deserialize(&mut stream)?;
         Ok(IsXInitializedReply { initialized })
@@ -292,15 +349,20 @@ impl NimbusCoreXyCoord {
     pub async fn get_desired_position(&self) -> Result<GetDesiredPositionReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 13, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 13, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXyCoord.GetDesiredPosition()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
-        let x_position = i32::deserialize(&mut stream)?;
-        let y_position = Vec::<i32>::deserialize(&mut stream)?;
+        let x_position = Millimeters::from_wire(i32::deserialize(&mut stream)?);
+        let y_position = Vec::<i32>::deserialize(&mut stream)?
+            .into_iter()
+            .map(Millimeters::from_wire)
+            .collect::<Vec<_>>();
         Ok(GetDesiredPositionReply {
             x_position,
             y_position,
@@ -310,12 +372,14 @@ impl NimbusCoreXyCoord {
     pub async fn get_settling_parameters(&self) -> Result<GetSettlingParametersReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 14, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 14, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXyCoord.GetSettlingParameters()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let time = u16::deserialize(&mut stream)?;
         let time_limit = u16::deserialize(&mut stream)?;
@@ -327,7 +391,9 @@ impl NimbusCoreXyCoord {
         time.serialize(&mut args);
         time_limit.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 15, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 15, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  time: {:?}", time),
@@ -341,7 +407,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -350,19 +416,21 @@ impl NimbusCoreXyCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        x_position: i32,
+        x_position: Millimeters,
         acceleration: u32,
         velocity: u32,
         times: impl AsRef<[u32]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        x_position.serialize(&mut args);
+        x_position.to_wire::<i32>()?.serialize(&mut args);
         acceleration.serialize(&mut args);
         velocity.serialize(&mut args);
         times.as_ref().serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 16, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 16, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -379,7 +447,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -388,7 +456,9 @@ impl NimbusCoreXyCoord {
         let mut args = BytesMut::new();
         y_velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 17, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 17, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  y_velocity: {:?}", y_velocity)];
                 format!(
@@ -399,7 +469,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -407,12 +477,14 @@ impl NimbusCoreXyCoord {
     pub async fn get_core_gripper_max_y_velocity(&self) -> Result</* y_velocity= */ u16, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 0, 18, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 0, 18, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXyCoord.GetCoreGripperMaxYVelocity()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let y_velocity = u16::deserialize(&mut stream)?;
         Ok(y_velocity)
@@ -421,16 +493,18 @@ impl NimbusCoreXyCoord {
     pub async fn move_x_relative_2(
         &self,
 
-        x_distance: i32,
+        x_distance: Millimeters,
         acceleration: u32,
         velocity: u32,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
-        x_distance.serialize(&mut args);
+        x_distance.to_wire::<i32>()?.serialize(&mut args);
         acceleration.serialize(&mut args);
         velocity.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 19, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 19, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  x_distance: {:?}", x_distance),
@@ -445,7 +519,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -454,13 +528,20 @@ impl NimbusCoreXyCoord {
         &self,
 
         tips_used: impl AsRef<[u16]>,
-        y_distance: impl AsRef<[i32]>,
+        y_distance: impl AsRef<[Millimeters]>,
     ) -> Result<(), Error> {
         let mut args = BytesMut::new();
         tips_used.as_ref().serialize(&mut args);
-        y_distance.as_ref().serialize(&mut args);
+        y_distance
+            .as_ref()
+            .iter()
+            .map(|v| v.to_wire::<i32>())
+            .collect::<Result<Vec<i32>, Error>>()?
+            .serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 20, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 20, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![
                     format!("  tips_used: {:?}", tips_used.as_ref()),
@@ -474,7 +555,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -483,7 +564,9 @@ impl NimbusCoreXyCoord {
         let mut args = BytesMut::new();
         scale.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 21, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 21, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  scale: {:?}", scale)];
                 format!(
@@ -494,7 +577,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -502,12 +585,14 @@ impl NimbusCoreXyCoord {
     pub async fn get_x_velocity_scale(&self) -> Result</* scale= */ u16, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 22, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 22, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXyCoord.GetXVelocityScale()".to_string(),
         )?;
 
         if count != 1 {
-            return Err(ConnectionError(anyhow!("Expected 1 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 1 values, not {}", count)));
         }
         let scale = u16::deserialize(&mut stream)?;
         Ok(scale)
@@ -516,12 +601,14 @@ impl NimbusCoreXyCoord {
     pub async fn unlock(&self) -> Result<(), Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 1, 3, 23, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 1, 3, 23, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXyCoord.Unlock()".to_string(),
         )?;
 
         if count != 0 {
-            return Err(ConnectionError(anyhow!("Expected 0 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 0 values, not {}", count)));
         }
         Ok(())
     }
@@ -529,12 +616,14 @@ impl NimbusCoreXyCoord {
     pub async fn object_info(&self) -> Result<ObjectInfoReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 1, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 1, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXyCoord.ObjectInfo()".to_string(),
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let name = String::deserialize(&mut stream)?;
         let version = String::deserialize(&mut stream)?;
@@ -552,7 +641,9 @@ impl NimbusCoreXyCoord {
         let mut args = BytesMut::new();
         method.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 2, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 2, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  method: {:?}", method)];
                 format!(
@@ -563,7 +654,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 6 {
-            return Err(ConnectionError(anyhow!("Expected 6 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 6 values, not {}", count)));
         }
         let interfaceid = u8::deserialize(&mut stream)?;
         let action = u8::deserialize(&mut stream)?;
@@ -585,7 +676,9 @@ impl NimbusCoreXyCoord {
         let mut args = BytesMut::new();
         subobject.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 3, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 3, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  subobject: {:?}", subobject)];
                 format!(
@@ -596,7 +689,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 3 {
-            return Err(ConnectionError(anyhow!("Expected 3 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 3 values, not {}", count)));
         }
         let module_id = u16::deserialize(&mut stream)?;
         let node_id = u16::deserialize(&mut stream)?;
@@ -611,12 +704,14 @@ impl NimbusCoreXyCoord {
     pub async fn interface_descriptors(&self) -> Result<InterfaceDescriptorsReply, Error> {
         let mut args = BytesMut::new();
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 4, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 4, args.freeze(), self.timeout)
+                .await,
             || "in call to NimbusCoreXyCoord.InterfaceDescriptors()".to_string(),
         )?;
 
         if count != 2 {
-            return Err(ConnectionError(anyhow!("Expected 2 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 2 values, not {}", count)));
         }
         let interface_ids = Vec::<u8>::deserialize(&mut stream)?;
         let interface_descriptors = Vec::<String>::deserialize(&mut stream)?;
@@ -630,7 +725,9 @@ impl NimbusCoreXyCoord {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 5, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 5, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -641,7 +738,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let enumeration_names = Vec::<String>::deserialize(&mut stream)?;
         let number_enumeration_values = Vec::<u32>::deserialize(&mut stream)?;
@@ -659,7 +756,9 @@ impl NimbusCoreXyCoord {
         let mut args = BytesMut::new();
         interface_id.serialize(&mut args);
         let (count, mut stream) = with_context(
-            self.robot.act(&self.address, 0, 0, 6, args.freeze()).await,
+            self.robot
+                .act_with_timeout(&self.address, 0, 0, 6, args.freeze(), self.timeout)
+                .await,
             || {
                 let parameters = vec![format!("  interface_id: {:?}", interface_id)];
                 format!(
@@ -670,7 +769,7 @@ impl NimbusCoreXyCoord {
         )?;
 
         if count != 4 {
-            return Err(ConnectionError(anyhow!("Expected 4 values, not {}", count)));
+            return Err(ProtocolError(anyhow!("Expected 4 values, not {}", count)));
         }
         let struct_names = Vec::<String>::deserialize(&mut stream)?;
         let number_structure_elements = Vec::<u32>::deserialize(&mut stream)?;
@@ -685,34 +784,670 @@ impl NimbusCoreXyCoord {
     }
 }
 
-#[allow(non_camel_case_types)]
+// The methods above as a trait, so code using them can be tested against NimbusCoreXyCoordFake instead
+#[allow(clippy::too_many_arguments)]
+pub trait NimbusCoreXyCoordApi {
+    fn initialize_xy(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_xy_absolute(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_xy_absolute_plate(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        gripper_tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn initialize_x(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_x_absolute(
+        &self,
+        x_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_x_relative_1(
+        &self,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_x_absolute_speed(
+        &self,
+        x_position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_x_calibration(&self, x_offset: i32) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_x_calibration(&self)
+    -> impl Future<Output = Result<GetXCalibrationReply, Error>> + Send;
+    fn set_x_indication_enable(
+        &self,
+        enable: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_x_indication_enable(
+        &self,
+    ) -> impl Future<Output = Result</* enable= */ bool, Error>> + Send;
+    fn is_x_initialized(&self) -> impl Future<Output = Result<IsXInitializedReply, Error>> + Send;
+    fn get_desired_position(
+        &self,
+    ) -> impl Future<Output = Result<GetDesiredPositionReply, Error>> + Send;
+    fn get_settling_parameters(
+        &self,
+    ) -> impl Future<Output = Result<GetSettlingParametersReply, Error>> + Send;
+    fn set_settling_parameters(
+        &self,
+        time: u16,
+        time_limit: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+        times: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_core_gripper_max_y_velocity(
+        &self,
+        y_velocity: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_core_gripper_max_y_velocity(
+        &self,
+    ) -> impl Future<Output = Result</* y_velocity= */ u16, Error>> + Send;
+    fn move_x_relative_2(
+        &self,
+        x_distance: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn move_y_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn set_x_velocity_scale(&self, scale: u16) -> impl Future<Output = Result<(), Error>> + Send;
+    fn get_x_velocity_scale(&self) -> impl Future<Output = Result</* scale= */ u16, Error>> + Send;
+    fn unlock(&self) -> impl Future<Output = Result<(), Error>> + Send;
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send;
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send;
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send;
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send;
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send;
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send;
+}
+
+impl NimbusCoreXyCoordApi for NimbusCoreXyCoord {
+    fn initialize_xy(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::initialize_xy(self, tips_used)
+    }
+
+    fn move_xy_absolute(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::move_xy_absolute(self, tips_used, x_position, y_position)
+    }
+
+    fn move_xy_absolute_plate(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        gripper_tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::move_xy_absolute_plate(
+            self,
+            tips_used,
+            gripper_tips_used,
+            x_position,
+            x_acceleration,
+            y_position,
+        )
+    }
+
+    fn initialize_x(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::initialize_x(self)
+    }
+
+    fn move_x_absolute(
+        &self,
+        x_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::move_x_absolute(self, x_position)
+    }
+
+    fn move_x_relative_1(
+        &self,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::move_x_relative_1(self, x_distance)
+    }
+
+    fn move_x_absolute_speed(
+        &self,
+        x_position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::move_x_absolute_speed(self, x_position, acceleration, velocity)
+    }
+
+    fn set_x_calibration(&self, x_offset: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::set_x_calibration(self, x_offset)
+    }
+
+    fn get_x_calibration(
+        &self,
+    ) -> impl Future<Output = Result<GetXCalibrationReply, Error>> + Send {
+        NimbusCoreXyCoord::get_x_calibration(self)
+    }
+
+    fn set_x_indication_enable(
+        &self,
+        enable: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::set_x_indication_enable(self, enable)
+    }
+
+    fn get_x_indication_enable(
+        &self,
+    ) -> impl Future<Output = Result</* enable= */ bool, Error>> + Send {
+        NimbusCoreXyCoord::get_x_indication_enable(self)
+    }
+
+    fn is_x_initialized(&self) -> impl Future<Output = Result<IsXInitializedReply, Error>> + Send {
+        NimbusCoreXyCoord::is_x_initialized(self)
+    }
+
+    fn get_desired_position(
+        &self,
+    ) -> impl Future<Output = Result<GetDesiredPositionReply, Error>> + Send {
+        NimbusCoreXyCoord::get_desired_position(self)
+    }
+
+    fn get_settling_parameters(
+        &self,
+    ) -> impl Future<Output = Result<GetSettlingParametersReply, Error>> + Send {
+        NimbusCoreXyCoord::get_settling_parameters(self)
+    }
+
+    fn set_settling_parameters(
+        &self,
+        time: u16,
+        time_limit: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::set_settling_parameters(self, time, time_limit)
+    }
+
+    fn on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+        times: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::on_the_fly_dispense(
+            self,
+            tips_used,
+            x_position,
+            acceleration,
+            velocity,
+            times,
+        )
+    }
+
+    fn set_core_gripper_max_y_velocity(
+        &self,
+        y_velocity: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::set_core_gripper_max_y_velocity(self, y_velocity)
+    }
+
+    fn get_core_gripper_max_y_velocity(
+        &self,
+    ) -> impl Future<Output = Result</* y_velocity= */ u16, Error>> + Send {
+        NimbusCoreXyCoord::get_core_gripper_max_y_velocity(self)
+    }
+
+    fn move_x_relative_2(
+        &self,
+        x_distance: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::move_x_relative_2(self, x_distance, acceleration, velocity)
+    }
+
+    fn move_y_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::move_y_relative(self, tips_used, y_distance)
+    }
+
+    fn set_x_velocity_scale(&self, scale: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::set_x_velocity_scale(self, scale)
+    }
+
+    fn get_x_velocity_scale(&self) -> impl Future<Output = Result</* scale= */ u16, Error>> + Send {
+        NimbusCoreXyCoord::get_x_velocity_scale(self)
+    }
+
+    fn unlock(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        NimbusCoreXyCoord::unlock(self)
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        NimbusCoreXyCoord::object_info(self)
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        NimbusCoreXyCoord::method_info(self, method)
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        NimbusCoreXyCoord::sub_object_info(self, subobject)
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        NimbusCoreXyCoord::interface_descriptors(self)
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        NimbusCoreXyCoord::enum_info(self, interface_id)
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        NimbusCoreXyCoord::struct_info(self, interface_id)
+    }
+}
+
+// Records calls instead of sending them, and answers each with the next reply queued on recorder
+#[cfg(feature = "testing")]
+#[derive(Clone, Default)]
+pub struct NimbusCoreXyCoordFake {
+    pub recorder: piglet_client::testing::Recorder,
+}
+
+#[cfg(feature = "testing")]
+impl NimbusCoreXyCoordApi for NimbusCoreXyCoordFake {
+    fn initialize_xy(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "initialize_xy",
+            vec![("tips_used", format!("{:?}", tips_used.as_ref()))],
+        );
+        async move { reply }
+    }
+
+    fn move_xy_absolute(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_xy_absolute",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position)),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_xy_absolute_plate(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        gripper_tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        x_acceleration: u32,
+        y_position: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_xy_absolute_plate",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                (
+                    "gripper_tips_used",
+                    format!("{:?}", gripper_tips_used.as_ref()),
+                ),
+                ("x_position", format!("{:?}", x_position)),
+                ("x_acceleration", format!("{:?}", x_acceleration)),
+                ("y_position", format!("{:?}", y_position.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn initialize_x(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("initialize_x", vec![]);
+        async move { reply }
+    }
+
+    fn move_x_absolute(
+        &self,
+        x_position: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_x_absolute",
+            vec![("x_position", format!("{:?}", x_position))],
+        );
+        async move { reply }
+    }
+
+    fn move_x_relative_1(
+        &self,
+        x_distance: Millimeters,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_x_relative_1",
+            vec![("x_distance", format!("{:?}", x_distance))],
+        );
+        async move { reply }
+    }
+
+    fn move_x_absolute_speed(
+        &self,
+        x_position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_x_absolute_speed",
+            vec![
+                ("x_position", format!("{:?}", x_position)),
+                ("acceleration", format!("{:?}", acceleration)),
+                ("velocity", format!("{:?}", velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_x_calibration(&self, x_offset: i32) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_x_calibration",
+            vec![("x_offset", format!("{:?}", x_offset))],
+        );
+        async move { reply }
+    }
+
+    fn get_x_calibration(
+        &self,
+    ) -> impl Future<Output = Result<GetXCalibrationReply, Error>> + Send {
+        let reply = self.recorder.record("get_x_calibration", vec![]);
+        async move { reply }
+    }
+
+    fn set_x_indication_enable(
+        &self,
+        enable: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_x_indication_enable",
+            vec![("enable", format!("{:?}", enable))],
+        );
+        async move { reply }
+    }
+
+    fn get_x_indication_enable(
+        &self,
+    ) -> impl Future<Output = Result</* enable= */ bool, Error>> + Send {
+        let reply = self.recorder.record("get_x_indication_enable", vec![]);
+        async move { reply }
+    }
+
+    fn is_x_initialized(&self) -> impl Future<Output = Result<IsXInitializedReply, Error>> + Send {
+        let reply = self.recorder.record("is_x_initialized", vec![]);
+        async move { reply }
+    }
+
+    fn get_desired_position(
+        &self,
+    ) -> impl Future<Output = Result<GetDesiredPositionReply, Error>> + Send {
+        let reply = self.recorder.record("get_desired_position", vec![]);
+        async move { reply }
+    }
+
+    fn get_settling_parameters(
+        &self,
+    ) -> impl Future<Output = Result<GetSettlingParametersReply, Error>> + Send {
+        let reply = self.recorder.record("get_settling_parameters", vec![]);
+        async move { reply }
+    }
+
+    fn set_settling_parameters(
+        &self,
+        time: u16,
+        time_limit: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_settling_parameters",
+            vec![
+                ("time", format!("{:?}", time)),
+                ("time_limit", format!("{:?}", time_limit)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn on_the_fly_dispense(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        x_position: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+        times: impl AsRef<[u32]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "on_the_fly_dispense",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("x_position", format!("{:?}", x_position)),
+                ("acceleration", format!("{:?}", acceleration)),
+                ("velocity", format!("{:?}", velocity)),
+                ("times", format!("{:?}", times.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_core_gripper_max_y_velocity(
+        &self,
+        y_velocity: u16,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_core_gripper_max_y_velocity",
+            vec![("y_velocity", format!("{:?}", y_velocity))],
+        );
+        async move { reply }
+    }
+
+    fn get_core_gripper_max_y_velocity(
+        &self,
+    ) -> impl Future<Output = Result</* y_velocity= */ u16, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("get_core_gripper_max_y_velocity", vec![]);
+        async move { reply }
+    }
+
+    fn move_x_relative_2(
+        &self,
+        x_distance: Millimeters,
+        acceleration: u32,
+        velocity: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_x_relative_2",
+            vec![
+                ("x_distance", format!("{:?}", x_distance)),
+                ("acceleration", format!("{:?}", acceleration)),
+                ("velocity", format!("{:?}", velocity)),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn move_y_relative(
+        &self,
+        tips_used: impl AsRef<[u16]> + Send,
+        y_distance: impl AsRef<[Millimeters]> + Send,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "move_y_relative",
+            vec![
+                ("tips_used", format!("{:?}", tips_used.as_ref())),
+                ("y_distance", format!("{:?}", y_distance.as_ref())),
+            ],
+        );
+        async move { reply }
+    }
+
+    fn set_x_velocity_scale(&self, scale: u16) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record(
+            "set_x_velocity_scale",
+            vec![("scale", format!("{:?}", scale))],
+        );
+        async move { reply }
+    }
+
+    fn get_x_velocity_scale(&self) -> impl Future<Output = Result</* scale= */ u16, Error>> + Send {
+        let reply = self.recorder.record("get_x_velocity_scale", vec![]);
+        async move { reply }
+    }
+
+    fn unlock(&self) -> impl Future<Output = Result<(), Error>> + Send {
+        let reply = self.recorder.record("unlock", vec![]);
+        async move { reply }
+    }
+
+    fn object_info(&self) -> impl Future<Output = Result<ObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record("object_info", vec![]);
+        async move { reply }
+    }
+
+    fn method_info(
+        &self,
+        method: u32,
+    ) -> impl Future<Output = Result<MethodInfoReply, Error>> + Send {
+        let reply = self
+            .recorder
+            .record("method_info", vec![("method", format!("{:?}", method))]);
+        async move { reply }
+    }
+
+    fn sub_object_info(
+        &self,
+        subobject: u16,
+    ) -> impl Future<Output = Result<SubObjectInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "sub_object_info",
+            vec![("subobject", format!("{:?}", subobject))],
+        );
+        async move { reply }
+    }
+
+    fn interface_descriptors(
+        &self,
+    ) -> impl Future<Output = Result<InterfaceDescriptorsReply, Error>> + Send {
+        let reply = self.recorder.record("interface_descriptors", vec![]);
+        async move { reply }
+    }
+
+    fn enum_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<EnumInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "enum_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+
+    fn struct_info(
+        &self,
+        interface_id: u8,
+    ) -> impl Future<Output = Result<StructInfoReply, Error>> + Send {
+        let reply = self.recorder.record(
+            "struct_info",
+            vec![("interface_id", format!("{:?}", interface_id))],
+        );
+        async move { reply }
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetXCalibrationReply {
     pub x_offset: i32,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IsXInitializedReply {
     pub initialized: bool,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetDesiredPositionReply {
-    pub x_position: i32,
-    pub y_position: Vec<i32>,
+    pub x_position: Millimeters,
+    pub y_position: Vec<Millimeters>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetSettlingParametersReply {
     pub time: u16,
     pub time_limit: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectInfoReply {
     pub name: String,
     pub version: String,
@@ -720,8 +1455,8 @@ pub struct ObjectInfoReply {
     pub subobjects: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MethodInfoReply {
     pub interfaceid: u8,
     pub action: u8,
@@ -731,23 +1466,23 @@ pub struct MethodInfoReply {
     pub parameternames: String,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubObjectInfoReply {
     pub module_id: u16,
     pub node_id: u16,
     pub object_id: u16,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterfaceDescriptorsReply {
     pub interface_ids: Vec<u8>,
     pub interface_descriptors: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumInfoReply {
     pub enumeration_names: Vec<String>,
     pub number_enumeration_values: Vec<u32>,
@@ -755,8 +1490,8 @@ pub struct EnumInfoReply {
     pub enumeration_value_descriptions: Vec<String>,
 }
 
-#[allow(non_camel_case_types)]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructInfoReply {
     pub struct_names: Vec<String>,
     pub number_structure_elements: Vec<u32>,
@@ -0,0 +1,82 @@
+// The deck sends two kinds of event, and each subscription only sees its own
+use bytes::BytesMut;
+use piglet_client::client::RobotClient;
+use piglet_client::events::{Event, PigletEvent};
+use piglet_client::object_address::ObjectAddress;
+use piglet_client::testing::{SimulatedObject, Simulator};
+use piglet_client::values::PigletSerialize;
+use piglet_generated::nimbus_hd_1_0::nimbus_core_hd_deck::{
+    EventGantryState, EventTrackSensors, GantryState,
+};
+
+const DECK: ObjectAddress = ObjectAddress {
+    module_id: 1,
+    node_id: 1,
+    object_id: 266,
+};
+
+fn event<T: PigletEvent + PigletSerialize>(value: T) -> Event {
+    let mut parameters = BytesMut::new();
+    value.serialize(&mut parameters);
+    Event {
+        source: DECK,
+        action: 0,
+        interface_id: T::INTERFACE_ID,
+        call_type: 0,
+        call_type_id: T::CALL_TYPE_ID,
+        count: 1,
+        parameters: parameters.freeze(),
+    }
+}
+
+#[tokio::test]
+async fn subscriptions_only_get_their_own_events() {
+    let simulator = Simulator::builder()
+        .root(
+            DECK.object_id,
+            SimulatedObject {
+                name: "HdDeck".to_string(),
+                ..Default::default()
+            },
+        )
+        .start()
+        .await
+        .unwrap();
+    let robot = RobotClient::connect(simulator.address()).await.unwrap();
+    let mut sensors = robot.subscribe::<EventTrackSensors>(&DECK);
+    let mut gantry = robot.subscribe::<EventGantryState>(&DECK);
+    let mut everything = robot.subscribe_events(&DECK);
+
+    simulator.emit(&event(EventGantryState {
+        state: GantryState::Locked,
+    }));
+    simulator.emit(&event(EventTrackSensors {
+        sensors: vec![true, false],
+    }));
+    // Something the deck sends that none of the generated types are
+    let mut unknown = event(EventTrackSensors {
+        sensors: vec![false],
+    });
+    unknown.call_type_id = 7;
+    simulator.emit(&unknown);
+
+    let received = sensors.recv().await.unwrap().unwrap();
+    assert_eq!(received.sensors, [true, false]);
+    let received = gantry.recv().await.unwrap().unwrap();
+    assert!(matches!(received.state, GantryState::Locked));
+    let ids: Vec<u16> = [
+        everything.recv().await.unwrap(),
+        everything.recv().await.unwrap(),
+        everything.recv().await.unwrap(),
+    ]
+    .iter()
+    .map(|e| e.call_type_id)
+    .collect();
+    assert_eq!(ids, [2, 1, 7]);
+
+    // Closing the connection ends every subscription, so anything else queued would show up here
+    robot.close().await.unwrap();
+    assert!(sensors.recv().await.is_none());
+    assert!(gantry.recv().await.is_none());
+    simulator.close().await.unwrap();
+}
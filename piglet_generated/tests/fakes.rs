@@ -0,0 +1,151 @@
+// Code written against the generated Api traits, run with a fake and with the real thing
+use bytes::{Bytes, BytesMut};
+use piglet_client::client::{Error, RobotClient};
+use piglet_client::object_address::ObjectAddress;
+use piglet_client::testing::{SimulatedObject, Simulator};
+use piglet_client::units::{Millimeters, MmPerSecond};
+use piglet_client::values::PigletSerialize;
+use piglet_generated::nimbus_hd_1_0::nimbus_core_door_lock::{
+    IsDoorLockedReply, NimbusCoreDoorLock, NimbusCoreDoorLockApi, NimbusCoreDoorLockFake,
+};
+use piglet_generated::nimbus_hd_1_0::nimbus_core_pipette::{
+    NimbusCorePipetteApi, NimbusCorePipetteFake, PickupPlateRequest,
+};
+use std::sync::Arc;
+
+async fn lock(door: &impl NimbusCoreDoorLockApi) -> Result<bool, Error> {
+    door.lock_door().await?;
+    Ok(door.is_door_locked().await?.locked)
+}
+
+async fn pick_up(pipette: &impl NimbusCorePipetteApi) -> Result<bool, Error> {
+    let request = PickupPlateRequest::default()
+        .x_position(Millimeters(480.86))
+        .y_plate_center_position(Millimeters(-291.5))
+        .y_plate_width(Millimeters(81.2))
+        .y_open_position(Millimeters(87.2))
+        .y_grip_speed(MmPerSecond(277.8))
+        .y_grip_strength(250)
+        .traverse_height(Millimeters(186.0))
+        .z_grip_height(Millimeters(105.43))
+        .z_final(Millimeters(186.0))
+        .z_speed(MmPerSecond(128.69));
+    pipette.pickup_plate_with(&request).await?;
+    pipette
+        .move_to_position(
+            [1, 0],
+            Millimeters(553.61),
+            vec![Millimeters(-1.61); 2],
+            [Millimeters(235.5), Millimeters(235.5)],
+        )
+        .await?;
+    pipette.is_core_gripper_plate_gripped().await
+}
+
+#[tokio::test]
+async fn fakes_record_calls_and_replay_replies() {
+    let pipette = NimbusCorePipetteFake::default();
+    pipette
+        .recorder
+        .reply("is_core_gripper_plate_gripped", Ok(true));
+    assert!(pick_up(&pipette).await.unwrap());
+
+    let calls = pipette.recorder.calls();
+    let methods: Vec<&str> = calls.iter().map(|c| c.method).collect();
+    assert_eq!(
+        methods,
+        [
+            "pickup_plate",
+            "move_to_position",
+            "is_core_gripper_plate_gripped"
+        ]
+    );
+    assert_eq!(calls[0].argument("x_position"), Some("Millimeters(480.86)"));
+    assert_eq!(calls[0].argument("y_grip_strength"), Some("250"));
+    assert_eq!(calls[1].argument("tips_used"), Some("[1, 0]"));
+    assert_eq!(calls[1].argument("nope"), None);
+
+    // Replies are used up in order, and anything else that returns a value has to be queued
+    assert!(pipette.is_core_gripper_plate_gripped().await.is_err());
+    pipette
+        .recorder
+        .reply("is_core_gripper_plate_gripped", Ok(false));
+    pipette
+        .recorder
+        .reply("is_core_gripper_plate_gripped", Ok(true));
+    assert!(!pick_up(&pipette).await.unwrap());
+    assert!(pick_up(&pipette).await.unwrap());
+}
+
+#[tokio::test]
+async fn requests_with_unset_fields_are_not_sent() {
+    let pipette = NimbusCorePipetteFake::default();
+    let request = PickupPlateRequest::default().x_position(Millimeters(480.86));
+    let result = pipette.pickup_plate_with(&request).await;
+    let Err(Error::InvalidArgument(e)) = result else {
+        panic!("{result:?}");
+    };
+    assert_eq!(
+        e.to_string(),
+        "PickupPlateRequest has no y_plate_center_position set"
+    );
+    assert!(pipette.recorder.calls().is_empty());
+}
+
+#[tokio::test]
+async fn fakes_return_queued_errors_and_check_reply_types() {
+    let door = NimbusCoreDoorLockFake::default();
+    door.recorder.reply::<()>(
+        "lock_door",
+        Err(Error::ConnectionError(anyhow::anyhow!("Door is open"))),
+    );
+    assert!(lock(&door).await.is_err());
+
+    door.recorder.reply("is_door_locked", Ok(true));
+    assert!(lock(&door).await.is_err());
+    door.recorder
+        .reply("is_door_locked", Ok(IsDoorLockedReply { locked: true }));
+    assert!(lock(&door).await.unwrap());
+    assert_eq!(door.recorder.calls().len(), 5);
+}
+
+#[tokio::test]
+async fn robots_implement_the_same_trait() {
+    let address = ObjectAddress {
+        module_id: 1,
+        node_id: 1,
+        object_id: 268,
+    };
+    let simulator = Simulator::builder()
+        .root(
+            268,
+            SimulatedObject {
+                name: "DoorLock".to_string(),
+                ..Default::default()
+            },
+        )
+        .on(&address, 1, 1, |_| Ok((0, Bytes::new())))
+        .on(&address, 1, 3, |_| {
+            let mut reply = BytesMut::new();
+            true.serialize(&mut reply);
+            Ok((1, reply.freeze()))
+        })
+        .start()
+        .await
+        .unwrap();
+    let robot = Arc::new(RobotClient::connect(simulator.address()).await.unwrap());
+
+    // The futures are Send, so they can run on another task
+    let door = NimbusCoreDoorLock::new(&robot);
+    let locked = tokio::spawn(async move { lock(&door).await })
+        .await
+        .unwrap();
+    assert!(locked.unwrap());
+    let calls = simulator.calls();
+    let door_calls: Vec<u16> = calls
+        .iter()
+        .filter(|c| c.destination == address && c.interface_id == 1)
+        .map(|c| c.call_type_id)
+        .collect();
+    assert_eq!(door_calls, [1, 3]);
+}
@@ -0,0 +1,77 @@
+// Generated types through JSON with the serde feature, and back to the same JSON
+use piglet_client::object_address::ObjectAddress;
+use piglet_client::units::{Microliters, Millimeters};
+use piglet_client::values::{ErrorCode, NetworkResult};
+use piglet_generated::nimbus_hd_1_0::nimbus_core_channel::EmptyTipRequest;
+use piglet_generated::nimbus_hd_1_0::nimbus_core_cpu::CalibrationInformation;
+use piglet_generated::nimbus_hd_1_0::nimbus_core_global_objects::{
+    ChannelConfiguration, ChannelType, Rail,
+};
+use piglet_generated::nimbus_hd_1_0::nimbus_core_hd_deck::{
+    GantryState, LedConfiguration, LedState,
+};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> Value {
+    let encoded = serde_json::to_value(value).unwrap();
+    let decoded: T = serde_json::from_value(encoded.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), encoded);
+    encoded
+}
+
+#[test]
+fn structs_and_enums() {
+    let configuration = ChannelConfiguration {
+        type_: ChannelType::Channel1000ul,
+        rail: Rail::Right,
+        previous_neighbor_spacing: 900,
+        next_neighbor_spacing: 900,
+        can_address: 3,
+    };
+    assert_eq!(
+        round_trip(&configuration),
+        json!({
+            "type_": "Channel1000ul",
+            "rail": "Right",
+            "previous_neighbor_spacing": 900,
+            "next_neighbor_spacing": 900,
+            "can_address": 3,
+        })
+    );
+    assert_eq!(round_trip(&GantryState::Locked), json!("Locked"));
+    assert_eq!(
+        round_trip(&vec![LedConfiguration {
+            position: 2,
+            state: LedState::Loading,
+        }]),
+        json!([{ "position": 2, "state": "Loading" }])
+    );
+}
+
+#[test]
+fn requests_and_client_types() {
+    let request = EmptyTipRequest::default()
+        .dispense_height(Millimeters(12.5))
+        .transport_air_volume(Microliters(5.0));
+    let encoded = round_trip(&request);
+    assert_eq!(encoded["dispense_height"], json!(12.5));
+    assert_eq!(encoded["transport_air_volume"], json!(5.0));
+    assert_eq!(encoded["follow_depth"], json!(null));
+
+    round_trip(&CalibrationInformation {
+        description: "Pressure".to_string(),
+        date: NetworkResult {
+            bytes: vec![5, 0, 2, 0, 0xe9, 0x07].into(),
+        },
+    });
+    assert_eq!(
+        round_trip(&ObjectAddress {
+            module_id: 1,
+            node_id: 1,
+            object_id: 257,
+        }),
+        json!({ "module_id": 1, "node_id": 1, "object_id": 257 })
+    );
+    assert_eq!(round_trip(&ErrorCode(75)), json!(75));
+}